@@ -0,0 +1,167 @@
+//! `readable` benchmarks.
+//!
+//! Run with:
+//! ```bash
+//! cargo bench --features bench
+//! ```
+//!
+//! `criterion` saves the previous results in `target/criterion`
+//! and reports any regressions on the next run.
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+//---------------------------------------------------------------------------------------------------- From
+/// Construction of every type from its inner number.
+fn from(c: &mut Criterion) {
+    let mut g = c.benchmark_group("from");
+
+    // byte
+    g.bench_function("Byte", |b| {
+        b.iter(|| readable::byte::Byte::from(black_box(1_567_112_131_u64)))
+    });
+
+    // num
+    g.bench_function("Unsigned", |b| {
+        b.iter(|| readable::num::Unsigned::from(black_box(u64::MAX)))
+    });
+    g.bench_function("Int", |b| {
+        b.iter(|| readable::num::Int::from(black_box(i64::MIN)))
+    });
+    g.bench_function("Float", |b| {
+        b.iter(|| readable::num::Float::from(black_box(1_234_567.891_f64)))
+    });
+    g.bench_function("Percent", |b| {
+        b.iter(|| readable::num::Percent::from(black_box(99.123_f64)))
+    });
+
+    // run
+    g.bench_function("Runtime", |b| {
+        b.iter(|| readable::run::Runtime::from(black_box(11_111.1_f32)))
+    });
+    g.bench_function("RuntimePad", |b| {
+        b.iter(|| readable::run::RuntimePad::from(black_box(11_111.1_f32)))
+    });
+    g.bench_function("RuntimeMilli", |b| {
+        b.iter(|| readable::run::RuntimeMilli::from(black_box(11_111.1_f64)))
+    });
+
+    // up
+    g.bench_function("Uptime", |b| {
+        b.iter(|| readable::up::Uptime::from(black_box(3_283_199_u32)))
+    });
+    g.bench_function("UptimeFull", |b| {
+        b.iter(|| readable::up::UptimeFull::from(black_box(3_283_199_u32)))
+    });
+
+    // time
+    g.bench_function("Time", |b| {
+        b.iter(|| readable::time::Time::from(black_box(86_399_u32)))
+    });
+    g.bench_function("Military", |b| {
+        b.iter(|| readable::time::Military::from(black_box(86_399_u32)))
+    });
+
+    // date
+    g.bench_function("Date", |b| {
+        b.iter(|| readable::date::Date::from_str(black_box("2020-12-25")))
+    });
+
+    g.finish();
+}
+
+//---------------------------------------------------------------------------------------------------- Display
+/// `Display` of every type, both plain and padded.
+///
+/// These write into a pre-allocated `String` so
+/// that only the formatting itself is measured.
+fn display(c: &mut Criterion) {
+    let mut g = c.benchmark_group("display");
+
+    macro_rules! bench {
+        ($($name:literal => $value:expr),* $(,)?) => {$(
+            let value = $value;
+            let mut buf = String::with_capacity(64);
+
+            g.bench_function($name, |b| {
+                b.iter(|| {
+                    buf.clear();
+                    write!(buf, "{}", black_box(&value)).unwrap();
+                })
+            });
+
+            g.bench_function(concat!($name, " (padded)"), |b| {
+                b.iter(|| {
+                    buf.clear();
+                    write!(buf, "{:>40}", black_box(&value)).unwrap();
+                })
+            });
+        )*};
+    }
+
+    bench! {
+        "Str"          => readable::str::Str::<32>::from_static_str("hello world"),
+        "Byte"         => readable::byte::Byte::from(1_567_112_131_u64),
+        "Unsigned"     => readable::num::Unsigned::from(u64::MAX),
+        "Int"          => readable::num::Int::from(i64::MIN),
+        "Float"        => readable::num::Float::from(1_234_567.891_f64),
+        "Percent"      => readable::num::Percent::from(99.123_f64),
+        "Runtime"      => readable::run::Runtime::from(11_111.1_f32),
+        "RuntimeMilli" => readable::run::RuntimeMilli::from(11_111.1_f64),
+        "Uptime"       => readable::up::Uptime::from(3_283_199_u32),
+        "Time"         => readable::time::Time::from(86_399_u32),
+        "Date"         => readable::date::Date::from_str("2020-12-25").unwrap(),
+    }
+
+    g.finish();
+}
+
+//---------------------------------------------------------------------------------------------------- Toa
+/// The `toa` backends.
+///
+/// Every `Dtoa` input is also formatted with `ryu` directly, so
+/// this compares the built-in Grisu2 backend against `ryu`:
+/// ```bash
+/// cargo bench --features bench -- toa
+/// ```
+///
+/// With the `ryu` feature, `Dtoa` itself uses `ryu`, which
+/// instead shows the overhead `Dtoa` adds on top of it.
+fn toa(c: &mut Criterion) {
+    let mut g = c.benchmark_group("toa");
+
+    g.bench_function("Itoa", |b| {
+        b.iter(|| readable::toa::Itoa::new(black_box(u64::MAX)))
+    });
+
+    for (name, f) in [
+        ("short", 1.5_f64),
+        ("long", 0.1 + 0.2),
+        ("exponent", 1.234_567_8e-200),
+        ("max", f64::MAX),
+    ] {
+        g.bench_function(format!("Dtoa ({name})"), |b| {
+            b.iter(|| readable::toa::Dtoa::new(black_box(f)))
+        });
+        g.bench_function(format!("ryu ({name})"), |b| {
+            b.iter(|| ryu::Buffer::new().format(black_box(f)).len())
+        });
+    }
+    g.bench_function("Dtoa (f32)", |b| {
+        b.iter(|| readable::toa::Dtoa::new(black_box(0.1_f32)))
+    });
+    g.bench_function("ryu (f32)", |b| {
+        b.iter(|| ryu::Buffer::new().format(black_box(0.1_f32)).len())
+    });
+    g.bench_function("Dtoa::fixed", |b| {
+        b.iter(|| readable::toa::Dtoa::fixed(black_box(1_234.567_89_f64), black_box(2)))
+    });
+
+    g.finish();
+}
+
+//---------------------------------------------------------------------------------------------------- Main
+criterion_group!(benches, from, display, toa);
+criterion_main!(benches);
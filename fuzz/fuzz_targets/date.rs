@@ -1,22 +1,17 @@
 #![no_main]
 
 use libfuzzer_sys::fuzz_target;
+use readable::date::*;
 
-fuzz_target!(|data: u64| {
-// fuzz_target!(|data: (u16, u8, u8)| {
-//fuzz_target!(|data: &str| {
-	// TODO/FIXME:
-	// these functions are terrible, fuzzing even
-	// for a bit reveals an insane amount of panics.
-//	if data.is_ascii() {
-//		let _ = readable::Date::from_str(data);
-//		let _ = readable::Nichi::from_str(data);
-//		let _ = readable::NichiFull::from_str(data);
-//	}
-	// let _ = readable::Date::from(data);
-	// let _ = readable::Nichi::from(data);
-	// let _ = readable::NichiFull::from(data);
-	// let _ = readable::Date::from_unix(data);
-	// let _ = readable::Nichi::from_unix(data);
-	// let _ = readable::NichiFull::from_unix(data);
+fuzz_target!(|data: &str| {
+	if let Ok(date) = Date::from_str(data) {
+		assert!(date.len() <= Date::MAX_LEN);
+	}
+	assert!(Date::from_str_silent(data).len() <= Date::MAX_LEN);
+	if let Ok(nichi) = Nichi::from_str(data) {
+		assert!(nichi.len() <= Nichi::MAX_LEN);
+	}
+	if let Ok(nichi) = NichiFull::from_str(data) {
+		assert!(nichi.len() <= NichiFull::MAX_LEN);
+	}
 });
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use readable::toa::*;
+
+// The `dtoa` port must round-trip through `str::parse`.
+fuzz_target!(|data: (f32, f64)| {
+	let (f32, f64) = data;
+
+	let parsed = Dtoa::from(f64).as_str().parse::<f64>().unwrap();
+	assert!(parsed == f64 || (parsed.is_nan() && f64.is_nan()));
+
+	let parsed = Dtoa::from(f32).as_str().parse::<f32>().unwrap();
+	assert!(parsed == f32 || (parsed.is_nan() && f32.is_nan()));
+});
@@ -1,25 +1,90 @@
 #![no_main]
 
+use arbitrary::Arbitrary;
 use libfuzzer_sys::fuzz_target;
 use readable::str::*;
 
-fuzz_target!(|data: ([u8; 255], &str, char)| {
-	let b = data.0;
-	let s = data.1;
-	let c = data.2;
+// A small capacity so the "doesn't fit" paths are hit often.
+const N: usize = 8;
 
-	if let Ok(string) = std::str::from_utf8(&b) {
-		if string.len() <= 255 {
-			let _ = Str::<255>::try_from(string).unwrap();
+#[derive(Arbitrary, Debug)]
+enum Op<'a> {
+	PushStr(&'a str),
+	PushStrSaturating(&'a str),
+	PushChar(char),
+	PushCharSaturating(char),
+	Truncate(usize),
+	Remove(usize),
+	Pop,
+	Clear,
+	Uppercase,
+	Lowercase,
+}
+
+// Applies every operation to both a `Str` and a `String`, they must always match.
+fuzz_target!(|data: (Str<N>, Vec<Op<'_>>)| {
+	let (mut s, ops) = data;
+	let mut model = String::from(s.as_str());
+
+	for op in ops {
+		match op {
+			Op::PushStr(x) => {
+				let fits = model.len() + x.len() <= N;
+				assert_eq!(s.push_str(x).is_ok(), fits);
+				if fits {
+					model.push_str(x);
+				}
+			},
+			Op::PushStrSaturating(x) => {
+				let before = model.len();
+				for c in x.chars() {
+					if model.len() + c.len_utf8() > N {
+						break;
+					}
+					model.push(c);
+				}
+				assert_eq!(s.push_str_saturating(x), model.len() - before);
+			},
+			Op::PushChar(c) => {
+				let fits = model.len() + c.len_utf8() <= N;
+				assert_eq!(s.push_char(c).is_ok(), fits);
+				if fits {
+					model.push(c);
+				}
+			},
+			Op::PushCharSaturating(c) => {
+				if model.len() + c.len_utf8() <= N {
+					model.push(c);
+				}
+				s.push_char_saturating(c);
+			},
+			Op::Truncate(i) => {
+				if i > model.len() || model.is_char_boundary(i) {
+					model.truncate(i);
+					s.truncate(i);
+				}
+			},
+			Op::Remove(i) => {
+				if i < model.len() && model.is_char_boundary(i) {
+					assert_eq!(s.remove(i), model.remove(i));
+				}
+			},
+			Op::Pop => assert_eq!(s.pop(), model.pop()),
+			Op::Clear => {
+				s.clear();
+				model.clear();
+			},
+			Op::Uppercase => {
+				s.make_ascii_uppercase();
+				model.make_ascii_uppercase();
+			},
+			Op::Lowercase => {
+				s.make_ascii_lowercase();
+				model.make_ascii_lowercase();
+			},
 		}
-	}
 
-	if s.len() < 255 {
-		let _ = Str::<255>::try_from(s).unwrap();
-		let mut new = Str::<255>::new();
-		new.push_str_panic(s);
+		assert_eq!(s.as_str(), model);
+		assert!(s.len() <= N);
 	}
-
-	let mut new = Str::<255>::new();
-	new.push_char_panic(c);
 });
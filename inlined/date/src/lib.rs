@@ -2,39 +2,53 @@
 //!
 //! Do not use this crate directly.
 
-mod year;
-mod month;
-mod day;
+/// The first inlined year.
+pub const YEAR_MIN: u16 = 1000;
+/// The last inlined year.
+pub const YEAR_MAX: u16 = 9999;
 
-//---------------------------------------------------------------------------------------------------- Inlined `1900-2100`
+//---------------------------------------------------------------------------------------------------- Inlined `1000-9999`
 #[inline(always)]
-/// # INVARIANT
-/// Input must be [u8; 10].
+/// Parse a `YYYY-MM-DD` date within [`YEAR_MIN`]..=[`YEAR_MAX`].
 ///
+/// The separators are not checked, and are always `-` in the output bytes.
+///
+/// [`None`] is returned if the input is not `10` bytes, or is out of range.
 pub const fn inlined(bytes: &[u8]) -> Option<(u16, u8, u8, [u8; 10])> {
-	let y = match crate::year::year(&bytes) {
-		Some(y) => y,
+	let [y0, y1, y2, y3, _, m0, m1, _, d0, d1] = match bytes {
+		[y0, y1, y2, y3, s0, m0, m1, s1, d0, d1] => [*y0, *y1, *y2, *y3, *s0, *m0, *m1, *s1, *d0, *d1],
+		_ => return None,
+	};
+
+	let y = match digits(&[y0, y1, y2, y3]) {
+		Some(y) if y >= YEAR_MIN && y <= YEAR_MAX => y,
 		_ => return None,
 	};
 
-	let m = match crate::month::month(&bytes) {
-		Some(m) => m,
+	let m = match digits(&[m0, m1]) {
+		Some(m) if m >= 1 && m <= 12 => m as u8,
 		_ => return None,
 	};
 
-	let d = match crate::day::day(&bytes) {
-		Some(m) => m,
+	let d = match digits(&[d0, d1]) {
+		Some(d) if d >= 1 && d <= 31 => d as u8,
 		_ => return None,
 	};
 
-	// `-` is `45` in UTF-8 encoding.
-	Some((y.0, m.0, d.0,
-		[
-			y.1[0], y.1[1], y.1[2], y.1[3],
-			45,
-			m.1[0], m.1[1],
-			45,
-			d.1[0], d.1[1],
-		]
-	))
+	Some((y, m, d, [y0, y1, y2, y3, b'-', m0, m1, b'-', d0, d1]))
+}
+
+/// ASCII digits to a number, [`None`] if there's a non-digit.
+const fn digits(bytes: &[u8]) -> Option<u16> {
+	let mut n = 0;
+	let mut i = 0;
+	while i < bytes.len() {
+		let b = bytes[i];
+		if !b.is_ascii_digit() {
+			return None;
+		}
+		n = n * 10 + (b - b'0') as u16;
+		i += 1;
+	}
+	Some(n)
 }
@@ -1,20 +0,0 @@
-#[inline(always)]
-/// # INVARIANT
-/// Input must be [u8; 10] with byte 7-8 being the `MONTH`.
-pub const fn month(bytes: &[u8]) -> Option<(u8, [u8; 2])> {
-	match bytes {
-		[_,_,_,_,_, 48, 49,_,_,_] => Some(01, [48, 49])),
-		[_,_,_,_,_, 48, 50,_,_,_] => Some(02, [48, 50])),
-		[_,_,_,_,_, 48, 51,_,_,_] => Some(03, [48, 51])),
-		[_,_,_,_,_, 48, 52,_,_,_] => Some(04, [48, 52])),
-		[_,_,_,_,_, 48, 53,_,_,_] => Some(05, [48, 53])),
-		[_,_,_,_,_, 48, 54,_,_,_] => Some(06, [48, 54])),
-		[_,_,_,_,_, 48, 55,_,_,_] => Some(07, [48, 55])),
-		[_,_,_,_,_, 48, 56,_,_,_] => Some(08, [48, 56])),
-		[_,_,_,_,_, 48, 57,_,_,_] => Some(09, [48, 57])),
-		[_,_,_,_,_, 49, 48,_,_,_] => Some(10, [49, 48])),
-		[_,_,_,_,_, 49, 49,_,_,_] => Some(11, [49, 49])),
-		[_,_,_,_,_, 49, 50,_,_,_] => Some(12, [49, 50])),
-		_ => None,
-	}
-}
@@ -1,209 +0,0 @@
-#[inline(always)]
-/// # INVARIANT
-/// Input must be [u8; 10] with first 4 bytes being the `YEAR`.
-pub const fn year(bytes: &[u8]) -> Option<(u16, [u8; 4])> {
-	match bytes {
-		[49, 57, 48, 48,_,_,_,_,_,_] => Some(1900, [49, 57, 48, 48])),
-		[49, 57, 48, 49,_,_,_,_,_,_] => Some(1901, [49, 57, 48, 49])),
-		[49, 57, 48, 50,_,_,_,_,_,_] => Some(1902, [49, 57, 48, 50])),
-		[49, 57, 48, 51,_,_,_,_,_,_] => Some(1903, [49, 57, 48, 51])),
-		[49, 57, 48, 52,_,_,_,_,_,_] => Some(1904, [49, 57, 48, 52])),
-		[49, 57, 48, 53,_,_,_,_,_,_] => Some(1905, [49, 57, 48, 53])),
-		[49, 57, 48, 54,_,_,_,_,_,_] => Some(1906, [49, 57, 48, 54])),
-		[49, 57, 48, 55,_,_,_,_,_,_] => Some(1907, [49, 57, 48, 55])),
-		[49, 57, 48, 56,_,_,_,_,_,_] => Some(1908, [49, 57, 48, 56])),
-		[49, 57, 48, 57,_,_,_,_,_,_] => Some(1909, [49, 57, 48, 57])),
-		[49, 57, 49, 48,_,_,_,_,_,_] => Some(1910, [49, 57, 49, 48])),
-		[49, 57, 49, 49,_,_,_,_,_,_] => Some(1911, [49, 57, 49, 49])),
-		[49, 57, 49, 50,_,_,_,_,_,_] => Some(1912, [49, 57, 49, 50])),
-		[49, 57, 49, 51,_,_,_,_,_,_] => Some(1913, [49, 57, 49, 51])),
-		[49, 57, 49, 52,_,_,_,_,_,_] => Some(1914, [49, 57, 49, 52])),
-		[49, 57, 49, 53,_,_,_,_,_,_] => Some(1915, [49, 57, 49, 53])),
-		[49, 57, 49, 54,_,_,_,_,_,_] => Some(1916, [49, 57, 49, 54])),
-		[49, 57, 49, 55,_,_,_,_,_,_] => Some(1917, [49, 57, 49, 55])),
-		[49, 57, 49, 56,_,_,_,_,_,_] => Some(1918, [49, 57, 49, 56])),
-		[49, 57, 49, 57,_,_,_,_,_,_] => Some(1919, [49, 57, 49, 57])),
-		[49, 57, 50, 48,_,_,_,_,_,_] => Some(1920, [49, 57, 50, 48])),
-		[49, 57, 50, 49,_,_,_,_,_,_] => Some(1921, [49, 57, 50, 49])),
-		[49, 57, 50, 50,_,_,_,_,_,_] => Some(1922, [49, 57, 50, 50])),
-		[49, 57, 50, 51,_,_,_,_,_,_] => Some(1923, [49, 57, 50, 51])),
-		[49, 57, 50, 52,_,_,_,_,_,_] => Some(1924, [49, 57, 50, 52])),
-		[49, 57, 50, 53,_,_,_,_,_,_] => Some(1925, [49, 57, 50, 53])),
-		[49, 57, 50, 54,_,_,_,_,_,_] => Some(1926, [49, 57, 50, 54])),
-		[49, 57, 50, 55,_,_,_,_,_,_] => Some(1927, [49, 57, 50, 55])),
-		[49, 57, 50, 56,_,_,_,_,_,_] => Some(1928, [49, 57, 50, 56])),
-		[49, 57, 50, 57,_,_,_,_,_,_] => Some(1929, [49, 57, 50, 57])),
-		[49, 57, 51, 48,_,_,_,_,_,_] => Some(1930, [49, 57, 51, 48])),
-		[49, 57, 51, 49,_,_,_,_,_,_] => Some(1931, [49, 57, 51, 49])),
-		[49, 57, 51, 50,_,_,_,_,_,_] => Some(1932, [49, 57, 51, 50])),
-		[49, 57, 51, 51,_,_,_,_,_,_] => Some(1933, [49, 57, 51, 51])),
-		[49, 57, 51, 52,_,_,_,_,_,_] => Some(1934, [49, 57, 51, 52])),
-		[49, 57, 51, 53,_,_,_,_,_,_] => Some(1935, [49, 57, 51, 53])),
-		[49, 57, 51, 54,_,_,_,_,_,_] => Some(1936, [49, 57, 51, 54])),
-		[49, 57, 51, 55,_,_,_,_,_,_] => Some(1937, [49, 57, 51, 55])),
-		[49, 57, 51, 56,_,_,_,_,_,_] => Some(1938, [49, 57, 51, 56])),
-		[49, 57, 51, 57,_,_,_,_,_,_] => Some(1939, [49, 57, 51, 57])),
-		[49, 57, 52, 48,_,_,_,_,_,_] => Some(1940, [49, 57, 52, 48])),
-		[49, 57, 52, 49,_,_,_,_,_,_] => Some(1941, [49, 57, 52, 49])),
-		[49, 57, 52, 50,_,_,_,_,_,_] => Some(1942, [49, 57, 52, 50])),
-		[49, 57, 52, 51,_,_,_,_,_,_] => Some(1943, [49, 57, 52, 51])),
-		[49, 57, 52, 52,_,_,_,_,_,_] => Some(1944, [49, 57, 52, 52])),
-		[49, 57, 52, 53,_,_,_,_,_,_] => Some(1945, [49, 57, 52, 53])),
-		[49, 57, 52, 54,_,_,_,_,_,_] => Some(1946, [49, 57, 52, 54])),
-		[49, 57, 52, 55,_,_,_,_,_,_] => Some(1947, [49, 57, 52, 55])),
-		[49, 57, 52, 56,_,_,_,_,_,_] => Some(1948, [49, 57, 52, 56])),
-		[49, 57, 52, 57,_,_,_,_,_,_] => Some(1949, [49, 57, 52, 57])),
-		[49, 57, 53, 48,_,_,_,_,_,_] => Some(1950, [49, 57, 53, 48])),
-		[49, 57, 53, 49,_,_,_,_,_,_] => Some(1951, [49, 57, 53, 49])),
-		[49, 57, 53, 50,_,_,_,_,_,_] => Some(1952, [49, 57, 53, 50])),
-		[49, 57, 53, 51,_,_,_,_,_,_] => Some(1953, [49, 57, 53, 51])),
-		[49, 57, 53, 52,_,_,_,_,_,_] => Some(1954, [49, 57, 53, 52])),
-		[49, 57, 53, 53,_,_,_,_,_,_] => Some(1955, [49, 57, 53, 53])),
-		[49, 57, 53, 54,_,_,_,_,_,_] => Some(1956, [49, 57, 53, 54])),
-		[49, 57, 53, 55,_,_,_,_,_,_] => Some(1957, [49, 57, 53, 55])),
-		[49, 57, 53, 56,_,_,_,_,_,_] => Some(1958, [49, 57, 53, 56])),
-		[49, 57, 53, 57,_,_,_,_,_,_] => Some(1959, [49, 57, 53, 57])),
-		[49, 57, 54, 48,_,_,_,_,_,_] => Some(1960, [49, 57, 54, 48])),
-		[49, 57, 54, 49,_,_,_,_,_,_] => Some(1961, [49, 57, 54, 49])),
-		[49, 57, 54, 50,_,_,_,_,_,_] => Some(1962, [49, 57, 54, 50])),
-		[49, 57, 54, 51,_,_,_,_,_,_] => Some(1963, [49, 57, 54, 51])),
-		[49, 57, 54, 52,_,_,_,_,_,_] => Some(1964, [49, 57, 54, 52])),
-		[49, 57, 54, 53,_,_,_,_,_,_] => Some(1965, [49, 57, 54, 53])),
-		[49, 57, 54, 54,_,_,_,_,_,_] => Some(1966, [49, 57, 54, 54])),
-		[49, 57, 54, 55,_,_,_,_,_,_] => Some(1967, [49, 57, 54, 55])),
-		[49, 57, 54, 56,_,_,_,_,_,_] => Some(1968, [49, 57, 54, 56])),
-		[49, 57, 54, 57,_,_,_,_,_,_] => Some(1969, [49, 57, 54, 57])),
-		[49, 57, 55, 48,_,_,_,_,_,_] => Some(1970, [49, 57, 55, 48])),
-		[49, 57, 55, 49,_,_,_,_,_,_] => Some(1971, [49, 57, 55, 49])),
-		[49, 57, 55, 50,_,_,_,_,_,_] => Some(1972, [49, 57, 55, 50])),
-		[49, 57, 55, 51,_,_,_,_,_,_] => Some(1973, [49, 57, 55, 51])),
-		[49, 57, 55, 52,_,_,_,_,_,_] => Some(1974, [49, 57, 55, 52])),
-		[49, 57, 55, 53,_,_,_,_,_,_] => Some(1975, [49, 57, 55, 53])),
-		[49, 57, 55, 54,_,_,_,_,_,_] => Some(1976, [49, 57, 55, 54])),
-		[49, 57, 55, 55,_,_,_,_,_,_] => Some(1977, [49, 57, 55, 55])),
-		[49, 57, 55, 56,_,_,_,_,_,_] => Some(1978, [49, 57, 55, 56])),
-		[49, 57, 55, 57,_,_,_,_,_,_] => Some(1979, [49, 57, 55, 57])),
-		[49, 57, 56, 48,_,_,_,_,_,_] => Some(1980, [49, 57, 56, 48])),
-		[49, 57, 56, 49,_,_,_,_,_,_] => Some(1981, [49, 57, 56, 49])),
-		[49, 57, 56, 50,_,_,_,_,_,_] => Some(1982, [49, 57, 56, 50])),
-		[49, 57, 56, 51,_,_,_,_,_,_] => Some(1983, [49, 57, 56, 51])),
-		[49, 57, 56, 52,_,_,_,_,_,_] => Some(1984, [49, 57, 56, 52])),
-		[49, 57, 56, 53,_,_,_,_,_,_] => Some(1985, [49, 57, 56, 53])),
-		[49, 57, 56, 54,_,_,_,_,_,_] => Some(1986, [49, 57, 56, 54])),
-		[49, 57, 56, 55,_,_,_,_,_,_] => Some(1987, [49, 57, 56, 55])),
-		[49, 57, 56, 56,_,_,_,_,_,_] => Some(1988, [49, 57, 56, 56])),
-		[49, 57, 56, 57,_,_,_,_,_,_] => Some(1989, [49, 57, 56, 57])),
-		[49, 57, 57, 48,_,_,_,_,_,_] => Some(1990, [49, 57, 57, 48])),
-		[49, 57, 57, 49,_,_,_,_,_,_] => Some(1991, [49, 57, 57, 49])),
-		[49, 57, 57, 50,_,_,_,_,_,_] => Some(1992, [49, 57, 57, 50])),
-		[49, 57, 57, 51,_,_,_,_,_,_] => Some(1993, [49, 57, 57, 51])),
-		[49, 57, 57, 52,_,_,_,_,_,_] => Some(1994, [49, 57, 57, 52])),
-		[49, 57, 57, 53,_,_,_,_,_,_] => Some(1995, [49, 57, 57, 53])),
-		[49, 57, 57, 54,_,_,_,_,_,_] => Some(1996, [49, 57, 57, 54])),
-		[49, 57, 57, 55,_,_,_,_,_,_] => Some(1997, [49, 57, 57, 55])),
-		[49, 57, 57, 56,_,_,_,_,_,_] => Some(1998, [49, 57, 57, 56])),
-		[49, 57, 57, 57,_,_,_,_,_,_] => Some(1999, [49, 57, 57, 57])),
-		[50, 48, 48, 48,_,_,_,_,_,_] => Some(2000, [50, 48, 48, 48])),
-		[50, 48, 48, 49,_,_,_,_,_,_] => Some(2001, [50, 48, 48, 49])),
-		[50, 48, 48, 50,_,_,_,_,_,_] => Some(2002, [50, 48, 48, 50])),
-		[50, 48, 48, 51,_,_,_,_,_,_] => Some(2003, [50, 48, 48, 51])),
-		[50, 48, 48, 52,_,_,_,_,_,_] => Some(2004, [50, 48, 48, 52])),
-		[50, 48, 48, 53,_,_,_,_,_,_] => Some(2005, [50, 48, 48, 53])),
-		[50, 48, 48, 54,_,_,_,_,_,_] => Some(2006, [50, 48, 48, 54])),
-		[50, 48, 48, 55,_,_,_,_,_,_] => Some(2007, [50, 48, 48, 55])),
-		[50, 48, 48, 56,_,_,_,_,_,_] => Some(2008, [50, 48, 48, 56])),
-		[50, 48, 48, 57,_,_,_,_,_,_] => Some(2009, [50, 48, 48, 57])),
-		[50, 48, 49, 48,_,_,_,_,_,_] => Some(2010, [50, 48, 49, 48])),
-		[50, 48, 49, 49,_,_,_,_,_,_] => Some(2011, [50, 48, 49, 49])),
-		[50, 48, 49, 50,_,_,_,_,_,_] => Some(2012, [50, 48, 49, 50])),
-		[50, 48, 49, 51,_,_,_,_,_,_] => Some(2013, [50, 48, 49, 51])),
-		[50, 48, 49, 52,_,_,_,_,_,_] => Some(2014, [50, 48, 49, 52])),
-		[50, 48, 49, 53,_,_,_,_,_,_] => Some(2015, [50, 48, 49, 53])),
-		[50, 48, 49, 54,_,_,_,_,_,_] => Some(2016, [50, 48, 49, 54])),
-		[50, 48, 49, 55,_,_,_,_,_,_] => Some(2017, [50, 48, 49, 55])),
-		[50, 48, 49, 56,_,_,_,_,_,_] => Some(2018, [50, 48, 49, 56])),
-		[50, 48, 49, 57,_,_,_,_,_,_] => Some(2019, [50, 48, 49, 57])),
-		[50, 48, 50, 48,_,_,_,_,_,_] => Some(2020, [50, 48, 50, 48])),
-		[50, 48, 50, 49,_,_,_,_,_,_] => Some(2021, [50, 48, 50, 49])),
-		[50, 48, 50, 50,_,_,_,_,_,_] => Some(2022, [50, 48, 50, 50])),
-		[50, 48, 50, 51,_,_,_,_,_,_] => Some(2023, [50, 48, 50, 51])),
-		[50, 48, 50, 52,_,_,_,_,_,_] => Some(2024, [50, 48, 50, 52])),
-		[50, 48, 50, 53,_,_,_,_,_,_] => Some(2025, [50, 48, 50, 53])),
-		[50, 48, 50, 54,_,_,_,_,_,_] => Some(2026, [50, 48, 50, 54])),
-		[50, 48, 50, 55,_,_,_,_,_,_] => Some(2027, [50, 48, 50, 55])),
-		[50, 48, 50, 56,_,_,_,_,_,_] => Some(2028, [50, 48, 50, 56])),
-		[50, 48, 50, 57,_,_,_,_,_,_] => Some(2029, [50, 48, 50, 57])),
-		[50, 48, 51, 48,_,_,_,_,_,_] => Some(2030, [50, 48, 51, 48])),
-		[50, 48, 51, 49,_,_,_,_,_,_] => Some(2031, [50, 48, 51, 49])),
-		[50, 48, 51, 50,_,_,_,_,_,_] => Some(2032, [50, 48, 51, 50])),
-		[50, 48, 51, 51,_,_,_,_,_,_] => Some(2033, [50, 48, 51, 51])),
-		[50, 48, 51, 52,_,_,_,_,_,_] => Some(2034, [50, 48, 51, 52])),
-		[50, 48, 51, 53,_,_,_,_,_,_] => Some(2035, [50, 48, 51, 53])),
-		[50, 48, 51, 54,_,_,_,_,_,_] => Some(2036, [50, 48, 51, 54])),
-		[50, 48, 51, 55,_,_,_,_,_,_] => Some(2037, [50, 48, 51, 55])),
-		[50, 48, 51, 56,_,_,_,_,_,_] => Some(2038, [50, 48, 51, 56])),
-		[50, 48, 51, 57,_,_,_,_,_,_] => Some(2039, [50, 48, 51, 57])),
-		[50, 48, 52, 48,_,_,_,_,_,_] => Some(2040, [50, 48, 52, 48])),
-		[50, 48, 52, 49,_,_,_,_,_,_] => Some(2041, [50, 48, 52, 49])),
-		[50, 48, 52, 50,_,_,_,_,_,_] => Some(2042, [50, 48, 52, 50])),
-		[50, 48, 52, 51,_,_,_,_,_,_] => Some(2043, [50, 48, 52, 51])),
-		[50, 48, 52, 52,_,_,_,_,_,_] => Some(2044, [50, 48, 52, 52])),
-		[50, 48, 52, 53,_,_,_,_,_,_] => Some(2045, [50, 48, 52, 53])),
-		[50, 48, 52, 54,_,_,_,_,_,_] => Some(2046, [50, 48, 52, 54])),
-		[50, 48, 52, 55,_,_,_,_,_,_] => Some(2047, [50, 48, 52, 55])),
-		[50, 48, 52, 56,_,_,_,_,_,_] => Some(2048, [50, 48, 52, 56])),
-		[50, 48, 52, 57,_,_,_,_,_,_] => Some(2049, [50, 48, 52, 57])),
-		[50, 48, 53, 48,_,_,_,_,_,_] => Some(2050, [50, 48, 53, 48])),
-		[50, 48, 53, 49,_,_,_,_,_,_] => Some(2051, [50, 48, 53, 49])),
-		[50, 48, 53, 50,_,_,_,_,_,_] => Some(2052, [50, 48, 53, 50])),
-		[50, 48, 53, 51,_,_,_,_,_,_] => Some(2053, [50, 48, 53, 51])),
-		[50, 48, 53, 52,_,_,_,_,_,_] => Some(2054, [50, 48, 53, 52])),
-		[50, 48, 53, 53,_,_,_,_,_,_] => Some(2055, [50, 48, 53, 53])),
-		[50, 48, 53, 54,_,_,_,_,_,_] => Some(2056, [50, 48, 53, 54])),
-		[50, 48, 53, 55,_,_,_,_,_,_] => Some(2057, [50, 48, 53, 55])),
-		[50, 48, 53, 56,_,_,_,_,_,_] => Some(2058, [50, 48, 53, 56])),
-		[50, 48, 53, 57,_,_,_,_,_,_] => Some(2059, [50, 48, 53, 57])),
-		[50, 48, 54, 48,_,_,_,_,_,_] => Some(2060, [50, 48, 54, 48])),
-		[50, 48, 54, 49,_,_,_,_,_,_] => Some(2061, [50, 48, 54, 49])),
-		[50, 48, 54, 50,_,_,_,_,_,_] => Some(2062, [50, 48, 54, 50])),
-		[50, 48, 54, 51,_,_,_,_,_,_] => Some(2063, [50, 48, 54, 51])),
-		[50, 48, 54, 52,_,_,_,_,_,_] => Some(2064, [50, 48, 54, 52])),
-		[50, 48, 54, 53,_,_,_,_,_,_] => Some(2065, [50, 48, 54, 53])),
-		[50, 48, 54, 54,_,_,_,_,_,_] => Some(2066, [50, 48, 54, 54])),
-		[50, 48, 54, 55,_,_,_,_,_,_] => Some(2067, [50, 48, 54, 55])),
-		[50, 48, 54, 56,_,_,_,_,_,_] => Some(2068, [50, 48, 54, 56])),
-		[50, 48, 54, 57,_,_,_,_,_,_] => Some(2069, [50, 48, 54, 57])),
-		[50, 48, 55, 48,_,_,_,_,_,_] => Some(2070, [50, 48, 55, 48])),
-		[50, 48, 55, 49,_,_,_,_,_,_] => Some(2071, [50, 48, 55, 49])),
-		[50, 48, 55, 50,_,_,_,_,_,_] => Some(2072, [50, 48, 55, 50])),
-		[50, 48, 55, 51,_,_,_,_,_,_] => Some(2073, [50, 48, 55, 51])),
-		[50, 48, 55, 52,_,_,_,_,_,_] => Some(2074, [50, 48, 55, 52])),
-		[50, 48, 55, 53,_,_,_,_,_,_] => Some(2075, [50, 48, 55, 53])),
-		[50, 48, 55, 54,_,_,_,_,_,_] => Some(2076, [50, 48, 55, 54])),
-		[50, 48, 55, 55,_,_,_,_,_,_] => Some(2077, [50, 48, 55, 55])),
-		[50, 48, 55, 56,_,_,_,_,_,_] => Some(2078, [50, 48, 55, 56])),
-		[50, 48, 55, 57,_,_,_,_,_,_] => Some(2079, [50, 48, 55, 57])),
-		[50, 48, 56, 48,_,_,_,_,_,_] => Some(2080, [50, 48, 56, 48])),
-		[50, 48, 56, 49,_,_,_,_,_,_] => Some(2081, [50, 48, 56, 49])),
-		[50, 48, 56, 50,_,_,_,_,_,_] => Some(2082, [50, 48, 56, 50])),
-		[50, 48, 56, 51,_,_,_,_,_,_] => Some(2083, [50, 48, 56, 51])),
-		[50, 48, 56, 52,_,_,_,_,_,_] => Some(2084, [50, 48, 56, 52])),
-		[50, 48, 56, 53,_,_,_,_,_,_] => Some(2085, [50, 48, 56, 53])),
-		[50, 48, 56, 54,_,_,_,_,_,_] => Some(2086, [50, 48, 56, 54])),
-		[50, 48, 56, 55,_,_,_,_,_,_] => Some(2087, [50, 48, 56, 55])),
-		[50, 48, 56, 56,_,_,_,_,_,_] => Some(2088, [50, 48, 56, 56])),
-		[50, 48, 56, 57,_,_,_,_,_,_] => Some(2089, [50, 48, 56, 57])),
-		[50, 48, 57, 48,_,_,_,_,_,_] => Some(2090, [50, 48, 57, 48])),
-		[50, 48, 57, 49,_,_,_,_,_,_] => Some(2091, [50, 48, 57, 49])),
-		[50, 48, 57, 50,_,_,_,_,_,_] => Some(2092, [50, 48, 57, 50])),
-		[50, 48, 57, 51,_,_,_,_,_,_] => Some(2093, [50, 48, 57, 51])),
-		[50, 48, 57, 52,_,_,_,_,_,_] => Some(2094, [50, 48, 57, 52])),
-		[50, 48, 57, 53,_,_,_,_,_,_] => Some(2095, [50, 48, 57, 53])),
-		[50, 48, 57, 54,_,_,_,_,_,_] => Some(2096, [50, 48, 57, 54])),
-		[50, 48, 57, 55,_,_,_,_,_,_] => Some(2097, [50, 48, 57, 55])),
-		[50, 48, 57, 56,_,_,_,_,_,_] => Some(2098, [50, 48, 57, 56])),
-		[50, 48, 57, 57,_,_,_,_,_,_] => Some(2099, [50, 48, 57, 57])),
-		[50, 49, 48, 48,_,_,_,_,_,_] => Some(2100, [50, 49, 48, 48])),
-		_ => None,
-	}
-}
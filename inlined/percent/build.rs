@@ -0,0 +1,19 @@
+//! Generates `PERCENT_LUT` into `$OUT_DIR/percent.rs`.
+//!
+//! That's every `Percent` string from `0.00%` to `100.00%`.
+
+use std::fmt::Write;
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+
+	let mut out = String::new();
+	writeln!(out, "static PERCENT_LUT: [&str; 10001] = [").unwrap();
+	for i in 0..=10_000 {
+		writeln!(out, "\t\"{}.{:02}%\",", i / 100, i % 100).unwrap();
+	}
+	out.push_str("];\n");
+
+	let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("percent.rs");
+	std::fs::write(path, out).unwrap();
+}
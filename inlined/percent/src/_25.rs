@@ -1,2505 +0,0 @@
-#[inline(always)]
-pub(crate) fn inlined_25(f: f64) -> &'static str {
-	match f {
-		25.00..=25.01 => "25.01%",
-		25.01..=25.02 => "25.02%",
-		25.02..=25.03 => "25.03%",
-		25.03..=25.04 => "25.04%",
-		25.04..=25.05 => "25.05%",
-		25.05..=25.06 => "25.06%",
-		25.06..=25.07 => "25.07%",
-		25.07..=25.08 => "25.08%",
-		25.08..=25.09 => "25.09%",
-		25.09..=25.10 => "25.10%",
-		25.10..=25.11 => "25.11%",
-		25.11..=25.12 => "25.12%",
-		25.12..=25.13 => "25.13%",
-		25.13..=25.14 => "25.14%",
-		25.14..=25.15 => "25.15%",
-		25.15..=25.16 => "25.16%",
-		25.16..=25.17 => "25.17%",
-		25.17..=25.18 => "25.18%",
-		25.18..=25.19 => "25.19%",
-		25.19..=25.20 => "25.20%",
-		25.20..=25.21 => "25.21%",
-		25.21..=25.22 => "25.22%",
-		25.22..=25.23 => "25.23%",
-		25.23..=25.24 => "25.24%",
-		25.24..=25.25 => "25.25%",
-		25.25..=25.26 => "25.26%",
-		25.26..=25.27 => "25.27%",
-		25.27..=25.28 => "25.28%",
-		25.28..=25.29 => "25.29%",
-		25.29..=25.30 => "25.30%",
-		25.30..=25.31 => "25.31%",
-		25.31..=25.32 => "25.32%",
-		25.32..=25.33 => "25.33%",
-		25.33..=25.34 => "25.34%",
-		25.34..=25.35 => "25.35%",
-		25.35..=25.36 => "25.36%",
-		25.36..=25.37 => "25.37%",
-		25.37..=25.38 => "25.38%",
-		25.38..=25.39 => "25.39%",
-		25.39..=25.40 => "25.40%",
-		25.40..=25.41 => "25.41%",
-		25.41..=25.42 => "25.42%",
-		25.42..=25.43 => "25.43%",
-		25.43..=25.44 => "25.44%",
-		25.44..=25.45 => "25.45%",
-		25.45..=25.46 => "25.46%",
-		25.46..=25.47 => "25.47%",
-		25.47..=25.48 => "25.48%",
-		25.48..=25.49 => "25.49%",
-		25.49..=25.50 => "25.50%",
-		25.50..=25.51 => "25.51%",
-		25.51..=25.52 => "25.52%",
-		25.52..=25.53 => "25.53%",
-		25.53..=25.54 => "25.54%",
-		25.54..=25.55 => "25.55%",
-		25.55..=25.56 => "25.56%",
-		25.56..=25.57 => "25.57%",
-		25.57..=25.58 => "25.58%",
-		25.58..=25.59 => "25.59%",
-		25.59..=25.60 => "25.60%",
-		25.60..=25.61 => "25.61%",
-		25.61..=25.62 => "25.62%",
-		25.62..=25.63 => "25.63%",
-		25.63..=25.64 => "25.64%",
-		25.64..=25.65 => "25.65%",
-		25.65..=25.66 => "25.66%",
-		25.66..=25.67 => "25.67%",
-		25.67..=25.68 => "25.68%",
-		25.68..=25.69 => "25.69%",
-		25.69..=25.70 => "25.70%",
-		25.70..=25.71 => "25.71%",
-		25.71..=25.72 => "25.72%",
-		25.72..=25.73 => "25.73%",
-		25.73..=25.74 => "25.74%",
-		25.74..=25.75 => "25.75%",
-		25.75..=25.76 => "25.76%",
-		25.76..=25.77 => "25.77%",
-		25.77..=25.78 => "25.78%",
-		25.78..=25.79 => "25.79%",
-		25.79..=25.80 => "25.80%",
-		25.80..=25.81 => "25.81%",
-		25.81..=25.82 => "25.82%",
-		25.82..=25.83 => "25.83%",
-		25.83..=25.84 => "25.84%",
-		25.84..=25.85 => "25.85%",
-		25.85..=25.86 => "25.86%",
-		25.86..=25.87 => "25.87%",
-		25.87..=25.88 => "25.88%",
-		25.88..=25.89 => "25.89%",
-		25.89..=25.90 => "25.90%",
-		25.90..=25.91 => "25.91%",
-		25.91..=25.92 => "25.92%",
-		25.92..=25.93 => "25.93%",
-		25.93..=25.94 => "25.94%",
-		25.94..=25.95 => "25.95%",
-		25.95..=25.96 => "25.96%",
-		25.96..=25.97 => "25.97%",
-		25.97..=25.98 => "25.98%",
-		25.98..=25.99 => "25.99%",
-		25.99..=26.00 => "26.00%",
-		26.00..=26.01 => "26.01%",
-		26.01..=26.02 => "26.02%",
-		26.02..=26.03 => "26.03%",
-		26.03..=26.04 => "26.04%",
-		26.04..=26.05 => "26.05%",
-		26.05..=26.06 => "26.06%",
-		26.06..=26.07 => "26.07%",
-		26.07..=26.08 => "26.08%",
-		26.08..=26.09 => "26.09%",
-		26.09..=26.10 => "26.10%",
-		26.10..=26.11 => "26.11%",
-		26.11..=26.12 => "26.12%",
-		26.12..=26.13 => "26.13%",
-		26.13..=26.14 => "26.14%",
-		26.14..=26.15 => "26.15%",
-		26.15..=26.16 => "26.16%",
-		26.16..=26.17 => "26.17%",
-		26.17..=26.18 => "26.18%",
-		26.18..=26.19 => "26.19%",
-		26.19..=26.20 => "26.20%",
-		26.20..=26.21 => "26.21%",
-		26.21..=26.22 => "26.22%",
-		26.22..=26.23 => "26.23%",
-		26.23..=26.24 => "26.24%",
-		26.24..=26.25 => "26.25%",
-		26.25..=26.26 => "26.26%",
-		26.26..=26.27 => "26.27%",
-		26.27..=26.28 => "26.28%",
-		26.28..=26.29 => "26.29%",
-		26.29..=26.30 => "26.30%",
-		26.30..=26.31 => "26.31%",
-		26.31..=26.32 => "26.32%",
-		26.32..=26.33 => "26.33%",
-		26.33..=26.34 => "26.34%",
-		26.34..=26.35 => "26.35%",
-		26.35..=26.36 => "26.36%",
-		26.36..=26.37 => "26.37%",
-		26.37..=26.38 => "26.38%",
-		26.38..=26.39 => "26.39%",
-		26.39..=26.40 => "26.40%",
-		26.40..=26.41 => "26.41%",
-		26.41..=26.42 => "26.42%",
-		26.42..=26.43 => "26.43%",
-		26.43..=26.44 => "26.44%",
-		26.44..=26.45 => "26.45%",
-		26.45..=26.46 => "26.46%",
-		26.46..=26.47 => "26.47%",
-		26.47..=26.48 => "26.48%",
-		26.48..=26.49 => "26.49%",
-		26.49..=26.50 => "26.50%",
-		26.50..=26.51 => "26.51%",
-		26.51..=26.52 => "26.52%",
-		26.52..=26.53 => "26.53%",
-		26.53..=26.54 => "26.54%",
-		26.54..=26.55 => "26.55%",
-		26.55..=26.56 => "26.56%",
-		26.56..=26.57 => "26.57%",
-		26.57..=26.58 => "26.58%",
-		26.58..=26.59 => "26.59%",
-		26.59..=26.60 => "26.60%",
-		26.60..=26.61 => "26.61%",
-		26.61..=26.62 => "26.62%",
-		26.62..=26.63 => "26.63%",
-		26.63..=26.64 => "26.64%",
-		26.64..=26.65 => "26.65%",
-		26.65..=26.66 => "26.66%",
-		26.66..=26.67 => "26.67%",
-		26.67..=26.68 => "26.68%",
-		26.68..=26.69 => "26.69%",
-		26.69..=26.70 => "26.70%",
-		26.70..=26.71 => "26.71%",
-		26.71..=26.72 => "26.72%",
-		26.72..=26.73 => "26.73%",
-		26.73..=26.74 => "26.74%",
-		26.74..=26.75 => "26.75%",
-		26.75..=26.76 => "26.76%",
-		26.76..=26.77 => "26.77%",
-		26.77..=26.78 => "26.78%",
-		26.78..=26.79 => "26.79%",
-		26.79..=26.80 => "26.80%",
-		26.80..=26.81 => "26.81%",
-		26.81..=26.82 => "26.82%",
-		26.82..=26.83 => "26.83%",
-		26.83..=26.84 => "26.84%",
-		26.84..=26.85 => "26.85%",
-		26.85..=26.86 => "26.86%",
-		26.86..=26.87 => "26.87%",
-		26.87..=26.88 => "26.88%",
-		26.88..=26.89 => "26.89%",
-		26.89..=26.90 => "26.90%",
-		26.90..=26.91 => "26.91%",
-		26.91..=26.92 => "26.92%",
-		26.92..=26.93 => "26.93%",
-		26.93..=26.94 => "26.94%",
-		26.94..=26.95 => "26.95%",
-		26.95..=26.96 => "26.96%",
-		26.96..=26.97 => "26.97%",
-		26.97..=26.98 => "26.98%",
-		26.98..=26.99 => "26.99%",
-		26.99..=27.00 => "27.00%",
-		27.00..=27.01 => "27.01%",
-		27.01..=27.02 => "27.02%",
-		27.02..=27.03 => "27.03%",
-		27.03..=27.04 => "27.04%",
-		27.04..=27.05 => "27.05%",
-		27.05..=27.06 => "27.06%",
-		27.06..=27.07 => "27.07%",
-		27.07..=27.08 => "27.08%",
-		27.08..=27.09 => "27.09%",
-		27.09..=27.10 => "27.10%",
-		27.10..=27.11 => "27.11%",
-		27.11..=27.12 => "27.12%",
-		27.12..=27.13 => "27.13%",
-		27.13..=27.14 => "27.14%",
-		27.14..=27.15 => "27.15%",
-		27.15..=27.16 => "27.16%",
-		27.16..=27.17 => "27.17%",
-		27.17..=27.18 => "27.18%",
-		27.18..=27.19 => "27.19%",
-		27.19..=27.20 => "27.20%",
-		27.20..=27.21 => "27.21%",
-		27.21..=27.22 => "27.22%",
-		27.22..=27.23 => "27.23%",
-		27.23..=27.24 => "27.24%",
-		27.24..=27.25 => "27.25%",
-		27.25..=27.26 => "27.26%",
-		27.26..=27.27 => "27.27%",
-		27.27..=27.28 => "27.28%",
-		27.28..=27.29 => "27.29%",
-		27.29..=27.30 => "27.30%",
-		27.30..=27.31 => "27.31%",
-		27.31..=27.32 => "27.32%",
-		27.32..=27.33 => "27.33%",
-		27.33..=27.34 => "27.34%",
-		27.34..=27.35 => "27.35%",
-		27.35..=27.36 => "27.36%",
-		27.36..=27.37 => "27.37%",
-		27.37..=27.38 => "27.38%",
-		27.38..=27.39 => "27.39%",
-		27.39..=27.40 => "27.40%",
-		27.40..=27.41 => "27.41%",
-		27.41..=27.42 => "27.42%",
-		27.42..=27.43 => "27.43%",
-		27.43..=27.44 => "27.44%",
-		27.44..=27.45 => "27.45%",
-		27.45..=27.46 => "27.46%",
-		27.46..=27.47 => "27.47%",
-		27.47..=27.48 => "27.48%",
-		27.48..=27.49 => "27.49%",
-		27.49..=27.50 => "27.50%",
-		27.50..=27.51 => "27.51%",
-		27.51..=27.52 => "27.52%",
-		27.52..=27.53 => "27.53%",
-		27.53..=27.54 => "27.54%",
-		27.54..=27.55 => "27.55%",
-		27.55..=27.56 => "27.56%",
-		27.56..=27.57 => "27.57%",
-		27.57..=27.58 => "27.58%",
-		27.58..=27.59 => "27.59%",
-		27.59..=27.60 => "27.60%",
-		27.60..=27.61 => "27.61%",
-		27.61..=27.62 => "27.62%",
-		27.62..=27.63 => "27.63%",
-		27.63..=27.64 => "27.64%",
-		27.64..=27.65 => "27.65%",
-		27.65..=27.66 => "27.66%",
-		27.66..=27.67 => "27.67%",
-		27.67..=27.68 => "27.68%",
-		27.68..=27.69 => "27.69%",
-		27.69..=27.70 => "27.70%",
-		27.70..=27.71 => "27.71%",
-		27.71..=27.72 => "27.72%",
-		27.72..=27.73 => "27.73%",
-		27.73..=27.74 => "27.74%",
-		27.74..=27.75 => "27.75%",
-		27.75..=27.76 => "27.76%",
-		27.76..=27.77 => "27.77%",
-		27.77..=27.78 => "27.78%",
-		27.78..=27.79 => "27.79%",
-		27.79..=27.80 => "27.80%",
-		27.80..=27.81 => "27.81%",
-		27.81..=27.82 => "27.82%",
-		27.82..=27.83 => "27.83%",
-		27.83..=27.84 => "27.84%",
-		27.84..=27.85 => "27.85%",
-		27.85..=27.86 => "27.86%",
-		27.86..=27.87 => "27.87%",
-		27.87..=27.88 => "27.88%",
-		27.88..=27.89 => "27.89%",
-		27.89..=27.90 => "27.90%",
-		27.90..=27.91 => "27.91%",
-		27.91..=27.92 => "27.92%",
-		27.92..=27.93 => "27.93%",
-		27.93..=27.94 => "27.94%",
-		27.94..=27.95 => "27.95%",
-		27.95..=27.96 => "27.96%",
-		27.96..=27.97 => "27.97%",
-		27.97..=27.98 => "27.98%",
-		27.98..=27.99 => "27.99%",
-		27.99..=28.00 => "28.00%",
-		28.00..=28.01 => "28.01%",
-		28.01..=28.02 => "28.02%",
-		28.02..=28.03 => "28.03%",
-		28.03..=28.04 => "28.04%",
-		28.04..=28.05 => "28.05%",
-		28.05..=28.06 => "28.06%",
-		28.06..=28.07 => "28.07%",
-		28.07..=28.08 => "28.08%",
-		28.08..=28.09 => "28.09%",
-		28.09..=28.10 => "28.10%",
-		28.10..=28.11 => "28.11%",
-		28.11..=28.12 => "28.12%",
-		28.12..=28.13 => "28.13%",
-		28.13..=28.14 => "28.14%",
-		28.14..=28.15 => "28.15%",
-		28.15..=28.16 => "28.16%",
-		28.16..=28.17 => "28.17%",
-		28.17..=28.18 => "28.18%",
-		28.18..=28.19 => "28.19%",
-		28.19..=28.20 => "28.20%",
-		28.20..=28.21 => "28.21%",
-		28.21..=28.22 => "28.22%",
-		28.22..=28.23 => "28.23%",
-		28.23..=28.24 => "28.24%",
-		28.24..=28.25 => "28.25%",
-		28.25..=28.26 => "28.26%",
-		28.26..=28.27 => "28.27%",
-		28.27..=28.28 => "28.28%",
-		28.28..=28.29 => "28.29%",
-		28.29..=28.30 => "28.30%",
-		28.30..=28.31 => "28.31%",
-		28.31..=28.32 => "28.32%",
-		28.32..=28.33 => "28.33%",
-		28.33..=28.34 => "28.34%",
-		28.34..=28.35 => "28.35%",
-		28.35..=28.36 => "28.36%",
-		28.36..=28.37 => "28.37%",
-		28.37..=28.38 => "28.38%",
-		28.38..=28.39 => "28.39%",
-		28.39..=28.40 => "28.40%",
-		28.40..=28.41 => "28.41%",
-		28.41..=28.42 => "28.42%",
-		28.42..=28.43 => "28.43%",
-		28.43..=28.44 => "28.44%",
-		28.44..=28.45 => "28.45%",
-		28.45..=28.46 => "28.46%",
-		28.46..=28.47 => "28.47%",
-		28.47..=28.48 => "28.48%",
-		28.48..=28.49 => "28.49%",
-		28.49..=28.50 => "28.50%",
-		28.50..=28.51 => "28.51%",
-		28.51..=28.52 => "28.52%",
-		28.52..=28.53 => "28.53%",
-		28.53..=28.54 => "28.54%",
-		28.54..=28.55 => "28.55%",
-		28.55..=28.56 => "28.56%",
-		28.56..=28.57 => "28.57%",
-		28.57..=28.58 => "28.58%",
-		28.58..=28.59 => "28.59%",
-		28.59..=28.60 => "28.60%",
-		28.60..=28.61 => "28.61%",
-		28.61..=28.62 => "28.62%",
-		28.62..=28.63 => "28.63%",
-		28.63..=28.64 => "28.64%",
-		28.64..=28.65 => "28.65%",
-		28.65..=28.66 => "28.66%",
-		28.66..=28.67 => "28.67%",
-		28.67..=28.68 => "28.68%",
-		28.68..=28.69 => "28.69%",
-		28.69..=28.70 => "28.70%",
-		28.70..=28.71 => "28.71%",
-		28.71..=28.72 => "28.72%",
-		28.72..=28.73 => "28.73%",
-		28.73..=28.74 => "28.74%",
-		28.74..=28.75 => "28.75%",
-		28.75..=28.76 => "28.76%",
-		28.76..=28.77 => "28.77%",
-		28.77..=28.78 => "28.78%",
-		28.78..=28.79 => "28.79%",
-		28.79..=28.80 => "28.80%",
-		28.80..=28.81 => "28.81%",
-		28.81..=28.82 => "28.82%",
-		28.82..=28.83 => "28.83%",
-		28.83..=28.84 => "28.84%",
-		28.84..=28.85 => "28.85%",
-		28.85..=28.86 => "28.86%",
-		28.86..=28.87 => "28.87%",
-		28.87..=28.88 => "28.88%",
-		28.88..=28.89 => "28.89%",
-		28.89..=28.90 => "28.90%",
-		28.90..=28.91 => "28.91%",
-		28.91..=28.92 => "28.92%",
-		28.92..=28.93 => "28.93%",
-		28.93..=28.94 => "28.94%",
-		28.94..=28.95 => "28.95%",
-		28.95..=28.96 => "28.96%",
-		28.96..=28.97 => "28.97%",
-		28.97..=28.98 => "28.98%",
-		28.98..=28.99 => "28.99%",
-		28.99..=29.00 => "29.00%",
-		29.00..=29.01 => "29.01%",
-		29.01..=29.02 => "29.02%",
-		29.02..=29.03 => "29.03%",
-		29.03..=29.04 => "29.04%",
-		29.04..=29.05 => "29.05%",
-		29.05..=29.06 => "29.06%",
-		29.06..=29.07 => "29.07%",
-		29.07..=29.08 => "29.08%",
-		29.08..=29.09 => "29.09%",
-		29.09..=29.10 => "29.10%",
-		29.10..=29.11 => "29.11%",
-		29.11..=29.12 => "29.12%",
-		29.12..=29.13 => "29.13%",
-		29.13..=29.14 => "29.14%",
-		29.14..=29.15 => "29.15%",
-		29.15..=29.16 => "29.16%",
-		29.16..=29.17 => "29.17%",
-		29.17..=29.18 => "29.18%",
-		29.18..=29.19 => "29.19%",
-		29.19..=29.20 => "29.20%",
-		29.20..=29.21 => "29.21%",
-		29.21..=29.22 => "29.22%",
-		29.22..=29.23 => "29.23%",
-		29.23..=29.24 => "29.24%",
-		29.24..=29.25 => "29.25%",
-		29.25..=29.26 => "29.26%",
-		29.26..=29.27 => "29.27%",
-		29.27..=29.28 => "29.28%",
-		29.28..=29.29 => "29.29%",
-		29.29..=29.30 => "29.30%",
-		29.30..=29.31 => "29.31%",
-		29.31..=29.32 => "29.32%",
-		29.32..=29.33 => "29.33%",
-		29.33..=29.34 => "29.34%",
-		29.34..=29.35 => "29.35%",
-		29.35..=29.36 => "29.36%",
-		29.36..=29.37 => "29.37%",
-		29.37..=29.38 => "29.38%",
-		29.38..=29.39 => "29.39%",
-		29.39..=29.40 => "29.40%",
-		29.40..=29.41 => "29.41%",
-		29.41..=29.42 => "29.42%",
-		29.42..=29.43 => "29.43%",
-		29.43..=29.44 => "29.44%",
-		29.44..=29.45 => "29.45%",
-		29.45..=29.46 => "29.46%",
-		29.46..=29.47 => "29.47%",
-		29.47..=29.48 => "29.48%",
-		29.48..=29.49 => "29.49%",
-		29.49..=29.50 => "29.50%",
-		29.50..=29.51 => "29.51%",
-		29.51..=29.52 => "29.52%",
-		29.52..=29.53 => "29.53%",
-		29.53..=29.54 => "29.54%",
-		29.54..=29.55 => "29.55%",
-		29.55..=29.56 => "29.56%",
-		29.56..=29.57 => "29.57%",
-		29.57..=29.58 => "29.58%",
-		29.58..=29.59 => "29.59%",
-		29.59..=29.60 => "29.60%",
-		29.60..=29.61 => "29.61%",
-		29.61..=29.62 => "29.62%",
-		29.62..=29.63 => "29.63%",
-		29.63..=29.64 => "29.64%",
-		29.64..=29.65 => "29.65%",
-		29.65..=29.66 => "29.66%",
-		29.66..=29.67 => "29.67%",
-		29.67..=29.68 => "29.68%",
-		29.68..=29.69 => "29.69%",
-		29.69..=29.70 => "29.70%",
-		29.70..=29.71 => "29.71%",
-		29.71..=29.72 => "29.72%",
-		29.72..=29.73 => "29.73%",
-		29.73..=29.74 => "29.74%",
-		29.74..=29.75 => "29.75%",
-		29.75..=29.76 => "29.76%",
-		29.76..=29.77 => "29.77%",
-		29.77..=29.78 => "29.78%",
-		29.78..=29.79 => "29.79%",
-		29.79..=29.80 => "29.80%",
-		29.80..=29.81 => "29.81%",
-		29.81..=29.82 => "29.82%",
-		29.82..=29.83 => "29.83%",
-		29.83..=29.84 => "29.84%",
-		29.84..=29.85 => "29.85%",
-		29.85..=29.86 => "29.86%",
-		29.86..=29.87 => "29.87%",
-		29.87..=29.88 => "29.88%",
-		29.88..=29.89 => "29.89%",
-		29.89..=29.90 => "29.90%",
-		29.90..=29.91 => "29.91%",
-		29.91..=29.92 => "29.92%",
-		29.92..=29.93 => "29.93%",
-		29.93..=29.94 => "29.94%",
-		29.94..=29.95 => "29.95%",
-		29.95..=29.96 => "29.96%",
-		29.96..=29.97 => "29.97%",
-		29.97..=29.98 => "29.98%",
-		29.98..=29.99 => "29.99%",
-		29.99..=30.00 => "30.00%",
-		30.00..=30.01 => "30.01%",
-		30.01..=30.02 => "30.02%",
-		30.02..=30.03 => "30.03%",
-		30.03..=30.04 => "30.04%",
-		30.04..=30.05 => "30.05%",
-		30.05..=30.06 => "30.06%",
-		30.06..=30.07 => "30.07%",
-		30.07..=30.08 => "30.08%",
-		30.08..=30.09 => "30.09%",
-		30.09..=30.10 => "30.10%",
-		30.10..=30.11 => "30.11%",
-		30.11..=30.12 => "30.12%",
-		30.12..=30.13 => "30.13%",
-		30.13..=30.14 => "30.14%",
-		30.14..=30.15 => "30.15%",
-		30.15..=30.16 => "30.16%",
-		30.16..=30.17 => "30.17%",
-		30.17..=30.18 => "30.18%",
-		30.18..=30.19 => "30.19%",
-		30.19..=30.20 => "30.20%",
-		30.20..=30.21 => "30.21%",
-		30.21..=30.22 => "30.22%",
-		30.22..=30.23 => "30.23%",
-		30.23..=30.24 => "30.24%",
-		30.24..=30.25 => "30.25%",
-		30.25..=30.26 => "30.26%",
-		30.26..=30.27 => "30.27%",
-		30.27..=30.28 => "30.28%",
-		30.28..=30.29 => "30.29%",
-		30.29..=30.30 => "30.30%",
-		30.30..=30.31 => "30.31%",
-		30.31..=30.32 => "30.32%",
-		30.32..=30.33 => "30.33%",
-		30.33..=30.34 => "30.34%",
-		30.34..=30.35 => "30.35%",
-		30.35..=30.36 => "30.36%",
-		30.36..=30.37 => "30.37%",
-		30.37..=30.38 => "30.38%",
-		30.38..=30.39 => "30.39%",
-		30.39..=30.40 => "30.40%",
-		30.40..=30.41 => "30.41%",
-		30.41..=30.42 => "30.42%",
-		30.42..=30.43 => "30.43%",
-		30.43..=30.44 => "30.44%",
-		30.44..=30.45 => "30.45%",
-		30.45..=30.46 => "30.46%",
-		30.46..=30.47 => "30.47%",
-		30.47..=30.48 => "30.48%",
-		30.48..=30.49 => "30.49%",
-		30.49..=30.50 => "30.50%",
-		30.50..=30.51 => "30.51%",
-		30.51..=30.52 => "30.52%",
-		30.52..=30.53 => "30.53%",
-		30.53..=30.54 => "30.54%",
-		30.54..=30.55 => "30.55%",
-		30.55..=30.56 => "30.56%",
-		30.56..=30.57 => "30.57%",
-		30.57..=30.58 => "30.58%",
-		30.58..=30.59 => "30.59%",
-		30.59..=30.60 => "30.60%",
-		30.60..=30.61 => "30.61%",
-		30.61..=30.62 => "30.62%",
-		30.62..=30.63 => "30.63%",
-		30.63..=30.64 => "30.64%",
-		30.64..=30.65 => "30.65%",
-		30.65..=30.66 => "30.66%",
-		30.66..=30.67 => "30.67%",
-		30.67..=30.68 => "30.68%",
-		30.68..=30.69 => "30.69%",
-		30.69..=30.70 => "30.70%",
-		30.70..=30.71 => "30.71%",
-		30.71..=30.72 => "30.72%",
-		30.72..=30.73 => "30.73%",
-		30.73..=30.74 => "30.74%",
-		30.74..=30.75 => "30.75%",
-		30.75..=30.76 => "30.76%",
-		30.76..=30.77 => "30.77%",
-		30.77..=30.78 => "30.78%",
-		30.78..=30.79 => "30.79%",
-		30.79..=30.80 => "30.80%",
-		30.80..=30.81 => "30.81%",
-		30.81..=30.82 => "30.82%",
-		30.82..=30.83 => "30.83%",
-		30.83..=30.84 => "30.84%",
-		30.84..=30.85 => "30.85%",
-		30.85..=30.86 => "30.86%",
-		30.86..=30.87 => "30.87%",
-		30.87..=30.88 => "30.88%",
-		30.88..=30.89 => "30.89%",
-		30.89..=30.90 => "30.90%",
-		30.90..=30.91 => "30.91%",
-		30.91..=30.92 => "30.92%",
-		30.92..=30.93 => "30.93%",
-		30.93..=30.94 => "30.94%",
-		30.94..=30.95 => "30.95%",
-		30.95..=30.96 => "30.96%",
-		30.96..=30.97 => "30.97%",
-		30.97..=30.98 => "30.98%",
-		30.98..=30.99 => "30.99%",
-		30.99..=31.00 => "31.00%",
-		31.00..=31.01 => "31.01%",
-		31.01..=31.02 => "31.02%",
-		31.02..=31.03 => "31.03%",
-		31.03..=31.04 => "31.04%",
-		31.04..=31.05 => "31.05%",
-		31.05..=31.06 => "31.06%",
-		31.06..=31.07 => "31.07%",
-		31.07..=31.08 => "31.08%",
-		31.08..=31.09 => "31.09%",
-		31.09..=31.10 => "31.10%",
-		31.10..=31.11 => "31.11%",
-		31.11..=31.12 => "31.12%",
-		31.12..=31.13 => "31.13%",
-		31.13..=31.14 => "31.14%",
-		31.14..=31.15 => "31.15%",
-		31.15..=31.16 => "31.16%",
-		31.16..=31.17 => "31.17%",
-		31.17..=31.18 => "31.18%",
-		31.18..=31.19 => "31.19%",
-		31.19..=31.20 => "31.20%",
-		31.20..=31.21 => "31.21%",
-		31.21..=31.22 => "31.22%",
-		31.22..=31.23 => "31.23%",
-		31.23..=31.24 => "31.24%",
-		31.24..=31.25 => "31.25%",
-		31.25..=31.26 => "31.26%",
-		31.26..=31.27 => "31.27%",
-		31.27..=31.28 => "31.28%",
-		31.28..=31.29 => "31.29%",
-		31.29..=31.30 => "31.30%",
-		31.30..=31.31 => "31.31%",
-		31.31..=31.32 => "31.32%",
-		31.32..=31.33 => "31.33%",
-		31.33..=31.34 => "31.34%",
-		31.34..=31.35 => "31.35%",
-		31.35..=31.36 => "31.36%",
-		31.36..=31.37 => "31.37%",
-		31.37..=31.38 => "31.38%",
-		31.38..=31.39 => "31.39%",
-		31.39..=31.40 => "31.40%",
-		31.40..=31.41 => "31.41%",
-		31.41..=31.42 => "31.42%",
-		31.42..=31.43 => "31.43%",
-		31.43..=31.44 => "31.44%",
-		31.44..=31.45 => "31.45%",
-		31.45..=31.46 => "31.46%",
-		31.46..=31.47 => "31.47%",
-		31.47..=31.48 => "31.48%",
-		31.48..=31.49 => "31.49%",
-		31.49..=31.50 => "31.50%",
-		31.50..=31.51 => "31.51%",
-		31.51..=31.52 => "31.52%",
-		31.52..=31.53 => "31.53%",
-		31.53..=31.54 => "31.54%",
-		31.54..=31.55 => "31.55%",
-		31.55..=31.56 => "31.56%",
-		31.56..=31.57 => "31.57%",
-		31.57..=31.58 => "31.58%",
-		31.58..=31.59 => "31.59%",
-		31.59..=31.60 => "31.60%",
-		31.60..=31.61 => "31.61%",
-		31.61..=31.62 => "31.62%",
-		31.62..=31.63 => "31.63%",
-		31.63..=31.64 => "31.64%",
-		31.64..=31.65 => "31.65%",
-		31.65..=31.66 => "31.66%",
-		31.66..=31.67 => "31.67%",
-		31.67..=31.68 => "31.68%",
-		31.68..=31.69 => "31.69%",
-		31.69..=31.70 => "31.70%",
-		31.70..=31.71 => "31.71%",
-		31.71..=31.72 => "31.72%",
-		31.72..=31.73 => "31.73%",
-		31.73..=31.74 => "31.74%",
-		31.74..=31.75 => "31.75%",
-		31.75..=31.76 => "31.76%",
-		31.76..=31.77 => "31.77%",
-		31.77..=31.78 => "31.78%",
-		31.78..=31.79 => "31.79%",
-		31.79..=31.80 => "31.80%",
-		31.80..=31.81 => "31.81%",
-		31.81..=31.82 => "31.82%",
-		31.82..=31.83 => "31.83%",
-		31.83..=31.84 => "31.84%",
-		31.84..=31.85 => "31.85%",
-		31.85..=31.86 => "31.86%",
-		31.86..=31.87 => "31.87%",
-		31.87..=31.88 => "31.88%",
-		31.88..=31.89 => "31.89%",
-		31.89..=31.90 => "31.90%",
-		31.90..=31.91 => "31.91%",
-		31.91..=31.92 => "31.92%",
-		31.92..=31.93 => "31.93%",
-		31.93..=31.94 => "31.94%",
-		31.94..=31.95 => "31.95%",
-		31.95..=31.96 => "31.96%",
-		31.96..=31.97 => "31.97%",
-		31.97..=31.98 => "31.98%",
-		31.98..=31.99 => "31.99%",
-		31.99..=32.00 => "32.00%",
-		32.00..=32.01 => "32.01%",
-		32.01..=32.02 => "32.02%",
-		32.02..=32.03 => "32.03%",
-		32.03..=32.04 => "32.04%",
-		32.04..=32.05 => "32.05%",
-		32.05..=32.06 => "32.06%",
-		32.06..=32.07 => "32.07%",
-		32.07..=32.08 => "32.08%",
-		32.08..=32.09 => "32.09%",
-		32.09..=32.10 => "32.10%",
-		32.10..=32.11 => "32.11%",
-		32.11..=32.12 => "32.12%",
-		32.12..=32.13 => "32.13%",
-		32.13..=32.14 => "32.14%",
-		32.14..=32.15 => "32.15%",
-		32.15..=32.16 => "32.16%",
-		32.16..=32.17 => "32.17%",
-		32.17..=32.18 => "32.18%",
-		32.18..=32.19 => "32.19%",
-		32.19..=32.20 => "32.20%",
-		32.20..=32.21 => "32.21%",
-		32.21..=32.22 => "32.22%",
-		32.22..=32.23 => "32.23%",
-		32.23..=32.24 => "32.24%",
-		32.24..=32.25 => "32.25%",
-		32.25..=32.26 => "32.26%",
-		32.26..=32.27 => "32.27%",
-		32.27..=32.28 => "32.28%",
-		32.28..=32.29 => "32.29%",
-		32.29..=32.30 => "32.30%",
-		32.30..=32.31 => "32.31%",
-		32.31..=32.32 => "32.32%",
-		32.32..=32.33 => "32.33%",
-		32.33..=32.34 => "32.34%",
-		32.34..=32.35 => "32.35%",
-		32.35..=32.36 => "32.36%",
-		32.36..=32.37 => "32.37%",
-		32.37..=32.38 => "32.38%",
-		32.38..=32.39 => "32.39%",
-		32.39..=32.40 => "32.40%",
-		32.40..=32.41 => "32.41%",
-		32.41..=32.42 => "32.42%",
-		32.42..=32.43 => "32.43%",
-		32.43..=32.44 => "32.44%",
-		32.44..=32.45 => "32.45%",
-		32.45..=32.46 => "32.46%",
-		32.46..=32.47 => "32.47%",
-		32.47..=32.48 => "32.48%",
-		32.48..=32.49 => "32.49%",
-		32.49..=32.50 => "32.50%",
-		32.50..=32.51 => "32.51%",
-		32.51..=32.52 => "32.52%",
-		32.52..=32.53 => "32.53%",
-		32.53..=32.54 => "32.54%",
-		32.54..=32.55 => "32.55%",
-		32.55..=32.56 => "32.56%",
-		32.56..=32.57 => "32.57%",
-		32.57..=32.58 => "32.58%",
-		32.58..=32.59 => "32.59%",
-		32.59..=32.60 => "32.60%",
-		32.60..=32.61 => "32.61%",
-		32.61..=32.62 => "32.62%",
-		32.62..=32.63 => "32.63%",
-		32.63..=32.64 => "32.64%",
-		32.64..=32.65 => "32.65%",
-		32.65..=32.66 => "32.66%",
-		32.66..=32.67 => "32.67%",
-		32.67..=32.68 => "32.68%",
-		32.68..=32.69 => "32.69%",
-		32.69..=32.70 => "32.70%",
-		32.70..=32.71 => "32.71%",
-		32.71..=32.72 => "32.72%",
-		32.72..=32.73 => "32.73%",
-		32.73..=32.74 => "32.74%",
-		32.74..=32.75 => "32.75%",
-		32.75..=32.76 => "32.76%",
-		32.76..=32.77 => "32.77%",
-		32.77..=32.78 => "32.78%",
-		32.78..=32.79 => "32.79%",
-		32.79..=32.80 => "32.80%",
-		32.80..=32.81 => "32.81%",
-		32.81..=32.82 => "32.82%",
-		32.82..=32.83 => "32.83%",
-		32.83..=32.84 => "32.84%",
-		32.84..=32.85 => "32.85%",
-		32.85..=32.86 => "32.86%",
-		32.86..=32.87 => "32.87%",
-		32.87..=32.88 => "32.88%",
-		32.88..=32.89 => "32.89%",
-		32.89..=32.90 => "32.90%",
-		32.90..=32.91 => "32.91%",
-		32.91..=32.92 => "32.92%",
-		32.92..=32.93 => "32.93%",
-		32.93..=32.94 => "32.94%",
-		32.94..=32.95 => "32.95%",
-		32.95..=32.96 => "32.96%",
-		32.96..=32.97 => "32.97%",
-		32.97..=32.98 => "32.98%",
-		32.98..=32.99 => "32.99%",
-		32.99..=33.00 => "33.00%",
-		33.00..=33.01 => "33.01%",
-		33.01..=33.02 => "33.02%",
-		33.02..=33.03 => "33.03%",
-		33.03..=33.04 => "33.04%",
-		33.04..=33.05 => "33.05%",
-		33.05..=33.06 => "33.06%",
-		33.06..=33.07 => "33.07%",
-		33.07..=33.08 => "33.08%",
-		33.08..=33.09 => "33.09%",
-		33.09..=33.10 => "33.10%",
-		33.10..=33.11 => "33.11%",
-		33.11..=33.12 => "33.12%",
-		33.12..=33.13 => "33.13%",
-		33.13..=33.14 => "33.14%",
-		33.14..=33.15 => "33.15%",
-		33.15..=33.16 => "33.16%",
-		33.16..=33.17 => "33.17%",
-		33.17..=33.18 => "33.18%",
-		33.18..=33.19 => "33.19%",
-		33.19..=33.20 => "33.20%",
-		33.20..=33.21 => "33.21%",
-		33.21..=33.22 => "33.22%",
-		33.22..=33.23 => "33.23%",
-		33.23..=33.24 => "33.24%",
-		33.24..=33.25 => "33.25%",
-		33.25..=33.26 => "33.26%",
-		33.26..=33.27 => "33.27%",
-		33.27..=33.28 => "33.28%",
-		33.28..=33.29 => "33.29%",
-		33.29..=33.30 => "33.30%",
-		33.30..=33.31 => "33.31%",
-		33.31..=33.32 => "33.32%",
-		33.32..=33.33 => "33.33%",
-		33.33..=33.34 => "33.34%",
-		33.34..=33.35 => "33.35%",
-		33.35..=33.36 => "33.36%",
-		33.36..=33.37 => "33.37%",
-		33.37..=33.38 => "33.38%",
-		33.38..=33.39 => "33.39%",
-		33.39..=33.40 => "33.40%",
-		33.40..=33.41 => "33.41%",
-		33.41..=33.42 => "33.42%",
-		33.42..=33.43 => "33.43%",
-		33.43..=33.44 => "33.44%",
-		33.44..=33.45 => "33.45%",
-		33.45..=33.46 => "33.46%",
-		33.46..=33.47 => "33.47%",
-		33.47..=33.48 => "33.48%",
-		33.48..=33.49 => "33.49%",
-		33.49..=33.50 => "33.50%",
-		33.50..=33.51 => "33.51%",
-		33.51..=33.52 => "33.52%",
-		33.52..=33.53 => "33.53%",
-		33.53..=33.54 => "33.54%",
-		33.54..=33.55 => "33.55%",
-		33.55..=33.56 => "33.56%",
-		33.56..=33.57 => "33.57%",
-		33.57..=33.58 => "33.58%",
-		33.58..=33.59 => "33.59%",
-		33.59..=33.60 => "33.60%",
-		33.60..=33.61 => "33.61%",
-		33.61..=33.62 => "33.62%",
-		33.62..=33.63 => "33.63%",
-		33.63..=33.64 => "33.64%",
-		33.64..=33.65 => "33.65%",
-		33.65..=33.66 => "33.66%",
-		33.66..=33.67 => "33.67%",
-		33.67..=33.68 => "33.68%",
-		33.68..=33.69 => "33.69%",
-		33.69..=33.70 => "33.70%",
-		33.70..=33.71 => "33.71%",
-		33.71..=33.72 => "33.72%",
-		33.72..=33.73 => "33.73%",
-		33.73..=33.74 => "33.74%",
-		33.74..=33.75 => "33.75%",
-		33.75..=33.76 => "33.76%",
-		33.76..=33.77 => "33.77%",
-		33.77..=33.78 => "33.78%",
-		33.78..=33.79 => "33.79%",
-		33.79..=33.80 => "33.80%",
-		33.80..=33.81 => "33.81%",
-		33.81..=33.82 => "33.82%",
-		33.82..=33.83 => "33.83%",
-		33.83..=33.84 => "33.84%",
-		33.84..=33.85 => "33.85%",
-		33.85..=33.86 => "33.86%",
-		33.86..=33.87 => "33.87%",
-		33.87..=33.88 => "33.88%",
-		33.88..=33.89 => "33.89%",
-		33.89..=33.90 => "33.90%",
-		33.90..=33.91 => "33.91%",
-		33.91..=33.92 => "33.92%",
-		33.92..=33.93 => "33.93%",
-		33.93..=33.94 => "33.94%",
-		33.94..=33.95 => "33.95%",
-		33.95..=33.96 => "33.96%",
-		33.96..=33.97 => "33.97%",
-		33.97..=33.98 => "33.98%",
-		33.98..=33.99 => "33.99%",
-		33.99..=34.00 => "34.00%",
-		34.00..=34.01 => "34.01%",
-		34.01..=34.02 => "34.02%",
-		34.02..=34.03 => "34.03%",
-		34.03..=34.04 => "34.04%",
-		34.04..=34.05 => "34.05%",
-		34.05..=34.06 => "34.06%",
-		34.06..=34.07 => "34.07%",
-		34.07..=34.08 => "34.08%",
-		34.08..=34.09 => "34.09%",
-		34.09..=34.10 => "34.10%",
-		34.10..=34.11 => "34.11%",
-		34.11..=34.12 => "34.12%",
-		34.12..=34.13 => "34.13%",
-		34.13..=34.14 => "34.14%",
-		34.14..=34.15 => "34.15%",
-		34.15..=34.16 => "34.16%",
-		34.16..=34.17 => "34.17%",
-		34.17..=34.18 => "34.18%",
-		34.18..=34.19 => "34.19%",
-		34.19..=34.20 => "34.20%",
-		34.20..=34.21 => "34.21%",
-		34.21..=34.22 => "34.22%",
-		34.22..=34.23 => "34.23%",
-		34.23..=34.24 => "34.24%",
-		34.24..=34.25 => "34.25%",
-		34.25..=34.26 => "34.26%",
-		34.26..=34.27 => "34.27%",
-		34.27..=34.28 => "34.28%",
-		34.28..=34.29 => "34.29%",
-		34.29..=34.30 => "34.30%",
-		34.30..=34.31 => "34.31%",
-		34.31..=34.32 => "34.32%",
-		34.32..=34.33 => "34.33%",
-		34.33..=34.34 => "34.34%",
-		34.34..=34.35 => "34.35%",
-		34.35..=34.36 => "34.36%",
-		34.36..=34.37 => "34.37%",
-		34.37..=34.38 => "34.38%",
-		34.38..=34.39 => "34.39%",
-		34.39..=34.40 => "34.40%",
-		34.40..=34.41 => "34.41%",
-		34.41..=34.42 => "34.42%",
-		34.42..=34.43 => "34.43%",
-		34.43..=34.44 => "34.44%",
-		34.44..=34.45 => "34.45%",
-		34.45..=34.46 => "34.46%",
-		34.46..=34.47 => "34.47%",
-		34.47..=34.48 => "34.48%",
-		34.48..=34.49 => "34.49%",
-		34.49..=34.50 => "34.50%",
-		34.50..=34.51 => "34.51%",
-		34.51..=34.52 => "34.52%",
-		34.52..=34.53 => "34.53%",
-		34.53..=34.54 => "34.54%",
-		34.54..=34.55 => "34.55%",
-		34.55..=34.56 => "34.56%",
-		34.56..=34.57 => "34.57%",
-		34.57..=34.58 => "34.58%",
-		34.58..=34.59 => "34.59%",
-		34.59..=34.60 => "34.60%",
-		34.60..=34.61 => "34.61%",
-		34.61..=34.62 => "34.62%",
-		34.62..=34.63 => "34.63%",
-		34.63..=34.64 => "34.64%",
-		34.64..=34.65 => "34.65%",
-		34.65..=34.66 => "34.66%",
-		34.66..=34.67 => "34.67%",
-		34.67..=34.68 => "34.68%",
-		34.68..=34.69 => "34.69%",
-		34.69..=34.70 => "34.70%",
-		34.70..=34.71 => "34.71%",
-		34.71..=34.72 => "34.72%",
-		34.72..=34.73 => "34.73%",
-		34.73..=34.74 => "34.74%",
-		34.74..=34.75 => "34.75%",
-		34.75..=34.76 => "34.76%",
-		34.76..=34.77 => "34.77%",
-		34.77..=34.78 => "34.78%",
-		34.78..=34.79 => "34.79%",
-		34.79..=34.80 => "34.80%",
-		34.80..=34.81 => "34.81%",
-		34.81..=34.82 => "34.82%",
-		34.82..=34.83 => "34.83%",
-		34.83..=34.84 => "34.84%",
-		34.84..=34.85 => "34.85%",
-		34.85..=34.86 => "34.86%",
-		34.86..=34.87 => "34.87%",
-		34.87..=34.88 => "34.88%",
-		34.88..=34.89 => "34.89%",
-		34.89..=34.90 => "34.90%",
-		34.90..=34.91 => "34.91%",
-		34.91..=34.92 => "34.92%",
-		34.92..=34.93 => "34.93%",
-		34.93..=34.94 => "34.94%",
-		34.94..=34.95 => "34.95%",
-		34.95..=34.96 => "34.96%",
-		34.96..=34.97 => "34.97%",
-		34.97..=34.98 => "34.98%",
-		34.98..=34.99 => "34.99%",
-		34.99..=35.00 => "35.00%",
-		35.00..=35.01 => "35.01%",
-		35.01..=35.02 => "35.02%",
-		35.02..=35.03 => "35.03%",
-		35.03..=35.04 => "35.04%",
-		35.04..=35.05 => "35.05%",
-		35.05..=35.06 => "35.06%",
-		35.06..=35.07 => "35.07%",
-		35.07..=35.08 => "35.08%",
-		35.08..=35.09 => "35.09%",
-		35.09..=35.10 => "35.10%",
-		35.10..=35.11 => "35.11%",
-		35.11..=35.12 => "35.12%",
-		35.12..=35.13 => "35.13%",
-		35.13..=35.14 => "35.14%",
-		35.14..=35.15 => "35.15%",
-		35.15..=35.16 => "35.16%",
-		35.16..=35.17 => "35.17%",
-		35.17..=35.18 => "35.18%",
-		35.18..=35.19 => "35.19%",
-		35.19..=35.20 => "35.20%",
-		35.20..=35.21 => "35.21%",
-		35.21..=35.22 => "35.22%",
-		35.22..=35.23 => "35.23%",
-		35.23..=35.24 => "35.24%",
-		35.24..=35.25 => "35.25%",
-		35.25..=35.26 => "35.26%",
-		35.26..=35.27 => "35.27%",
-		35.27..=35.28 => "35.28%",
-		35.28..=35.29 => "35.29%",
-		35.29..=35.30 => "35.30%",
-		35.30..=35.31 => "35.31%",
-		35.31..=35.32 => "35.32%",
-		35.32..=35.33 => "35.33%",
-		35.33..=35.34 => "35.34%",
-		35.34..=35.35 => "35.35%",
-		35.35..=35.36 => "35.36%",
-		35.36..=35.37 => "35.37%",
-		35.37..=35.38 => "35.38%",
-		35.38..=35.39 => "35.39%",
-		35.39..=35.40 => "35.40%",
-		35.40..=35.41 => "35.41%",
-		35.41..=35.42 => "35.42%",
-		35.42..=35.43 => "35.43%",
-		35.43..=35.44 => "35.44%",
-		35.44..=35.45 => "35.45%",
-		35.45..=35.46 => "35.46%",
-		35.46..=35.47 => "35.47%",
-		35.47..=35.48 => "35.48%",
-		35.48..=35.49 => "35.49%",
-		35.49..=35.50 => "35.50%",
-		35.50..=35.51 => "35.51%",
-		35.51..=35.52 => "35.52%",
-		35.52..=35.53 => "35.53%",
-		35.53..=35.54 => "35.54%",
-		35.54..=35.55 => "35.55%",
-		35.55..=35.56 => "35.56%",
-		35.56..=35.57 => "35.57%",
-		35.57..=35.58 => "35.58%",
-		35.58..=35.59 => "35.59%",
-		35.59..=35.60 => "35.60%",
-		35.60..=35.61 => "35.61%",
-		35.61..=35.62 => "35.62%",
-		35.62..=35.63 => "35.63%",
-		35.63..=35.64 => "35.64%",
-		35.64..=35.65 => "35.65%",
-		35.65..=35.66 => "35.66%",
-		35.66..=35.67 => "35.67%",
-		35.67..=35.68 => "35.68%",
-		35.68..=35.69 => "35.69%",
-		35.69..=35.70 => "35.70%",
-		35.70..=35.71 => "35.71%",
-		35.71..=35.72 => "35.72%",
-		35.72..=35.73 => "35.73%",
-		35.73..=35.74 => "35.74%",
-		35.74..=35.75 => "35.75%",
-		35.75..=35.76 => "35.76%",
-		35.76..=35.77 => "35.77%",
-		35.77..=35.78 => "35.78%",
-		35.78..=35.79 => "35.79%",
-		35.79..=35.80 => "35.80%",
-		35.80..=35.81 => "35.81%",
-		35.81..=35.82 => "35.82%",
-		35.82..=35.83 => "35.83%",
-		35.83..=35.84 => "35.84%",
-		35.84..=35.85 => "35.85%",
-		35.85..=35.86 => "35.86%",
-		35.86..=35.87 => "35.87%",
-		35.87..=35.88 => "35.88%",
-		35.88..=35.89 => "35.89%",
-		35.89..=35.90 => "35.90%",
-		35.90..=35.91 => "35.91%",
-		35.91..=35.92 => "35.92%",
-		35.92..=35.93 => "35.93%",
-		35.93..=35.94 => "35.94%",
-		35.94..=35.95 => "35.95%",
-		35.95..=35.96 => "35.96%",
-		35.96..=35.97 => "35.97%",
-		35.97..=35.98 => "35.98%",
-		35.98..=35.99 => "35.99%",
-		35.99..=36.00 => "36.00%",
-		36.00..=36.01 => "36.01%",
-		36.01..=36.02 => "36.02%",
-		36.02..=36.03 => "36.03%",
-		36.03..=36.04 => "36.04%",
-		36.04..=36.05 => "36.05%",
-		36.05..=36.06 => "36.06%",
-		36.06..=36.07 => "36.07%",
-		36.07..=36.08 => "36.08%",
-		36.08..=36.09 => "36.09%",
-		36.09..=36.10 => "36.10%",
-		36.10..=36.11 => "36.11%",
-		36.11..=36.12 => "36.12%",
-		36.12..=36.13 => "36.13%",
-		36.13..=36.14 => "36.14%",
-		36.14..=36.15 => "36.15%",
-		36.15..=36.16 => "36.16%",
-		36.16..=36.17 => "36.17%",
-		36.17..=36.18 => "36.18%",
-		36.18..=36.19 => "36.19%",
-		36.19..=36.20 => "36.20%",
-		36.20..=36.21 => "36.21%",
-		36.21..=36.22 => "36.22%",
-		36.22..=36.23 => "36.23%",
-		36.23..=36.24 => "36.24%",
-		36.24..=36.25 => "36.25%",
-		36.25..=36.26 => "36.26%",
-		36.26..=36.27 => "36.27%",
-		36.27..=36.28 => "36.28%",
-		36.28..=36.29 => "36.29%",
-		36.29..=36.30 => "36.30%",
-		36.30..=36.31 => "36.31%",
-		36.31..=36.32 => "36.32%",
-		36.32..=36.33 => "36.33%",
-		36.33..=36.34 => "36.34%",
-		36.34..=36.35 => "36.35%",
-		36.35..=36.36 => "36.36%",
-		36.36..=36.37 => "36.37%",
-		36.37..=36.38 => "36.38%",
-		36.38..=36.39 => "36.39%",
-		36.39..=36.40 => "36.40%",
-		36.40..=36.41 => "36.41%",
-		36.41..=36.42 => "36.42%",
-		36.42..=36.43 => "36.43%",
-		36.43..=36.44 => "36.44%",
-		36.44..=36.45 => "36.45%",
-		36.45..=36.46 => "36.46%",
-		36.46..=36.47 => "36.47%",
-		36.47..=36.48 => "36.48%",
-		36.48..=36.49 => "36.49%",
-		36.49..=36.50 => "36.50%",
-		36.50..=36.51 => "36.51%",
-		36.51..=36.52 => "36.52%",
-		36.52..=36.53 => "36.53%",
-		36.53..=36.54 => "36.54%",
-		36.54..=36.55 => "36.55%",
-		36.55..=36.56 => "36.56%",
-		36.56..=36.57 => "36.57%",
-		36.57..=36.58 => "36.58%",
-		36.58..=36.59 => "36.59%",
-		36.59..=36.60 => "36.60%",
-		36.60..=36.61 => "36.61%",
-		36.61..=36.62 => "36.62%",
-		36.62..=36.63 => "36.63%",
-		36.63..=36.64 => "36.64%",
-		36.64..=36.65 => "36.65%",
-		36.65..=36.66 => "36.66%",
-		36.66..=36.67 => "36.67%",
-		36.67..=36.68 => "36.68%",
-		36.68..=36.69 => "36.69%",
-		36.69..=36.70 => "36.70%",
-		36.70..=36.71 => "36.71%",
-		36.71..=36.72 => "36.72%",
-		36.72..=36.73 => "36.73%",
-		36.73..=36.74 => "36.74%",
-		36.74..=36.75 => "36.75%",
-		36.75..=36.76 => "36.76%",
-		36.76..=36.77 => "36.77%",
-		36.77..=36.78 => "36.78%",
-		36.78..=36.79 => "36.79%",
-		36.79..=36.80 => "36.80%",
-		36.80..=36.81 => "36.81%",
-		36.81..=36.82 => "36.82%",
-		36.82..=36.83 => "36.83%",
-		36.83..=36.84 => "36.84%",
-		36.84..=36.85 => "36.85%",
-		36.85..=36.86 => "36.86%",
-		36.86..=36.87 => "36.87%",
-		36.87..=36.88 => "36.88%",
-		36.88..=36.89 => "36.89%",
-		36.89..=36.90 => "36.90%",
-		36.90..=36.91 => "36.91%",
-		36.91..=36.92 => "36.92%",
-		36.92..=36.93 => "36.93%",
-		36.93..=36.94 => "36.94%",
-		36.94..=36.95 => "36.95%",
-		36.95..=36.96 => "36.96%",
-		36.96..=36.97 => "36.97%",
-		36.97..=36.98 => "36.98%",
-		36.98..=36.99 => "36.99%",
-		36.99..=37.00 => "37.00%",
-		37.00..=37.01 => "37.01%",
-		37.01..=37.02 => "37.02%",
-		37.02..=37.03 => "37.03%",
-		37.03..=37.04 => "37.04%",
-		37.04..=37.05 => "37.05%",
-		37.05..=37.06 => "37.06%",
-		37.06..=37.07 => "37.07%",
-		37.07..=37.08 => "37.08%",
-		37.08..=37.09 => "37.09%",
-		37.09..=37.10 => "37.10%",
-		37.10..=37.11 => "37.11%",
-		37.11..=37.12 => "37.12%",
-		37.12..=37.13 => "37.13%",
-		37.13..=37.14 => "37.14%",
-		37.14..=37.15 => "37.15%",
-		37.15..=37.16 => "37.16%",
-		37.16..=37.17 => "37.17%",
-		37.17..=37.18 => "37.18%",
-		37.18..=37.19 => "37.19%",
-		37.19..=37.20 => "37.20%",
-		37.20..=37.21 => "37.21%",
-		37.21..=37.22 => "37.22%",
-		37.22..=37.23 => "37.23%",
-		37.23..=37.24 => "37.24%",
-		37.24..=37.25 => "37.25%",
-		37.25..=37.26 => "37.26%",
-		37.26..=37.27 => "37.27%",
-		37.27..=37.28 => "37.28%",
-		37.28..=37.29 => "37.29%",
-		37.29..=37.30 => "37.30%",
-		37.30..=37.31 => "37.31%",
-		37.31..=37.32 => "37.32%",
-		37.32..=37.33 => "37.33%",
-		37.33..=37.34 => "37.34%",
-		37.34..=37.35 => "37.35%",
-		37.35..=37.36 => "37.36%",
-		37.36..=37.37 => "37.37%",
-		37.37..=37.38 => "37.38%",
-		37.38..=37.39 => "37.39%",
-		37.39..=37.40 => "37.40%",
-		37.40..=37.41 => "37.41%",
-		37.41..=37.42 => "37.42%",
-		37.42..=37.43 => "37.43%",
-		37.43..=37.44 => "37.44%",
-		37.44..=37.45 => "37.45%",
-		37.45..=37.46 => "37.46%",
-		37.46..=37.47 => "37.47%",
-		37.47..=37.48 => "37.48%",
-		37.48..=37.49 => "37.49%",
-		37.49..=37.50 => "37.50%",
-		37.50..=37.51 => "37.51%",
-		37.51..=37.52 => "37.52%",
-		37.52..=37.53 => "37.53%",
-		37.53..=37.54 => "37.54%",
-		37.54..=37.55 => "37.55%",
-		37.55..=37.56 => "37.56%",
-		37.56..=37.57 => "37.57%",
-		37.57..=37.58 => "37.58%",
-		37.58..=37.59 => "37.59%",
-		37.59..=37.60 => "37.60%",
-		37.60..=37.61 => "37.61%",
-		37.61..=37.62 => "37.62%",
-		37.62..=37.63 => "37.63%",
-		37.63..=37.64 => "37.64%",
-		37.64..=37.65 => "37.65%",
-		37.65..=37.66 => "37.66%",
-		37.66..=37.67 => "37.67%",
-		37.67..=37.68 => "37.68%",
-		37.68..=37.69 => "37.69%",
-		37.69..=37.70 => "37.70%",
-		37.70..=37.71 => "37.71%",
-		37.71..=37.72 => "37.72%",
-		37.72..=37.73 => "37.73%",
-		37.73..=37.74 => "37.74%",
-		37.74..=37.75 => "37.75%",
-		37.75..=37.76 => "37.76%",
-		37.76..=37.77 => "37.77%",
-		37.77..=37.78 => "37.78%",
-		37.78..=37.79 => "37.79%",
-		37.79..=37.80 => "37.80%",
-		37.80..=37.81 => "37.81%",
-		37.81..=37.82 => "37.82%",
-		37.82..=37.83 => "37.83%",
-		37.83..=37.84 => "37.84%",
-		37.84..=37.85 => "37.85%",
-		37.85..=37.86 => "37.86%",
-		37.86..=37.87 => "37.87%",
-		37.87..=37.88 => "37.88%",
-		37.88..=37.89 => "37.89%",
-		37.89..=37.90 => "37.90%",
-		37.90..=37.91 => "37.91%",
-		37.91..=37.92 => "37.92%",
-		37.92..=37.93 => "37.93%",
-		37.93..=37.94 => "37.94%",
-		37.94..=37.95 => "37.95%",
-		37.95..=37.96 => "37.96%",
-		37.96..=37.97 => "37.97%",
-		37.97..=37.98 => "37.98%",
-		37.98..=37.99 => "37.99%",
-		37.99..=38.00 => "38.00%",
-		38.00..=38.01 => "38.01%",
-		38.01..=38.02 => "38.02%",
-		38.02..=38.03 => "38.03%",
-		38.03..=38.04 => "38.04%",
-		38.04..=38.05 => "38.05%",
-		38.05..=38.06 => "38.06%",
-		38.06..=38.07 => "38.07%",
-		38.07..=38.08 => "38.08%",
-		38.08..=38.09 => "38.09%",
-		38.09..=38.10 => "38.10%",
-		38.10..=38.11 => "38.11%",
-		38.11..=38.12 => "38.12%",
-		38.12..=38.13 => "38.13%",
-		38.13..=38.14 => "38.14%",
-		38.14..=38.15 => "38.15%",
-		38.15..=38.16 => "38.16%",
-		38.16..=38.17 => "38.17%",
-		38.17..=38.18 => "38.18%",
-		38.18..=38.19 => "38.19%",
-		38.19..=38.20 => "38.20%",
-		38.20..=38.21 => "38.21%",
-		38.21..=38.22 => "38.22%",
-		38.22..=38.23 => "38.23%",
-		38.23..=38.24 => "38.24%",
-		38.24..=38.25 => "38.25%",
-		38.25..=38.26 => "38.26%",
-		38.26..=38.27 => "38.27%",
-		38.27..=38.28 => "38.28%",
-		38.28..=38.29 => "38.29%",
-		38.29..=38.30 => "38.30%",
-		38.30..=38.31 => "38.31%",
-		38.31..=38.32 => "38.32%",
-		38.32..=38.33 => "38.33%",
-		38.33..=38.34 => "38.34%",
-		38.34..=38.35 => "38.35%",
-		38.35..=38.36 => "38.36%",
-		38.36..=38.37 => "38.37%",
-		38.37..=38.38 => "38.38%",
-		38.38..=38.39 => "38.39%",
-		38.39..=38.40 => "38.40%",
-		38.40..=38.41 => "38.41%",
-		38.41..=38.42 => "38.42%",
-		38.42..=38.43 => "38.43%",
-		38.43..=38.44 => "38.44%",
-		38.44..=38.45 => "38.45%",
-		38.45..=38.46 => "38.46%",
-		38.46..=38.47 => "38.47%",
-		38.47..=38.48 => "38.48%",
-		38.48..=38.49 => "38.49%",
-		38.49..=38.50 => "38.50%",
-		38.50..=38.51 => "38.51%",
-		38.51..=38.52 => "38.52%",
-		38.52..=38.53 => "38.53%",
-		38.53..=38.54 => "38.54%",
-		38.54..=38.55 => "38.55%",
-		38.55..=38.56 => "38.56%",
-		38.56..=38.57 => "38.57%",
-		38.57..=38.58 => "38.58%",
-		38.58..=38.59 => "38.59%",
-		38.59..=38.60 => "38.60%",
-		38.60..=38.61 => "38.61%",
-		38.61..=38.62 => "38.62%",
-		38.62..=38.63 => "38.63%",
-		38.63..=38.64 => "38.64%",
-		38.64..=38.65 => "38.65%",
-		38.65..=38.66 => "38.66%",
-		38.66..=38.67 => "38.67%",
-		38.67..=38.68 => "38.68%",
-		38.68..=38.69 => "38.69%",
-		38.69..=38.70 => "38.70%",
-		38.70..=38.71 => "38.71%",
-		38.71..=38.72 => "38.72%",
-		38.72..=38.73 => "38.73%",
-		38.73..=38.74 => "38.74%",
-		38.74..=38.75 => "38.75%",
-		38.75..=38.76 => "38.76%",
-		38.76..=38.77 => "38.77%",
-		38.77..=38.78 => "38.78%",
-		38.78..=38.79 => "38.79%",
-		38.79..=38.80 => "38.80%",
-		38.80..=38.81 => "38.81%",
-		38.81..=38.82 => "38.82%",
-		38.82..=38.83 => "38.83%",
-		38.83..=38.84 => "38.84%",
-		38.84..=38.85 => "38.85%",
-		38.85..=38.86 => "38.86%",
-		38.86..=38.87 => "38.87%",
-		38.87..=38.88 => "38.88%",
-		38.88..=38.89 => "38.89%",
-		38.89..=38.90 => "38.90%",
-		38.90..=38.91 => "38.91%",
-		38.91..=38.92 => "38.92%",
-		38.92..=38.93 => "38.93%",
-		38.93..=38.94 => "38.94%",
-		38.94..=38.95 => "38.95%",
-		38.95..=38.96 => "38.96%",
-		38.96..=38.97 => "38.97%",
-		38.97..=38.98 => "38.98%",
-		38.98..=38.99 => "38.99%",
-		38.99..=39.00 => "39.00%",
-		39.00..=39.01 => "39.01%",
-		39.01..=39.02 => "39.02%",
-		39.02..=39.03 => "39.03%",
-		39.03..=39.04 => "39.04%",
-		39.04..=39.05 => "39.05%",
-		39.05..=39.06 => "39.06%",
-		39.06..=39.07 => "39.07%",
-		39.07..=39.08 => "39.08%",
-		39.08..=39.09 => "39.09%",
-		39.09..=39.10 => "39.10%",
-		39.10..=39.11 => "39.11%",
-		39.11..=39.12 => "39.12%",
-		39.12..=39.13 => "39.13%",
-		39.13..=39.14 => "39.14%",
-		39.14..=39.15 => "39.15%",
-		39.15..=39.16 => "39.16%",
-		39.16..=39.17 => "39.17%",
-		39.17..=39.18 => "39.18%",
-		39.18..=39.19 => "39.19%",
-		39.19..=39.20 => "39.20%",
-		39.20..=39.21 => "39.21%",
-		39.21..=39.22 => "39.22%",
-		39.22..=39.23 => "39.23%",
-		39.23..=39.24 => "39.24%",
-		39.24..=39.25 => "39.25%",
-		39.25..=39.26 => "39.26%",
-		39.26..=39.27 => "39.27%",
-		39.27..=39.28 => "39.28%",
-		39.28..=39.29 => "39.29%",
-		39.29..=39.30 => "39.30%",
-		39.30..=39.31 => "39.31%",
-		39.31..=39.32 => "39.32%",
-		39.32..=39.33 => "39.33%",
-		39.33..=39.34 => "39.34%",
-		39.34..=39.35 => "39.35%",
-		39.35..=39.36 => "39.36%",
-		39.36..=39.37 => "39.37%",
-		39.37..=39.38 => "39.38%",
-		39.38..=39.39 => "39.39%",
-		39.39..=39.40 => "39.40%",
-		39.40..=39.41 => "39.41%",
-		39.41..=39.42 => "39.42%",
-		39.42..=39.43 => "39.43%",
-		39.43..=39.44 => "39.44%",
-		39.44..=39.45 => "39.45%",
-		39.45..=39.46 => "39.46%",
-		39.46..=39.47 => "39.47%",
-		39.47..=39.48 => "39.48%",
-		39.48..=39.49 => "39.49%",
-		39.49..=39.50 => "39.50%",
-		39.50..=39.51 => "39.51%",
-		39.51..=39.52 => "39.52%",
-		39.52..=39.53 => "39.53%",
-		39.53..=39.54 => "39.54%",
-		39.54..=39.55 => "39.55%",
-		39.55..=39.56 => "39.56%",
-		39.56..=39.57 => "39.57%",
-		39.57..=39.58 => "39.58%",
-		39.58..=39.59 => "39.59%",
-		39.59..=39.60 => "39.60%",
-		39.60..=39.61 => "39.61%",
-		39.61..=39.62 => "39.62%",
-		39.62..=39.63 => "39.63%",
-		39.63..=39.64 => "39.64%",
-		39.64..=39.65 => "39.65%",
-		39.65..=39.66 => "39.66%",
-		39.66..=39.67 => "39.67%",
-		39.67..=39.68 => "39.68%",
-		39.68..=39.69 => "39.69%",
-		39.69..=39.70 => "39.70%",
-		39.70..=39.71 => "39.71%",
-		39.71..=39.72 => "39.72%",
-		39.72..=39.73 => "39.73%",
-		39.73..=39.74 => "39.74%",
-		39.74..=39.75 => "39.75%",
-		39.75..=39.76 => "39.76%",
-		39.76..=39.77 => "39.77%",
-		39.77..=39.78 => "39.78%",
-		39.78..=39.79 => "39.79%",
-		39.79..=39.80 => "39.80%",
-		39.80..=39.81 => "39.81%",
-		39.81..=39.82 => "39.82%",
-		39.82..=39.83 => "39.83%",
-		39.83..=39.84 => "39.84%",
-		39.84..=39.85 => "39.85%",
-		39.85..=39.86 => "39.86%",
-		39.86..=39.87 => "39.87%",
-		39.87..=39.88 => "39.88%",
-		39.88..=39.89 => "39.89%",
-		39.89..=39.90 => "39.90%",
-		39.90..=39.91 => "39.91%",
-		39.91..=39.92 => "39.92%",
-		39.92..=39.93 => "39.93%",
-		39.93..=39.94 => "39.94%",
-		39.94..=39.95 => "39.95%",
-		39.95..=39.96 => "39.96%",
-		39.96..=39.97 => "39.97%",
-		39.97..=39.98 => "39.98%",
-		39.98..=39.99 => "39.99%",
-		39.99..=40.00 => "40.00%",
-		40.00..=40.01 => "40.01%",
-		40.01..=40.02 => "40.02%",
-		40.02..=40.03 => "40.03%",
-		40.03..=40.04 => "40.04%",
-		40.04..=40.05 => "40.05%",
-		40.05..=40.06 => "40.06%",
-		40.06..=40.07 => "40.07%",
-		40.07..=40.08 => "40.08%",
-		40.08..=40.09 => "40.09%",
-		40.09..=40.10 => "40.10%",
-		40.10..=40.11 => "40.11%",
-		40.11..=40.12 => "40.12%",
-		40.12..=40.13 => "40.13%",
-		40.13..=40.14 => "40.14%",
-		40.14..=40.15 => "40.15%",
-		40.15..=40.16 => "40.16%",
-		40.16..=40.17 => "40.17%",
-		40.17..=40.18 => "40.18%",
-		40.18..=40.19 => "40.19%",
-		40.19..=40.20 => "40.20%",
-		40.20..=40.21 => "40.21%",
-		40.21..=40.22 => "40.22%",
-		40.22..=40.23 => "40.23%",
-		40.23..=40.24 => "40.24%",
-		40.24..=40.25 => "40.25%",
-		40.25..=40.26 => "40.26%",
-		40.26..=40.27 => "40.27%",
-		40.27..=40.28 => "40.28%",
-		40.28..=40.29 => "40.29%",
-		40.29..=40.30 => "40.30%",
-		40.30..=40.31 => "40.31%",
-		40.31..=40.32 => "40.32%",
-		40.32..=40.33 => "40.33%",
-		40.33..=40.34 => "40.34%",
-		40.34..=40.35 => "40.35%",
-		40.35..=40.36 => "40.36%",
-		40.36..=40.37 => "40.37%",
-		40.37..=40.38 => "40.38%",
-		40.38..=40.39 => "40.39%",
-		40.39..=40.40 => "40.40%",
-		40.40..=40.41 => "40.41%",
-		40.41..=40.42 => "40.42%",
-		40.42..=40.43 => "40.43%",
-		40.43..=40.44 => "40.44%",
-		40.44..=40.45 => "40.45%",
-		40.45..=40.46 => "40.46%",
-		40.46..=40.47 => "40.47%",
-		40.47..=40.48 => "40.48%",
-		40.48..=40.49 => "40.49%",
-		40.49..=40.50 => "40.50%",
-		40.50..=40.51 => "40.51%",
-		40.51..=40.52 => "40.52%",
-		40.52..=40.53 => "40.53%",
-		40.53..=40.54 => "40.54%",
-		40.54..=40.55 => "40.55%",
-		40.55..=40.56 => "40.56%",
-		40.56..=40.57 => "40.57%",
-		40.57..=40.58 => "40.58%",
-		40.58..=40.59 => "40.59%",
-		40.59..=40.60 => "40.60%",
-		40.60..=40.61 => "40.61%",
-		40.61..=40.62 => "40.62%",
-		40.62..=40.63 => "40.63%",
-		40.63..=40.64 => "40.64%",
-		40.64..=40.65 => "40.65%",
-		40.65..=40.66 => "40.66%",
-		40.66..=40.67 => "40.67%",
-		40.67..=40.68 => "40.68%",
-		40.68..=40.69 => "40.69%",
-		40.69..=40.70 => "40.70%",
-		40.70..=40.71 => "40.71%",
-		40.71..=40.72 => "40.72%",
-		40.72..=40.73 => "40.73%",
-		40.73..=40.74 => "40.74%",
-		40.74..=40.75 => "40.75%",
-		40.75..=40.76 => "40.76%",
-		40.76..=40.77 => "40.77%",
-		40.77..=40.78 => "40.78%",
-		40.78..=40.79 => "40.79%",
-		40.79..=40.80 => "40.80%",
-		40.80..=40.81 => "40.81%",
-		40.81..=40.82 => "40.82%",
-		40.82..=40.83 => "40.83%",
-		40.83..=40.84 => "40.84%",
-		40.84..=40.85 => "40.85%",
-		40.85..=40.86 => "40.86%",
-		40.86..=40.87 => "40.87%",
-		40.87..=40.88 => "40.88%",
-		40.88..=40.89 => "40.89%",
-		40.89..=40.90 => "40.90%",
-		40.90..=40.91 => "40.91%",
-		40.91..=40.92 => "40.92%",
-		40.92..=40.93 => "40.93%",
-		40.93..=40.94 => "40.94%",
-		40.94..=40.95 => "40.95%",
-		40.95..=40.96 => "40.96%",
-		40.96..=40.97 => "40.97%",
-		40.97..=40.98 => "40.98%",
-		40.98..=40.99 => "40.99%",
-		40.99..=41.00 => "41.00%",
-		41.00..=41.01 => "41.01%",
-		41.01..=41.02 => "41.02%",
-		41.02..=41.03 => "41.03%",
-		41.03..=41.04 => "41.04%",
-		41.04..=41.05 => "41.05%",
-		41.05..=41.06 => "41.06%",
-		41.06..=41.07 => "41.07%",
-		41.07..=41.08 => "41.08%",
-		41.08..=41.09 => "41.09%",
-		41.09..=41.10 => "41.10%",
-		41.10..=41.11 => "41.11%",
-		41.11..=41.12 => "41.12%",
-		41.12..=41.13 => "41.13%",
-		41.13..=41.14 => "41.14%",
-		41.14..=41.15 => "41.15%",
-		41.15..=41.16 => "41.16%",
-		41.16..=41.17 => "41.17%",
-		41.17..=41.18 => "41.18%",
-		41.18..=41.19 => "41.19%",
-		41.19..=41.20 => "41.20%",
-		41.20..=41.21 => "41.21%",
-		41.21..=41.22 => "41.22%",
-		41.22..=41.23 => "41.23%",
-		41.23..=41.24 => "41.24%",
-		41.24..=41.25 => "41.25%",
-		41.25..=41.26 => "41.26%",
-		41.26..=41.27 => "41.27%",
-		41.27..=41.28 => "41.28%",
-		41.28..=41.29 => "41.29%",
-		41.29..=41.30 => "41.30%",
-		41.30..=41.31 => "41.31%",
-		41.31..=41.32 => "41.32%",
-		41.32..=41.33 => "41.33%",
-		41.33..=41.34 => "41.34%",
-		41.34..=41.35 => "41.35%",
-		41.35..=41.36 => "41.36%",
-		41.36..=41.37 => "41.37%",
-		41.37..=41.38 => "41.38%",
-		41.38..=41.39 => "41.39%",
-		41.39..=41.40 => "41.40%",
-		41.40..=41.41 => "41.41%",
-		41.41..=41.42 => "41.42%",
-		41.42..=41.43 => "41.43%",
-		41.43..=41.44 => "41.44%",
-		41.44..=41.45 => "41.45%",
-		41.45..=41.46 => "41.46%",
-		41.46..=41.47 => "41.47%",
-		41.47..=41.48 => "41.48%",
-		41.48..=41.49 => "41.49%",
-		41.49..=41.50 => "41.50%",
-		41.50..=41.51 => "41.51%",
-		41.51..=41.52 => "41.52%",
-		41.52..=41.53 => "41.53%",
-		41.53..=41.54 => "41.54%",
-		41.54..=41.55 => "41.55%",
-		41.55..=41.56 => "41.56%",
-		41.56..=41.57 => "41.57%",
-		41.57..=41.58 => "41.58%",
-		41.58..=41.59 => "41.59%",
-		41.59..=41.60 => "41.60%",
-		41.60..=41.61 => "41.61%",
-		41.61..=41.62 => "41.62%",
-		41.62..=41.63 => "41.63%",
-		41.63..=41.64 => "41.64%",
-		41.64..=41.65 => "41.65%",
-		41.65..=41.66 => "41.66%",
-		41.66..=41.67 => "41.67%",
-		41.67..=41.68 => "41.68%",
-		41.68..=41.69 => "41.69%",
-		41.69..=41.70 => "41.70%",
-		41.70..=41.71 => "41.71%",
-		41.71..=41.72 => "41.72%",
-		41.72..=41.73 => "41.73%",
-		41.73..=41.74 => "41.74%",
-		41.74..=41.75 => "41.75%",
-		41.75..=41.76 => "41.76%",
-		41.76..=41.77 => "41.77%",
-		41.77..=41.78 => "41.78%",
-		41.78..=41.79 => "41.79%",
-		41.79..=41.80 => "41.80%",
-		41.80..=41.81 => "41.81%",
-		41.81..=41.82 => "41.82%",
-		41.82..=41.83 => "41.83%",
-		41.83..=41.84 => "41.84%",
-		41.84..=41.85 => "41.85%",
-		41.85..=41.86 => "41.86%",
-		41.86..=41.87 => "41.87%",
-		41.87..=41.88 => "41.88%",
-		41.88..=41.89 => "41.89%",
-		41.89..=41.90 => "41.90%",
-		41.90..=41.91 => "41.91%",
-		41.91..=41.92 => "41.92%",
-		41.92..=41.93 => "41.93%",
-		41.93..=41.94 => "41.94%",
-		41.94..=41.95 => "41.95%",
-		41.95..=41.96 => "41.96%",
-		41.96..=41.97 => "41.97%",
-		41.97..=41.98 => "41.98%",
-		41.98..=41.99 => "41.99%",
-		41.99..=42.00 => "42.00%",
-		42.00..=42.01 => "42.01%",
-		42.01..=42.02 => "42.02%",
-		42.02..=42.03 => "42.03%",
-		42.03..=42.04 => "42.04%",
-		42.04..=42.05 => "42.05%",
-		42.05..=42.06 => "42.06%",
-		42.06..=42.07 => "42.07%",
-		42.07..=42.08 => "42.08%",
-		42.08..=42.09 => "42.09%",
-		42.09..=42.10 => "42.10%",
-		42.10..=42.11 => "42.11%",
-		42.11..=42.12 => "42.12%",
-		42.12..=42.13 => "42.13%",
-		42.13..=42.14 => "42.14%",
-		42.14..=42.15 => "42.15%",
-		42.15..=42.16 => "42.16%",
-		42.16..=42.17 => "42.17%",
-		42.17..=42.18 => "42.18%",
-		42.18..=42.19 => "42.19%",
-		42.19..=42.20 => "42.20%",
-		42.20..=42.21 => "42.21%",
-		42.21..=42.22 => "42.22%",
-		42.22..=42.23 => "42.23%",
-		42.23..=42.24 => "42.24%",
-		42.24..=42.25 => "42.25%",
-		42.25..=42.26 => "42.26%",
-		42.26..=42.27 => "42.27%",
-		42.27..=42.28 => "42.28%",
-		42.28..=42.29 => "42.29%",
-		42.29..=42.30 => "42.30%",
-		42.30..=42.31 => "42.31%",
-		42.31..=42.32 => "42.32%",
-		42.32..=42.33 => "42.33%",
-		42.33..=42.34 => "42.34%",
-		42.34..=42.35 => "42.35%",
-		42.35..=42.36 => "42.36%",
-		42.36..=42.37 => "42.37%",
-		42.37..=42.38 => "42.38%",
-		42.38..=42.39 => "42.39%",
-		42.39..=42.40 => "42.40%",
-		42.40..=42.41 => "42.41%",
-		42.41..=42.42 => "42.42%",
-		42.42..=42.43 => "42.43%",
-		42.43..=42.44 => "42.44%",
-		42.44..=42.45 => "42.45%",
-		42.45..=42.46 => "42.46%",
-		42.46..=42.47 => "42.47%",
-		42.47..=42.48 => "42.48%",
-		42.48..=42.49 => "42.49%",
-		42.49..=42.50 => "42.50%",
-		42.50..=42.51 => "42.51%",
-		42.51..=42.52 => "42.52%",
-		42.52..=42.53 => "42.53%",
-		42.53..=42.54 => "42.54%",
-		42.54..=42.55 => "42.55%",
-		42.55..=42.56 => "42.56%",
-		42.56..=42.57 => "42.57%",
-		42.57..=42.58 => "42.58%",
-		42.58..=42.59 => "42.59%",
-		42.59..=42.60 => "42.60%",
-		42.60..=42.61 => "42.61%",
-		42.61..=42.62 => "42.62%",
-		42.62..=42.63 => "42.63%",
-		42.63..=42.64 => "42.64%",
-		42.64..=42.65 => "42.65%",
-		42.65..=42.66 => "42.66%",
-		42.66..=42.67 => "42.67%",
-		42.67..=42.68 => "42.68%",
-		42.68..=42.69 => "42.69%",
-		42.69..=42.70 => "42.70%",
-		42.70..=42.71 => "42.71%",
-		42.71..=42.72 => "42.72%",
-		42.72..=42.73 => "42.73%",
-		42.73..=42.74 => "42.74%",
-		42.74..=42.75 => "42.75%",
-		42.75..=42.76 => "42.76%",
-		42.76..=42.77 => "42.77%",
-		42.77..=42.78 => "42.78%",
-		42.78..=42.79 => "42.79%",
-		42.79..=42.80 => "42.80%",
-		42.80..=42.81 => "42.81%",
-		42.81..=42.82 => "42.82%",
-		42.82..=42.83 => "42.83%",
-		42.83..=42.84 => "42.84%",
-		42.84..=42.85 => "42.85%",
-		42.85..=42.86 => "42.86%",
-		42.86..=42.87 => "42.87%",
-		42.87..=42.88 => "42.88%",
-		42.88..=42.89 => "42.89%",
-		42.89..=42.90 => "42.90%",
-		42.90..=42.91 => "42.91%",
-		42.91..=42.92 => "42.92%",
-		42.92..=42.93 => "42.93%",
-		42.93..=42.94 => "42.94%",
-		42.94..=42.95 => "42.95%",
-		42.95..=42.96 => "42.96%",
-		42.96..=42.97 => "42.97%",
-		42.97..=42.98 => "42.98%",
-		42.98..=42.99 => "42.99%",
-		42.99..=43.00 => "43.00%",
-		43.00..=43.01 => "43.01%",
-		43.01..=43.02 => "43.02%",
-		43.02..=43.03 => "43.03%",
-		43.03..=43.04 => "43.04%",
-		43.04..=43.05 => "43.05%",
-		43.05..=43.06 => "43.06%",
-		43.06..=43.07 => "43.07%",
-		43.07..=43.08 => "43.08%",
-		43.08..=43.09 => "43.09%",
-		43.09..=43.10 => "43.10%",
-		43.10..=43.11 => "43.11%",
-		43.11..=43.12 => "43.12%",
-		43.12..=43.13 => "43.13%",
-		43.13..=43.14 => "43.14%",
-		43.14..=43.15 => "43.15%",
-		43.15..=43.16 => "43.16%",
-		43.16..=43.17 => "43.17%",
-		43.17..=43.18 => "43.18%",
-		43.18..=43.19 => "43.19%",
-		43.19..=43.20 => "43.20%",
-		43.20..=43.21 => "43.21%",
-		43.21..=43.22 => "43.22%",
-		43.22..=43.23 => "43.23%",
-		43.23..=43.24 => "43.24%",
-		43.24..=43.25 => "43.25%",
-		43.25..=43.26 => "43.26%",
-		43.26..=43.27 => "43.27%",
-		43.27..=43.28 => "43.28%",
-		43.28..=43.29 => "43.29%",
-		43.29..=43.30 => "43.30%",
-		43.30..=43.31 => "43.31%",
-		43.31..=43.32 => "43.32%",
-		43.32..=43.33 => "43.33%",
-		43.33..=43.34 => "43.34%",
-		43.34..=43.35 => "43.35%",
-		43.35..=43.36 => "43.36%",
-		43.36..=43.37 => "43.37%",
-		43.37..=43.38 => "43.38%",
-		43.38..=43.39 => "43.39%",
-		43.39..=43.40 => "43.40%",
-		43.40..=43.41 => "43.41%",
-		43.41..=43.42 => "43.42%",
-		43.42..=43.43 => "43.43%",
-		43.43..=43.44 => "43.44%",
-		43.44..=43.45 => "43.45%",
-		43.45..=43.46 => "43.46%",
-		43.46..=43.47 => "43.47%",
-		43.47..=43.48 => "43.48%",
-		43.48..=43.49 => "43.49%",
-		43.49..=43.50 => "43.50%",
-		43.50..=43.51 => "43.51%",
-		43.51..=43.52 => "43.52%",
-		43.52..=43.53 => "43.53%",
-		43.53..=43.54 => "43.54%",
-		43.54..=43.55 => "43.55%",
-		43.55..=43.56 => "43.56%",
-		43.56..=43.57 => "43.57%",
-		43.57..=43.58 => "43.58%",
-		43.58..=43.59 => "43.59%",
-		43.59..=43.60 => "43.60%",
-		43.60..=43.61 => "43.61%",
-		43.61..=43.62 => "43.62%",
-		43.62..=43.63 => "43.63%",
-		43.63..=43.64 => "43.64%",
-		43.64..=43.65 => "43.65%",
-		43.65..=43.66 => "43.66%",
-		43.66..=43.67 => "43.67%",
-		43.67..=43.68 => "43.68%",
-		43.68..=43.69 => "43.69%",
-		43.69..=43.70 => "43.70%",
-		43.70..=43.71 => "43.71%",
-		43.71..=43.72 => "43.72%",
-		43.72..=43.73 => "43.73%",
-		43.73..=43.74 => "43.74%",
-		43.74..=43.75 => "43.75%",
-		43.75..=43.76 => "43.76%",
-		43.76..=43.77 => "43.77%",
-		43.77..=43.78 => "43.78%",
-		43.78..=43.79 => "43.79%",
-		43.79..=43.80 => "43.80%",
-		43.80..=43.81 => "43.81%",
-		43.81..=43.82 => "43.82%",
-		43.82..=43.83 => "43.83%",
-		43.83..=43.84 => "43.84%",
-		43.84..=43.85 => "43.85%",
-		43.85..=43.86 => "43.86%",
-		43.86..=43.87 => "43.87%",
-		43.87..=43.88 => "43.88%",
-		43.88..=43.89 => "43.89%",
-		43.89..=43.90 => "43.90%",
-		43.90..=43.91 => "43.91%",
-		43.91..=43.92 => "43.92%",
-		43.92..=43.93 => "43.93%",
-		43.93..=43.94 => "43.94%",
-		43.94..=43.95 => "43.95%",
-		43.95..=43.96 => "43.96%",
-		43.96..=43.97 => "43.97%",
-		43.97..=43.98 => "43.98%",
-		43.98..=43.99 => "43.99%",
-		43.99..=44.00 => "44.00%",
-		44.00..=44.01 => "44.01%",
-		44.01..=44.02 => "44.02%",
-		44.02..=44.03 => "44.03%",
-		44.03..=44.04 => "44.04%",
-		44.04..=44.05 => "44.05%",
-		44.05..=44.06 => "44.06%",
-		44.06..=44.07 => "44.07%",
-		44.07..=44.08 => "44.08%",
-		44.08..=44.09 => "44.09%",
-		44.09..=44.10 => "44.10%",
-		44.10..=44.11 => "44.11%",
-		44.11..=44.12 => "44.12%",
-		44.12..=44.13 => "44.13%",
-		44.13..=44.14 => "44.14%",
-		44.14..=44.15 => "44.15%",
-		44.15..=44.16 => "44.16%",
-		44.16..=44.17 => "44.17%",
-		44.17..=44.18 => "44.18%",
-		44.18..=44.19 => "44.19%",
-		44.19..=44.20 => "44.20%",
-		44.20..=44.21 => "44.21%",
-		44.21..=44.22 => "44.22%",
-		44.22..=44.23 => "44.23%",
-		44.23..=44.24 => "44.24%",
-		44.24..=44.25 => "44.25%",
-		44.25..=44.26 => "44.26%",
-		44.26..=44.27 => "44.27%",
-		44.27..=44.28 => "44.28%",
-		44.28..=44.29 => "44.29%",
-		44.29..=44.30 => "44.30%",
-		44.30..=44.31 => "44.31%",
-		44.31..=44.32 => "44.32%",
-		44.32..=44.33 => "44.33%",
-		44.33..=44.34 => "44.34%",
-		44.34..=44.35 => "44.35%",
-		44.35..=44.36 => "44.36%",
-		44.36..=44.37 => "44.37%",
-		44.37..=44.38 => "44.38%",
-		44.38..=44.39 => "44.39%",
-		44.39..=44.40 => "44.40%",
-		44.40..=44.41 => "44.41%",
-		44.41..=44.42 => "44.42%",
-		44.42..=44.43 => "44.43%",
-		44.43..=44.44 => "44.44%",
-		44.44..=44.45 => "44.45%",
-		44.45..=44.46 => "44.46%",
-		44.46..=44.47 => "44.47%",
-		44.47..=44.48 => "44.48%",
-		44.48..=44.49 => "44.49%",
-		44.49..=44.50 => "44.50%",
-		44.50..=44.51 => "44.51%",
-		44.51..=44.52 => "44.52%",
-		44.52..=44.53 => "44.53%",
-		44.53..=44.54 => "44.54%",
-		44.54..=44.55 => "44.55%",
-		44.55..=44.56 => "44.56%",
-		44.56..=44.57 => "44.57%",
-		44.57..=44.58 => "44.58%",
-		44.58..=44.59 => "44.59%",
-		44.59..=44.60 => "44.60%",
-		44.60..=44.61 => "44.61%",
-		44.61..=44.62 => "44.62%",
-		44.62..=44.63 => "44.63%",
-		44.63..=44.64 => "44.64%",
-		44.64..=44.65 => "44.65%",
-		44.65..=44.66 => "44.66%",
-		44.66..=44.67 => "44.67%",
-		44.67..=44.68 => "44.68%",
-		44.68..=44.69 => "44.69%",
-		44.69..=44.70 => "44.70%",
-		44.70..=44.71 => "44.71%",
-		44.71..=44.72 => "44.72%",
-		44.72..=44.73 => "44.73%",
-		44.73..=44.74 => "44.74%",
-		44.74..=44.75 => "44.75%",
-		44.75..=44.76 => "44.76%",
-		44.76..=44.77 => "44.77%",
-		44.77..=44.78 => "44.78%",
-		44.78..=44.79 => "44.79%",
-		44.79..=44.80 => "44.80%",
-		44.80..=44.81 => "44.81%",
-		44.81..=44.82 => "44.82%",
-		44.82..=44.83 => "44.83%",
-		44.83..=44.84 => "44.84%",
-		44.84..=44.85 => "44.85%",
-		44.85..=44.86 => "44.86%",
-		44.86..=44.87 => "44.87%",
-		44.87..=44.88 => "44.88%",
-		44.88..=44.89 => "44.89%",
-		44.89..=44.90 => "44.90%",
-		44.90..=44.91 => "44.91%",
-		44.91..=44.92 => "44.92%",
-		44.92..=44.93 => "44.93%",
-		44.93..=44.94 => "44.94%",
-		44.94..=44.95 => "44.95%",
-		44.95..=44.96 => "44.96%",
-		44.96..=44.97 => "44.97%",
-		44.97..=44.98 => "44.98%",
-		44.98..=44.99 => "44.99%",
-		44.99..=45.00 => "45.00%",
-		45.00..=45.01 => "45.01%",
-		45.01..=45.02 => "45.02%",
-		45.02..=45.03 => "45.03%",
-		45.03..=45.04 => "45.04%",
-		45.04..=45.05 => "45.05%",
-		45.05..=45.06 => "45.06%",
-		45.06..=45.07 => "45.07%",
-		45.07..=45.08 => "45.08%",
-		45.08..=45.09 => "45.09%",
-		45.09..=45.10 => "45.10%",
-		45.10..=45.11 => "45.11%",
-		45.11..=45.12 => "45.12%",
-		45.12..=45.13 => "45.13%",
-		45.13..=45.14 => "45.14%",
-		45.14..=45.15 => "45.15%",
-		45.15..=45.16 => "45.16%",
-		45.16..=45.17 => "45.17%",
-		45.17..=45.18 => "45.18%",
-		45.18..=45.19 => "45.19%",
-		45.19..=45.20 => "45.20%",
-		45.20..=45.21 => "45.21%",
-		45.21..=45.22 => "45.22%",
-		45.22..=45.23 => "45.23%",
-		45.23..=45.24 => "45.24%",
-		45.24..=45.25 => "45.25%",
-		45.25..=45.26 => "45.26%",
-		45.26..=45.27 => "45.27%",
-		45.27..=45.28 => "45.28%",
-		45.28..=45.29 => "45.29%",
-		45.29..=45.30 => "45.30%",
-		45.30..=45.31 => "45.31%",
-		45.31..=45.32 => "45.32%",
-		45.32..=45.33 => "45.33%",
-		45.33..=45.34 => "45.34%",
-		45.34..=45.35 => "45.35%",
-		45.35..=45.36 => "45.36%",
-		45.36..=45.37 => "45.37%",
-		45.37..=45.38 => "45.38%",
-		45.38..=45.39 => "45.39%",
-		45.39..=45.40 => "45.40%",
-		45.40..=45.41 => "45.41%",
-		45.41..=45.42 => "45.42%",
-		45.42..=45.43 => "45.43%",
-		45.43..=45.44 => "45.44%",
-		45.44..=45.45 => "45.45%",
-		45.45..=45.46 => "45.46%",
-		45.46..=45.47 => "45.47%",
-		45.47..=45.48 => "45.48%",
-		45.48..=45.49 => "45.49%",
-		45.49..=45.50 => "45.50%",
-		45.50..=45.51 => "45.51%",
-		45.51..=45.52 => "45.52%",
-		45.52..=45.53 => "45.53%",
-		45.53..=45.54 => "45.54%",
-		45.54..=45.55 => "45.55%",
-		45.55..=45.56 => "45.56%",
-		45.56..=45.57 => "45.57%",
-		45.57..=45.58 => "45.58%",
-		45.58..=45.59 => "45.59%",
-		45.59..=45.60 => "45.60%",
-		45.60..=45.61 => "45.61%",
-		45.61..=45.62 => "45.62%",
-		45.62..=45.63 => "45.63%",
-		45.63..=45.64 => "45.64%",
-		45.64..=45.65 => "45.65%",
-		45.65..=45.66 => "45.66%",
-		45.66..=45.67 => "45.67%",
-		45.67..=45.68 => "45.68%",
-		45.68..=45.69 => "45.69%",
-		45.69..=45.70 => "45.70%",
-		45.70..=45.71 => "45.71%",
-		45.71..=45.72 => "45.72%",
-		45.72..=45.73 => "45.73%",
-		45.73..=45.74 => "45.74%",
-		45.74..=45.75 => "45.75%",
-		45.75..=45.76 => "45.76%",
-		45.76..=45.77 => "45.77%",
-		45.77..=45.78 => "45.78%",
-		45.78..=45.79 => "45.79%",
-		45.79..=45.80 => "45.80%",
-		45.80..=45.81 => "45.81%",
-		45.81..=45.82 => "45.82%",
-		45.82..=45.83 => "45.83%",
-		45.83..=45.84 => "45.84%",
-		45.84..=45.85 => "45.85%",
-		45.85..=45.86 => "45.86%",
-		45.86..=45.87 => "45.87%",
-		45.87..=45.88 => "45.88%",
-		45.88..=45.89 => "45.89%",
-		45.89..=45.90 => "45.90%",
-		45.90..=45.91 => "45.91%",
-		45.91..=45.92 => "45.92%",
-		45.92..=45.93 => "45.93%",
-		45.93..=45.94 => "45.94%",
-		45.94..=45.95 => "45.95%",
-		45.95..=45.96 => "45.96%",
-		45.96..=45.97 => "45.97%",
-		45.97..=45.98 => "45.98%",
-		45.98..=45.99 => "45.99%",
-		45.99..=46.00 => "46.00%",
-		46.00..=46.01 => "46.01%",
-		46.01..=46.02 => "46.02%",
-		46.02..=46.03 => "46.03%",
-		46.03..=46.04 => "46.04%",
-		46.04..=46.05 => "46.05%",
-		46.05..=46.06 => "46.06%",
-		46.06..=46.07 => "46.07%",
-		46.07..=46.08 => "46.08%",
-		46.08..=46.09 => "46.09%",
-		46.09..=46.10 => "46.10%",
-		46.10..=46.11 => "46.11%",
-		46.11..=46.12 => "46.12%",
-		46.12..=46.13 => "46.13%",
-		46.13..=46.14 => "46.14%",
-		46.14..=46.15 => "46.15%",
-		46.15..=46.16 => "46.16%",
-		46.16..=46.17 => "46.17%",
-		46.17..=46.18 => "46.18%",
-		46.18..=46.19 => "46.19%",
-		46.19..=46.20 => "46.20%",
-		46.20..=46.21 => "46.21%",
-		46.21..=46.22 => "46.22%",
-		46.22..=46.23 => "46.23%",
-		46.23..=46.24 => "46.24%",
-		46.24..=46.25 => "46.25%",
-		46.25..=46.26 => "46.26%",
-		46.26..=46.27 => "46.27%",
-		46.27..=46.28 => "46.28%",
-		46.28..=46.29 => "46.29%",
-		46.29..=46.30 => "46.30%",
-		46.30..=46.31 => "46.31%",
-		46.31..=46.32 => "46.32%",
-		46.32..=46.33 => "46.33%",
-		46.33..=46.34 => "46.34%",
-		46.34..=46.35 => "46.35%",
-		46.35..=46.36 => "46.36%",
-		46.36..=46.37 => "46.37%",
-		46.37..=46.38 => "46.38%",
-		46.38..=46.39 => "46.39%",
-		46.39..=46.40 => "46.40%",
-		46.40..=46.41 => "46.41%",
-		46.41..=46.42 => "46.42%",
-		46.42..=46.43 => "46.43%",
-		46.43..=46.44 => "46.44%",
-		46.44..=46.45 => "46.45%",
-		46.45..=46.46 => "46.46%",
-		46.46..=46.47 => "46.47%",
-		46.47..=46.48 => "46.48%",
-		46.48..=46.49 => "46.49%",
-		46.49..=46.50 => "46.50%",
-		46.50..=46.51 => "46.51%",
-		46.51..=46.52 => "46.52%",
-		46.52..=46.53 => "46.53%",
-		46.53..=46.54 => "46.54%",
-		46.54..=46.55 => "46.55%",
-		46.55..=46.56 => "46.56%",
-		46.56..=46.57 => "46.57%",
-		46.57..=46.58 => "46.58%",
-		46.58..=46.59 => "46.59%",
-		46.59..=46.60 => "46.60%",
-		46.60..=46.61 => "46.61%",
-		46.61..=46.62 => "46.62%",
-		46.62..=46.63 => "46.63%",
-		46.63..=46.64 => "46.64%",
-		46.64..=46.65 => "46.65%",
-		46.65..=46.66 => "46.66%",
-		46.66..=46.67 => "46.67%",
-		46.67..=46.68 => "46.68%",
-		46.68..=46.69 => "46.69%",
-		46.69..=46.70 => "46.70%",
-		46.70..=46.71 => "46.71%",
-		46.71..=46.72 => "46.72%",
-		46.72..=46.73 => "46.73%",
-		46.73..=46.74 => "46.74%",
-		46.74..=46.75 => "46.75%",
-		46.75..=46.76 => "46.76%",
-		46.76..=46.77 => "46.77%",
-		46.77..=46.78 => "46.78%",
-		46.78..=46.79 => "46.79%",
-		46.79..=46.80 => "46.80%",
-		46.80..=46.81 => "46.81%",
-		46.81..=46.82 => "46.82%",
-		46.82..=46.83 => "46.83%",
-		46.83..=46.84 => "46.84%",
-		46.84..=46.85 => "46.85%",
-		46.85..=46.86 => "46.86%",
-		46.86..=46.87 => "46.87%",
-		46.87..=46.88 => "46.88%",
-		46.88..=46.89 => "46.89%",
-		46.89..=46.90 => "46.90%",
-		46.90..=46.91 => "46.91%",
-		46.91..=46.92 => "46.92%",
-		46.92..=46.93 => "46.93%",
-		46.93..=46.94 => "46.94%",
-		46.94..=46.95 => "46.95%",
-		46.95..=46.96 => "46.96%",
-		46.96..=46.97 => "46.97%",
-		46.97..=46.98 => "46.98%",
-		46.98..=46.99 => "46.99%",
-		46.99..=47.00 => "47.00%",
-		47.00..=47.01 => "47.01%",
-		47.01..=47.02 => "47.02%",
-		47.02..=47.03 => "47.03%",
-		47.03..=47.04 => "47.04%",
-		47.04..=47.05 => "47.05%",
-		47.05..=47.06 => "47.06%",
-		47.06..=47.07 => "47.07%",
-		47.07..=47.08 => "47.08%",
-		47.08..=47.09 => "47.09%",
-		47.09..=47.10 => "47.10%",
-		47.10..=47.11 => "47.11%",
-		47.11..=47.12 => "47.12%",
-		47.12..=47.13 => "47.13%",
-		47.13..=47.14 => "47.14%",
-		47.14..=47.15 => "47.15%",
-		47.15..=47.16 => "47.16%",
-		47.16..=47.17 => "47.17%",
-		47.17..=47.18 => "47.18%",
-		47.18..=47.19 => "47.19%",
-		47.19..=47.20 => "47.20%",
-		47.20..=47.21 => "47.21%",
-		47.21..=47.22 => "47.22%",
-		47.22..=47.23 => "47.23%",
-		47.23..=47.24 => "47.24%",
-		47.24..=47.25 => "47.25%",
-		47.25..=47.26 => "47.26%",
-		47.26..=47.27 => "47.27%",
-		47.27..=47.28 => "47.28%",
-		47.28..=47.29 => "47.29%",
-		47.29..=47.30 => "47.30%",
-		47.30..=47.31 => "47.31%",
-		47.31..=47.32 => "47.32%",
-		47.32..=47.33 => "47.33%",
-		47.33..=47.34 => "47.34%",
-		47.34..=47.35 => "47.35%",
-		47.35..=47.36 => "47.36%",
-		47.36..=47.37 => "47.37%",
-		47.37..=47.38 => "47.38%",
-		47.38..=47.39 => "47.39%",
-		47.39..=47.40 => "47.40%",
-		47.40..=47.41 => "47.41%",
-		47.41..=47.42 => "47.42%",
-		47.42..=47.43 => "47.43%",
-		47.43..=47.44 => "47.44%",
-		47.44..=47.45 => "47.45%",
-		47.45..=47.46 => "47.46%",
-		47.46..=47.47 => "47.47%",
-		47.47..=47.48 => "47.48%",
-		47.48..=47.49 => "47.49%",
-		47.49..=47.50 => "47.50%",
-		47.50..=47.51 => "47.51%",
-		47.51..=47.52 => "47.52%",
-		47.52..=47.53 => "47.53%",
-		47.53..=47.54 => "47.54%",
-		47.54..=47.55 => "47.55%",
-		47.55..=47.56 => "47.56%",
-		47.56..=47.57 => "47.57%",
-		47.57..=47.58 => "47.58%",
-		47.58..=47.59 => "47.59%",
-		47.59..=47.60 => "47.60%",
-		47.60..=47.61 => "47.61%",
-		47.61..=47.62 => "47.62%",
-		47.62..=47.63 => "47.63%",
-		47.63..=47.64 => "47.64%",
-		47.64..=47.65 => "47.65%",
-		47.65..=47.66 => "47.66%",
-		47.66..=47.67 => "47.67%",
-		47.67..=47.68 => "47.68%",
-		47.68..=47.69 => "47.69%",
-		47.69..=47.70 => "47.70%",
-		47.70..=47.71 => "47.71%",
-		47.71..=47.72 => "47.72%",
-		47.72..=47.73 => "47.73%",
-		47.73..=47.74 => "47.74%",
-		47.74..=47.75 => "47.75%",
-		47.75..=47.76 => "47.76%",
-		47.76..=47.77 => "47.77%",
-		47.77..=47.78 => "47.78%",
-		47.78..=47.79 => "47.79%",
-		47.79..=47.80 => "47.80%",
-		47.80..=47.81 => "47.81%",
-		47.81..=47.82 => "47.82%",
-		47.82..=47.83 => "47.83%",
-		47.83..=47.84 => "47.84%",
-		47.84..=47.85 => "47.85%",
-		47.85..=47.86 => "47.86%",
-		47.86..=47.87 => "47.87%",
-		47.87..=47.88 => "47.88%",
-		47.88..=47.89 => "47.89%",
-		47.89..=47.90 => "47.90%",
-		47.90..=47.91 => "47.91%",
-		47.91..=47.92 => "47.92%",
-		47.92..=47.93 => "47.93%",
-		47.93..=47.94 => "47.94%",
-		47.94..=47.95 => "47.95%",
-		47.95..=47.96 => "47.96%",
-		47.96..=47.97 => "47.97%",
-		47.97..=47.98 => "47.98%",
-		47.98..=47.99 => "47.99%",
-		47.99..=48.00 => "48.00%",
-		48.00..=48.01 => "48.01%",
-		48.01..=48.02 => "48.02%",
-		48.02..=48.03 => "48.03%",
-		48.03..=48.04 => "48.04%",
-		48.04..=48.05 => "48.05%",
-		48.05..=48.06 => "48.06%",
-		48.06..=48.07 => "48.07%",
-		48.07..=48.08 => "48.08%",
-		48.08..=48.09 => "48.09%",
-		48.09..=48.10 => "48.10%",
-		48.10..=48.11 => "48.11%",
-		48.11..=48.12 => "48.12%",
-		48.12..=48.13 => "48.13%",
-		48.13..=48.14 => "48.14%",
-		48.14..=48.15 => "48.15%",
-		48.15..=48.16 => "48.16%",
-		48.16..=48.17 => "48.17%",
-		48.17..=48.18 => "48.18%",
-		48.18..=48.19 => "48.19%",
-		48.19..=48.20 => "48.20%",
-		48.20..=48.21 => "48.21%",
-		48.21..=48.22 => "48.22%",
-		48.22..=48.23 => "48.23%",
-		48.23..=48.24 => "48.24%",
-		48.24..=48.25 => "48.25%",
-		48.25..=48.26 => "48.26%",
-		48.26..=48.27 => "48.27%",
-		48.27..=48.28 => "48.28%",
-		48.28..=48.29 => "48.29%",
-		48.29..=48.30 => "48.30%",
-		48.30..=48.31 => "48.31%",
-		48.31..=48.32 => "48.32%",
-		48.32..=48.33 => "48.33%",
-		48.33..=48.34 => "48.34%",
-		48.34..=48.35 => "48.35%",
-		48.35..=48.36 => "48.36%",
-		48.36..=48.37 => "48.37%",
-		48.37..=48.38 => "48.38%",
-		48.38..=48.39 => "48.39%",
-		48.39..=48.40 => "48.40%",
-		48.40..=48.41 => "48.41%",
-		48.41..=48.42 => "48.42%",
-		48.42..=48.43 => "48.43%",
-		48.43..=48.44 => "48.44%",
-		48.44..=48.45 => "48.45%",
-		48.45..=48.46 => "48.46%",
-		48.46..=48.47 => "48.47%",
-		48.47..=48.48 => "48.48%",
-		48.48..=48.49 => "48.49%",
-		48.49..=48.50 => "48.50%",
-		48.50..=48.51 => "48.51%",
-		48.51..=48.52 => "48.52%",
-		48.52..=48.53 => "48.53%",
-		48.53..=48.54 => "48.54%",
-		48.54..=48.55 => "48.55%",
-		48.55..=48.56 => "48.56%",
-		48.56..=48.57 => "48.57%",
-		48.57..=48.58 => "48.58%",
-		48.58..=48.59 => "48.59%",
-		48.59..=48.60 => "48.60%",
-		48.60..=48.61 => "48.61%",
-		48.61..=48.62 => "48.62%",
-		48.62..=48.63 => "48.63%",
-		48.63..=48.64 => "48.64%",
-		48.64..=48.65 => "48.65%",
-		48.65..=48.66 => "48.66%",
-		48.66..=48.67 => "48.67%",
-		48.67..=48.68 => "48.68%",
-		48.68..=48.69 => "48.69%",
-		48.69..=48.70 => "48.70%",
-		48.70..=48.71 => "48.71%",
-		48.71..=48.72 => "48.72%",
-		48.72..=48.73 => "48.73%",
-		48.73..=48.74 => "48.74%",
-		48.74..=48.75 => "48.75%",
-		48.75..=48.76 => "48.76%",
-		48.76..=48.77 => "48.77%",
-		48.77..=48.78 => "48.78%",
-		48.78..=48.79 => "48.79%",
-		48.79..=48.80 => "48.80%",
-		48.80..=48.81 => "48.81%",
-		48.81..=48.82 => "48.82%",
-		48.82..=48.83 => "48.83%",
-		48.83..=48.84 => "48.84%",
-		48.84..=48.85 => "48.85%",
-		48.85..=48.86 => "48.86%",
-		48.86..=48.87 => "48.87%",
-		48.87..=48.88 => "48.88%",
-		48.88..=48.89 => "48.89%",
-		48.89..=48.90 => "48.90%",
-		48.90..=48.91 => "48.91%",
-		48.91..=48.92 => "48.92%",
-		48.92..=48.93 => "48.93%",
-		48.93..=48.94 => "48.94%",
-		48.94..=48.95 => "48.95%",
-		48.95..=48.96 => "48.96%",
-		48.96..=48.97 => "48.97%",
-		48.97..=48.98 => "48.98%",
-		48.98..=48.99 => "48.99%",
-		48.99..=49.00 => "49.00%",
-		49.00..=49.01 => "49.01%",
-		49.01..=49.02 => "49.02%",
-		49.02..=49.03 => "49.03%",
-		49.03..=49.04 => "49.04%",
-		49.04..=49.05 => "49.05%",
-		49.05..=49.06 => "49.06%",
-		49.06..=49.07 => "49.07%",
-		49.07..=49.08 => "49.08%",
-		49.08..=49.09 => "49.09%",
-		49.09..=49.10 => "49.10%",
-		49.10..=49.11 => "49.11%",
-		49.11..=49.12 => "49.12%",
-		49.12..=49.13 => "49.13%",
-		49.13..=49.14 => "49.14%",
-		49.14..=49.15 => "49.15%",
-		49.15..=49.16 => "49.16%",
-		49.16..=49.17 => "49.17%",
-		49.17..=49.18 => "49.18%",
-		49.18..=49.19 => "49.19%",
-		49.19..=49.20 => "49.20%",
-		49.20..=49.21 => "49.21%",
-		49.21..=49.22 => "49.22%",
-		49.22..=49.23 => "49.23%",
-		49.23..=49.24 => "49.24%",
-		49.24..=49.25 => "49.25%",
-		49.25..=49.26 => "49.26%",
-		49.26..=49.27 => "49.27%",
-		49.27..=49.28 => "49.28%",
-		49.28..=49.29 => "49.29%",
-		49.29..=49.30 => "49.30%",
-		49.30..=49.31 => "49.31%",
-		49.31..=49.32 => "49.32%",
-		49.32..=49.33 => "49.33%",
-		49.33..=49.34 => "49.34%",
-		49.34..=49.35 => "49.35%",
-		49.35..=49.36 => "49.36%",
-		49.36..=49.37 => "49.37%",
-		49.37..=49.38 => "49.38%",
-		49.38..=49.39 => "49.39%",
-		49.39..=49.40 => "49.40%",
-		49.40..=49.41 => "49.41%",
-		49.41..=49.42 => "49.42%",
-		49.42..=49.43 => "49.43%",
-		49.43..=49.44 => "49.44%",
-		49.44..=49.45 => "49.45%",
-		49.45..=49.46 => "49.46%",
-		49.46..=49.47 => "49.47%",
-		49.47..=49.48 => "49.48%",
-		49.48..=49.49 => "49.49%",
-		49.49..=49.50 => "49.50%",
-		49.50..=49.51 => "49.51%",
-		49.51..=49.52 => "49.52%",
-		49.52..=49.53 => "49.53%",
-		49.53..=49.54 => "49.54%",
-		49.54..=49.55 => "49.55%",
-		49.55..=49.56 => "49.56%",
-		49.56..=49.57 => "49.57%",
-		49.57..=49.58 => "49.58%",
-		49.58..=49.59 => "49.59%",
-		49.59..=49.60 => "49.60%",
-		49.60..=49.61 => "49.61%",
-		49.61..=49.62 => "49.62%",
-		49.62..=49.63 => "49.63%",
-		49.63..=49.64 => "49.64%",
-		49.64..=49.65 => "49.65%",
-		49.65..=49.66 => "49.66%",
-		49.66..=49.67 => "49.67%",
-		49.67..=49.68 => "49.68%",
-		49.68..=49.69 => "49.69%",
-		49.69..=49.70 => "49.70%",
-		49.70..=49.71 => "49.71%",
-		49.71..=49.72 => "49.72%",
-		49.72..=49.73 => "49.73%",
-		49.73..=49.74 => "49.74%",
-		49.74..=49.75 => "49.75%",
-		49.75..=49.76 => "49.76%",
-		49.76..=49.77 => "49.77%",
-		49.77..=49.78 => "49.78%",
-		49.78..=49.79 => "49.79%",
-		49.79..=49.80 => "49.80%",
-		49.80..=49.81 => "49.81%",
-		49.81..=49.82 => "49.82%",
-		49.82..=49.83 => "49.83%",
-		49.83..=49.84 => "49.84%",
-		49.84..=49.85 => "49.85%",
-		49.85..=49.86 => "49.86%",
-		49.86..=49.87 => "49.87%",
-		49.87..=49.88 => "49.88%",
-		49.88..=49.89 => "49.89%",
-		49.89..=49.90 => "49.90%",
-		49.90..=49.91 => "49.91%",
-		49.91..=49.92 => "49.92%",
-		49.92..=49.93 => "49.93%",
-		49.93..=49.94 => "49.94%",
-		49.94..=49.95 => "49.95%",
-		49.95..=49.96 => "49.96%",
-		49.96..=49.97 => "49.97%",
-		49.97..=49.98 => "49.98%",
-		49.98..=49.99 => "49.99%",
-		_ => "50.00%",
-	}
-}
@@ -1,2505 +0,0 @@
-#[inline(always)]
-pub(crate) fn inlined_50(f: f64) -> &'static str {
-	match f {
-		50.00..=50.01 => "50.01%",
-		50.01..=50.02 => "50.02%",
-		50.02..=50.03 => "50.03%",
-		50.03..=50.04 => "50.04%",
-		50.04..=50.05 => "50.05%",
-		50.05..=50.06 => "50.06%",
-		50.06..=50.07 => "50.07%",
-		50.07..=50.08 => "50.08%",
-		50.08..=50.09 => "50.09%",
-		50.09..=50.10 => "50.10%",
-		50.10..=50.11 => "50.11%",
-		50.11..=50.12 => "50.12%",
-		50.12..=50.13 => "50.13%",
-		50.13..=50.14 => "50.14%",
-		50.14..=50.15 => "50.15%",
-		50.15..=50.16 => "50.16%",
-		50.16..=50.17 => "50.17%",
-		50.17..=50.18 => "50.18%",
-		50.18..=50.19 => "50.19%",
-		50.19..=50.20 => "50.20%",
-		50.20..=50.21 => "50.21%",
-		50.21..=50.22 => "50.22%",
-		50.22..=50.23 => "50.23%",
-		50.23..=50.24 => "50.24%",
-		50.24..=50.25 => "50.25%",
-		50.25..=50.26 => "50.26%",
-		50.26..=50.27 => "50.27%",
-		50.27..=50.28 => "50.28%",
-		50.28..=50.29 => "50.29%",
-		50.29..=50.30 => "50.30%",
-		50.30..=50.31 => "50.31%",
-		50.31..=50.32 => "50.32%",
-		50.32..=50.33 => "50.33%",
-		50.33..=50.34 => "50.34%",
-		50.34..=50.35 => "50.35%",
-		50.35..=50.36 => "50.36%",
-		50.36..=50.37 => "50.37%",
-		50.37..=50.38 => "50.38%",
-		50.38..=50.39 => "50.39%",
-		50.39..=50.40 => "50.40%",
-		50.40..=50.41 => "50.41%",
-		50.41..=50.42 => "50.42%",
-		50.42..=50.43 => "50.43%",
-		50.43..=50.44 => "50.44%",
-		50.44..=50.45 => "50.45%",
-		50.45..=50.46 => "50.46%",
-		50.46..=50.47 => "50.47%",
-		50.47..=50.48 => "50.48%",
-		50.48..=50.49 => "50.49%",
-		50.49..=50.50 => "50.50%",
-		50.50..=50.51 => "50.51%",
-		50.51..=50.52 => "50.52%",
-		50.52..=50.53 => "50.53%",
-		50.53..=50.54 => "50.54%",
-		50.54..=50.55 => "50.55%",
-		50.55..=50.56 => "50.56%",
-		50.56..=50.57 => "50.57%",
-		50.57..=50.58 => "50.58%",
-		50.58..=50.59 => "50.59%",
-		50.59..=50.60 => "50.60%",
-		50.60..=50.61 => "50.61%",
-		50.61..=50.62 => "50.62%",
-		50.62..=50.63 => "50.63%",
-		50.63..=50.64 => "50.64%",
-		50.64..=50.65 => "50.65%",
-		50.65..=50.66 => "50.66%",
-		50.66..=50.67 => "50.67%",
-		50.67..=50.68 => "50.68%",
-		50.68..=50.69 => "50.69%",
-		50.69..=50.70 => "50.70%",
-		50.70..=50.71 => "50.71%",
-		50.71..=50.72 => "50.72%",
-		50.72..=50.73 => "50.73%",
-		50.73..=50.74 => "50.74%",
-		50.74..=50.75 => "50.75%",
-		50.75..=50.76 => "50.76%",
-		50.76..=50.77 => "50.77%",
-		50.77..=50.78 => "50.78%",
-		50.78..=50.79 => "50.79%",
-		50.79..=50.80 => "50.80%",
-		50.80..=50.81 => "50.81%",
-		50.81..=50.82 => "50.82%",
-		50.82..=50.83 => "50.83%",
-		50.83..=50.84 => "50.84%",
-		50.84..=50.85 => "50.85%",
-		50.85..=50.86 => "50.86%",
-		50.86..=50.87 => "50.87%",
-		50.87..=50.88 => "50.88%",
-		50.88..=50.89 => "50.89%",
-		50.89..=50.90 => "50.90%",
-		50.90..=50.91 => "50.91%",
-		50.91..=50.92 => "50.92%",
-		50.92..=50.93 => "50.93%",
-		50.93..=50.94 => "50.94%",
-		50.94..=50.95 => "50.95%",
-		50.95..=50.96 => "50.96%",
-		50.96..=50.97 => "50.97%",
-		50.97..=50.98 => "50.98%",
-		50.98..=50.99 => "50.99%",
-		50.99..=51.00 => "51.00%",
-		51.00..=51.01 => "51.01%",
-		51.01..=51.02 => "51.02%",
-		51.02..=51.03 => "51.03%",
-		51.03..=51.04 => "51.04%",
-		51.04..=51.05 => "51.05%",
-		51.05..=51.06 => "51.06%",
-		51.06..=51.07 => "51.07%",
-		51.07..=51.08 => "51.08%",
-		51.08..=51.09 => "51.09%",
-		51.09..=51.10 => "51.10%",
-		51.10..=51.11 => "51.11%",
-		51.11..=51.12 => "51.12%",
-		51.12..=51.13 => "51.13%",
-		51.13..=51.14 => "51.14%",
-		51.14..=51.15 => "51.15%",
-		51.15..=51.16 => "51.16%",
-		51.16..=51.17 => "51.17%",
-		51.17..=51.18 => "51.18%",
-		51.18..=51.19 => "51.19%",
-		51.19..=51.20 => "51.20%",
-		51.20..=51.21 => "51.21%",
-		51.21..=51.22 => "51.22%",
-		51.22..=51.23 => "51.23%",
-		51.23..=51.24 => "51.24%",
-		51.24..=51.25 => "51.25%",
-		51.25..=51.26 => "51.26%",
-		51.26..=51.27 => "51.27%",
-		51.27..=51.28 => "51.28%",
-		51.28..=51.29 => "51.29%",
-		51.29..=51.30 => "51.30%",
-		51.30..=51.31 => "51.31%",
-		51.31..=51.32 => "51.32%",
-		51.32..=51.33 => "51.33%",
-		51.33..=51.34 => "51.34%",
-		51.34..=51.35 => "51.35%",
-		51.35..=51.36 => "51.36%",
-		51.36..=51.37 => "51.37%",
-		51.37..=51.38 => "51.38%",
-		51.38..=51.39 => "51.39%",
-		51.39..=51.40 => "51.40%",
-		51.40..=51.41 => "51.41%",
-		51.41..=51.42 => "51.42%",
-		51.42..=51.43 => "51.43%",
-		51.43..=51.44 => "51.44%",
-		51.44..=51.45 => "51.45%",
-		51.45..=51.46 => "51.46%",
-		51.46..=51.47 => "51.47%",
-		51.47..=51.48 => "51.48%",
-		51.48..=51.49 => "51.49%",
-		51.49..=51.50 => "51.50%",
-		51.50..=51.51 => "51.51%",
-		51.51..=51.52 => "51.52%",
-		51.52..=51.53 => "51.53%",
-		51.53..=51.54 => "51.54%",
-		51.54..=51.55 => "51.55%",
-		51.55..=51.56 => "51.56%",
-		51.56..=51.57 => "51.57%",
-		51.57..=51.58 => "51.58%",
-		51.58..=51.59 => "51.59%",
-		51.59..=51.60 => "51.60%",
-		51.60..=51.61 => "51.61%",
-		51.61..=51.62 => "51.62%",
-		51.62..=51.63 => "51.63%",
-		51.63..=51.64 => "51.64%",
-		51.64..=51.65 => "51.65%",
-		51.65..=51.66 => "51.66%",
-		51.66..=51.67 => "51.67%",
-		51.67..=51.68 => "51.68%",
-		51.68..=51.69 => "51.69%",
-		51.69..=51.70 => "51.70%",
-		51.70..=51.71 => "51.71%",
-		51.71..=51.72 => "51.72%",
-		51.72..=51.73 => "51.73%",
-		51.73..=51.74 => "51.74%",
-		51.74..=51.75 => "51.75%",
-		51.75..=51.76 => "51.76%",
-		51.76..=51.77 => "51.77%",
-		51.77..=51.78 => "51.78%",
-		51.78..=51.79 => "51.79%",
-		51.79..=51.80 => "51.80%",
-		51.80..=51.81 => "51.81%",
-		51.81..=51.82 => "51.82%",
-		51.82..=51.83 => "51.83%",
-		51.83..=51.84 => "51.84%",
-		51.84..=51.85 => "51.85%",
-		51.85..=51.86 => "51.86%",
-		51.86..=51.87 => "51.87%",
-		51.87..=51.88 => "51.88%",
-		51.88..=51.89 => "51.89%",
-		51.89..=51.90 => "51.90%",
-		51.90..=51.91 => "51.91%",
-		51.91..=51.92 => "51.92%",
-		51.92..=51.93 => "51.93%",
-		51.93..=51.94 => "51.94%",
-		51.94..=51.95 => "51.95%",
-		51.95..=51.96 => "51.96%",
-		51.96..=51.97 => "51.97%",
-		51.97..=51.98 => "51.98%",
-		51.98..=51.99 => "51.99%",
-		51.99..=52.00 => "52.00%",
-		52.00..=52.01 => "52.01%",
-		52.01..=52.02 => "52.02%",
-		52.02..=52.03 => "52.03%",
-		52.03..=52.04 => "52.04%",
-		52.04..=52.05 => "52.05%",
-		52.05..=52.06 => "52.06%",
-		52.06..=52.07 => "52.07%",
-		52.07..=52.08 => "52.08%",
-		52.08..=52.09 => "52.09%",
-		52.09..=52.10 => "52.10%",
-		52.10..=52.11 => "52.11%",
-		52.11..=52.12 => "52.12%",
-		52.12..=52.13 => "52.13%",
-		52.13..=52.14 => "52.14%",
-		52.14..=52.15 => "52.15%",
-		52.15..=52.16 => "52.16%",
-		52.16..=52.17 => "52.17%",
-		52.17..=52.18 => "52.18%",
-		52.18..=52.19 => "52.19%",
-		52.19..=52.20 => "52.20%",
-		52.20..=52.21 => "52.21%",
-		52.21..=52.22 => "52.22%",
-		52.22..=52.23 => "52.23%",
-		52.23..=52.24 => "52.24%",
-		52.24..=52.25 => "52.25%",
-		52.25..=52.26 => "52.26%",
-		52.26..=52.27 => "52.27%",
-		52.27..=52.28 => "52.28%",
-		52.28..=52.29 => "52.29%",
-		52.29..=52.30 => "52.30%",
-		52.30..=52.31 => "52.31%",
-		52.31..=52.32 => "52.32%",
-		52.32..=52.33 => "52.33%",
-		52.33..=52.34 => "52.34%",
-		52.34..=52.35 => "52.35%",
-		52.35..=52.36 => "52.36%",
-		52.36..=52.37 => "52.37%",
-		52.37..=52.38 => "52.38%",
-		52.38..=52.39 => "52.39%",
-		52.39..=52.40 => "52.40%",
-		52.40..=52.41 => "52.41%",
-		52.41..=52.42 => "52.42%",
-		52.42..=52.43 => "52.43%",
-		52.43..=52.44 => "52.44%",
-		52.44..=52.45 => "52.45%",
-		52.45..=52.46 => "52.46%",
-		52.46..=52.47 => "52.47%",
-		52.47..=52.48 => "52.48%",
-		52.48..=52.49 => "52.49%",
-		52.49..=52.50 => "52.50%",
-		52.50..=52.51 => "52.51%",
-		52.51..=52.52 => "52.52%",
-		52.52..=52.53 => "52.53%",
-		52.53..=52.54 => "52.54%",
-		52.54..=52.55 => "52.55%",
-		52.55..=52.56 => "52.56%",
-		52.56..=52.57 => "52.57%",
-		52.57..=52.58 => "52.58%",
-		52.58..=52.59 => "52.59%",
-		52.59..=52.60 => "52.60%",
-		52.60..=52.61 => "52.61%",
-		52.61..=52.62 => "52.62%",
-		52.62..=52.63 => "52.63%",
-		52.63..=52.64 => "52.64%",
-		52.64..=52.65 => "52.65%",
-		52.65..=52.66 => "52.66%",
-		52.66..=52.67 => "52.67%",
-		52.67..=52.68 => "52.68%",
-		52.68..=52.69 => "52.69%",
-		52.69..=52.70 => "52.70%",
-		52.70..=52.71 => "52.71%",
-		52.71..=52.72 => "52.72%",
-		52.72..=52.73 => "52.73%",
-		52.73..=52.74 => "52.74%",
-		52.74..=52.75 => "52.75%",
-		52.75..=52.76 => "52.76%",
-		52.76..=52.77 => "52.77%",
-		52.77..=52.78 => "52.78%",
-		52.78..=52.79 => "52.79%",
-		52.79..=52.80 => "52.80%",
-		52.80..=52.81 => "52.81%",
-		52.81..=52.82 => "52.82%",
-		52.82..=52.83 => "52.83%",
-		52.83..=52.84 => "52.84%",
-		52.84..=52.85 => "52.85%",
-		52.85..=52.86 => "52.86%",
-		52.86..=52.87 => "52.87%",
-		52.87..=52.88 => "52.88%",
-		52.88..=52.89 => "52.89%",
-		52.89..=52.90 => "52.90%",
-		52.90..=52.91 => "52.91%",
-		52.91..=52.92 => "52.92%",
-		52.92..=52.93 => "52.93%",
-		52.93..=52.94 => "52.94%",
-		52.94..=52.95 => "52.95%",
-		52.95..=52.96 => "52.96%",
-		52.96..=52.97 => "52.97%",
-		52.97..=52.98 => "52.98%",
-		52.98..=52.99 => "52.99%",
-		52.99..=53.00 => "53.00%",
-		53.00..=53.01 => "53.01%",
-		53.01..=53.02 => "53.02%",
-		53.02..=53.03 => "53.03%",
-		53.03..=53.04 => "53.04%",
-		53.04..=53.05 => "53.05%",
-		53.05..=53.06 => "53.06%",
-		53.06..=53.07 => "53.07%",
-		53.07..=53.08 => "53.08%",
-		53.08..=53.09 => "53.09%",
-		53.09..=53.10 => "53.10%",
-		53.10..=53.11 => "53.11%",
-		53.11..=53.12 => "53.12%",
-		53.12..=53.13 => "53.13%",
-		53.13..=53.14 => "53.14%",
-		53.14..=53.15 => "53.15%",
-		53.15..=53.16 => "53.16%",
-		53.16..=53.17 => "53.17%",
-		53.17..=53.18 => "53.18%",
-		53.18..=53.19 => "53.19%",
-		53.19..=53.20 => "53.20%",
-		53.20..=53.21 => "53.21%",
-		53.21..=53.22 => "53.22%",
-		53.22..=53.23 => "53.23%",
-		53.23..=53.24 => "53.24%",
-		53.24..=53.25 => "53.25%",
-		53.25..=53.26 => "53.26%",
-		53.26..=53.27 => "53.27%",
-		53.27..=53.28 => "53.28%",
-		53.28..=53.29 => "53.29%",
-		53.29..=53.30 => "53.30%",
-		53.30..=53.31 => "53.31%",
-		53.31..=53.32 => "53.32%",
-		53.32..=53.33 => "53.33%",
-		53.33..=53.34 => "53.34%",
-		53.34..=53.35 => "53.35%",
-		53.35..=53.36 => "53.36%",
-		53.36..=53.37 => "53.37%",
-		53.37..=53.38 => "53.38%",
-		53.38..=53.39 => "53.39%",
-		53.39..=53.40 => "53.40%",
-		53.40..=53.41 => "53.41%",
-		53.41..=53.42 => "53.42%",
-		53.42..=53.43 => "53.43%",
-		53.43..=53.44 => "53.44%",
-		53.44..=53.45 => "53.45%",
-		53.45..=53.46 => "53.46%",
-		53.46..=53.47 => "53.47%",
-		53.47..=53.48 => "53.48%",
-		53.48..=53.49 => "53.49%",
-		53.49..=53.50 => "53.50%",
-		53.50..=53.51 => "53.51%",
-		53.51..=53.52 => "53.52%",
-		53.52..=53.53 => "53.53%",
-		53.53..=53.54 => "53.54%",
-		53.54..=53.55 => "53.55%",
-		53.55..=53.56 => "53.56%",
-		53.56..=53.57 => "53.57%",
-		53.57..=53.58 => "53.58%",
-		53.58..=53.59 => "53.59%",
-		53.59..=53.60 => "53.60%",
-		53.60..=53.61 => "53.61%",
-		53.61..=53.62 => "53.62%",
-		53.62..=53.63 => "53.63%",
-		53.63..=53.64 => "53.64%",
-		53.64..=53.65 => "53.65%",
-		53.65..=53.66 => "53.66%",
-		53.66..=53.67 => "53.67%",
-		53.67..=53.68 => "53.68%",
-		53.68..=53.69 => "53.69%",
-		53.69..=53.70 => "53.70%",
-		53.70..=53.71 => "53.71%",
-		53.71..=53.72 => "53.72%",
-		53.72..=53.73 => "53.73%",
-		53.73..=53.74 => "53.74%",
-		53.74..=53.75 => "53.75%",
-		53.75..=53.76 => "53.76%",
-		53.76..=53.77 => "53.77%",
-		53.77..=53.78 => "53.78%",
-		53.78..=53.79 => "53.79%",
-		53.79..=53.80 => "53.80%",
-		53.80..=53.81 => "53.81%",
-		53.81..=53.82 => "53.82%",
-		53.82..=53.83 => "53.83%",
-		53.83..=53.84 => "53.84%",
-		53.84..=53.85 => "53.85%",
-		53.85..=53.86 => "53.86%",
-		53.86..=53.87 => "53.87%",
-		53.87..=53.88 => "53.88%",
-		53.88..=53.89 => "53.89%",
-		53.89..=53.90 => "53.90%",
-		53.90..=53.91 => "53.91%",
-		53.91..=53.92 => "53.92%",
-		53.92..=53.93 => "53.93%",
-		53.93..=53.94 => "53.94%",
-		53.94..=53.95 => "53.95%",
-		53.95..=53.96 => "53.96%",
-		53.96..=53.97 => "53.97%",
-		53.97..=53.98 => "53.98%",
-		53.98..=53.99 => "53.99%",
-		53.99..=54.00 => "54.00%",
-		54.00..=54.01 => "54.01%",
-		54.01..=54.02 => "54.02%",
-		54.02..=54.03 => "54.03%",
-		54.03..=54.04 => "54.04%",
-		54.04..=54.05 => "54.05%",
-		54.05..=54.06 => "54.06%",
-		54.06..=54.07 => "54.07%",
-		54.07..=54.08 => "54.08%",
-		54.08..=54.09 => "54.09%",
-		54.09..=54.10 => "54.10%",
-		54.10..=54.11 => "54.11%",
-		54.11..=54.12 => "54.12%",
-		54.12..=54.13 => "54.13%",
-		54.13..=54.14 => "54.14%",
-		54.14..=54.15 => "54.15%",
-		54.15..=54.16 => "54.16%",
-		54.16..=54.17 => "54.17%",
-		54.17..=54.18 => "54.18%",
-		54.18..=54.19 => "54.19%",
-		54.19..=54.20 => "54.20%",
-		54.20..=54.21 => "54.21%",
-		54.21..=54.22 => "54.22%",
-		54.22..=54.23 => "54.23%",
-		54.23..=54.24 => "54.24%",
-		54.24..=54.25 => "54.25%",
-		54.25..=54.26 => "54.26%",
-		54.26..=54.27 => "54.27%",
-		54.27..=54.28 => "54.28%",
-		54.28..=54.29 => "54.29%",
-		54.29..=54.30 => "54.30%",
-		54.30..=54.31 => "54.31%",
-		54.31..=54.32 => "54.32%",
-		54.32..=54.33 => "54.33%",
-		54.33..=54.34 => "54.34%",
-		54.34..=54.35 => "54.35%",
-		54.35..=54.36 => "54.36%",
-		54.36..=54.37 => "54.37%",
-		54.37..=54.38 => "54.38%",
-		54.38..=54.39 => "54.39%",
-		54.39..=54.40 => "54.40%",
-		54.40..=54.41 => "54.41%",
-		54.41..=54.42 => "54.42%",
-		54.42..=54.43 => "54.43%",
-		54.43..=54.44 => "54.44%",
-		54.44..=54.45 => "54.45%",
-		54.45..=54.46 => "54.46%",
-		54.46..=54.47 => "54.47%",
-		54.47..=54.48 => "54.48%",
-		54.48..=54.49 => "54.49%",
-		54.49..=54.50 => "54.50%",
-		54.50..=54.51 => "54.51%",
-		54.51..=54.52 => "54.52%",
-		54.52..=54.53 => "54.53%",
-		54.53..=54.54 => "54.54%",
-		54.54..=54.55 => "54.55%",
-		54.55..=54.56 => "54.56%",
-		54.56..=54.57 => "54.57%",
-		54.57..=54.58 => "54.58%",
-		54.58..=54.59 => "54.59%",
-		54.59..=54.60 => "54.60%",
-		54.60..=54.61 => "54.61%",
-		54.61..=54.62 => "54.62%",
-		54.62..=54.63 => "54.63%",
-		54.63..=54.64 => "54.64%",
-		54.64..=54.65 => "54.65%",
-		54.65..=54.66 => "54.66%",
-		54.66..=54.67 => "54.67%",
-		54.67..=54.68 => "54.68%",
-		54.68..=54.69 => "54.69%",
-		54.69..=54.70 => "54.70%",
-		54.70..=54.71 => "54.71%",
-		54.71..=54.72 => "54.72%",
-		54.72..=54.73 => "54.73%",
-		54.73..=54.74 => "54.74%",
-		54.74..=54.75 => "54.75%",
-		54.75..=54.76 => "54.76%",
-		54.76..=54.77 => "54.77%",
-		54.77..=54.78 => "54.78%",
-		54.78..=54.79 => "54.79%",
-		54.79..=54.80 => "54.80%",
-		54.80..=54.81 => "54.81%",
-		54.81..=54.82 => "54.82%",
-		54.82..=54.83 => "54.83%",
-		54.83..=54.84 => "54.84%",
-		54.84..=54.85 => "54.85%",
-		54.85..=54.86 => "54.86%",
-		54.86..=54.87 => "54.87%",
-		54.87..=54.88 => "54.88%",
-		54.88..=54.89 => "54.89%",
-		54.89..=54.90 => "54.90%",
-		54.90..=54.91 => "54.91%",
-		54.91..=54.92 => "54.92%",
-		54.92..=54.93 => "54.93%",
-		54.93..=54.94 => "54.94%",
-		54.94..=54.95 => "54.95%",
-		54.95..=54.96 => "54.96%",
-		54.96..=54.97 => "54.97%",
-		54.97..=54.98 => "54.98%",
-		54.98..=54.99 => "54.99%",
-		54.99..=55.00 => "55.00%",
-		55.00..=55.01 => "55.01%",
-		55.01..=55.02 => "55.02%",
-		55.02..=55.03 => "55.03%",
-		55.03..=55.04 => "55.04%",
-		55.04..=55.05 => "55.05%",
-		55.05..=55.06 => "55.06%",
-		55.06..=55.07 => "55.07%",
-		55.07..=55.08 => "55.08%",
-		55.08..=55.09 => "55.09%",
-		55.09..=55.10 => "55.10%",
-		55.10..=55.11 => "55.11%",
-		55.11..=55.12 => "55.12%",
-		55.12..=55.13 => "55.13%",
-		55.13..=55.14 => "55.14%",
-		55.14..=55.15 => "55.15%",
-		55.15..=55.16 => "55.16%",
-		55.16..=55.17 => "55.17%",
-		55.17..=55.18 => "55.18%",
-		55.18..=55.19 => "55.19%",
-		55.19..=55.20 => "55.20%",
-		55.20..=55.21 => "55.21%",
-		55.21..=55.22 => "55.22%",
-		55.22..=55.23 => "55.23%",
-		55.23..=55.24 => "55.24%",
-		55.24..=55.25 => "55.25%",
-		55.25..=55.26 => "55.26%",
-		55.26..=55.27 => "55.27%",
-		55.27..=55.28 => "55.28%",
-		55.28..=55.29 => "55.29%",
-		55.29..=55.30 => "55.30%",
-		55.30..=55.31 => "55.31%",
-		55.31..=55.32 => "55.32%",
-		55.32..=55.33 => "55.33%",
-		55.33..=55.34 => "55.34%",
-		55.34..=55.35 => "55.35%",
-		55.35..=55.36 => "55.36%",
-		55.36..=55.37 => "55.37%",
-		55.37..=55.38 => "55.38%",
-		55.38..=55.39 => "55.39%",
-		55.39..=55.40 => "55.40%",
-		55.40..=55.41 => "55.41%",
-		55.41..=55.42 => "55.42%",
-		55.42..=55.43 => "55.43%",
-		55.43..=55.44 => "55.44%",
-		55.44..=55.45 => "55.45%",
-		55.45..=55.46 => "55.46%",
-		55.46..=55.47 => "55.47%",
-		55.47..=55.48 => "55.48%",
-		55.48..=55.49 => "55.49%",
-		55.49..=55.50 => "55.50%",
-		55.50..=55.51 => "55.51%",
-		55.51..=55.52 => "55.52%",
-		55.52..=55.53 => "55.53%",
-		55.53..=55.54 => "55.54%",
-		55.54..=55.55 => "55.55%",
-		55.55..=55.56 => "55.56%",
-		55.56..=55.57 => "55.57%",
-		55.57..=55.58 => "55.58%",
-		55.58..=55.59 => "55.59%",
-		55.59..=55.60 => "55.60%",
-		55.60..=55.61 => "55.61%",
-		55.61..=55.62 => "55.62%",
-		55.62..=55.63 => "55.63%",
-		55.63..=55.64 => "55.64%",
-		55.64..=55.65 => "55.65%",
-		55.65..=55.66 => "55.66%",
-		55.66..=55.67 => "55.67%",
-		55.67..=55.68 => "55.68%",
-		55.68..=55.69 => "55.69%",
-		55.69..=55.70 => "55.70%",
-		55.70..=55.71 => "55.71%",
-		55.71..=55.72 => "55.72%",
-		55.72..=55.73 => "55.73%",
-		55.73..=55.74 => "55.74%",
-		55.74..=55.75 => "55.75%",
-		55.75..=55.76 => "55.76%",
-		55.76..=55.77 => "55.77%",
-		55.77..=55.78 => "55.78%",
-		55.78..=55.79 => "55.79%",
-		55.79..=55.80 => "55.80%",
-		55.80..=55.81 => "55.81%",
-		55.81..=55.82 => "55.82%",
-		55.82..=55.83 => "55.83%",
-		55.83..=55.84 => "55.84%",
-		55.84..=55.85 => "55.85%",
-		55.85..=55.86 => "55.86%",
-		55.86..=55.87 => "55.87%",
-		55.87..=55.88 => "55.88%",
-		55.88..=55.89 => "55.89%",
-		55.89..=55.90 => "55.90%",
-		55.90..=55.91 => "55.91%",
-		55.91..=55.92 => "55.92%",
-		55.92..=55.93 => "55.93%",
-		55.93..=55.94 => "55.94%",
-		55.94..=55.95 => "55.95%",
-		55.95..=55.96 => "55.96%",
-		55.96..=55.97 => "55.97%",
-		55.97..=55.98 => "55.98%",
-		55.98..=55.99 => "55.99%",
-		55.99..=56.00 => "56.00%",
-		56.00..=56.01 => "56.01%",
-		56.01..=56.02 => "56.02%",
-		56.02..=56.03 => "56.03%",
-		56.03..=56.04 => "56.04%",
-		56.04..=56.05 => "56.05%",
-		56.05..=56.06 => "56.06%",
-		56.06..=56.07 => "56.07%",
-		56.07..=56.08 => "56.08%",
-		56.08..=56.09 => "56.09%",
-		56.09..=56.10 => "56.10%",
-		56.10..=56.11 => "56.11%",
-		56.11..=56.12 => "56.12%",
-		56.12..=56.13 => "56.13%",
-		56.13..=56.14 => "56.14%",
-		56.14..=56.15 => "56.15%",
-		56.15..=56.16 => "56.16%",
-		56.16..=56.17 => "56.17%",
-		56.17..=56.18 => "56.18%",
-		56.18..=56.19 => "56.19%",
-		56.19..=56.20 => "56.20%",
-		56.20..=56.21 => "56.21%",
-		56.21..=56.22 => "56.22%",
-		56.22..=56.23 => "56.23%",
-		56.23..=56.24 => "56.24%",
-		56.24..=56.25 => "56.25%",
-		56.25..=56.26 => "56.26%",
-		56.26..=56.27 => "56.27%",
-		56.27..=56.28 => "56.28%",
-		56.28..=56.29 => "56.29%",
-		56.29..=56.30 => "56.30%",
-		56.30..=56.31 => "56.31%",
-		56.31..=56.32 => "56.32%",
-		56.32..=56.33 => "56.33%",
-		56.33..=56.34 => "56.34%",
-		56.34..=56.35 => "56.35%",
-		56.35..=56.36 => "56.36%",
-		56.36..=56.37 => "56.37%",
-		56.37..=56.38 => "56.38%",
-		56.38..=56.39 => "56.39%",
-		56.39..=56.40 => "56.40%",
-		56.40..=56.41 => "56.41%",
-		56.41..=56.42 => "56.42%",
-		56.42..=56.43 => "56.43%",
-		56.43..=56.44 => "56.44%",
-		56.44..=56.45 => "56.45%",
-		56.45..=56.46 => "56.46%",
-		56.46..=56.47 => "56.47%",
-		56.47..=56.48 => "56.48%",
-		56.48..=56.49 => "56.49%",
-		56.49..=56.50 => "56.50%",
-		56.50..=56.51 => "56.51%",
-		56.51..=56.52 => "56.52%",
-		56.52..=56.53 => "56.53%",
-		56.53..=56.54 => "56.54%",
-		56.54..=56.55 => "56.55%",
-		56.55..=56.56 => "56.56%",
-		56.56..=56.57 => "56.57%",
-		56.57..=56.58 => "56.58%",
-		56.58..=56.59 => "56.59%",
-		56.59..=56.60 => "56.60%",
-		56.60..=56.61 => "56.61%",
-		56.61..=56.62 => "56.62%",
-		56.62..=56.63 => "56.63%",
-		56.63..=56.64 => "56.64%",
-		56.64..=56.65 => "56.65%",
-		56.65..=56.66 => "56.66%",
-		56.66..=56.67 => "56.67%",
-		56.67..=56.68 => "56.68%",
-		56.68..=56.69 => "56.69%",
-		56.69..=56.70 => "56.70%",
-		56.70..=56.71 => "56.71%",
-		56.71..=56.72 => "56.72%",
-		56.72..=56.73 => "56.73%",
-		56.73..=56.74 => "56.74%",
-		56.74..=56.75 => "56.75%",
-		56.75..=56.76 => "56.76%",
-		56.76..=56.77 => "56.77%",
-		56.77..=56.78 => "56.78%",
-		56.78..=56.79 => "56.79%",
-		56.79..=56.80 => "56.80%",
-		56.80..=56.81 => "56.81%",
-		56.81..=56.82 => "56.82%",
-		56.82..=56.83 => "56.83%",
-		56.83..=56.84 => "56.84%",
-		56.84..=56.85 => "56.85%",
-		56.85..=56.86 => "56.86%",
-		56.86..=56.87 => "56.87%",
-		56.87..=56.88 => "56.88%",
-		56.88..=56.89 => "56.89%",
-		56.89..=56.90 => "56.90%",
-		56.90..=56.91 => "56.91%",
-		56.91..=56.92 => "56.92%",
-		56.92..=56.93 => "56.93%",
-		56.93..=56.94 => "56.94%",
-		56.94..=56.95 => "56.95%",
-		56.95..=56.96 => "56.96%",
-		56.96..=56.97 => "56.97%",
-		56.97..=56.98 => "56.98%",
-		56.98..=56.99 => "56.99%",
-		56.99..=57.00 => "57.00%",
-		57.00..=57.01 => "57.01%",
-		57.01..=57.02 => "57.02%",
-		57.02..=57.03 => "57.03%",
-		57.03..=57.04 => "57.04%",
-		57.04..=57.05 => "57.05%",
-		57.05..=57.06 => "57.06%",
-		57.06..=57.07 => "57.07%",
-		57.07..=57.08 => "57.08%",
-		57.08..=57.09 => "57.09%",
-		57.09..=57.10 => "57.10%",
-		57.10..=57.11 => "57.11%",
-		57.11..=57.12 => "57.12%",
-		57.12..=57.13 => "57.13%",
-		57.13..=57.14 => "57.14%",
-		57.14..=57.15 => "57.15%",
-		57.15..=57.16 => "57.16%",
-		57.16..=57.17 => "57.17%",
-		57.17..=57.18 => "57.18%",
-		57.18..=57.19 => "57.19%",
-		57.19..=57.20 => "57.20%",
-		57.20..=57.21 => "57.21%",
-		57.21..=57.22 => "57.22%",
-		57.22..=57.23 => "57.23%",
-		57.23..=57.24 => "57.24%",
-		57.24..=57.25 => "57.25%",
-		57.25..=57.26 => "57.26%",
-		57.26..=57.27 => "57.27%",
-		57.27..=57.28 => "57.28%",
-		57.28..=57.29 => "57.29%",
-		57.29..=57.30 => "57.30%",
-		57.30..=57.31 => "57.31%",
-		57.31..=57.32 => "57.32%",
-		57.32..=57.33 => "57.33%",
-		57.33..=57.34 => "57.34%",
-		57.34..=57.35 => "57.35%",
-		57.35..=57.36 => "57.36%",
-		57.36..=57.37 => "57.37%",
-		57.37..=57.38 => "57.38%",
-		57.38..=57.39 => "57.39%",
-		57.39..=57.40 => "57.40%",
-		57.40..=57.41 => "57.41%",
-		57.41..=57.42 => "57.42%",
-		57.42..=57.43 => "57.43%",
-		57.43..=57.44 => "57.44%",
-		57.44..=57.45 => "57.45%",
-		57.45..=57.46 => "57.46%",
-		57.46..=57.47 => "57.47%",
-		57.47..=57.48 => "57.48%",
-		57.48..=57.49 => "57.49%",
-		57.49..=57.50 => "57.50%",
-		57.50..=57.51 => "57.51%",
-		57.51..=57.52 => "57.52%",
-		57.52..=57.53 => "57.53%",
-		57.53..=57.54 => "57.54%",
-		57.54..=57.55 => "57.55%",
-		57.55..=57.56 => "57.56%",
-		57.56..=57.57 => "57.57%",
-		57.57..=57.58 => "57.58%",
-		57.58..=57.59 => "57.59%",
-		57.59..=57.60 => "57.60%",
-		57.60..=57.61 => "57.61%",
-		57.61..=57.62 => "57.62%",
-		57.62..=57.63 => "57.63%",
-		57.63..=57.64 => "57.64%",
-		57.64..=57.65 => "57.65%",
-		57.65..=57.66 => "57.66%",
-		57.66..=57.67 => "57.67%",
-		57.67..=57.68 => "57.68%",
-		57.68..=57.69 => "57.69%",
-		57.69..=57.70 => "57.70%",
-		57.70..=57.71 => "57.71%",
-		57.71..=57.72 => "57.72%",
-		57.72..=57.73 => "57.73%",
-		57.73..=57.74 => "57.74%",
-		57.74..=57.75 => "57.75%",
-		57.75..=57.76 => "57.76%",
-		57.76..=57.77 => "57.77%",
-		57.77..=57.78 => "57.78%",
-		57.78..=57.79 => "57.79%",
-		57.79..=57.80 => "57.80%",
-		57.80..=57.81 => "57.81%",
-		57.81..=57.82 => "57.82%",
-		57.82..=57.83 => "57.83%",
-		57.83..=57.84 => "57.84%",
-		57.84..=57.85 => "57.85%",
-		57.85..=57.86 => "57.86%",
-		57.86..=57.87 => "57.87%",
-		57.87..=57.88 => "57.88%",
-		57.88..=57.89 => "57.89%",
-		57.89..=57.90 => "57.90%",
-		57.90..=57.91 => "57.91%",
-		57.91..=57.92 => "57.92%",
-		57.92..=57.93 => "57.93%",
-		57.93..=57.94 => "57.94%",
-		57.94..=57.95 => "57.95%",
-		57.95..=57.96 => "57.96%",
-		57.96..=57.97 => "57.97%",
-		57.97..=57.98 => "57.98%",
-		57.98..=57.99 => "57.99%",
-		57.99..=58.00 => "58.00%",
-		58.00..=58.01 => "58.01%",
-		58.01..=58.02 => "58.02%",
-		58.02..=58.03 => "58.03%",
-		58.03..=58.04 => "58.04%",
-		58.04..=58.05 => "58.05%",
-		58.05..=58.06 => "58.06%",
-		58.06..=58.07 => "58.07%",
-		58.07..=58.08 => "58.08%",
-		58.08..=58.09 => "58.09%",
-		58.09..=58.10 => "58.10%",
-		58.10..=58.11 => "58.11%",
-		58.11..=58.12 => "58.12%",
-		58.12..=58.13 => "58.13%",
-		58.13..=58.14 => "58.14%",
-		58.14..=58.15 => "58.15%",
-		58.15..=58.16 => "58.16%",
-		58.16..=58.17 => "58.17%",
-		58.17..=58.18 => "58.18%",
-		58.18..=58.19 => "58.19%",
-		58.19..=58.20 => "58.20%",
-		58.20..=58.21 => "58.21%",
-		58.21..=58.22 => "58.22%",
-		58.22..=58.23 => "58.23%",
-		58.23..=58.24 => "58.24%",
-		58.24..=58.25 => "58.25%",
-		58.25..=58.26 => "58.26%",
-		58.26..=58.27 => "58.27%",
-		58.27..=58.28 => "58.28%",
-		58.28..=58.29 => "58.29%",
-		58.29..=58.30 => "58.30%",
-		58.30..=58.31 => "58.31%",
-		58.31..=58.32 => "58.32%",
-		58.32..=58.33 => "58.33%",
-		58.33..=58.34 => "58.34%",
-		58.34..=58.35 => "58.35%",
-		58.35..=58.36 => "58.36%",
-		58.36..=58.37 => "58.37%",
-		58.37..=58.38 => "58.38%",
-		58.38..=58.39 => "58.39%",
-		58.39..=58.40 => "58.40%",
-		58.40..=58.41 => "58.41%",
-		58.41..=58.42 => "58.42%",
-		58.42..=58.43 => "58.43%",
-		58.43..=58.44 => "58.44%",
-		58.44..=58.45 => "58.45%",
-		58.45..=58.46 => "58.46%",
-		58.46..=58.47 => "58.47%",
-		58.47..=58.48 => "58.48%",
-		58.48..=58.49 => "58.49%",
-		58.49..=58.50 => "58.50%",
-		58.50..=58.51 => "58.51%",
-		58.51..=58.52 => "58.52%",
-		58.52..=58.53 => "58.53%",
-		58.53..=58.54 => "58.54%",
-		58.54..=58.55 => "58.55%",
-		58.55..=58.56 => "58.56%",
-		58.56..=58.57 => "58.57%",
-		58.57..=58.58 => "58.58%",
-		58.58..=58.59 => "58.59%",
-		58.59..=58.60 => "58.60%",
-		58.60..=58.61 => "58.61%",
-		58.61..=58.62 => "58.62%",
-		58.62..=58.63 => "58.63%",
-		58.63..=58.64 => "58.64%",
-		58.64..=58.65 => "58.65%",
-		58.65..=58.66 => "58.66%",
-		58.66..=58.67 => "58.67%",
-		58.67..=58.68 => "58.68%",
-		58.68..=58.69 => "58.69%",
-		58.69..=58.70 => "58.70%",
-		58.70..=58.71 => "58.71%",
-		58.71..=58.72 => "58.72%",
-		58.72..=58.73 => "58.73%",
-		58.73..=58.74 => "58.74%",
-		58.74..=58.75 => "58.75%",
-		58.75..=58.76 => "58.76%",
-		58.76..=58.77 => "58.77%",
-		58.77..=58.78 => "58.78%",
-		58.78..=58.79 => "58.79%",
-		58.79..=58.80 => "58.80%",
-		58.80..=58.81 => "58.81%",
-		58.81..=58.82 => "58.82%",
-		58.82..=58.83 => "58.83%",
-		58.83..=58.84 => "58.84%",
-		58.84..=58.85 => "58.85%",
-		58.85..=58.86 => "58.86%",
-		58.86..=58.87 => "58.87%",
-		58.87..=58.88 => "58.88%",
-		58.88..=58.89 => "58.89%",
-		58.89..=58.90 => "58.90%",
-		58.90..=58.91 => "58.91%",
-		58.91..=58.92 => "58.92%",
-		58.92..=58.93 => "58.93%",
-		58.93..=58.94 => "58.94%",
-		58.94..=58.95 => "58.95%",
-		58.95..=58.96 => "58.96%",
-		58.96..=58.97 => "58.97%",
-		58.97..=58.98 => "58.98%",
-		58.98..=58.99 => "58.99%",
-		58.99..=59.00 => "59.00%",
-		59.00..=59.01 => "59.01%",
-		59.01..=59.02 => "59.02%",
-		59.02..=59.03 => "59.03%",
-		59.03..=59.04 => "59.04%",
-		59.04..=59.05 => "59.05%",
-		59.05..=59.06 => "59.06%",
-		59.06..=59.07 => "59.07%",
-		59.07..=59.08 => "59.08%",
-		59.08..=59.09 => "59.09%",
-		59.09..=59.10 => "59.10%",
-		59.10..=59.11 => "59.11%",
-		59.11..=59.12 => "59.12%",
-		59.12..=59.13 => "59.13%",
-		59.13..=59.14 => "59.14%",
-		59.14..=59.15 => "59.15%",
-		59.15..=59.16 => "59.16%",
-		59.16..=59.17 => "59.17%",
-		59.17..=59.18 => "59.18%",
-		59.18..=59.19 => "59.19%",
-		59.19..=59.20 => "59.20%",
-		59.20..=59.21 => "59.21%",
-		59.21..=59.22 => "59.22%",
-		59.22..=59.23 => "59.23%",
-		59.23..=59.24 => "59.24%",
-		59.24..=59.25 => "59.25%",
-		59.25..=59.26 => "59.26%",
-		59.26..=59.27 => "59.27%",
-		59.27..=59.28 => "59.28%",
-		59.28..=59.29 => "59.29%",
-		59.29..=59.30 => "59.30%",
-		59.30..=59.31 => "59.31%",
-		59.31..=59.32 => "59.32%",
-		59.32..=59.33 => "59.33%",
-		59.33..=59.34 => "59.34%",
-		59.34..=59.35 => "59.35%",
-		59.35..=59.36 => "59.36%",
-		59.36..=59.37 => "59.37%",
-		59.37..=59.38 => "59.38%",
-		59.38..=59.39 => "59.39%",
-		59.39..=59.40 => "59.40%",
-		59.40..=59.41 => "59.41%",
-		59.41..=59.42 => "59.42%",
-		59.42..=59.43 => "59.43%",
-		59.43..=59.44 => "59.44%",
-		59.44..=59.45 => "59.45%",
-		59.45..=59.46 => "59.46%",
-		59.46..=59.47 => "59.47%",
-		59.47..=59.48 => "59.48%",
-		59.48..=59.49 => "59.49%",
-		59.49..=59.50 => "59.50%",
-		59.50..=59.51 => "59.51%",
-		59.51..=59.52 => "59.52%",
-		59.52..=59.53 => "59.53%",
-		59.53..=59.54 => "59.54%",
-		59.54..=59.55 => "59.55%",
-		59.55..=59.56 => "59.56%",
-		59.56..=59.57 => "59.57%",
-		59.57..=59.58 => "59.58%",
-		59.58..=59.59 => "59.59%",
-		59.59..=59.60 => "59.60%",
-		59.60..=59.61 => "59.61%",
-		59.61..=59.62 => "59.62%",
-		59.62..=59.63 => "59.63%",
-		59.63..=59.64 => "59.64%",
-		59.64..=59.65 => "59.65%",
-		59.65..=59.66 => "59.66%",
-		59.66..=59.67 => "59.67%",
-		59.67..=59.68 => "59.68%",
-		59.68..=59.69 => "59.69%",
-		59.69..=59.70 => "59.70%",
-		59.70..=59.71 => "59.71%",
-		59.71..=59.72 => "59.72%",
-		59.72..=59.73 => "59.73%",
-		59.73..=59.74 => "59.74%",
-		59.74..=59.75 => "59.75%",
-		59.75..=59.76 => "59.76%",
-		59.76..=59.77 => "59.77%",
-		59.77..=59.78 => "59.78%",
-		59.78..=59.79 => "59.79%",
-		59.79..=59.80 => "59.80%",
-		59.80..=59.81 => "59.81%",
-		59.81..=59.82 => "59.82%",
-		59.82..=59.83 => "59.83%",
-		59.83..=59.84 => "59.84%",
-		59.84..=59.85 => "59.85%",
-		59.85..=59.86 => "59.86%",
-		59.86..=59.87 => "59.87%",
-		59.87..=59.88 => "59.88%",
-		59.88..=59.89 => "59.89%",
-		59.89..=59.90 => "59.90%",
-		59.90..=59.91 => "59.91%",
-		59.91..=59.92 => "59.92%",
-		59.92..=59.93 => "59.93%",
-		59.93..=59.94 => "59.94%",
-		59.94..=59.95 => "59.95%",
-		59.95..=59.96 => "59.96%",
-		59.96..=59.97 => "59.97%",
-		59.97..=59.98 => "59.98%",
-		59.98..=59.99 => "59.99%",
-		59.99..=60.00 => "60.00%",
-		60.00..=60.01 => "60.01%",
-		60.01..=60.02 => "60.02%",
-		60.02..=60.03 => "60.03%",
-		60.03..=60.04 => "60.04%",
-		60.04..=60.05 => "60.05%",
-		60.05..=60.06 => "60.06%",
-		60.06..=60.07 => "60.07%",
-		60.07..=60.08 => "60.08%",
-		60.08..=60.09 => "60.09%",
-		60.09..=60.10 => "60.10%",
-		60.10..=60.11 => "60.11%",
-		60.11..=60.12 => "60.12%",
-		60.12..=60.13 => "60.13%",
-		60.13..=60.14 => "60.14%",
-		60.14..=60.15 => "60.15%",
-		60.15..=60.16 => "60.16%",
-		60.16..=60.17 => "60.17%",
-		60.17..=60.18 => "60.18%",
-		60.18..=60.19 => "60.19%",
-		60.19..=60.20 => "60.20%",
-		60.20..=60.21 => "60.21%",
-		60.21..=60.22 => "60.22%",
-		60.22..=60.23 => "60.23%",
-		60.23..=60.24 => "60.24%",
-		60.24..=60.25 => "60.25%",
-		60.25..=60.26 => "60.26%",
-		60.26..=60.27 => "60.27%",
-		60.27..=60.28 => "60.28%",
-		60.28..=60.29 => "60.29%",
-		60.29..=60.30 => "60.30%",
-		60.30..=60.31 => "60.31%",
-		60.31..=60.32 => "60.32%",
-		60.32..=60.33 => "60.33%",
-		60.33..=60.34 => "60.34%",
-		60.34..=60.35 => "60.35%",
-		60.35..=60.36 => "60.36%",
-		60.36..=60.37 => "60.37%",
-		60.37..=60.38 => "60.38%",
-		60.38..=60.39 => "60.39%",
-		60.39..=60.40 => "60.40%",
-		60.40..=60.41 => "60.41%",
-		60.41..=60.42 => "60.42%",
-		60.42..=60.43 => "60.43%",
-		60.43..=60.44 => "60.44%",
-		60.44..=60.45 => "60.45%",
-		60.45..=60.46 => "60.46%",
-		60.46..=60.47 => "60.47%",
-		60.47..=60.48 => "60.48%",
-		60.48..=60.49 => "60.49%",
-		60.49..=60.50 => "60.50%",
-		60.50..=60.51 => "60.51%",
-		60.51..=60.52 => "60.52%",
-		60.52..=60.53 => "60.53%",
-		60.53..=60.54 => "60.54%",
-		60.54..=60.55 => "60.55%",
-		60.55..=60.56 => "60.56%",
-		60.56..=60.57 => "60.57%",
-		60.57..=60.58 => "60.58%",
-		60.58..=60.59 => "60.59%",
-		60.59..=60.60 => "60.60%",
-		60.60..=60.61 => "60.61%",
-		60.61..=60.62 => "60.62%",
-		60.62..=60.63 => "60.63%",
-		60.63..=60.64 => "60.64%",
-		60.64..=60.65 => "60.65%",
-		60.65..=60.66 => "60.66%",
-		60.66..=60.67 => "60.67%",
-		60.67..=60.68 => "60.68%",
-		60.68..=60.69 => "60.69%",
-		60.69..=60.70 => "60.70%",
-		60.70..=60.71 => "60.71%",
-		60.71..=60.72 => "60.72%",
-		60.72..=60.73 => "60.73%",
-		60.73..=60.74 => "60.74%",
-		60.74..=60.75 => "60.75%",
-		60.75..=60.76 => "60.76%",
-		60.76..=60.77 => "60.77%",
-		60.77..=60.78 => "60.78%",
-		60.78..=60.79 => "60.79%",
-		60.79..=60.80 => "60.80%",
-		60.80..=60.81 => "60.81%",
-		60.81..=60.82 => "60.82%",
-		60.82..=60.83 => "60.83%",
-		60.83..=60.84 => "60.84%",
-		60.84..=60.85 => "60.85%",
-		60.85..=60.86 => "60.86%",
-		60.86..=60.87 => "60.87%",
-		60.87..=60.88 => "60.88%",
-		60.88..=60.89 => "60.89%",
-		60.89..=60.90 => "60.90%",
-		60.90..=60.91 => "60.91%",
-		60.91..=60.92 => "60.92%",
-		60.92..=60.93 => "60.93%",
-		60.93..=60.94 => "60.94%",
-		60.94..=60.95 => "60.95%",
-		60.95..=60.96 => "60.96%",
-		60.96..=60.97 => "60.97%",
-		60.97..=60.98 => "60.98%",
-		60.98..=60.99 => "60.99%",
-		60.99..=61.00 => "61.00%",
-		61.00..=61.01 => "61.01%",
-		61.01..=61.02 => "61.02%",
-		61.02..=61.03 => "61.03%",
-		61.03..=61.04 => "61.04%",
-		61.04..=61.05 => "61.05%",
-		61.05..=61.06 => "61.06%",
-		61.06..=61.07 => "61.07%",
-		61.07..=61.08 => "61.08%",
-		61.08..=61.09 => "61.09%",
-		61.09..=61.10 => "61.10%",
-		61.10..=61.11 => "61.11%",
-		61.11..=61.12 => "61.12%",
-		61.12..=61.13 => "61.13%",
-		61.13..=61.14 => "61.14%",
-		61.14..=61.15 => "61.15%",
-		61.15..=61.16 => "61.16%",
-		61.16..=61.17 => "61.17%",
-		61.17..=61.18 => "61.18%",
-		61.18..=61.19 => "61.19%",
-		61.19..=61.20 => "61.20%",
-		61.20..=61.21 => "61.21%",
-		61.21..=61.22 => "61.22%",
-		61.22..=61.23 => "61.23%",
-		61.23..=61.24 => "61.24%",
-		61.24..=61.25 => "61.25%",
-		61.25..=61.26 => "61.26%",
-		61.26..=61.27 => "61.27%",
-		61.27..=61.28 => "61.28%",
-		61.28..=61.29 => "61.29%",
-		61.29..=61.30 => "61.30%",
-		61.30..=61.31 => "61.31%",
-		61.31..=61.32 => "61.32%",
-		61.32..=61.33 => "61.33%",
-		61.33..=61.34 => "61.34%",
-		61.34..=61.35 => "61.35%",
-		61.35..=61.36 => "61.36%",
-		61.36..=61.37 => "61.37%",
-		61.37..=61.38 => "61.38%",
-		61.38..=61.39 => "61.39%",
-		61.39..=61.40 => "61.40%",
-		61.40..=61.41 => "61.41%",
-		61.41..=61.42 => "61.42%",
-		61.42..=61.43 => "61.43%",
-		61.43..=61.44 => "61.44%",
-		61.44..=61.45 => "61.45%",
-		61.45..=61.46 => "61.46%",
-		61.46..=61.47 => "61.47%",
-		61.47..=61.48 => "61.48%",
-		61.48..=61.49 => "61.49%",
-		61.49..=61.50 => "61.50%",
-		61.50..=61.51 => "61.51%",
-		61.51..=61.52 => "61.52%",
-		61.52..=61.53 => "61.53%",
-		61.53..=61.54 => "61.54%",
-		61.54..=61.55 => "61.55%",
-		61.55..=61.56 => "61.56%",
-		61.56..=61.57 => "61.57%",
-		61.57..=61.58 => "61.58%",
-		61.58..=61.59 => "61.59%",
-		61.59..=61.60 => "61.60%",
-		61.60..=61.61 => "61.61%",
-		61.61..=61.62 => "61.62%",
-		61.62..=61.63 => "61.63%",
-		61.63..=61.64 => "61.64%",
-		61.64..=61.65 => "61.65%",
-		61.65..=61.66 => "61.66%",
-		61.66..=61.67 => "61.67%",
-		61.67..=61.68 => "61.68%",
-		61.68..=61.69 => "61.69%",
-		61.69..=61.70 => "61.70%",
-		61.70..=61.71 => "61.71%",
-		61.71..=61.72 => "61.72%",
-		61.72..=61.73 => "61.73%",
-		61.73..=61.74 => "61.74%",
-		61.74..=61.75 => "61.75%",
-		61.75..=61.76 => "61.76%",
-		61.76..=61.77 => "61.77%",
-		61.77..=61.78 => "61.78%",
-		61.78..=61.79 => "61.79%",
-		61.79..=61.80 => "61.80%",
-		61.80..=61.81 => "61.81%",
-		61.81..=61.82 => "61.82%",
-		61.82..=61.83 => "61.83%",
-		61.83..=61.84 => "61.84%",
-		61.84..=61.85 => "61.85%",
-		61.85..=61.86 => "61.86%",
-		61.86..=61.87 => "61.87%",
-		61.87..=61.88 => "61.88%",
-		61.88..=61.89 => "61.89%",
-		61.89..=61.90 => "61.90%",
-		61.90..=61.91 => "61.91%",
-		61.91..=61.92 => "61.92%",
-		61.92..=61.93 => "61.93%",
-		61.93..=61.94 => "61.94%",
-		61.94..=61.95 => "61.95%",
-		61.95..=61.96 => "61.96%",
-		61.96..=61.97 => "61.97%",
-		61.97..=61.98 => "61.98%",
-		61.98..=61.99 => "61.99%",
-		61.99..=62.00 => "62.00%",
-		62.00..=62.01 => "62.01%",
-		62.01..=62.02 => "62.02%",
-		62.02..=62.03 => "62.03%",
-		62.03..=62.04 => "62.04%",
-		62.04..=62.05 => "62.05%",
-		62.05..=62.06 => "62.06%",
-		62.06..=62.07 => "62.07%",
-		62.07..=62.08 => "62.08%",
-		62.08..=62.09 => "62.09%",
-		62.09..=62.10 => "62.10%",
-		62.10..=62.11 => "62.11%",
-		62.11..=62.12 => "62.12%",
-		62.12..=62.13 => "62.13%",
-		62.13..=62.14 => "62.14%",
-		62.14..=62.15 => "62.15%",
-		62.15..=62.16 => "62.16%",
-		62.16..=62.17 => "62.17%",
-		62.17..=62.18 => "62.18%",
-		62.18..=62.19 => "62.19%",
-		62.19..=62.20 => "62.20%",
-		62.20..=62.21 => "62.21%",
-		62.21..=62.22 => "62.22%",
-		62.22..=62.23 => "62.23%",
-		62.23..=62.24 => "62.24%",
-		62.24..=62.25 => "62.25%",
-		62.25..=62.26 => "62.26%",
-		62.26..=62.27 => "62.27%",
-		62.27..=62.28 => "62.28%",
-		62.28..=62.29 => "62.29%",
-		62.29..=62.30 => "62.30%",
-		62.30..=62.31 => "62.31%",
-		62.31..=62.32 => "62.32%",
-		62.32..=62.33 => "62.33%",
-		62.33..=62.34 => "62.34%",
-		62.34..=62.35 => "62.35%",
-		62.35..=62.36 => "62.36%",
-		62.36..=62.37 => "62.37%",
-		62.37..=62.38 => "62.38%",
-		62.38..=62.39 => "62.39%",
-		62.39..=62.40 => "62.40%",
-		62.40..=62.41 => "62.41%",
-		62.41..=62.42 => "62.42%",
-		62.42..=62.43 => "62.43%",
-		62.43..=62.44 => "62.44%",
-		62.44..=62.45 => "62.45%",
-		62.45..=62.46 => "62.46%",
-		62.46..=62.47 => "62.47%",
-		62.47..=62.48 => "62.48%",
-		62.48..=62.49 => "62.49%",
-		62.49..=62.50 => "62.50%",
-		62.50..=62.51 => "62.51%",
-		62.51..=62.52 => "62.52%",
-		62.52..=62.53 => "62.53%",
-		62.53..=62.54 => "62.54%",
-		62.54..=62.55 => "62.55%",
-		62.55..=62.56 => "62.56%",
-		62.56..=62.57 => "62.57%",
-		62.57..=62.58 => "62.58%",
-		62.58..=62.59 => "62.59%",
-		62.59..=62.60 => "62.60%",
-		62.60..=62.61 => "62.61%",
-		62.61..=62.62 => "62.62%",
-		62.62..=62.63 => "62.63%",
-		62.63..=62.64 => "62.64%",
-		62.64..=62.65 => "62.65%",
-		62.65..=62.66 => "62.66%",
-		62.66..=62.67 => "62.67%",
-		62.67..=62.68 => "62.68%",
-		62.68..=62.69 => "62.69%",
-		62.69..=62.70 => "62.70%",
-		62.70..=62.71 => "62.71%",
-		62.71..=62.72 => "62.72%",
-		62.72..=62.73 => "62.73%",
-		62.73..=62.74 => "62.74%",
-		62.74..=62.75 => "62.75%",
-		62.75..=62.76 => "62.76%",
-		62.76..=62.77 => "62.77%",
-		62.77..=62.78 => "62.78%",
-		62.78..=62.79 => "62.79%",
-		62.79..=62.80 => "62.80%",
-		62.80..=62.81 => "62.81%",
-		62.81..=62.82 => "62.82%",
-		62.82..=62.83 => "62.83%",
-		62.83..=62.84 => "62.84%",
-		62.84..=62.85 => "62.85%",
-		62.85..=62.86 => "62.86%",
-		62.86..=62.87 => "62.87%",
-		62.87..=62.88 => "62.88%",
-		62.88..=62.89 => "62.89%",
-		62.89..=62.90 => "62.90%",
-		62.90..=62.91 => "62.91%",
-		62.91..=62.92 => "62.92%",
-		62.92..=62.93 => "62.93%",
-		62.93..=62.94 => "62.94%",
-		62.94..=62.95 => "62.95%",
-		62.95..=62.96 => "62.96%",
-		62.96..=62.97 => "62.97%",
-		62.97..=62.98 => "62.98%",
-		62.98..=62.99 => "62.99%",
-		62.99..=63.00 => "63.00%",
-		63.00..=63.01 => "63.01%",
-		63.01..=63.02 => "63.02%",
-		63.02..=63.03 => "63.03%",
-		63.03..=63.04 => "63.04%",
-		63.04..=63.05 => "63.05%",
-		63.05..=63.06 => "63.06%",
-		63.06..=63.07 => "63.07%",
-		63.07..=63.08 => "63.08%",
-		63.08..=63.09 => "63.09%",
-		63.09..=63.10 => "63.10%",
-		63.10..=63.11 => "63.11%",
-		63.11..=63.12 => "63.12%",
-		63.12..=63.13 => "63.13%",
-		63.13..=63.14 => "63.14%",
-		63.14..=63.15 => "63.15%",
-		63.15..=63.16 => "63.16%",
-		63.16..=63.17 => "63.17%",
-		63.17..=63.18 => "63.18%",
-		63.18..=63.19 => "63.19%",
-		63.19..=63.20 => "63.20%",
-		63.20..=63.21 => "63.21%",
-		63.21..=63.22 => "63.22%",
-		63.22..=63.23 => "63.23%",
-		63.23..=63.24 => "63.24%",
-		63.24..=63.25 => "63.25%",
-		63.25..=63.26 => "63.26%",
-		63.26..=63.27 => "63.27%",
-		63.27..=63.28 => "63.28%",
-		63.28..=63.29 => "63.29%",
-		63.29..=63.30 => "63.30%",
-		63.30..=63.31 => "63.31%",
-		63.31..=63.32 => "63.32%",
-		63.32..=63.33 => "63.33%",
-		63.33..=63.34 => "63.34%",
-		63.34..=63.35 => "63.35%",
-		63.35..=63.36 => "63.36%",
-		63.36..=63.37 => "63.37%",
-		63.37..=63.38 => "63.38%",
-		63.38..=63.39 => "63.39%",
-		63.39..=63.40 => "63.40%",
-		63.40..=63.41 => "63.41%",
-		63.41..=63.42 => "63.42%",
-		63.42..=63.43 => "63.43%",
-		63.43..=63.44 => "63.44%",
-		63.44..=63.45 => "63.45%",
-		63.45..=63.46 => "63.46%",
-		63.46..=63.47 => "63.47%",
-		63.47..=63.48 => "63.48%",
-		63.48..=63.49 => "63.49%",
-		63.49..=63.50 => "63.50%",
-		63.50..=63.51 => "63.51%",
-		63.51..=63.52 => "63.52%",
-		63.52..=63.53 => "63.53%",
-		63.53..=63.54 => "63.54%",
-		63.54..=63.55 => "63.55%",
-		63.55..=63.56 => "63.56%",
-		63.56..=63.57 => "63.57%",
-		63.57..=63.58 => "63.58%",
-		63.58..=63.59 => "63.59%",
-		63.59..=63.60 => "63.60%",
-		63.60..=63.61 => "63.61%",
-		63.61..=63.62 => "63.62%",
-		63.62..=63.63 => "63.63%",
-		63.63..=63.64 => "63.64%",
-		63.64..=63.65 => "63.65%",
-		63.65..=63.66 => "63.66%",
-		63.66..=63.67 => "63.67%",
-		63.67..=63.68 => "63.68%",
-		63.68..=63.69 => "63.69%",
-		63.69..=63.70 => "63.70%",
-		63.70..=63.71 => "63.71%",
-		63.71..=63.72 => "63.72%",
-		63.72..=63.73 => "63.73%",
-		63.73..=63.74 => "63.74%",
-		63.74..=63.75 => "63.75%",
-		63.75..=63.76 => "63.76%",
-		63.76..=63.77 => "63.77%",
-		63.77..=63.78 => "63.78%",
-		63.78..=63.79 => "63.79%",
-		63.79..=63.80 => "63.80%",
-		63.80..=63.81 => "63.81%",
-		63.81..=63.82 => "63.82%",
-		63.82..=63.83 => "63.83%",
-		63.83..=63.84 => "63.84%",
-		63.84..=63.85 => "63.85%",
-		63.85..=63.86 => "63.86%",
-		63.86..=63.87 => "63.87%",
-		63.87..=63.88 => "63.88%",
-		63.88..=63.89 => "63.89%",
-		63.89..=63.90 => "63.90%",
-		63.90..=63.91 => "63.91%",
-		63.91..=63.92 => "63.92%",
-		63.92..=63.93 => "63.93%",
-		63.93..=63.94 => "63.94%",
-		63.94..=63.95 => "63.95%",
-		63.95..=63.96 => "63.96%",
-		63.96..=63.97 => "63.97%",
-		63.97..=63.98 => "63.98%",
-		63.98..=63.99 => "63.99%",
-		63.99..=64.00 => "64.00%",
-		64.00..=64.01 => "64.01%",
-		64.01..=64.02 => "64.02%",
-		64.02..=64.03 => "64.03%",
-		64.03..=64.04 => "64.04%",
-		64.04..=64.05 => "64.05%",
-		64.05..=64.06 => "64.06%",
-		64.06..=64.07 => "64.07%",
-		64.07..=64.08 => "64.08%",
-		64.08..=64.09 => "64.09%",
-		64.09..=64.10 => "64.10%",
-		64.10..=64.11 => "64.11%",
-		64.11..=64.12 => "64.12%",
-		64.12..=64.13 => "64.13%",
-		64.13..=64.14 => "64.14%",
-		64.14..=64.15 => "64.15%",
-		64.15..=64.16 => "64.16%",
-		64.16..=64.17 => "64.17%",
-		64.17..=64.18 => "64.18%",
-		64.18..=64.19 => "64.19%",
-		64.19..=64.20 => "64.20%",
-		64.20..=64.21 => "64.21%",
-		64.21..=64.22 => "64.22%",
-		64.22..=64.23 => "64.23%",
-		64.23..=64.24 => "64.24%",
-		64.24..=64.25 => "64.25%",
-		64.25..=64.26 => "64.26%",
-		64.26..=64.27 => "64.27%",
-		64.27..=64.28 => "64.28%",
-		64.28..=64.29 => "64.29%",
-		64.29..=64.30 => "64.30%",
-		64.30..=64.31 => "64.31%",
-		64.31..=64.32 => "64.32%",
-		64.32..=64.33 => "64.33%",
-		64.33..=64.34 => "64.34%",
-		64.34..=64.35 => "64.35%",
-		64.35..=64.36 => "64.36%",
-		64.36..=64.37 => "64.37%",
-		64.37..=64.38 => "64.38%",
-		64.38..=64.39 => "64.39%",
-		64.39..=64.40 => "64.40%",
-		64.40..=64.41 => "64.41%",
-		64.41..=64.42 => "64.42%",
-		64.42..=64.43 => "64.43%",
-		64.43..=64.44 => "64.44%",
-		64.44..=64.45 => "64.45%",
-		64.45..=64.46 => "64.46%",
-		64.46..=64.47 => "64.47%",
-		64.47..=64.48 => "64.48%",
-		64.48..=64.49 => "64.49%",
-		64.49..=64.50 => "64.50%",
-		64.50..=64.51 => "64.51%",
-		64.51..=64.52 => "64.52%",
-		64.52..=64.53 => "64.53%",
-		64.53..=64.54 => "64.54%",
-		64.54..=64.55 => "64.55%",
-		64.55..=64.56 => "64.56%",
-		64.56..=64.57 => "64.57%",
-		64.57..=64.58 => "64.58%",
-		64.58..=64.59 => "64.59%",
-		64.59..=64.60 => "64.60%",
-		64.60..=64.61 => "64.61%",
-		64.61..=64.62 => "64.62%",
-		64.62..=64.63 => "64.63%",
-		64.63..=64.64 => "64.64%",
-		64.64..=64.65 => "64.65%",
-		64.65..=64.66 => "64.66%",
-		64.66..=64.67 => "64.67%",
-		64.67..=64.68 => "64.68%",
-		64.68..=64.69 => "64.69%",
-		64.69..=64.70 => "64.70%",
-		64.70..=64.71 => "64.71%",
-		64.71..=64.72 => "64.72%",
-		64.72..=64.73 => "64.73%",
-		64.73..=64.74 => "64.74%",
-		64.74..=64.75 => "64.75%",
-		64.75..=64.76 => "64.76%",
-		64.76..=64.77 => "64.77%",
-		64.77..=64.78 => "64.78%",
-		64.78..=64.79 => "64.79%",
-		64.79..=64.80 => "64.80%",
-		64.80..=64.81 => "64.81%",
-		64.81..=64.82 => "64.82%",
-		64.82..=64.83 => "64.83%",
-		64.83..=64.84 => "64.84%",
-		64.84..=64.85 => "64.85%",
-		64.85..=64.86 => "64.86%",
-		64.86..=64.87 => "64.87%",
-		64.87..=64.88 => "64.88%",
-		64.88..=64.89 => "64.89%",
-		64.89..=64.90 => "64.90%",
-		64.90..=64.91 => "64.91%",
-		64.91..=64.92 => "64.92%",
-		64.92..=64.93 => "64.93%",
-		64.93..=64.94 => "64.94%",
-		64.94..=64.95 => "64.95%",
-		64.95..=64.96 => "64.96%",
-		64.96..=64.97 => "64.97%",
-		64.97..=64.98 => "64.98%",
-		64.98..=64.99 => "64.99%",
-		64.99..=65.00 => "65.00%",
-		65.00..=65.01 => "65.01%",
-		65.01..=65.02 => "65.02%",
-		65.02..=65.03 => "65.03%",
-		65.03..=65.04 => "65.04%",
-		65.04..=65.05 => "65.05%",
-		65.05..=65.06 => "65.06%",
-		65.06..=65.07 => "65.07%",
-		65.07..=65.08 => "65.08%",
-		65.08..=65.09 => "65.09%",
-		65.09..=65.10 => "65.10%",
-		65.10..=65.11 => "65.11%",
-		65.11..=65.12 => "65.12%",
-		65.12..=65.13 => "65.13%",
-		65.13..=65.14 => "65.14%",
-		65.14..=65.15 => "65.15%",
-		65.15..=65.16 => "65.16%",
-		65.16..=65.17 => "65.17%",
-		65.17..=65.18 => "65.18%",
-		65.18..=65.19 => "65.19%",
-		65.19..=65.20 => "65.20%",
-		65.20..=65.21 => "65.21%",
-		65.21..=65.22 => "65.22%",
-		65.22..=65.23 => "65.23%",
-		65.23..=65.24 => "65.24%",
-		65.24..=65.25 => "65.25%",
-		65.25..=65.26 => "65.26%",
-		65.26..=65.27 => "65.27%",
-		65.27..=65.28 => "65.28%",
-		65.28..=65.29 => "65.29%",
-		65.29..=65.30 => "65.30%",
-		65.30..=65.31 => "65.31%",
-		65.31..=65.32 => "65.32%",
-		65.32..=65.33 => "65.33%",
-		65.33..=65.34 => "65.34%",
-		65.34..=65.35 => "65.35%",
-		65.35..=65.36 => "65.36%",
-		65.36..=65.37 => "65.37%",
-		65.37..=65.38 => "65.38%",
-		65.38..=65.39 => "65.39%",
-		65.39..=65.40 => "65.40%",
-		65.40..=65.41 => "65.41%",
-		65.41..=65.42 => "65.42%",
-		65.42..=65.43 => "65.43%",
-		65.43..=65.44 => "65.44%",
-		65.44..=65.45 => "65.45%",
-		65.45..=65.46 => "65.46%",
-		65.46..=65.47 => "65.47%",
-		65.47..=65.48 => "65.48%",
-		65.48..=65.49 => "65.49%",
-		65.49..=65.50 => "65.50%",
-		65.50..=65.51 => "65.51%",
-		65.51..=65.52 => "65.52%",
-		65.52..=65.53 => "65.53%",
-		65.53..=65.54 => "65.54%",
-		65.54..=65.55 => "65.55%",
-		65.55..=65.56 => "65.56%",
-		65.56..=65.57 => "65.57%",
-		65.57..=65.58 => "65.58%",
-		65.58..=65.59 => "65.59%",
-		65.59..=65.60 => "65.60%",
-		65.60..=65.61 => "65.61%",
-		65.61..=65.62 => "65.62%",
-		65.62..=65.63 => "65.63%",
-		65.63..=65.64 => "65.64%",
-		65.64..=65.65 => "65.65%",
-		65.65..=65.66 => "65.66%",
-		65.66..=65.67 => "65.67%",
-		65.67..=65.68 => "65.68%",
-		65.68..=65.69 => "65.69%",
-		65.69..=65.70 => "65.70%",
-		65.70..=65.71 => "65.71%",
-		65.71..=65.72 => "65.72%",
-		65.72..=65.73 => "65.73%",
-		65.73..=65.74 => "65.74%",
-		65.74..=65.75 => "65.75%",
-		65.75..=65.76 => "65.76%",
-		65.76..=65.77 => "65.77%",
-		65.77..=65.78 => "65.78%",
-		65.78..=65.79 => "65.79%",
-		65.79..=65.80 => "65.80%",
-		65.80..=65.81 => "65.81%",
-		65.81..=65.82 => "65.82%",
-		65.82..=65.83 => "65.83%",
-		65.83..=65.84 => "65.84%",
-		65.84..=65.85 => "65.85%",
-		65.85..=65.86 => "65.86%",
-		65.86..=65.87 => "65.87%",
-		65.87..=65.88 => "65.88%",
-		65.88..=65.89 => "65.89%",
-		65.89..=65.90 => "65.90%",
-		65.90..=65.91 => "65.91%",
-		65.91..=65.92 => "65.92%",
-		65.92..=65.93 => "65.93%",
-		65.93..=65.94 => "65.94%",
-		65.94..=65.95 => "65.95%",
-		65.95..=65.96 => "65.96%",
-		65.96..=65.97 => "65.97%",
-		65.97..=65.98 => "65.98%",
-		65.98..=65.99 => "65.99%",
-		65.99..=66.00 => "66.00%",
-		66.00..=66.01 => "66.01%",
-		66.01..=66.02 => "66.02%",
-		66.02..=66.03 => "66.03%",
-		66.03..=66.04 => "66.04%",
-		66.04..=66.05 => "66.05%",
-		66.05..=66.06 => "66.06%",
-		66.06..=66.07 => "66.07%",
-		66.07..=66.08 => "66.08%",
-		66.08..=66.09 => "66.09%",
-		66.09..=66.10 => "66.10%",
-		66.10..=66.11 => "66.11%",
-		66.11..=66.12 => "66.12%",
-		66.12..=66.13 => "66.13%",
-		66.13..=66.14 => "66.14%",
-		66.14..=66.15 => "66.15%",
-		66.15..=66.16 => "66.16%",
-		66.16..=66.17 => "66.17%",
-		66.17..=66.18 => "66.18%",
-		66.18..=66.19 => "66.19%",
-		66.19..=66.20 => "66.20%",
-		66.20..=66.21 => "66.21%",
-		66.21..=66.22 => "66.22%",
-		66.22..=66.23 => "66.23%",
-		66.23..=66.24 => "66.24%",
-		66.24..=66.25 => "66.25%",
-		66.25..=66.26 => "66.26%",
-		66.26..=66.27 => "66.27%",
-		66.27..=66.28 => "66.28%",
-		66.28..=66.29 => "66.29%",
-		66.29..=66.30 => "66.30%",
-		66.30..=66.31 => "66.31%",
-		66.31..=66.32 => "66.32%",
-		66.32..=66.33 => "66.33%",
-		66.33..=66.34 => "66.34%",
-		66.34..=66.35 => "66.35%",
-		66.35..=66.36 => "66.36%",
-		66.36..=66.37 => "66.37%",
-		66.37..=66.38 => "66.38%",
-		66.38..=66.39 => "66.39%",
-		66.39..=66.40 => "66.40%",
-		66.40..=66.41 => "66.41%",
-		66.41..=66.42 => "66.42%",
-		66.42..=66.43 => "66.43%",
-		66.43..=66.44 => "66.44%",
-		66.44..=66.45 => "66.45%",
-		66.45..=66.46 => "66.46%",
-		66.46..=66.47 => "66.47%",
-		66.47..=66.48 => "66.48%",
-		66.48..=66.49 => "66.49%",
-		66.49..=66.50 => "66.50%",
-		66.50..=66.51 => "66.51%",
-		66.51..=66.52 => "66.52%",
-		66.52..=66.53 => "66.53%",
-		66.53..=66.54 => "66.54%",
-		66.54..=66.55 => "66.55%",
-		66.55..=66.56 => "66.56%",
-		66.56..=66.57 => "66.57%",
-		66.57..=66.58 => "66.58%",
-		66.58..=66.59 => "66.59%",
-		66.59..=66.60 => "66.60%",
-		66.60..=66.61 => "66.61%",
-		66.61..=66.62 => "66.62%",
-		66.62..=66.63 => "66.63%",
-		66.63..=66.64 => "66.64%",
-		66.64..=66.65 => "66.65%",
-		66.65..=66.66 => "66.66%",
-		66.66..=66.67 => "66.67%",
-		66.67..=66.68 => "66.68%",
-		66.68..=66.69 => "66.69%",
-		66.69..=66.70 => "66.70%",
-		66.70..=66.71 => "66.71%",
-		66.71..=66.72 => "66.72%",
-		66.72..=66.73 => "66.73%",
-		66.73..=66.74 => "66.74%",
-		66.74..=66.75 => "66.75%",
-		66.75..=66.76 => "66.76%",
-		66.76..=66.77 => "66.77%",
-		66.77..=66.78 => "66.78%",
-		66.78..=66.79 => "66.79%",
-		66.79..=66.80 => "66.80%",
-		66.80..=66.81 => "66.81%",
-		66.81..=66.82 => "66.82%",
-		66.82..=66.83 => "66.83%",
-		66.83..=66.84 => "66.84%",
-		66.84..=66.85 => "66.85%",
-		66.85..=66.86 => "66.86%",
-		66.86..=66.87 => "66.87%",
-		66.87..=66.88 => "66.88%",
-		66.88..=66.89 => "66.89%",
-		66.89..=66.90 => "66.90%",
-		66.90..=66.91 => "66.91%",
-		66.91..=66.92 => "66.92%",
-		66.92..=66.93 => "66.93%",
-		66.93..=66.94 => "66.94%",
-		66.94..=66.95 => "66.95%",
-		66.95..=66.96 => "66.96%",
-		66.96..=66.97 => "66.97%",
-		66.97..=66.98 => "66.98%",
-		66.98..=66.99 => "66.99%",
-		66.99..=67.00 => "67.00%",
-		67.00..=67.01 => "67.01%",
-		67.01..=67.02 => "67.02%",
-		67.02..=67.03 => "67.03%",
-		67.03..=67.04 => "67.04%",
-		67.04..=67.05 => "67.05%",
-		67.05..=67.06 => "67.06%",
-		67.06..=67.07 => "67.07%",
-		67.07..=67.08 => "67.08%",
-		67.08..=67.09 => "67.09%",
-		67.09..=67.10 => "67.10%",
-		67.10..=67.11 => "67.11%",
-		67.11..=67.12 => "67.12%",
-		67.12..=67.13 => "67.13%",
-		67.13..=67.14 => "67.14%",
-		67.14..=67.15 => "67.15%",
-		67.15..=67.16 => "67.16%",
-		67.16..=67.17 => "67.17%",
-		67.17..=67.18 => "67.18%",
-		67.18..=67.19 => "67.19%",
-		67.19..=67.20 => "67.20%",
-		67.20..=67.21 => "67.21%",
-		67.21..=67.22 => "67.22%",
-		67.22..=67.23 => "67.23%",
-		67.23..=67.24 => "67.24%",
-		67.24..=67.25 => "67.25%",
-		67.25..=67.26 => "67.26%",
-		67.26..=67.27 => "67.27%",
-		67.27..=67.28 => "67.28%",
-		67.28..=67.29 => "67.29%",
-		67.29..=67.30 => "67.30%",
-		67.30..=67.31 => "67.31%",
-		67.31..=67.32 => "67.32%",
-		67.32..=67.33 => "67.33%",
-		67.33..=67.34 => "67.34%",
-		67.34..=67.35 => "67.35%",
-		67.35..=67.36 => "67.36%",
-		67.36..=67.37 => "67.37%",
-		67.37..=67.38 => "67.38%",
-		67.38..=67.39 => "67.39%",
-		67.39..=67.40 => "67.40%",
-		67.40..=67.41 => "67.41%",
-		67.41..=67.42 => "67.42%",
-		67.42..=67.43 => "67.43%",
-		67.43..=67.44 => "67.44%",
-		67.44..=67.45 => "67.45%",
-		67.45..=67.46 => "67.46%",
-		67.46..=67.47 => "67.47%",
-		67.47..=67.48 => "67.48%",
-		67.48..=67.49 => "67.49%",
-		67.49..=67.50 => "67.50%",
-		67.50..=67.51 => "67.51%",
-		67.51..=67.52 => "67.52%",
-		67.52..=67.53 => "67.53%",
-		67.53..=67.54 => "67.54%",
-		67.54..=67.55 => "67.55%",
-		67.55..=67.56 => "67.56%",
-		67.56..=67.57 => "67.57%",
-		67.57..=67.58 => "67.58%",
-		67.58..=67.59 => "67.59%",
-		67.59..=67.60 => "67.60%",
-		67.60..=67.61 => "67.61%",
-		67.61..=67.62 => "67.62%",
-		67.62..=67.63 => "67.63%",
-		67.63..=67.64 => "67.64%",
-		67.64..=67.65 => "67.65%",
-		67.65..=67.66 => "67.66%",
-		67.66..=67.67 => "67.67%",
-		67.67..=67.68 => "67.68%",
-		67.68..=67.69 => "67.69%",
-		67.69..=67.70 => "67.70%",
-		67.70..=67.71 => "67.71%",
-		67.71..=67.72 => "67.72%",
-		67.72..=67.73 => "67.73%",
-		67.73..=67.74 => "67.74%",
-		67.74..=67.75 => "67.75%",
-		67.75..=67.76 => "67.76%",
-		67.76..=67.77 => "67.77%",
-		67.77..=67.78 => "67.78%",
-		67.78..=67.79 => "67.79%",
-		67.79..=67.80 => "67.80%",
-		67.80..=67.81 => "67.81%",
-		67.81..=67.82 => "67.82%",
-		67.82..=67.83 => "67.83%",
-		67.83..=67.84 => "67.84%",
-		67.84..=67.85 => "67.85%",
-		67.85..=67.86 => "67.86%",
-		67.86..=67.87 => "67.87%",
-		67.87..=67.88 => "67.88%",
-		67.88..=67.89 => "67.89%",
-		67.89..=67.90 => "67.90%",
-		67.90..=67.91 => "67.91%",
-		67.91..=67.92 => "67.92%",
-		67.92..=67.93 => "67.93%",
-		67.93..=67.94 => "67.94%",
-		67.94..=67.95 => "67.95%",
-		67.95..=67.96 => "67.96%",
-		67.96..=67.97 => "67.97%",
-		67.97..=67.98 => "67.98%",
-		67.98..=67.99 => "67.99%",
-		67.99..=68.00 => "68.00%",
-		68.00..=68.01 => "68.01%",
-		68.01..=68.02 => "68.02%",
-		68.02..=68.03 => "68.03%",
-		68.03..=68.04 => "68.04%",
-		68.04..=68.05 => "68.05%",
-		68.05..=68.06 => "68.06%",
-		68.06..=68.07 => "68.07%",
-		68.07..=68.08 => "68.08%",
-		68.08..=68.09 => "68.09%",
-		68.09..=68.10 => "68.10%",
-		68.10..=68.11 => "68.11%",
-		68.11..=68.12 => "68.12%",
-		68.12..=68.13 => "68.13%",
-		68.13..=68.14 => "68.14%",
-		68.14..=68.15 => "68.15%",
-		68.15..=68.16 => "68.16%",
-		68.16..=68.17 => "68.17%",
-		68.17..=68.18 => "68.18%",
-		68.18..=68.19 => "68.19%",
-		68.19..=68.20 => "68.20%",
-		68.20..=68.21 => "68.21%",
-		68.21..=68.22 => "68.22%",
-		68.22..=68.23 => "68.23%",
-		68.23..=68.24 => "68.24%",
-		68.24..=68.25 => "68.25%",
-		68.25..=68.26 => "68.26%",
-		68.26..=68.27 => "68.27%",
-		68.27..=68.28 => "68.28%",
-		68.28..=68.29 => "68.29%",
-		68.29..=68.30 => "68.30%",
-		68.30..=68.31 => "68.31%",
-		68.31..=68.32 => "68.32%",
-		68.32..=68.33 => "68.33%",
-		68.33..=68.34 => "68.34%",
-		68.34..=68.35 => "68.35%",
-		68.35..=68.36 => "68.36%",
-		68.36..=68.37 => "68.37%",
-		68.37..=68.38 => "68.38%",
-		68.38..=68.39 => "68.39%",
-		68.39..=68.40 => "68.40%",
-		68.40..=68.41 => "68.41%",
-		68.41..=68.42 => "68.42%",
-		68.42..=68.43 => "68.43%",
-		68.43..=68.44 => "68.44%",
-		68.44..=68.45 => "68.45%",
-		68.45..=68.46 => "68.46%",
-		68.46..=68.47 => "68.47%",
-		68.47..=68.48 => "68.48%",
-		68.48..=68.49 => "68.49%",
-		68.49..=68.50 => "68.50%",
-		68.50..=68.51 => "68.51%",
-		68.51..=68.52 => "68.52%",
-		68.52..=68.53 => "68.53%",
-		68.53..=68.54 => "68.54%",
-		68.54..=68.55 => "68.55%",
-		68.55..=68.56 => "68.56%",
-		68.56..=68.57 => "68.57%",
-		68.57..=68.58 => "68.58%",
-		68.58..=68.59 => "68.59%",
-		68.59..=68.60 => "68.60%",
-		68.60..=68.61 => "68.61%",
-		68.61..=68.62 => "68.62%",
-		68.62..=68.63 => "68.63%",
-		68.63..=68.64 => "68.64%",
-		68.64..=68.65 => "68.65%",
-		68.65..=68.66 => "68.66%",
-		68.66..=68.67 => "68.67%",
-		68.67..=68.68 => "68.68%",
-		68.68..=68.69 => "68.69%",
-		68.69..=68.70 => "68.70%",
-		68.70..=68.71 => "68.71%",
-		68.71..=68.72 => "68.72%",
-		68.72..=68.73 => "68.73%",
-		68.73..=68.74 => "68.74%",
-		68.74..=68.75 => "68.75%",
-		68.75..=68.76 => "68.76%",
-		68.76..=68.77 => "68.77%",
-		68.77..=68.78 => "68.78%",
-		68.78..=68.79 => "68.79%",
-		68.79..=68.80 => "68.80%",
-		68.80..=68.81 => "68.81%",
-		68.81..=68.82 => "68.82%",
-		68.82..=68.83 => "68.83%",
-		68.83..=68.84 => "68.84%",
-		68.84..=68.85 => "68.85%",
-		68.85..=68.86 => "68.86%",
-		68.86..=68.87 => "68.87%",
-		68.87..=68.88 => "68.88%",
-		68.88..=68.89 => "68.89%",
-		68.89..=68.90 => "68.90%",
-		68.90..=68.91 => "68.91%",
-		68.91..=68.92 => "68.92%",
-		68.92..=68.93 => "68.93%",
-		68.93..=68.94 => "68.94%",
-		68.94..=68.95 => "68.95%",
-		68.95..=68.96 => "68.96%",
-		68.96..=68.97 => "68.97%",
-		68.97..=68.98 => "68.98%",
-		68.98..=68.99 => "68.99%",
-		68.99..=69.00 => "69.00%",
-		69.00..=69.01 => "69.01%",
-		69.01..=69.02 => "69.02%",
-		69.02..=69.03 => "69.03%",
-		69.03..=69.04 => "69.04%",
-		69.04..=69.05 => "69.05%",
-		69.05..=69.06 => "69.06%",
-		69.06..=69.07 => "69.07%",
-		69.07..=69.08 => "69.08%",
-		69.08..=69.09 => "69.09%",
-		69.09..=69.10 => "69.10%",
-		69.10..=69.11 => "69.11%",
-		69.11..=69.12 => "69.12%",
-		69.12..=69.13 => "69.13%",
-		69.13..=69.14 => "69.14%",
-		69.14..=69.15 => "69.15%",
-		69.15..=69.16 => "69.16%",
-		69.16..=69.17 => "69.17%",
-		69.17..=69.18 => "69.18%",
-		69.18..=69.19 => "69.19%",
-		69.19..=69.20 => "69.20%",
-		69.20..=69.21 => "69.21%",
-		69.21..=69.22 => "69.22%",
-		69.22..=69.23 => "69.23%",
-		69.23..=69.24 => "69.24%",
-		69.24..=69.25 => "69.25%",
-		69.25..=69.26 => "69.26%",
-		69.26..=69.27 => "69.27%",
-		69.27..=69.28 => "69.28%",
-		69.28..=69.29 => "69.29%",
-		69.29..=69.30 => "69.30%",
-		69.30..=69.31 => "69.31%",
-		69.31..=69.32 => "69.32%",
-		69.32..=69.33 => "69.33%",
-		69.33..=69.34 => "69.34%",
-		69.34..=69.35 => "69.35%",
-		69.35..=69.36 => "69.36%",
-		69.36..=69.37 => "69.37%",
-		69.37..=69.38 => "69.38%",
-		69.38..=69.39 => "69.39%",
-		69.39..=69.40 => "69.40%",
-		69.40..=69.41 => "69.41%",
-		69.41..=69.42 => "69.42%",
-		69.42..=69.43 => "69.43%",
-		69.43..=69.44 => "69.44%",
-		69.44..=69.45 => "69.45%",
-		69.45..=69.46 => "69.46%",
-		69.46..=69.47 => "69.47%",
-		69.47..=69.48 => "69.48%",
-		69.48..=69.49 => "69.49%",
-		69.49..=69.50 => "69.50%",
-		69.50..=69.51 => "69.51%",
-		69.51..=69.52 => "69.52%",
-		69.52..=69.53 => "69.53%",
-		69.53..=69.54 => "69.54%",
-		69.54..=69.55 => "69.55%",
-		69.55..=69.56 => "69.56%",
-		69.56..=69.57 => "69.57%",
-		69.57..=69.58 => "69.58%",
-		69.58..=69.59 => "69.59%",
-		69.59..=69.60 => "69.60%",
-		69.60..=69.61 => "69.61%",
-		69.61..=69.62 => "69.62%",
-		69.62..=69.63 => "69.63%",
-		69.63..=69.64 => "69.64%",
-		69.64..=69.65 => "69.65%",
-		69.65..=69.66 => "69.66%",
-		69.66..=69.67 => "69.67%",
-		69.67..=69.68 => "69.68%",
-		69.68..=69.69 => "69.69%",
-		69.69..=69.70 => "69.70%",
-		69.70..=69.71 => "69.71%",
-		69.71..=69.72 => "69.72%",
-		69.72..=69.73 => "69.73%",
-		69.73..=69.74 => "69.74%",
-		69.74..=69.75 => "69.75%",
-		69.75..=69.76 => "69.76%",
-		69.76..=69.77 => "69.77%",
-		69.77..=69.78 => "69.78%",
-		69.78..=69.79 => "69.79%",
-		69.79..=69.80 => "69.80%",
-		69.80..=69.81 => "69.81%",
-		69.81..=69.82 => "69.82%",
-		69.82..=69.83 => "69.83%",
-		69.83..=69.84 => "69.84%",
-		69.84..=69.85 => "69.85%",
-		69.85..=69.86 => "69.86%",
-		69.86..=69.87 => "69.87%",
-		69.87..=69.88 => "69.88%",
-		69.88..=69.89 => "69.89%",
-		69.89..=69.90 => "69.90%",
-		69.90..=69.91 => "69.91%",
-		69.91..=69.92 => "69.92%",
-		69.92..=69.93 => "69.93%",
-		69.93..=69.94 => "69.94%",
-		69.94..=69.95 => "69.95%",
-		69.95..=69.96 => "69.96%",
-		69.96..=69.97 => "69.97%",
-		69.97..=69.98 => "69.98%",
-		69.98..=69.99 => "69.99%",
-		69.99..=70.00 => "70.00%",
-		70.00..=70.01 => "70.01%",
-		70.01..=70.02 => "70.02%",
-		70.02..=70.03 => "70.03%",
-		70.03..=70.04 => "70.04%",
-		70.04..=70.05 => "70.05%",
-		70.05..=70.06 => "70.06%",
-		70.06..=70.07 => "70.07%",
-		70.07..=70.08 => "70.08%",
-		70.08..=70.09 => "70.09%",
-		70.09..=70.10 => "70.10%",
-		70.10..=70.11 => "70.11%",
-		70.11..=70.12 => "70.12%",
-		70.12..=70.13 => "70.13%",
-		70.13..=70.14 => "70.14%",
-		70.14..=70.15 => "70.15%",
-		70.15..=70.16 => "70.16%",
-		70.16..=70.17 => "70.17%",
-		70.17..=70.18 => "70.18%",
-		70.18..=70.19 => "70.19%",
-		70.19..=70.20 => "70.20%",
-		70.20..=70.21 => "70.21%",
-		70.21..=70.22 => "70.22%",
-		70.22..=70.23 => "70.23%",
-		70.23..=70.24 => "70.24%",
-		70.24..=70.25 => "70.25%",
-		70.25..=70.26 => "70.26%",
-		70.26..=70.27 => "70.27%",
-		70.27..=70.28 => "70.28%",
-		70.28..=70.29 => "70.29%",
-		70.29..=70.30 => "70.30%",
-		70.30..=70.31 => "70.31%",
-		70.31..=70.32 => "70.32%",
-		70.32..=70.33 => "70.33%",
-		70.33..=70.34 => "70.34%",
-		70.34..=70.35 => "70.35%",
-		70.35..=70.36 => "70.36%",
-		70.36..=70.37 => "70.37%",
-		70.37..=70.38 => "70.38%",
-		70.38..=70.39 => "70.39%",
-		70.39..=70.40 => "70.40%",
-		70.40..=70.41 => "70.41%",
-		70.41..=70.42 => "70.42%",
-		70.42..=70.43 => "70.43%",
-		70.43..=70.44 => "70.44%",
-		70.44..=70.45 => "70.45%",
-		70.45..=70.46 => "70.46%",
-		70.46..=70.47 => "70.47%",
-		70.47..=70.48 => "70.48%",
-		70.48..=70.49 => "70.49%",
-		70.49..=70.50 => "70.50%",
-		70.50..=70.51 => "70.51%",
-		70.51..=70.52 => "70.52%",
-		70.52..=70.53 => "70.53%",
-		70.53..=70.54 => "70.54%",
-		70.54..=70.55 => "70.55%",
-		70.55..=70.56 => "70.56%",
-		70.56..=70.57 => "70.57%",
-		70.57..=70.58 => "70.58%",
-		70.58..=70.59 => "70.59%",
-		70.59..=70.60 => "70.60%",
-		70.60..=70.61 => "70.61%",
-		70.61..=70.62 => "70.62%",
-		70.62..=70.63 => "70.63%",
-		70.63..=70.64 => "70.64%",
-		70.64..=70.65 => "70.65%",
-		70.65..=70.66 => "70.66%",
-		70.66..=70.67 => "70.67%",
-		70.67..=70.68 => "70.68%",
-		70.68..=70.69 => "70.69%",
-		70.69..=70.70 => "70.70%",
-		70.70..=70.71 => "70.71%",
-		70.71..=70.72 => "70.72%",
-		70.72..=70.73 => "70.73%",
-		70.73..=70.74 => "70.74%",
-		70.74..=70.75 => "70.75%",
-		70.75..=70.76 => "70.76%",
-		70.76..=70.77 => "70.77%",
-		70.77..=70.78 => "70.78%",
-		70.78..=70.79 => "70.79%",
-		70.79..=70.80 => "70.80%",
-		70.80..=70.81 => "70.81%",
-		70.81..=70.82 => "70.82%",
-		70.82..=70.83 => "70.83%",
-		70.83..=70.84 => "70.84%",
-		70.84..=70.85 => "70.85%",
-		70.85..=70.86 => "70.86%",
-		70.86..=70.87 => "70.87%",
-		70.87..=70.88 => "70.88%",
-		70.88..=70.89 => "70.89%",
-		70.89..=70.90 => "70.90%",
-		70.90..=70.91 => "70.91%",
-		70.91..=70.92 => "70.92%",
-		70.92..=70.93 => "70.93%",
-		70.93..=70.94 => "70.94%",
-		70.94..=70.95 => "70.95%",
-		70.95..=70.96 => "70.96%",
-		70.96..=70.97 => "70.97%",
-		70.97..=70.98 => "70.98%",
-		70.98..=70.99 => "70.99%",
-		70.99..=71.00 => "71.00%",
-		71.00..=71.01 => "71.01%",
-		71.01..=71.02 => "71.02%",
-		71.02..=71.03 => "71.03%",
-		71.03..=71.04 => "71.04%",
-		71.04..=71.05 => "71.05%",
-		71.05..=71.06 => "71.06%",
-		71.06..=71.07 => "71.07%",
-		71.07..=71.08 => "71.08%",
-		71.08..=71.09 => "71.09%",
-		71.09..=71.10 => "71.10%",
-		71.10..=71.11 => "71.11%",
-		71.11..=71.12 => "71.12%",
-		71.12..=71.13 => "71.13%",
-		71.13..=71.14 => "71.14%",
-		71.14..=71.15 => "71.15%",
-		71.15..=71.16 => "71.16%",
-		71.16..=71.17 => "71.17%",
-		71.17..=71.18 => "71.18%",
-		71.18..=71.19 => "71.19%",
-		71.19..=71.20 => "71.20%",
-		71.20..=71.21 => "71.21%",
-		71.21..=71.22 => "71.22%",
-		71.22..=71.23 => "71.23%",
-		71.23..=71.24 => "71.24%",
-		71.24..=71.25 => "71.25%",
-		71.25..=71.26 => "71.26%",
-		71.26..=71.27 => "71.27%",
-		71.27..=71.28 => "71.28%",
-		71.28..=71.29 => "71.29%",
-		71.29..=71.30 => "71.30%",
-		71.30..=71.31 => "71.31%",
-		71.31..=71.32 => "71.32%",
-		71.32..=71.33 => "71.33%",
-		71.33..=71.34 => "71.34%",
-		71.34..=71.35 => "71.35%",
-		71.35..=71.36 => "71.36%",
-		71.36..=71.37 => "71.37%",
-		71.37..=71.38 => "71.38%",
-		71.38..=71.39 => "71.39%",
-		71.39..=71.40 => "71.40%",
-		71.40..=71.41 => "71.41%",
-		71.41..=71.42 => "71.42%",
-		71.42..=71.43 => "71.43%",
-		71.43..=71.44 => "71.44%",
-		71.44..=71.45 => "71.45%",
-		71.45..=71.46 => "71.46%",
-		71.46..=71.47 => "71.47%",
-		71.47..=71.48 => "71.48%",
-		71.48..=71.49 => "71.49%",
-		71.49..=71.50 => "71.50%",
-		71.50..=71.51 => "71.51%",
-		71.51..=71.52 => "71.52%",
-		71.52..=71.53 => "71.53%",
-		71.53..=71.54 => "71.54%",
-		71.54..=71.55 => "71.55%",
-		71.55..=71.56 => "71.56%",
-		71.56..=71.57 => "71.57%",
-		71.57..=71.58 => "71.58%",
-		71.58..=71.59 => "71.59%",
-		71.59..=71.60 => "71.60%",
-		71.60..=71.61 => "71.61%",
-		71.61..=71.62 => "71.62%",
-		71.62..=71.63 => "71.63%",
-		71.63..=71.64 => "71.64%",
-		71.64..=71.65 => "71.65%",
-		71.65..=71.66 => "71.66%",
-		71.66..=71.67 => "71.67%",
-		71.67..=71.68 => "71.68%",
-		71.68..=71.69 => "71.69%",
-		71.69..=71.70 => "71.70%",
-		71.70..=71.71 => "71.71%",
-		71.71..=71.72 => "71.72%",
-		71.72..=71.73 => "71.73%",
-		71.73..=71.74 => "71.74%",
-		71.74..=71.75 => "71.75%",
-		71.75..=71.76 => "71.76%",
-		71.76..=71.77 => "71.77%",
-		71.77..=71.78 => "71.78%",
-		71.78..=71.79 => "71.79%",
-		71.79..=71.80 => "71.80%",
-		71.80..=71.81 => "71.81%",
-		71.81..=71.82 => "71.82%",
-		71.82..=71.83 => "71.83%",
-		71.83..=71.84 => "71.84%",
-		71.84..=71.85 => "71.85%",
-		71.85..=71.86 => "71.86%",
-		71.86..=71.87 => "71.87%",
-		71.87..=71.88 => "71.88%",
-		71.88..=71.89 => "71.89%",
-		71.89..=71.90 => "71.90%",
-		71.90..=71.91 => "71.91%",
-		71.91..=71.92 => "71.92%",
-		71.92..=71.93 => "71.93%",
-		71.93..=71.94 => "71.94%",
-		71.94..=71.95 => "71.95%",
-		71.95..=71.96 => "71.96%",
-		71.96..=71.97 => "71.97%",
-		71.97..=71.98 => "71.98%",
-		71.98..=71.99 => "71.99%",
-		71.99..=72.00 => "72.00%",
-		72.00..=72.01 => "72.01%",
-		72.01..=72.02 => "72.02%",
-		72.02..=72.03 => "72.03%",
-		72.03..=72.04 => "72.04%",
-		72.04..=72.05 => "72.05%",
-		72.05..=72.06 => "72.06%",
-		72.06..=72.07 => "72.07%",
-		72.07..=72.08 => "72.08%",
-		72.08..=72.09 => "72.09%",
-		72.09..=72.10 => "72.10%",
-		72.10..=72.11 => "72.11%",
-		72.11..=72.12 => "72.12%",
-		72.12..=72.13 => "72.13%",
-		72.13..=72.14 => "72.14%",
-		72.14..=72.15 => "72.15%",
-		72.15..=72.16 => "72.16%",
-		72.16..=72.17 => "72.17%",
-		72.17..=72.18 => "72.18%",
-		72.18..=72.19 => "72.19%",
-		72.19..=72.20 => "72.20%",
-		72.20..=72.21 => "72.21%",
-		72.21..=72.22 => "72.22%",
-		72.22..=72.23 => "72.23%",
-		72.23..=72.24 => "72.24%",
-		72.24..=72.25 => "72.25%",
-		72.25..=72.26 => "72.26%",
-		72.26..=72.27 => "72.27%",
-		72.27..=72.28 => "72.28%",
-		72.28..=72.29 => "72.29%",
-		72.29..=72.30 => "72.30%",
-		72.30..=72.31 => "72.31%",
-		72.31..=72.32 => "72.32%",
-		72.32..=72.33 => "72.33%",
-		72.33..=72.34 => "72.34%",
-		72.34..=72.35 => "72.35%",
-		72.35..=72.36 => "72.36%",
-		72.36..=72.37 => "72.37%",
-		72.37..=72.38 => "72.38%",
-		72.38..=72.39 => "72.39%",
-		72.39..=72.40 => "72.40%",
-		72.40..=72.41 => "72.41%",
-		72.41..=72.42 => "72.42%",
-		72.42..=72.43 => "72.43%",
-		72.43..=72.44 => "72.44%",
-		72.44..=72.45 => "72.45%",
-		72.45..=72.46 => "72.46%",
-		72.46..=72.47 => "72.47%",
-		72.47..=72.48 => "72.48%",
-		72.48..=72.49 => "72.49%",
-		72.49..=72.50 => "72.50%",
-		72.50..=72.51 => "72.51%",
-		72.51..=72.52 => "72.52%",
-		72.52..=72.53 => "72.53%",
-		72.53..=72.54 => "72.54%",
-		72.54..=72.55 => "72.55%",
-		72.55..=72.56 => "72.56%",
-		72.56..=72.57 => "72.57%",
-		72.57..=72.58 => "72.58%",
-		72.58..=72.59 => "72.59%",
-		72.59..=72.60 => "72.60%",
-		72.60..=72.61 => "72.61%",
-		72.61..=72.62 => "72.62%",
-		72.62..=72.63 => "72.63%",
-		72.63..=72.64 => "72.64%",
-		72.64..=72.65 => "72.65%",
-		72.65..=72.66 => "72.66%",
-		72.66..=72.67 => "72.67%",
-		72.67..=72.68 => "72.68%",
-		72.68..=72.69 => "72.69%",
-		72.69..=72.70 => "72.70%",
-		72.70..=72.71 => "72.71%",
-		72.71..=72.72 => "72.72%",
-		72.72..=72.73 => "72.73%",
-		72.73..=72.74 => "72.74%",
-		72.74..=72.75 => "72.75%",
-		72.75..=72.76 => "72.76%",
-		72.76..=72.77 => "72.77%",
-		72.77..=72.78 => "72.78%",
-		72.78..=72.79 => "72.79%",
-		72.79..=72.80 => "72.80%",
-		72.80..=72.81 => "72.81%",
-		72.81..=72.82 => "72.82%",
-		72.82..=72.83 => "72.83%",
-		72.83..=72.84 => "72.84%",
-		72.84..=72.85 => "72.85%",
-		72.85..=72.86 => "72.86%",
-		72.86..=72.87 => "72.87%",
-		72.87..=72.88 => "72.88%",
-		72.88..=72.89 => "72.89%",
-		72.89..=72.90 => "72.90%",
-		72.90..=72.91 => "72.91%",
-		72.91..=72.92 => "72.92%",
-		72.92..=72.93 => "72.93%",
-		72.93..=72.94 => "72.94%",
-		72.94..=72.95 => "72.95%",
-		72.95..=72.96 => "72.96%",
-		72.96..=72.97 => "72.97%",
-		72.97..=72.98 => "72.98%",
-		72.98..=72.99 => "72.99%",
-		72.99..=73.00 => "73.00%",
-		73.00..=73.01 => "73.01%",
-		73.01..=73.02 => "73.02%",
-		73.02..=73.03 => "73.03%",
-		73.03..=73.04 => "73.04%",
-		73.04..=73.05 => "73.05%",
-		73.05..=73.06 => "73.06%",
-		73.06..=73.07 => "73.07%",
-		73.07..=73.08 => "73.08%",
-		73.08..=73.09 => "73.09%",
-		73.09..=73.10 => "73.10%",
-		73.10..=73.11 => "73.11%",
-		73.11..=73.12 => "73.12%",
-		73.12..=73.13 => "73.13%",
-		73.13..=73.14 => "73.14%",
-		73.14..=73.15 => "73.15%",
-		73.15..=73.16 => "73.16%",
-		73.16..=73.17 => "73.17%",
-		73.17..=73.18 => "73.18%",
-		73.18..=73.19 => "73.19%",
-		73.19..=73.20 => "73.20%",
-		73.20..=73.21 => "73.21%",
-		73.21..=73.22 => "73.22%",
-		73.22..=73.23 => "73.23%",
-		73.23..=73.24 => "73.24%",
-		73.24..=73.25 => "73.25%",
-		73.25..=73.26 => "73.26%",
-		73.26..=73.27 => "73.27%",
-		73.27..=73.28 => "73.28%",
-		73.28..=73.29 => "73.29%",
-		73.29..=73.30 => "73.30%",
-		73.30..=73.31 => "73.31%",
-		73.31..=73.32 => "73.32%",
-		73.32..=73.33 => "73.33%",
-		73.33..=73.34 => "73.34%",
-		73.34..=73.35 => "73.35%",
-		73.35..=73.36 => "73.36%",
-		73.36..=73.37 => "73.37%",
-		73.37..=73.38 => "73.38%",
-		73.38..=73.39 => "73.39%",
-		73.39..=73.40 => "73.40%",
-		73.40..=73.41 => "73.41%",
-		73.41..=73.42 => "73.42%",
-		73.42..=73.43 => "73.43%",
-		73.43..=73.44 => "73.44%",
-		73.44..=73.45 => "73.45%",
-		73.45..=73.46 => "73.46%",
-		73.46..=73.47 => "73.47%",
-		73.47..=73.48 => "73.48%",
-		73.48..=73.49 => "73.49%",
-		73.49..=73.50 => "73.50%",
-		73.50..=73.51 => "73.51%",
-		73.51..=73.52 => "73.52%",
-		73.52..=73.53 => "73.53%",
-		73.53..=73.54 => "73.54%",
-		73.54..=73.55 => "73.55%",
-		73.55..=73.56 => "73.56%",
-		73.56..=73.57 => "73.57%",
-		73.57..=73.58 => "73.58%",
-		73.58..=73.59 => "73.59%",
-		73.59..=73.60 => "73.60%",
-		73.60..=73.61 => "73.61%",
-		73.61..=73.62 => "73.62%",
-		73.62..=73.63 => "73.63%",
-		73.63..=73.64 => "73.64%",
-		73.64..=73.65 => "73.65%",
-		73.65..=73.66 => "73.66%",
-		73.66..=73.67 => "73.67%",
-		73.67..=73.68 => "73.68%",
-		73.68..=73.69 => "73.69%",
-		73.69..=73.70 => "73.70%",
-		73.70..=73.71 => "73.71%",
-		73.71..=73.72 => "73.72%",
-		73.72..=73.73 => "73.73%",
-		73.73..=73.74 => "73.74%",
-		73.74..=73.75 => "73.75%",
-		73.75..=73.76 => "73.76%",
-		73.76..=73.77 => "73.77%",
-		73.77..=73.78 => "73.78%",
-		73.78..=73.79 => "73.79%",
-		73.79..=73.80 => "73.80%",
-		73.80..=73.81 => "73.81%",
-		73.81..=73.82 => "73.82%",
-		73.82..=73.83 => "73.83%",
-		73.83..=73.84 => "73.84%",
-		73.84..=73.85 => "73.85%",
-		73.85..=73.86 => "73.86%",
-		73.86..=73.87 => "73.87%",
-		73.87..=73.88 => "73.88%",
-		73.88..=73.89 => "73.89%",
-		73.89..=73.90 => "73.90%",
-		73.90..=73.91 => "73.91%",
-		73.91..=73.92 => "73.92%",
-		73.92..=73.93 => "73.93%",
-		73.93..=73.94 => "73.94%",
-		73.94..=73.95 => "73.95%",
-		73.95..=73.96 => "73.96%",
-		73.96..=73.97 => "73.97%",
-		73.97..=73.98 => "73.98%",
-		73.98..=73.99 => "73.99%",
-		73.99..=74.00 => "74.00%",
-		74.00..=74.01 => "74.01%",
-		74.01..=74.02 => "74.02%",
-		74.02..=74.03 => "74.03%",
-		74.03..=74.04 => "74.04%",
-		74.04..=74.05 => "74.05%",
-		74.05..=74.06 => "74.06%",
-		74.06..=74.07 => "74.07%",
-		74.07..=74.08 => "74.08%",
-		74.08..=74.09 => "74.09%",
-		74.09..=74.10 => "74.10%",
-		74.10..=74.11 => "74.11%",
-		74.11..=74.12 => "74.12%",
-		74.12..=74.13 => "74.13%",
-		74.13..=74.14 => "74.14%",
-		74.14..=74.15 => "74.15%",
-		74.15..=74.16 => "74.16%",
-		74.16..=74.17 => "74.17%",
-		74.17..=74.18 => "74.18%",
-		74.18..=74.19 => "74.19%",
-		74.19..=74.20 => "74.20%",
-		74.20..=74.21 => "74.21%",
-		74.21..=74.22 => "74.22%",
-		74.22..=74.23 => "74.23%",
-		74.23..=74.24 => "74.24%",
-		74.24..=74.25 => "74.25%",
-		74.25..=74.26 => "74.26%",
-		74.26..=74.27 => "74.27%",
-		74.27..=74.28 => "74.28%",
-		74.28..=74.29 => "74.29%",
-		74.29..=74.30 => "74.30%",
-		74.30..=74.31 => "74.31%",
-		74.31..=74.32 => "74.32%",
-		74.32..=74.33 => "74.33%",
-		74.33..=74.34 => "74.34%",
-		74.34..=74.35 => "74.35%",
-		74.35..=74.36 => "74.36%",
-		74.36..=74.37 => "74.37%",
-		74.37..=74.38 => "74.38%",
-		74.38..=74.39 => "74.39%",
-		74.39..=74.40 => "74.40%",
-		74.40..=74.41 => "74.41%",
-		74.41..=74.42 => "74.42%",
-		74.42..=74.43 => "74.43%",
-		74.43..=74.44 => "74.44%",
-		74.44..=74.45 => "74.45%",
-		74.45..=74.46 => "74.46%",
-		74.46..=74.47 => "74.47%",
-		74.47..=74.48 => "74.48%",
-		74.48..=74.49 => "74.49%",
-		74.49..=74.50 => "74.50%",
-		74.50..=74.51 => "74.51%",
-		74.51..=74.52 => "74.52%",
-		74.52..=74.53 => "74.53%",
-		74.53..=74.54 => "74.54%",
-		74.54..=74.55 => "74.55%",
-		74.55..=74.56 => "74.56%",
-		74.56..=74.57 => "74.57%",
-		74.57..=74.58 => "74.58%",
-		74.58..=74.59 => "74.59%",
-		74.59..=74.60 => "74.60%",
-		74.60..=74.61 => "74.61%",
-		74.61..=74.62 => "74.62%",
-		74.62..=74.63 => "74.63%",
-		74.63..=74.64 => "74.64%",
-		74.64..=74.65 => "74.65%",
-		74.65..=74.66 => "74.66%",
-		74.66..=74.67 => "74.67%",
-		74.67..=74.68 => "74.68%",
-		74.68..=74.69 => "74.69%",
-		74.69..=74.70 => "74.70%",
-		74.70..=74.71 => "74.71%",
-		74.71..=74.72 => "74.72%",
-		74.72..=74.73 => "74.73%",
-		74.73..=74.74 => "74.74%",
-		74.74..=74.75 => "74.75%",
-		74.75..=74.76 => "74.76%",
-		74.76..=74.77 => "74.77%",
-		74.77..=74.78 => "74.78%",
-		74.78..=74.79 => "74.79%",
-		74.79..=74.80 => "74.80%",
-		74.80..=74.81 => "74.81%",
-		74.81..=74.82 => "74.82%",
-		74.82..=74.83 => "74.83%",
-		74.83..=74.84 => "74.84%",
-		74.84..=74.85 => "74.85%",
-		74.85..=74.86 => "74.86%",
-		74.86..=74.87 => "74.87%",
-		74.87..=74.88 => "74.88%",
-		74.88..=74.89 => "74.89%",
-		74.89..=74.90 => "74.90%",
-		74.90..=74.91 => "74.91%",
-		74.91..=74.92 => "74.92%",
-		74.92..=74.93 => "74.93%",
-		74.93..=74.94 => "74.94%",
-		74.94..=74.95 => "74.95%",
-		74.95..=74.96 => "74.96%",
-		74.96..=74.97 => "74.97%",
-		74.97..=74.98 => "74.98%",
-		74.98..=74.99 => "74.99%",
-		_ => "75.00%",
-	}
-}
@@ -1,2505 +0,0 @@
-#[inline(always)]
-pub(crate) fn inlined_75(f: f64) -> &'static str {
-	match f {
-		75.00..=75.01 => "75.01%",
-		75.01..=75.02 => "75.02%",
-		75.02..=75.03 => "75.03%",
-		75.03..=75.04 => "75.04%",
-		75.04..=75.05 => "75.05%",
-		75.05..=75.06 => "75.06%",
-		75.06..=75.07 => "75.07%",
-		75.07..=75.08 => "75.08%",
-		75.08..=75.09 => "75.09%",
-		75.09..=75.10 => "75.10%",
-		75.10..=75.11 => "75.11%",
-		75.11..=75.12 => "75.12%",
-		75.12..=75.13 => "75.13%",
-		75.13..=75.14 => "75.14%",
-		75.14..=75.15 => "75.15%",
-		75.15..=75.16 => "75.16%",
-		75.16..=75.17 => "75.17%",
-		75.17..=75.18 => "75.18%",
-		75.18..=75.19 => "75.19%",
-		75.19..=75.20 => "75.20%",
-		75.20..=75.21 => "75.21%",
-		75.21..=75.22 => "75.22%",
-		75.22..=75.23 => "75.23%",
-		75.23..=75.24 => "75.24%",
-		75.24..=75.25 => "75.25%",
-		75.25..=75.26 => "75.26%",
-		75.26..=75.27 => "75.27%",
-		75.27..=75.28 => "75.28%",
-		75.28..=75.29 => "75.29%",
-		75.29..=75.30 => "75.30%",
-		75.30..=75.31 => "75.31%",
-		75.31..=75.32 => "75.32%",
-		75.32..=75.33 => "75.33%",
-		75.33..=75.34 => "75.34%",
-		75.34..=75.35 => "75.35%",
-		75.35..=75.36 => "75.36%",
-		75.36..=75.37 => "75.37%",
-		75.37..=75.38 => "75.38%",
-		75.38..=75.39 => "75.39%",
-		75.39..=75.40 => "75.40%",
-		75.40..=75.41 => "75.41%",
-		75.41..=75.42 => "75.42%",
-		75.42..=75.43 => "75.43%",
-		75.43..=75.44 => "75.44%",
-		75.44..=75.45 => "75.45%",
-		75.45..=75.46 => "75.46%",
-		75.46..=75.47 => "75.47%",
-		75.47..=75.48 => "75.48%",
-		75.48..=75.49 => "75.49%",
-		75.49..=75.50 => "75.50%",
-		75.50..=75.51 => "75.51%",
-		75.51..=75.52 => "75.52%",
-		75.52..=75.53 => "75.53%",
-		75.53..=75.54 => "75.54%",
-		75.54..=75.55 => "75.55%",
-		75.55..=75.56 => "75.56%",
-		75.56..=75.57 => "75.57%",
-		75.57..=75.58 => "75.58%",
-		75.58..=75.59 => "75.59%",
-		75.59..=75.60 => "75.60%",
-		75.60..=75.61 => "75.61%",
-		75.61..=75.62 => "75.62%",
-		75.62..=75.63 => "75.63%",
-		75.63..=75.64 => "75.64%",
-		75.64..=75.65 => "75.65%",
-		75.65..=75.66 => "75.66%",
-		75.66..=75.67 => "75.67%",
-		75.67..=75.68 => "75.68%",
-		75.68..=75.69 => "75.69%",
-		75.69..=75.70 => "75.70%",
-		75.70..=75.71 => "75.71%",
-		75.71..=75.72 => "75.72%",
-		75.72..=75.73 => "75.73%",
-		75.73..=75.74 => "75.74%",
-		75.74..=75.75 => "75.75%",
-		75.75..=75.76 => "75.76%",
-		75.76..=75.77 => "75.77%",
-		75.77..=75.78 => "75.78%",
-		75.78..=75.79 => "75.79%",
-		75.79..=75.80 => "75.80%",
-		75.80..=75.81 => "75.81%",
-		75.81..=75.82 => "75.82%",
-		75.82..=75.83 => "75.83%",
-		75.83..=75.84 => "75.84%",
-		75.84..=75.85 => "75.85%",
-		75.85..=75.86 => "75.86%",
-		75.86..=75.87 => "75.87%",
-		75.87..=75.88 => "75.88%",
-		75.88..=75.89 => "75.89%",
-		75.89..=75.90 => "75.90%",
-		75.90..=75.91 => "75.91%",
-		75.91..=75.92 => "75.92%",
-		75.92..=75.93 => "75.93%",
-		75.93..=75.94 => "75.94%",
-		75.94..=75.95 => "75.95%",
-		75.95..=75.96 => "75.96%",
-		75.96..=75.97 => "75.97%",
-		75.97..=75.98 => "75.98%",
-		75.98..=75.99 => "75.99%",
-		75.99..=76.00 => "76.00%",
-		76.00..=76.01 => "76.01%",
-		76.01..=76.02 => "76.02%",
-		76.02..=76.03 => "76.03%",
-		76.03..=76.04 => "76.04%",
-		76.04..=76.05 => "76.05%",
-		76.05..=76.06 => "76.06%",
-		76.06..=76.07 => "76.07%",
-		76.07..=76.08 => "76.08%",
-		76.08..=76.09 => "76.09%",
-		76.09..=76.10 => "76.10%",
-		76.10..=76.11 => "76.11%",
-		76.11..=76.12 => "76.12%",
-		76.12..=76.13 => "76.13%",
-		76.13..=76.14 => "76.14%",
-		76.14..=76.15 => "76.15%",
-		76.15..=76.16 => "76.16%",
-		76.16..=76.17 => "76.17%",
-		76.17..=76.18 => "76.18%",
-		76.18..=76.19 => "76.19%",
-		76.19..=76.20 => "76.20%",
-		76.20..=76.21 => "76.21%",
-		76.21..=76.22 => "76.22%",
-		76.22..=76.23 => "76.23%",
-		76.23..=76.24 => "76.24%",
-		76.24..=76.25 => "76.25%",
-		76.25..=76.26 => "76.26%",
-		76.26..=76.27 => "76.27%",
-		76.27..=76.28 => "76.28%",
-		76.28..=76.29 => "76.29%",
-		76.29..=76.30 => "76.30%",
-		76.30..=76.31 => "76.31%",
-		76.31..=76.32 => "76.32%",
-		76.32..=76.33 => "76.33%",
-		76.33..=76.34 => "76.34%",
-		76.34..=76.35 => "76.35%",
-		76.35..=76.36 => "76.36%",
-		76.36..=76.37 => "76.37%",
-		76.37..=76.38 => "76.38%",
-		76.38..=76.39 => "76.39%",
-		76.39..=76.40 => "76.40%",
-		76.40..=76.41 => "76.41%",
-		76.41..=76.42 => "76.42%",
-		76.42..=76.43 => "76.43%",
-		76.43..=76.44 => "76.44%",
-		76.44..=76.45 => "76.45%",
-		76.45..=76.46 => "76.46%",
-		76.46..=76.47 => "76.47%",
-		76.47..=76.48 => "76.48%",
-		76.48..=76.49 => "76.49%",
-		76.49..=76.50 => "76.50%",
-		76.50..=76.51 => "76.51%",
-		76.51..=76.52 => "76.52%",
-		76.52..=76.53 => "76.53%",
-		76.53..=76.54 => "76.54%",
-		76.54..=76.55 => "76.55%",
-		76.55..=76.56 => "76.56%",
-		76.56..=76.57 => "76.57%",
-		76.57..=76.58 => "76.58%",
-		76.58..=76.59 => "76.59%",
-		76.59..=76.60 => "76.60%",
-		76.60..=76.61 => "76.61%",
-		76.61..=76.62 => "76.62%",
-		76.62..=76.63 => "76.63%",
-		76.63..=76.64 => "76.64%",
-		76.64..=76.65 => "76.65%",
-		76.65..=76.66 => "76.66%",
-		76.66..=76.67 => "76.67%",
-		76.67..=76.68 => "76.68%",
-		76.68..=76.69 => "76.69%",
-		76.69..=76.70 => "76.70%",
-		76.70..=76.71 => "76.71%",
-		76.71..=76.72 => "76.72%",
-		76.72..=76.73 => "76.73%",
-		76.73..=76.74 => "76.74%",
-		76.74..=76.75 => "76.75%",
-		76.75..=76.76 => "76.76%",
-		76.76..=76.77 => "76.77%",
-		76.77..=76.78 => "76.78%",
-		76.78..=76.79 => "76.79%",
-		76.79..=76.80 => "76.80%",
-		76.80..=76.81 => "76.81%",
-		76.81..=76.82 => "76.82%",
-		76.82..=76.83 => "76.83%",
-		76.83..=76.84 => "76.84%",
-		76.84..=76.85 => "76.85%",
-		76.85..=76.86 => "76.86%",
-		76.86..=76.87 => "76.87%",
-		76.87..=76.88 => "76.88%",
-		76.88..=76.89 => "76.89%",
-		76.89..=76.90 => "76.90%",
-		76.90..=76.91 => "76.91%",
-		76.91..=76.92 => "76.92%",
-		76.92..=76.93 => "76.93%",
-		76.93..=76.94 => "76.94%",
-		76.94..=76.95 => "76.95%",
-		76.95..=76.96 => "76.96%",
-		76.96..=76.97 => "76.97%",
-		76.97..=76.98 => "76.98%",
-		76.98..=76.99 => "76.99%",
-		76.99..=77.00 => "77.00%",
-		77.00..=77.01 => "77.01%",
-		77.01..=77.02 => "77.02%",
-		77.02..=77.03 => "77.03%",
-		77.03..=77.04 => "77.04%",
-		77.04..=77.05 => "77.05%",
-		77.05..=77.06 => "77.06%",
-		77.06..=77.07 => "77.07%",
-		77.07..=77.08 => "77.08%",
-		77.08..=77.09 => "77.09%",
-		77.09..=77.10 => "77.10%",
-		77.10..=77.11 => "77.11%",
-		77.11..=77.12 => "77.12%",
-		77.12..=77.13 => "77.13%",
-		77.13..=77.14 => "77.14%",
-		77.14..=77.15 => "77.15%",
-		77.15..=77.16 => "77.16%",
-		77.16..=77.17 => "77.17%",
-		77.17..=77.18 => "77.18%",
-		77.18..=77.19 => "77.19%",
-		77.19..=77.20 => "77.20%",
-		77.20..=77.21 => "77.21%",
-		77.21..=77.22 => "77.22%",
-		77.22..=77.23 => "77.23%",
-		77.23..=77.24 => "77.24%",
-		77.24..=77.25 => "77.25%",
-		77.25..=77.26 => "77.26%",
-		77.26..=77.27 => "77.27%",
-		77.27..=77.28 => "77.28%",
-		77.28..=77.29 => "77.29%",
-		77.29..=77.30 => "77.30%",
-		77.30..=77.31 => "77.31%",
-		77.31..=77.32 => "77.32%",
-		77.32..=77.33 => "77.33%",
-		77.33..=77.34 => "77.34%",
-		77.34..=77.35 => "77.35%",
-		77.35..=77.36 => "77.36%",
-		77.36..=77.37 => "77.37%",
-		77.37..=77.38 => "77.38%",
-		77.38..=77.39 => "77.39%",
-		77.39..=77.40 => "77.40%",
-		77.40..=77.41 => "77.41%",
-		77.41..=77.42 => "77.42%",
-		77.42..=77.43 => "77.43%",
-		77.43..=77.44 => "77.44%",
-		77.44..=77.45 => "77.45%",
-		77.45..=77.46 => "77.46%",
-		77.46..=77.47 => "77.47%",
-		77.47..=77.48 => "77.48%",
-		77.48..=77.49 => "77.49%",
-		77.49..=77.50 => "77.50%",
-		77.50..=77.51 => "77.51%",
-		77.51..=77.52 => "77.52%",
-		77.52..=77.53 => "77.53%",
-		77.53..=77.54 => "77.54%",
-		77.54..=77.55 => "77.55%",
-		77.55..=77.56 => "77.56%",
-		77.56..=77.57 => "77.57%",
-		77.57..=77.58 => "77.58%",
-		77.58..=77.59 => "77.59%",
-		77.59..=77.60 => "77.60%",
-		77.60..=77.61 => "77.61%",
-		77.61..=77.62 => "77.62%",
-		77.62..=77.63 => "77.63%",
-		77.63..=77.64 => "77.64%",
-		77.64..=77.65 => "77.65%",
-		77.65..=77.66 => "77.66%",
-		77.66..=77.67 => "77.67%",
-		77.67..=77.68 => "77.68%",
-		77.68..=77.69 => "77.69%",
-		77.69..=77.70 => "77.70%",
-		77.70..=77.71 => "77.71%",
-		77.71..=77.72 => "77.72%",
-		77.72..=77.73 => "77.73%",
-		77.73..=77.74 => "77.74%",
-		77.74..=77.75 => "77.75%",
-		77.75..=77.76 => "77.76%",
-		77.76..=77.77 => "77.77%",
-		77.77..=77.78 => "77.78%",
-		77.78..=77.79 => "77.79%",
-		77.79..=77.80 => "77.80%",
-		77.80..=77.81 => "77.81%",
-		77.81..=77.82 => "77.82%",
-		77.82..=77.83 => "77.83%",
-		77.83..=77.84 => "77.84%",
-		77.84..=77.85 => "77.85%",
-		77.85..=77.86 => "77.86%",
-		77.86..=77.87 => "77.87%",
-		77.87..=77.88 => "77.88%",
-		77.88..=77.89 => "77.89%",
-		77.89..=77.90 => "77.90%",
-		77.90..=77.91 => "77.91%",
-		77.91..=77.92 => "77.92%",
-		77.92..=77.93 => "77.93%",
-		77.93..=77.94 => "77.94%",
-		77.94..=77.95 => "77.95%",
-		77.95..=77.96 => "77.96%",
-		77.96..=77.97 => "77.97%",
-		77.97..=77.98 => "77.98%",
-		77.98..=77.99 => "77.99%",
-		77.99..=78.00 => "78.00%",
-		78.00..=78.01 => "78.01%",
-		78.01..=78.02 => "78.02%",
-		78.02..=78.03 => "78.03%",
-		78.03..=78.04 => "78.04%",
-		78.04..=78.05 => "78.05%",
-		78.05..=78.06 => "78.06%",
-		78.06..=78.07 => "78.07%",
-		78.07..=78.08 => "78.08%",
-		78.08..=78.09 => "78.09%",
-		78.09..=78.10 => "78.10%",
-		78.10..=78.11 => "78.11%",
-		78.11..=78.12 => "78.12%",
-		78.12..=78.13 => "78.13%",
-		78.13..=78.14 => "78.14%",
-		78.14..=78.15 => "78.15%",
-		78.15..=78.16 => "78.16%",
-		78.16..=78.17 => "78.17%",
-		78.17..=78.18 => "78.18%",
-		78.18..=78.19 => "78.19%",
-		78.19..=78.20 => "78.20%",
-		78.20..=78.21 => "78.21%",
-		78.21..=78.22 => "78.22%",
-		78.22..=78.23 => "78.23%",
-		78.23..=78.24 => "78.24%",
-		78.24..=78.25 => "78.25%",
-		78.25..=78.26 => "78.26%",
-		78.26..=78.27 => "78.27%",
-		78.27..=78.28 => "78.28%",
-		78.28..=78.29 => "78.29%",
-		78.29..=78.30 => "78.30%",
-		78.30..=78.31 => "78.31%",
-		78.31..=78.32 => "78.32%",
-		78.32..=78.33 => "78.33%",
-		78.33..=78.34 => "78.34%",
-		78.34..=78.35 => "78.35%",
-		78.35..=78.36 => "78.36%",
-		78.36..=78.37 => "78.37%",
-		78.37..=78.38 => "78.38%",
-		78.38..=78.39 => "78.39%",
-		78.39..=78.40 => "78.40%",
-		78.40..=78.41 => "78.41%",
-		78.41..=78.42 => "78.42%",
-		78.42..=78.43 => "78.43%",
-		78.43..=78.44 => "78.44%",
-		78.44..=78.45 => "78.45%",
-		78.45..=78.46 => "78.46%",
-		78.46..=78.47 => "78.47%",
-		78.47..=78.48 => "78.48%",
-		78.48..=78.49 => "78.49%",
-		78.49..=78.50 => "78.50%",
-		78.50..=78.51 => "78.51%",
-		78.51..=78.52 => "78.52%",
-		78.52..=78.53 => "78.53%",
-		78.53..=78.54 => "78.54%",
-		78.54..=78.55 => "78.55%",
-		78.55..=78.56 => "78.56%",
-		78.56..=78.57 => "78.57%",
-		78.57..=78.58 => "78.58%",
-		78.58..=78.59 => "78.59%",
-		78.59..=78.60 => "78.60%",
-		78.60..=78.61 => "78.61%",
-		78.61..=78.62 => "78.62%",
-		78.62..=78.63 => "78.63%",
-		78.63..=78.64 => "78.64%",
-		78.64..=78.65 => "78.65%",
-		78.65..=78.66 => "78.66%",
-		78.66..=78.67 => "78.67%",
-		78.67..=78.68 => "78.68%",
-		78.68..=78.69 => "78.69%",
-		78.69..=78.70 => "78.70%",
-		78.70..=78.71 => "78.71%",
-		78.71..=78.72 => "78.72%",
-		78.72..=78.73 => "78.73%",
-		78.73..=78.74 => "78.74%",
-		78.74..=78.75 => "78.75%",
-		78.75..=78.76 => "78.76%",
-		78.76..=78.77 => "78.77%",
-		78.77..=78.78 => "78.78%",
-		78.78..=78.79 => "78.79%",
-		78.79..=78.80 => "78.80%",
-		78.80..=78.81 => "78.81%",
-		78.81..=78.82 => "78.82%",
-		78.82..=78.83 => "78.83%",
-		78.83..=78.84 => "78.84%",
-		78.84..=78.85 => "78.85%",
-		78.85..=78.86 => "78.86%",
-		78.86..=78.87 => "78.87%",
-		78.87..=78.88 => "78.88%",
-		78.88..=78.89 => "78.89%",
-		78.89..=78.90 => "78.90%",
-		78.90..=78.91 => "78.91%",
-		78.91..=78.92 => "78.92%",
-		78.92..=78.93 => "78.93%",
-		78.93..=78.94 => "78.94%",
-		78.94..=78.95 => "78.95%",
-		78.95..=78.96 => "78.96%",
-		78.96..=78.97 => "78.97%",
-		78.97..=78.98 => "78.98%",
-		78.98..=78.99 => "78.99%",
-		78.99..=79.00 => "79.00%",
-		79.00..=79.01 => "79.01%",
-		79.01..=79.02 => "79.02%",
-		79.02..=79.03 => "79.03%",
-		79.03..=79.04 => "79.04%",
-		79.04..=79.05 => "79.05%",
-		79.05..=79.06 => "79.06%",
-		79.06..=79.07 => "79.07%",
-		79.07..=79.08 => "79.08%",
-		79.08..=79.09 => "79.09%",
-		79.09..=79.10 => "79.10%",
-		79.10..=79.11 => "79.11%",
-		79.11..=79.12 => "79.12%",
-		79.12..=79.13 => "79.13%",
-		79.13..=79.14 => "79.14%",
-		79.14..=79.15 => "79.15%",
-		79.15..=79.16 => "79.16%",
-		79.16..=79.17 => "79.17%",
-		79.17..=79.18 => "79.18%",
-		79.18..=79.19 => "79.19%",
-		79.19..=79.20 => "79.20%",
-		79.20..=79.21 => "79.21%",
-		79.21..=79.22 => "79.22%",
-		79.22..=79.23 => "79.23%",
-		79.23..=79.24 => "79.24%",
-		79.24..=79.25 => "79.25%",
-		79.25..=79.26 => "79.26%",
-		79.26..=79.27 => "79.27%",
-		79.27..=79.28 => "79.28%",
-		79.28..=79.29 => "79.29%",
-		79.29..=79.30 => "79.30%",
-		79.30..=79.31 => "79.31%",
-		79.31..=79.32 => "79.32%",
-		79.32..=79.33 => "79.33%",
-		79.33..=79.34 => "79.34%",
-		79.34..=79.35 => "79.35%",
-		79.35..=79.36 => "79.36%",
-		79.36..=79.37 => "79.37%",
-		79.37..=79.38 => "79.38%",
-		79.38..=79.39 => "79.39%",
-		79.39..=79.40 => "79.40%",
-		79.40..=79.41 => "79.41%",
-		79.41..=79.42 => "79.42%",
-		79.42..=79.43 => "79.43%",
-		79.43..=79.44 => "79.44%",
-		79.44..=79.45 => "79.45%",
-		79.45..=79.46 => "79.46%",
-		79.46..=79.47 => "79.47%",
-		79.47..=79.48 => "79.48%",
-		79.48..=79.49 => "79.49%",
-		79.49..=79.50 => "79.50%",
-		79.50..=79.51 => "79.51%",
-		79.51..=79.52 => "79.52%",
-		79.52..=79.53 => "79.53%",
-		79.53..=79.54 => "79.54%",
-		79.54..=79.55 => "79.55%",
-		79.55..=79.56 => "79.56%",
-		79.56..=79.57 => "79.57%",
-		79.57..=79.58 => "79.58%",
-		79.58..=79.59 => "79.59%",
-		79.59..=79.60 => "79.60%",
-		79.60..=79.61 => "79.61%",
-		79.61..=79.62 => "79.62%",
-		79.62..=79.63 => "79.63%",
-		79.63..=79.64 => "79.64%",
-		79.64..=79.65 => "79.65%",
-		79.65..=79.66 => "79.66%",
-		79.66..=79.67 => "79.67%",
-		79.67..=79.68 => "79.68%",
-		79.68..=79.69 => "79.69%",
-		79.69..=79.70 => "79.70%",
-		79.70..=79.71 => "79.71%",
-		79.71..=79.72 => "79.72%",
-		79.72..=79.73 => "79.73%",
-		79.73..=79.74 => "79.74%",
-		79.74..=79.75 => "79.75%",
-		79.75..=79.76 => "79.76%",
-		79.76..=79.77 => "79.77%",
-		79.77..=79.78 => "79.78%",
-		79.78..=79.79 => "79.79%",
-		79.79..=79.80 => "79.80%",
-		79.80..=79.81 => "79.81%",
-		79.81..=79.82 => "79.82%",
-		79.82..=79.83 => "79.83%",
-		79.83..=79.84 => "79.84%",
-		79.84..=79.85 => "79.85%",
-		79.85..=79.86 => "79.86%",
-		79.86..=79.87 => "79.87%",
-		79.87..=79.88 => "79.88%",
-		79.88..=79.89 => "79.89%",
-		79.89..=79.90 => "79.90%",
-		79.90..=79.91 => "79.91%",
-		79.91..=79.92 => "79.92%",
-		79.92..=79.93 => "79.93%",
-		79.93..=79.94 => "79.94%",
-		79.94..=79.95 => "79.95%",
-		79.95..=79.96 => "79.96%",
-		79.96..=79.97 => "79.97%",
-		79.97..=79.98 => "79.98%",
-		79.98..=79.99 => "79.99%",
-		79.99..=80.00 => "80.00%",
-		80.00..=80.01 => "80.01%",
-		80.01..=80.02 => "80.02%",
-		80.02..=80.03 => "80.03%",
-		80.03..=80.04 => "80.04%",
-		80.04..=80.05 => "80.05%",
-		80.05..=80.06 => "80.06%",
-		80.06..=80.07 => "80.07%",
-		80.07..=80.08 => "80.08%",
-		80.08..=80.09 => "80.09%",
-		80.09..=80.10 => "80.10%",
-		80.10..=80.11 => "80.11%",
-		80.11..=80.12 => "80.12%",
-		80.12..=80.13 => "80.13%",
-		80.13..=80.14 => "80.14%",
-		80.14..=80.15 => "80.15%",
-		80.15..=80.16 => "80.16%",
-		80.16..=80.17 => "80.17%",
-		80.17..=80.18 => "80.18%",
-		80.18..=80.19 => "80.19%",
-		80.19..=80.20 => "80.20%",
-		80.20..=80.21 => "80.21%",
-		80.21..=80.22 => "80.22%",
-		80.22..=80.23 => "80.23%",
-		80.23..=80.24 => "80.24%",
-		80.24..=80.25 => "80.25%",
-		80.25..=80.26 => "80.26%",
-		80.26..=80.27 => "80.27%",
-		80.27..=80.28 => "80.28%",
-		80.28..=80.29 => "80.29%",
-		80.29..=80.30 => "80.30%",
-		80.30..=80.31 => "80.31%",
-		80.31..=80.32 => "80.32%",
-		80.32..=80.33 => "80.33%",
-		80.33..=80.34 => "80.34%",
-		80.34..=80.35 => "80.35%",
-		80.35..=80.36 => "80.36%",
-		80.36..=80.37 => "80.37%",
-		80.37..=80.38 => "80.38%",
-		80.38..=80.39 => "80.39%",
-		80.39..=80.40 => "80.40%",
-		80.40..=80.41 => "80.41%",
-		80.41..=80.42 => "80.42%",
-		80.42..=80.43 => "80.43%",
-		80.43..=80.44 => "80.44%",
-		80.44..=80.45 => "80.45%",
-		80.45..=80.46 => "80.46%",
-		80.46..=80.47 => "80.47%",
-		80.47..=80.48 => "80.48%",
-		80.48..=80.49 => "80.49%",
-		80.49..=80.50 => "80.50%",
-		80.50..=80.51 => "80.51%",
-		80.51..=80.52 => "80.52%",
-		80.52..=80.53 => "80.53%",
-		80.53..=80.54 => "80.54%",
-		80.54..=80.55 => "80.55%",
-		80.55..=80.56 => "80.56%",
-		80.56..=80.57 => "80.57%",
-		80.57..=80.58 => "80.58%",
-		80.58..=80.59 => "80.59%",
-		80.59..=80.60 => "80.60%",
-		80.60..=80.61 => "80.61%",
-		80.61..=80.62 => "80.62%",
-		80.62..=80.63 => "80.63%",
-		80.63..=80.64 => "80.64%",
-		80.64..=80.65 => "80.65%",
-		80.65..=80.66 => "80.66%",
-		80.66..=80.67 => "80.67%",
-		80.67..=80.68 => "80.68%",
-		80.68..=80.69 => "80.69%",
-		80.69..=80.70 => "80.70%",
-		80.70..=80.71 => "80.71%",
-		80.71..=80.72 => "80.72%",
-		80.72..=80.73 => "80.73%",
-		80.73..=80.74 => "80.74%",
-		80.74..=80.75 => "80.75%",
-		80.75..=80.76 => "80.76%",
-		80.76..=80.77 => "80.77%",
-		80.77..=80.78 => "80.78%",
-		80.78..=80.79 => "80.79%",
-		80.79..=80.80 => "80.80%",
-		80.80..=80.81 => "80.81%",
-		80.81..=80.82 => "80.82%",
-		80.82..=80.83 => "80.83%",
-		80.83..=80.84 => "80.84%",
-		80.84..=80.85 => "80.85%",
-		80.85..=80.86 => "80.86%",
-		80.86..=80.87 => "80.87%",
-		80.87..=80.88 => "80.88%",
-		80.88..=80.89 => "80.89%",
-		80.89..=80.90 => "80.90%",
-		80.90..=80.91 => "80.91%",
-		80.91..=80.92 => "80.92%",
-		80.92..=80.93 => "80.93%",
-		80.93..=80.94 => "80.94%",
-		80.94..=80.95 => "80.95%",
-		80.95..=80.96 => "80.96%",
-		80.96..=80.97 => "80.97%",
-		80.97..=80.98 => "80.98%",
-		80.98..=80.99 => "80.99%",
-		80.99..=81.00 => "81.00%",
-		81.00..=81.01 => "81.01%",
-		81.01..=81.02 => "81.02%",
-		81.02..=81.03 => "81.03%",
-		81.03..=81.04 => "81.04%",
-		81.04..=81.05 => "81.05%",
-		81.05..=81.06 => "81.06%",
-		81.06..=81.07 => "81.07%",
-		81.07..=81.08 => "81.08%",
-		81.08..=81.09 => "81.09%",
-		81.09..=81.10 => "81.10%",
-		81.10..=81.11 => "81.11%",
-		81.11..=81.12 => "81.12%",
-		81.12..=81.13 => "81.13%",
-		81.13..=81.14 => "81.14%",
-		81.14..=81.15 => "81.15%",
-		81.15..=81.16 => "81.16%",
-		81.16..=81.17 => "81.17%",
-		81.17..=81.18 => "81.18%",
-		81.18..=81.19 => "81.19%",
-		81.19..=81.20 => "81.20%",
-		81.20..=81.21 => "81.21%",
-		81.21..=81.22 => "81.22%",
-		81.22..=81.23 => "81.23%",
-		81.23..=81.24 => "81.24%",
-		81.24..=81.25 => "81.25%",
-		81.25..=81.26 => "81.26%",
-		81.26..=81.27 => "81.27%",
-		81.27..=81.28 => "81.28%",
-		81.28..=81.29 => "81.29%",
-		81.29..=81.30 => "81.30%",
-		81.30..=81.31 => "81.31%",
-		81.31..=81.32 => "81.32%",
-		81.32..=81.33 => "81.33%",
-		81.33..=81.34 => "81.34%",
-		81.34..=81.35 => "81.35%",
-		81.35..=81.36 => "81.36%",
-		81.36..=81.37 => "81.37%",
-		81.37..=81.38 => "81.38%",
-		81.38..=81.39 => "81.39%",
-		81.39..=81.40 => "81.40%",
-		81.40..=81.41 => "81.41%",
-		81.41..=81.42 => "81.42%",
-		81.42..=81.43 => "81.43%",
-		81.43..=81.44 => "81.44%",
-		81.44..=81.45 => "81.45%",
-		81.45..=81.46 => "81.46%",
-		81.46..=81.47 => "81.47%",
-		81.47..=81.48 => "81.48%",
-		81.48..=81.49 => "81.49%",
-		81.49..=81.50 => "81.50%",
-		81.50..=81.51 => "81.51%",
-		81.51..=81.52 => "81.52%",
-		81.52..=81.53 => "81.53%",
-		81.53..=81.54 => "81.54%",
-		81.54..=81.55 => "81.55%",
-		81.55..=81.56 => "81.56%",
-		81.56..=81.57 => "81.57%",
-		81.57..=81.58 => "81.58%",
-		81.58..=81.59 => "81.59%",
-		81.59..=81.60 => "81.60%",
-		81.60..=81.61 => "81.61%",
-		81.61..=81.62 => "81.62%",
-		81.62..=81.63 => "81.63%",
-		81.63..=81.64 => "81.64%",
-		81.64..=81.65 => "81.65%",
-		81.65..=81.66 => "81.66%",
-		81.66..=81.67 => "81.67%",
-		81.67..=81.68 => "81.68%",
-		81.68..=81.69 => "81.69%",
-		81.69..=81.70 => "81.70%",
-		81.70..=81.71 => "81.71%",
-		81.71..=81.72 => "81.72%",
-		81.72..=81.73 => "81.73%",
-		81.73..=81.74 => "81.74%",
-		81.74..=81.75 => "81.75%",
-		81.75..=81.76 => "81.76%",
-		81.76..=81.77 => "81.77%",
-		81.77..=81.78 => "81.78%",
-		81.78..=81.79 => "81.79%",
-		81.79..=81.80 => "81.80%",
-		81.80..=81.81 => "81.81%",
-		81.81..=81.82 => "81.82%",
-		81.82..=81.83 => "81.83%",
-		81.83..=81.84 => "81.84%",
-		81.84..=81.85 => "81.85%",
-		81.85..=81.86 => "81.86%",
-		81.86..=81.87 => "81.87%",
-		81.87..=81.88 => "81.88%",
-		81.88..=81.89 => "81.89%",
-		81.89..=81.90 => "81.90%",
-		81.90..=81.91 => "81.91%",
-		81.91..=81.92 => "81.92%",
-		81.92..=81.93 => "81.93%",
-		81.93..=81.94 => "81.94%",
-		81.94..=81.95 => "81.95%",
-		81.95..=81.96 => "81.96%",
-		81.96..=81.97 => "81.97%",
-		81.97..=81.98 => "81.98%",
-		81.98..=81.99 => "81.99%",
-		81.99..=82.00 => "82.00%",
-		82.00..=82.01 => "82.01%",
-		82.01..=82.02 => "82.02%",
-		82.02..=82.03 => "82.03%",
-		82.03..=82.04 => "82.04%",
-		82.04..=82.05 => "82.05%",
-		82.05..=82.06 => "82.06%",
-		82.06..=82.07 => "82.07%",
-		82.07..=82.08 => "82.08%",
-		82.08..=82.09 => "82.09%",
-		82.09..=82.10 => "82.10%",
-		82.10..=82.11 => "82.11%",
-		82.11..=82.12 => "82.12%",
-		82.12..=82.13 => "82.13%",
-		82.13..=82.14 => "82.14%",
-		82.14..=82.15 => "82.15%",
-		82.15..=82.16 => "82.16%",
-		82.16..=82.17 => "82.17%",
-		82.17..=82.18 => "82.18%",
-		82.18..=82.19 => "82.19%",
-		82.19..=82.20 => "82.20%",
-		82.20..=82.21 => "82.21%",
-		82.21..=82.22 => "82.22%",
-		82.22..=82.23 => "82.23%",
-		82.23..=82.24 => "82.24%",
-		82.24..=82.25 => "82.25%",
-		82.25..=82.26 => "82.26%",
-		82.26..=82.27 => "82.27%",
-		82.27..=82.28 => "82.28%",
-		82.28..=82.29 => "82.29%",
-		82.29..=82.30 => "82.30%",
-		82.30..=82.31 => "82.31%",
-		82.31..=82.32 => "82.32%",
-		82.32..=82.33 => "82.33%",
-		82.33..=82.34 => "82.34%",
-		82.34..=82.35 => "82.35%",
-		82.35..=82.36 => "82.36%",
-		82.36..=82.37 => "82.37%",
-		82.37..=82.38 => "82.38%",
-		82.38..=82.39 => "82.39%",
-		82.39..=82.40 => "82.40%",
-		82.40..=82.41 => "82.41%",
-		82.41..=82.42 => "82.42%",
-		82.42..=82.43 => "82.43%",
-		82.43..=82.44 => "82.44%",
-		82.44..=82.45 => "82.45%",
-		82.45..=82.46 => "82.46%",
-		82.46..=82.47 => "82.47%",
-		82.47..=82.48 => "82.48%",
-		82.48..=82.49 => "82.49%",
-		82.49..=82.50 => "82.50%",
-		82.50..=82.51 => "82.51%",
-		82.51..=82.52 => "82.52%",
-		82.52..=82.53 => "82.53%",
-		82.53..=82.54 => "82.54%",
-		82.54..=82.55 => "82.55%",
-		82.55..=82.56 => "82.56%",
-		82.56..=82.57 => "82.57%",
-		82.57..=82.58 => "82.58%",
-		82.58..=82.59 => "82.59%",
-		82.59..=82.60 => "82.60%",
-		82.60..=82.61 => "82.61%",
-		82.61..=82.62 => "82.62%",
-		82.62..=82.63 => "82.63%",
-		82.63..=82.64 => "82.64%",
-		82.64..=82.65 => "82.65%",
-		82.65..=82.66 => "82.66%",
-		82.66..=82.67 => "82.67%",
-		82.67..=82.68 => "82.68%",
-		82.68..=82.69 => "82.69%",
-		82.69..=82.70 => "82.70%",
-		82.70..=82.71 => "82.71%",
-		82.71..=82.72 => "82.72%",
-		82.72..=82.73 => "82.73%",
-		82.73..=82.74 => "82.74%",
-		82.74..=82.75 => "82.75%",
-		82.75..=82.76 => "82.76%",
-		82.76..=82.77 => "82.77%",
-		82.77..=82.78 => "82.78%",
-		82.78..=82.79 => "82.79%",
-		82.79..=82.80 => "82.80%",
-		82.80..=82.81 => "82.81%",
-		82.81..=82.82 => "82.82%",
-		82.82..=82.83 => "82.83%",
-		82.83..=82.84 => "82.84%",
-		82.84..=82.85 => "82.85%",
-		82.85..=82.86 => "82.86%",
-		82.86..=82.87 => "82.87%",
-		82.87..=82.88 => "82.88%",
-		82.88..=82.89 => "82.89%",
-		82.89..=82.90 => "82.90%",
-		82.90..=82.91 => "82.91%",
-		82.91..=82.92 => "82.92%",
-		82.92..=82.93 => "82.93%",
-		82.93..=82.94 => "82.94%",
-		82.94..=82.95 => "82.95%",
-		82.95..=82.96 => "82.96%",
-		82.96..=82.97 => "82.97%",
-		82.97..=82.98 => "82.98%",
-		82.98..=82.99 => "82.99%",
-		82.99..=83.00 => "83.00%",
-		83.00..=83.01 => "83.01%",
-		83.01..=83.02 => "83.02%",
-		83.02..=83.03 => "83.03%",
-		83.03..=83.04 => "83.04%",
-		83.04..=83.05 => "83.05%",
-		83.05..=83.06 => "83.06%",
-		83.06..=83.07 => "83.07%",
-		83.07..=83.08 => "83.08%",
-		83.08..=83.09 => "83.09%",
-		83.09..=83.10 => "83.10%",
-		83.10..=83.11 => "83.11%",
-		83.11..=83.12 => "83.12%",
-		83.12..=83.13 => "83.13%",
-		83.13..=83.14 => "83.14%",
-		83.14..=83.15 => "83.15%",
-		83.15..=83.16 => "83.16%",
-		83.16..=83.17 => "83.17%",
-		83.17..=83.18 => "83.18%",
-		83.18..=83.19 => "83.19%",
-		83.19..=83.20 => "83.20%",
-		83.20..=83.21 => "83.21%",
-		83.21..=83.22 => "83.22%",
-		83.22..=83.23 => "83.23%",
-		83.23..=83.24 => "83.24%",
-		83.24..=83.25 => "83.25%",
-		83.25..=83.26 => "83.26%",
-		83.26..=83.27 => "83.27%",
-		83.27..=83.28 => "83.28%",
-		83.28..=83.29 => "83.29%",
-		83.29..=83.30 => "83.30%",
-		83.30..=83.31 => "83.31%",
-		83.31..=83.32 => "83.32%",
-		83.32..=83.33 => "83.33%",
-		83.33..=83.34 => "83.34%",
-		83.34..=83.35 => "83.35%",
-		83.35..=83.36 => "83.36%",
-		83.36..=83.37 => "83.37%",
-		83.37..=83.38 => "83.38%",
-		83.38..=83.39 => "83.39%",
-		83.39..=83.40 => "83.40%",
-		83.40..=83.41 => "83.41%",
-		83.41..=83.42 => "83.42%",
-		83.42..=83.43 => "83.43%",
-		83.43..=83.44 => "83.44%",
-		83.44..=83.45 => "83.45%",
-		83.45..=83.46 => "83.46%",
-		83.46..=83.47 => "83.47%",
-		83.47..=83.48 => "83.48%",
-		83.48..=83.49 => "83.49%",
-		83.49..=83.50 => "83.50%",
-		83.50..=83.51 => "83.51%",
-		83.51..=83.52 => "83.52%",
-		83.52..=83.53 => "83.53%",
-		83.53..=83.54 => "83.54%",
-		83.54..=83.55 => "83.55%",
-		83.55..=83.56 => "83.56%",
-		83.56..=83.57 => "83.57%",
-		83.57..=83.58 => "83.58%",
-		83.58..=83.59 => "83.59%",
-		83.59..=83.60 => "83.60%",
-		83.60..=83.61 => "83.61%",
-		83.61..=83.62 => "83.62%",
-		83.62..=83.63 => "83.63%",
-		83.63..=83.64 => "83.64%",
-		83.64..=83.65 => "83.65%",
-		83.65..=83.66 => "83.66%",
-		83.66..=83.67 => "83.67%",
-		83.67..=83.68 => "83.68%",
-		83.68..=83.69 => "83.69%",
-		83.69..=83.70 => "83.70%",
-		83.70..=83.71 => "83.71%",
-		83.71..=83.72 => "83.72%",
-		83.72..=83.73 => "83.73%",
-		83.73..=83.74 => "83.74%",
-		83.74..=83.75 => "83.75%",
-		83.75..=83.76 => "83.76%",
-		83.76..=83.77 => "83.77%",
-		83.77..=83.78 => "83.78%",
-		83.78..=83.79 => "83.79%",
-		83.79..=83.80 => "83.80%",
-		83.80..=83.81 => "83.81%",
-		83.81..=83.82 => "83.82%",
-		83.82..=83.83 => "83.83%",
-		83.83..=83.84 => "83.84%",
-		83.84..=83.85 => "83.85%",
-		83.85..=83.86 => "83.86%",
-		83.86..=83.87 => "83.87%",
-		83.87..=83.88 => "83.88%",
-		83.88..=83.89 => "83.89%",
-		83.89..=83.90 => "83.90%",
-		83.90..=83.91 => "83.91%",
-		83.91..=83.92 => "83.92%",
-		83.92..=83.93 => "83.93%",
-		83.93..=83.94 => "83.94%",
-		83.94..=83.95 => "83.95%",
-		83.95..=83.96 => "83.96%",
-		83.96..=83.97 => "83.97%",
-		83.97..=83.98 => "83.98%",
-		83.98..=83.99 => "83.99%",
-		83.99..=84.00 => "84.00%",
-		84.00..=84.01 => "84.01%",
-		84.01..=84.02 => "84.02%",
-		84.02..=84.03 => "84.03%",
-		84.03..=84.04 => "84.04%",
-		84.04..=84.05 => "84.05%",
-		84.05..=84.06 => "84.06%",
-		84.06..=84.07 => "84.07%",
-		84.07..=84.08 => "84.08%",
-		84.08..=84.09 => "84.09%",
-		84.09..=84.10 => "84.10%",
-		84.10..=84.11 => "84.11%",
-		84.11..=84.12 => "84.12%",
-		84.12..=84.13 => "84.13%",
-		84.13..=84.14 => "84.14%",
-		84.14..=84.15 => "84.15%",
-		84.15..=84.16 => "84.16%",
-		84.16..=84.17 => "84.17%",
-		84.17..=84.18 => "84.18%",
-		84.18..=84.19 => "84.19%",
-		84.19..=84.20 => "84.20%",
-		84.20..=84.21 => "84.21%",
-		84.21..=84.22 => "84.22%",
-		84.22..=84.23 => "84.23%",
-		84.23..=84.24 => "84.24%",
-		84.24..=84.25 => "84.25%",
-		84.25..=84.26 => "84.26%",
-		84.26..=84.27 => "84.27%",
-		84.27..=84.28 => "84.28%",
-		84.28..=84.29 => "84.29%",
-		84.29..=84.30 => "84.30%",
-		84.30..=84.31 => "84.31%",
-		84.31..=84.32 => "84.32%",
-		84.32..=84.33 => "84.33%",
-		84.33..=84.34 => "84.34%",
-		84.34..=84.35 => "84.35%",
-		84.35..=84.36 => "84.36%",
-		84.36..=84.37 => "84.37%",
-		84.37..=84.38 => "84.38%",
-		84.38..=84.39 => "84.39%",
-		84.39..=84.40 => "84.40%",
-		84.40..=84.41 => "84.41%",
-		84.41..=84.42 => "84.42%",
-		84.42..=84.43 => "84.43%",
-		84.43..=84.44 => "84.44%",
-		84.44..=84.45 => "84.45%",
-		84.45..=84.46 => "84.46%",
-		84.46..=84.47 => "84.47%",
-		84.47..=84.48 => "84.48%",
-		84.48..=84.49 => "84.49%",
-		84.49..=84.50 => "84.50%",
-		84.50..=84.51 => "84.51%",
-		84.51..=84.52 => "84.52%",
-		84.52..=84.53 => "84.53%",
-		84.53..=84.54 => "84.54%",
-		84.54..=84.55 => "84.55%",
-		84.55..=84.56 => "84.56%",
-		84.56..=84.57 => "84.57%",
-		84.57..=84.58 => "84.58%",
-		84.58..=84.59 => "84.59%",
-		84.59..=84.60 => "84.60%",
-		84.60..=84.61 => "84.61%",
-		84.61..=84.62 => "84.62%",
-		84.62..=84.63 => "84.63%",
-		84.63..=84.64 => "84.64%",
-		84.64..=84.65 => "84.65%",
-		84.65..=84.66 => "84.66%",
-		84.66..=84.67 => "84.67%",
-		84.67..=84.68 => "84.68%",
-		84.68..=84.69 => "84.69%",
-		84.69..=84.70 => "84.70%",
-		84.70..=84.71 => "84.71%",
-		84.71..=84.72 => "84.72%",
-		84.72..=84.73 => "84.73%",
-		84.73..=84.74 => "84.74%",
-		84.74..=84.75 => "84.75%",
-		84.75..=84.76 => "84.76%",
-		84.76..=84.77 => "84.77%",
-		84.77..=84.78 => "84.78%",
-		84.78..=84.79 => "84.79%",
-		84.79..=84.80 => "84.80%",
-		84.80..=84.81 => "84.81%",
-		84.81..=84.82 => "84.82%",
-		84.82..=84.83 => "84.83%",
-		84.83..=84.84 => "84.84%",
-		84.84..=84.85 => "84.85%",
-		84.85..=84.86 => "84.86%",
-		84.86..=84.87 => "84.87%",
-		84.87..=84.88 => "84.88%",
-		84.88..=84.89 => "84.89%",
-		84.89..=84.90 => "84.90%",
-		84.90..=84.91 => "84.91%",
-		84.91..=84.92 => "84.92%",
-		84.92..=84.93 => "84.93%",
-		84.93..=84.94 => "84.94%",
-		84.94..=84.95 => "84.95%",
-		84.95..=84.96 => "84.96%",
-		84.96..=84.97 => "84.97%",
-		84.97..=84.98 => "84.98%",
-		84.98..=84.99 => "84.99%",
-		84.99..=85.00 => "85.00%",
-		85.00..=85.01 => "85.01%",
-		85.01..=85.02 => "85.02%",
-		85.02..=85.03 => "85.03%",
-		85.03..=85.04 => "85.04%",
-		85.04..=85.05 => "85.05%",
-		85.05..=85.06 => "85.06%",
-		85.06..=85.07 => "85.07%",
-		85.07..=85.08 => "85.08%",
-		85.08..=85.09 => "85.09%",
-		85.09..=85.10 => "85.10%",
-		85.10..=85.11 => "85.11%",
-		85.11..=85.12 => "85.12%",
-		85.12..=85.13 => "85.13%",
-		85.13..=85.14 => "85.14%",
-		85.14..=85.15 => "85.15%",
-		85.15..=85.16 => "85.16%",
-		85.16..=85.17 => "85.17%",
-		85.17..=85.18 => "85.18%",
-		85.18..=85.19 => "85.19%",
-		85.19..=85.20 => "85.20%",
-		85.20..=85.21 => "85.21%",
-		85.21..=85.22 => "85.22%",
-		85.22..=85.23 => "85.23%",
-		85.23..=85.24 => "85.24%",
-		85.24..=85.25 => "85.25%",
-		85.25..=85.26 => "85.26%",
-		85.26..=85.27 => "85.27%",
-		85.27..=85.28 => "85.28%",
-		85.28..=85.29 => "85.29%",
-		85.29..=85.30 => "85.30%",
-		85.30..=85.31 => "85.31%",
-		85.31..=85.32 => "85.32%",
-		85.32..=85.33 => "85.33%",
-		85.33..=85.34 => "85.34%",
-		85.34..=85.35 => "85.35%",
-		85.35..=85.36 => "85.36%",
-		85.36..=85.37 => "85.37%",
-		85.37..=85.38 => "85.38%",
-		85.38..=85.39 => "85.39%",
-		85.39..=85.40 => "85.40%",
-		85.40..=85.41 => "85.41%",
-		85.41..=85.42 => "85.42%",
-		85.42..=85.43 => "85.43%",
-		85.43..=85.44 => "85.44%",
-		85.44..=85.45 => "85.45%",
-		85.45..=85.46 => "85.46%",
-		85.46..=85.47 => "85.47%",
-		85.47..=85.48 => "85.48%",
-		85.48..=85.49 => "85.49%",
-		85.49..=85.50 => "85.50%",
-		85.50..=85.51 => "85.51%",
-		85.51..=85.52 => "85.52%",
-		85.52..=85.53 => "85.53%",
-		85.53..=85.54 => "85.54%",
-		85.54..=85.55 => "85.55%",
-		85.55..=85.56 => "85.56%",
-		85.56..=85.57 => "85.57%",
-		85.57..=85.58 => "85.58%",
-		85.58..=85.59 => "85.59%",
-		85.59..=85.60 => "85.60%",
-		85.60..=85.61 => "85.61%",
-		85.61..=85.62 => "85.62%",
-		85.62..=85.63 => "85.63%",
-		85.63..=85.64 => "85.64%",
-		85.64..=85.65 => "85.65%",
-		85.65..=85.66 => "85.66%",
-		85.66..=85.67 => "85.67%",
-		85.67..=85.68 => "85.68%",
-		85.68..=85.69 => "85.69%",
-		85.69..=85.70 => "85.70%",
-		85.70..=85.71 => "85.71%",
-		85.71..=85.72 => "85.72%",
-		85.72..=85.73 => "85.73%",
-		85.73..=85.74 => "85.74%",
-		85.74..=85.75 => "85.75%",
-		85.75..=85.76 => "85.76%",
-		85.76..=85.77 => "85.77%",
-		85.77..=85.78 => "85.78%",
-		85.78..=85.79 => "85.79%",
-		85.79..=85.80 => "85.80%",
-		85.80..=85.81 => "85.81%",
-		85.81..=85.82 => "85.82%",
-		85.82..=85.83 => "85.83%",
-		85.83..=85.84 => "85.84%",
-		85.84..=85.85 => "85.85%",
-		85.85..=85.86 => "85.86%",
-		85.86..=85.87 => "85.87%",
-		85.87..=85.88 => "85.88%",
-		85.88..=85.89 => "85.89%",
-		85.89..=85.90 => "85.90%",
-		85.90..=85.91 => "85.91%",
-		85.91..=85.92 => "85.92%",
-		85.92..=85.93 => "85.93%",
-		85.93..=85.94 => "85.94%",
-		85.94..=85.95 => "85.95%",
-		85.95..=85.96 => "85.96%",
-		85.96..=85.97 => "85.97%",
-		85.97..=85.98 => "85.98%",
-		85.98..=85.99 => "85.99%",
-		85.99..=86.00 => "86.00%",
-		86.00..=86.01 => "86.01%",
-		86.01..=86.02 => "86.02%",
-		86.02..=86.03 => "86.03%",
-		86.03..=86.04 => "86.04%",
-		86.04..=86.05 => "86.05%",
-		86.05..=86.06 => "86.06%",
-		86.06..=86.07 => "86.07%",
-		86.07..=86.08 => "86.08%",
-		86.08..=86.09 => "86.09%",
-		86.09..=86.10 => "86.10%",
-		86.10..=86.11 => "86.11%",
-		86.11..=86.12 => "86.12%",
-		86.12..=86.13 => "86.13%",
-		86.13..=86.14 => "86.14%",
-		86.14..=86.15 => "86.15%",
-		86.15..=86.16 => "86.16%",
-		86.16..=86.17 => "86.17%",
-		86.17..=86.18 => "86.18%",
-		86.18..=86.19 => "86.19%",
-		86.19..=86.20 => "86.20%",
-		86.20..=86.21 => "86.21%",
-		86.21..=86.22 => "86.22%",
-		86.22..=86.23 => "86.23%",
-		86.23..=86.24 => "86.24%",
-		86.24..=86.25 => "86.25%",
-		86.25..=86.26 => "86.26%",
-		86.26..=86.27 => "86.27%",
-		86.27..=86.28 => "86.28%",
-		86.28..=86.29 => "86.29%",
-		86.29..=86.30 => "86.30%",
-		86.30..=86.31 => "86.31%",
-		86.31..=86.32 => "86.32%",
-		86.32..=86.33 => "86.33%",
-		86.33..=86.34 => "86.34%",
-		86.34..=86.35 => "86.35%",
-		86.35..=86.36 => "86.36%",
-		86.36..=86.37 => "86.37%",
-		86.37..=86.38 => "86.38%",
-		86.38..=86.39 => "86.39%",
-		86.39..=86.40 => "86.40%",
-		86.40..=86.41 => "86.41%",
-		86.41..=86.42 => "86.42%",
-		86.42..=86.43 => "86.43%",
-		86.43..=86.44 => "86.44%",
-		86.44..=86.45 => "86.45%",
-		86.45..=86.46 => "86.46%",
-		86.46..=86.47 => "86.47%",
-		86.47..=86.48 => "86.48%",
-		86.48..=86.49 => "86.49%",
-		86.49..=86.50 => "86.50%",
-		86.50..=86.51 => "86.51%",
-		86.51..=86.52 => "86.52%",
-		86.52..=86.53 => "86.53%",
-		86.53..=86.54 => "86.54%",
-		86.54..=86.55 => "86.55%",
-		86.55..=86.56 => "86.56%",
-		86.56..=86.57 => "86.57%",
-		86.57..=86.58 => "86.58%",
-		86.58..=86.59 => "86.59%",
-		86.59..=86.60 => "86.60%",
-		86.60..=86.61 => "86.61%",
-		86.61..=86.62 => "86.62%",
-		86.62..=86.63 => "86.63%",
-		86.63..=86.64 => "86.64%",
-		86.64..=86.65 => "86.65%",
-		86.65..=86.66 => "86.66%",
-		86.66..=86.67 => "86.67%",
-		86.67..=86.68 => "86.68%",
-		86.68..=86.69 => "86.69%",
-		86.69..=86.70 => "86.70%",
-		86.70..=86.71 => "86.71%",
-		86.71..=86.72 => "86.72%",
-		86.72..=86.73 => "86.73%",
-		86.73..=86.74 => "86.74%",
-		86.74..=86.75 => "86.75%",
-		86.75..=86.76 => "86.76%",
-		86.76..=86.77 => "86.77%",
-		86.77..=86.78 => "86.78%",
-		86.78..=86.79 => "86.79%",
-		86.79..=86.80 => "86.80%",
-		86.80..=86.81 => "86.81%",
-		86.81..=86.82 => "86.82%",
-		86.82..=86.83 => "86.83%",
-		86.83..=86.84 => "86.84%",
-		86.84..=86.85 => "86.85%",
-		86.85..=86.86 => "86.86%",
-		86.86..=86.87 => "86.87%",
-		86.87..=86.88 => "86.88%",
-		86.88..=86.89 => "86.89%",
-		86.89..=86.90 => "86.90%",
-		86.90..=86.91 => "86.91%",
-		86.91..=86.92 => "86.92%",
-		86.92..=86.93 => "86.93%",
-		86.93..=86.94 => "86.94%",
-		86.94..=86.95 => "86.95%",
-		86.95..=86.96 => "86.96%",
-		86.96..=86.97 => "86.97%",
-		86.97..=86.98 => "86.98%",
-		86.98..=86.99 => "86.99%",
-		86.99..=87.00 => "87.00%",
-		87.00..=87.01 => "87.01%",
-		87.01..=87.02 => "87.02%",
-		87.02..=87.03 => "87.03%",
-		87.03..=87.04 => "87.04%",
-		87.04..=87.05 => "87.05%",
-		87.05..=87.06 => "87.06%",
-		87.06..=87.07 => "87.07%",
-		87.07..=87.08 => "87.08%",
-		87.08..=87.09 => "87.09%",
-		87.09..=87.10 => "87.10%",
-		87.10..=87.11 => "87.11%",
-		87.11..=87.12 => "87.12%",
-		87.12..=87.13 => "87.13%",
-		87.13..=87.14 => "87.14%",
-		87.14..=87.15 => "87.15%",
-		87.15..=87.16 => "87.16%",
-		87.16..=87.17 => "87.17%",
-		87.17..=87.18 => "87.18%",
-		87.18..=87.19 => "87.19%",
-		87.19..=87.20 => "87.20%",
-		87.20..=87.21 => "87.21%",
-		87.21..=87.22 => "87.22%",
-		87.22..=87.23 => "87.23%",
-		87.23..=87.24 => "87.24%",
-		87.24..=87.25 => "87.25%",
-		87.25..=87.26 => "87.26%",
-		87.26..=87.27 => "87.27%",
-		87.27..=87.28 => "87.28%",
-		87.28..=87.29 => "87.29%",
-		87.29..=87.30 => "87.30%",
-		87.30..=87.31 => "87.31%",
-		87.31..=87.32 => "87.32%",
-		87.32..=87.33 => "87.33%",
-		87.33..=87.34 => "87.34%",
-		87.34..=87.35 => "87.35%",
-		87.35..=87.36 => "87.36%",
-		87.36..=87.37 => "87.37%",
-		87.37..=87.38 => "87.38%",
-		87.38..=87.39 => "87.39%",
-		87.39..=87.40 => "87.40%",
-		87.40..=87.41 => "87.41%",
-		87.41..=87.42 => "87.42%",
-		87.42..=87.43 => "87.43%",
-		87.43..=87.44 => "87.44%",
-		87.44..=87.45 => "87.45%",
-		87.45..=87.46 => "87.46%",
-		87.46..=87.47 => "87.47%",
-		87.47..=87.48 => "87.48%",
-		87.48..=87.49 => "87.49%",
-		87.49..=87.50 => "87.50%",
-		87.50..=87.51 => "87.51%",
-		87.51..=87.52 => "87.52%",
-		87.52..=87.53 => "87.53%",
-		87.53..=87.54 => "87.54%",
-		87.54..=87.55 => "87.55%",
-		87.55..=87.56 => "87.56%",
-		87.56..=87.57 => "87.57%",
-		87.57..=87.58 => "87.58%",
-		87.58..=87.59 => "87.59%",
-		87.59..=87.60 => "87.60%",
-		87.60..=87.61 => "87.61%",
-		87.61..=87.62 => "87.62%",
-		87.62..=87.63 => "87.63%",
-		87.63..=87.64 => "87.64%",
-		87.64..=87.65 => "87.65%",
-		87.65..=87.66 => "87.66%",
-		87.66..=87.67 => "87.67%",
-		87.67..=87.68 => "87.68%",
-		87.68..=87.69 => "87.69%",
-		87.69..=87.70 => "87.70%",
-		87.70..=87.71 => "87.71%",
-		87.71..=87.72 => "87.72%",
-		87.72..=87.73 => "87.73%",
-		87.73..=87.74 => "87.74%",
-		87.74..=87.75 => "87.75%",
-		87.75..=87.76 => "87.76%",
-		87.76..=87.77 => "87.77%",
-		87.77..=87.78 => "87.78%",
-		87.78..=87.79 => "87.79%",
-		87.79..=87.80 => "87.80%",
-		87.80..=87.81 => "87.81%",
-		87.81..=87.82 => "87.82%",
-		87.82..=87.83 => "87.83%",
-		87.83..=87.84 => "87.84%",
-		87.84..=87.85 => "87.85%",
-		87.85..=87.86 => "87.86%",
-		87.86..=87.87 => "87.87%",
-		87.87..=87.88 => "87.88%",
-		87.88..=87.89 => "87.89%",
-		87.89..=87.90 => "87.90%",
-		87.90..=87.91 => "87.91%",
-		87.91..=87.92 => "87.92%",
-		87.92..=87.93 => "87.93%",
-		87.93..=87.94 => "87.94%",
-		87.94..=87.95 => "87.95%",
-		87.95..=87.96 => "87.96%",
-		87.96..=87.97 => "87.97%",
-		87.97..=87.98 => "87.98%",
-		87.98..=87.99 => "87.99%",
-		87.99..=88.00 => "88.00%",
-		88.00..=88.01 => "88.01%",
-		88.01..=88.02 => "88.02%",
-		88.02..=88.03 => "88.03%",
-		88.03..=88.04 => "88.04%",
-		88.04..=88.05 => "88.05%",
-		88.05..=88.06 => "88.06%",
-		88.06..=88.07 => "88.07%",
-		88.07..=88.08 => "88.08%",
-		88.08..=88.09 => "88.09%",
-		88.09..=88.10 => "88.10%",
-		88.10..=88.11 => "88.11%",
-		88.11..=88.12 => "88.12%",
-		88.12..=88.13 => "88.13%",
-		88.13..=88.14 => "88.14%",
-		88.14..=88.15 => "88.15%",
-		88.15..=88.16 => "88.16%",
-		88.16..=88.17 => "88.17%",
-		88.17..=88.18 => "88.18%",
-		88.18..=88.19 => "88.19%",
-		88.19..=88.20 => "88.20%",
-		88.20..=88.21 => "88.21%",
-		88.21..=88.22 => "88.22%",
-		88.22..=88.23 => "88.23%",
-		88.23..=88.24 => "88.24%",
-		88.24..=88.25 => "88.25%",
-		88.25..=88.26 => "88.26%",
-		88.26..=88.27 => "88.27%",
-		88.27..=88.28 => "88.28%",
-		88.28..=88.29 => "88.29%",
-		88.29..=88.30 => "88.30%",
-		88.30..=88.31 => "88.31%",
-		88.31..=88.32 => "88.32%",
-		88.32..=88.33 => "88.33%",
-		88.33..=88.34 => "88.34%",
-		88.34..=88.35 => "88.35%",
-		88.35..=88.36 => "88.36%",
-		88.36..=88.37 => "88.37%",
-		88.37..=88.38 => "88.38%",
-		88.38..=88.39 => "88.39%",
-		88.39..=88.40 => "88.40%",
-		88.40..=88.41 => "88.41%",
-		88.41..=88.42 => "88.42%",
-		88.42..=88.43 => "88.43%",
-		88.43..=88.44 => "88.44%",
-		88.44..=88.45 => "88.45%",
-		88.45..=88.46 => "88.46%",
-		88.46..=88.47 => "88.47%",
-		88.47..=88.48 => "88.48%",
-		88.48..=88.49 => "88.49%",
-		88.49..=88.50 => "88.50%",
-		88.50..=88.51 => "88.51%",
-		88.51..=88.52 => "88.52%",
-		88.52..=88.53 => "88.53%",
-		88.53..=88.54 => "88.54%",
-		88.54..=88.55 => "88.55%",
-		88.55..=88.56 => "88.56%",
-		88.56..=88.57 => "88.57%",
-		88.57..=88.58 => "88.58%",
-		88.58..=88.59 => "88.59%",
-		88.59..=88.60 => "88.60%",
-		88.60..=88.61 => "88.61%",
-		88.61..=88.62 => "88.62%",
-		88.62..=88.63 => "88.63%",
-		88.63..=88.64 => "88.64%",
-		88.64..=88.65 => "88.65%",
-		88.65..=88.66 => "88.66%",
-		88.66..=88.67 => "88.67%",
-		88.67..=88.68 => "88.68%",
-		88.68..=88.69 => "88.69%",
-		88.69..=88.70 => "88.70%",
-		88.70..=88.71 => "88.71%",
-		88.71..=88.72 => "88.72%",
-		88.72..=88.73 => "88.73%",
-		88.73..=88.74 => "88.74%",
-		88.74..=88.75 => "88.75%",
-		88.75..=88.76 => "88.76%",
-		88.76..=88.77 => "88.77%",
-		88.77..=88.78 => "88.78%",
-		88.78..=88.79 => "88.79%",
-		88.79..=88.80 => "88.80%",
-		88.80..=88.81 => "88.81%",
-		88.81..=88.82 => "88.82%",
-		88.82..=88.83 => "88.83%",
-		88.83..=88.84 => "88.84%",
-		88.84..=88.85 => "88.85%",
-		88.85..=88.86 => "88.86%",
-		88.86..=88.87 => "88.87%",
-		88.87..=88.88 => "88.88%",
-		88.88..=88.89 => "88.89%",
-		88.89..=88.90 => "88.90%",
-		88.90..=88.91 => "88.91%",
-		88.91..=88.92 => "88.92%",
-		88.92..=88.93 => "88.93%",
-		88.93..=88.94 => "88.94%",
-		88.94..=88.95 => "88.95%",
-		88.95..=88.96 => "88.96%",
-		88.96..=88.97 => "88.97%",
-		88.97..=88.98 => "88.98%",
-		88.98..=88.99 => "88.99%",
-		88.99..=89.00 => "89.00%",
-		89.00..=89.01 => "89.01%",
-		89.01..=89.02 => "89.02%",
-		89.02..=89.03 => "89.03%",
-		89.03..=89.04 => "89.04%",
-		89.04..=89.05 => "89.05%",
-		89.05..=89.06 => "89.06%",
-		89.06..=89.07 => "89.07%",
-		89.07..=89.08 => "89.08%",
-		89.08..=89.09 => "89.09%",
-		89.09..=89.10 => "89.10%",
-		89.10..=89.11 => "89.11%",
-		89.11..=89.12 => "89.12%",
-		89.12..=89.13 => "89.13%",
-		89.13..=89.14 => "89.14%",
-		89.14..=89.15 => "89.15%",
-		89.15..=89.16 => "89.16%",
-		89.16..=89.17 => "89.17%",
-		89.17..=89.18 => "89.18%",
-		89.18..=89.19 => "89.19%",
-		89.19..=89.20 => "89.20%",
-		89.20..=89.21 => "89.21%",
-		89.21..=89.22 => "89.22%",
-		89.22..=89.23 => "89.23%",
-		89.23..=89.24 => "89.24%",
-		89.24..=89.25 => "89.25%",
-		89.25..=89.26 => "89.26%",
-		89.26..=89.27 => "89.27%",
-		89.27..=89.28 => "89.28%",
-		89.28..=89.29 => "89.29%",
-		89.29..=89.30 => "89.30%",
-		89.30..=89.31 => "89.31%",
-		89.31..=89.32 => "89.32%",
-		89.32..=89.33 => "89.33%",
-		89.33..=89.34 => "89.34%",
-		89.34..=89.35 => "89.35%",
-		89.35..=89.36 => "89.36%",
-		89.36..=89.37 => "89.37%",
-		89.37..=89.38 => "89.38%",
-		89.38..=89.39 => "89.39%",
-		89.39..=89.40 => "89.40%",
-		89.40..=89.41 => "89.41%",
-		89.41..=89.42 => "89.42%",
-		89.42..=89.43 => "89.43%",
-		89.43..=89.44 => "89.44%",
-		89.44..=89.45 => "89.45%",
-		89.45..=89.46 => "89.46%",
-		89.46..=89.47 => "89.47%",
-		89.47..=89.48 => "89.48%",
-		89.48..=89.49 => "89.49%",
-		89.49..=89.50 => "89.50%",
-		89.50..=89.51 => "89.51%",
-		89.51..=89.52 => "89.52%",
-		89.52..=89.53 => "89.53%",
-		89.53..=89.54 => "89.54%",
-		89.54..=89.55 => "89.55%",
-		89.55..=89.56 => "89.56%",
-		89.56..=89.57 => "89.57%",
-		89.57..=89.58 => "89.58%",
-		89.58..=89.59 => "89.59%",
-		89.59..=89.60 => "89.60%",
-		89.60..=89.61 => "89.61%",
-		89.61..=89.62 => "89.62%",
-		89.62..=89.63 => "89.63%",
-		89.63..=89.64 => "89.64%",
-		89.64..=89.65 => "89.65%",
-		89.65..=89.66 => "89.66%",
-		89.66..=89.67 => "89.67%",
-		89.67..=89.68 => "89.68%",
-		89.68..=89.69 => "89.69%",
-		89.69..=89.70 => "89.70%",
-		89.70..=89.71 => "89.71%",
-		89.71..=89.72 => "89.72%",
-		89.72..=89.73 => "89.73%",
-		89.73..=89.74 => "89.74%",
-		89.74..=89.75 => "89.75%",
-		89.75..=89.76 => "89.76%",
-		89.76..=89.77 => "89.77%",
-		89.77..=89.78 => "89.78%",
-		89.78..=89.79 => "89.79%",
-		89.79..=89.80 => "89.80%",
-		89.80..=89.81 => "89.81%",
-		89.81..=89.82 => "89.82%",
-		89.82..=89.83 => "89.83%",
-		89.83..=89.84 => "89.84%",
-		89.84..=89.85 => "89.85%",
-		89.85..=89.86 => "89.86%",
-		89.86..=89.87 => "89.87%",
-		89.87..=89.88 => "89.88%",
-		89.88..=89.89 => "89.89%",
-		89.89..=89.90 => "89.90%",
-		89.90..=89.91 => "89.91%",
-		89.91..=89.92 => "89.92%",
-		89.92..=89.93 => "89.93%",
-		89.93..=89.94 => "89.94%",
-		89.94..=89.95 => "89.95%",
-		89.95..=89.96 => "89.96%",
-		89.96..=89.97 => "89.97%",
-		89.97..=89.98 => "89.98%",
-		89.98..=89.99 => "89.99%",
-		89.99..=90.00 => "90.00%",
-		90.00..=90.01 => "90.01%",
-		90.01..=90.02 => "90.02%",
-		90.02..=90.03 => "90.03%",
-		90.03..=90.04 => "90.04%",
-		90.04..=90.05 => "90.05%",
-		90.05..=90.06 => "90.06%",
-		90.06..=90.07 => "90.07%",
-		90.07..=90.08 => "90.08%",
-		90.08..=90.09 => "90.09%",
-		90.09..=90.10 => "90.10%",
-		90.10..=90.11 => "90.11%",
-		90.11..=90.12 => "90.12%",
-		90.12..=90.13 => "90.13%",
-		90.13..=90.14 => "90.14%",
-		90.14..=90.15 => "90.15%",
-		90.15..=90.16 => "90.16%",
-		90.16..=90.17 => "90.17%",
-		90.17..=90.18 => "90.18%",
-		90.18..=90.19 => "90.19%",
-		90.19..=90.20 => "90.20%",
-		90.20..=90.21 => "90.21%",
-		90.21..=90.22 => "90.22%",
-		90.22..=90.23 => "90.23%",
-		90.23..=90.24 => "90.24%",
-		90.24..=90.25 => "90.25%",
-		90.25..=90.26 => "90.26%",
-		90.26..=90.27 => "90.27%",
-		90.27..=90.28 => "90.28%",
-		90.28..=90.29 => "90.29%",
-		90.29..=90.30 => "90.30%",
-		90.30..=90.31 => "90.31%",
-		90.31..=90.32 => "90.32%",
-		90.32..=90.33 => "90.33%",
-		90.33..=90.34 => "90.34%",
-		90.34..=90.35 => "90.35%",
-		90.35..=90.36 => "90.36%",
-		90.36..=90.37 => "90.37%",
-		90.37..=90.38 => "90.38%",
-		90.38..=90.39 => "90.39%",
-		90.39..=90.40 => "90.40%",
-		90.40..=90.41 => "90.41%",
-		90.41..=90.42 => "90.42%",
-		90.42..=90.43 => "90.43%",
-		90.43..=90.44 => "90.44%",
-		90.44..=90.45 => "90.45%",
-		90.45..=90.46 => "90.46%",
-		90.46..=90.47 => "90.47%",
-		90.47..=90.48 => "90.48%",
-		90.48..=90.49 => "90.49%",
-		90.49..=90.50 => "90.50%",
-		90.50..=90.51 => "90.51%",
-		90.51..=90.52 => "90.52%",
-		90.52..=90.53 => "90.53%",
-		90.53..=90.54 => "90.54%",
-		90.54..=90.55 => "90.55%",
-		90.55..=90.56 => "90.56%",
-		90.56..=90.57 => "90.57%",
-		90.57..=90.58 => "90.58%",
-		90.58..=90.59 => "90.59%",
-		90.59..=90.60 => "90.60%",
-		90.60..=90.61 => "90.61%",
-		90.61..=90.62 => "90.62%",
-		90.62..=90.63 => "90.63%",
-		90.63..=90.64 => "90.64%",
-		90.64..=90.65 => "90.65%",
-		90.65..=90.66 => "90.66%",
-		90.66..=90.67 => "90.67%",
-		90.67..=90.68 => "90.68%",
-		90.68..=90.69 => "90.69%",
-		90.69..=90.70 => "90.70%",
-		90.70..=90.71 => "90.71%",
-		90.71..=90.72 => "90.72%",
-		90.72..=90.73 => "90.73%",
-		90.73..=90.74 => "90.74%",
-		90.74..=90.75 => "90.75%",
-		90.75..=90.76 => "90.76%",
-		90.76..=90.77 => "90.77%",
-		90.77..=90.78 => "90.78%",
-		90.78..=90.79 => "90.79%",
-		90.79..=90.80 => "90.80%",
-		90.80..=90.81 => "90.81%",
-		90.81..=90.82 => "90.82%",
-		90.82..=90.83 => "90.83%",
-		90.83..=90.84 => "90.84%",
-		90.84..=90.85 => "90.85%",
-		90.85..=90.86 => "90.86%",
-		90.86..=90.87 => "90.87%",
-		90.87..=90.88 => "90.88%",
-		90.88..=90.89 => "90.89%",
-		90.89..=90.90 => "90.90%",
-		90.90..=90.91 => "90.91%",
-		90.91..=90.92 => "90.92%",
-		90.92..=90.93 => "90.93%",
-		90.93..=90.94 => "90.94%",
-		90.94..=90.95 => "90.95%",
-		90.95..=90.96 => "90.96%",
-		90.96..=90.97 => "90.97%",
-		90.97..=90.98 => "90.98%",
-		90.98..=90.99 => "90.99%",
-		90.99..=91.00 => "91.00%",
-		91.00..=91.01 => "91.01%",
-		91.01..=91.02 => "91.02%",
-		91.02..=91.03 => "91.03%",
-		91.03..=91.04 => "91.04%",
-		91.04..=91.05 => "91.05%",
-		91.05..=91.06 => "91.06%",
-		91.06..=91.07 => "91.07%",
-		91.07..=91.08 => "91.08%",
-		91.08..=91.09 => "91.09%",
-		91.09..=91.10 => "91.10%",
-		91.10..=91.11 => "91.11%",
-		91.11..=91.12 => "91.12%",
-		91.12..=91.13 => "91.13%",
-		91.13..=91.14 => "91.14%",
-		91.14..=91.15 => "91.15%",
-		91.15..=91.16 => "91.16%",
-		91.16..=91.17 => "91.17%",
-		91.17..=91.18 => "91.18%",
-		91.18..=91.19 => "91.19%",
-		91.19..=91.20 => "91.20%",
-		91.20..=91.21 => "91.21%",
-		91.21..=91.22 => "91.22%",
-		91.22..=91.23 => "91.23%",
-		91.23..=91.24 => "91.24%",
-		91.24..=91.25 => "91.25%",
-		91.25..=91.26 => "91.26%",
-		91.26..=91.27 => "91.27%",
-		91.27..=91.28 => "91.28%",
-		91.28..=91.29 => "91.29%",
-		91.29..=91.30 => "91.30%",
-		91.30..=91.31 => "91.31%",
-		91.31..=91.32 => "91.32%",
-		91.32..=91.33 => "91.33%",
-		91.33..=91.34 => "91.34%",
-		91.34..=91.35 => "91.35%",
-		91.35..=91.36 => "91.36%",
-		91.36..=91.37 => "91.37%",
-		91.37..=91.38 => "91.38%",
-		91.38..=91.39 => "91.39%",
-		91.39..=91.40 => "91.40%",
-		91.40..=91.41 => "91.41%",
-		91.41..=91.42 => "91.42%",
-		91.42..=91.43 => "91.43%",
-		91.43..=91.44 => "91.44%",
-		91.44..=91.45 => "91.45%",
-		91.45..=91.46 => "91.46%",
-		91.46..=91.47 => "91.47%",
-		91.47..=91.48 => "91.48%",
-		91.48..=91.49 => "91.49%",
-		91.49..=91.50 => "91.50%",
-		91.50..=91.51 => "91.51%",
-		91.51..=91.52 => "91.52%",
-		91.52..=91.53 => "91.53%",
-		91.53..=91.54 => "91.54%",
-		91.54..=91.55 => "91.55%",
-		91.55..=91.56 => "91.56%",
-		91.56..=91.57 => "91.57%",
-		91.57..=91.58 => "91.58%",
-		91.58..=91.59 => "91.59%",
-		91.59..=91.60 => "91.60%",
-		91.60..=91.61 => "91.61%",
-		91.61..=91.62 => "91.62%",
-		91.62..=91.63 => "91.63%",
-		91.63..=91.64 => "91.64%",
-		91.64..=91.65 => "91.65%",
-		91.65..=91.66 => "91.66%",
-		91.66..=91.67 => "91.67%",
-		91.67..=91.68 => "91.68%",
-		91.68..=91.69 => "91.69%",
-		91.69..=91.70 => "91.70%",
-		91.70..=91.71 => "91.71%",
-		91.71..=91.72 => "91.72%",
-		91.72..=91.73 => "91.73%",
-		91.73..=91.74 => "91.74%",
-		91.74..=91.75 => "91.75%",
-		91.75..=91.76 => "91.76%",
-		91.76..=91.77 => "91.77%",
-		91.77..=91.78 => "91.78%",
-		91.78..=91.79 => "91.79%",
-		91.79..=91.80 => "91.80%",
-		91.80..=91.81 => "91.81%",
-		91.81..=91.82 => "91.82%",
-		91.82..=91.83 => "91.83%",
-		91.83..=91.84 => "91.84%",
-		91.84..=91.85 => "91.85%",
-		91.85..=91.86 => "91.86%",
-		91.86..=91.87 => "91.87%",
-		91.87..=91.88 => "91.88%",
-		91.88..=91.89 => "91.89%",
-		91.89..=91.90 => "91.90%",
-		91.90..=91.91 => "91.91%",
-		91.91..=91.92 => "91.92%",
-		91.92..=91.93 => "91.93%",
-		91.93..=91.94 => "91.94%",
-		91.94..=91.95 => "91.95%",
-		91.95..=91.96 => "91.96%",
-		91.96..=91.97 => "91.97%",
-		91.97..=91.98 => "91.98%",
-		91.98..=91.99 => "91.99%",
-		91.99..=92.00 => "92.00%",
-		92.00..=92.01 => "92.01%",
-		92.01..=92.02 => "92.02%",
-		92.02..=92.03 => "92.03%",
-		92.03..=92.04 => "92.04%",
-		92.04..=92.05 => "92.05%",
-		92.05..=92.06 => "92.06%",
-		92.06..=92.07 => "92.07%",
-		92.07..=92.08 => "92.08%",
-		92.08..=92.09 => "92.09%",
-		92.09..=92.10 => "92.10%",
-		92.10..=92.11 => "92.11%",
-		92.11..=92.12 => "92.12%",
-		92.12..=92.13 => "92.13%",
-		92.13..=92.14 => "92.14%",
-		92.14..=92.15 => "92.15%",
-		92.15..=92.16 => "92.16%",
-		92.16..=92.17 => "92.17%",
-		92.17..=92.18 => "92.18%",
-		92.18..=92.19 => "92.19%",
-		92.19..=92.20 => "92.20%",
-		92.20..=92.21 => "92.21%",
-		92.21..=92.22 => "92.22%",
-		92.22..=92.23 => "92.23%",
-		92.23..=92.24 => "92.24%",
-		92.24..=92.25 => "92.25%",
-		92.25..=92.26 => "92.26%",
-		92.26..=92.27 => "92.27%",
-		92.27..=92.28 => "92.28%",
-		92.28..=92.29 => "92.29%",
-		92.29..=92.30 => "92.30%",
-		92.30..=92.31 => "92.31%",
-		92.31..=92.32 => "92.32%",
-		92.32..=92.33 => "92.33%",
-		92.33..=92.34 => "92.34%",
-		92.34..=92.35 => "92.35%",
-		92.35..=92.36 => "92.36%",
-		92.36..=92.37 => "92.37%",
-		92.37..=92.38 => "92.38%",
-		92.38..=92.39 => "92.39%",
-		92.39..=92.40 => "92.40%",
-		92.40..=92.41 => "92.41%",
-		92.41..=92.42 => "92.42%",
-		92.42..=92.43 => "92.43%",
-		92.43..=92.44 => "92.44%",
-		92.44..=92.45 => "92.45%",
-		92.45..=92.46 => "92.46%",
-		92.46..=92.47 => "92.47%",
-		92.47..=92.48 => "92.48%",
-		92.48..=92.49 => "92.49%",
-		92.49..=92.50 => "92.50%",
-		92.50..=92.51 => "92.51%",
-		92.51..=92.52 => "92.52%",
-		92.52..=92.53 => "92.53%",
-		92.53..=92.54 => "92.54%",
-		92.54..=92.55 => "92.55%",
-		92.55..=92.56 => "92.56%",
-		92.56..=92.57 => "92.57%",
-		92.57..=92.58 => "92.58%",
-		92.58..=92.59 => "92.59%",
-		92.59..=92.60 => "92.60%",
-		92.60..=92.61 => "92.61%",
-		92.61..=92.62 => "92.62%",
-		92.62..=92.63 => "92.63%",
-		92.63..=92.64 => "92.64%",
-		92.64..=92.65 => "92.65%",
-		92.65..=92.66 => "92.66%",
-		92.66..=92.67 => "92.67%",
-		92.67..=92.68 => "92.68%",
-		92.68..=92.69 => "92.69%",
-		92.69..=92.70 => "92.70%",
-		92.70..=92.71 => "92.71%",
-		92.71..=92.72 => "92.72%",
-		92.72..=92.73 => "92.73%",
-		92.73..=92.74 => "92.74%",
-		92.74..=92.75 => "92.75%",
-		92.75..=92.76 => "92.76%",
-		92.76..=92.77 => "92.77%",
-		92.77..=92.78 => "92.78%",
-		92.78..=92.79 => "92.79%",
-		92.79..=92.80 => "92.80%",
-		92.80..=92.81 => "92.81%",
-		92.81..=92.82 => "92.82%",
-		92.82..=92.83 => "92.83%",
-		92.83..=92.84 => "92.84%",
-		92.84..=92.85 => "92.85%",
-		92.85..=92.86 => "92.86%",
-		92.86..=92.87 => "92.87%",
-		92.87..=92.88 => "92.88%",
-		92.88..=92.89 => "92.89%",
-		92.89..=92.90 => "92.90%",
-		92.90..=92.91 => "92.91%",
-		92.91..=92.92 => "92.92%",
-		92.92..=92.93 => "92.93%",
-		92.93..=92.94 => "92.94%",
-		92.94..=92.95 => "92.95%",
-		92.95..=92.96 => "92.96%",
-		92.96..=92.97 => "92.97%",
-		92.97..=92.98 => "92.98%",
-		92.98..=92.99 => "92.99%",
-		92.99..=93.00 => "93.00%",
-		93.00..=93.01 => "93.01%",
-		93.01..=93.02 => "93.02%",
-		93.02..=93.03 => "93.03%",
-		93.03..=93.04 => "93.04%",
-		93.04..=93.05 => "93.05%",
-		93.05..=93.06 => "93.06%",
-		93.06..=93.07 => "93.07%",
-		93.07..=93.08 => "93.08%",
-		93.08..=93.09 => "93.09%",
-		93.09..=93.10 => "93.10%",
-		93.10..=93.11 => "93.11%",
-		93.11..=93.12 => "93.12%",
-		93.12..=93.13 => "93.13%",
-		93.13..=93.14 => "93.14%",
-		93.14..=93.15 => "93.15%",
-		93.15..=93.16 => "93.16%",
-		93.16..=93.17 => "93.17%",
-		93.17..=93.18 => "93.18%",
-		93.18..=93.19 => "93.19%",
-		93.19..=93.20 => "93.20%",
-		93.20..=93.21 => "93.21%",
-		93.21..=93.22 => "93.22%",
-		93.22..=93.23 => "93.23%",
-		93.23..=93.24 => "93.24%",
-		93.24..=93.25 => "93.25%",
-		93.25..=93.26 => "93.26%",
-		93.26..=93.27 => "93.27%",
-		93.27..=93.28 => "93.28%",
-		93.28..=93.29 => "93.29%",
-		93.29..=93.30 => "93.30%",
-		93.30..=93.31 => "93.31%",
-		93.31..=93.32 => "93.32%",
-		93.32..=93.33 => "93.33%",
-		93.33..=93.34 => "93.34%",
-		93.34..=93.35 => "93.35%",
-		93.35..=93.36 => "93.36%",
-		93.36..=93.37 => "93.37%",
-		93.37..=93.38 => "93.38%",
-		93.38..=93.39 => "93.39%",
-		93.39..=93.40 => "93.40%",
-		93.40..=93.41 => "93.41%",
-		93.41..=93.42 => "93.42%",
-		93.42..=93.43 => "93.43%",
-		93.43..=93.44 => "93.44%",
-		93.44..=93.45 => "93.45%",
-		93.45..=93.46 => "93.46%",
-		93.46..=93.47 => "93.47%",
-		93.47..=93.48 => "93.48%",
-		93.48..=93.49 => "93.49%",
-		93.49..=93.50 => "93.50%",
-		93.50..=93.51 => "93.51%",
-		93.51..=93.52 => "93.52%",
-		93.52..=93.53 => "93.53%",
-		93.53..=93.54 => "93.54%",
-		93.54..=93.55 => "93.55%",
-		93.55..=93.56 => "93.56%",
-		93.56..=93.57 => "93.57%",
-		93.57..=93.58 => "93.58%",
-		93.58..=93.59 => "93.59%",
-		93.59..=93.60 => "93.60%",
-		93.60..=93.61 => "93.61%",
-		93.61..=93.62 => "93.62%",
-		93.62..=93.63 => "93.63%",
-		93.63..=93.64 => "93.64%",
-		93.64..=93.65 => "93.65%",
-		93.65..=93.66 => "93.66%",
-		93.66..=93.67 => "93.67%",
-		93.67..=93.68 => "93.68%",
-		93.68..=93.69 => "93.69%",
-		93.69..=93.70 => "93.70%",
-		93.70..=93.71 => "93.71%",
-		93.71..=93.72 => "93.72%",
-		93.72..=93.73 => "93.73%",
-		93.73..=93.74 => "93.74%",
-		93.74..=93.75 => "93.75%",
-		93.75..=93.76 => "93.76%",
-		93.76..=93.77 => "93.77%",
-		93.77..=93.78 => "93.78%",
-		93.78..=93.79 => "93.79%",
-		93.79..=93.80 => "93.80%",
-		93.80..=93.81 => "93.81%",
-		93.81..=93.82 => "93.82%",
-		93.82..=93.83 => "93.83%",
-		93.83..=93.84 => "93.84%",
-		93.84..=93.85 => "93.85%",
-		93.85..=93.86 => "93.86%",
-		93.86..=93.87 => "93.87%",
-		93.87..=93.88 => "93.88%",
-		93.88..=93.89 => "93.89%",
-		93.89..=93.90 => "93.90%",
-		93.90..=93.91 => "93.91%",
-		93.91..=93.92 => "93.92%",
-		93.92..=93.93 => "93.93%",
-		93.93..=93.94 => "93.94%",
-		93.94..=93.95 => "93.95%",
-		93.95..=93.96 => "93.96%",
-		93.96..=93.97 => "93.97%",
-		93.97..=93.98 => "93.98%",
-		93.98..=93.99 => "93.99%",
-		93.99..=94.00 => "94.00%",
-		94.00..=94.01 => "94.01%",
-		94.01..=94.02 => "94.02%",
-		94.02..=94.03 => "94.03%",
-		94.03..=94.04 => "94.04%",
-		94.04..=94.05 => "94.05%",
-		94.05..=94.06 => "94.06%",
-		94.06..=94.07 => "94.07%",
-		94.07..=94.08 => "94.08%",
-		94.08..=94.09 => "94.09%",
-		94.09..=94.10 => "94.10%",
-		94.10..=94.11 => "94.11%",
-		94.11..=94.12 => "94.12%",
-		94.12..=94.13 => "94.13%",
-		94.13..=94.14 => "94.14%",
-		94.14..=94.15 => "94.15%",
-		94.15..=94.16 => "94.16%",
-		94.16..=94.17 => "94.17%",
-		94.17..=94.18 => "94.18%",
-		94.18..=94.19 => "94.19%",
-		94.19..=94.20 => "94.20%",
-		94.20..=94.21 => "94.21%",
-		94.21..=94.22 => "94.22%",
-		94.22..=94.23 => "94.23%",
-		94.23..=94.24 => "94.24%",
-		94.24..=94.25 => "94.25%",
-		94.25..=94.26 => "94.26%",
-		94.26..=94.27 => "94.27%",
-		94.27..=94.28 => "94.28%",
-		94.28..=94.29 => "94.29%",
-		94.29..=94.30 => "94.30%",
-		94.30..=94.31 => "94.31%",
-		94.31..=94.32 => "94.32%",
-		94.32..=94.33 => "94.33%",
-		94.33..=94.34 => "94.34%",
-		94.34..=94.35 => "94.35%",
-		94.35..=94.36 => "94.36%",
-		94.36..=94.37 => "94.37%",
-		94.37..=94.38 => "94.38%",
-		94.38..=94.39 => "94.39%",
-		94.39..=94.40 => "94.40%",
-		94.40..=94.41 => "94.41%",
-		94.41..=94.42 => "94.42%",
-		94.42..=94.43 => "94.43%",
-		94.43..=94.44 => "94.44%",
-		94.44..=94.45 => "94.45%",
-		94.45..=94.46 => "94.46%",
-		94.46..=94.47 => "94.47%",
-		94.47..=94.48 => "94.48%",
-		94.48..=94.49 => "94.49%",
-		94.49..=94.50 => "94.50%",
-		94.50..=94.51 => "94.51%",
-		94.51..=94.52 => "94.52%",
-		94.52..=94.53 => "94.53%",
-		94.53..=94.54 => "94.54%",
-		94.54..=94.55 => "94.55%",
-		94.55..=94.56 => "94.56%",
-		94.56..=94.57 => "94.57%",
-		94.57..=94.58 => "94.58%",
-		94.58..=94.59 => "94.59%",
-		94.59..=94.60 => "94.60%",
-		94.60..=94.61 => "94.61%",
-		94.61..=94.62 => "94.62%",
-		94.62..=94.63 => "94.63%",
-		94.63..=94.64 => "94.64%",
-		94.64..=94.65 => "94.65%",
-		94.65..=94.66 => "94.66%",
-		94.66..=94.67 => "94.67%",
-		94.67..=94.68 => "94.68%",
-		94.68..=94.69 => "94.69%",
-		94.69..=94.70 => "94.70%",
-		94.70..=94.71 => "94.71%",
-		94.71..=94.72 => "94.72%",
-		94.72..=94.73 => "94.73%",
-		94.73..=94.74 => "94.74%",
-		94.74..=94.75 => "94.75%",
-		94.75..=94.76 => "94.76%",
-		94.76..=94.77 => "94.77%",
-		94.77..=94.78 => "94.78%",
-		94.78..=94.79 => "94.79%",
-		94.79..=94.80 => "94.80%",
-		94.80..=94.81 => "94.81%",
-		94.81..=94.82 => "94.82%",
-		94.82..=94.83 => "94.83%",
-		94.83..=94.84 => "94.84%",
-		94.84..=94.85 => "94.85%",
-		94.85..=94.86 => "94.86%",
-		94.86..=94.87 => "94.87%",
-		94.87..=94.88 => "94.88%",
-		94.88..=94.89 => "94.89%",
-		94.89..=94.90 => "94.90%",
-		94.90..=94.91 => "94.91%",
-		94.91..=94.92 => "94.92%",
-		94.92..=94.93 => "94.93%",
-		94.93..=94.94 => "94.94%",
-		94.94..=94.95 => "94.95%",
-		94.95..=94.96 => "94.96%",
-		94.96..=94.97 => "94.97%",
-		94.97..=94.98 => "94.98%",
-		94.98..=94.99 => "94.99%",
-		94.99..=95.00 => "95.00%",
-		95.00..=95.01 => "95.01%",
-		95.01..=95.02 => "95.02%",
-		95.02..=95.03 => "95.03%",
-		95.03..=95.04 => "95.04%",
-		95.04..=95.05 => "95.05%",
-		95.05..=95.06 => "95.06%",
-		95.06..=95.07 => "95.07%",
-		95.07..=95.08 => "95.08%",
-		95.08..=95.09 => "95.09%",
-		95.09..=95.10 => "95.10%",
-		95.10..=95.11 => "95.11%",
-		95.11..=95.12 => "95.12%",
-		95.12..=95.13 => "95.13%",
-		95.13..=95.14 => "95.14%",
-		95.14..=95.15 => "95.15%",
-		95.15..=95.16 => "95.16%",
-		95.16..=95.17 => "95.17%",
-		95.17..=95.18 => "95.18%",
-		95.18..=95.19 => "95.19%",
-		95.19..=95.20 => "95.20%",
-		95.20..=95.21 => "95.21%",
-		95.21..=95.22 => "95.22%",
-		95.22..=95.23 => "95.23%",
-		95.23..=95.24 => "95.24%",
-		95.24..=95.25 => "95.25%",
-		95.25..=95.26 => "95.26%",
-		95.26..=95.27 => "95.27%",
-		95.27..=95.28 => "95.28%",
-		95.28..=95.29 => "95.29%",
-		95.29..=95.30 => "95.30%",
-		95.30..=95.31 => "95.31%",
-		95.31..=95.32 => "95.32%",
-		95.32..=95.33 => "95.33%",
-		95.33..=95.34 => "95.34%",
-		95.34..=95.35 => "95.35%",
-		95.35..=95.36 => "95.36%",
-		95.36..=95.37 => "95.37%",
-		95.37..=95.38 => "95.38%",
-		95.38..=95.39 => "95.39%",
-		95.39..=95.40 => "95.40%",
-		95.40..=95.41 => "95.41%",
-		95.41..=95.42 => "95.42%",
-		95.42..=95.43 => "95.43%",
-		95.43..=95.44 => "95.44%",
-		95.44..=95.45 => "95.45%",
-		95.45..=95.46 => "95.46%",
-		95.46..=95.47 => "95.47%",
-		95.47..=95.48 => "95.48%",
-		95.48..=95.49 => "95.49%",
-		95.49..=95.50 => "95.50%",
-		95.50..=95.51 => "95.51%",
-		95.51..=95.52 => "95.52%",
-		95.52..=95.53 => "95.53%",
-		95.53..=95.54 => "95.54%",
-		95.54..=95.55 => "95.55%",
-		95.55..=95.56 => "95.56%",
-		95.56..=95.57 => "95.57%",
-		95.57..=95.58 => "95.58%",
-		95.58..=95.59 => "95.59%",
-		95.59..=95.60 => "95.60%",
-		95.60..=95.61 => "95.61%",
-		95.61..=95.62 => "95.62%",
-		95.62..=95.63 => "95.63%",
-		95.63..=95.64 => "95.64%",
-		95.64..=95.65 => "95.65%",
-		95.65..=95.66 => "95.66%",
-		95.66..=95.67 => "95.67%",
-		95.67..=95.68 => "95.68%",
-		95.68..=95.69 => "95.69%",
-		95.69..=95.70 => "95.70%",
-		95.70..=95.71 => "95.71%",
-		95.71..=95.72 => "95.72%",
-		95.72..=95.73 => "95.73%",
-		95.73..=95.74 => "95.74%",
-		95.74..=95.75 => "95.75%",
-		95.75..=95.76 => "95.76%",
-		95.76..=95.77 => "95.77%",
-		95.77..=95.78 => "95.78%",
-		95.78..=95.79 => "95.79%",
-		95.79..=95.80 => "95.80%",
-		95.80..=95.81 => "95.81%",
-		95.81..=95.82 => "95.82%",
-		95.82..=95.83 => "95.83%",
-		95.83..=95.84 => "95.84%",
-		95.84..=95.85 => "95.85%",
-		95.85..=95.86 => "95.86%",
-		95.86..=95.87 => "95.87%",
-		95.87..=95.88 => "95.88%",
-		95.88..=95.89 => "95.89%",
-		95.89..=95.90 => "95.90%",
-		95.90..=95.91 => "95.91%",
-		95.91..=95.92 => "95.92%",
-		95.92..=95.93 => "95.93%",
-		95.93..=95.94 => "95.94%",
-		95.94..=95.95 => "95.95%",
-		95.95..=95.96 => "95.96%",
-		95.96..=95.97 => "95.97%",
-		95.97..=95.98 => "95.98%",
-		95.98..=95.99 => "95.99%",
-		95.99..=96.00 => "96.00%",
-		96.00..=96.01 => "96.01%",
-		96.01..=96.02 => "96.02%",
-		96.02..=96.03 => "96.03%",
-		96.03..=96.04 => "96.04%",
-		96.04..=96.05 => "96.05%",
-		96.05..=96.06 => "96.06%",
-		96.06..=96.07 => "96.07%",
-		96.07..=96.08 => "96.08%",
-		96.08..=96.09 => "96.09%",
-		96.09..=96.10 => "96.10%",
-		96.10..=96.11 => "96.11%",
-		96.11..=96.12 => "96.12%",
-		96.12..=96.13 => "96.13%",
-		96.13..=96.14 => "96.14%",
-		96.14..=96.15 => "96.15%",
-		96.15..=96.16 => "96.16%",
-		96.16..=96.17 => "96.17%",
-		96.17..=96.18 => "96.18%",
-		96.18..=96.19 => "96.19%",
-		96.19..=96.20 => "96.20%",
-		96.20..=96.21 => "96.21%",
-		96.21..=96.22 => "96.22%",
-		96.22..=96.23 => "96.23%",
-		96.23..=96.24 => "96.24%",
-		96.24..=96.25 => "96.25%",
-		96.25..=96.26 => "96.26%",
-		96.26..=96.27 => "96.27%",
-		96.27..=96.28 => "96.28%",
-		96.28..=96.29 => "96.29%",
-		96.29..=96.30 => "96.30%",
-		96.30..=96.31 => "96.31%",
-		96.31..=96.32 => "96.32%",
-		96.32..=96.33 => "96.33%",
-		96.33..=96.34 => "96.34%",
-		96.34..=96.35 => "96.35%",
-		96.35..=96.36 => "96.36%",
-		96.36..=96.37 => "96.37%",
-		96.37..=96.38 => "96.38%",
-		96.38..=96.39 => "96.39%",
-		96.39..=96.40 => "96.40%",
-		96.40..=96.41 => "96.41%",
-		96.41..=96.42 => "96.42%",
-		96.42..=96.43 => "96.43%",
-		96.43..=96.44 => "96.44%",
-		96.44..=96.45 => "96.45%",
-		96.45..=96.46 => "96.46%",
-		96.46..=96.47 => "96.47%",
-		96.47..=96.48 => "96.48%",
-		96.48..=96.49 => "96.49%",
-		96.49..=96.50 => "96.50%",
-		96.50..=96.51 => "96.51%",
-		96.51..=96.52 => "96.52%",
-		96.52..=96.53 => "96.53%",
-		96.53..=96.54 => "96.54%",
-		96.54..=96.55 => "96.55%",
-		96.55..=96.56 => "96.56%",
-		96.56..=96.57 => "96.57%",
-		96.57..=96.58 => "96.58%",
-		96.58..=96.59 => "96.59%",
-		96.59..=96.60 => "96.60%",
-		96.60..=96.61 => "96.61%",
-		96.61..=96.62 => "96.62%",
-		96.62..=96.63 => "96.63%",
-		96.63..=96.64 => "96.64%",
-		96.64..=96.65 => "96.65%",
-		96.65..=96.66 => "96.66%",
-		96.66..=96.67 => "96.67%",
-		96.67..=96.68 => "96.68%",
-		96.68..=96.69 => "96.69%",
-		96.69..=96.70 => "96.70%",
-		96.70..=96.71 => "96.71%",
-		96.71..=96.72 => "96.72%",
-		96.72..=96.73 => "96.73%",
-		96.73..=96.74 => "96.74%",
-		96.74..=96.75 => "96.75%",
-		96.75..=96.76 => "96.76%",
-		96.76..=96.77 => "96.77%",
-		96.77..=96.78 => "96.78%",
-		96.78..=96.79 => "96.79%",
-		96.79..=96.80 => "96.80%",
-		96.80..=96.81 => "96.81%",
-		96.81..=96.82 => "96.82%",
-		96.82..=96.83 => "96.83%",
-		96.83..=96.84 => "96.84%",
-		96.84..=96.85 => "96.85%",
-		96.85..=96.86 => "96.86%",
-		96.86..=96.87 => "96.87%",
-		96.87..=96.88 => "96.88%",
-		96.88..=96.89 => "96.89%",
-		96.89..=96.90 => "96.90%",
-		96.90..=96.91 => "96.91%",
-		96.91..=96.92 => "96.92%",
-		96.92..=96.93 => "96.93%",
-		96.93..=96.94 => "96.94%",
-		96.94..=96.95 => "96.95%",
-		96.95..=96.96 => "96.96%",
-		96.96..=96.97 => "96.97%",
-		96.97..=96.98 => "96.98%",
-		96.98..=96.99 => "96.99%",
-		96.99..=97.00 => "97.00%",
-		97.00..=97.01 => "97.01%",
-		97.01..=97.02 => "97.02%",
-		97.02..=97.03 => "97.03%",
-		97.03..=97.04 => "97.04%",
-		97.04..=97.05 => "97.05%",
-		97.05..=97.06 => "97.06%",
-		97.06..=97.07 => "97.07%",
-		97.07..=97.08 => "97.08%",
-		97.08..=97.09 => "97.09%",
-		97.09..=97.10 => "97.10%",
-		97.10..=97.11 => "97.11%",
-		97.11..=97.12 => "97.12%",
-		97.12..=97.13 => "97.13%",
-		97.13..=97.14 => "97.14%",
-		97.14..=97.15 => "97.15%",
-		97.15..=97.16 => "97.16%",
-		97.16..=97.17 => "97.17%",
-		97.17..=97.18 => "97.18%",
-		97.18..=97.19 => "97.19%",
-		97.19..=97.20 => "97.20%",
-		97.20..=97.21 => "97.21%",
-		97.21..=97.22 => "97.22%",
-		97.22..=97.23 => "97.23%",
-		97.23..=97.24 => "97.24%",
-		97.24..=97.25 => "97.25%",
-		97.25..=97.26 => "97.26%",
-		97.26..=97.27 => "97.27%",
-		97.27..=97.28 => "97.28%",
-		97.28..=97.29 => "97.29%",
-		97.29..=97.30 => "97.30%",
-		97.30..=97.31 => "97.31%",
-		97.31..=97.32 => "97.32%",
-		97.32..=97.33 => "97.33%",
-		97.33..=97.34 => "97.34%",
-		97.34..=97.35 => "97.35%",
-		97.35..=97.36 => "97.36%",
-		97.36..=97.37 => "97.37%",
-		97.37..=97.38 => "97.38%",
-		97.38..=97.39 => "97.39%",
-		97.39..=97.40 => "97.40%",
-		97.40..=97.41 => "97.41%",
-		97.41..=97.42 => "97.42%",
-		97.42..=97.43 => "97.43%",
-		97.43..=97.44 => "97.44%",
-		97.44..=97.45 => "97.45%",
-		97.45..=97.46 => "97.46%",
-		97.46..=97.47 => "97.47%",
-		97.47..=97.48 => "97.48%",
-		97.48..=97.49 => "97.49%",
-		97.49..=97.50 => "97.50%",
-		97.50..=97.51 => "97.51%",
-		97.51..=97.52 => "97.52%",
-		97.52..=97.53 => "97.53%",
-		97.53..=97.54 => "97.54%",
-		97.54..=97.55 => "97.55%",
-		97.55..=97.56 => "97.56%",
-		97.56..=97.57 => "97.57%",
-		97.57..=97.58 => "97.58%",
-		97.58..=97.59 => "97.59%",
-		97.59..=97.60 => "97.60%",
-		97.60..=97.61 => "97.61%",
-		97.61..=97.62 => "97.62%",
-		97.62..=97.63 => "97.63%",
-		97.63..=97.64 => "97.64%",
-		97.64..=97.65 => "97.65%",
-		97.65..=97.66 => "97.66%",
-		97.66..=97.67 => "97.67%",
-		97.67..=97.68 => "97.68%",
-		97.68..=97.69 => "97.69%",
-		97.69..=97.70 => "97.70%",
-		97.70..=97.71 => "97.71%",
-		97.71..=97.72 => "97.72%",
-		97.72..=97.73 => "97.73%",
-		97.73..=97.74 => "97.74%",
-		97.74..=97.75 => "97.75%",
-		97.75..=97.76 => "97.76%",
-		97.76..=97.77 => "97.77%",
-		97.77..=97.78 => "97.78%",
-		97.78..=97.79 => "97.79%",
-		97.79..=97.80 => "97.80%",
-		97.80..=97.81 => "97.81%",
-		97.81..=97.82 => "97.82%",
-		97.82..=97.83 => "97.83%",
-		97.83..=97.84 => "97.84%",
-		97.84..=97.85 => "97.85%",
-		97.85..=97.86 => "97.86%",
-		97.86..=97.87 => "97.87%",
-		97.87..=97.88 => "97.88%",
-		97.88..=97.89 => "97.89%",
-		97.89..=97.90 => "97.90%",
-		97.90..=97.91 => "97.91%",
-		97.91..=97.92 => "97.92%",
-		97.92..=97.93 => "97.93%",
-		97.93..=97.94 => "97.94%",
-		97.94..=97.95 => "97.95%",
-		97.95..=97.96 => "97.96%",
-		97.96..=97.97 => "97.97%",
-		97.97..=97.98 => "97.98%",
-		97.98..=97.99 => "97.99%",
-		97.99..=98.00 => "98.00%",
-		98.00..=98.01 => "98.01%",
-		98.01..=98.02 => "98.02%",
-		98.02..=98.03 => "98.03%",
-		98.03..=98.04 => "98.04%",
-		98.04..=98.05 => "98.05%",
-		98.05..=98.06 => "98.06%",
-		98.06..=98.07 => "98.07%",
-		98.07..=98.08 => "98.08%",
-		98.08..=98.09 => "98.09%",
-		98.09..=98.10 => "98.10%",
-		98.10..=98.11 => "98.11%",
-		98.11..=98.12 => "98.12%",
-		98.12..=98.13 => "98.13%",
-		98.13..=98.14 => "98.14%",
-		98.14..=98.15 => "98.15%",
-		98.15..=98.16 => "98.16%",
-		98.16..=98.17 => "98.17%",
-		98.17..=98.18 => "98.18%",
-		98.18..=98.19 => "98.19%",
-		98.19..=98.20 => "98.20%",
-		98.20..=98.21 => "98.21%",
-		98.21..=98.22 => "98.22%",
-		98.22..=98.23 => "98.23%",
-		98.23..=98.24 => "98.24%",
-		98.24..=98.25 => "98.25%",
-		98.25..=98.26 => "98.26%",
-		98.26..=98.27 => "98.27%",
-		98.27..=98.28 => "98.28%",
-		98.28..=98.29 => "98.29%",
-		98.29..=98.30 => "98.30%",
-		98.30..=98.31 => "98.31%",
-		98.31..=98.32 => "98.32%",
-		98.32..=98.33 => "98.33%",
-		98.33..=98.34 => "98.34%",
-		98.34..=98.35 => "98.35%",
-		98.35..=98.36 => "98.36%",
-		98.36..=98.37 => "98.37%",
-		98.37..=98.38 => "98.38%",
-		98.38..=98.39 => "98.39%",
-		98.39..=98.40 => "98.40%",
-		98.40..=98.41 => "98.41%",
-		98.41..=98.42 => "98.42%",
-		98.42..=98.43 => "98.43%",
-		98.43..=98.44 => "98.44%",
-		98.44..=98.45 => "98.45%",
-		98.45..=98.46 => "98.46%",
-		98.46..=98.47 => "98.47%",
-		98.47..=98.48 => "98.48%",
-		98.48..=98.49 => "98.49%",
-		98.49..=98.50 => "98.50%",
-		98.50..=98.51 => "98.51%",
-		98.51..=98.52 => "98.52%",
-		98.52..=98.53 => "98.53%",
-		98.53..=98.54 => "98.54%",
-		98.54..=98.55 => "98.55%",
-		98.55..=98.56 => "98.56%",
-		98.56..=98.57 => "98.57%",
-		98.57..=98.58 => "98.58%",
-		98.58..=98.59 => "98.59%",
-		98.59..=98.60 => "98.60%",
-		98.60..=98.61 => "98.61%",
-		98.61..=98.62 => "98.62%",
-		98.62..=98.63 => "98.63%",
-		98.63..=98.64 => "98.64%",
-		98.64..=98.65 => "98.65%",
-		98.65..=98.66 => "98.66%",
-		98.66..=98.67 => "98.67%",
-		98.67..=98.68 => "98.68%",
-		98.68..=98.69 => "98.69%",
-		98.69..=98.70 => "98.70%",
-		98.70..=98.71 => "98.71%",
-		98.71..=98.72 => "98.72%",
-		98.72..=98.73 => "98.73%",
-		98.73..=98.74 => "98.74%",
-		98.74..=98.75 => "98.75%",
-		98.75..=98.76 => "98.76%",
-		98.76..=98.77 => "98.77%",
-		98.77..=98.78 => "98.78%",
-		98.78..=98.79 => "98.79%",
-		98.79..=98.80 => "98.80%",
-		98.80..=98.81 => "98.81%",
-		98.81..=98.82 => "98.82%",
-		98.82..=98.83 => "98.83%",
-		98.83..=98.84 => "98.84%",
-		98.84..=98.85 => "98.85%",
-		98.85..=98.86 => "98.86%",
-		98.86..=98.87 => "98.87%",
-		98.87..=98.88 => "98.88%",
-		98.88..=98.89 => "98.89%",
-		98.89..=98.90 => "98.90%",
-		98.90..=98.91 => "98.91%",
-		98.91..=98.92 => "98.92%",
-		98.92..=98.93 => "98.93%",
-		98.93..=98.94 => "98.94%",
-		98.94..=98.95 => "98.95%",
-		98.95..=98.96 => "98.96%",
-		98.96..=98.97 => "98.97%",
-		98.97..=98.98 => "98.98%",
-		98.98..=98.99 => "98.99%",
-		98.99..=99.00 => "99.00%",
-		99.00..=99.01 => "99.01%",
-		99.01..=99.02 => "99.02%",
-		99.02..=99.03 => "99.03%",
-		99.03..=99.04 => "99.04%",
-		99.04..=99.05 => "99.05%",
-		99.05..=99.06 => "99.06%",
-		99.06..=99.07 => "99.07%",
-		99.07..=99.08 => "99.08%",
-		99.08..=99.09 => "99.09%",
-		99.09..=99.10 => "99.10%",
-		99.10..=99.11 => "99.11%",
-		99.11..=99.12 => "99.12%",
-		99.12..=99.13 => "99.13%",
-		99.13..=99.14 => "99.14%",
-		99.14..=99.15 => "99.15%",
-		99.15..=99.16 => "99.16%",
-		99.16..=99.17 => "99.17%",
-		99.17..=99.18 => "99.18%",
-		99.18..=99.19 => "99.19%",
-		99.19..=99.20 => "99.20%",
-		99.20..=99.21 => "99.21%",
-		99.21..=99.22 => "99.22%",
-		99.22..=99.23 => "99.23%",
-		99.23..=99.24 => "99.24%",
-		99.24..=99.25 => "99.25%",
-		99.25..=99.26 => "99.26%",
-		99.26..=99.27 => "99.27%",
-		99.27..=99.28 => "99.28%",
-		99.28..=99.29 => "99.29%",
-		99.29..=99.30 => "99.30%",
-		99.30..=99.31 => "99.31%",
-		99.31..=99.32 => "99.32%",
-		99.32..=99.33 => "99.33%",
-		99.33..=99.34 => "99.34%",
-		99.34..=99.35 => "99.35%",
-		99.35..=99.36 => "99.36%",
-		99.36..=99.37 => "99.37%",
-		99.37..=99.38 => "99.38%",
-		99.38..=99.39 => "99.39%",
-		99.39..=99.40 => "99.40%",
-		99.40..=99.41 => "99.41%",
-		99.41..=99.42 => "99.42%",
-		99.42..=99.43 => "99.43%",
-		99.43..=99.44 => "99.44%",
-		99.44..=99.45 => "99.45%",
-		99.45..=99.46 => "99.46%",
-		99.46..=99.47 => "99.47%",
-		99.47..=99.48 => "99.48%",
-		99.48..=99.49 => "99.49%",
-		99.49..=99.50 => "99.50%",
-		99.50..=99.51 => "99.51%",
-		99.51..=99.52 => "99.52%",
-		99.52..=99.53 => "99.53%",
-		99.53..=99.54 => "99.54%",
-		99.54..=99.55 => "99.55%",
-		99.55..=99.56 => "99.56%",
-		99.56..=99.57 => "99.57%",
-		99.57..=99.58 => "99.58%",
-		99.58..=99.59 => "99.59%",
-		99.59..=99.60 => "99.60%",
-		99.60..=99.61 => "99.61%",
-		99.61..=99.62 => "99.62%",
-		99.62..=99.63 => "99.63%",
-		99.63..=99.64 => "99.64%",
-		99.64..=99.65 => "99.65%",
-		99.65..=99.66 => "99.66%",
-		99.66..=99.67 => "99.67%",
-		99.67..=99.68 => "99.68%",
-		99.68..=99.69 => "99.69%",
-		99.69..=99.70 => "99.70%",
-		99.70..=99.71 => "99.71%",
-		99.71..=99.72 => "99.72%",
-		99.72..=99.73 => "99.73%",
-		99.73..=99.74 => "99.74%",
-		99.74..=99.75 => "99.75%",
-		99.75..=99.76 => "99.76%",
-		99.76..=99.77 => "99.77%",
-		99.77..=99.78 => "99.78%",
-		99.78..=99.79 => "99.79%",
-		99.79..=99.80 => "99.80%",
-		99.80..=99.81 => "99.81%",
-		99.81..=99.82 => "99.82%",
-		99.82..=99.83 => "99.83%",
-		99.83..=99.84 => "99.84%",
-		99.84..=99.85 => "99.85%",
-		99.85..=99.86 => "99.86%",
-		99.86..=99.87 => "99.87%",
-		99.87..=99.88 => "99.88%",
-		99.88..=99.89 => "99.89%",
-		99.89..=99.90 => "99.90%",
-		99.90..=99.91 => "99.91%",
-		99.91..=99.92 => "99.92%",
-		99.92..=99.93 => "99.93%",
-		99.93..=99.94 => "99.94%",
-		99.94..=99.95 => "99.95%",
-		99.95..=99.96 => "99.96%",
-		99.96..=99.97 => "99.97%",
-		99.97..=99.98 => "99.98%",
-		99.98..=99.99 => "99.99%",
-		_ => "100.00%",
-	}
-}
@@ -1,20 +1,18 @@
 //! Inlined data for [`readable`](https://docs.rs/readable).
 //!
 //! Do not use this crate directly.
+//!
+//! The strings are generated by `build.rs`.
 
-mod _0;
-mod _25;
-mod _50;
-mod _75;
+include!(concat!(env!("OUT_DIR"), "/percent.rs"));
 
 #[inline(always)]
-/// INVARIANT:
-/// Input must be `0.0..100.0`.
-pub fn inlined(f: f64) -> &'static str {
-	match f {
-		0.0..=25.0 => crate::_0::inlined_0(f),
-		25.0..=50.0 => crate::_25::inlined_25(f),
-		50.0..=75.0 => crate::_50::inlined_50(f),
-		_ => crate::_75::inlined_75(f),
+/// Returns the inlined `Percent` string for `f`, rounded to `2` decimals.
+///
+/// [`None`] is returned if `f` is not within `0.0..=100.0`.
+pub fn inlined(f: f64) -> Option<&'static str> {
+	if !(0.0..=100.0).contains(&f) {
+		return None;
 	}
+	PERCENT_LUT.get((f * 100.0).round() as usize).copied()
 }
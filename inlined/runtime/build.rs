@@ -0,0 +1,50 @@
+//! Generates `RUNTIME_LUT` into `$OUT_DIR/runtime.rs`.
+//!
+//! The inlined range is `0..N` seconds, where `N` is:
+//! 1. `READABLE_INLINED_RUNTIME` (env var) if set
+//! 2. else, the largest enabled feature (`1h`, `24h`, `full`)
+//! 3. else, `0`, nothing is inlined
+
+use std::fmt::Write;
+
+/// `99:59:59` is the maximum `Runtime`.
+const MAX: u32 = 360_000;
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-env-changed=READABLE_INLINED_RUNTIME");
+
+	let len = match std::env::var("READABLE_INLINED_RUNTIME") {
+		Ok(len) => match len.parse::<u32>() {
+			Ok(len) if len <= MAX => len,
+			_ => panic!("READABLE_INLINED_RUNTIME must be a number within `0..={MAX}`, found: {len}"),
+		},
+		Err(_) => {
+			let feature = |f: &str| std::env::var_os(format!("CARGO_FEATURE_{f}")).is_some();
+			if feature("FULL") {
+				MAX
+			} else if feature("24H") {
+				86_400
+			} else if feature("1H") {
+				3_600
+			} else {
+				0
+			}
+		}
+	};
+
+	let mut out = String::new();
+	writeln!(out, "static RUNTIME_LUT: [&str; {len}] = [").unwrap();
+	for u in 0..len {
+		let (h, m, s) = (u / 3600, u % 3600 / 60, u % 60);
+		if h == 0 {
+			writeln!(out, "\t\"{m}:{s:02}\",").unwrap();
+		} else {
+			writeln!(out, "\t\"{h}:{m:02}:{s:02}\",").unwrap();
+		}
+	}
+	out.push_str("];\n");
+
+	let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("runtime.rs");
+	std::fs::write(path, out).unwrap();
+}
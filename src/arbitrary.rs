@@ -0,0 +1,465 @@
+//! `arbitrary` integration
+//!
+//! With the `arbitrary` feature, all owned `readable` types implement [`arbitrary::Arbitrary`].
+//!
+//! The values are not random bytes, they are created with the
+//! type's normal constructors from an arbitrary input, so they always
+//! hold a valid string (or `UNKNOWN`).
+//!
+//! The plain enums and structs (e.g. [`crate::Error`]) derive it instead.
+//!
+//! Borrowed views (`Head`, `Tail`, etc), scratch buffers (`ItoaTmp`, `DtoaTmp`)
+//! and the live clocks (`UptimeLive`, `ClockLive`, `MilitaryLive`) do not implement it.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::{HexFormat, HexStr, ShortHash, SmartStr, Str};
+use crate::toa::{Dtoa, Itoa};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+//---------------------------------------------------------------------------------------------------- Macros
+// Implements `Arbitrary` for types that implement `From<$input>`.
+macro_rules! impl_arbitrary {
+	($input:ty => $($type:ty),* $(,)?) => {
+		$(
+			impl<'a> Arbitrary<'a> for $type {
+				#[inline]
+				fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+					Ok(Self::from(<$input>::arbitrary(u)?))
+				}
+
+				#[inline]
+				fn size_hint(depth: usize) -> (usize, Option<usize>) {
+					<$input>::size_hint(depth)
+				}
+			}
+		)*
+	};
+}
+
+//---------------------------------------------------------------------------------------------------- Str
+impl<'a, const N: usize> Arbitrary<'a> for Str<N> {
+    #[allow(clippy::string_slice)]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let s = <&str>::arbitrary(u)?;
+
+        // Cut off at the last `char` that fits.
+        let mut end = s.len().min(N);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        Ok(Self::try_from(&s[..end]).unwrap())
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for SmartStr<N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(String::arbitrary(u)?))
+    }
+}
+
+impl<'a, const N: usize> Arbitrary<'a> for HexStr<N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (bytes, format) = <(&[u8], HexFormat)>::arbitrary(u)?;
+        Ok(Self::with_format(bytes, format).unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<'a, const N: usize> Arbitrary<'a> for crate::str::Base64Str<N> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?).unwrap_or_default())
+    }
+}
+
+impl<'a> Arbitrary<'a> for ShortHash {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (bytes, head, tail) = <(&[u8], u8, u8)>::arbitrary(u)?;
+        Ok(Self::from_bytes(bytes, head, tail))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- toa
+impl<'a> Arbitrary<'a> for Itoa {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Cover both ends, `u128::MAX` and `i128::MIN`.
+        if bool::arbitrary(u)? {
+            Ok(Self::from(u128::arbitrary(u)?))
+        } else {
+            Ok(Self::from(i128::arbitrary(u)?))
+        }
+    }
+}
+
+impl_arbitrary!(f64 => Dtoa);
+
+//---------------------------------------------------------------------------------------------------- num
+#[cfg(feature = "num")]
+mod num {
+    use super::*;
+    use crate::num::{
+        Accounting, Float, FloatSig, Fraction, Int, Int128, Measure, Percent, PercentBar, Permille,
+        Ppm, Separated, Sparkline, Unsigned, Unsigned128, ZeroPad,
+    };
+
+    impl_arbitrary!(u64 => Unsigned);
+    impl_arbitrary!(i64 => Int);
+    impl_arbitrary!(u128 => Unsigned128);
+    impl_arbitrary!(i128 => Int128);
+    impl_arbitrary!(f64 => Float, Percent, Permille, Ppm, Accounting);
+
+    impl<'a> Arbitrary<'a> for Fraction {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for PercentBar {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let percent = u.arbitrary()?;
+            let width = u.arbitrary()?;
+            if bool::arbitrary(u)? {
+                Ok(Self::new(percent, width))
+            } else {
+                Ok(Self::new_unicode(percent, width))
+            }
+        }
+    }
+
+    impl<'a, const W: usize> Arbitrary<'a> for ZeroPad<W> {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from(u64::arbitrary(u)?))
+        }
+    }
+
+    impl<'a, const N: usize> Arbitrary<'a> for FloatSig<N> {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from(f64::arbitrary(u)?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Measure {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Separated {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let digits: String = <&[u8]>::arbitrary(u)?
+                .iter()
+                .map(|b| char::from(b'0' + b % 10))
+                .collect();
+            Ok(Self::from_digits_silent(&digits))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Sparkline {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from(&Vec::<f64>::arbitrary(u)?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- byte
+#[cfg(feature = "byte")]
+mod byte {
+    use super::*;
+    use crate::byte::Byte;
+
+    impl_arbitrary!(u64 => Byte);
+
+    // `Progress` needs `num` for its `Percent`.
+    #[cfg(feature = "num")]
+    impl<'a> Arbitrary<'a> for crate::byte::Progress {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- run
+#[cfg(feature = "run")]
+mod run {
+    use super::*;
+    use crate::run::{
+        Runtime, RuntimeHours, RuntimeMilli, RuntimePad, RuntimeUnion, RuntimeUnits, Timecode,
+    };
+
+    impl_arbitrary!(f32 => Runtime, RuntimePad, RuntimeHours, RuntimeUnits, RuntimeUnion);
+    impl_arbitrary!(f64 => RuntimeMilli);
+
+    impl<'a> Arbitrary<'a> for Timecode {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from_frames(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- up
+#[cfg(feature = "up")]
+mod up {
+    use super::*;
+    use crate::up::{
+        Eta, Htop, Idle, Uptime, UptimeApprox, UptimeCalendar, UptimeCompact, UptimeFull,
+        UptimeParts,
+    };
+
+    impl_arbitrary!(u32 => Uptime, UptimeFull, UptimeApprox, Htop, UptimeCompact);
+
+    impl<'a> Arbitrary<'a> for Idle {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Eta {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for UptimeParts {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from_secs(u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for UptimeCalendar {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let month = u.int_in_range(Self::MIN_MONTH_SECS..=Self::MAX_MONTH_SECS)?;
+            let year = u.int_in_range(Self::MIN_YEAR_SECS..=Self::MAX_YEAR_SECS)?;
+            Ok(Self::new(month, year).unwrap_or(Self::NAIVE))
+        }
+    }
+
+    #[cfg(feature = "date")]
+    impl<'a> Arbitrary<'a> for crate::up::UptimeSince {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- time
+#[cfg(feature = "time")]
+mod time {
+    use super::*;
+    use crate::time::{Military, Time, TimeSpan, TimeUnit};
+
+    impl_arbitrary!(u32 => Time, Military, TimeUnit);
+
+    impl<'a> Arbitrary<'a> for TimeSpan {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- date
+#[cfg(feature = "date")]
+mod date {
+    use super::*;
+    use crate::date::{Date, Month, Nichi, NichiFull, NichiWareki, Quarter, Weekday};
+
+    impl_arbitrary!(u8 => Month, Weekday);
+
+    impl<'a> Arbitrary<'a> for Date {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from_ymd_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Nichi {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for NichiFull {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for NichiWareki {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Quarter {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    #[cfg(feature = "calendar")]
+    impl<'a> Arbitrary<'a> for crate::date::DateHebrew {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+
+    #[cfg(feature = "calendar")]
+    impl<'a> Arbitrary<'a> for crate::date::DateHijri {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new_silent(
+                u.arbitrary()?,
+                u.arbitrary()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- sys
+#[cfg(feature = "sys")]
+mod sys {
+    use super::*;
+    use crate::sys::{SysLoadAvg, SysMem};
+
+    impl<'a> Arbitrary<'a> for SysLoadAvg {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for SysMem {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- unit
+#[cfg(feature = "unit")]
+mod unit {
+    use super::*;
+    use crate::unit::{Coord, Distance, Mass, System, Volume};
+
+    // Implements `Arbitrary` with `Self::new(f64, System)`.
+    macro_rules! impl_system {
+		($($type:ty),* $(,)?) => { $(
+			impl<'a> Arbitrary<'a> for $type {
+				#[inline]
+				fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+					Ok(Self::new(u.arbitrary()?, System::arbitrary(u)?))
+				}
+			}
+		)*};
+    }
+    impl_system!(Distance, Mass, Volume);
+
+    impl<'a> Arbitrary<'a> for Coord {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let (lat, lon, precision) = u.arbitrary()?;
+            let coord = if bool::arbitrary(u)? {
+                Self::dms(lat, lon, precision)
+            } else {
+                Self::decimal(lat, lon, precision)
+            };
+            Ok(match coord {
+                Ok(s) | Err(s) => s,
+            })
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- net
+#[cfg(feature = "net")]
+mod net {
+    use super::*;
+    use crate::net::{Ip, IpFormat, SockAddr};
+
+    impl<'a> Arbitrary<'a> for Ip {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::with_format(u.arbitrary()?, IpFormat::arbitrary(u)?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for SockAddr {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::with_format(u.arbitrary()?, IpFormat::arbitrary(u)?))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_fits() {
+        // Multi-byte `char`s that don't fit must be cut off, not panic.
+        let string = "aあいうえお".repeat(8);
+        let bytes = string.as_bytes();
+        for i in 0..bytes.len() {
+            let mut u = Unstructured::new(&bytes[i..]);
+            let s = Str::<4>::arbitrary(&mut u).unwrap();
+            assert!(s.len() <= 4);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn from_constructor() {
+        let bytes = [0xFF; 64];
+        let mut u = Unstructured::new(&bytes);
+        let unsigned = crate::num::Unsigned::arbitrary(&mut u).unwrap();
+        assert_eq!(unsigned, crate::num::Unsigned::from(u64::MAX));
+    }
+}
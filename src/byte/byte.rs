@@ -4,8 +4,13 @@ use std::num::{
     NonZeroU64, NonZeroU8, NonZeroUsize,
 };
 
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize};
+use crate::byte::{Standard, Unit};
+use crate::macros::{
+    impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math, impl_math,
+    impl_std_from_str, impl_traits, impl_usize,
+};
 use crate::str::Str;
+use crate::Rounding;
 
 //---------------------------------------------------------------------------------------------------- Byte
 /// Human-readable byte formatting
@@ -54,7 +59,7 @@ use crate::str::Str;
 ///
 /// They can either be:
 /// - Combined with another `Self`, e.g: `Byte::from(1.0) + Byte::from(1.0)`
-/// - Or with the inner number itself: `BytePad::from(1.0) + 1.0`
+/// - Or with the inner number itself: `Byte::from(1.0) + 1024`
 ///
 /// ```rust
 /// # use readable::byte::*;
@@ -63,10 +68,32 @@ use crate::str::Str;
 ///
 /// let byte = byte + Byte::from(1.0);
 /// assert_eq!(byte, "2 B");
+///
+/// let byte = byte + 1024_u64;
+/// assert_eq!(byte, "1.026 KB");
+/// assert_eq!(2048_u64 - byte, 1022);
+/// ```
+///
+/// [`Byte`] can also be compared with [`u64`] directly,
+/// and summed with [`Iterator::sum`] (which saturates at [`u64::MAX`]):
+///
+/// ```rust
+/// # use readable::byte::*;
+/// let files = [Byte::from(1_000_u64), Byte::from(24_u64), Byte::from(1_000_000_u64)];
+///
+/// assert!(files[0] > 999);
+/// assert!(files[1] <= 24);
+///
+/// let total: Byte = files.iter().sum();
+/// assert_eq!(total, 1_001_024);
+/// assert_eq!(total, "1.001 MB");
+///
+/// let total: Byte = [Byte::MAX, Byte::from(1_u64)].into_iter().sum();
+/// assert_eq!(total, u64::MAX);
 /// ```
 ///
 /// ## Size
-/// [`Str<10>`] is used internally to represent the string.
+/// [`Str<15>`] is used internally to represent the string.
 ///
 /// ```rust
 /// # use readable::byte::*;
@@ -77,7 +104,7 @@ use crate::str::Str;
 /// [`Copy`] is available.
 ///
 /// The actual strings used internally is not a [`String`](https://doc.rust-lang.org/std/string/struct.String.html),
-/// but a byte array buffer, literally: [`Str<10>`].
+/// but a byte array buffer, literally: [`Str<15>`].
 ///
 /// The documentation will still refer to the inner buffer as a [`String`]. Anything returned will also be a [`String`].
 /// ```rust
@@ -97,11 +124,20 @@ use crate::str::Str;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Byte(u64, Str<{ Byte::MAX_LEN }>);
 
 impl_math!(Byte, u64);
+impl_from_option!(Byte);
 impl_traits!(Byte, u64);
+impl_std_from_str!(Byte => from_str);
+#[cfg(feature = "clap")]
+crate::macros::impl_clap!(Byte, "a byte size, e.g. `1.5GiB`, `100 MB` or `1024`");
 
 //---------------------------------------------------------------------------------------------------- Constants
 /// 1 `byte`
@@ -124,11 +160,20 @@ const ZERO: u64 = 0;
 //---------------------------------------------------------------------------------------------------- Constants
 impl Byte {
     /// The maximum string length of a [`Byte`]
+    ///
+    /// The automatic unit is at most `xxx.xxx KB`, the extra space
+    /// is used by [`Byte::fixed_unit`] and [`Byte::with_standard`].
+    ///
+    /// [`Byte::fixed_unit`] strings longer than this are [`Byte::UNKNOWN`].
     /// ```rust
-    /// # use readable::byte::Byte;
-    /// assert_eq!("xxx.xxx KB".len(), Byte::MAX_LEN);
+    /// # use readable::byte::*;
+    /// let max = Byte::fixed_unit(Unit::MiB, 1_048_575_999_999);
+    /// assert_eq!(max, "999999.999 MiB");
+    /// assert_eq!(max.len(), Byte::MAX_LEN - 1);
+    ///
+    /// assert!(Byte::fixed_unit(Unit::KiB, u64::MAX).is_unknown());
     /// ```
-    pub const MAX_LEN: usize = 10;
+    pub const MAX_LEN: usize = 15;
 
     /// ```rust
     /// # use readable::byte::*;
@@ -215,6 +260,7 @@ impl Byte {
 impl Byte {
     impl_common!(u64);
     impl_const!();
+    impl_format_slice!(byte, Byte, [0_u64, 999, 1_500, u64::MAX]);
     impl_usize!();
 
     #[inline]
@@ -227,14 +273,450 @@ impl Byte {
     pub const fn is_unknown(&self) -> bool {
         matches!(*self, Self::UNKNOWN)
     }
+
+    #[must_use]
+    /// Return the unit used in the string
+    ///
+    /// [`Byte::ZERO`] and [`Byte::UNKNOWN`] return [`Unit::B`].
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::from(999).unit(), Unit::B);
+    /// assert_eq!(Byte::from(2_101_123).unit(), Unit::MB);
+    /// assert_eq!(Byte::fixed_unit(Unit::MiB, 1).unit(), Unit::MiB);
+    /// assert_eq!(Byte::UNKNOWN.unit(), Unit::B);
+    /// ```
+    pub fn unit(&self) -> Unit {
+        self.as_str()
+            .rsplit(' ')
+            .next()
+            .and_then(Unit::from_suffix)
+            .unwrap_or(Unit::B)
+    }
+
+    #[must_use]
+    /// Same as [`Byte::unit`] but reads the suffix in a specific [`Standard`]
+    ///
+    /// [`Byte`] does not store the [`Standard`] it was created
+    /// with, so this is needed for [`Standard::Jedec`] strings.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// let jedec = Byte::with_standard(1_048_576, Standard::Jedec);
+    /// assert_eq!(jedec, "1.000 MB");
+    /// assert_eq!(jedec.unit(), Unit::MB);
+    /// assert_eq!(jedec.unit_standard(Standard::Jedec), Unit::MiB);
+    ///
+    /// // `Si` and `Binary` suffixes are unambiguous.
+    /// let binary = Byte::with_standard(1_048_576, Standard::Binary);
+    /// assert_eq!(binary.unit_standard(Standard::Binary), binary.unit());
+    /// ```
+    pub fn unit_standard(&self, standard: Standard) -> Unit {
+        standard.read(self.unit())
+    }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a byte size, e.g. `1.5GiB`
+    ///
+    /// This is a number, optionally followed by a [`Unit`] suffix with or without a space.
+    ///
+    /// - No suffix means bytes
+    /// - The suffix is case-insensitive, so `gb` and `GiB` both work
+    /// - Decimals are rounded to the nearest byte
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::from_str("1.5GiB").unwrap(),   1_610_612_736);
+    /// assert_eq!(Byte::from_str("1.5 GB").unwrap(),   1_500_000_000);
+    /// assert_eq!(Byte::from_str("100kib").unwrap(),   102_400);
+    /// assert_eq!(Byte::from_str("1024").unwrap(),     1_024);
+    /// assert_eq!(Byte::from_str("1024 B").unwrap(),   1_024);
+    /// assert_eq!(Byte::from_str("2 EiB").unwrap(),    1_u64 << 61);
+    ///
+    /// // Formatted strings parse back.
+    /// let byte = Byte::from(2_101_123_u64);
+    /// assert_eq!(Byte::from_str(&byte).unwrap(), 2_101_000);
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not a byte size, or is over [`u64::MAX`],
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert!(Byte::from_str("").is_err());
+    /// assert!(Byte::from_str("GiB").is_err());
+    /// assert!(Byte::from_str("1.5 XB").is_err());
+    /// assert!(Byte::from_str("-1 KB").is_err());
+    /// assert!(Byte::from_str("16 EiB").is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        Self::from_str_standard(string, Standard::Si)
+    }
+
+    /// Same as [`Byte::from_str`] but parses and formats in a specific [`Standard`]
+    ///
+    /// With [`Standard::Jedec`], the `SI` suffixes are powers of `1024`.
+    /// The binary suffixes (e.g. `KiB`) are powers of `1024` in every standard.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::from_str_standard("1 KB", Standard::Si).unwrap(),     1_000);
+    /// assert_eq!(Byte::from_str_standard("1 KB", Standard::Jedec).unwrap(),  1_024);
+    /// assert_eq!(Byte::from_str_standard("1 KiB", Standard::Si).unwrap(),    1_024);
+    ///
+    /// // The result is formatted with the same standard.
+    /// assert_eq!(Byte::from_str_standard("1536", Standard::Binary).unwrap(), "1.500 KiB");
+    ///
+    /// // `Jedec` strings parse back.
+    /// let jedec = Byte::with_standard(1_048_576, Standard::Jedec);
+    /// assert_eq!(Byte::from_str_standard(&jedec, Standard::Jedec).unwrap(), jedec);
+    /// ```
+    ///
+    /// # Errors
+    /// This errors in the same situations as [`Byte::from_str`].
+    pub fn from_str_standard(string: &str, standard: Standard) -> Result<Self, Self> {
+        match Self::priv_from_str(string, standard) {
+            Some(bytes) => Ok(Self::with_standard(bytes, standard)),
+            None => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Byte::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[must_use]
+    /// Format the bytes in a specific [`Unit`], regardless of magnitude
+    ///
+    /// This uses the same `3` decimal points as the automatic unit,
+    /// see [`Byte::fixed_unit_precision`] to change it.
+    ///
+    /// The decimals are always rounded down, [`Unit::B`] has no decimals.
+    ///
+    /// [`Byte::UNKNOWN`] is returned if the string is longer than [`Byte::MAX_LEN`].
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::fixed_unit(Unit::MiB, 419_431),        "0.400 MiB");
+    /// assert_eq!(Byte::fixed_unit(Unit::MiB, 12_582_912_000), "12000.000 MiB");
+    /// assert_eq!(Byte::fixed_unit(Unit::MB, 1_500),           "0.001 MB");
+    /// assert_eq!(Byte::fixed_unit(Unit::B, 1_500),            "1500 B");
+    ///
+    /// // The inner number is still the bytes.
+    /// assert_eq!(Byte::fixed_unit(Unit::GiB, 1 << 30), 1 << 30);
+    /// ```
+    pub fn fixed_unit(unit: Unit, bytes: u64) -> Self {
+        Self::fixed_unit_precision(unit, bytes, 3)
+    }
+
+    #[must_use]
+    /// Same as [`Byte::fixed_unit`] but with `0..=3` decimal points
+    ///
+    /// `precision` above `3` is treated as `3`.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::fixed_unit_precision(Unit::MiB, 419_431, 1),        "0.4 MiB");
+    /// assert_eq!(Byte::fixed_unit_precision(Unit::MiB, 12_582_912_000, 1), "12000.0 MiB");
+    /// assert_eq!(Byte::fixed_unit_precision(Unit::MiB, 12_582_912_000, 0), "12000 MiB");
+    /// assert_eq!(Byte::fixed_unit_precision(Unit::KB, 1_999, 2),           "1.99 KB");
+    /// assert_eq!(Byte::fixed_unit_precision(Unit::KB, 1_999, 200),         "1.999 KB");
+    /// ```
+    pub fn fixed_unit_precision(unit: Unit, bytes: u64, precision: u8) -> Self {
+        Self::fixed_unit_rounding(unit, bytes, precision, Rounding::Truncate)
+    }
+
+    #[must_use]
+    /// Same as [`Byte::fixed_unit_precision`] but rounded with a specific [`Rounding`]
+    ///
+    /// The rounding is done with integers, there are no [`f64`] rounding errors.
+    ///
+    /// ```rust
+    /// # use readable::{byte::*,Rounding};
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::KB, 1_999, 2, Rounding::Truncate), "1.99 KB");
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::KB, 1_999, 2, Rounding::HalfUp),   "2.00 KB");
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::KB, 1_250, 1, Rounding::HalfUp),   "1.3 KB");
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::KB, 1_250, 1, Rounding::HalfEven), "1.2 KB");
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::KB, 1_500, 0, Rounding::HalfEven), "2 KB");
+    /// assert_eq!(Byte::fixed_unit_rounding(Unit::MiB, 1 << 19, 0, Rounding::HalfEven), "0 MiB");
+    /// ```
+    pub fn fixed_unit_rounding(unit: Unit, bytes: u64, precision: u8, rounding: Rounding) -> Self {
+        Self::priv_fixed_unit(unit, unit.as_str(), bytes, precision, rounding)
+    }
+
+    #[must_use]
+    /// Same as [`Byte::from`] but rounded with a specific [`Rounding`]
+    ///
+    /// [`Byte::from`] always rounds down, this can
+    /// round the `3` decimal points up or to the nearest.
+    ///
+    /// If rounding reaches `1000` of a unit, the next unit is used.
+    ///
+    /// ```rust
+    /// # use readable::{byte::*,Rounding};
+    /// assert_eq!(Byte::with_rounding(1_234_500, Rounding::Truncate), "1.234 MB");
+    /// assert_eq!(Byte::with_rounding(1_234_500, Rounding::HalfUp),   "1.235 MB");
+    /// assert_eq!(Byte::with_rounding(1_234_500, Rounding::HalfEven), "1.234 MB");
+    ///
+    /// assert_eq!(Byte::with_rounding(999_999_999, Rounding::Truncate), "999.999 MB");
+    /// assert_eq!(Byte::with_rounding(999_999_999, Rounding::HalfUp),   "1.000 GB");
+    ///
+    /// assert_eq!(Byte::with_rounding(999, Rounding::HalfUp),      "999 B");
+    /// assert_eq!(Byte::with_rounding(u64::MAX, Rounding::HalfUp), "18.447 EB");
+    ///
+    /// // `Truncate` is the same as `Byte::from`.
+    /// for bytes in [1_000, 1_999, 123_456_789, u64::MAX] {
+    ///     assert_eq!(Byte::with_rounding(bytes, Rounding::Truncate), Byte::from(bytes));
+    /// }
+    /// ```
+    pub fn with_rounding(bytes: u64, rounding: Rounding) -> Self {
+        if bytes < KILOBYTE {
+            return Self::from_priv(bytes);
+        }
+
+        // The largest `1000^exp` that fits.
+        let mut exp = 1;
+        while exp < Unit::SI.len() - 1 && bytes >= KILOBYTE.pow(exp as u32 + 1) {
+            exp += 1;
+        }
+
+        // Rounded up to `1000.000`, use the next unit.
+        if exp < Unit::SI.len() - 1
+            && Self::priv_scaled(Unit::SI[exp], bytes, 1_000, rounding) >= 1_000_000
+        {
+            exp += 1;
+        }
+
+        Self::fixed_unit_rounding(Unit::SI[exp], bytes, 3, rounding)
+    }
+
+    #[must_use]
+    /// Same as [`Byte::from`] but with the units of a specific [`Standard`]
+    ///
+    /// Like [`Byte::from`], the `3` decimal points are always rounded down.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Byte::with_standard(1_000, Standard::Si),     "1.000 KB");
+    /// assert_eq!(Byte::with_standard(1_000, Standard::Binary), "1000 B");
+    /// assert_eq!(Byte::with_standard(1_000, Standard::Jedec),  "1000 B");
+    ///
+    /// assert_eq!(Byte::with_standard(1_536, Standard::Binary), "1.500 KiB");
+    /// assert_eq!(Byte::with_standard(1_536, Standard::Jedec),  "1.500 KB");
+    ///
+    /// assert_eq!(Byte::with_standard(u64::MAX, Standard::Binary), "15.999 EiB");
+    /// assert_eq!(Byte::with_standard(u64::MAX, Standard::Jedec),  "15.999 EB");
+    ///
+    /// // `Si` is the same as `Byte::from`.
+    /// for bytes in [0, 999, 1_000, 123_456_789, u64::MAX] {
+    ///     assert_eq!(Byte::with_standard(bytes, Standard::Si), Byte::from(bytes));
+    /// }
+    /// ```
+    ///
+    /// The [`Standard`] is not stored, which keeps [`Byte`] at `24` bytes.
+    /// [`Byte::unit`] and [`Byte::from_str`] read the suffix, so they treat
+    /// [`Standard::Jedec`] strings as [`Standard::Si`], use
+    /// [`Byte::unit_standard`] and [`Byte::from_str_standard`] instead:
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// let jedec = Byte::with_standard(1_024, Standard::Jedec);
+    /// assert_eq!(jedec, "1.000 KB");
+    /// assert_eq!(jedec.unit(), Unit::KB);
+    /// assert_eq!(Byte::from_str(&jedec).unwrap(), 1_000);
+    ///
+    /// assert_eq!(jedec.unit_standard(Standard::Jedec), Unit::KiB);
+    /// assert_eq!(Byte::from_str_standard(&jedec, Standard::Jedec).unwrap(), 1_024);
+    /// ```
+    pub fn with_standard(bytes: u64, standard: Standard) -> Self {
+        if standard == Standard::Si {
+            return Self::from_priv(bytes);
+        }
+
+        // e.g. `1023 B`.
+        let base = standard.base();
+        if bytes < base {
+            return Self::fixed_unit(Unit::B, bytes);
+        }
+
+        // The largest `base^exp` that fits.
+        let units = standard.units();
+        let mut exp = 1;
+        while exp < units.len() - 1 && bytes >= base.pow(exp as u32 + 1) {
+            exp += 1;
+        }
+
+        let unit = units[exp];
+        Self::priv_fixed_unit(unit, standard.suffix(unit), bytes, 3, Rounding::Truncate)
+    }
+
+    #[cfg(feature = "up")]
+    #[must_use]
+    /// Estimate the time left to transfer these bytes at `rate` bytes per second
+    ///
+    /// The seconds are calculated with integers (no [`f64`] rounding errors)
+    /// and are always rounded up, so a partial second left is `1s`.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// let remaining = Byte::from(1_500_000_000_u64);
+    /// assert_eq!(remaining.eta_at_rate(Byte::MEGABYTE),        "25m");
+    /// assert_eq!(remaining.eta_at_rate(Byte::from(999_999)),   "25m, 1s");
+    /// assert_eq!(Byte::from(1_u64).eta_at_rate(Byte::MAX),     "1s");
+    /// assert_eq!(Byte::ZERO.eta_at_rate(Byte::BYTE),           "0s");
+    /// ```
+    ///
+    /// ## Errors
+    /// [`Uptime::UNKNOWN`](crate::up::Uptime::UNKNOWN) is returned if:
+    /// - `self` or `rate` is [`Byte::UNKNOWN`]
+    /// - `rate` is `0`
+    /// - The estimate is greater than [`u32::MAX`] seconds
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert!(Byte::MAX.eta_at_rate(Byte::ZERO).is_unknown());
+    /// assert!(Byte::MAX.eta_at_rate(Byte::UNKNOWN).is_unknown());
+    /// assert!(Byte::MAX.eta_at_rate(Byte::BYTE).is_unknown());
+    /// ```
+    pub fn eta_at_rate(&self, rate: Self) -> crate::up::Uptime {
+        match self.priv_secs_at_rate(rate) {
+            Some(secs) => crate::up::Uptime::from(secs),
+            None => crate::up::Uptime::UNKNOWN,
+        }
+    }
+
+    #[cfg(feature = "run")]
+    #[must_use]
+    /// Same as [`Byte::eta_at_rate`], but formatted as a [`Runtime`](crate::run::Runtime)
+    ///
+    /// [`Runtime::UNKNOWN`](crate::run::Runtime::UNKNOWN) is returned on the
+    /// same errors, or if the estimate is greater than [`Runtime::MAX`](crate::run::Runtime::MAX).
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// let remaining = Byte::from(1_500_000_000_u64);
+    /// assert_eq!(remaining.runtime_at_rate(Byte::MEGABYTE), "25:00");
+    /// assert!(remaining.runtime_at_rate(Byte::BYTE).is_unknown());
+    /// ```
+    pub fn runtime_at_rate(&self, rate: Self) -> crate::run::Runtime {
+        match self.priv_secs_at_rate(rate) {
+            Some(secs) => crate::run::Runtime::from(secs),
+            None => crate::run::Runtime::UNKNOWN,
+        }
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private Impl
 impl Byte {
+    // Parse `[number][ ][unit]` into bytes, `None` on error or overflow.
+    fn priv_from_str(string: &str, standard: Standard) -> Option<u64> {
+        let string = string.trim();
+        let end = string
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(string.len());
+        let (number, suffix) = string.split_at(end);
+
+        let unit = match suffix.trim_start() {
+            "" => Unit::B,
+            suffix => Unit::SI
+                .into_iter()
+                .chain(Unit::BINARY)
+                .find(|unit| unit.as_str().eq_ignore_ascii_case(suffix))?,
+        };
+        let unit = standard.read(unit);
+
+        match number.split_once('.') {
+            // Integers are exact.
+            None => number.parse::<u64>().ok()?.checked_mul(unit.bytes()),
+            Some((int, fract)) => {
+                if int.is_empty() || fract.is_empty() || fract.contains('.') {
+                    return None;
+                }
+                let bytes = (number.parse::<f64>().ok()? * unit.bytes() as f64).round();
+                // `u64::MAX as f64` rounds up to `2^64`, which is out of range.
+                if bytes >= u64::MAX as f64 {
+                    return None;
+                }
+                Some(bytes as u64)
+            }
+        }
+    }
+
+    /// The seconds (rounded up) to transfer `self` at `rate` bytes per second.
+    #[cfg(feature = "run")]
+    const fn priv_secs_at_rate(&self, rate: Self) -> Option<u64> {
+        if self.is_unknown() || rate.is_unknown() || rate.0 == 0 {
+            return None;
+        }
+        Some(self.0 / rate.0 + (self.0 % rate.0 != 0) as u64)
+    }
+
+    /// [`Byte::fixed_unit_rounding`] with a specific `suffix` for `unit`.
+    fn priv_fixed_unit(
+        unit: Unit,
+        suffix: &str,
+        bytes: u64,
+        precision: u8,
+        rounding: Rounding,
+    ) -> Self {
+        let precision = if unit == Unit::B {
+            0
+        } else {
+            u32::from(precision.min(3))
+        };
+
+        let scale = 10_u64.pow(precision);
+        let scaled = Self::priv_scaled(unit, bytes, scale, rounding);
+
+        let mut itoa = crate::toa::ItoaTmp::new();
+        let int = itoa.format(scaled / scale).len();
+        let len =
+            int + if precision == 0 {
+                0
+            } else {
+                precision as usize + 1
+            } + 1
+                + suffix.len();
+        if len > Self::MAX_LEN {
+            return Self::UNKNOWN;
+        }
+
+        let mut string = Str::new();
+        string.push_str_panic(itoa.format(scaled / scale));
+
+        if precision != 0 {
+            // Pad with leading zeros, e.g. `.005`.
+            let fract = itoa.format(scaled % scale + scale);
+            string.push_char_panic('.');
+            string.push_str_panic(&fract[1..]);
+        }
+
+        string.push_char_panic(' ');
+        string.push_str_panic(suffix);
+
+        Self(bytes, string)
+    }
+
+    /// `bytes` in `unit` multiplied by `scale`, rounded with `rounding`.
+    fn priv_scaled(unit: Unit, bytes: u64, scale: u64, rounding: Rounding) -> u64 {
+        let unit_bytes = u128::from(unit.bytes());
+        let n = u128::from(bytes) * u128::from(scale);
+        let (scaled, rest) = (n / unit_bytes, n % unit_bytes);
+        let odd = scaled % 2 == 1;
+        // This fits, `scale` is only used with units of at least `1000` bytes.
+        scaled as u64 + u64::from(rounding.round_up(odd, (rest * 2).cmp(&unit_bytes)))
+    }
+
     /// Private constructor
     fn from_priv(bytes: u64) -> Self {
         const UNITS: [u8; 6] = [b'K', b'M', b'G', b'T', b'P', b'E'];
-        const LN_KILOBYTE: f64 = 6.931471806; // ln 1024
         const Z: u8 = b'0';
         const SPACE: u8 = b' ';
         const B: u8 = b'B';
@@ -254,7 +736,7 @@ impl Byte {
         }
 
         // Our final string buffer.
-        let mut b = [0; 10];
+        let mut b = [0; Self::MAX_LEN];
 
         // If bytes is `999 B` or less.
         if bytes < Self::KILOBYTE {
@@ -272,10 +754,11 @@ impl Byte {
         // Else calculate.
         } else {
             let size = bytes as f64;
-            let exp = match (size.ln() / LN_KILOBYTE) as usize {
-                0 => 1,
-                e => e,
-            };
+            // The largest `1000^exp` that fits.
+            let mut exp = 1;
+            while exp < UNITS.len() && bytes >= KILOBYTE.pow(exp as u32 + 1) {
+                exp += 1;
+            }
 
             // e.g, 111.222
             // 111
@@ -456,11 +939,306 @@ impl_noni! {
     NonZeroIsize,&NonZeroIsize,
 }
 
+//---------------------------------------------------------------------------------------------------- SQL
+// Stored as a `BIGINT`, so anything over `i64::MAX` can't be stored.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+impl Byte {
+    #[inline]
+    // Negative numbers are `Self::UNKNOWN`.
+    fn priv_from_sql(i: i64) -> Self {
+        Self::from(i)
+    }
+
+    #[inline]
+    const fn priv_to_sql(&self) -> Option<&i64> {
+        crate::macros::u64_as_i64(&self.0)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+crate::macros::impl_sqlx!(Byte => i64);
+#[cfg(feature = "diesel")]
+crate::macros::impl_diesel!(Byte => i64 as BigInt);
+
 //---------------------------------------------------------------------------------------------------- Tests
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "clap")]
+    fn clap() {
+        let command = clap::Command::new("test").arg(
+            clap::Arg::new("arg")
+                .long("arg")
+                .value_parser(clap::value_parser!(Byte)),
+        );
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["test", "--arg", "1.5GiB"])
+            .unwrap();
+        assert_eq!(*matches.get_one::<Byte>("arg").unwrap(), 1_610_612_736_u64);
+
+        let error = command
+            .try_get_matches_from(["test", "--arg", "1.5XB"])
+            .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(error.to_string().contains("expected a byte size"));
+    }
+
+    #[test]
+    fn from_str() {
+        for (string, bytes) in [
+            ("0", 0),
+            ("1", 1),
+            ("  1 KB  ", 1_000),
+            ("1kb", 1_000),
+            ("1KiB", 1_024),
+            ("0.5 KiB", 512),
+            ("1.0015 KB", 1_002),
+        ] {
+            assert_eq!(Byte::from_str(string).unwrap(), bytes, "{string}");
+        }
+
+        for string in [
+            "",
+            " ",
+            "1.",
+            ".5",
+            "1..5",
+            "1.5.5",
+            "1 K B",
+            "1 KBB",
+            "16 EiB",
+            "18446744073709551616",
+        ] {
+            assert!(Byte::from_str(string).is_err(), "{string}");
+        }
+
+        // Every unit round-trips.
+        for unit in Unit::SI.into_iter().chain(Unit::BINARY) {
+            let byte = Byte::fixed_unit(unit, unit.bytes() * 3);
+            assert_eq!(Byte::from_str(&byte).unwrap(), unit.bytes() * 3, "{unit}");
+        }
+        assert_eq!("1.5 MB".parse::<Byte>().unwrap(), 1_500_000);
+    }
+
+    #[test]
+    #[cfg(any(feature = "sqlx", feature = "diesel"))]
+    fn sql() {
+        assert_eq!(Byte::priv_from_sql(1_234), "1.234 KB");
+        assert!(Byte::priv_from_sql(-1).is_unknown());
+
+        assert_eq!(Byte::from(1_234_u64).priv_to_sql(), Some(&1_234));
+        assert_eq!(Byte::MAX.priv_to_sql(), None);
+    }
+
+    #[test]
+    fn unit_boundaries() {
+        // These used to pick the unit with `ln(1024)`.
+        assert_eq!(Byte::from(1_023_999), "1.023 MB");
+        assert_eq!(Byte::from(1_000_000_001), "1.000 GB");
+        assert_eq!(Byte::from(999_999), "999.999 KB");
+
+        for (exp, unit) in Unit::SI.into_iter().enumerate().skip(1) {
+            let bytes = 1_000_u64.pow(exp as u32);
+            assert_eq!(Byte::from(bytes - 1).unit(), Unit::SI[exp - 1]);
+            assert_eq!(Byte::from(bytes).unit(), unit);
+            assert_eq!(Byte::from(bytes + 1).unit(), unit);
+            assert!(Byte::from(bytes + 1).len() <= 10);
+        }
+    }
+
+    #[test]
+    fn with_standard() {
+        for standard in [Standard::Binary, Standard::Jedec] {
+            assert_eq!(Byte::with_standard(1_023, standard), "1023 B");
+
+            let units = standard.units();
+            for exp in 1..units.len() {
+                let bytes = 1_024_u64.pow(exp as u32);
+                let suffix = standard.suffix(units[exp]);
+                let byte = Byte::with_standard(bytes, standard);
+                assert_eq!(byte.as_str(), format!("1.000 {suffix}"));
+                assert_eq!(byte, bytes);
+
+                if exp > 1 {
+                    let suffix = standard.suffix(units[exp - 1]);
+                    let byte = Byte::with_standard(bytes - 1, standard);
+                    assert_eq!(byte.as_str(), format!("1023.999 {suffix}"));
+                }
+            }
+        }
+
+        for bytes in (0..u64::MAX).step_by(99_999_999_999_989).chain([u64::MAX]) {
+            assert_eq!(Byte::with_standard(bytes, Standard::Si), Byte::from(bytes));
+            let binary = Byte::with_standard(bytes, Standard::Binary);
+            let jedec = Byte::with_standard(bytes, Standard::Jedec);
+            assert_eq!(binary.replace("iB", "B"), jedec.as_str());
+            assert!(jedec.len() <= 11);
+        }
+    }
+
+    #[test]
+    fn standard_round_trip() {
+        for standard in [Standard::Binary, Standard::Jedec] {
+            for bytes in [
+                0,
+                1,
+                1_023,
+                1_024,
+                1_536,
+                1 << 20,
+                1 << 30,
+                1 << 60,
+                u64::MAX,
+            ] {
+                let byte = Byte::with_standard(bytes, standard);
+                assert!(byte.unit_standard(standard).bytes() <= bytes.max(1));
+                // The decimals are truncated, so only the string round-trips.
+                let parsed = Byte::from_str_standard(&byte, standard).unwrap();
+                assert_eq!(parsed.as_str(), byte.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn symbols() {
+        // There is no bit-rate type, this only checks that no byte
+        // symbol could be read as a bit, i.e. a lowercase `b`.
+        for standard in Standard::ALL {
+            for unit in standard.units() {
+                let suffix = standard.suffix(unit);
+                assert!(suffix.ends_with('B'), "{suffix}");
+                assert!(!suffix.contains('b'), "{suffix}");
+            }
+            for bytes in [0, 1, 1_023, 1_024, 1_000_000, u64::MAX] {
+                let byte = Byte::with_standard(bytes, standard);
+                assert!(byte.ends_with('B'), "{byte}");
+                assert!(!byte.contains('b'), "{byte}");
+            }
+        }
+
+        // Only the `Binary` suffixes have an `i`.
+        for unit in Unit::SI.into_iter().chain(Unit::BINARY) {
+            assert_eq!(
+                Standard::Binary.suffix(unit).contains('i'),
+                unit.is_binary()
+            );
+            assert!(!Standard::Jedec.suffix(unit).contains('i'));
+        }
+    }
+
+    #[test]
+    fn math() {
+        let byte = Byte::from(1_000_u64);
+        assert_eq!(byte + 24_u64, Byte::from(1_024_u64));
+        assert_eq!(24_u64 + byte, 1_024);
+        assert_eq!(byte - 1_u64, "999 B");
+        assert_eq!(byte * 1_000_u64, "1.000 MB");
+        assert_eq!(byte / 3_u64, 333);
+        assert_eq!(byte % 3_u64, 1);
+
+        assert!(byte == 1_000_u64);
+        assert!(byte > 999_u64);
+        assert!(byte < 1_001_u64);
+        assert!(999_u64 < byte);
+
+        let sizes = [byte; 3];
+        assert_eq!(sizes.iter().sum::<Byte>(), "3.000 KB");
+        assert_eq!(sizes.into_iter().sum::<Byte>(), 3_000);
+        assert_eq!(std::iter::empty::<Byte>().sum::<Byte>(), 0);
+    }
+
+    #[test]
+    fn with_rounding() {
+        for bytes in (0..u64::MAX).step_by(99_999_999_999_989).chain([u64::MAX]) {
+            assert_eq!(
+                Byte::with_rounding(bytes, Rounding::Truncate),
+                Byte::from(bytes)
+            );
+            for rounding in [Rounding::HalfUp, Rounding::HalfEven] {
+                let byte = Byte::with_rounding(bytes, rounding);
+                assert!(byte.len() <= 10);
+                assert!(!byte.starts_with("1000"));
+            }
+        }
+
+        for (exp, unit) in Unit::SI.into_iter().enumerate().skip(3) {
+            let bytes = 1_000_u64.pow(exp as u32);
+            assert_eq!(
+                Byte::with_rounding(bytes - 1, Rounding::HalfUp).unit(),
+                unit
+            );
+            assert_eq!(
+                Byte::with_rounding(bytes - 1, Rounding::Truncate).unit(),
+                Unit::SI[exp - 1]
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_unit() {
+        for unit in Unit::SI.into_iter().chain(Unit::BINARY) {
+            for bytes in [0, 1, 999, 1_000, 1_024, 1 << 40, u64::MAX] {
+                let byte = Byte::fixed_unit(unit, bytes);
+                if byte.is_unknown() {
+                    // Too long, e.g. `18446744073709551615 B`.
+                    assert!(bytes / unit.bytes() > 99_999);
+                    continue;
+                }
+                assert!(byte.len() <= Byte::MAX_LEN);
+                assert_eq!(byte.unit(), unit);
+                assert_eq!(byte.inner(), bytes);
+                assert!(byte.ends_with(unit.as_str()));
+            }
+        }
+        assert!(Byte::fixed_unit(Unit::B, u64::MAX).is_unknown());
+        assert_eq!(Byte::fixed_unit(Unit::EiB, u64::MAX), "15.999 EiB");
+        assert_eq!(
+            Byte::fixed_unit(Unit::B, 9_999_999_999_999),
+            "9999999999999 B"
+        );
+        assert!(Byte::fixed_unit(Unit::B, 10_000_000_000_000).is_unknown());
+
+        assert_eq!(Byte::fixed_unit(Unit::KB, 1_005), "1.005 KB");
+        assert_eq!(Byte::fixed_unit_precision(Unit::KB, 1_005, 2), "1.00 KB");
+        assert_eq!(Byte::fixed_unit(Unit::EB, 0), "0.000 EB");
+    }
+
+    #[test]
+    #[cfg(feature = "up")]
+    fn eta_at_rate() {
+        for (bytes, rate, secs) in [
+            (0, 1, 0),
+            (1, 1, 1),
+            (1, 2, 1),
+            (3, 2, 2),
+            (4, 2, 2),
+            (u64::MAX, u64::MAX, 1),
+            (u64::MAX - 1, u64::MAX, 1),
+            (u64::from(u32::MAX), 1, u32::MAX),
+        ] {
+            let eta = Byte::from(bytes).eta_at_rate(Byte::from(rate));
+            assert_eq!(eta, secs, "{bytes} / {rate}");
+        }
+
+        // `f64` would round these to the same number.
+        let a = Byte::from(9_007_199_254_740_993_u64);
+        let b = Byte::from(9_007_199_254_740_992_u64);
+        assert_ne!(
+            a.eta_at_rate(Byte::from(4_503_599_627_370_496_u64)),
+            b.eta_at_rate(Byte::from(4_503_599_627_370_496_u64)),
+        );
+
+        assert!(Byte::from(u64::from(u32::MAX) + 1)
+            .eta_at_rate(Byte::BYTE)
+            .is_unknown());
+        assert!(Byte::UNKNOWN.eta_at_rate(Byte::BYTE).is_unknown());
+        assert!(Byte::BYTE.eta_at_rate(Byte::ZERO).is_unknown());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
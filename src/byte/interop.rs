@@ -0,0 +1,41 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::byte::Byte;
+use crate::macros::impl_json_value;
+
+//---------------------------------------------------------------------------------------------------- serde_json
+/// This will return [`Self::UNKNOWN`] wrapped in [`Result::Err`]
+/// if the number is negative or too large for a [`u64`].
+impl TryFrom<&serde_json::Number> for Byte {
+    type Error = Self;
+    #[inline]
+    fn try_from(number: &serde_json::Number) -> Result<Self, Self> {
+        if let Some(u) = number.as_u64() {
+            return Ok(Self::from(u));
+        }
+
+        match number.as_f64() {
+            Some(f) if f.is_sign_positive() && f <= u64::MAX as f64 => Ok(Self::from(f)),
+            _ => Err(Self::UNKNOWN),
+        }
+    }
+}
+
+impl_json_value!(Byte);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn byte() {
+        assert_eq!(Byte::try_from(&json!(1_234)).unwrap(), "1.234 KB");
+        assert_eq!(Byte::try_from(&json!(1_234.9)).unwrap(), "1.234 KB");
+        assert_eq!(Byte::try_from(&json!("1234")).unwrap(), "1.234 KB");
+        assert_eq!(Byte::try_from(&json!(-1)), Err(Byte::UNKNOWN));
+        assert_eq!(Byte::try_from(&json!(1e30)), Err(Byte::UNKNOWN));
+        assert_eq!(Byte::try_from(&json!(null)), Err(Byte::UNKNOWN));
+        assert_eq!(Byte::try_from(&json!("1 KB")), Err(Byte::UNKNOWN));
+    }
+}
@@ -2,3 +2,14 @@
 
 mod byte;
 pub use byte::*;
+
+mod unit;
+pub use unit::*;
+
+#[cfg(feature = "num")]
+mod progress;
+#[cfg(feature = "num")]
+pub use progress::*;
+
+#[cfg(feature = "serde_json")]
+mod interop;
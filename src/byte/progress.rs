@@ -0,0 +1,356 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::byte::Byte;
+use crate::num::Percent;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Progress
+/// Human-readable `done / total` byte progress
+///
+/// This takes `(done, total)` bytes as input and stores
+/// the combined [`Byte`]'s along with a [`Percent`]:
+/// ```rust
+/// # use readable::byte::*;
+/// let progress = Progress::new(1_000_000, 2_000_000);
+/// assert_eq!(progress, "1.000 MB / 2.000 MB (50.00%)");
+///
+/// assert_eq!(progress.done(),    1_000_000);
+/// assert_eq!(progress.total(),   2_000_000);
+/// assert_eq!(progress.percent(), 50.0);
+/// ```
+///
+/// ## Updating
+/// [`Progress::set_done`] can be used to update the `done` amount.
+///
+/// The `total` is only formatted once in [`Progress::new`], only
+/// the `done` [`Byte`] and the [`Percent`] are re-formatted on updates.
+///
+/// ```rust
+/// # use readable::byte::*;
+/// let mut progress = Progress::new(0, 1_000);
+/// assert_eq!(progress, "0 B / 1.000 KB (0.00%)");
+///
+/// progress.set_done(500);
+/// assert_eq!(progress, "500 B / 1.000 KB (50.00%)");
+///
+/// progress.set_done(1_000);
+/// assert_eq!(progress, "1.000 KB / 1.000 KB (100.00%)");
+/// ```
+///
+/// ## Percent
+/// The [`Percent`] is clamped to `100.00%`, even if `done` is greater than `total`.
+///
+/// If `total` is `0`, the [`Percent`] will be [`Percent::UNKNOWN`].
+/// ```rust
+/// # use readable::byte::*;
+/// assert_eq!(Progress::new(2_000, 1_000), "2.000 KB / 1.000 KB (100.00%)");
+/// assert_eq!(Progress::new(0, 0),         "0 B / 0 B (?.??%)");
+/// ```
+///
+/// ## Size
+/// [`Str<33>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::byte::*;
+/// assert_eq!(std::mem::size_of::<Progress>(), 120);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct Progress {
+    done: Byte,
+    total: Byte,
+    percent: Percent,
+    string: Str<{ Progress::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Progress {
+    /// The maximum string length of a [`Progress`]
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!("xxx.xxx KB / xxx.xxx KB (100.00%)".len(), Progress::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 33;
+
+    /// ```rust
+    /// # use readable::byte::*;
+    /// # use readable::num::Percent;
+    /// assert_eq!(Progress::UNKNOWN, "???.??? B / ???.??? B (?.??%)");
+    /// assert_eq!(Progress::UNKNOWN.done(),    Byte::UNKNOWN);
+    /// assert_eq!(Progress::UNKNOWN.total(),   Byte::UNKNOWN);
+    /// assert_eq!(Progress::UNKNOWN.percent(), Percent::UNKNOWN);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        done: Byte::UNKNOWN,
+        total: Byte::UNKNOWN,
+        percent: Percent::UNKNOWN,
+        string: Str::from_static_str("???.??? B / ???.??? B (?.??%)"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Progress Impl
+impl Progress {
+    #[must_use]
+    /// Create a new [`Progress`] from `done` and `total` bytes
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Progress::new(1, 1_000), "1 B / 1.000 KB (0.10%)");
+    /// ```
+    pub fn new(done: u64, total: u64) -> Self {
+        let total = Byte::from(total);
+        Self::priv_from(Byte::from(done), total)
+    }
+
+    /// Update the `done` bytes
+    ///
+    /// This re-formats the `done` [`Byte`] and the [`Percent`],
+    /// the already formatted `total` string is re-used.
+    ///
+    /// If `done` is the same as the current `done`, this does nothing.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// let mut progress = Progress::new(0, 4_000);
+    ///
+    /// progress.set_done(1_000);
+    /// assert_eq!(progress, "1.000 KB / 4.000 KB (25.00%)");
+    ///
+    /// progress.set_done(3_000);
+    /// assert_eq!(progress, "3.000 KB / 4.000 KB (75.00%)");
+    /// ```
+    pub fn set_done(&mut self, done: u64) {
+        if self.done.inner() == done && !self.is_unknown() {
+            return;
+        }
+        *self = Self::priv_from(Byte::from(done), self.total);
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `done` [`Byte`]
+    pub const fn done(&self) -> Byte {
+        self.done
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `total` [`Byte`]
+    pub const fn total(&self) -> Byte {
+        self.total
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `done / total` [`Percent`]
+    pub const fn percent(&self) -> Percent {
+        self.percent
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Progress::new(1, 2).as_str(), "1 B / 2 B (50.00%)");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Progress::new(1, 2).as_bytes(), b"1 B / 2 B (50.00%)");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Progress::new(1, 2).len(), 18);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert!(Progress::UNKNOWN.is_unknown());
+    /// assert!(!Progress::new(0, 0).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"???.??? B / ???.??? B (?.??%)")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Progress {
+    /// Private constructor, `total` is already formatted.
+    fn priv_from(done: Byte, total: Byte) -> Self {
+        let percent = match total.inner() {
+            0 => Percent::UNKNOWN,
+            t => {
+                let p = done.inner() as f64 / t as f64 * 100.0;
+                Percent::from(p.min(100.0))
+            }
+        };
+
+        let mut string = Str::new();
+        string.push_str_panic(done.as_str());
+        string.push_str_panic(" / ");
+        string.push_str_panic(total.as_str());
+        string.push_str_panic(" (");
+        string.push_str_panic(percent.as_str());
+        string.push_str_panic(")");
+
+        Self {
+            done,
+            total,
+            percent,
+            string,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Progress {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Progress {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `percent` is derived from `done` and `total`.
+        let Self {
+            done,
+            total,
+            string,
+            ..
+        } = self;
+        f.debug_struct("Progress")
+            .field("inner", &(done.inner(), total.inner()))
+            .field("str", &string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Progress {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Progress {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Progress> for str {
+    #[inline]
+    fn eq(&self, other: &Progress) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Progress> for &str {
+    #[inline]
+    fn eq(&self, other: &Progress) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Progress::new(512, 1024)),
+            r#"Progress { inner: (512, 1024), str: "512 B / 1.024 KB (50.00%)" }"#
+        );
+    }
+
+    #[test]
+    fn set_done() {
+        let mut this = Progress::new(0, u64::MAX);
+        for done in [1, 1_000, 1_000_000, u64::MAX / 2, u64::MAX] {
+            this.set_done(done);
+            assert_eq!(this, Progress::new(done, u64::MAX));
+        }
+        assert_eq!(this, "18.446 EB / 18.446 EB (100.00%)");
+    }
+
+    #[test]
+    fn max_len() {
+        let this = Progress::new(999_999, 999_999_999);
+        assert!(this.len() <= Progress::MAX_LEN);
+        let this = Progress::new(999_999_999, 999_999_999);
+        assert_eq!(this, "999.999 MB / 999.999 MB (100.00%)");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: Progress = Progress::new(500, 1000);
+        let json = serde_json::to_string(&this).unwrap();
+
+        let this: Progress = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "500 B / 1.000 KB (50.00%)");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<Progress>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&Progress::UNKNOWN).unwrap();
+        assert!(serde_json::from_str::<Progress>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this: Progress = Progress::new(500, 1000);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: Progress = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "500 B / 1.000 KB (50.00%)");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&Progress::UNKNOWN).unwrap();
+        let this: Progress = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
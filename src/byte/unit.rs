@@ -0,0 +1,289 @@
+//---------------------------------------------------------------------------------------------------- Unit
+/// A byte unit
+///
+/// The `SI` units (`KB`, `MB`, etc) are powers of `1000`,
+/// the binary units (`KiB`, `MiB`, etc) are powers of `1024`.
+///
+/// This is used by [`Byte::fixed_unit`](crate::byte::Byte::fixed_unit)
+/// and returned by [`Byte::unit`](crate::byte::Byte::unit).
+///
+/// ```rust
+/// # use readable::byte::*;
+/// assert_eq!(Unit::MB.as_str(), "MB");
+/// assert_eq!(Unit::MB.bytes(), 1_000_000);
+///
+/// assert_eq!(Unit::MiB.as_str(), "MiB");
+/// assert_eq!(Unit::MiB.bytes(), 1_048_576);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    /// Byte
+    B,
+    /// Kilobyte, `1000` bytes
+    KB,
+    /// Megabyte, `1000^2` bytes
+    MB,
+    /// Gigabyte, `1000^3` bytes
+    GB,
+    /// Terabyte, `1000^4` bytes
+    TB,
+    /// Petabyte, `1000^5` bytes
+    PB,
+    /// Exabyte, `1000^6` bytes
+    EB,
+    /// Kibibyte, `1024` bytes
+    KiB,
+    /// Mebibyte, `1024^2` bytes
+    MiB,
+    /// Gibibyte, `1024^3` bytes
+    GiB,
+    /// Tebibyte, `1024^4` bytes
+    TiB,
+    /// Pebibyte, `1024^5` bytes
+    PiB,
+    /// Exbibyte, `1024^6` bytes
+    EiB,
+}
+
+impl Unit {
+    /// The `SI` units, from smallest to largest
+    pub const SI: [Self; 7] = [
+        Self::B,
+        Self::KB,
+        Self::MB,
+        Self::GB,
+        Self::TB,
+        Self::PB,
+        Self::EB,
+    ];
+
+    /// The binary units, from smallest to largest
+    pub const BINARY: [Self; 7] = [
+        Self::B,
+        Self::KiB,
+        Self::MiB,
+        Self::GiB,
+        Self::TiB,
+        Self::PiB,
+        Self::EiB,
+    ];
+
+    #[inline]
+    #[must_use]
+    /// The unit's suffix, e.g. `MiB`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::B => "B",
+            Self::KB => "KB",
+            Self::MB => "MB",
+            Self::GB => "GB",
+            Self::TB => "TB",
+            Self::PB => "PB",
+            Self::EB => "EB",
+            Self::KiB => "KiB",
+            Self::MiB => "MiB",
+            Self::GiB => "GiB",
+            Self::TiB => "TiB",
+            Self::PiB => "PiB",
+            Self::EiB => "EiB",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The unit's suffix in the JEDEC convention, e.g. `MB` for [`Unit::MiB`]
+    ///
+    /// JEDEC uses the binary (`1024`) units with the `SI` suffixes,
+    /// so the binary units drop the `i` and the `SI` units stay the same.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Unit::MiB.as_str_jedec(), "MB");
+    /// assert_eq!(Unit::MB.as_str_jedec(),  "MB");
+    /// assert_eq!(Unit::B.as_str_jedec(),   "B");
+    /// ```
+    pub const fn as_str_jedec(self) -> &'static str {
+        match self {
+            Self::B => "B",
+            Self::KB | Self::KiB => "KB",
+            Self::MB | Self::MiB => "MB",
+            Self::GB | Self::GiB => "GB",
+            Self::TB | Self::TiB => "TB",
+            Self::PB | Self::PiB => "PB",
+            Self::EB | Self::EiB => "EB",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// How many bytes `1` of this unit is
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Unit::B.bytes(), 1);
+    /// assert_eq!(Unit::KB.bytes(), 1_000);
+    /// assert_eq!(Unit::KiB.bytes(), 1_024);
+    /// assert_eq!(Unit::EiB.bytes(), 1 << 60);
+    /// ```
+    pub const fn bytes(self) -> u64 {
+        match self {
+            Self::B => 1,
+            Self::KB => 1_000,
+            Self::MB => 1_000_000,
+            Self::GB => 1_000_000_000,
+            Self::TB => 1_000_000_000_000,
+            Self::PB => 1_000_000_000_000_000,
+            Self::EB => 1_000_000_000_000_000_000,
+            Self::KiB => 1 << 10,
+            Self::MiB => 1 << 20,
+            Self::GiB => 1 << 30,
+            Self::TiB => 1 << 40,
+            Self::PiB => 1 << 50,
+            Self::EiB => 1 << 60,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if this is a binary (`1024`) unit
+    ///
+    /// [`Unit::B`] is neither, and returns `false`.
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert!(Unit::MiB.is_binary());
+    /// assert!(!Unit::MB.is_binary());
+    /// assert!(!Unit::B.is_binary());
+    /// ```
+    pub const fn is_binary(self) -> bool {
+        matches!(
+            self,
+            Self::KiB | Self::MiB | Self::GiB | Self::TiB | Self::PiB | Self::EiB
+        )
+    }
+
+    #[inline]
+    /// Parse the suffix returned by [`Unit::as_str`]
+    pub(super) fn from_suffix(suffix: &str) -> Option<Self> {
+        Self::SI
+            .into_iter()
+            .chain(Self::BINARY)
+            .find(|unit| unit.as_str() == suffix)
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Standard
+/// The convention used for the byte units
+///
+/// | Standard | `1 KB`       | Suffixes               |
+/// |----------|--------------|------------------------|
+/// | `Si`     | `1000` bytes | `KB`, `MB`, `GB`...    |
+/// | `Binary` | `1024` bytes | `KiB`, `MiB`, `GiB`... |
+/// | `Jedec`  | `1024` bytes | `KB`, `MB`, `GB`...    |
+///
+/// [`Byte::from`](crate::byte::Byte) uses [`Standard::Si`],
+/// see [`Byte::with_standard`](crate::byte::Byte::with_standard) for the others.
+///
+/// ```rust
+/// # use readable::byte::*;
+/// assert_eq!(Byte::with_standard(1_048_576, Standard::Si),     "1.048 MB");
+/// assert_eq!(Byte::with_standard(1_048_576, Standard::Binary), "1.000 MiB");
+/// assert_eq!(Byte::with_standard(1_048_576, Standard::Jedec),  "1.000 MB");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Standard {
+    #[default]
+    /// Powers of `1000` with `SI` suffixes, e.g. `1 KB` is `1000` bytes
+    Si,
+    /// Powers of `1024` with binary suffixes, e.g. `1 KiB` is `1024` bytes
+    Binary,
+    /// Powers of `1024` with `SI` suffixes, e.g. `1 KB` is `1024` bytes
+    ///
+    /// This is what Windows and JEDEC memory specifications use.
+    Jedec,
+}
+
+impl Standard {
+    /// All the variants
+    pub const ALL: [Self; 3] = [Self::Si, Self::Binary, Self::Jedec];
+
+    #[inline]
+    #[must_use]
+    /// How many bytes `1 KB` (or `1 KiB`) is in this standard
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Standard::Si.base(),     1000);
+    /// assert_eq!(Standard::Binary.base(), 1024);
+    /// assert_eq!(Standard::Jedec.base(),  1024);
+    /// ```
+    pub const fn base(self) -> u64 {
+        match self {
+            Self::Si => 1000,
+            Self::Binary | Self::Jedec => 1024,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The units this standard uses, from smallest to largest
+    ///
+    /// [`Standard::Jedec`] uses the binary units, with their [`Unit::as_str_jedec`] suffix.
+    pub const fn units(self) -> [Unit; 7] {
+        match self {
+            Self::Si => Unit::SI,
+            Self::Binary | Self::Jedec => Unit::BINARY,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The suffix of `unit` in this standard
+    ///
+    /// ```rust
+    /// # use readable::byte::*;
+    /// assert_eq!(Standard::Binary.suffix(Unit::MiB), "MiB");
+    /// assert_eq!(Standard::Jedec.suffix(Unit::MiB),  "MB");
+    /// ```
+    pub const fn suffix(self, unit: Unit) -> &'static str {
+        match self {
+            Self::Si | Self::Binary => unit.as_str(),
+            Self::Jedec => unit.as_str_jedec(),
+        }
+    }
+
+    /// The unit a parsed `unit` suffix means in this standard
+    ///
+    /// [`Standard::Jedec`] reads the `SI` suffixes as the binary units.
+    pub(super) const fn read(self, unit: Unit) -> Unit {
+        match (self, unit) {
+            (Self::Jedec, Unit::KB) => Unit::KiB,
+            (Self::Jedec, Unit::MB) => Unit::MiB,
+            (Self::Jedec, Unit::GB) => Unit::GiB,
+            (Self::Jedec, Unit::TB) => Unit::TiB,
+            (Self::Jedec, Unit::PB) => Unit::PiB,
+            (Self::Jedec, Unit::EB) => Unit::EiB,
+            _ => unit,
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Caching repeated formats
+//!
+//! [`ReadableCache`] is a small LRU cache that maps an
+//! input (usually the inner number) to an already formatted type.
+//!
+//! This is useful when the same values are formatted over and over,
+//! e.g. a table where thousands of rows show the same `3:45` [`Runtime`](crate::run::Runtime).
+//!
+//! ```rust
+//! # use readable::cache::*;
+//! # use readable::run::*;
+//! let mut cache = ReadableCache::<u32, Runtime>::new(128);
+//!
+//! for secs in [225, 225, 225, 60] {
+//!     let runtime = cache.get(secs);
+//!     assert_eq!(*runtime, Runtime::from(secs));
+//! }
+//!
+//! let stats = cache.stats();
+//! assert_eq!(stats.hits, 2);
+//! assert_eq!(stats.misses, 2);
+//! assert_eq!(stats.hit_rate(), 0.5);
+//! ```
+//!
+//! The formatting in `readable` is already fast, so this is only
+//! worth it for the more expensive types or a lot of repeated values.
+
+//---------------------------------------------------------------------------------------------------- Use
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//---------------------------------------------------------------------------------------------------- ReadableCache
+/// A small LRU cache of formatted types
+///
+/// This maps a `K` key (usually the inner number) to a `V` value (a `readable` type).
+///
+/// Once [`ReadableCache::capacity`] is reached, the least recently used entry is evicted.
+/// Eviction scans the entire cache, so this is meant for small capacities (hundreds to thousands).
+///
+/// Floats do not implement [`Hash`], key them by their bits instead:
+/// ```rust
+/// # use readable::cache::*;
+/// # use readable::num::*;
+/// let mut cache = ReadableCache::<u64, Float>::new(16);
+///
+/// let f = 1234.5_f64;
+/// let float = cache.get_or_insert_with(f.to_bits(), |bits| Float::from(f64::from_bits(bits)));
+/// assert_eq!(*float, "1,234.500");
+/// ```
+///
+/// A `capacity` of `0` caches nothing, every lookup is a miss.
+///
+/// ```rust
+/// # use readable::cache::*;
+/// # use readable::num::*;
+/// let mut cache = ReadableCache::<u64, Unsigned>::new(0);
+/// assert_eq!(*cache.get(1000), "1,000");
+/// assert_eq!(*cache.get(1000), "1,000");
+/// assert_eq!(cache.len(), 0);
+/// assert_eq!(cache.stats().misses, 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReadableCache<K, V> {
+    map: HashMap<K, (V, u64)>,
+    capacity: usize,
+    // Incremented on every lookup, used as the "last used" timestamp.
+    tick: u64,
+    stats: CacheStats,
+    // Where the value goes when `capacity` is `0`.
+    uncached: Option<V>,
+}
+
+//---------------------------------------------------------------------------------------------------- CacheStats
+/// Statistics of a [`ReadableCache`]
+///
+/// ```rust
+/// # use readable::cache::*;
+/// let stats = CacheStats { hits: 3, misses: 1, evictions: 0 };
+/// assert_eq!(stats.lookups(), 4);
+/// assert_eq!(stats.hit_rate(), 0.75);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CacheStats {
+    /// Lookups that found an existing entry
+    pub hits: u64,
+    /// Lookups that had to create the entry
+    pub misses: u64,
+    /// Entries removed to make room for new ones
+    pub evictions: u64,
+}
+
+impl CacheStats {
+    #[inline]
+    #[must_use]
+    /// The total amount of lookups, `hits + misses`
+    pub const fn lookups(&self) -> u64 {
+        self.hits.saturating_add(self.misses)
+    }
+
+    #[inline]
+    #[must_use]
+    /// The ratio of lookups that were hits, `0.0..=1.0`
+    ///
+    /// This is `0.0` if there were no lookups.
+    ///
+    /// ```rust
+    /// # use readable::cache::*;
+    /// assert_eq!(CacheStats::default().hit_rate(), 0.0);
+    /// ```
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl<K: Hash + Eq + Copy, V> ReadableCache<K, V> {
+    #[must_use]
+    /// Create an empty cache that holds at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            capacity,
+            tick: 0,
+            stats: CacheStats::default(),
+            uncached: None,
+        }
+    }
+
+    /// Return the cached value for `key`, or create it with `f`
+    ///
+    /// ```rust
+    /// # use readable::cache::*;
+    /// # use readable::up::*;
+    /// let mut cache = ReadableCache::new(16);
+    ///
+    /// let uptime = cache.get_or_insert_with(86_400_u32, Uptime::from);
+    /// assert_eq!(*uptime, "1d");
+    ///
+    /// // Cached, `f` is not called.
+    /// let uptime = cache.get_or_insert_with(86_400_u32, |_| unreachable!());
+    /// assert_eq!(*uptime, "1d");
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> &V
+    where
+        F: FnOnce(K) -> V,
+    {
+        self.tick += 1;
+        let tick = self.tick;
+
+        if self.map.contains_key(&key) {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+
+            if self.capacity == 0 {
+                return self.uncached.insert(f(key));
+            }
+
+            if self.map.len() >= self.capacity {
+                self.evict();
+            }
+            self.map.insert(key, (f(key), tick));
+        }
+
+        // The key was found or just inserted.
+        let (value, used) = self.map.get_mut(&key).unwrap_or_else(|| unreachable!());
+        *used = tick;
+        value
+    }
+
+    /// Return the cached value for `key`, or create it with [`From`]
+    ///
+    /// ```rust
+    /// # use readable::cache::*;
+    /// # use readable::byte::*;
+    /// let mut cache = ReadableCache::<u64, Byte>::new(16);
+    /// assert_eq!(*cache.get(1_500), "1.500 KB");
+    /// assert_eq!(cache.stats().misses, 1);
+    ///
+    /// assert_eq!(*cache.get(1_500), "1.500 KB");
+    /// assert_eq!(cache.stats().hits, 1);
+    /// ```
+    pub fn get(&mut self, key: K) -> &V
+    where
+        V: From<K>,
+    {
+        self.get_or_insert_with(key, V::from)
+    }
+
+    /// Return the cached value for `key` without creating it
+    ///
+    /// This does not count as a lookup in [`ReadableCache::stats`]
+    /// and does not change the LRU order.
+    ///
+    /// ```rust
+    /// # use readable::cache::*;
+    /// # use readable::num::*;
+    /// let mut cache = ReadableCache::<i64, Int>::new(16);
+    /// assert!(cache.peek(&-1).is_none());
+    ///
+    /// cache.get(-1);
+    /// assert_eq!(cache.peek(&-1).unwrap(), "-1");
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|(value, _)| value)
+    }
+
+    #[inline]
+    #[must_use]
+    /// The hits, misses and evictions so far
+    pub const fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    #[inline]
+    /// Reset [`ReadableCache::stats`] to `0`, the entries are kept
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    #[inline]
+    /// Remove all entries, [`ReadableCache::stats`] are kept
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.uncached = None;
+    }
+
+    #[inline]
+    #[must_use]
+    /// The amount of cached entries
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// If there are no cached entries
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// The maximum amount of cached entries
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl<K: Hash + Eq + Copy, V> ReadableCache<K, V> {
+    /// Remove the least recently used entry.
+    fn evict(&mut self) {
+        let oldest = self
+            .map
+            .iter()
+            .min_by_key(|(_, (_, used))| *used)
+            .map(|(key, _)| *key);
+
+        if let Some(key) = oldest {
+            self.map.remove(&key);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru() {
+        let mut cache = ReadableCache::<u32, u32>::new(2);
+        cache.get(1);
+        cache.get(2);
+        // `1` is now the most recently used.
+        cache.get(1);
+        // Evicts `2`.
+        cache.get(3);
+
+        assert!(cache.peek(&1).is_some());
+        assert!(cache.peek(&2).is_none());
+        assert!(cache.peek(&3).is_some());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 3,
+                evictions: 1,
+            }
+        );
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().lookups(), 4);
+        cache.reset_stats();
+        assert_eq!(cache.stats(), CacheStats::default());
+    }
+}
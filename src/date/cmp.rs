@@ -0,0 +1,97 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+use std::cmp::Ordering;
+
+//---------------------------------------------------------------------------------------------------- Cross-type comparison
+// Implements `PartialEq` and `PartialOrd` between 2 date types (both ways).
+//
+// These compare the inner `(year, month, day)` tuples, which is
+// what the derived `Ord` of each type is decided by (the string
+// is always the same for the same tuple), so this is consistent
+// with converting to the same type first and then comparing.
+macro_rules! impl_cmp {
+	($($a:ty => $b:ty),* $(,)?) => { $(
+		impl PartialEq<$b> for $a {
+			#[inline]
+			fn eq(&self, other: &$b) -> bool {
+				self.inner() == other.inner()
+			}
+		}
+		impl PartialEq<$a> for $b {
+			#[inline]
+			fn eq(&self, other: &$a) -> bool {
+				self.inner() == other.inner()
+			}
+		}
+		impl PartialOrd<$b> for $a {
+			#[inline]
+			fn partial_cmp(&self, other: &$b) -> Option<Ordering> {
+				Some(self.inner().cmp(&other.inner()))
+			}
+		}
+		impl PartialOrd<$a> for $b {
+			#[inline]
+			fn partial_cmp(&self, other: &$a) -> Option<Ordering> {
+				Some(self.inner().cmp(&other.inner()))
+			}
+		}
+	)*}
+}
+impl_cmp! {
+    Date => Nichi,
+    Date => NichiFull,
+    Date => NichiWareki,
+    Nichi => NichiFull,
+    Nichi => NichiWareki,
+    NichiFull => NichiWareki,
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent() {
+        let dates = [
+            Date::UNKNOWN,
+            Date::from_y(1000).unwrap(),
+            Date::from_ym(2020, 12).unwrap(),
+            Date::from_ymd(2020, 12, 25).unwrap(),
+            Date::from_ymd(2020, 12, 26).unwrap(),
+            Date::from_ymd(9999, 12, 31).unwrap(),
+        ];
+        let nichis = [
+            Nichi::UNKNOWN,
+            Nichi::new(1000, 1, 1).unwrap(),
+            Nichi::new(2020, 12, 25).unwrap(),
+            Nichi::new(9999, 12, 31).unwrap(),
+        ];
+
+        for date in dates {
+            for nichi in nichis {
+                let full = NichiFull::from(nichi);
+                let ord = date.inner().cmp(&nichi.inner());
+
+                assert_eq!(date.partial_cmp(&nichi), Some(ord));
+                assert_eq!(date.partial_cmp(&full), Some(ord));
+                assert_eq!(nichi.partial_cmp(&date), Some(ord.reverse()));
+                assert_eq!(full.partial_cmp(&date), Some(ord.reverse()));
+                assert_eq!(date == nichi, ord.is_eq());
+                assert_eq!(full == date, ord.is_eq());
+
+                // Same as converting first, for full dates.
+                if date.ok() {
+                    assert_eq!(
+                        Some(Nichi::from(date).cmp(&nichi)),
+                        date.partial_cmp(&nichi)
+                    );
+                }
+            }
+        }
+
+        for nichi in nichis {
+            assert!(NichiFull::from(nichi) == nichi);
+        }
+    }
+}
@@ -1,8 +1,9 @@
 //---------------------------------------------------------------------------------------------------- Use
 
-use crate::date::free::{ok, ok_day, ok_month, ok_year};
+use crate::date::free::{check, ok, ok_day, ok_month, ok_year};
+use crate::date::WeekStart;
 use crate::itoa;
-use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::macros::{impl_common, impl_const, impl_std_from_str, impl_traits};
 use crate::str::Str;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -264,6 +265,52 @@ pub(super) static DDMMY: Lazy<Regex> =
 /// assert_eq!(d4, "2000-12-25");
 /// ```
 ///
+/// ## Comparison
+/// [`Date`], [`Nichi`] and [`NichiFull`] can be compared to each other directly,
+/// by their `(year, month, day)`, the same as converting them to the same type first.
+///
+/// ```rust
+/// # use readable::date::*;
+/// let date  = Date::from_ymd(2020, 12, 25).unwrap();
+/// let nichi = Nichi::new(2020, 12, 25).unwrap();
+/// let full  = NichiFull::new(2020, 12, 26).unwrap();
+///
+/// assert!(date == nichi);
+/// assert!(date < full);
+/// assert!(full > nichi);
+///
+/// // Missing days and months sort first.
+/// assert!(Date::from_ym(2020, 12).unwrap() < nichi);
+///
+/// // Mixed dates can be sorted by the inner tuple.
+/// let mut dates = [full.inner(), date.inner(), Nichi::new(1999, 1, 1).unwrap().inner()];
+/// dates.sort();
+/// assert_eq!(dates, [(1999, 1, 1), (2020, 12, 25), (2020, 12, 26)]);
+/// ```
+///
+/// ## Duration
+/// A [`std::time::Duration`] can be added or subtracted, only the whole days are used.
+///
+/// The date must be a full date, and the result must be in-between `1000-9999`,
+/// else [`Date::UNKNOWN`] is returned.
+///
+/// ```rust
+/// # use readable::date::*;
+/// # use std::time::Duration;
+/// const DAY: Duration = Duration::from_secs(86400);
+///
+/// let date = Date::from_ymd(2024, 2, 28).unwrap();
+/// assert_eq!(date + DAY,     "2024-02-29");
+/// assert_eq!(date + DAY * 2, "2024-03-01");
+/// assert_eq!(date - DAY,     "2024-02-27");
+///
+/// // Less than a day does nothing.
+/// assert_eq!(date + Duration::from_secs(86399), date);
+///
+/// // Not a full date.
+/// assert!((Date::from_ym(2024, 2).unwrap() + DAY).is_unknown());
+/// ```
+///
 /// ## Size
 /// [`Str<10>`] is used internally to represent the string.
 ///
@@ -277,10 +324,18 @@ pub(super) static DDMMY: Lazy<Regex> =
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct Date((u16, u8, u8), Str<{ Date::MAX_LEN }>);
 
 impl_traits!(Date, (u16, u8, u8));
+impl_std_from_str!(Date => from_str);
+#[cfg(feature = "clap")]
+crate::macros::impl_clap!(Date, "a date, e.g. `2020-12-25`");
 
 //---------------------------------------------------------------------------------------------------- Date Constants
 impl Date {
@@ -344,6 +399,46 @@ impl Date {
         self.0 .2
     }
 
+    #[inline]
+    #[must_use]
+    /// Return the quarter of the year (1-4)
+    ///
+    /// `0` is returned if the month is missing.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Date::from_ym(2020, 3).unwrap().quarter(), 1);
+    /// assert_eq!(Date::from_ym(2020, 12).unwrap().quarter(), 4);
+    /// assert_eq!(Date::from_y(2020).unwrap().quarter(), 0);
+    /// ```
+    pub const fn quarter(&self) -> u8 {
+        if self.ok_month() {
+            (self.month() + 2) / 3
+        } else {
+            0
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the half of the year (1-2)
+    ///
+    /// `0` is returned if the month is missing.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Date::from_ym(2020, 6).unwrap().half(), 1);
+    /// assert_eq!(Date::from_ym(2020, 7).unwrap().half(), 2);
+    /// assert_eq!(Date::from_y(2020).unwrap().half(), 0);
+    /// ```
+    pub const fn half(&self) -> u8 {
+        if self.ok_month() {
+            (self.month() + 5) / 6
+        } else {
+            0
+        }
+    }
+
     #[inline]
     #[must_use]
     /// Returns `true` if the inner year is valid.
@@ -557,6 +652,87 @@ impl Date {
         }
     }
 
+    #[inline]
+    #[must_use]
+    /// If this is a business day
+    ///
+    /// Business days are full dates that are not on the weekend
+    /// (Saturday and Sunday) and not marked by `is_holiday`.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let christmas = |date: &Date| date.month() == 12 && date.day() == 25;
+    ///
+    /// // Friday.
+    /// assert!(Date::from_ymd(2020, 12, 18).unwrap().is_business_day(&christmas));
+    /// // Friday, but Christmas.
+    /// assert!(!Date::from_ymd(2020, 12, 25).unwrap().is_business_day(&christmas));
+    /// // Saturday.
+    /// assert!(!Date::from_ymd(2020, 12, 26).unwrap().is_business_day(&christmas));
+    /// // Not a full date.
+    /// assert!(!Date::from_ym(2020, 12).unwrap().is_business_day(&christmas));
+    /// ```
+    pub fn is_business_day<F>(&self, is_holiday: &F) -> bool
+    where
+        F: Fn(&Self) -> bool,
+    {
+        self.weekday().is_some() && !self.is_weekend(WeekStart::Monday) && !is_holiday(self)
+    }
+
+    #[must_use]
+    /// Move the date by `n` business days, see [`Self::is_business_day`]
+    ///
+    /// A negative `n` moves backwards, `0` returns the date as is.
+    ///
+    /// [`Self::UNKNOWN`] is returned if the date is not a full date
+    /// or the result is not in-between `1000-9999`.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let christmas = |date: &Date| date.month() == 12 && date.day() == 25;
+    /// let no_holidays = |_: &Date| false;
+    ///
+    /// // Wednesday.
+    /// let date = Date::from_ymd(2020, 12, 23).unwrap();
+    ///
+    /// assert_eq!(date.add_business_days(1, &no_holidays), "2020-12-24");
+    /// // Friday is Christmas, and the weekend is skipped.
+    /// assert_eq!(date.add_business_days(2, &christmas), "2020-12-28");
+    /// assert_eq!(date.add_business_days(-3, &christmas), "2020-12-18");
+    /// assert_eq!(date.add_business_days(0, &christmas), date);
+    ///
+    /// // Saturday, the next business day is Monday.
+    /// let date = Date::from_ymd(2020, 12, 26).unwrap();
+    /// assert_eq!(date.add_business_days(1, &no_holidays), "2020-12-28");
+    ///
+    /// assert!(Date::from_ym(2020, 12).unwrap().add_business_days(1, &no_holidays).is_unknown());
+    /// assert!(Date::from_ymd(9999, 12, 31).unwrap().add_business_days(1, &no_holidays).is_unknown());
+    /// ```
+    pub fn add_business_days<F>(self, n: i32, is_holiday: &F) -> Self
+    where
+        F: Fn(&Self) -> bool,
+    {
+        const DAY: std::time::Duration = std::time::Duration::from_secs(86400);
+
+        if self.weekday().is_none() {
+            return Self::UNKNOWN;
+        }
+
+        let mut date = self;
+        let mut left = n.unsigned_abs();
+        while left > 0 {
+            date = if n > 0 { date + DAY } else { date - DAY };
+            if date.is_unknown() {
+                return Self::UNKNOWN;
+            }
+            if date.is_business_day(is_holiday) {
+                left -= 1;
+            }
+        }
+
+        date
+    }
+
     #[inline]
     /// Create a [`Self`] from a UNIX timestamp
     ///
@@ -604,6 +780,84 @@ impl Date {
         }
     }
 
+    /// Create a [`Self`] from every UNIX timestamp in `unix` and append them to `output`
+    ///
+    /// This uses [`Self::from_unix_silent`], so out of range dates are [`Self::UNKNOWN`].
+    ///
+    /// `output` is grown once for all of `unix`, which is cheaper than
+    /// creating and pushing one at a time, e.g. for a table column.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let unix = [0, 1698019200, 339618217000];
+    /// let mut output = Vec::new();
+    ///
+    /// Date::format_unix_slice(&unix, &mut output);
+    /// assert_eq!(output[0], "1970-01-01");
+    /// assert_eq!(output[1], "2023-10-23");
+    /// assert!(output[2].is_unknown());
+    /// ```
+    pub fn format_unix_slice(unix: &[u64], output: &mut Vec<Self>) {
+        output.reserve(unix.len());
+        output.extend(unix.iter().map(|u| Self::from_unix_silent(*u)));
+    }
+
+    #[cfg(feature = "rayon")]
+    /// Same as [`Self::format_unix_slice`], but formatted in parallel with `rayon`
+    ///
+    /// `output` is in the same order as `unix`.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let unix: Vec<u64> = (0..1000).map(|i| 1698019200 + i * 86400).collect();
+    /// let mut output = Vec::new();
+    ///
+    /// Date::par_format_unix_slice(&unix, &mut output);
+    /// assert_eq!(output[0], "2023-10-23");
+    /// assert_eq!(output[999], "2026-07-18");
+    /// ```
+    pub fn par_format_unix_slice(unix: &[u64], output: &mut Vec<Self>) {
+        use rayon::prelude::*;
+        output.par_extend(unix.par_iter().map(|u| Self::from_unix_silent(*u)));
+    }
+
+    #[inline]
+    /// Same as [`Self::from_ymd`] but returns the reason for failure
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// # use readable::Error;
+    /// assert_eq!(Date::try_new(2022, 12, 31).unwrap(), "2022-12-31");
+    /// assert_eq!(Date::try_new(999, 12, 31),  Err(Error::YearOutOfRange));
+    /// assert_eq!(Date::try_new(2022, 13, 31), Err(Error::MonthOutOfRange));
+    /// assert_eq!(Date::try_new(2022, 12, 32), Err(Error::DayOutOfRange));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`crate::Error::YearOutOfRange`] if the year is not in-between `1000-9999`
+    /// - [`crate::Error::MonthOutOfRange`] if the month is not in-between `1-12`
+    /// - [`crate::Error::DayOutOfRange`] if the day is not in-between `1-31`
+    pub fn try_new(year: u16, month: u8, day: u8) -> Result<Self, crate::Error> {
+        check(year, month, day)?;
+        Ok(Self::from_ymd_silent(year, month, day))
+    }
+
+    #[inline]
+    /// Same as [`Self::from_unix`] but returns the reason for failure
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// # use readable::Error;
+    /// assert!(Date::try_from_unix(1698019200).is_ok());
+    /// assert_eq!(Date::try_from_unix(339618217000), Err(Error::YearOutOfRange));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`crate::Error::YearOutOfRange`] if the date's year is not in-between `1000-9999`.
+    pub fn try_from_unix(unix_timestamp: u64) -> Result<Self, crate::Error> {
+        Self::from_unix(unix_timestamp).map_err(|_| crate::Error::YearOutOfRange)
+    }
+
     #[inline]
     #[must_use]
     #[allow(clippy::cast_possible_wrap)]
@@ -698,8 +952,21 @@ impl Date {
     /// attempt to extract as much as it can, which may lead to
     /// surprising results. Read [`Date`]'s documentation for more info.
     ///
-    /// # Panic
-    /// If the input to this function is not ASCII (or 1 byte per character), it may panic.
+    /// # Non-ASCII input
+    /// Any UTF-8 input is accepted without panicking.
+    ///
+    /// Each run of non-ASCII characters counts as a single separator,
+    /// so separators that are multi-byte (or made of multiple `char`'s,
+    /// e.g. an accented character) work like `-` does.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Date::from_str("2222Ꜳ12Ꜳ20").unwrap(), "2222-12-20");
+    /// assert_eq!(Date::from_str("2020年12月20日").unwrap(), "2020-12-20");
+    /// assert_eq!(Date::from_str("12\u{301}\u{301}20-2020").unwrap(), "2020-12-20");
+    ///
+    /// assert!(Date::from_str("２０２０-12-20").is_err());
+    /// ```
     ///
     /// # Example
     /// ```rust
@@ -734,10 +1001,6 @@ impl Date {
 
     #[inline]
     #[must_use]
-    // TODO/FIXME:
-    // This panics on `UTF-8` input, e.g `2222Ꜳ22Ꜳ20` since
-    // we are byte indexing, assuming each character is 1 byte.
-    //
     /// Same as [`Date::from_str`] but silently returns a [`Self::UNKNOWN`]
     /// on error that isn't wrapped in a [`Result::Err`].
     pub fn from_str_silent(string: &str) -> Self {
@@ -749,17 +1012,20 @@ impl Date {
     #[inline]
     #[allow(clippy::string_slice, clippy::else_if_without_else)]
     fn priv_from_str(s: &str) -> Result<Self, Self> {
+        // The parsing below indexes by bytes, so
+        // non-ASCII input is turned into ASCII first.
+        if !s.is_ascii() {
+            return Self::priv_from_str(&Self::priv_ascii_separators(s));
+        }
+
         let len = s.len();
 
-        // // If feature enabled, match on all possible
-        // // `YYYY-MM-DD` strings between `1900-2100`.
-        // #[cfg(feature = "inline_date")]
-        // if len == 10 {
-        // 	if let Some(date) = readable_inlined_date::inlined(string.as_bytes()) {
-        // 		let (y, m, d, bytes) = date;
-        // 		return Ok(Self((y, m, d), Buffer::from_unchecked(&bytes)));
-        // 	}
-        // }
+        // Fast path for `YYYY-MM-DD`, the same as the `YMMDD` regex below.
+        if len == 10 {
+            if let Some((y, m, d)) = Self::priv_ymmdd_bytes(s.as_bytes()) {
+                return Ok(Self::priv_ymd_num(y, m, d));
+            }
+        }
 
         // Return `YYYY`.
         if len == 4 {
@@ -1095,6 +1361,73 @@ impl Date {
         Self::priv_ymd_num(y, m, d)
     }
 
+    // Replace each run of non-ASCII `char`'s with a single `-`,
+    // e.g. `2222Ꜳ12Ꜳ20` -> `2222-12-20`.
+    #[allow(clippy::else_if_without_else)]
+    fn priv_ascii_separators(s: &str) -> String {
+        let mut ascii = String::with_capacity(s.len());
+        let mut last_ascii = true;
+        for c in s.chars() {
+            if c.is_ascii() {
+                ascii.push(c);
+                last_ascii = true;
+            } else if last_ascii {
+                ascii.push('-');
+                last_ascii = false;
+            }
+        }
+        ascii
+    }
+
+    #[inline]
+    // Parse `YYYY?MM?DD` without the regexes, for any year in `1000-9999`.
+    //
+    // This matches exactly what `YMMDD` does:
+    // the separators can be any non-digit byte.
+    const fn priv_ymmdd_bytes(b: &[u8]) -> Option<(u16, u8, u8)> {
+        const fn digit(b: u8) -> Option<u16> {
+            if b.is_ascii_digit() {
+                Some((b - b'0') as u16)
+            } else {
+                None
+            }
+        }
+
+        let [y0, y1, y2, y3, s0, m0, m1, s1, d0, d1] = match b {
+            [y0, y1, y2, y3, s0, m0, m1, s1, d0, d1] => {
+                [*y0, *y1, *y2, *y3, *s0, *m0, *m1, *s1, *d0, *d1]
+            }
+            _ => return None,
+        };
+
+        if s0.is_ascii_digit() || s1.is_ascii_digit() {
+            return None;
+        }
+
+        let (Some(y0), Some(y1), Some(y2), Some(y3), Some(m0), Some(m1), Some(d0), Some(d1)) = (
+            digit(y0),
+            digit(y1),
+            digit(y2),
+            digit(y3),
+            digit(m0),
+            digit(m1),
+            digit(d0),
+            digit(d1),
+        ) else {
+            return None;
+        };
+
+        let y = y0 * 1000 + y1 * 100 + y2 * 10 + y3;
+        let m = (m0 * 10 + m1) as u8;
+        let d = (d0 * 10 + d1) as u8;
+
+        if ok(y, m, d) {
+            Some((y, m, d))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn priv_y_num(y: u16) -> Self {
         let mut buf = [0_u8; Self::MAX_LEN];
@@ -1302,6 +1635,31 @@ impl From<crate::date::NichiFull> for Date {
     }
 }
 
+//---------------------------------------------------------------------------------------------------- SQL
+// Stored as `TEXT`, the same string as `Date::as_str()`, e.g. `2020-12-25`.
+//
+// Partial dates (`2020-12`) can be stored this way, the
+// `chrono`/`time` conversions are for actual `DATE` columns.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+impl Date {
+    #[inline]
+    // Anything `Self::from_str()` can't parse is `Self::UNKNOWN`.
+    fn priv_from_sql(s: &str) -> Self {
+        Self::from_str_silent(s)
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_wraps)]
+    const fn priv_to_sql(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
+
+#[cfg(feature = "sqlx")]
+crate::macros::impl_sqlx!(Date => str);
+#[cfg(feature = "diesel")]
+crate::macros::impl_diesel!(Date => str as Text);
+
 //---------------------------------------------------------------------------------------------------- TESTS
 #[cfg(test)]
 mod tests {
@@ -1309,10 +1667,82 @@ mod tests {
     use compact_str::format_compact;
     use std::cmp::Ordering;
 
+    #[test]
+    #[cfg(feature = "clap")]
+    fn clap() {
+        let command = clap::Command::new("test").arg(
+            clap::Arg::new("arg")
+                .long("arg")
+                .value_parser(clap::value_parser!(Date)),
+        );
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["test", "--arg", "2020-12-25"])
+            .unwrap();
+        assert_eq!(*matches.get_one::<Date>("arg").unwrap(), (2020, 12, 25));
+
+        let error = command
+            .try_get_matches_from(["test", "--arg", "not a date"])
+            .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(error.to_string().contains("expected a date"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "sqlx", feature = "diesel"))]
+    fn sql() {
+        assert_eq!(Date::priv_from_sql("2020-12-25"), "2020-12-25");
+        assert_eq!(Date::priv_from_sql("2020-12"), "2020-12");
+        assert!(Date::priv_from_sql("not a date").is_unknown());
+
+        let date = Date::from_ymd(2020, 12, 25).unwrap();
+        assert_eq!(date.priv_to_sql(), Some("2020-12-25"));
+        assert_eq!(Date::priv_from_sql(date.priv_to_sql().unwrap()), date);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Date::from_ym(2020, 12).unwrap()),
+            r#"Date { inner: (2020, 12, 0), str: "2020-12" }"#
+        );
+    }
+
     //-------------------------------------------------------------------------------- Date tests.
     const EXPECTED: (u16, u8, u8) = (2020, 12, 25);
     const EXPECTED_STR: &str = "2020-12-25";
 
+    #[test]
+    fn ymmdd_fast_path() {
+        for (input, expected) in [
+            ("1850-06-15", Some((1850, 6, 15))),
+            ("1000/01/01", Some((1000, 1, 1))),
+            ("9999.12.31", Some((9999, 12, 31))),
+            ("2024 02 29", Some((2024, 2, 29))),
+            ("0999-01-01", None),
+            ("2024-13-01", None),
+            ("2024-00-01", None),
+            ("2024-01-32", None),
+            ("2024-01-00", None),
+            ("2024101-01", None),
+            ("12-25-2020", None),
+        ] {
+            let fast = Date::priv_ymmdd_bytes(input.as_bytes());
+            assert_eq!(fast, expected, "{input}");
+
+            // The regexes agree.
+            if fast.is_some() {
+                assert!(YMMDD.is_match(input), "{input}");
+                assert_eq!(Date::from_str(input).unwrap().inner(), fast.unwrap());
+            }
+        }
+
+        // Not `YYYY-MM-DD`, so the regexes are still used.
+        assert_eq!(Date::from_str("12-25-2020").unwrap(), "2020-12-25");
+        assert_eq!(Date::from_str("2024-13-01").unwrap(), "2024-01");
+    }
+
     #[test]
     fn cmp() {
         let a = Date::from_str("2020-12-01").unwrap();
@@ -1435,6 +1865,58 @@ mod tests {
         assert_eq!(Date::from_str("2020_12_25").unwrap(), EXPECTED_STR);
     }
 
+    #[test]
+    fn from_str_non_ascii() {
+        assert_eq!(Date::from_str("2020Ꜳ12Ꜳ25").unwrap(), EXPECTED);
+        assert_eq!(Date::from_str("12😀25😀2020").unwrap(), EXPECTED);
+        assert_eq!(Date::from_str("25日12月2020").unwrap(), EXPECTED);
+        assert_eq!(Date::from_str("2020\u{301}\u{301}12-25").unwrap(), EXPECTED);
+        assert_eq!(Date::from_str_silent("2222Ꜳ22Ꜳ20"), (2222, 2, 0));
+        assert_eq!(Date::from_str_silent("Ꜳ"), Date::UNKNOWN);
+        assert_eq!(Date::from_str_silent("２０２０"), Date::UNKNOWN);
+    }
+
+    #[test]
+    fn from_str_non_ascii_no_panic() {
+        // Every input shape, with multi-byte `char`'s in every position.
+        let shapes = [
+            "2020-12-25",
+            "12-25-2020",
+            "25-12-2020",
+            "20201225",
+            "2020-1-5",
+            "2020-12",
+            "2020-1",
+            "2020",
+            "202012",
+            "12025",
+        ];
+        let chars = ['é', '\u{301}', 'Ꜳ', '日', '😀', '٣', '２', '\u{200d}'];
+
+        for shape in shapes {
+            for c in chars {
+                for i in 0..=shape.len() {
+                    let mut replaced = String::from(shape);
+                    replaced.replace_range(i..(i + 1).min(shape.len()), c.encode_utf8(&mut [0; 4]));
+                    let mut inserted = String::from(shape);
+                    inserted.insert(i, c);
+
+                    for s in [replaced, inserted] {
+                        let date = Date::from_str_silent(&s);
+                        assert!(date.len() <= Date::MAX_LEN, "{s}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("2020-12-25".parse::<Date>().unwrap(), EXPECTED);
+        assert_eq!("".parse::<Date>(), Err(crate::Error::Parse));
+        assert_eq!("hello".parse::<Date>(), Err(crate::Error::Parse));
+    }
+
     #[test]
     fn from_str_mdy() {
         assert_eq!(Date::from_str("12-25-2020").unwrap(), EXPECTED);
@@ -1461,6 +1943,33 @@ mod tests {
         assert_eq!(Date::from_str("25_12_2020").unwrap(), EXPECTED);
     }
 
+    #[test]
+    fn business_days() {
+        let no_holidays = |_: &Date| false;
+        let new_year = |date: &Date| date.month() == 1 && date.day() == 1;
+
+        // 2024 has 262 weekdays, 2023-12-31 is a Sunday.
+        let date = Date::from_ymd(2023, 12, 31).unwrap();
+        assert_eq!(date.add_business_days(262, &no_holidays), "2024-12-31");
+        assert_eq!(date.add_business_days(261, &new_year), "2024-12-31");
+        assert_eq!(date.add_business_days(-1, &no_holidays), "2023-12-29");
+
+        // Forwards and back from a business day is the same day.
+        let date = Date::from_ymd(2024, 1, 2).unwrap();
+        for n in 0..100 {
+            let moved = date.add_business_days(n, &new_year);
+            assert!(moved.is_business_day(&new_year));
+            assert_eq!(moved.add_business_days(-n, &new_year), date);
+        }
+
+        // Every day is a holiday.
+        assert!(date.add_business_days(1, &|_: &Date| true).is_unknown());
+        assert!(date.add_business_days(-1, &|_: &Date| true).is_unknown());
+        assert!(Date::UNKNOWN
+            .add_business_days(0, &no_holidays)
+            .is_unknown());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
@@ -1481,6 +1990,22 @@ mod tests {
         assert!(serde_json::from_str::<Date>(&json).unwrap().is_unknown());
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn postcard() {
+        // `postcard` does not allocate.
+        let mut buf = [0_u8; 64];
+        let bytes = postcard::to_slice(&Date::try_from((2024, 1, 1)).unwrap(), &mut buf).unwrap();
+
+        let this: Date = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, (2024, 1, 1));
+        assert_eq!(this, "2024-01-01");
+
+        // Unknown.
+        let bytes = postcard::to_slice(&Date::UNKNOWN, &mut buf).unwrap();
+        assert!(postcard::from_bytes::<Date>(bytes).unwrap().is_unknown());
+    }
+
     #[test]
     #[cfg(feature = "bincode")]
     fn bincode() {
@@ -0,0 +1,131 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+use std::ops::{Add, Sub};
+use std::time::Duration;
+
+//---------------------------------------------------------------------------------------------------- Days
+// The whole days in a `Duration`.
+fn days(duration: Duration) -> i128 {
+    i128::from(duration.as_secs() / 86400)
+}
+
+// Move `(year, month, day)` by `days`, returns `None`
+// if the result is not in-between `1000-9999`.
+fn add_days((y, m, d): (u16, u8, u8), days: i128) -> Option<(u16, u8, u8)> {
+    #[allow(clippy::cast_possible_wrap)]
+    let unix = nichi::Date::new(y as i16, m, d).as_unix();
+    let unix = unix.checked_add(days.checked_mul(86400)?)?;
+
+    // `nichi` wraps years outside of `i16`, so bail
+    // early if the year is nowhere near `1000-9999`.
+    if !(-400_000_000_000..=400_000_000_000).contains(&unix) {
+        return None;
+    }
+
+    // Noon, `nichi` returns the day before for midnights before `1970`.
+    let (y, m, d) = nichi::Date::from_unix(unix + 43200).inner();
+    Some((u16::try_from(y).ok()?, m, d))
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+// Implements `Add<Duration>` and `Sub<Duration>` for the date types.
+//
+// These are day-granular, only the whole days of the `Duration` are used.
+macro_rules! impl_date {
+	($($s:ty),* $(,)?) => { $(
+		impl Add<Duration> for $s {
+			type Output = Self;
+			#[inline]
+			/// Move the date forwards by the whole days in the [`Duration`].
+			///
+			/// [`Self::UNKNOWN`] is returned if the date is not a full date
+			/// or the result is not in-between `1000-9999`.
+			fn add(self, duration: Duration) -> Self {
+				self.priv_add_days(days(duration))
+			}
+		}
+
+		impl Sub<Duration> for $s {
+			type Output = Self;
+			#[inline]
+			/// Move the date backwards by the whole days in the [`Duration`].
+			///
+			/// [`Self::UNKNOWN`] is returned if the date is not a full date
+			/// or the result is not in-between `1000-9999`.
+			fn sub(self, duration: Duration) -> Self {
+				self.priv_add_days(-days(duration))
+			}
+		}
+
+		impl $s {
+			fn priv_add_days(self, days: i128) -> Self {
+				let (y, m, d) = self.inner();
+				if !crate::date::free::ok(y, m, d) {
+					return Self::UNKNOWN;
+				}
+				match add_days((y, m, d), days) {
+					Some(ymd) => Self::try_from(ymd).unwrap_or(Self::UNKNOWN),
+					None => Self::UNKNOWN,
+				}
+			}
+		}
+	)*}
+}
+impl_date!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(86400);
+
+    #[test]
+    fn days() {
+        let date = Date::from_ymd(2024, 2, 28).unwrap();
+        assert_eq!(date + DAY, "2024-02-29");
+        assert_eq!(date + DAY * 2, "2024-03-01");
+        assert_eq!(date - DAY * 59, "2023-12-31");
+        assert_eq!(date + DAY * 366, "2025-02-28");
+
+        // Less than a day.
+        assert_eq!(date + Duration::from_secs(86399), date);
+        assert_eq!(date - Duration::from_secs(86399), date);
+
+        // Before `1970`.
+        let date = Date::from_ymd(1969, 12, 31).unwrap();
+        assert_eq!(date + Duration::ZERO, date);
+        assert_eq!(date + DAY, "1970-01-01");
+        assert_eq!(date - DAY, "1969-12-30");
+        assert_eq!(Date::from_ymd(1000, 1, 1).unwrap() + DAY, "1000-01-02");
+
+        let nichi = Nichi::new(2020, 12, 25).unwrap();
+        assert_eq!(nichi + DAY, "Sat, Dec 26, 2020");
+        let full = NichiFull::new(2020, 12, 25).unwrap();
+        assert_eq!(full - DAY * 25, "Monday, November 30th, 2020");
+    }
+
+    #[test]
+    fn round_trip() {
+        let date = Date::from_ymd(1000, 1, 1).unwrap();
+        for days in (0..3_000_000).step_by(997) {
+            let d = DAY * days;
+            let added = date + d;
+            assert!(!added.is_unknown(), "{days}");
+            assert_eq!(added - d, date);
+            assert_eq!(Nichi::from(date) + d, Nichi::from(added));
+        }
+        assert_eq!(date + DAY * 2_000_000, "6475-10-25");
+    }
+
+    #[test]
+    fn unknown() {
+        assert!((Date::from_ymd(9999, 12, 31).unwrap() + DAY).is_unknown());
+        assert!((Date::from_ymd(1000, 1, 1).unwrap() - DAY).is_unknown());
+        assert!((Date::from_ym(2020, 12).unwrap() + DAY).is_unknown());
+        assert!((Date::UNKNOWN + DAY).is_unknown());
+        assert!((Nichi::UNKNOWN - DAY).is_unknown());
+        assert!((NichiFull::new(2020, 1, 1).unwrap() + Duration::MAX).is_unknown());
+        assert!((NichiFull::new(2020, 1, 1).unwrap() - Duration::MAX).is_unknown());
+    }
+}
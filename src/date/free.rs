@@ -1,28 +1,180 @@
 //---------------------------------------------------------------------------------------------------- Ok
 #[inline]
+#[must_use]
 /// If `year` is in-between `1000..=9999`
-pub(crate) const fn ok_year(year: u16) -> bool {
+///
+/// This is the same year check the `date` types use.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert!(ok_year(1000));
+/// assert!(ok_year(9999));
+/// assert!(!ok_year(999));
+/// assert!(!ok_year(10000));
+/// ```
+pub const fn ok_year(year: u16) -> bool {
     year >= 1000 && year <= 9999
 }
 
 #[inline]
+#[must_use]
 /// If `month` is in-between `1..=12`
-pub(crate) const fn ok_month(month: u8) -> bool {
+///
+/// This is the same month check the `date` types use.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert!(ok_month(1));
+/// assert!(ok_month(12));
+/// assert!(!ok_month(0));
+/// assert!(!ok_month(13));
+/// ```
+pub const fn ok_month(month: u8) -> bool {
     month >= 1 && month <= 12
 }
 
 #[inline]
+#[must_use]
 /// If `day` is in-between `1..=31`
-pub(crate) const fn ok_day(day: u8) -> bool {
+///
+/// This is the same day check the `date` types use,
+/// note that it does not depend on the month, see
+/// [`days_in_month`] for the real amount of days.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert!(ok_day(1));
+/// assert!(ok_day(31));
+/// assert!(!ok_day(0));
+/// assert!(!ok_day(32));
+/// ```
+pub const fn ok_day(day: u8) -> bool {
     day >= 1 && day <= 31
 }
 
+#[inline]
+#[must_use]
+/// If `year` is a leap year in the Gregorian calendar
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert!(is_leap_year(2024));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(2023));
+/// assert!(!is_leap_year(1900));
+/// ```
+pub const fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+#[inline]
+#[must_use]
+/// Get the amount of days in `month` of `year`
+///
+/// This returns `0` if `month` is not in-between `1..=12`.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(days_in_month(2023, 1),  31);
+/// assert_eq!(days_in_month(2023, 2),  28);
+/// assert_eq!(days_in_month(2024, 2),  29);
+/// assert_eq!(days_in_month(2024, 4),  30);
+/// assert_eq!(days_in_month(2024, 13), 0);
+/// ```
+pub const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
 #[inline]
 /// If `ok_year`, `ok_month`, and `ok_day` are all okay
 pub(crate) const fn ok(year: u16, month: u8, day: u8) -> bool {
     ok_year(year) && ok_month(month) && ok_day(day)
 }
 
+#[inline]
+/// Same as `ok` but returns which part failed
+pub(crate) const fn check(year: u16, month: u8, day: u8) -> Result<(), crate::Error> {
+    if !ok_year(year) {
+        Err(crate::Error::YearOutOfRange)
+    } else if !ok_month(month) {
+        Err(crate::Error::MonthOutOfRange)
+    } else if !ok_day(day) {
+        Err(crate::Error::DayOutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+/// If the weekday that `s` starts with (if any) is the weekday of `year-month-day`
+///
+/// Both the full name and the 3 letter abbreviation are checked, case-insensitively,
+/// e.g. the `Fri` in `Fri, Dec 25, 2020`. Anything else, e.g. `Dec 25, 2020`, is okay.
+///
+/// The inputs must already be a valid date.
+pub(crate) fn ok_weekday(s: &str, year: u16, month: u8, day: u8) -> bool {
+    let s = s.trim_start();
+    let word = s
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+
+    #[allow(clippy::cast_possible_wrap)]
+    let weekday = nichi::Date::new(year as i16, month, day).weekday();
+
+    let is_weekday = |w: nichi::Weekday| {
+        word.eq_ignore_ascii_case(w.as_str()) || word.eq_ignore_ascii_case(w.as_str_short())
+    };
+
+    !nichi::Weekday::ALL.into_iter().any(is_weekday) || is_weekday(weekday)
+}
+
+//---------------------------------------------------------------------------------------------------- Fixed
+// "Fixed" day numbers (Rata Die) are the days since `0000-12-31`, i.e. `0001-01-01` is `1`.
+// The other calendars (`DateHijri`, `DateHebrew`) convert to and from these.
+//
+// <https://en.wikipedia.org/wiki/Rata_Die>
+
+#[cfg(feature = "calendar")]
+/// The fixed day number of `1970-01-01`.
+const FIXED_UNIX_EPOCH: i64 = 719_163;
+
+#[cfg(feature = "calendar")]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+/// Gregorian `(year, month, day)` to its fixed day number.
+///
+/// The inputs must already be a valid date.
+pub(crate) const fn fixed_from_ymd(year: u16, month: u8, day: u8) -> i64 {
+    let unix = nichi::Date::new(year as i16, month, day).as_unix();
+    (unix / 86400) as i64 + FIXED_UNIX_EPOCH
+}
+
+#[cfg(feature = "calendar")]
+/// Fixed day number to Gregorian `(year, month, day)`, [`None`] if the year is not in-between `1000-9999`.
+pub(crate) const fn ymd_from_fixed(fixed: i64) -> Option<(u16, u8, u8)> {
+    // Anything outside of this is nowhere near `1000-9999`,
+    // and `nichi` wraps years outside of `i16`.
+    if fixed < 300_000 || fixed > 4_000_000 {
+        return None;
+    }
+
+    // Noon, `nichi` returns the day before for midnights before `1970`.
+    let unix = (fixed - FIXED_UNIX_EPOCH) as i128 * 86400 + 43200;
+    let (y, m, d) = nichi::Date::from_unix(unix).inner();
+    #[allow(clippy::cast_sign_loss)]
+    let y = y as u16;
+    if ok_year(y) {
+        Some((y, m, d))
+    } else {
+        None
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- Date
 #[inline]
 #[must_use]
@@ -44,3 +196,24 @@ pub fn date_utc() -> (i16, u8, u8) {
     let unix = i128::from(chrono::offset::Local::now().timestamp());
     nichi::Date::from_unix(unix).inner()
 }
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_in_month() {
+        for year in 1000..=9999 {
+            let days: u32 = (1..=12)
+                .map(|m| u32::from(super::days_in_month(year, m)))
+                .sum();
+            let expected = if is_leap_year(year) { 366 } else { 365 };
+            assert_eq!(days, expected);
+
+            let chrono = chrono::NaiveDate::from_ymd_opt(i32::from(year), 2, 29).is_some();
+            assert_eq!(is_leap_year(year), chrono);
+        }
+        assert_eq!(super::days_in_month(2024, 0), 0);
+    }
+}
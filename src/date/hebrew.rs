@@ -0,0 +1,525 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::free::{days_in_month, fixed_from_ymd, ok, ymd_from_fixed};
+#[allow(unused_imports)]
+use crate::date::Date;
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str; // docs
+
+//---------------------------------------------------------------------------------------------------- `DateHebrew`
+/// A date in the Hebrew calendar, e.g. `17 Nisan 5784`
+///
+/// This is the arithmetic (rabbinic) Hebrew calendar, the same as [ICU](https://icu.unicode.org)'s `hebrew`.
+///
+/// The inner `(year, month, day)` are the Hebrew ones, months are counted from `Nisan`:
+///
+/// | Month | Name                               |
+/// |-------|------------------------------------|
+/// | 1     | `Nisan`                            |
+/// | 2     | `Iyar`                             |
+/// | 3     | `Sivan`                            |
+/// | 4     | `Tamuz`                            |
+/// | 5     | `Av`                               |
+/// | 6     | `Elul`                             |
+/// | 7     | `Tishri` (the year starts here)    |
+/// | 8     | `Heshvan`                          |
+/// | 9     | `Kislev`                           |
+/// | 10    | `Tevet`                            |
+/// | 11    | `Shevat`                           |
+/// | 12    | `Adar` (`Adar I` in leap years)    |
+/// | 13    | `Adar II` (only in leap years)     |
+///
+/// ```rust
+/// # use readable::date::*;
+/// let hebrew = DateHebrew::from(Date::from_ymd(2024, 4, 25).unwrap());
+/// assert_eq!(hebrew, "17 Nisan 5784");
+/// assert_eq!(hebrew, (5784, 1, 17));
+///
+/// // And back.
+/// assert_eq!(Date::from(hebrew), "2024-04-25");
+/// assert_eq!(DateHebrew::new(5784, 1, 17).unwrap(), hebrew);
+/// ```
+///
+/// ## Size
+/// [`Str<16>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<DateHebrew>(), 22);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(DateHebrew::new(1, 7, 1), Err(DateHebrew::UNKNOWN)); // before the year `1000`
+/// assert_eq!(DateHebrew::new(5785, 7, 1).unwrap(),  "1 Tishri 5785");
+/// assert_eq!(DateHebrew::new(5784, 12, 14).unwrap(), "14 Adar I 5784");
+/// assert_eq!(DateHebrew::new(5784, 13, 14).unwrap(), "14 Adar II 5784");
+/// assert_eq!(DateHebrew::new(5785, 12, 14).unwrap(), "14 Adar 5785");
+///
+/// // Not a leap year.
+/// assert!(DateHebrew::new(5785, 13, 14).is_err());
+///
+/// assert_eq!(Date::from(DateHebrew::new(5785, 7, 1).unwrap()), "2024-10-03");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct DateHebrew((u16, u8, u8), Str<{ DateHebrew::MAX_LEN }>);
+
+impl_traits!(DateHebrew, (u16, u8, u8));
+
+//---------------------------------------------------------------------------------------------------- DateHebrew Constants
+impl DateHebrew {
+    /// The maximum string length of a [`DateHebrew`].
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHebrew::new(13760, 8, 28).unwrap().len(), DateHebrew::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 16;
+
+    /// Returns a [`Self`] with the date values set to `(0, 0, 0)`
+    ///
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHebrew::ZERO, (0, 0, 0));
+    /// assert_eq!(DateHebrew::ZERO, "???");
+    /// assert_eq!(DateHebrew::ZERO, DateHebrew::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned when using [`DateHebrew::UNKNOWN`] or error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHebrew::UNKNOWN, (0, 0, 0));
+    /// assert_eq!(DateHebrew::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self((0, 0, 0), Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- DateHebrew impl
+impl DateHebrew {
+    impl_common!((u16, u8, u8));
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hebrew year
+    pub const fn year(&self) -> u16 {
+        self.0 .0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hebrew month (1-13), `Nisan` is `1`
+    pub const fn month(&self) -> u8 {
+        self.0 .1
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hebrew day (1-30)
+    pub const fn day(&self) -> u8 {
+        self.0 .2
+    }
+
+    #[inline]
+    /// Parse [`u16`], [`u8`], [`u8`] for a Hebrew year, month and day.
+    ///
+    /// ## Errors
+    /// - The month must be in-between `1-12`, or `1-13` in leap years
+    /// - The day must be in-between `1-29` or `1-30` (depending on the month and year)
+    /// - The date must be in-between the Gregorian years `1000-9999`
+    ///
+    /// If an [`Err`] is returned, it will contain a [`DateHebrew`] set with [`Self::UNKNOWN`].
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, Self> {
+        // Anything outside of this is not in-between `1000-9999`,
+        // and it keeps the calculations away from year `0`.
+        if !(4000..=14000).contains(&year)
+            || month == 0
+            || month > hebrew_last_month(year)
+            || day == 0
+            || day > hebrew_days_in_month(year, month)
+        {
+            return Err(Self::UNKNOWN);
+        }
+
+        match ymd_from_fixed(fixed_from_hebrew(year, month, day)) {
+            Some(_) => Ok(Self::priv_from(year, month, day)),
+            None => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::new`] but silently errors
+    ///
+    /// [`Self::UNKNOWN`] will be returned silently if an error occurs.
+    pub fn new_silent(year: u16, month: u8, day: u8) -> Self {
+        match Self::new(year, month, day) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the name of the inner month, e.g. `Nisan`
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHebrew::new(5784, 1, 17).unwrap().month_as_str(), "Nisan");
+    /// assert_eq!(DateHebrew::new(5784, 12, 1).unwrap().month_as_str(), "Adar I");
+    /// assert_eq!(DateHebrew::new(5785, 12, 1).unwrap().month_as_str(), "Adar");
+    /// assert_eq!(DateHebrew::UNKNOWN.month_as_str(), "???");
+    /// ```
+    pub const fn month_as_str(&self) -> &'static str {
+        hebrew_month_str(self.year(), self.month())
+    }
+
+    #[inline]
+    #[must_use]
+    /// If the inner year is a leap year (it has `Adar II`)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(DateHebrew::new(5784, 1, 1).unwrap().is_leap_year());
+    /// assert!(!DateHebrew::new(5785, 1, 1).unwrap().is_leap_year());
+    /// ```
+    pub const fn is_leap_year(&self) -> bool {
+        hebrew_is_leap_year(self.year())
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(DateHebrew::UNKNOWN.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- DateHebrew impl (private)
+// These are all from Calendrical Calculations (Reingold & Dershowitz).
+
+/// The fixed day number of `1 Tishri 1` (`-3761-10-07` Julian).
+const EPOCH: i64 = -1_373_427;
+
+/// If the Hebrew `year` has 13 months.
+const fn hebrew_is_leap_year(year: u16) -> bool {
+    (7 * year as u32 + 1) % 19 < 7
+}
+
+/// `Adar` or `Adar II`.
+const fn hebrew_last_month(year: u16) -> u8 {
+    if hebrew_is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+const fn hebrew_month_str(year: u16, month: u8) -> &'static str {
+    match month {
+        1 => "Nisan",
+        2 => "Iyar",
+        3 => "Sivan",
+        4 => "Tamuz",
+        5 => "Av",
+        6 => "Elul",
+        7 => "Tishri",
+        8 => "Heshvan",
+        9 => "Kislev",
+        10 => "Tevet",
+        11 => "Shevat",
+        12 if hebrew_is_leap_year(year) => "Adar I",
+        12 => "Adar",
+        13 => "Adar II",
+        _ => "???",
+    }
+}
+
+// `hebrew-calendar-elapsed-days`, the days from the epoch to the molad of Tishri (with the first delay).
+const fn elapsed_days(year: u16) -> i64 {
+    let months = (235 * year as i64 - 234) / 19;
+    let parts = 12084 + 13753 * months;
+    let days = 29 * months + parts / 25920;
+    if (3 * (days + 1)) % 7 < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+// `hebrew-new-year`, the fixed day number of `1 Tishri` (with the year length delays).
+const fn new_year(year: u16) -> i64 {
+    let (ny0, ny1, ny2) = (
+        elapsed_days(year - 1),
+        elapsed_days(year),
+        elapsed_days(year + 1),
+    );
+
+    let delay = if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    };
+
+    EPOCH + ny1 + delay
+}
+
+const fn days_in_year(year: u16) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+const fn hebrew_days_in_month(year: u16, month: u8) -> u8 {
+    let short = match month {
+        2 | 4 | 6 | 10 | 13 => true,
+        12 => !hebrew_is_leap_year(year),
+        8 => !matches!(days_in_year(year), 355 | 385),
+        9 => matches!(days_in_year(year), 353 | 383),
+        _ => false,
+    };
+
+    if short {
+        29
+    } else {
+        30
+    }
+}
+
+// `fixed-from-hebrew`.
+const fn fixed_from_hebrew(year: u16, month: u8, day: u8) -> i64 {
+    let mut fixed = new_year(year) + day as i64 - 1;
+
+    // The year starts at `Tishri` (7), so the months before
+    // `month` are either `7..month`, or `7..=last` and `1..month`.
+    let mut m = 7;
+    if month < 7 {
+        let last = hebrew_last_month(year);
+        while m <= last {
+            fixed += hebrew_days_in_month(year, m) as i64;
+            m += 1;
+        }
+        m = 1;
+    }
+    while m < month {
+        fixed += hebrew_days_in_month(year, m) as i64;
+        m += 1;
+    }
+
+    fixed
+}
+
+// `hebrew-from-fixed`.
+//
+// INVARIANT: `fixed` must be in-between the Gregorian years `1000-9999`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn hebrew_from_fixed(fixed: i64) -> (u16, u8, u8) {
+    // The last year starting on or before `fixed`, searching from just before the estimate.
+    let mut year = ((98_496 * (fixed - EPOCH)) / 35_975_351) as u16;
+    while new_year(year + 1) <= fixed {
+        year += 1;
+    }
+
+    let mut month = if fixed < fixed_from_hebrew(year, 1, 1) {
+        7
+    } else {
+        1
+    };
+    while fixed > fixed_from_hebrew(year, month, hebrew_days_in_month(year, month)) {
+        month += 1;
+    }
+
+    let day = (fixed - fixed_from_hebrew(year, month, 1) + 1) as u8;
+    (year, month, day)
+}
+
+impl DateHebrew {
+    // INVARIANT: inputs must be a valid Hebrew date.
+    fn priv_from(y: u16, m: u8, d: u8) -> Self {
+        let mut itoa = crate::toa::Itoa64::new();
+        let mut string = Str::new();
+
+        // 17 Nisan 5784
+        string.push_str_panic(itoa.format_str(d));
+        string.push_str_panic(" ");
+        string.push_str_panic(hebrew_month_str(y, m));
+        string.push_str_panic(" ");
+        string.push_str_panic(itoa.format_str(y));
+
+        Self((y, m, d), string)
+    }
+
+    // Gregorian `(year, month, day)` to `Self`.
+    fn priv_from_ymd((y, m, d): (u16, u8, u8)) -> Self {
+        if ok(y, m, d) && d <= days_in_month(y, m) {
+            let (y, m, d) = hebrew_from_fixed(fixed_from_ymd(y, m, d));
+            Self::priv_from(y, m, d)
+        } else {
+            Self::UNKNOWN
+        }
+    }
+
+    // `Self` to Gregorian `(year, month, day)`.
+    const fn priv_to_ymd(self) -> Option<(u16, u8, u8)> {
+        if self.is_unknown() {
+            None
+        } else {
+            ymd_from_fixed(fixed_from_hebrew(self.year(), self.month(), self.day()))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl TryFrom<(u16, u8, u8)> for DateHebrew {
+    type Error = Self;
+    #[inline]
+    // Calls [`Self::new`].
+    fn try_from(value: (u16, u8, u8)) -> Result<Self, Self> {
+        Self::new(value.0, value.1, value.2)
+    }
+}
+
+// Implements `From` between `DateHebrew` and the Gregorian date types (both ways).
+macro_rules! impl_from {
+	($($other:ty),* $(,)?) => { $(
+		impl From<$other> for DateHebrew {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the date is not a full, valid date.
+			fn from(value: $other) -> Self {
+				Self::priv_from_ymd(value.inner())
+			}
+		}
+
+		impl From<DateHebrew> for $other {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the [`DateHebrew`] is unknown.
+			fn from(value: DateHebrew) -> Self {
+				match value.priv_to_ymd() {
+					Some(ymd) => Self::try_from(ymd).unwrap_or(Self::UNKNOWN),
+					None => Self::UNKNOWN,
+				}
+			}
+		}
+	)*}
+}
+impl_from!(
+    crate::date::Date,
+    crate::date::Nichi,
+    crate::date::NichiFull,
+    crate::date::NichiWareki,
+);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::Nichi;
+
+    #[test]
+    fn from_date() {
+        for (ymd, expected, hebrew) in [
+            ((1000, 1, 1), "16 Tevet 4760", (4760, 10, 16)),
+            ((1970, 1, 1), "23 Tevet 5730", (5730, 10, 23)),
+            ((2000, 2, 29), "23 Adar I 5760", (5760, 12, 23)),
+            ((2024, 3, 24), "14 Adar II 5784", (5784, 13, 14)),
+            ((2024, 4, 25), "17 Nisan 5784", (5784, 1, 17)),
+            ((2024, 10, 3), "1 Tishri 5785", (5785, 7, 1)),
+            ((9999, 12, 31), "28 Heshvan 13760", (13760, 8, 28)),
+        ] {
+            let date = Date::try_from(ymd).unwrap();
+            let this = DateHebrew::from(date);
+            assert_eq!(this, expected);
+            assert_eq!(this, hebrew);
+            assert_eq!(Date::from(this), date);
+            assert_eq!(DateHebrew::try_from(hebrew).unwrap(), this);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut prev = DateHebrew::from(Date::from_ymd(1000, 1, 1).unwrap());
+        let mut fixed = fixed_from_ymd(1000, 1, 1);
+        while let Some(ymd) = ymd_from_fixed(fixed + 1) {
+            fixed += 1;
+            let this = DateHebrew::from(Nichi::try_from(ymd).unwrap());
+            assert_eq!(
+                DateHebrew::new(this.year(), this.month(), this.day()),
+                Ok(this)
+            );
+            assert_eq!(Nichi::from(this), ymd);
+            assert_ne!(this, prev);
+            prev = this;
+        }
+        assert_eq!(prev, (13760, 8, 28));
+    }
+
+    #[test]
+    fn year_lengths() {
+        for year in 4760..=13760 {
+            assert!(matches!(days_in_year(year), 353..=355 | 383..=385));
+            // Rosh Hashanah is never on a Sunday, Wednesday or Friday.
+            let weekday = (new_year(year) % 7) as u8;
+            assert!(!matches!(weekday, 0 | 3 | 5), "{year}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(DateHebrew::new(5784, 0, 1).is_err());
+        assert!(DateHebrew::new(5784, 14, 1).is_err());
+        assert!(DateHebrew::new(5785, 13, 1).is_err());
+        assert!(DateHebrew::new(5784, 2, 30).is_err());
+        assert!(DateHebrew::new(4760, 10, 15).is_err());
+        assert!(DateHebrew::new(13760, 8, 29).is_err());
+        assert!(DateHebrew::new(u16::MAX, 1, 1).is_err());
+        assert!(DateHebrew::from(Date::from_ym(2024, 4).unwrap()).is_unknown());
+        assert!(DateHebrew::from(Date::from_ymd(2023, 2, 29).unwrap()).is_unknown());
+        assert!(Date::from(DateHebrew::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = DateHebrew::new(5784, 1, 17).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[5784,1,17],"17 Nisan 5784"]"#);
+
+        let this: DateHebrew = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (5784, 1, 17));
+        assert_eq!(this, "17 Nisan 5784");
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let this = DateHebrew::new(5784, 1, 17).unwrap();
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(&this, config).unwrap();
+
+        let this: DateHebrew = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, (5784, 1, 17));
+        assert_eq!(this, "17 Nisan 5784");
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this = DateHebrew::new(5784, 1, 17).unwrap();
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: DateHebrew = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, (5784, 1, 17));
+        assert_eq!(this, "17 Nisan 5784");
+    }
+}
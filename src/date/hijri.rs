@@ -0,0 +1,401 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::free::{days_in_month, fixed_from_ymd, ok, ymd_from_fixed};
+#[allow(unused_imports)]
+use crate::date::Date;
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str; // docs
+
+//---------------------------------------------------------------------------------------------------- `DateHijri`
+/// A date in the (tabular) Islamic calendar, e.g. `16 Shawwal 1445 AH`
+///
+/// This is the arithmetic "civil" Hijri calendar (epoch `622-07-16` Julian,
+/// leap years `2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29` of each 30 year cycle),
+/// the same as [ICU](https://icu.unicode.org)'s `islamic-civil`.
+///
+/// It can differ by a day or two from calendars based on moon sightings (e.g. Umm al-Qura).
+///
+/// The inner `(year, month, day)` are the Hijri ones:
+/// ```rust
+/// # use readable::date::*;
+/// let hijri = DateHijri::from(Date::from_ymd(2024, 4, 25).unwrap());
+/// assert_eq!(hijri, "16 Shawwal 1445 AH");
+/// assert_eq!(hijri, (1445, 10, 16));
+///
+/// // And back.
+/// assert_eq!(Date::from(hijri), "2024-04-25");
+/// assert_eq!(DateHijri::new(1445, 10, 16).unwrap(), hijri);
+/// ```
+///
+/// Month names are ASCII transliterations, e.g. `Rabi al-Awwal`, `Dhu al-Hijjah`.
+///
+/// ## Size
+/// [`Str<26>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<DateHijri>(), 32);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(DateHijri::new(1, 1, 1), Err(DateHijri::UNKNOWN)); // before the year `1000`
+/// assert_eq!(DateHijri::new(1445, 1, 1).unwrap(),   "1 Muharram 1445 AH");
+/// assert_eq!(DateHijri::new(1445, 9, 1).unwrap(),   "1 Ramadan 1445 AH");
+/// assert_eq!(DateHijri::new(1445, 12, 29).unwrap(), "29 Dhu al-Hijjah 1445 AH");
+///
+/// assert_eq!(Date::from(DateHijri::new(1445, 1, 1).unwrap()), "2023-07-19");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct DateHijri((u16, u8, u8), Str<{ DateHijri::MAX_LEN }>);
+
+impl_traits!(DateHijri, (u16, u8, u8));
+
+//---------------------------------------------------------------------------------------------------- DateHijri Constants
+impl DateHijri {
+    /// The maximum string length of a [`DateHijri`].
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHijri::new(9665, 5, 30).unwrap().len(), DateHijri::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 26;
+
+    /// Returns a [`Self`] with the date values set to `(0, 0, 0)`
+    ///
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHijri::ZERO, (0, 0, 0));
+    /// assert_eq!(DateHijri::ZERO, "???");
+    /// assert_eq!(DateHijri::ZERO, DateHijri::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned when using [`DateHijri::UNKNOWN`] or error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHijri::UNKNOWN, (0, 0, 0));
+    /// assert_eq!(DateHijri::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self((0, 0, 0), Str::from_static_str("???"));
+
+    /// The month names, `Muharram` is month `1`.
+    pub const MONTHS: [&'static str; 12] = [
+        "Muharram",
+        "Safar",
+        "Rabi al-Awwal",
+        "Rabi al-Thani",
+        "Jumada al-Awwal",
+        "Jumada al-Thani",
+        "Rajab",
+        "Shaban",
+        "Ramadan",
+        "Shawwal",
+        "Dhu al-Qadah",
+        "Dhu al-Hijjah",
+    ];
+}
+
+//---------------------------------------------------------------------------------------------------- DateHijri impl
+impl DateHijri {
+    impl_common!((u16, u8, u8));
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hijri year
+    pub const fn year(&self) -> u16 {
+        self.0 .0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hijri month (1-12)
+    pub const fn month(&self) -> u8 {
+        self.0 .1
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner Hijri day (1-30)
+    pub const fn day(&self) -> u8 {
+        self.0 .2
+    }
+
+    #[inline]
+    /// Parse [`u16`], [`u8`], [`u8`] for a Hijri year, month and day.
+    ///
+    /// ## Errors
+    /// - The month must be in-between `1-12`
+    /// - The day must be in-between `1-29` or `1-30` (depending on the month and year)
+    /// - The date must be in-between the Gregorian years `1000-9999`
+    ///
+    /// If an [`Err`] is returned, it will contain a [`DateHijri`] set with [`Self::UNKNOWN`].
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, Self> {
+        if month == 0 || month > 12 || day == 0 || day > hijri_days_in_month(year, month) {
+            return Err(Self::UNKNOWN);
+        }
+
+        match ymd_from_fixed(fixed_from_hijri(year, month, day)) {
+            Some(_) => Ok(Self::priv_from(year, month, day)),
+            None => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::new`] but silently errors
+    ///
+    /// [`Self::UNKNOWN`] will be returned silently if an error occurs.
+    pub fn new_silent(year: u16, month: u8, day: u8) -> Self {
+        match Self::new(year, month, day) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the name of the inner month, e.g. `Ramadan`
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(DateHijri::new(1445, 9, 1).unwrap().month_as_str(), "Ramadan");
+    /// assert_eq!(DateHijri::UNKNOWN.month_as_str(), "???");
+    /// ```
+    pub const fn month_as_str(&self) -> &'static str {
+        match self.month() {
+            1..=12 => Self::MONTHS[self.month() as usize - 1],
+            _ => "???",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(DateHijri::UNKNOWN.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- DateHijri impl (private)
+/// The fixed day number of `1 Muharram 1 AH` (`622-07-16` Julian).
+const EPOCH: i64 = 227_015;
+
+/// If the Hijri `year` has 355 days (`Dhu al-Hijjah` has 30 days).
+const fn hijri_is_leap_year(year: u16) -> bool {
+    (14 + 11 * year as u32) % 30 < 11
+}
+
+/// Odd months have 30 days, even months have 29, except `Dhu al-Hijjah` in leap years.
+const fn hijri_days_in_month(year: u16, month: u8) -> u8 {
+    if month % 2 == 1 || (month == 12 && hijri_is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+// Calendrical Calculations, `fixed-from-islamic`.
+const fn fixed_from_hijri(year: u16, month: u8, day: u8) -> i64 {
+    let (y, m, d) = (year as i64, month as i64, day as i64);
+    EPOCH - 1 + (y - 1) * 354 + (3 + 11 * y) / 30 + 29 * (m - 1) + (6 * m - 1) / 11 + d
+}
+
+// Calendrical Calculations, `islamic-from-fixed`.
+//
+// INVARIANT: `fixed` must be after `EPOCH`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn hijri_from_fixed(fixed: i64) -> (u16, u8, u8) {
+    let year = ((30 * (fixed - EPOCH) + 10646) / 10631) as u16;
+    let prior_days = fixed - fixed_from_hijri(year, 1, 1);
+    let month = ((11 * prior_days + 330) / 325) as u8;
+    let day = (fixed - fixed_from_hijri(year, month, 1) + 1) as u8;
+    (year, month, day)
+}
+
+impl DateHijri {
+    // INVARIANT: inputs must be a valid Hijri date.
+    fn priv_from(y: u16, m: u8, d: u8) -> Self {
+        let mut itoa = crate::toa::Itoa64::new();
+        let mut string = Str::new();
+
+        // 16 Shawwal 1445 AH
+        string.push_str_panic(itoa.format_str(d));
+        string.push_str_panic(" ");
+        string.push_str_panic(Self::MONTHS[m as usize - 1]);
+        string.push_str_panic(" ");
+        string.push_str_panic(itoa.format_str(y));
+        string.push_str_panic(" AH");
+
+        Self((y, m, d), string)
+    }
+
+    // Gregorian `(year, month, day)` to `Self`.
+    fn priv_from_ymd((y, m, d): (u16, u8, u8)) -> Self {
+        if ok(y, m, d) && d <= days_in_month(y, m) {
+            let (y, m, d) = hijri_from_fixed(fixed_from_ymd(y, m, d));
+            Self::priv_from(y, m, d)
+        } else {
+            Self::UNKNOWN
+        }
+    }
+
+    // `Self` to Gregorian `(year, month, day)`.
+    const fn priv_to_ymd(self) -> Option<(u16, u8, u8)> {
+        if self.is_unknown() {
+            None
+        } else {
+            ymd_from_fixed(fixed_from_hijri(self.year(), self.month(), self.day()))
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl TryFrom<(u16, u8, u8)> for DateHijri {
+    type Error = Self;
+    #[inline]
+    // Calls [`Self::new`].
+    fn try_from(value: (u16, u8, u8)) -> Result<Self, Self> {
+        Self::new(value.0, value.1, value.2)
+    }
+}
+
+// Implements `From` between `DateHijri` and the Gregorian date types (both ways).
+macro_rules! impl_from {
+	($($other:ty),* $(,)?) => { $(
+		impl From<$other> for DateHijri {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the date is not a full, valid date.
+			fn from(value: $other) -> Self {
+				Self::priv_from_ymd(value.inner())
+			}
+		}
+
+		impl From<DateHijri> for $other {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the [`DateHijri`] is unknown.
+			fn from(value: DateHijri) -> Self {
+				match value.priv_to_ymd() {
+					Some(ymd) => Self::try_from(ymd).unwrap_or(Self::UNKNOWN),
+					None => Self::UNKNOWN,
+				}
+			}
+		}
+	)*}
+}
+impl_from!(
+    crate::date::Date,
+    crate::date::Nichi,
+    crate::date::NichiFull,
+    crate::date::NichiWareki,
+);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::Nichi;
+
+    #[test]
+    fn from_date() {
+        for (ymd, expected, hijri) in [
+            ((1000, 1, 1), "15 Muharram 390 AH", (390, 1, 15)),
+            ((1970, 1, 1), "22 Shawwal 1389 AH", (1389, 10, 22)),
+            ((2000, 2, 29), "24 Dhu al-Qadah 1420 AH", (1420, 11, 24)),
+            ((2024, 3, 11), "1 Ramadan 1445 AH", (1445, 9, 1)),
+            ((2024, 4, 25), "16 Shawwal 1445 AH", (1445, 10, 16)),
+            ((9999, 12, 31), "2 Rabi al-Thani 9666 AH", (9666, 4, 2)),
+        ] {
+            let date = Date::try_from(ymd).unwrap();
+            let this = DateHijri::from(date);
+            assert_eq!(this, expected);
+            assert_eq!(this, hijri);
+            assert_eq!(Date::from(this), date);
+            assert_eq!(DateHijri::try_from(hijri).unwrap(), this);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut prev = DateHijri::from(Date::from_ymd(1000, 1, 1).unwrap());
+        let mut fixed = fixed_from_ymd(1000, 1, 1);
+        while let Some(ymd) = ymd_from_fixed(fixed + 1) {
+            fixed += 1;
+            let this = DateHijri::from(Nichi::try_from(ymd).unwrap());
+            assert!(this > prev, "{this} <= {prev}");
+            assert_eq!(
+                DateHijri::new(this.year(), this.month(), this.day()),
+                Ok(this)
+            );
+            assert_eq!(Nichi::from(this), ymd);
+            prev = this;
+        }
+        assert_eq!(prev, (9666, 4, 2));
+    }
+
+    #[test]
+    fn leap_years() {
+        let leap: Vec<u16> = (1..=30).filter(|y| hijri_is_leap_year(*y)).collect();
+        assert_eq!(leap, [2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+        assert!(DateHijri::new(1445, 12, 30).is_ok());
+        assert!(DateHijri::new(1444, 12, 30).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(DateHijri::new(1445, 0, 1).is_err());
+        assert!(DateHijri::new(1445, 13, 1).is_err());
+        assert!(DateHijri::new(1445, 2, 30).is_err());
+        assert!(DateHijri::new(390, 1, 14).is_err());
+        assert!(DateHijri::new(9666, 4, 3).is_err());
+        assert!(DateHijri::from(Date::from_ym(2024, 4).unwrap()).is_unknown());
+        assert!(DateHijri::from(Date::from_ymd(2023, 2, 29).unwrap()).is_unknown());
+        assert!(Date::from(DateHijri::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = DateHijri::new(1445, 10, 16).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[1445,10,16],"16 Shawwal 1445 AH"]"#);
+
+        let this: DateHijri = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (1445, 10, 16));
+        assert_eq!(this, "16 Shawwal 1445 AH");
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let this = DateHijri::new(1445, 10, 16).unwrap();
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(&this, config).unwrap();
+
+        let this: DateHijri = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, (1445, 10, 16));
+        assert_eq!(this, "16 Shawwal 1445 AH");
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this = DateHijri::new(1445, 10, 16).unwrap();
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: DateHijri = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, (1445, 10, 16));
+        assert_eq!(this, "16 Shawwal 1445 AH");
+    }
+}
@@ -0,0 +1,206 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+
+//---------------------------------------------------------------------------------------------------- chrono
+// Implements `From<chrono::NaiveDate>` and the reverse `TryFrom`.
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono {
+	($($date:ty),*) => {
+		$(
+			impl From<chrono::NaiveDate> for $date {
+				#[inline]
+				/// Returns [`Self::UNKNOWN`] if the year is not in-between `1000-9999`.
+				fn from(date: chrono::NaiveDate) -> Self {
+					use chrono::Datelike;
+					let Ok(year) = u16::try_from(date.year()) else {
+						return Self::UNKNOWN;
+					};
+					// `chrono` months and days always fit in a `u8`.
+					Self::try_from((year, date.month() as u8, date.day() as u8)).unwrap_or_else(|e| e)
+				}
+			}
+
+			impl TryFrom<$date> for chrono::NaiveDate {
+				type Error = $date;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is not a full, valid date.
+				fn try_from(date: $date) -> Result<Self, Self::Error> {
+					let (y, m, d) = date.inner();
+					Self::from_ymd_opt(i32::from(y), u32::from(m), u32::from(d)).ok_or(date)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "chrono")]
+impl_chrono!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- time
+// Implements `From<time::Date>` and the reverse `TryFrom`.
+#[cfg(feature = "time-crate")]
+macro_rules! impl_time {
+	($($date:ty),*) => {
+		$(
+			impl From<::time::Date> for $date {
+				#[inline]
+				/// Returns [`Self::UNKNOWN`] if the year is not in-between `1000-9999`.
+				fn from(date: ::time::Date) -> Self {
+					let Ok(year) = u16::try_from(date.year()) else {
+						return Self::UNKNOWN;
+					};
+					Self::try_from((year, u8::from(date.month()), date.day())).unwrap_or_else(|e| e)
+				}
+			}
+
+			impl TryFrom<$date> for ::time::Date {
+				type Error = $date;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is not a full, valid date.
+				fn try_from(date: $date) -> Result<Self, Self::Error> {
+					let (y, m, d) = date.inner();
+					let Ok(month) = ::time::Month::try_from(m) else {
+						return Err(date);
+					};
+					#[allow(clippy::map_err_ignore)]
+					Self::from_calendar_date(i32::from(y), month, d).map_err(|_| date)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "time-crate")]
+impl_time!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- jiff
+// Implements `From<jiff::civil::{Date, DateTime}>` and the reverse `TryFrom`.
+#[cfg(feature = "jiff")]
+macro_rules! impl_jiff {
+	($($date:ty),*) => {
+		$(
+			impl From<jiff::civil::Date> for $date {
+				#[inline]
+				/// Returns [`Self::UNKNOWN`] if the year is not in-between `1000-9999`.
+				fn from(date: jiff::civil::Date) -> Self {
+					let Ok(year) = u16::try_from(date.year()) else {
+						return Self::UNKNOWN;
+					};
+					// `jiff` months and days are always positive.
+					Self::try_from((year, date.month() as u8, date.day() as u8)).unwrap_or_else(|e| e)
+				}
+			}
+
+			impl From<jiff::civil::DateTime> for $date {
+				#[inline]
+				/// The time of day is ignored.
+				fn from(datetime: jiff::civil::DateTime) -> Self {
+					Self::from(datetime.date())
+				}
+			}
+
+			impl TryFrom<$date> for jiff::civil::Date {
+				type Error = $date;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is not a full, valid date.
+				fn try_from(date: $date) -> Result<Self, Self::Error> {
+					let (y, m, d) = date.inner();
+					let (Ok(y), Ok(m), Ok(d)) = (i16::try_from(y), i8::try_from(m), i8::try_from(d)) else {
+						return Err(date);
+					};
+					#[allow(clippy::map_err_ignore)]
+					Self::new(y, m, d).map_err(|_| date)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "jiff")]
+impl_jiff!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+        assert_eq!(Date::from(naive), "2020-12-25");
+        assert_eq!(Nichi::from(naive), "Fri, Dec 25, 2020");
+        assert_eq!(NichiFull::from(naive), "Friday, December 25th, 2020");
+
+        assert_eq!(chrono::NaiveDate::try_from(Date::from(naive)), Ok(naive));
+        assert_eq!(chrono::NaiveDate::try_from(Nichi::from(naive)), Ok(naive));
+        assert_eq!(
+            chrono::NaiveDate::try_from(NichiFull::from(naive)),
+            Ok(naive)
+        );
+
+        // Out of range years.
+        let naive = chrono::NaiveDate::from_ymd_opt(999, 1, 1).unwrap();
+        assert!(Date::from(naive).is_unknown());
+        assert!(Nichi::from(naive).is_unknown());
+        let naive = chrono::NaiveDate::from_ymd_opt(-1, 1, 1).unwrap();
+        assert!(NichiFull::from(naive).is_unknown());
+
+        // Partial or invalid dates.
+        let date = Date::from_ym(2020, 12).unwrap();
+        assert_eq!(chrono::NaiveDate::try_from(date), Err(date));
+        assert!(chrono::NaiveDate::try_from(Date::UNKNOWN).is_err());
+        let date = Date::from_ymd(2021, 2, 31).unwrap();
+        assert_eq!(chrono::NaiveDate::try_from(date), Err(date));
+    }
+
+    #[test]
+    #[cfg(feature = "time-crate")]
+    fn time() {
+        let date = ::time::Date::from_calendar_date(2020, ::time::Month::December, 25).unwrap();
+        assert_eq!(Date::from(date), "2020-12-25");
+        assert_eq!(Nichi::from(date), "Fri, Dec 25, 2020");
+        assert_eq!(NichiFull::from(date), "Friday, December 25th, 2020");
+
+        assert_eq!(::time::Date::try_from(Date::from(date)), Ok(date));
+        assert_eq!(::time::Date::try_from(Nichi::from(date)), Ok(date));
+        assert_eq!(::time::Date::try_from(NichiFull::from(date)), Ok(date));
+
+        // Out of range years.
+        let date = ::time::Date::from_calendar_date(10_000, ::time::Month::January, 1);
+        if let Ok(date) = date {
+            assert!(Date::from(date).is_unknown());
+        }
+        let date = ::time::Date::from_calendar_date(-1, ::time::Month::January, 1).unwrap();
+        assert!(Nichi::from(date).is_unknown());
+
+        // Partial or invalid dates.
+        let date = Date::from_y(2020).unwrap();
+        assert_eq!(::time::Date::try_from(date), Err(date));
+        assert!(::time::Date::try_from(Nichi::UNKNOWN).is_err());
+        let date = Date::from_ymd(2021, 2, 31).unwrap();
+        assert_eq!(::time::Date::try_from(date), Err(date));
+    }
+
+    #[test]
+    #[cfg(feature = "jiff")]
+    fn jiff() {
+        let date = jiff::civil::date(2020, 12, 25);
+        assert_eq!(Date::from(date), "2020-12-25");
+        assert_eq!(Nichi::from(date), "Fri, Dec 25, 2020");
+        assert_eq!(NichiFull::from(date), "Friday, December 25th, 2020");
+        assert_eq!(Date::from(date.at(23, 59, 59, 0)), "2020-12-25");
+
+        assert_eq!(jiff::civil::Date::try_from(Date::from(date)), Ok(date));
+        assert_eq!(jiff::civil::Date::try_from(Nichi::from(date)), Ok(date));
+        assert_eq!(jiff::civil::Date::try_from(NichiFull::from(date)), Ok(date));
+
+        // Out of range years.
+        assert!(Date::from(jiff::civil::date(999, 1, 1)).is_unknown());
+        assert!(Nichi::from(jiff::civil::date(-1, 1, 1)).is_unknown());
+
+        // Partial or invalid dates.
+        let date = Date::from_ym(2020, 12).unwrap();
+        assert_eq!(jiff::civil::Date::try_from(date), Err(date));
+        assert!(jiff::civil::Date::try_from(NichiFull::UNKNOWN).is_err());
+        let date = Date::from_ymd(2021, 2, 31).unwrap();
+        assert_eq!(jiff::civil::Date::try_from(date), Err(date));
+    }
+}
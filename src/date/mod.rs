@@ -52,6 +52,41 @@
 //!     "Wednesday"
 //! );
 //! ```
+//!
+//! `.is_weekend()` and `.week_of_month()` take a [`WeekStart`], the first day of the week:
+//! ```rust
+//! # use readable::date::*;
+//! let nichi = Nichi::new(2024, 4, 7).unwrap(); // Sunday
+//! assert!(nichi.is_weekend(WeekStart::Monday));
+//! assert_eq!(nichi.week_of_month(WeekStart::Monday), 1);
+//! assert_eq!(nichi.week_of_month(WeekStart::Sunday), 2);
+//! ```
+//! ## Quarter
+//! These types all have `.quarter()` and `.half()` functions,
+//! and [`Quarter`] formats the year's quarter for reports.
+//!
+//! ```rust
+//! # use readable::date::*;
+//! let date = Date::from_ymd(2020, 12, 25).unwrap();
+//! assert_eq!(date.quarter(), 4);
+//! assert_eq!(date.half(), 2);
+//!
+//! let quarter = Quarter::from(date);
+//! assert_eq!(quarter, "Q4 2020");
+//! assert_eq!(quarter.as_str_iso(), "2020-Q4");
+//! ```
+//!
+//! ## Parsing
+//! [`Date`], [`Nichi`] and [`NichiFull`] implement [`std::str::FromStr`] with their `from_str()`,
+//! returning [`Error::Parse`](crate::Error::Parse) on error:
+//!
+//! ```rust
+//! # use readable::date::*;
+//! let date: Date = "2020-12-25".parse().unwrap();
+//! assert_eq!(date, (2020, 12, 25));
+//! assert_eq!("????".parse::<Date>(), Err(readable::Error::Parse));
+//! ```
+//!
 //! ## From other types
 //! All types support conversion with each other using [`From`],
 //! although [`Date`] itself is only lossless if the full `year-month-day` is available.
@@ -72,6 +107,52 @@
 //! assert!(nichi.is_unknown());
 //! ```
 //!
+//! ## `chrono` and `time`
+//! With the `chrono` and `time-crate` features, all types in this module implement:
+//! - [`From`] [`chrono::NaiveDate`](https://docs.rs/chrono/latest/chrono/struct.NaiveDate.html) and [`time::Date`](https://docs.rs/time/latest/time/struct.Date.html)
+//! - [`TryFrom`] for the reverse, returning the input as the [`Err`] if it is not a full, valid date
+//!
+//! Years outside of `1000-9999` will return an `UNKNOWN`.
+//!
+//! ```rust
+//! # use readable::date::*;
+//! # #[cfg(feature = "chrono")] {
+//! let naive = chrono::NaiveDate::from_ymd_opt(2020, 12, 25).unwrap();
+//! let date  = Date::from(naive);
+//! assert_eq!(date, "2020-12-25");
+//! assert_eq!(chrono::NaiveDate::try_from(date), Ok(naive));
+//! # }
+//! ```
+//!
+//! ## `jiff`
+//! With the `jiff` feature, all types in this module implement:
+//! - [`From`] [`jiff::civil::Date`](https://docs.rs/jiff/latest/jiff/civil/struct.Date.html) and [`jiff::civil::DateTime`](https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html) (the time of day is ignored)
+//! - [`TryFrom`] for the reverse into [`jiff::civil::Date`](https://docs.rs/jiff/latest/jiff/civil/struct.Date.html), with the same rules as above
+//!
+//! ```rust
+//! # use readable::date::*;
+//! # #[cfg(feature = "jiff")] {
+//! let civil = jiff::civil::date(2020, 12, 25);
+//! let date  = Date::from(civil.at(12, 0, 0, 0));
+//! assert_eq!(date, "2020-12-25");
+//! assert_eq!(jiff::civil::Date::try_from(date), Ok(civil));
+//! # }
+//! ```
+//!
+//! ## Other calendars
+//! With the `calendar` feature, [`DateHijri`] (Islamic) and [`DateHebrew`] convert
+//! to and from the above types, their inner `(year, month, day)` are in their own calendar.
+//!
+//! ```rust
+//! # use readable::date::*;
+//! # #[cfg(feature = "calendar")] {
+//! let date = Date::from_ymd(2024, 4, 25).unwrap();
+//! assert_eq!(DateHijri::from(date),  "16 Shawwal 1445 AH");
+//! assert_eq!(DateHebrew::from(date), "17 Nisan 5784");
+//! assert_eq!(Date::from(DateHebrew::from(date)), date);
+//! # }
+//! ```
+//!
 //! ## Copy
 //! [`Copy`] is available.
 //!
@@ -96,8 +177,39 @@ pub use self::nichi::*;
 mod nichi_full;
 pub use nichi_full::*;
 
+mod nichi_wareki;
+pub use nichi_wareki::*;
+
+#[cfg(feature = "calendar")]
+mod hijri;
+#[cfg(feature = "calendar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calendar")))]
+pub use hijri::DateHijri;
+
+#[cfg(feature = "calendar")]
+mod hebrew;
+#[cfg(feature = "calendar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calendar")))]
+pub use hebrew::DateHebrew;
+
+mod quarter;
+pub use quarter::*;
+
+mod month;
+pub use month::*;
+
+mod weekday;
+pub use weekday::*;
+
+mod cmp;
+
+mod duration;
+
 pub(super) mod free;
 pub use free::*;
 
 mod sysdate;
 pub use sysdate::*;
+
+#[cfg(any(feature = "chrono", feature = "time-crate", feature = "jiff"))]
+mod interop;
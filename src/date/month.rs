@@ -0,0 +1,193 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Month
+/// A month of the year, e.g. `December`
+///
+/// The inner "integer" is the month, `1-12`.
+///
+/// [`Month::as_str`] is the full name, [`Month::as_str_short`] is the 3 letter abbreviation.
+///
+/// ```rust
+/// # use readable::date::*;
+/// let month = Month::from(12);
+/// assert_eq!(month, 12);
+/// assert_eq!(month, "December");
+/// assert_eq!(month.as_str_short(), "Dec");
+///
+/// // Out of range.
+/// assert_eq!(Month::from(13), Month::unknown());
+/// assert_eq!(Month::from(0), "???");
+///
+/// // From a date.
+/// let date = Date::from_ymd(2020, 12, 25).unwrap();
+/// assert_eq!(Month::from(date), month);
+/// ```
+///
+/// ## Size
+/// [`Str<9>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<Month>(), 11);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Month(u8, Str<{ Month::MAX_LEN }>);
+
+impl_traits!(Month, u8);
+
+//---------------------------------------------------------------------------------------------------- Month Constants
+impl Month {
+    /// The maximum string length of a [`Month`].
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Month::from(9).len(), Month::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 9;
+
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Month::ZERO, Month::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Month::UNKNOWN, 0);
+    /// assert_eq!(Month::UNKNOWN, "???");
+    /// assert_eq!(Month::UNKNOWN.as_str_short(), "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- Month impl
+impl Month {
+    impl_common!(u8);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(Month::UNKNOWN.is_unknown());
+    /// assert!(!Month::from(1).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the 3 letter abbreviation, e.g. `Dec`
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Month::from(1).as_str_short(), "Jan");
+    /// assert_eq!(Month::from(5).as_str_short(), "May");
+    /// assert_eq!(Month::from(9).as_str_short(), "Sep");
+    /// ```
+    pub const fn as_str_short(&self) -> &'static str {
+        if self.is_unknown() {
+            "???"
+        } else {
+            nichi::Month::new(self.0).as_str_short()
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<u8> for Month {
+    #[inline]
+    fn from(month: u8) -> Self {
+        if matches!(month, 1..=12) {
+            Self::from(nichi::Month::new(month))
+        } else {
+            Self::UNKNOWN
+        }
+    }
+}
+
+impl From<&u8> for Month {
+    #[inline]
+    fn from(month: &u8) -> Self {
+        Self::from(*month)
+    }
+}
+
+impl From<nichi::Month> for Month {
+    #[inline]
+    fn from(month: nichi::Month) -> Self {
+        Self(month.inner(), Str::from_static_str(month.as_str()))
+    }
+}
+
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Month {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the month is missing.
+			fn from(date: $from) -> Self {
+				Self::from(date.month())
+			}
+		}
+		impl From<&$from> for Month {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the month is missing.
+			fn from(date: &$from) -> Self {
+				Self::from(date.month())
+			}
+		}
+	)*}
+}
+impl_from!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all() {
+        for m in 1..=12 {
+            let month = Month::from(m);
+            let nichi = nichi::Month::new(m);
+            assert_eq!(month, m);
+            assert_eq!(month, nichi.as_str());
+            assert_eq!(month.as_str_short(), nichi.as_str_short());
+        }
+        for m in 13..=u8::MAX {
+            assert!(Month::from(m).is_unknown());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Month::from(12);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[12,"December"]"#);
+
+        let this: Month = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 12);
+        assert_eq!(this, "December");
+    }
+}
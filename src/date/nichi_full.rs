@@ -1,8 +1,8 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::date::free::{ok, ok_year};
+use crate::date::free::{check, ok, ok_weekday, ok_year};
 #[allow(unused_imports)]
 use crate::date::Nichi;
-use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::macros::{impl_common, impl_const, impl_std_from_str, impl_traits};
 use crate::str::Str; // docs
 
 //---------------------------------------------------------------------------------------------------- `NichiFull`
@@ -16,7 +16,7 @@ use crate::str::Str; // docs
 /// ```
 ///
 /// ## Size
-/// [`Str<30>`] is used internally to represent the string.
+/// [`Str<31>`] is used internally to represent the string.
 ///
 /// ```rust
 /// # use readable::date::*;
@@ -37,19 +37,20 @@ use crate::str::Str; // docs
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct NichiFull((u16, u8, u8), Str<{ NichiFull::MAX_LEN }>);
 
 impl_traits!(NichiFull, (u16, u8, u8));
+impl_std_from_str!(NichiFull => from_str);
 
 //---------------------------------------------------------------------------------------------------- NichiFull Constants
 impl NichiFull {
     /// The maximum string length of a [`NichiFull`].
     /// ```rust
     /// # use readable::date::*;
-    /// assert_eq!(NichiFull::from_str("Saturday, September 31st, 9998").unwrap().len(), NichiFull::MAX_LEN);
+    /// assert_eq!(NichiFull::from_str("Wednesday, September 17th, 9947").unwrap().len(), NichiFull::MAX_LEN);
     /// ```
-    pub const MAX_LEN: usize = 30;
+    pub const MAX_LEN: usize = 31;
 
     /// Returns a [`Self`] with the date values set to `(0, 0, 0)`
     ///
@@ -121,6 +122,32 @@ impl NichiFull {
         self.0 .2
     }
 
+    #[inline]
+    #[must_use]
+    /// Return the quarter of the year (1-4)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiFull::new(2020, 3, 31).unwrap().quarter(), 1);
+    /// assert_eq!(NichiFull::new(2020, 12, 25).unwrap().quarter(), 4);
+    /// ```
+    pub const fn quarter(&self) -> u8 {
+        (self.month() + 2) / 3
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the half of the year (1-2)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiFull::new(2020, 6, 30).unwrap().half(), 1);
+    /// assert_eq!(NichiFull::new(2020, 7, 1).unwrap().half(), 2);
+    /// ```
+    pub const fn half(&self) -> u8 {
+        (self.month() + 5) / 6
+    }
+
     #[inline]
     #[must_use]
     /// Calculate the weekday
@@ -234,6 +261,43 @@ impl NichiFull {
         }
     }
 
+    #[inline]
+    /// Same as [`Self::new`] but returns the reason for failure
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// # use readable::Error;
+    /// assert_eq!(NichiFull::try_new(2022, 12, 31).unwrap(), "Saturday, December 31st, 2022");
+    /// assert_eq!(NichiFull::try_new(999, 12, 31),  Err(Error::YearOutOfRange));
+    /// assert_eq!(NichiFull::try_new(2022, 13, 31), Err(Error::MonthOutOfRange));
+    /// assert_eq!(NichiFull::try_new(2022, 12, 32), Err(Error::DayOutOfRange));
+    /// ```
+    ///
+    /// ## Errors
+    /// - [`crate::Error::YearOutOfRange`] if the year is not in-between `1000-9999`
+    /// - [`crate::Error::MonthOutOfRange`] if the month is not in-between `1-12`
+    /// - [`crate::Error::DayOutOfRange`] if the day is not in-between `1-31`
+    pub fn try_new(year: u16, month: u8, day: u8) -> Result<Self, crate::Error> {
+        check(year, month, day)?;
+        Ok(Self::new_silent(year, month, day))
+    }
+
+    #[inline]
+    /// Same as [`Self::from_unix`] but returns the reason for failure
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// # use readable::Error;
+    /// assert!(NichiFull::try_from_unix(1698019200).is_ok());
+    /// assert_eq!(NichiFull::try_from_unix(339618217000), Err(Error::YearOutOfRange));
+    /// ```
+    ///
+    /// ## Errors
+    /// [`crate::Error::YearOutOfRange`] if the date's year is not in-between `1000-9999`.
+    pub fn try_from_unix(unix_timestamp: u64) -> Result<Self, crate::Error> {
+        Self::from_unix(unix_timestamp).map_err(|_| crate::Error::YearOutOfRange)
+    }
+
     #[inline]
     #[must_use]
     #[allow(clippy::cast_possible_wrap)]
@@ -304,6 +368,28 @@ impl NichiFull {
     /// assert_eq!(NichiFull::from_str("2010 2 02").unwrap(),  nichi);
     /// ```
     ///
+    /// ## Weekday
+    /// [`NichiFull`]'s own output can be parsed back, along with [`Nichi`]'s:
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let nichi = NichiFull::new(2020, 12, 25).unwrap();
+    /// assert_eq!(nichi, "Friday, December 25th, 2020");
+    /// assert_eq!(NichiFull::from_str(&nichi).unwrap(), nichi);
+    /// assert_eq!(NichiFull::from_str("Fri, Dec 25, 2020").unwrap(), nichi);
+    /// ```
+    ///
+    /// A leading weekday (full or 3 letters) is optional, but if it
+    /// exists, it must be the correct weekday for the date:
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(NichiFull::from_str("Dec 25, 2020").is_ok());
+    /// assert!(NichiFull::from_str("fri dec 25 2020").is_ok());
+    /// assert!(NichiFull::from_str("Mon, Dec 25, 2020").is_err());
+    /// assert!(NichiFull::from_str("Monday, December 25th, 2020").is_err());
+    /// ```
+    ///
     /// ## Panic
     /// If the input to this function is not ASCII (or 1 byte per character), it may panic.
     ///
@@ -357,7 +443,13 @@ impl NichiFull {
         match nichi::Date::from_str(s) {
             Some(nichi) => {
                 let (y, m, d) = nichi.inner();
-                Ok(Self::priv_from(y as u16, m, d))
+                // `nichi` accepts years outside of `1000..=9999`,
+                // and ignores the weekday, e.g. the `Fri` in `Fri, Dec 25, 2020`.
+                if ok(y as u16, m, d) && ok_weekday(s, y as u16, m, d) {
+                    Ok(Self::priv_from(y as u16, m, d))
+                } else {
+                    Err(Self::unknown())
+                }
             }
             None => Err(Self::unknown()),
         }
@@ -477,6 +569,18 @@ mod tests {
         assert_eq!(NichiFull::from_str_silent("0999"), NichiFull::unknown());
     }
 
+    #[test]
+    fn fuzz_regressions() {
+        // Years outside of `1000..=9999`.
+        assert_eq!(NichiFull::from_str_silent(" 9 17"), NichiFull::unknown());
+        assert_eq!(Nichi::from_str_silent(" 9 17"), Nichi::UNKNOWN);
+        // The longest string, previously overflowed the buffer.
+        assert_eq!(
+            NichiFull::from_str(" a_9947T9 17").unwrap(),
+            "Wednesday, September 17th, 9947"
+        );
+    }
+
     #[test]
     fn invalid_dates() {
         assert_eq!(
@@ -507,6 +611,24 @@ mod tests {
         assert_eq!(NichiFull::from_str("2020_12_25").unwrap(), EXPECTED_STR);
     }
 
+    #[test]
+    fn from_str_round_trip() {
+        for (y, m, d) in [(1000, 1, 1), (2020, 12, 25), (2024, 2, 29), (9999, 12, 31)] {
+            let this = NichiFull::new(y, m, d).unwrap();
+            assert_eq!(NichiFull::from_str(&this).unwrap(), this);
+            assert_eq!(
+                NichiFull::from_str(&Nichi::new(y, m, d).unwrap()).unwrap(),
+                this
+            );
+        }
+
+        // Wrong weekday.
+        assert!(NichiFull::from_str("Sat, Dec 25, 2020").is_err());
+        assert!(NichiFull::from_str("saturday december 25th 2020").is_err());
+        // Only a leading weekday is checked.
+        assert!(NichiFull::from_str("Dec 25 2020 (Sat)").is_ok());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
@@ -0,0 +1,620 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::free::{ok, ok_year};
+#[allow(unused_imports)]
+use crate::date::Nichi;
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str; // docs
+
+//---------------------------------------------------------------------------------------------------- Era
+/// A Japanese era (元号), used by [`NichiWareki`]
+///
+/// The first day of each era is the same as [ICU](https://icu.unicode.org)'s
+/// (and so JavaScript's `Intl` with the `japanese` calendar):
+///
+/// | Era              | Kanji  | First day    |
+/// |------------------|--------|--------------|
+/// | [`Era::Meiji`]   | `明治` | `1868-09-08` |
+/// | [`Era::Taisho`]  | `大正` | `1912-07-30` |
+/// | [`Era::Showa`]   | `昭和` | `1926-12-25` |
+/// | [`Era::Heisei`]  | `平成` | `1989-01-08` |
+/// | [`Era::Reiwa`]   | `令和` | `2019-05-01` |
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(Era::from_ymd(2019, 4, 30), Some(Era::Heisei));
+/// assert_eq!(Era::from_ymd(2019, 5, 1),  Some(Era::Reiwa));
+/// assert_eq!(Era::from_ymd(1868, 9, 7),  None);
+///
+/// assert_eq!(Era::Reiwa.as_str(), "令和");
+/// assert_eq!(Era::Reiwa.as_str_romaji(), "Reiwa");
+/// assert_eq!(Era::Reiwa.start(), (2019, 5, 1));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    /// 明治, from `1868-09-08`
+    Meiji,
+    /// 大正, from `1912-07-30`
+    Taisho,
+    /// 昭和, from `1926-12-25`
+    Showa,
+    /// 平成, from `1989-01-08`
+    Heisei,
+    /// 令和, from `2019-05-01`
+    Reiwa,
+}
+
+impl Era {
+    /// All the eras, from oldest to newest
+    pub const ALL: [Self; 5] = [
+        Self::Meiji,
+        Self::Taisho,
+        Self::Showa,
+        Self::Heisei,
+        Self::Reiwa,
+    ];
+
+    #[inline]
+    #[must_use]
+    /// The era's name in kanji, e.g. `令和`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Meiji => "明治",
+            Self::Taisho => "大正",
+            Self::Showa => "昭和",
+            Self::Heisei => "平成",
+            Self::Reiwa => "令和",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The era's name in ASCII romaji, e.g. `Reiwa`
+    pub const fn as_str_romaji(self) -> &'static str {
+        match self {
+            Self::Meiji => "Meiji",
+            Self::Taisho => "Taisho",
+            Self::Showa => "Showa",
+            Self::Heisei => "Heisei",
+            Self::Reiwa => "Reiwa",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The first day of the era, `(year, month, day)`
+    pub const fn start(self) -> (u16, u8, u8) {
+        match self {
+            Self::Meiji => (1868, 9, 8),
+            Self::Taisho => (1912, 7, 30),
+            Self::Showa => (1926, 12, 25),
+            Self::Heisei => (1989, 1, 8),
+            Self::Reiwa => (2019, 5, 1),
+        }
+    }
+
+    #[must_use]
+    /// The era of a date, [`None`] if it is before [`Era::Meiji`]
+    ///
+    /// The date itself is not checked, e.g. `(2020, 2, 31)` is [`Era::Reiwa`].
+    pub const fn from_ymd(year: u16, month: u8, day: u8) -> Option<Self> {
+        let date = ymd_key(year, month, day);
+
+        let mut i = Self::ALL.len();
+        while i > 0 {
+            i -= 1;
+            let era = Self::ALL[i];
+            let (y, m, d) = era.start();
+            if date >= ymd_key(y, m, d) {
+                return Some(era);
+            }
+        }
+
+        None
+    }
+}
+
+/// `(year, month, day)` as a single comparable number, e.g. `20190501`.
+const fn ymd_key(year: u16, month: u8, day: u8) -> u32 {
+    year as u32 * 10_000 + month as u32 * 100 + day as u32
+}
+
+impl std::fmt::Display for Era {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- `NichiWareki`
+/// A date in the Japanese calendar (和暦), e.g. `令和6年4月25日`
+///
+/// This is the same date as [`Nichi`], formatted with the [`Era`] and the year of that era.
+///
+/// The first year of an era is `元年`, e.g. `令和元年5月1日`.
+///
+/// ```rust
+/// # use readable::date::*;
+/// let wareki = NichiWareki::new(2024, 4, 25).unwrap();
+/// assert_eq!(wareki, "令和6年4月25日");
+/// assert_eq!(wareki, (2024, 4, 25));
+/// assert_eq!(wareki.era(), Some(Era::Reiwa));
+/// assert_eq!(wareki.era_year(), 6);
+/// ```
+///
+/// Dates before [`Era::Meiji`] (`1868-09-08`) are [`NichiWareki::UNKNOWN`].
+///
+/// The digits are ASCII, [`FullWidth`](crate::str::FullWidth) can render them full-width:
+/// ```rust
+/// # use readable::date::*;
+/// use readable::str::FullWidth;
+///
+/// let wareki = NichiWareki::new(2024, 4, 25).unwrap();
+/// assert_eq!(wareki.full_width(), "令和６年４月２５日");
+/// ```
+///
+/// ## Size
+/// [`Str<23>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<NichiWareki>(), 28);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(NichiWareki::new(1868, 9, 8).unwrap(),   "明治元年9月8日");
+/// assert_eq!(NichiWareki::new(1912, 7, 29).unwrap(),  "明治45年7月29日");
+/// assert_eq!(NichiWareki::new(1912, 7, 30).unwrap(),  "大正元年7月30日");
+/// assert_eq!(NichiWareki::new(1926, 12, 24).unwrap(), "大正15年12月24日");
+/// assert_eq!(NichiWareki::new(1926, 12, 25).unwrap(), "昭和元年12月25日");
+/// assert_eq!(NichiWareki::new(1989, 1, 7).unwrap(),   "昭和64年1月7日");
+/// assert_eq!(NichiWareki::new(1989, 1, 8).unwrap(),   "平成元年1月8日");
+/// assert_eq!(NichiWareki::new(2019, 4, 30).unwrap(),  "平成31年4月30日");
+/// assert_eq!(NichiWareki::new(2019, 5, 1).unwrap(),   "令和元年5月1日");
+///
+/// assert!(NichiWareki::new(1868, 9, 7).is_err());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct NichiWareki((u16, u8, u8), Str<{ NichiWareki::MAX_LEN }>);
+
+impl_traits!(NichiWareki, (u16, u8, u8));
+
+//---------------------------------------------------------------------------------------------------- NichiWareki Constants
+impl NichiWareki {
+    /// The maximum string length of a [`NichiWareki`].
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::new(9999, 12, 31).unwrap().len(), NichiWareki::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 23;
+
+    /// Returns a [`Self`] with the date values set to `(0, 0, 0)`
+    ///
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::ZERO, (0, 0, 0));
+    /// assert_eq!(NichiWareki::ZERO, "???");
+    /// assert_eq!(NichiWareki::ZERO, NichiWareki::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned when using [`NichiWareki::UNKNOWN`] or error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::UNKNOWN, (0, 0, 0));
+    /// assert_eq!(NichiWareki::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self((0, 0, 0), Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- NichiWareki impl
+impl NichiWareki {
+    impl_common!((u16, u8, u8));
+    impl_const!();
+
+    // Common functions.
+
+    #[inline]
+    #[must_use]
+    /// Return the inner (Gregorian) year (1868-9999)
+    pub const fn year(&self) -> u16 {
+        self.0 .0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner month (1-12)
+    pub const fn month(&self) -> u8 {
+        self.0 .1
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner day (1-31)
+    pub const fn day(&self) -> u8 {
+        self.0 .2
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the quarter of the year (1-4)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::new(2020, 3, 31).unwrap().quarter(), 1);
+    /// assert_eq!(NichiWareki::new(2020, 12, 25).unwrap().quarter(), 4);
+    /// ```
+    pub const fn quarter(&self) -> u8 {
+        (self.month() + 2) / 3
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the half of the year (1-2)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::new(2020, 6, 30).unwrap().half(), 1);
+    /// assert_eq!(NichiWareki::new(2020, 7, 1).unwrap().half(), 2);
+    /// ```
+    pub const fn half(&self) -> u8 {
+        (self.month() + 5) / 6
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calculate the weekday, [`None`] if [`Self::UNKNOWN`]
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(
+    ///     NichiWareki::new(2019, 5, 1).unwrap().weekday().unwrap().as_str(),
+    ///     "Wednesday"
+    /// );
+    /// assert_eq!(NichiWareki::UNKNOWN.weekday(), None);
+    /// ```
+    pub const fn weekday(&self) -> Option<nichi::Weekday> {
+        if self.is_unknown() {
+            return None;
+        }
+        #[allow(clippy::cast_possible_wrap)]
+        Some(nichi::Date::weekday_raw(
+            self.year() as i16,
+            self.month(),
+            self.day(),
+        ))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the [`Era`], [`None`] if [`Self::UNKNOWN`]
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::new(1989, 1, 8).unwrap().era(), Some(Era::Heisei));
+    /// assert_eq!(NichiWareki::UNKNOWN.era(), None);
+    /// ```
+    pub const fn era(&self) -> Option<Era> {
+        Era::from_ymd(self.year(), self.month(), self.day())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the year of the [`Era`], `1` is `元年`, `0` if [`Self::UNKNOWN`]
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(NichiWareki::new(2019, 5, 1).unwrap().era_year(), 1);
+    /// assert_eq!(NichiWareki::new(2024, 4, 25).unwrap().era_year(), 6);
+    /// assert_eq!(NichiWareki::UNKNOWN.era_year(), 0);
+    /// ```
+    pub const fn era_year(&self) -> u16 {
+        match self.era() {
+            Some(era) => self.year() - era.start().0 + 1,
+            None => 0,
+        }
+    }
+
+    #[inline]
+    /// Parse [`u16`], [`u8`], [`u8`] for a (Gregorian) year, month and day.
+    ///
+    /// ## Errors
+    /// - The year must be in-between `1000-9999`
+    /// - The month must be in-between `1-12`
+    /// - The day must be in-between `1-31`
+    /// - The date must be on or after `1868-09-08` ([`Era::Meiji`])
+    ///
+    /// If an [`Err`] is returned, it will contain a [`NichiWareki`] set with [`Self::UNKNOWN`].
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, Self> {
+        if ok(year, month, day) {
+            match Self::priv_from(year, month, day) {
+                this if this.is_unknown() => Err(this),
+                this => Ok(this),
+            }
+        } else {
+            Err(Self::UNKNOWN)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::new`] but silently errors
+    ///
+    /// [`Self::UNKNOWN`] will be returned silently if an error occurs.
+    pub fn new_silent(year: u16, month: u8, day: u8) -> Self {
+        match Self::new(year, month, day) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    /// Create a [`Self`] from a UNIX timestamp
+    ///
+    /// (Seconds after `January 1st, 1970 UTC`).
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let wareki = NichiWareki::from_unix(1698019200).unwrap();
+    /// assert_eq!(wareki, "令和5年10月23日");
+    /// assert_eq!(wareki, (2023, 10, 23));
+    /// ```
+    ///
+    /// ## Errors
+    /// This returns a [`Self::UNKNOWN`] wrapped in an [`Err`] if the given
+    /// `unix_timestamp` is a date with a year larger than `9999`.
+    pub fn from_unix(unix_timestamp: u64) -> Result<Self, Self> {
+        let nichi = nichi::Date::from_unix(i128::from(unix_timestamp));
+        let year = nichi.year().inner() as u16;
+        if ok_year(year) {
+            Self::new(year, nichi.month().inner(), nichi.day().inner())
+        } else {
+            Err(Self::UNKNOWN)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::from_unix`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_unix_silent(unix_timestamp: u64) -> Self {
+        match Self::from_unix(unix_timestamp) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    /// Get the corresponding UNIX timestamp of [`Self`]
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let wareki = NichiWareki::from_unix(1698019200).unwrap();
+    /// assert_eq!(wareki.as_unix(), 1698019200);
+    /// ```
+    pub const fn as_unix(&self) -> u64 {
+        nichi::Date::new(self.year() as i16, self.month(), self.day()).as_unix() as u64
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(NichiWareki::UNKNOWN.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- NichiWareki impl (private)
+impl NichiWareki {
+    // INVARIANT: inputs must be valid.
+    //
+    // Dates before `Era::Meiji` return `Self::UNKNOWN`.
+    pub(super) fn priv_from(y: u16, m: u8, d: u8) -> Self {
+        let Some(era) = Era::from_ymd(y, m, d) else {
+            return Self::UNKNOWN;
+        };
+
+        let mut itoa = crate::toa::Itoa64::new();
+        let mut string = Str::new();
+
+        // 令和6年4月25日
+        string.push_str_panic(era.as_str());
+        match y - era.start().0 + 1 {
+            1 => string.push_str_panic("元"),
+            year => string.push_str_panic(itoa.format_str(year)),
+        };
+        string.push_str_panic("年");
+        string.push_str_panic(itoa.format_str(m));
+        string.push_str_panic("月");
+        string.push_str_panic(itoa.format_str(d));
+        string.push_str_panic("日");
+
+        Self((y, m, d), string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl TryFrom<(u16, u8, u8)> for NichiWareki {
+    type Error = Self;
+    #[inline]
+    // Calls [`Self::new`].
+    fn try_from(value: (u16, u8, u8)) -> Result<Self, Self> {
+        Self::new(value.0, value.1, value.2)
+    }
+}
+
+// Implements `From` between `NichiWareki` and the other date types (both ways).
+macro_rules! impl_from {
+	($($other:ty),* $(,)?) => { $(
+		impl From<$other> for NichiWareki {
+			/// Returns [`Self::UNKNOWN`] if the date is not a full date or is before [`Era::Meiji`].
+			fn from(value: $other) -> Self {
+				let (y, m, d) = value.inner();
+				Self::new_silent(y, m, d)
+			}
+		}
+
+		impl From<NichiWareki> for $other {
+			/// Returns [`Self::UNKNOWN`] if the [`NichiWareki`] is unknown.
+			fn from(value: NichiWareki) -> Self {
+				if value.is_unknown() {
+					Self::UNKNOWN
+				} else {
+					let (y, m, d) = value.inner();
+					Self::try_from((y, m, d)).unwrap_or(Self::UNKNOWN)
+				}
+			}
+		}
+	)*}
+}
+impl_from!(
+    crate::date::Date,
+    crate::date::Nichi,
+    crate::date::NichiFull
+);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date::{Date, NichiFull};
+
+    #[test]
+    fn era_boundaries() {
+        for era in Era::ALL {
+            let (y, m, d) = era.start();
+            let first = NichiWareki::new(y, m, d).unwrap();
+            assert_eq!(first.era(), Some(era));
+            assert_eq!(first.era_year(), 1);
+            assert!(first.starts_with(era.as_str()));
+            assert!(first.contains("元年"));
+
+            // The day before is the previous era (or unknown).
+            let before =
+                NichiWareki::from(Date::from(first) - std::time::Duration::from_secs(86400));
+            let index = Era::ALL.iter().position(|e| *e == era).unwrap();
+            match index.checked_sub(1).map(|i| Era::ALL[i]) {
+                Some(previous) => {
+                    assert_eq!(before.era(), Some(previous));
+                    assert!(before.era_year() > 1);
+                }
+                None => assert!(before.is_unknown()),
+            }
+        }
+    }
+
+    #[test]
+    fn era_year() {
+        for (ymd, expected) in [
+            ((1868, 12, 31), "明治元年12月31日"),
+            ((1869, 1, 1), "明治2年1月1日"),
+            ((1945, 8, 15), "昭和20年8月15日"),
+            ((2000, 1, 1), "平成12年1月1日"),
+            ((2024, 4, 25), "令和6年4月25日"),
+            ((9999, 12, 31), "令和7981年12月31日"),
+        ] {
+            let this = NichiWareki::try_from(ymd).unwrap();
+            assert_eq!(this, expected);
+            assert_eq!(this, ymd);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(NichiWareki::new(1000, 1, 1).is_err());
+        assert!(NichiWareki::new(1868, 9, 7).is_err());
+        assert!(NichiWareki::new(2020, 13, 1).is_err());
+        assert!(NichiWareki::new(2020, 1, 0).is_err());
+        assert!(NichiWareki::new(10_000, 1, 1).is_err());
+        assert_eq!(NichiWareki::from_unix_silent(0), "昭和45年1月1日");
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(NichiWareki::UNKNOWN.weekday(), None);
+        assert_eq!(NichiWareki::UNKNOWN.era(), None);
+        assert!(crate::date::Weekday::from(NichiWareki::UNKNOWN).is_unknown());
+        assert_eq!(
+            NichiWareki::new(2024, 4, 25).unwrap().weekday(),
+            Some(nichi::Weekday::Thursday)
+        );
+    }
+
+    #[test]
+    fn from() {
+        let date = Date::from_ymd(2024, 4, 25).unwrap();
+        let wareki = NichiWareki::from(date);
+        assert_eq!(wareki, "令和6年4月25日");
+        assert_eq!(Date::from(wareki), date);
+        assert_eq!(Nichi::from(wareki), "Thu, Apr 25, 2024");
+        assert_eq!(NichiFull::from(wareki), (2024, 4, 25));
+        assert_eq!(NichiWareki::from(Nichi::from(wareki)), wareki);
+
+        // Not a full date, or before Meiji.
+        assert!(NichiWareki::from(Date::from_ym(2024, 4).unwrap()).is_unknown());
+        assert!(NichiWareki::from(Nichi::new(1800, 1, 1).unwrap()).is_unknown());
+        assert!(Date::from(NichiWareki::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = NichiWareki::new(2024, 4, 25).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[2024,4,25],"令和6年4月25日"]"#);
+
+        let this: NichiWareki = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (2024, 4, 25));
+        assert_eq!(this, "令和6年4月25日");
+
+        let json = serde_json::to_string(&Era::Reiwa).unwrap();
+        assert_eq!(json, r#""Reiwa""#);
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let this = NichiWareki::new(2024, 4, 25).unwrap();
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(&this, config).unwrap();
+
+        let this: NichiWareki = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, (2024, 4, 25));
+        assert_eq!(this, "令和6年4月25日");
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this = NichiWareki::new(2024, 4, 25).unwrap();
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: NichiWareki = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, (2024, 4, 25));
+        assert_eq!(this, "令和6年4月25日");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&NichiWareki::UNKNOWN).unwrap();
+        let this: NichiWareki = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
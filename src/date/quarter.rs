@@ -0,0 +1,257 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::free::ok_year;
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Quarter
+/// A year's quarter, e.g. `Q4 2020`
+///
+/// The inner "integer" type is a tuple of: `(u16, u8)` representing the `(Year, Quarter)`.
+///
+/// - The year must be `1000-9999`
+/// - The quarter must be `1-4`
+///
+/// [`Quarter::as_str`] is the `Q4 2020` format, [`Quarter::as_str_iso`] is the `2020-Q4` format.
+///
+/// ```rust
+/// # use readable::date::*;
+/// let quarter = Quarter::new(2020, 4).unwrap();
+/// assert_eq!(quarter, "Q4 2020");
+/// assert_eq!(quarter.as_str_iso(), "2020-Q4");
+/// assert_eq!(quarter, (2020, 4));
+///
+/// let date = Date::from_ymd(2020, 12, 25).unwrap();
+/// assert_eq!(Quarter::from(date), quarter);
+///
+/// // Missing month, unknown is returned.
+/// let date = Date::from_y(2020).unwrap();
+/// assert!(Quarter::from(date).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<7>`] is used internally to represent each string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<Quarter>(), 20);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Quarter(
+    (u16, u8),
+    Str<{ Quarter::MAX_LEN }>,
+    Str<{ Quarter::MAX_LEN }>,
+);
+
+impl_traits!(Quarter, (u16, u8));
+
+//---------------------------------------------------------------------------------------------------- Quarter Constants
+impl Quarter {
+    /// The maximum string length of a [`Quarter`], both formats are the same length.
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::new(2020, 4).unwrap().len(), Quarter::MAX_LEN);
+    /// assert_eq!(Quarter::new(2020, 4).unwrap().as_str_iso().len(), Quarter::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 7;
+
+    /// Returns a [`Self`] with the values set to `(0, 0)`
+    ///
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::ZERO, Quarter::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::UNKNOWN, (0, 0));
+    /// assert_eq!(Quarter::UNKNOWN, "Q? ????");
+    /// assert_eq!(Quarter::UNKNOWN.as_str_iso(), "????-Q?");
+    /// ```
+    pub const UNKNOWN: Self = Self(
+        (0, 0),
+        Str::from_static_str("Q? ????"),
+        Str::from_static_str("????-Q?"),
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- Quarter impl
+impl Quarter {
+    impl_common!((u16, u8));
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Return the inner year (1000-9999)
+    pub const fn year(&self) -> u16 {
+        self.0 .0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner quarter (1-4)
+    pub const fn quarter(&self) -> u8 {
+        self.0 .1
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner half of the year (1-2)
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::new(2020, 2).unwrap().half(), 1);
+    /// assert_eq!(Quarter::new(2020, 3).unwrap().half(), 2);
+    /// assert_eq!(Quarter::UNKNOWN.half(), 0);
+    /// ```
+    pub const fn half(&self) -> u8 {
+        (self.0 .1 + 1) / 2
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the `2020-Q4` formatted string
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::new(1999, 1).unwrap().as_str_iso(), "1999-Q1");
+    /// ```
+    pub const fn as_str_iso(&self) -> &str {
+        self.2.as_str()
+    }
+
+    #[inline]
+    /// Create a [`Quarter`] from a year and quarter
+    ///
+    /// ## Errors
+    /// If the year is not `1000-9999` or the quarter is not `1-4`, [`Self::UNKNOWN`] is returned.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Quarter::new(2020, 1).unwrap(), "Q1 2020");
+    /// assert_eq!(Quarter::new(2020, 5), Err(Quarter::UNKNOWN));
+    /// assert_eq!(Quarter::new(999, 1), Err(Quarter::UNKNOWN));
+    /// ```
+    pub fn new(year: u16, quarter: u8) -> Result<Self, Self> {
+        if ok_year(year) && matches!(quarter, 1..=4) {
+            Ok(Self::priv_from(year, quarter))
+        } else {
+            Err(Self::UNKNOWN)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Quarter::new`] but silently returns an [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn new_silent(year: u16, quarter: u8) -> Self {
+        match Self::new(year, quarter) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(Quarter::UNKNOWN.is_unknown());
+    /// assert!(!Quarter::new(2020, 4).unwrap().is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.0 .1 == 0
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Quarter impl (private)
+impl Quarter {
+    // INVARIANT: inputs must be valid.
+    fn priv_from(year: u16, quarter: u8) -> Self {
+        let mut year_buf = crate::toa::Itoa64::new();
+        let y = year_buf.format_str(year).as_bytes();
+        let q = b'0' + quarter;
+
+        let short = [b'Q', q, b' ', y[0], y[1], y[2], y[3]];
+        let iso = [y[0], y[1], y[2], y[3], b'-', b'Q', q];
+
+        // SAFETY: we know the str len and the bytes are ASCII.
+        unsafe {
+            Self(
+                (year, quarter),
+                Str::from_raw(short, 7),
+                Str::from_raw(iso, 7),
+            )
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Quarter {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the year or month are missing.
+			fn from(date: $from) -> Self {
+				Self::new_silent(date.year(), date.quarter())
+			}
+		}
+		impl From<&$from> for Quarter {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the year or month are missing.
+			fn from(date: &$from) -> Self {
+				Self::new_silent(date.year(), date.quarter())
+			}
+		}
+	)*}
+}
+impl_from!(Date, Nichi, NichiFull, NichiWareki);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all() {
+        for year in 1000..=9999 {
+            for quarter in 1..=4 {
+                let q = Quarter::new(year, quarter).unwrap();
+                assert_eq!(q.as_str(), format!("Q{quarter} {year}"));
+                assert_eq!(q.as_str_iso(), format!("{year}-Q{quarter}"));
+            }
+        }
+    }
+
+    #[test]
+    fn from_date() {
+        for month in 1..=12 {
+            let date = Date::from_ym(2020, month).unwrap();
+            let quarter = Quarter::from(date);
+            assert_eq!(quarter.quarter(), (month + 2) / 3);
+            assert_eq!(quarter.half(), date.half());
+            assert_eq!(quarter, Quarter::from(Nichi::new(2020, month, 1).unwrap()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Quarter::new(2020, 4).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[2020,4],"Q4 2020","2020-Q4"]"#);
+
+        let this: Quarter = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (2020, 4));
+        assert_eq!(this, "Q4 2020");
+    }
+}
@@ -23,9 +23,35 @@ pub trait SysDate {
     fn sysdate() -> Self;
 }
 
+//---------------------------------------------------------------------------------------------------- Inherent Impl
+// Implements `today()`, the same as `SysDate::sysdate()` without the trait import.
+macro_rules! impl_today {
+	($($n:ident),* $(,)?) => {
+		$(
+			impl crate::date::$n {
+				#[inline]
+				#[must_use]
+				/// Create a `Self` from the current system date
+				///
+				/// This is the same as [`SysDate::sysdate`], without needing the trait in scope.
+				///
+				/// ```rust
+				/// # use readable::date::*;
+				#[doc = concat!("let today = ", stringify!($n), "::today();")]
+				/// assert!(!today.is_unknown());
+				/// ```
+				pub fn today() -> Self {
+					<Self as SysDate>::sysdate()
+				}
+			}
+		)*
+	};
+}
+impl_today!(Date, Nichi, NichiFull, NichiWareki);
+
 //---------------------------------------------------------------------------------------------------- Uptime Function
 mod private {
-    use crate::date::{Date, Nichi, NichiFull};
+    use crate::date::{Date, Nichi, NichiFull, NichiWareki};
 
     trait Sealed {}
 
@@ -44,7 +70,8 @@ mod private {
 	}
     impl_sealed! {
         Date      => priv_ymd_num,
-        Nichi     => priv_from,
-        NichiFull => priv_from
+        Nichi       => priv_from,
+        NichiFull   => priv_from,
+        NichiWareki => priv_from
     }
 }
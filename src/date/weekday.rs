@@ -0,0 +1,487 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Nichi, NichiFull, NichiWareki};
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Weekday
+/// A day of the week, e.g. `Friday`
+///
+/// The inner "integer" is the weekday, `1-7`, starting on Sunday (the same as [`nichi::Weekday`]).
+///
+/// [`Weekday::as_str`] is the full name, [`Weekday::as_str_short`] is the 3 letter abbreviation.
+///
+/// ```rust
+/// # use readable::date::*;
+/// let weekday = Weekday::from(6);
+/// assert_eq!(weekday, 6);
+/// assert_eq!(weekday, "Friday");
+/// assert_eq!(weekday.as_str_short(), "Fri");
+///
+/// // Out of range.
+/// assert_eq!(Weekday::from(8), Weekday::unknown());
+/// assert_eq!(Weekday::from(0), "???");
+///
+/// // From a date.
+/// let date = Date::from_ymd(2020, 12, 25).unwrap();
+/// assert_eq!(Weekday::from(date), weekday);
+///
+/// // Missing day, unknown is returned.
+/// let date = Date::from_ym(2020, 12).unwrap();
+/// assert!(Weekday::from(date).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<9>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::date::*;
+/// assert_eq!(std::mem::size_of::<Weekday>(), 11);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Weekday(u8, Str<{ Weekday::MAX_LEN }>);
+
+impl_traits!(Weekday, u8);
+
+//---------------------------------------------------------------------------------------------------- Weekday Constants
+impl Weekday {
+    /// The maximum string length of a [`Weekday`].
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Weekday::from(4).len(), Weekday::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 9;
+
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Weekday::ZERO, Weekday::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Weekday::UNKNOWN, 0);
+    /// assert_eq!(Weekday::UNKNOWN, "???");
+    /// assert_eq!(Weekday::UNKNOWN.as_str_short(), "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- Weekday impl
+impl Weekday {
+    impl_common!(u8);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert!(Weekday::UNKNOWN.is_unknown());
+    /// assert!(!Weekday::from(1).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the 3 letter abbreviation, e.g. `Fri`
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(Weekday::from(1).as_str_short(), "Sun");
+    /// assert_eq!(Weekday::from(4).as_str_short(), "Wed");
+    /// assert_eq!(Weekday::from(7).as_str_short(), "Sat");
+    /// ```
+    pub const fn as_str_short(&self) -> &'static str {
+        if self.is_unknown() {
+            "???"
+        } else {
+            nichi::Weekday::new(self.0).as_str_short()
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this is on the weekend, depending on the [`WeekStart`]
+    ///
+    /// `false` is returned if [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// let friday = Weekday::from(6);
+    /// assert!(!friday.is_weekend(WeekStart::Monday));
+    /// assert!(friday.is_weekend(WeekStart::Saturday));
+    ///
+    /// let sunday = Weekday::from(1);
+    /// assert!(sunday.is_weekend(WeekStart::Sunday));
+    /// assert!(!sunday.is_weekend(WeekStart::Saturday));
+    ///
+    /// assert!(!Weekday::UNKNOWN.is_weekend(WeekStart::Monday));
+    /// ```
+    pub const fn is_weekend(&self, start: WeekStart) -> bool {
+        start.priv_is_weekend(self.0)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- WeekStart
+/// The first day of the week
+///
+/// This is used by `is_weekend()` and `week_of_month()`
+/// on [`Weekday`] and the date types, the weekend depends on it:
+///
+/// | Variant                          | Week        | Weekend              |
+/// |----------------------------------|-------------|----------------------|
+/// | [`WeekStart::Sunday`]            | `Sun - Sat` | `Saturday`, `Sunday` |
+/// | [`WeekStart::Monday`] (default)  | `Mon - Sun` | `Saturday`, `Sunday` |
+/// | [`WeekStart::Saturday`]          | `Sat - Fri` | `Friday`, `Saturday` |
+///
+/// ```rust
+/// # use readable::date::*;
+/// // Friday, the 5th.
+/// let nichi = Nichi::new(2024, 4, 5).unwrap();
+///
+/// assert!(!nichi.is_weekend(WeekStart::Monday));
+/// assert!(nichi.is_weekend(WeekStart::Saturday));
+///
+/// // April 2024 starts on a Monday.
+/// assert_eq!(nichi.week_of_month(WeekStart::Monday), 1);
+/// assert_eq!(nichi.week_of_month(WeekStart::Sunday), 1);
+/// assert_eq!(nichi.week_of_month(WeekStart::Saturday), 1);
+///
+/// // Sunday, the 7th.
+/// let nichi = Nichi::new(2024, 4, 7).unwrap();
+/// assert_eq!(nichi.week_of_month(WeekStart::Monday), 1);
+/// assert_eq!(nichi.week_of_month(WeekStart::Sunday), 2);
+/// assert_eq!(nichi.week_of_month(WeekStart::Saturday), 2);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WeekStart {
+    /// Weeks start on Sunday, the weekend is Saturday and Sunday
+    Sunday,
+    #[default]
+    /// Weeks start on Monday (ISO 8601), the weekend is Saturday and Sunday
+    Monday,
+    /// Weeks start on Saturday, the weekend is Friday and Saturday
+    Saturday,
+}
+
+impl WeekStart {
+    /// All the variants
+    pub const ALL: [Self; 3] = [Self::Sunday, Self::Monday, Self::Saturday];
+
+    #[inline]
+    #[must_use]
+    /// The first day of the week
+    ///
+    /// ```rust
+    /// # use readable::date::*;
+    /// assert_eq!(WeekStart::Sunday.weekday(), "Sunday");
+    /// assert_eq!(WeekStart::Monday.weekday(), "Monday");
+    /// assert_eq!(WeekStart::Saturday.weekday(), "Saturday");
+    /// ```
+    pub fn weekday(self) -> Weekday {
+        Weekday::from(self.as_u8())
+    }
+
+    /// The first day of the week, `1-7` starting on Sunday.
+    const fn as_u8(self) -> u8 {
+        match self {
+            Self::Sunday => 1,
+            Self::Monday => 2,
+            Self::Saturday => 7,
+        }
+    }
+
+    /// If `weekday` (`1-7` starting on Sunday) is on the weekend.
+    pub(super) const fn priv_is_weekend(self, weekday: u8) -> bool {
+        match self {
+            Self::Sunday | Self::Monday => matches!(weekday, 1 | 7),
+            Self::Saturday => matches!(weekday, 6 | 7),
+        }
+    }
+
+    /// The week (`1-6`) of the month that `day` is in,
+    /// `first` is the weekday (`1-7` starting on Sunday) of the 1st.
+    pub(super) const fn priv_week_of_month(self, first: u8, day: u8) -> u8 {
+        let offset = (first + 7 - self.as_u8()) % 7;
+        (day - 1 + offset) / 7 + 1
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Week
+// Implements `is_weekend()` and `week_of_month()` for the date types.
+//
+// `false` and `0` are returned if the date is not a full date.
+macro_rules! impl_week {
+	($($s:ident => $example:literal),* $(,)?) => { $(
+		impl crate::date::$s {
+			#[inline]
+			#[must_use]
+			/// If the date is on the weekend, depending on the [`WeekStart`]
+			///
+			/// `false` is returned if the date is not a full date.
+			///
+			/// ```rust
+			/// # use readable::date::*;
+			#[doc = concat!("let date = ", stringify!($s), $example, "; // Saturday")]
+			/// assert!(date.is_weekend(WeekStart::Monday));
+			/// assert!(date.is_weekend(WeekStart::Saturday));
+			///
+			#[doc = concat!("let date = ", stringify!($s), $example, " - std::time::Duration::from_secs(86400); // Friday")]
+			/// assert!(!date.is_weekend(WeekStart::Sunday));
+			/// assert!(date.is_weekend(WeekStart::Saturday));
+			/// ```
+			pub const fn is_weekend(&self, start: WeekStart) -> bool {
+				let (y, m, d) = self.inner();
+				if !crate::date::free::ok(y, m, d) {
+					return false;
+				}
+				#[allow(clippy::cast_possible_wrap)]
+				let weekday = nichi::Date::weekday_raw(y as i16, m, d).inner();
+				start.priv_is_weekend(weekday)
+			}
+
+			#[inline]
+			#[must_use]
+			/// Return the week of the month (1-6), depending on the [`WeekStart`]
+			///
+			/// The first week is the one with the 1st in it, even if it is not a full week,
+			/// i.e. this is the row of the date in a month calendar.
+			///
+			/// `0` is returned if the date is not a full date.
+			///
+			/// ```rust
+			/// # use readable::date::*;
+			#[doc = concat!("let date = ", stringify!($s), $example, "; // Saturday")]
+			/// assert_eq!(date.week_of_month(WeekStart::Sunday), 4);
+			/// assert_eq!(date.week_of_month(WeekStart::Monday), 4);
+			/// assert_eq!(date.week_of_month(WeekStart::Saturday), 5);
+			/// ```
+			pub const fn week_of_month(&self, start: WeekStart) -> u8 {
+				let (y, m, d) = self.inner();
+				if !crate::date::free::ok(y, m, d) {
+					return 0;
+				}
+				#[allow(clippy::cast_possible_wrap)]
+				let first = nichi::Date::weekday_raw(y as i16, m, 1).inner();
+				start.priv_week_of_month(first, d)
+			}
+		}
+	)*}
+}
+impl_week! {
+    Date        => "::from_ymd(2020, 12, 26).unwrap()",
+    Nichi       => "::new(2020, 12, 26).unwrap()",
+    NichiFull   => "::new(2020, 12, 26).unwrap()",
+    NichiWareki => "::new(2020, 12, 26).unwrap()",
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<u8> for Weekday {
+    #[inline]
+    fn from(weekday: u8) -> Self {
+        if matches!(weekday, 1..=7) {
+            Self::from(nichi::Weekday::new(weekday))
+        } else {
+            Self::UNKNOWN
+        }
+    }
+}
+
+impl From<&u8> for Weekday {
+    #[inline]
+    fn from(weekday: &u8) -> Self {
+        Self::from(*weekday)
+    }
+}
+
+impl From<nichi::Weekday> for Weekday {
+    #[inline]
+    fn from(weekday: nichi::Weekday) -> Self {
+        Self(weekday.inner(), Str::from_static_str(weekday.as_str()))
+    }
+}
+
+impl From<Date> for Weekday {
+    #[inline]
+    /// Returns [`Self::UNKNOWN`] if the month or day are missing.
+    fn from(date: Date) -> Self {
+        date.weekday().map_or(Self::UNKNOWN, Self::from)
+    }
+}
+
+impl From<&Date> for Weekday {
+    #[inline]
+    /// Returns [`Self::UNKNOWN`] if the month or day are missing.
+    fn from(date: &Date) -> Self {
+        date.weekday().map_or(Self::UNKNOWN, Self::from)
+    }
+}
+
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Weekday {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the date is unknown.
+			fn from(date: $from) -> Self {
+				Self::from(&date)
+			}
+		}
+		impl From<&$from> for Weekday {
+			#[inline]
+			/// Returns [`Self::UNKNOWN`] if the date is unknown.
+			fn from(date: &$from) -> Self {
+				if date.is_unknown() {
+					return Self::UNKNOWN;
+				}
+				Self::from(date.weekday())
+			}
+		}
+	)*}
+}
+impl_from!(Nichi, NichiFull);
+
+impl From<NichiWareki> for Weekday {
+    #[inline]
+    /// Returns [`Self::UNKNOWN`] if the date is unknown.
+    fn from(date: NichiWareki) -> Self {
+        date.weekday().map_or(Self::UNKNOWN, Self::from)
+    }
+}
+
+impl From<&NichiWareki> for Weekday {
+    #[inline]
+    /// Returns [`Self::UNKNOWN`] if the date is unknown.
+    fn from(date: &NichiWareki) -> Self {
+        date.weekday().map_or(Self::UNKNOWN, Self::from)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all() {
+        for w in 1..=7 {
+            let weekday = Weekday::from(w);
+            let nichi = nichi::Weekday::new(w);
+            assert_eq!(weekday, w);
+            assert_eq!(weekday, nichi.as_str());
+            assert_eq!(weekday.as_str_short(), nichi.as_str_short());
+        }
+        for w in 8..=u8::MAX {
+            assert!(Weekday::from(w).is_unknown());
+        }
+    }
+
+    #[test]
+    fn from_nichi() {
+        let nichi = Nichi::new(1999, 12, 25).unwrap();
+        assert_eq!(Weekday::from(nichi), "Saturday");
+        assert_eq!(Weekday::from(nichi).inner(), nichi.weekday().inner());
+    }
+
+    #[test]
+    fn from_unknown() {
+        assert!(Weekday::from(Nichi::UNKNOWN).is_unknown());
+        assert!(Weekday::from(&Nichi::UNKNOWN).is_unknown());
+        assert!(Weekday::from(NichiFull::UNKNOWN).is_unknown());
+        assert!(Weekday::from(&NichiFull::UNKNOWN).is_unknown());
+        assert!(Weekday::from(NichiWareki::UNKNOWN).is_unknown());
+        assert!(Weekday::from(&NichiWareki::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    fn weekend() {
+        // 2024-04-01 is a Monday.
+        for day in 1..=7 {
+            let nichi = Nichi::new(2024, 4, day).unwrap();
+            let weekday = Weekday::from(nichi);
+            for start in WeekStart::ALL {
+                assert_eq!(nichi.is_weekend(start), weekday.is_weekend(start));
+            }
+            assert_eq!(nichi.is_weekend(WeekStart::Monday), day >= 6);
+            assert_eq!(nichi.is_weekend(WeekStart::Sunday), day >= 6);
+            assert_eq!(nichi.is_weekend(WeekStart::Saturday), matches!(day, 5 | 6));
+        }
+        assert!(!Date::from_ym(2024, 4)
+            .unwrap()
+            .is_weekend(WeekStart::Monday));
+        assert!(!Nichi::UNKNOWN.is_weekend(WeekStart::Monday));
+    }
+
+    #[test]
+    fn week_of_month() {
+        // The first column of a month calendar is the `WeekStart`,
+        // so the week increases on that weekday.
+        for (y, m) in [(2024, 2), (2024, 4), (2024, 6), (2024, 9), (2026, 2)] {
+            for start in WeekStart::ALL {
+                let mut week = 1;
+                for d in 1..=crate::date::free::days_in_month(y, m) {
+                    let nichi = Nichi::new(y, m, d).unwrap();
+                    if d != 1 && Weekday::from(nichi) == start.weekday() {
+                        week += 1;
+                    }
+                    assert_eq!(nichi.week_of_month(start), week, "{nichi} {start:?}");
+                }
+                assert!((4..=6).contains(&week));
+            }
+        }
+
+        // February 2026 starts on a Sunday, and fits in exactly 4 weeks.
+        let date = Date::from_ymd(2026, 2, 28).unwrap();
+        assert_eq!(date.week_of_month(WeekStart::Sunday), 4);
+        assert_eq!(date.week_of_month(WeekStart::Monday), 5);
+
+        // June 2024 starts on a Saturday, and spans 6 weeks with Sundays.
+        let date = Date::from_ymd(2024, 6, 30).unwrap();
+        assert_eq!(date.week_of_month(WeekStart::Sunday), 6);
+        assert_eq!(date.week_of_month(WeekStart::Saturday), 5);
+
+        assert_eq!(
+            Date::from_ym(2024, 6)
+                .unwrap()
+                .week_of_month(WeekStart::Monday),
+            0
+        );
+        assert_eq!(NichiFull::UNKNOWN.week_of_month(WeekStart::Monday), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Weekday::from(6);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[6,"Friday"]"#);
+
+        let this: Weekday = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 6);
+        assert_eq!(this, "Friday");
+    }
+}
@@ -0,0 +1,94 @@
+//! Errors
+
+//---------------------------------------------------------------------------------------------------- Error
+/// The reason a checked constructor failed
+///
+/// Most types have constructors that return [`Self::UNKNOWN`] (or [`Result<Self, Self>`])
+/// on bad input, which is convenient but throws away _why_ it failed.
+///
+/// The `try_*` constructors, e.g. `Date::try_new()` or `Runtime::try_from_secs()`,
+/// return this instead.
+///
+/// It is also the [`std::str::FromStr::Err`] of every type that can be parsed,
+/// so they all work with `str::parse()`.
+///
+/// ```rust
+/// # use readable::Error;
+/// assert_eq!(Error::YearOutOfRange.to_string(), "year is out of range (1000..=9999)");
+/// assert_eq!(Error::NonFinite.to_string(), "input is NaN or infinite");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Error {
+    /// The year was not in-between `1000..=9999`
+    YearOutOfRange,
+    /// The month was not in-between `1..=12`
+    MonthOutOfRange,
+    /// The day was not in-between `1..=31`
+    DayOutOfRange,
+    /// The input was negative
+    Negative,
+    /// The input was [`f64::NAN`], [`f64::INFINITY`] or [`f64::NEG_INFINITY`]
+    NonFinite,
+    /// The input was larger than the type's maximum
+    TooLarge,
+    /// The input string could not be parsed
+    ///
+    /// This is the [`std::str::FromStr::Err`] for all parseable types.
+    Parse,
+}
+
+impl Error {
+    #[inline]
+    #[must_use]
+    /// Returns a short description of the error
+    ///
+    /// This is the same string as the [`std::fmt::Display`] implementation.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::YearOutOfRange => "year is out of range (1000..=9999)",
+            Self::MonthOutOfRange => "month is out of range (1..=12)",
+            Self::DayOutOfRange => "day is out of range (1..=31)",
+            Self::Negative => "input is negative",
+            Self::NonFinite => "input is NaN or infinite",
+            Self::TooLarge => "input is too large",
+            Self::Parse => "input could not be parsed",
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl std::error::Error for Error {}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error() {
+        let err: Box<dyn std::error::Error> = Box::new(Error::TooLarge);
+        assert_eq!(err.to_string(), "input is too large");
+        assert_eq!(format!("{:>20}", Error::Negative), "   input is negative");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let json = serde_json::to_string(&Error::DayOutOfRange).unwrap();
+        assert_eq!(json, r#""DayOutOfRange""#);
+        let this: Error = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, Error::DayOutOfRange);
+    }
+}
@@ -0,0 +1,346 @@
+//! GUI integration
+//!
+//! This module has no items, it implements the text conversion
+//! traits of GUI libraries for all `readable` types so that they
+//! can be passed directly to widget builders.
+//!
+//! ## `egui`
+//! With the `egui` feature, `egui::WidgetText` implements [`From`] for all types, and references to them.
+//!
+//! This means any `readable` type can be used as an `impl Into<egui::WidgetText>`, e.g. `ui.label(uptime)`.
+//!
+//! ```rust
+//! # #[cfg(feature = "egui")] {
+//! use readable::up::Uptime;
+//!
+//! let uptime = Uptime::from(86461_u32);
+//! let text = egui::WidgetText::from(&uptime);
+//! assert_eq!(text.text(), "1d, 1m, 1s");
+//! # }
+//! ```
+//!
+//! ## `iced`
+//! With the `iced` feature, all types, and references to them, implement `iced::widget::text::IntoFragment`.
+//!
+//! References are borrowed without allocating, owned values are copied into a [`String`].
+//! [`NumeralStr`] converts its digits while formatting, so it is always a [`String`].
+//!
+//! ```rust
+//! # #[cfg(feature = "iced")] {
+//! use iced_core::text::IntoFragment;
+//! use readable::num::Unsigned;
+//!
+//! let unsigned = Unsigned::from(1000_u64);
+//! assert_eq!(unsigned.into_fragment(), "1,000");
+//! assert!(matches!((&unsigned).into_fragment(), std::borrow::Cow::Borrowed("1,000")));
+//! # }
+//! ```
+
+//---------------------------------------------------------------------------------------------------- Use
+#[cfg(feature = "num")]
+use crate::num::{FloatSig, ZeroPad};
+#[cfg(feature = "base64")]
+use crate::str::Base64Str;
+use crate::str::{HexStr, NumeralStr, ShortHash, SmartStr, Str};
+use crate::toa::{Dtoa, Itoa};
+use crate::ReadableOr;
+
+//---------------------------------------------------------------------------------------------------- Macros
+// Implements the GUI traits for types with an `as_str()`.
+macro_rules! impl_gui {
+	($($type:ty),* $(,)?) => {
+		$(
+			#[cfg(feature = "egui")]
+			impl From<$type> for egui::WidgetText {
+				#[inline]
+				fn from(value: $type) -> Self {
+					Self::from(value.as_str())
+				}
+			}
+
+			#[cfg(feature = "egui")]
+			impl From<&$type> for egui::WidgetText {
+				#[inline]
+				fn from(value: &$type) -> Self {
+					Self::from(value.as_str())
+				}
+			}
+
+			#[cfg(feature = "iced")]
+			impl<'a> iced_core::text::IntoFragment<'a> for $type {
+				#[inline]
+				fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+					iced_core::text::Fragment::Owned(self.as_str().to_owned())
+				}
+			}
+
+			#[cfg(feature = "iced")]
+			impl<'a> iced_core::text::IntoFragment<'a> for &'a $type {
+				#[inline]
+				fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+					iced_core::text::Fragment::Borrowed(self.as_str())
+				}
+			}
+		)*
+	};
+}
+
+// Same as `impl_gui` but for the `const N: usize` string types.
+macro_rules! impl_gui_const {
+	($($type:ident),* $(,)?) => {
+		$(
+			#[cfg(feature = "egui")]
+			impl<const N: usize> From<$type<N>> for egui::WidgetText {
+				#[inline]
+				fn from(value: $type<N>) -> Self {
+					Self::from(value.as_str())
+				}
+			}
+
+			#[cfg(feature = "egui")]
+			impl<const N: usize> From<&$type<N>> for egui::WidgetText {
+				#[inline]
+				fn from(value: &$type<N>) -> Self {
+					Self::from(value.as_str())
+				}
+			}
+
+			#[cfg(feature = "iced")]
+			impl<'a, const N: usize> iced_core::text::IntoFragment<'a> for $type<N> {
+				#[inline]
+				fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+					iced_core::text::Fragment::Owned(self.as_str().to_owned())
+				}
+			}
+
+			#[cfg(feature = "iced")]
+			impl<'a, const N: usize> iced_core::text::IntoFragment<'a> for &'a $type<N> {
+				#[inline]
+				fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+					iced_core::text::Fragment::Borrowed(self.as_str())
+				}
+			}
+		)*
+	};
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl_gui_const!(Str, SmartStr, HexStr);
+#[cfg(feature = "base64")]
+impl_gui_const!(Base64Str);
+#[cfg(feature = "num")]
+impl_gui_const!(ZeroPad, FloatSig);
+impl_gui!(Itoa, Dtoa, ShortHash);
+
+#[cfg(feature = "num")]
+impl_gui!(
+    crate::num::Unsigned,
+    crate::num::Int,
+    crate::num::Unsigned128,
+    crate::num::Int128,
+    crate::num::Float,
+    crate::num::Percent,
+    crate::num::Permille,
+    crate::num::Ppm,
+    crate::num::PercentBar,
+    crate::num::Sparkline,
+    crate::num::Separated,
+    crate::num::Fraction,
+    crate::num::Accounting,
+    crate::num::Measure,
+);
+
+#[cfg(feature = "byte")]
+impl_gui!(crate::byte::Byte);
+#[cfg(all(feature = "byte", feature = "num"))]
+impl_gui!(crate::byte::Progress);
+
+#[cfg(feature = "run")]
+impl_gui!(
+    crate::run::Runtime,
+    crate::run::RuntimePad,
+    crate::run::RuntimeHours,
+    crate::run::RuntimeUnits,
+    crate::run::RuntimeMilli,
+    crate::run::RuntimeUnion,
+    crate::run::Timecode,
+);
+
+#[cfg(feature = "up")]
+impl_gui!(
+    crate::up::Uptime,
+    crate::up::UptimeFull,
+    crate::up::Htop,
+    crate::up::UptimeCompact,
+    crate::up::Eta,
+    crate::up::UptimeLive,
+    crate::up::UptimeApprox,
+    crate::up::Idle,
+);
+#[cfg(all(feature = "up", feature = "date"))]
+impl_gui!(crate::up::UptimeSince);
+
+#[cfg(feature = "sys")]
+impl_gui!(crate::sys::SysLoadAvg, crate::sys::SysMem);
+#[cfg(feature = "unit")]
+impl_gui!(
+    crate::unit::Coord,
+    crate::unit::Distance,
+    crate::unit::Mass,
+    crate::unit::Volume
+);
+#[cfg(feature = "net")]
+impl_gui!(crate::net::Ip, crate::net::SockAddr);
+
+#[cfg(feature = "date")]
+impl_gui!(
+    crate::date::Date,
+    crate::date::Nichi,
+    crate::date::NichiFull,
+    crate::date::NichiWareki,
+    crate::date::Quarter,
+    crate::date::Month,
+    crate::date::Weekday,
+);
+
+#[cfg(feature = "calendar")]
+impl_gui!(crate::date::DateHebrew, crate::date::DateHijri);
+
+#[cfg(feature = "time")]
+impl_gui!(
+    crate::time::Time,
+    crate::time::Military,
+    crate::time::ClockLive,
+    crate::time::MilitaryLive,
+);
+
+// `ReadableOr` is generic over both of its variants.
+#[cfg(feature = "egui")]
+impl<A: AsRef<str>, B: AsRef<str>> From<ReadableOr<A, B>> for egui::WidgetText {
+    #[inline]
+    fn from(value: ReadableOr<A, B>) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+#[cfg(feature = "egui")]
+impl<A: AsRef<str>, B: AsRef<str>> From<&ReadableOr<A, B>> for egui::WidgetText {
+    #[inline]
+    fn from(value: &ReadableOr<A, B>) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+#[cfg(feature = "iced")]
+impl<'a, A: AsRef<str>, B: AsRef<str>> iced_core::text::IntoFragment<'a> for ReadableOr<A, B> {
+    #[inline]
+    fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+        iced_core::text::Fragment::Owned(self.as_str().to_owned())
+    }
+}
+
+#[cfg(feature = "iced")]
+impl<'a, A: AsRef<str>, B: AsRef<str>> iced_core::text::IntoFragment<'a> for &'a ReadableOr<A, B> {
+    #[inline]
+    fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+        iced_core::text::Fragment::Borrowed(self.as_str())
+    }
+}
+
+// `NumeralStr` has no `as_str()`, its digits are converted when formatting.
+#[cfg(feature = "egui")]
+impl From<NumeralStr<'_>> for egui::WidgetText {
+    #[inline]
+    fn from(value: NumeralStr<'_>) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<&NumeralStr<'_>> for egui::WidgetText {
+    #[inline]
+    fn from(value: &NumeralStr<'_>) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+#[cfg(feature = "iced")]
+impl<'a> iced_core::text::IntoFragment<'a> for NumeralStr<'_> {
+    #[inline]
+    fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+        iced_core::text::Fragment::Owned(self.to_string())
+    }
+}
+
+#[cfg(feature = "iced")]
+impl<'a> iced_core::text::IntoFragment<'a> for &NumeralStr<'_> {
+    #[inline]
+    fn into_fragment(self) -> iced_core::text::Fragment<'a> {
+        iced_core::text::Fragment::Owned(self.to_string())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "egui")]
+    fn egui() {
+        let text = egui::WidgetText::from(Str::<4>::from_static_str("str"));
+        assert_eq!(text.text(), "str");
+        let text = egui::WidgetText::from(&SmartStr::<4>::from("smart string"));
+        assert_eq!(text.text(), "smart string");
+        let text = egui::WidgetText::from(Itoa::new(1000_u64));
+        assert_eq!(text.text(), "1000");
+        let text = egui::WidgetText::from(ReadableOr::<&str>::from(None));
+        assert_eq!(text.text(), "N/A");
+
+        #[cfg(feature = "num")]
+        assert_eq!(
+            egui::WidgetText::from(crate::num::Int::from(-1000)).text(),
+            "-1,000"
+        );
+        #[cfg(feature = "time")]
+        assert_eq!(
+            egui::WidgetText::from(&crate::time::Military::new(86399)).text(),
+            "23:59:59"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "iced")]
+    fn iced() {
+        use iced_core::text::IntoFragment;
+        use std::borrow::Cow;
+
+        let s = Str::<4>::from_static_str("str");
+        assert!(matches!((&s).into_fragment(), Cow::Borrowed("str")));
+        assert_eq!(s.into_fragment(), "str");
+
+        let s = SmartStr::<4>::from("smart string");
+        assert!(matches!(
+            (&s).into_fragment(),
+            Cow::Borrowed("smart string")
+        ));
+        assert_eq!(s.into_fragment(), "smart string");
+
+        let s = crate::str::FullWidth::full_width(&"1,000");
+        assert_eq!((&s).into_fragment(), "１,０００");
+        assert_eq!(s.into_fragment(), "１,０００");
+
+        #[cfg(feature = "byte")]
+        assert_eq!(crate::byte::Byte::from(1234).into_fragment(), "1.234 KB");
+        #[cfg(feature = "date")]
+        assert_eq!(
+            (&crate::date::Date::from_ymd(2014, 12, 31).unwrap()).into_fragment(),
+            "2014-12-31"
+        );
+        #[cfg(all(feature = "up", feature = "date"))]
+        assert_eq!(
+            crate::up::UptimeSince::new(86_400, 86_400).into_fragment(),
+            "up since Thu 1970-01-01, 1d"
+        );
+    }
+}
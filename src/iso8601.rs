@@ -0,0 +1,199 @@
+//! ISO 8601 durations, e.g. `P1DT2H3M4S`
+//!
+//! Shared by the `up` and `run` types with `impl_iso8601!()`.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// The maximum length of a formatted duration, e.g. `P49710DT6H28M15S`.
+pub(crate) const LEN: usize = 16;
+
+/// The naive units, the same as `Uptime`.
+const YEAR: f64 = 31_536_000.0;
+const MONTH: f64 = 2_678_400.0;
+const WEEK: f64 = 604_800.0;
+const DAY: f64 = 86_400.0;
+const HOUR: f64 = 3_600.0;
+const MINUTE: f64 = 60.0;
+const SECOND: f64 = 1.0;
+
+//---------------------------------------------------------------------------------------------------- Format
+/// Format `millis` as an ISO 8601 duration.
+///
+/// If `days` is `false`, hours are the largest unit, e.g. `PT25H`.
+pub(crate) fn format(millis: u64, days: bool) -> Str<LEN> {
+    let mut s = Str::new();
+    s.push_char_panic('P');
+
+    if millis == 0 {
+        s.push_str_panic("T0S");
+        return s;
+    }
+
+    let secs = millis / 1000;
+    let milli = millis % 1000;
+    let (day, hour) = if days {
+        (secs / 86_400, secs % 86_400 / 3_600)
+    } else {
+        (0, secs / 3_600)
+    };
+    let minute = secs % 3_600 / 60;
+    let second = secs % 60;
+
+    if day != 0 {
+        s.push_str_panic(itoa!(day));
+        s.push_char_panic('D');
+    }
+
+    if hour == 0 && minute == 0 && second == 0 && milli == 0 {
+        return s;
+    }
+    s.push_char_panic('T');
+
+    if hour != 0 {
+        s.push_str_panic(itoa!(hour));
+        s.push_char_panic('H');
+    }
+    if minute != 0 {
+        s.push_str_panic(itoa!(minute));
+        s.push_char_panic('M');
+    }
+    if second != 0 || milli != 0 {
+        s.push_str_panic(itoa!(second));
+        if milli != 0 {
+            let digits = [milli / 100, milli / 10 % 10, milli % 10];
+            let len = if milli % 100 == 0 {
+                1
+            } else if milli % 10 == 0 {
+                2
+            } else {
+                3
+            };
+            s.push_char_panic('.');
+            for d in &digits[..len] {
+                s.push_char_panic(char::from(b'0' + *d as u8));
+            }
+        }
+        s.push_char_panic('S');
+    }
+
+    s
+}
+
+//---------------------------------------------------------------------------------------------------- Parse
+/// Parse an ISO 8601 duration into seconds, rounded to the millisecond.
+///
+/// Years and months use the naive `365` and `31` days.
+pub(crate) fn parse(string: &str) -> Option<f64> {
+    let rest = string.trim().strip_prefix('P')?;
+
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let date = components(date, &[('Y', YEAR), ('M', MONTH), ('W', WEEK), ('D', DAY)])?;
+    let time = components(time, &[('H', HOUR), ('M', MINUTE), ('S', SECOND)])?;
+
+    // So float error (e.g. `PT0.1H`) can't reach a truncating `From`.
+    Some(((date + time) * 1000.0).round() / 1000.0)
+}
+
+/// Sum `string`'s `[number][designator]` components in seconds.
+///
+/// Designators must follow the order of `units`, and can appear once.
+fn components(string: &str, units: &[(char, f64)]) -> Option<f64> {
+    let mut units = units.iter();
+    let mut total = 0.0;
+    let mut start = 0;
+
+    for (i, c) in string.char_indices() {
+        if c.is_ascii_digit() || c == '.' || c == ',' {
+            continue;
+        }
+        let (_, secs) = units.find(|(unit, _)| *unit == c)?;
+        total += number(&string[start..i])? * secs;
+        start = i + c.len_utf8();
+    }
+
+    // Trailing number without a designator.
+    if start != string.len() {
+        return None;
+    }
+
+    Some(total)
+}
+
+/// Parse `1`, `1.5` or `1,5`.
+pub(crate) fn number(string: &str) -> Option<f64> {
+    let (int, fract) = match string.split_once(['.', ',']) {
+        Some((int, fract)) => (int, fract),
+        None => (string, ""),
+    };
+
+    if int.is_empty() || !int.bytes().chain(fract.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut n: f64 = int.parse().ok()?;
+    let mut scale = 1.0;
+    for b in fract.bytes() {
+        scale /= 10.0;
+        n += f64::from(b - b'0') * scale;
+    }
+    Some(n)
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_parse() {
+        for (millis, days, expected) in [
+            (0, true, "PT0S"),
+            (1_000, true, "PT1S"),
+            (86_400_000, true, "P1D"),
+            (86_400_000, false, "PT24H"),
+            (93_784_000, true, "P1DT2H3M4S"),
+            (93_784_000, false, "PT26H3M4S"),
+            (5_400_000, true, "PT1H30M"),
+            (1_500, false, "PT1.5S"),
+            (1_050, false, "PT1.05S"),
+            (61_555, false, "PT1M1.555S"),
+            (u64::from(u32::MAX) * 1000, true, "P49710DT6H28M15S"),
+        ] {
+            let iso = format(millis, days);
+            assert_eq!(iso, expected);
+            assert_eq!(parse(&iso), Some(millis as f64 / 1000.0));
+        }
+    }
+
+    #[test]
+    fn parse_units() {
+        assert_eq!(parse("PT90M"), Some(5_400.0));
+        assert_eq!(parse("P1W"), Some(WEEK));
+        assert_eq!(parse("P1Y2M"), Some(YEAR + MONTH * 2.0));
+        assert_eq!(parse("P0D"), Some(0.0));
+        assert_eq!(parse("PT0,5H"), Some(1_800.0));
+        assert_eq!(parse(" PT1M "), Some(60.0));
+    }
+
+    #[test]
+    fn parse_err() {
+        for s in [
+            "", "P", "PT", "T1H", "1H", "PT1", "P1H", "PT1D", "PT1S1M", "PT1M1M", "P1.2.3D",
+            "P.5D", "P-1D", "pt1h", "PT1HX",
+        ] {
+            assert_eq!(parse(s), None, "{s}");
+        }
+    }
+}
@@ -111,6 +111,22 @@ pub(crate) mod macros;
 pub mod str;
 pub mod toa;
 pub(crate) use toa::Itoa64;
+// The low-level formatting functions only rely on `str` and `toa`.
+pub mod raw;
+
+mod error;
+pub use error::Error;
+
+mod rounding;
+pub use rounding::Rounding;
+
+mod readable_or;
+pub use readable_or::ReadableOr;
+
+mod readable_fmt;
+
+#[cfg(any(feature = "up", feature = "run"))]
+mod iso8601;
 
 #[cfg(feature = "num")]
 #[cfg_attr(docsrs, doc(cfg(feature = "num")))]
@@ -135,3 +151,28 @@ pub mod date;
 #[cfg(feature = "byte")]
 #[cfg_attr(docsrs, doc(cfg(feature = "byte")))]
 pub mod byte;
+
+#[cfg(feature = "sys")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
+pub mod sys;
+
+#[cfg(feature = "unit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unit")))]
+pub mod unit;
+
+#[cfg(feature = "net")]
+#[cfg_attr(docsrs, doc(cfg(feature = "net")))]
+pub mod net;
+
+#[cfg(feature = "cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+pub mod cache;
+
+pub mod prelude;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(any(feature = "egui", feature = "iced"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "egui", feature = "iced"))))]
+pub mod gui;
@@ -86,6 +86,56 @@ macro_rules! impl_not_const {
 }
 pub(crate) use impl_not_const;
 
+//---------------------------------------------------------------------------------------------------- Batch formatting
+macro_rules! impl_format_slice {
+    ($module:ident, $self:ident, $input:expr) => {
+        /// Format every number in `input` and append them to `output`
+        ///
+        /// `output` is grown once for all of `input`, which is cheaper than
+        /// formatting and pushing one at a time, e.g. for a table column.
+        ///
+        /// ```rust
+        #[doc = concat!("# use readable::", stringify!($module), "::*;")]
+        #[doc = concat!("let input = ", stringify!($input), ";")]
+        /// let mut output = Vec::with_capacity(input.len());
+        ///
+        #[doc = concat!(stringify!($self), "::format_slice(&input, &mut output);")]
+        #[doc = concat!("assert_eq!(output, input.map(", stringify!($self), "::from));")]
+        /// ```
+        pub fn format_slice<T>(input: &[T], output: &mut Vec<Self>)
+        where
+            T: Copy,
+            Self: From<T>,
+        {
+            output.reserve(input.len());
+            output.extend(input.iter().map(|t| Self::from(*t)));
+        }
+
+        #[cfg(feature = "rayon")]
+        /// Same as [`Self::format_slice`], but formatted in parallel with `rayon`
+        ///
+        /// `output` is in the same order as `input`.
+        ///
+        /// ```rust
+        #[doc = concat!("# use readable::", stringify!($module), "::*;")]
+        #[doc = concat!("let input = ", stringify!($input), ";")]
+        /// let mut output = Vec::new();
+        ///
+        #[doc = concat!(stringify!($self), "::par_format_slice(&input, &mut output);")]
+        #[doc = concat!("assert_eq!(output, input.map(", stringify!($self), "::from));")]
+        /// ```
+        pub fn par_format_slice<T>(input: &[T], output: &mut Vec<Self>)
+        where
+            T: Copy + Sync,
+            Self: From<T> + Send,
+        {
+            use rayon::prelude::*;
+            output.par_extend(input.par_iter().map(|t| Self::from(*t)));
+        }
+    };
+}
+pub(crate) use impl_format_slice;
+
 //---------------------------------------------------------------------------------------------------- `usize` functions
 macro_rules! impl_usize {
     () => {
@@ -120,9 +170,229 @@ macro_rules! impl_isize {
 }
 pub(crate) use impl_isize;
 
+//---------------------------------------------------------------------------------------------------- `str` -> `Self`
+// Implements lenient parsing of formatted integer strings.
+macro_rules! impl_from_str {
+    ($type:ident, $num:ty, $example:literal, $parsed:literal) => {
+        #[inline]
+        #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+        /// Parse a formatted number string
+        ///
+        /// Surrounding whitespace is trimmed, and the separators
+        /// `,`, `_` and ` ` are ignored, so formatted output
+        /// (or a user-edited version of it) can be parsed back.
+        ///
+        /// ```rust
+        /// # use readable::num::*;
+        #[doc = concat!("let this = ", stringify!($type), "::from_str(\"", $example, "\").unwrap();")]
+        #[doc = concat!("assert_eq!(this, ", $parsed, ");")]
+        ///
+        #[doc = concat!("assert_eq!(", stringify!($type), "::from_str(\"1_234\").unwrap(), 1234);")]
+        #[doc = concat!("assert_eq!(", stringify!($type), "::from_str(\" 1 234 \").unwrap(), 1234);")]
+        /// ```
+        ///
+        /// # Errors
+        /// If the string is not a number, or is out of range,
+        /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+        ///
+        /// ```rust
+        /// # use readable::num::*;
+        #[doc = concat!("assert!(", stringify!($type), "::from_str(\"1.5\").is_err());")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_str(\"\").is_err());")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_str(\"???\").is_err());")]
+        /// ```
+        pub fn from_str(string: &str) -> Result<Self, Self> {
+            // Longer than any formatted number, anything longer is rejected.
+            const MAX: usize = 64;
+            let mut buf = [0_u8; MAX];
+            let mut len = 0;
+
+            for b in string.trim().bytes() {
+                if matches!(b, b',' | b'_' | b' ') {
+                    continue;
+                }
+                if len == MAX {
+                    return Err(Self::UNKNOWN);
+                }
+                buf[len] = b;
+                len += 1;
+            }
+
+            match std::str::from_utf8(&buf[..len]).map(str::parse::<$num>) {
+                Ok(Ok(n)) => Ok(Self::from(n)),
+                _ => Err(Self::UNKNOWN),
+            }
+        }
+
+        #[inline]
+        #[must_use]
+        /// Same as [`Self::from_str`] but silently returns a [`Self::UNKNOWN`]
+        /// on error that isn't wrapped in a [`Result::Err`].
+        pub fn from_str_silent(string: &str) -> Self {
+            match Self::from_str(string) {
+                Ok(s) | Err(s) => s,
+            }
+        }
+    };
+}
+pub(crate) use impl_from_str;
+
+//---------------------------------------------------------------------------------------------------- ISO 8601 durations
+// Implements `as_iso8601()` and `from_iso8601()` for the `up`/`run` types.
+//
+// `$kind` is how the inner number is turned into milliseconds:
+// - `up` is `u32` seconds, days are the largest unit
+// - `run` is `f32` seconds (truncated), hours are the largest unit
+// - `milli` is `f64` seconds (rounded to the millisecond), hours are the largest unit
+macro_rules! impl_iso8601 {
+    (@millis up, $n:expr) => { u64::from($n) * 1000 };
+    (@millis run, $n:expr) => { $n as u64 * 1000 };
+    (@millis milli, $n:expr) => { ($n * 1000.0).round() as u64 };
+    (@days up) => { true };
+    (@days $kind:ident) => { false };
+
+    ($type:ident, $kind:ident, $module:ident, $input:literal, $iso:literal, $parsed:literal, $inner:literal) => {
+        #[must_use]
+        /// Format as an ISO 8601 duration, e.g. `P1DT2H3M4S`
+        ///
+        /// Zero units are left out, and zero (or `UNKNOWN`) is `PT0S`.
+        ///
+        /// ```rust
+        #[doc = concat!("# use readable::", stringify!($module), "::*;")]
+        #[doc = concat!("assert_eq!(", stringify!($type), "::from(", $input, ").as_iso8601(), \"", $iso, "\");")]
+        #[doc = concat!("assert_eq!(", stringify!($type), "::ZERO.as_iso8601(), \"PT0S\");")]
+        /// ```
+        pub fn as_iso8601(&self) -> $crate::str::Str<{ $crate::iso8601::LEN }> {
+            $crate::iso8601::format(
+                $crate::macros::impl_iso8601!(@millis $kind, self.0),
+                $crate::macros::impl_iso8601!(@days $kind),
+            )
+        }
+
+        /// Parse an ISO 8601 duration, e.g. `PT1H30M`
+        ///
+        /// Years (`Y`) and months (`M`) are the naive `365` and `31` days,
+        /// weeks (`W`) are `7` days, and any unit can have a `.` or `,` decimal.
+        ///
+        /// ```rust
+        #[doc = concat!("# use readable::", stringify!($module), "::*;")]
+        #[doc = concat!("let this = ", stringify!($type), "::from_iso8601(\"", $parsed, "\").unwrap();")]
+        #[doc = concat!("assert_eq!(this, ", $inner, ");")]
+        #[doc = concat!("assert_eq!(", stringify!($type), "::from_iso8601(&this.as_iso8601()).unwrap(), this);")]
+        /// ```
+        ///
+        /// # Errors
+        /// If the string is not an ISO 8601 duration, or is out of range,
+        /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+        ///
+        /// ```rust
+        #[doc = concat!("# use readable::", stringify!($module), "::*;")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_iso8601(\"P\").is_err());")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_iso8601(\"1H30M\").is_err());")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_iso8601(\"PT30M1H\").is_err());")]
+        #[doc = concat!("assert!(", stringify!($type), "::from_iso8601(\"P1000Y\").is_err());")]
+        /// ```
+        pub fn from_iso8601(string: &str) -> Result<Self, Self> {
+            match $crate::iso8601::parse(string).map(Self::from) {
+                Some(this) if !this.is_unknown() => Ok(this),
+                _ => Err(Self::UNKNOWN),
+            }
+        }
+
+        #[inline]
+        #[must_use]
+        /// Same as [`Self::from_iso8601`] but silently returns a [`Self::UNKNOWN`]
+        /// on error that isn't wrapped in a [`Result::Err`].
+        pub fn from_iso8601_silent(string: &str) -> Self {
+            match Self::from_iso8601(string) {
+                Ok(s) | Err(s) => s,
+            }
+        }
+    };
+}
+pub(crate) use impl_iso8601;
+
+//---------------------------------------------------------------------------------------------------- `FromStr`
+// Implements `std::str::FromStr` with an inherent `Result<Self, Self>` parser.
+macro_rules! impl_std_from_str {
+	($type:ty => $parse:ident $(($($arg:expr),*))?) => {
+		impl std::str::FromStr for $type {
+			type Err = $crate::Error;
+
+			#[inline]
+			#[doc = concat!("Same as [`", stringify!($type), "::", stringify!($parse), "`], returning [`Error::Parse`](crate::Error::Parse) on error.")]
+			fn from_str(string: &str) -> Result<Self, Self::Err> {
+				<$type>::$parse(string $($(, $arg)*)?).map_err(|_| $crate::Error::Parse)
+			}
+		}
+	};
+}
+pub(crate) use impl_std_from_str;
+
+//---------------------------------------------------------------------------------------------------- `Option<T>` -> `Self`
+// Implements `From<Option<T>>` for every `T` the type can already be created from.
+//
+// `None` is `Self::UNKNOWN`, so optional data (e.g. a nullable database column)
+// can be formatted without a `map_or()` at every call site.
+macro_rules! impl_from_option {
+	($($s:ty),* $(,)?) => { $(
+		impl<T> From<Option<T>> for $s
+		where
+			$s: From<T>,
+		{
+			#[inline]
+			/// [`Some`] is the same as `From<T>`, [`None`] is [`Self::UNKNOWN`].
+			fn from(option: Option<T>) -> Self {
+				option.map_or(Self::UNKNOWN, Self::from)
+			}
+		}
+	)*}
+}
+pub(crate) use impl_from_option;
+
 //---------------------------------------------------------------------------------------------------- Implement common traits
 macro_rules! impl_traits {
+    // `Display` respects the width, fill, and alignment flags.
     ($s:ty, $num:ty) => {
+        impl std::fmt::Display for $s {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                $crate::str::pad(f, self.1.as_str())
+            }
+        }
+
+        $crate::macros::impl_traits!(@common $s, $num);
+    };
+
+    // Same as above, but the precision flag re-formats
+    // the type with `Self::priv_precision()`, e.g. `{:.1}`.
+    ($s:ty, $num:ty, precision) => {
+        impl std::fmt::Display for $s {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match f.precision() {
+                    Some(p) if self.0.is_finite() && !self.is_unknown() => {
+                        $crate::str::pad(f, Self::priv_precision(self.0, p).1.as_str())
+                    }
+                    _ => $crate::str::pad(f, self.1.as_str()),
+                }
+            }
+        }
+
+        $crate::macros::impl_traits!(@common $s, $num);
+    };
+
+    (@common $s:ty, $num:ty) => {
+        // Both the inner value and the string, e.g. `Runtime { inner: 61.0, str: "1:01" }`.
+        impl std::fmt::Debug for $s {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_struct(stringify!($s))
+                    .field("inner", &self.0)
+                    .field("str", &self.1.as_str())
+                    .finish()
+            }
+        }
+
         impl std::ops::Deref for $s {
             type Target = str;
 
@@ -153,13 +423,6 @@ macro_rules! impl_traits {
             }
         }
 
-        impl std::fmt::Display for $s {
-            #[inline]
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, "{}", &self.1.as_str())
-            }
-        }
-
         impl std::default::Default for $s {
             #[inline]
             /// Returns [`Self::ZERO`]
@@ -354,16 +617,44 @@ pub(crate) use impl_impl_math;
 
 // Implement math operators.
 macro_rules! impl_math {
-	($s:ty, $num:ty) => {
+	($s:ty, $num:ident) => {
 		impl_impl_math!(Add, +, $s, $num);
 		impl_impl_math!(Sub, -, $s, $num);
 		impl_impl_math!(Div, /, $s, $num);
 		impl_impl_math!(Mul, *, $s, $num);
 		impl_impl_math!(Rem, %, $s, $num);
+		$crate::macros::impl_sum!($s, $num);
 	}
 }
 pub(crate) use impl_math;
 
+// Implement `std::iter::Sum` for `Self` and `&Self`.
+//
+// Integers saturate at their `MAX`/`MIN` instead of
+// panicking on overflow, floats are summed normally.
+macro_rules! impl_sum {
+	($s:ty, f32) => { $crate::macros::impl_sum!(@add $s, f32, |a: f32, b: f32| a + b); };
+	($s:ty, f64) => { $crate::macros::impl_sum!(@add $s, f64, |a: f64, b: f64| a + b); };
+	($s:ty, $num:ident) => { $crate::macros::impl_sum!(@add $s, $num, $num::saturating_add); };
+
+	(@add $s:ty, $num:ident, $add:expr) => {
+		impl std::iter::Sum for $s {
+			#[inline]
+			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+				Self::from(iter.fold(0 as $num, |sum, s| ($add)(sum, s.inner())))
+			}
+		}
+
+		impl<'a> std::iter::Sum<&'a $s> for $s {
+			#[inline]
+			fn sum<I: Iterator<Item = &'a $s>>(iter: I) -> Self {
+				Self::from(iter.fold(0 as $num, |sum, s| ($add)(sum, s.inner())))
+			}
+		}
+	};
+}
+pub(crate) use impl_sum;
+
 //---------------------------------------------------------------------------------------------------- Handle bad floats
 macro_rules! return_bad_float {
     ($float:ident, $nan:expr, $infinite:expr) => {
@@ -377,6 +668,63 @@ macro_rules! return_bad_float {
 }
 pub(crate) use return_bad_float;
 
+//---------------------------------------------------------------------------------------------------- Float `Eq`, `Ord` & `Hash`
+// Implements `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` for
+// a `(float, Str)` type with a canonicalized inner float, so:
+// - all `NaN`s are equal, and sort after `inf` (like `f64::total_cmp`)
+// - `-0.0` is equal to `0.0`
+//
+// The string is compared after the float, so
+// the same number with different formatting is not equal.
+macro_rules! impl_float_eq {
+	($($s:ty => $float:ty),* $(,)?) => {
+		$(
+			impl PartialEq for $s {
+				#[inline]
+				fn eq(&self, other: &Self) -> bool {
+					self.cmp(other).is_eq()
+				}
+			}
+
+			impl Eq for $s {}
+
+			impl PartialOrd for $s {
+				#[inline]
+				fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+					Some(self.cmp(other))
+				}
+			}
+
+			impl Ord for $s {
+				#[inline]
+				fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+					$crate::macros::impl_float_eq!(@canonical $float, self.0)
+						.total_cmp(&$crate::macros::impl_float_eq!(@canonical $float, other.0))
+						.then_with(|| self.1.cmp(&other.1))
+				}
+			}
+
+			impl std::hash::Hash for $s {
+				#[inline]
+				fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+					$crate::macros::impl_float_eq!(@canonical $float, self.0).to_bits().hash(state);
+					self.1.hash(state);
+				}
+			}
+		)*
+	};
+
+	(@canonical $float:ty, $f:expr) => {
+		if $f.is_nan() {
+			<$float>::NAN
+		} else {
+			// `-0.0 + 0.0` is `0.0`, everything else is unchanged.
+			$f + 0.0
+		}
+	};
+}
+pub(crate) use impl_float_eq;
+
 //---------------------------------------------------------------------------------------------------- `u64/i64` -> `str`
 macro_rules! str_u64 {
     ($number:expr) => {{
@@ -403,6 +751,256 @@ macro_rules! handle_over_u32 {
 }
 pub(crate) use handle_over_u32;
 
+//---------------------------------------------------------------------------------------------------- `serde_json::Value` -> `Self`
+// Implements `TryFrom<&serde_json::Value>` in terms of `TryFrom<&serde_json::Number>`.
+#[cfg(feature = "serde_json")]
+macro_rules! impl_json_value {
+	($($type:ty),*) => {
+		$(
+			/// This will return [`Self::UNKNOWN`] wrapped in [`Result::Err`]
+			/// if the [`serde_json::Value`] is not a number or numeric string.
+			impl TryFrom<&serde_json::Value> for $type {
+				type Error = Self;
+				#[inline]
+				fn try_from(value: &serde_json::Value) -> Result<Self, Self> {
+					match value {
+						serde_json::Value::Number(n) => Self::try_from(n),
+						serde_json::Value::String(s) => match s.trim().parse::<serde_json::Number>() {
+							Ok(n) => Self::try_from(&n),
+							Err(_) => Err(Self::UNKNOWN),
+						},
+						_ => Err(Self::UNKNOWN),
+					}
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "serde_json")]
+pub(crate) use impl_json_value;
+
+//---------------------------------------------------------------------------------------------------- SQL
+// Borrow a `u64` as the `i64` that SQL `BIGINT`s are, `None` if it is over `i64::MAX`.
+//
+// `diesel::ToSql` needs a reference that lives as long as `self`, so this can't be a copy.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+pub(crate) const fn u64_as_i64(u: &u64) -> Option<&i64> {
+    if *u > i64::MAX as u64 {
+        return None;
+    }
+    // SAFETY: `u64` and `i64` have the same size and alignment,
+    // and the same bits are the same number when `<= i64::MAX`.
+    Some(unsafe { &*(u as *const u64).cast::<i64>() })
+}
+
+// Implements `sqlx::{Type, Decode, Encode}`, the type is stored as a `$sql` column.
+//
+// `NULL` is decoded as `Self::UNKNOWN`, and `Self::UNKNOWN` is encoded as `NULL`,
+// everything else goes through the type's `priv_from_sql()` and `priv_to_sql()`.
+#[cfg(feature = "sqlx")]
+macro_rules! impl_sqlx {
+	($s:ty => str) => {
+		$crate::macros::impl_sqlx!(@type $s => str);
+
+		impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for $s
+		where
+			&'r str: sqlx::Decode<'r, DB>,
+		{
+			fn decode(value: <DB as sqlx::Database>::ValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+				if sqlx::ValueRef::is_null(&value) {
+					return Ok(Self::UNKNOWN);
+				}
+				<&str as sqlx::Decode<'r, DB>>::decode(value).map(Self::priv_from_sql)
+			}
+		}
+
+		impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for $s
+		where
+			String: sqlx::Encode<'q, DB>,
+		{
+			fn encode_by_ref(
+				&self,
+				buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+			) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+				if self.is_unknown() {
+					return Ok(sqlx::encode::IsNull::Yes);
+				}
+				match self.priv_to_sql() {
+					// A `&str` would have to live as long as the buffer.
+					Some(s) => sqlx::Encode::<'q, DB>::encode(s.to_owned(), buf),
+					None => Err(concat!(stringify!($s), " is out of range for the column type").into()),
+				}
+			}
+		}
+	};
+
+	($s:ty => $sql:ty) => {
+		$crate::macros::impl_sqlx!(@type $s => $sql);
+
+		impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for $s
+		where
+			$sql: sqlx::Decode<'r, DB>,
+		{
+			fn decode(value: <DB as sqlx::Database>::ValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+				if sqlx::ValueRef::is_null(&value) {
+					return Ok(Self::UNKNOWN);
+				}
+				<$sql as sqlx::Decode<'r, DB>>::decode(value).map(Self::priv_from_sql)
+			}
+		}
+
+		impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for $s
+		where
+			$sql: sqlx::Encode<'q, DB>,
+		{
+			fn encode_by_ref(
+				&self,
+				buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+			) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+				if self.is_unknown() {
+					return Ok(sqlx::encode::IsNull::Yes);
+				}
+				match self.priv_to_sql() {
+					Some(v) => sqlx::Encode::<'q, DB>::encode_by_ref(v, buf),
+					None => Err(concat!(stringify!($s), " is out of range for the column type").into()),
+				}
+			}
+		}
+	};
+
+	(@type $s:ty => $sql:ty) => {
+		impl<DB: sqlx::Database> sqlx::Type<DB> for $s
+		where
+			$sql: sqlx::Type<DB>,
+		{
+			#[inline]
+			fn type_info() -> DB::TypeInfo {
+				<$sql as sqlx::Type<DB>>::type_info()
+			}
+
+			#[inline]
+			fn compatible(ty: &DB::TypeInfo) -> bool {
+				<$sql as sqlx::Type<DB>>::compatible(ty)
+			}
+		}
+	};
+}
+#[cfg(feature = "sqlx")]
+pub(crate) use impl_sqlx;
+
+// Implements `diesel::{FromSql, ToSql}` for the `$st` SQL type and `diesel::FromSql` for its
+// `Nullable` version, the type must also derive `diesel::{AsExpression, FromSqlRow}`.
+//
+// Same as `impl_sqlx!()`, `NULL` <-> `Self::UNKNOWN`.
+#[cfg(feature = "diesel")]
+macro_rules! impl_diesel {
+	($s:ty => str as $st:ident) => {
+		$crate::macros::impl_diesel!(@nullable $s => $st);
+
+		impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::$st, DB> for $s
+		where
+			String: diesel::deserialize::FromSql<diesel::sql_types::$st, DB>,
+		{
+			fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+				let s = <String as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_sql(bytes)?;
+				Ok(Self::priv_from_sql(&s))
+			}
+
+			fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> diesel::deserialize::Result<Self> {
+				match bytes {
+					Some(bytes) => <Self as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_sql(bytes),
+					None => Ok(Self::UNKNOWN),
+				}
+			}
+		}
+
+		$crate::macros::impl_diesel!(@to_sql $s => str as $st);
+	};
+
+	($s:ty => $sql:ty as $st:ident) => {
+		$crate::macros::impl_diesel!(@nullable $s => $st);
+
+		impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::$st, DB> for $s
+		where
+			$sql: diesel::deserialize::FromSql<diesel::sql_types::$st, DB>,
+		{
+			fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+				<$sql as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_sql(bytes).map(Self::priv_from_sql)
+			}
+
+			fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> diesel::deserialize::Result<Self> {
+				match bytes {
+					Some(bytes) => <Self as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_sql(bytes),
+					None => Ok(Self::UNKNOWN),
+				}
+			}
+		}
+
+		$crate::macros::impl_diesel!(@to_sql $s => $sql as $st);
+	};
+
+	(@to_sql $s:ty => $sql:ty as $st:ident) => {
+		impl<DB: diesel::backend::Backend> diesel::serialize::ToSql<diesel::sql_types::$st, DB> for $s
+		where
+			$sql: diesel::serialize::ToSql<diesel::sql_types::$st, DB>,
+		{
+			fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, DB>) -> diesel::serialize::Result {
+				if self.is_unknown() {
+					return Ok(diesel::serialize::IsNull::Yes);
+				}
+				match self.priv_to_sql() {
+					Some(v) => <$sql as diesel::serialize::ToSql<diesel::sql_types::$st, DB>>::to_sql(v, out),
+					None => Err(concat!(stringify!($s), " is out of range for the column type").into()),
+				}
+			}
+		}
+	};
+
+	(@nullable $s:ty => $st:ident) => {
+		impl<DB: diesel::backend::Backend> diesel::deserialize::FromSql<diesel::sql_types::Nullable<diesel::sql_types::$st>, DB> for $s
+		where
+			Self: diesel::deserialize::FromSql<diesel::sql_types::$st, DB>,
+		{
+			#[inline]
+			fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+				<Self as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_sql(bytes)
+			}
+
+			#[inline]
+			fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> diesel::deserialize::Result<Self> {
+				<Self as diesel::deserialize::FromSql<diesel::sql_types::$st, DB>>::from_nullable_sql(bytes)
+			}
+		}
+	};
+}
+#[cfg(feature = "diesel")]
+pub(crate) use impl_diesel;
+
+//---------------------------------------------------------------------------------------------------- `clap`
+// Implements `clap::builder::ValueParserFactory` with the type's `FromStr`,
+// so `value_parser!($s)` (and `#[derive(clap::Parser)]` fields) just work.
+//
+// The error shown after clap's `invalid value '...' for '--arg'` is `expected $expected`.
+#[cfg(feature = "clap")]
+macro_rules! impl_clap {
+    ($s:ty, $expected:literal) => {
+        impl clap::builder::ValueParserFactory for $s {
+            type Parser = clap::builder::ValueParser;
+
+            #[inline]
+            fn value_parser() -> Self::Parser {
+                clap::builder::ValueParser::new(|string: &str| {
+                    string
+                        .parse::<Self>()
+                        .map_err(|_| concat!("expected ", $expected))
+                })
+            }
+        }
+    };
+}
+#[cfg(feature = "clap")]
+pub(crate) use impl_clap;
+
 // //---------------------------------------------------------------------------------------------------- serde impl
 // // Macro to implement all the serde functions.
 // macro_rules! impl_serde {
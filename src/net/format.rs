@@ -0,0 +1,51 @@
+//---------------------------------------------------------------------------------------------------- IpFormat
+/// How [`Ip`](crate::net::Ip) and [`SockAddr`](crate::net::SockAddr) format addresses
+///
+/// ```rust
+/// # use readable::net::*;
+/// # use std::net::*;
+/// let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+///
+/// assert_eq!(Ip::with_format(addr.into(), IpFormat::DEFAULT), "2001:db8::1");
+///
+/// let format = IpFormat {
+///     compress: false,
+///     width: 24,
+/// };
+/// assert_eq!(Ip::with_format(addr.into(), format), "2001:db8:0:0:0:0:0:1    ");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IpFormat {
+    /// Compress the longest run of zero IPv6 groups into `::`
+    ///
+    /// This has no effect on IPv4 addresses.
+    pub compress: bool,
+    /// Pad the string with trailing spaces to this width
+    ///
+    /// This is capped at the type's `MAX_LEN`, `0` means no padding.
+    pub width: u8,
+}
+
+impl IpFormat {
+    /// Compressed, no padding, the same as [`std::net`]'s [`Display`](std::fmt::Display)
+    ///
+    /// This is used by `new()` and [`From`].
+    pub const DEFAULT: Self = Self {
+        compress: true,
+        width: 0,
+    };
+}
+
+impl Default for IpFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,196 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::net::IpFormat;
+use crate::str::Str;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+//---------------------------------------------------------------------------------------------------- Ip
+/// Human-readable IP address
+///
+/// This wraps an [`IpAddr`] and stores its formatted string inline,
+/// so it can be copied around instead of a [`String`].
+///
+/// ```rust
+/// # use readable::net::*;
+/// # use std::net::*;
+/// assert_eq!(Ip::from(Ipv4Addr::new(192, 168, 0, 1)), "192.168.0.1");
+/// assert_eq!(Ip::from(Ipv6Addr::LOCALHOST), "::1");
+///
+/// // Arrays work too.
+/// assert_eq!(Ip::from([10, 0, 0, 1]), "10.0.0.1");
+///
+/// // The inner address is kept.
+/// let ip = Ip::from(Ipv4Addr::LOCALHOST);
+/// assert_eq!(ip, IpAddr::V4(Ipv4Addr::LOCALHOST));
+/// assert!(ip.inner().is_loopback());
+/// ```
+///
+/// ## Format
+/// See [`IpFormat`] and [`Ip::with_format`].
+///
+/// ## Size
+/// [`Str<39>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::net::*;
+/// assert_eq!(std::mem::size_of::<Ip>(), 57);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Ip(IpAddr, Str<{ Ip::MAX_LEN }>);
+
+impl_traits!(Ip, IpAddr);
+
+//---------------------------------------------------------------------------------------------------- Ip Constants
+impl Ip {
+    /// The maximum string length of an [`Ip`]
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// let max = Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff);
+    /// assert_eq!(Ip::from(max).len(), Ip::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 39;
+
+    /// The unspecified IPv4 address
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// assert_eq!(Ip::ZERO, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    /// assert_eq!(Ip::ZERO, "0.0.0.0");
+    /// ```
+    pub const ZERO: Self = Self(
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        Str::from_static_str("0.0.0.0"),
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- Ip Impl
+impl Ip {
+    impl_common!(IpAddr);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Ip::with_format`] with [`IpFormat::DEFAULT`]
+    pub fn new(addr: IpAddr) -> Self {
+        Self::with_format(addr, IpFormat::DEFAULT)
+    }
+
+    #[must_use]
+    /// Format `addr` with `format`
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// let addr = IpAddr::from(Ipv6Addr::UNSPECIFIED);
+    /// let format = IpFormat {
+    ///     compress: false,
+    ///     width: 0,
+    /// };
+    /// assert_eq!(Ip::with_format(addr, format), "0:0:0:0:0:0:0:0");
+    ///
+    /// // The width is capped at `Ip::MAX_LEN`.
+    /// let format = IpFormat {
+    ///     compress: true,
+    ///     width: u8::MAX,
+    /// };
+    /// assert_eq!(Ip::with_format(addr, format).len(), Ip::MAX_LEN);
+    /// ```
+    pub fn with_format(addr: IpAddr, format: IpFormat) -> Self {
+        let mut string = Str::new();
+        super::push_ip(&mut string, addr, format.compress);
+        super::pad_width(&mut string, format.width);
+        Self(addr, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<IpAddr> for Ip {
+    #[inline]
+    fn from(addr: IpAddr) -> Self {
+        Self::new(addr)
+    }
+}
+
+impl From<Ipv4Addr> for Ip {
+    #[inline]
+    fn from(addr: Ipv4Addr) -> Self {
+        Self::new(IpAddr::V4(addr))
+    }
+}
+
+impl From<Ipv6Addr> for Ip {
+    #[inline]
+    fn from(addr: Ipv6Addr) -> Self {
+        Self::new(IpAddr::V6(addr))
+    }
+}
+
+impl From<[u8; 4]> for Ip {
+    #[inline]
+    fn from(octets: [u8; 4]) -> Self {
+        Self::new(IpAddr::from(octets))
+    }
+}
+
+impl From<[u16; 8]> for Ip {
+    #[inline]
+    fn from(segments: [u16; 8]) -> Self {
+        Self::new(IpAddr::from(segments))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn std() {
+        // The default format must match `std`.
+        for addr in [
+            IpAddr::from([0, 0, 0, 0]),
+            IpAddr::from([255, 255, 255, 255]),
+            IpAddr::from(Ipv6Addr::UNSPECIFIED),
+            IpAddr::from(Ipv6Addr::LOCALHOST),
+            IpAddr::from([0x2001, 0xdb8, 0, 0, 1, 0, 0, 1]),
+            IpAddr::from([0xfe80, 0, 0, 0, 0x1ff, 0xfe23, 0x4567, 0x890a]),
+            IpAddr::from(Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped()),
+        ] {
+            assert_eq!(Ip::from(addr).as_str(), addr.to_string());
+        }
+    }
+
+    #[test]
+    fn full() {
+        let format = IpFormat {
+            compress: false,
+            width: 0,
+        };
+        let addr = IpAddr::from(Ipv4Addr::new(1, 2, 3, 4).to_ipv6_mapped());
+        assert_eq!(Ip::with_format(addr, format), "0:0:0:0:0:ffff:102:304");
+
+        let max = IpAddr::from([0xffff; 8]);
+        assert_eq!(Ip::with_format(max, format).len(), Ip::MAX_LEN);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Ip::from([127, 0, 0, 1]);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"["127.0.0.1","127.0.0.1"]"#);
+
+        let this: Ip = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, IpAddr::from([127, 0, 0, 1]));
+        assert_eq!(this, "127.0.0.1");
+    }
+}
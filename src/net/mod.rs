@@ -0,0 +1,76 @@
+//! Network address formatting
+//!
+//! Copyable, stack-allocated display types for [`std::net`] addresses, e.g:
+//! ```rust
+//! # use readable::net::*;
+//! # use std::net::*;
+//! assert_eq!(Ip::from(Ipv4Addr::LOCALHOST), "127.0.0.1");
+//! assert_eq!(Ip::from(Ipv6Addr::LOCALHOST), "::1");
+//!
+//! let addr = SocketAddr::from(([192, 168, 0, 1], 8080));
+//! assert_eq!(SockAddr::from(addr), "192.168.0.1:8080");
+//! ```
+//!
+//! These are meant for tables/TUIs that store display cells
+//! instead of allocating a new [`String`] for every address.
+//!
+//! ## Format
+//! [`IpFormat`] controls IPv6 `::` compression and width padding.
+//!
+//! ```rust
+//! # use readable::net::*;
+//! # use std::net::*;
+//! let format = IpFormat {
+//!     compress: false,
+//!     width: 0,
+//! };
+//! assert_eq!(Ip::with_format(Ipv6Addr::LOCALHOST.into(), format), "0:0:0:0:0:0:0:1");
+//!
+//! // Padded with trailing spaces, so columns line up.
+//! let format = IpFormat {
+//!     width: 15,
+//!     ..IpFormat::DEFAULT
+//! };
+//! assert_eq!(Ip::with_format(Ipv4Addr::LOCALHOST.into(), format), "127.0.0.1      ");
+//! assert_eq!(Ip::with_format([10, 0, 0, 1].into(), format), "10.0.0.1       ");
+//! ```
+
+mod format;
+pub use format::*;
+
+mod ip;
+pub use ip::*;
+
+mod sockaddr;
+pub use sockaddr::*;
+
+//---------------------------------------------------------------------------------------------------- Private
+use crate::str::Str;
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv6Addr};
+
+/// Push `addr`, with or without `::` compression.
+fn push_ip<const N: usize>(string: &mut Str<N>, addr: IpAddr, compress: bool) {
+    match addr {
+        IpAddr::V6(v6) if !compress => push_ipv6_full(string, v6),
+        _ => write!(string, "{addr}").unwrap(),
+    }
+}
+
+/// Push all 8 groups of `addr`, e.g. `2001:db8:0:0:0:0:0:1`.
+fn push_ipv6_full<const N: usize>(string: &mut Str<N>, addr: Ipv6Addr) {
+    for (i, segment) in addr.segments().iter().enumerate() {
+        if i != 0 {
+            string.push_char_panic(':');
+        }
+        write!(string, "{segment:x}").unwrap();
+    }
+}
+
+/// Pad `string` with trailing spaces up to `width`, capped at `N`.
+fn pad_width<const N: usize>(string: &mut Str<N>, width: u8) {
+    let width = usize::from(width).min(N);
+    while string.len() < width {
+        string.push_char_panic(' ');
+    }
+}
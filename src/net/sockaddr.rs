@@ -0,0 +1,210 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::net::{Ip, IpFormat};
+use crate::str::Str;
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+//---------------------------------------------------------------------------------------------------- SockAddr
+/// Human-readable socket address
+///
+/// This wraps a [`SocketAddr`] and stores its formatted string inline,
+/// so it can be copied around instead of a [`String`].
+///
+/// IPv6 addresses are wrapped in `[]`, and the scope ID is included if it is not `0`.
+///
+/// ```rust
+/// # use readable::net::*;
+/// # use std::net::*;
+/// let addr = SocketAddr::from(([127, 0, 0, 1], 8080));
+/// assert_eq!(SockAddr::from(addr), "127.0.0.1:8080");
+///
+/// let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, 443));
+/// assert_eq!(SockAddr::from(addr), "[::1]:443");
+///
+/// let addr = SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 22, 0, 3);
+/// assert_eq!(SockAddr::from(addr), "[fe80::1%3]:22");
+///
+/// // The inner address is kept.
+/// let sock = SockAddr::from(([10, 0, 0, 1], 53));
+/// assert_eq!(sock.port(), 53);
+/// assert_eq!(sock.ip(), "10.0.0.1");
+/// ```
+///
+/// ## Format
+/// See [`IpFormat`] and [`SockAddr::with_format`].
+///
+/// ## Size
+/// [`Str<58>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::net::*;
+/// assert_eq!(std::mem::size_of::<SockAddr>(), 92);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct SockAddr(SocketAddr, Str<{ SockAddr::MAX_LEN }>);
+
+impl_traits!(SockAddr, SocketAddr);
+
+//---------------------------------------------------------------------------------------------------- SockAddr Constants
+impl SockAddr {
+    /// The maximum string length of a [`SockAddr`]
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// let max = Ipv6Addr::new(0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff);
+    /// let max = SocketAddrV6::new(max, u16::MAX, 0, u32::MAX);
+    /// assert_eq!(SockAddr::from(max).len(), SockAddr::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 58;
+
+    /// The unspecified IPv4 address with port `0`
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// assert_eq!(SockAddr::ZERO, SocketAddr::from(([0, 0, 0, 0], 0)));
+    /// assert_eq!(SockAddr::ZERO, "0.0.0.0:0");
+    /// ```
+    pub const ZERO: Self = Self(
+        SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)),
+        Str::from_static_str("0.0.0.0:0"),
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- SockAddr Impl
+impl SockAddr {
+    impl_common!(SocketAddr);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns the port of the inner [`SocketAddr`]
+    pub const fn port(&self) -> u16 {
+        self.0.port()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the IP of the inner [`SocketAddr`] as an [`Ip`]
+    pub fn ip(&self) -> Ip {
+        Ip::new(self.0.ip())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`SockAddr::with_format`] with [`IpFormat::DEFAULT`]
+    pub fn new(addr: SocketAddr) -> Self {
+        Self::with_format(addr, IpFormat::DEFAULT)
+    }
+
+    #[must_use]
+    /// Format `addr` with `format`
+    ///
+    /// ```rust
+    /// # use readable::net::*;
+    /// # use std::net::*;
+    /// let addr = SocketAddr::from((Ipv6Addr::LOCALHOST, 443));
+    /// let format = IpFormat {
+    ///     compress: false,
+    ///     width: 0,
+    /// };
+    /// assert_eq!(SockAddr::with_format(addr, format), "[0:0:0:0:0:0:0:1]:443");
+    ///
+    /// let addr = SocketAddr::from(([127, 0, 0, 1], 80));
+    /// let format = IpFormat {
+    ///     compress: true,
+    ///     width: 21,
+    /// };
+    /// assert_eq!(SockAddr::with_format(addr, format), "127.0.0.1:80         ");
+    /// ```
+    pub fn with_format(addr: SocketAddr, format: IpFormat) -> Self {
+        let mut string = Str::new();
+
+        match addr {
+            SocketAddr::V4(v4) => write!(string, "{v4}").unwrap(),
+            SocketAddr::V6(v6) => {
+                string.push_char_panic('[');
+                super::push_ip(&mut string, IpAddr::V6(*v6.ip()), format.compress);
+                if v6.scope_id() != 0 {
+                    write!(string, "%{}", v6.scope_id()).unwrap();
+                }
+                write!(string, "]:{}", v6.port()).unwrap();
+            }
+        }
+
+        super::pad_width(&mut string, format.width);
+        Self(addr, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<SocketAddr> for SockAddr {
+    #[inline]
+    fn from(addr: SocketAddr) -> Self {
+        Self::new(addr)
+    }
+}
+
+impl From<SocketAddrV4> for SockAddr {
+    #[inline]
+    fn from(addr: SocketAddrV4) -> Self {
+        Self::new(SocketAddr::V4(addr))
+    }
+}
+
+impl From<SocketAddrV6> for SockAddr {
+    #[inline]
+    fn from(addr: SocketAddrV6) -> Self {
+        Self::new(SocketAddr::V6(addr))
+    }
+}
+
+impl<I: Into<IpAddr>> From<(I, u16)> for SockAddr {
+    #[inline]
+    fn from(addr: (I, u16)) -> Self {
+        Self::new(SocketAddr::from(addr))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn std() {
+        // The default format must match `std`.
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1);
+        for addr in [
+            SocketAddr::from(([0, 0, 0, 0], 0)),
+            SocketAddr::from(([255, 255, 255, 255], u16::MAX)),
+            SocketAddr::from((v6, 8080)),
+            SocketAddr::V6(SocketAddrV6::new(v6, 1, 0, 0)),
+            SocketAddr::V6(SocketAddrV6::new(v6, 1, 0, 42)),
+            SocketAddr::V6(SocketAddrV6::new(v6, 1, 7, u32::MAX)),
+        ] {
+            assert_eq!(SockAddr::from(addr).as_str(), addr.to_string());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = SockAddr::from(([127, 0, 0, 1], 80));
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"["127.0.0.1:80","127.0.0.1:80"]"#);
+
+        let this: SockAddr = serde_json::from_str(&json).unwrap();
+        assert_eq!(this.port(), 80);
+        assert_eq!(this, "127.0.0.1:80");
+    }
+}
@@ -0,0 +1,330 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_from_option, impl_impl_math, impl_math,
+    impl_traits,
+};
+use crate::num::{Float, Int, Unsigned};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Accounting
+/// Human readable number in accounting style.
+///
+/// This is the same as [`Int`] and [`Float`], except
+/// negative numbers are wrapped in parentheses instead
+/// of being prefixed with `-`.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Accounting::from(1_234),       "1,234");
+/// assert_eq!(Accounting::from(-1_234),      "(1,234)");
+/// assert_eq!(Accounting::from(-1_234.56),   "(1,234.560)");
+/// assert_eq!(Accounting::from(Float::from_2(-1_234.56)), "(1,234.56)");
+/// ```
+///
+/// ## Creation
+/// - Integers and [`Int`] are formatted with no decimals
+/// - Floats are formatted with `3` decimals, same as [`Float::from`]
+/// - [`Float`] keeps the amount of decimals it was created with
+///
+/// A negative value that rounds to zero is not wrapped in parentheses.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Accounting::from(-0.0001), "0.000");
+/// ```
+///
+/// ## Unknown
+/// [`Accounting::UNKNOWN`] is returned if the input is:
+/// - [`Int::UNKNOWN`] or [`Float::UNKNOWN`]
+/// - [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`] (or the [`f32`] versions)
+/// - Too large for [`Float`] to format
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Accounting::from(Int::UNKNOWN).is_unknown());
+/// assert!(Accounting::from(Float::UNKNOWN).is_unknown());
+/// assert!(Accounting::from(f64::NAN).is_unknown());
+/// assert!(Accounting::from(f64::NEG_INFINITY).is_unknown());
+/// ```
+///
+/// ## Warning
+/// The inner number is always an [`f64`], so integers
+/// larger than `2^53` may lose precision in the inner number.
+///
+/// The [`String`] itself is formatted from the original integer and stays exact.
+///
+/// ## Size
+/// [`Str<28>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Accounting>(), 40);
+/// ```
+///
+/// ## Math
+/// These operators are overloaded. They will always output a new [`Self`]:
+/// - `Add +`
+/// - `Sub -`
+/// - `Div /`
+/// - `Mul *`
+/// - `Rem %`
+///
+/// They can either be:
+/// - Combined with another [`Self`]: `Accounting::from(1.0) + Accounting::from(1.0)`
+/// - Or with the inner number itself: `Accounting::from(1.0) + 1.0`
+///
+/// The output is formatted from an [`f64`], so it will have `3` decimals.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Accounting::from(10.0) - 20.0 == Accounting::from(-10.0));
+/// assert!(Accounting::from(10) - 20.0 == "(10.000)");
+/// assert!(Accounting::from(-10.0) * -1.0 == "10.000");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Accounting(f64, Str<{ Accounting::MAX_LEN }>);
+
+impl_math!(Accounting, f64);
+impl_from_option!(Accounting);
+impl_traits!(Accounting, f64);
+impl_float_eq!(Accounting => f64);
+
+//---------------------------------------------------------------------------------------------------- Accounting Constants
+impl Accounting {
+    /// The maximum string length of an [`Accounting`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Accounting::from(i64::MIN), "(9,223,372,036,854,775,808)");
+    /// assert_eq!("(18,446,744,073,709,551,615)".len(), Accounting::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 28;
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Accounting::ZERO, 0.0);
+    /// assert_eq!(Accounting::ZERO, "0.000");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0.000"));
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Accounting::UNKNOWN, 0.0);
+    /// assert_eq!(Accounting::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- Accounting Impl
+impl Accounting {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Accounting::UNKNOWN.is_unknown());
+    /// assert!(!Accounting::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.as_str().as_bytes(), b"???")
+    }
+
+    #[inline]
+    #[must_use]
+    /// If the [`String`] is wrapped in parentheses
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Accounting::from(-1).is_negative());
+    /// assert!(!Accounting::from(1).is_negative());
+    /// assert!(!Accounting::from(-0.0001).is_negative());
+    /// assert!(!Accounting::UNKNOWN.is_negative());
+    /// ```
+    pub const fn is_negative(&self) -> bool {
+        matches!(self.as_str().as_bytes(), [b'(', ..])
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Accounting {
+    /// Wrap `abs` (the formatted absolute value) in parentheses if `negative`.
+    fn from_priv(f: f64, negative: bool, abs: &str) -> Self {
+        // Don't wrap something that rounded to zero, e.g. `(0.000)`.
+        let negative = negative && abs.bytes().any(|b| matches!(b, b'1'..=b'9'));
+
+        let mut s = Str::new();
+        if negative {
+            s.push_str_panic("(");
+            s.push_str_panic(abs);
+            s.push_str_panic(")");
+        } else {
+            s.push_str_panic(abs);
+        }
+
+        Self(f, s)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `i*`
+macro_rules! impl_i {
+	($($number:ty),*) => {
+		$(
+			impl From<$number> for Accounting {
+				#[inline]
+				fn from(number: $number) -> Self {
+					let i = number as i64;
+					Self::from_priv(i as f64, i < 0, Unsigned::from(i.unsigned_abs()).as_str())
+				}
+			}
+		)*
+	}
+}
+impl_i!(i8, i16, i32, i64, isize);
+
+//---------------------------------------------------------------------------------------------------- From `f32/f64`
+impl From<f32> for Accounting {
+    #[inline]
+    fn from(f: f32) -> Self {
+        Self::from(f64::from(f))
+    }
+}
+
+impl From<f64> for Accounting {
+    #[inline]
+    fn from(f: f64) -> Self {
+        if !f.is_finite() {
+            return Self::UNKNOWN;
+        }
+
+        let abs = Float::from(f.abs());
+        if abs.is_unknown() {
+            return Self::UNKNOWN;
+        }
+
+        Self::from_priv(f, f < 0.0, abs.as_str())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `Int/Float`
+impl From<Int> for Accounting {
+    #[inline]
+    fn from(int: Int) -> Self {
+        if int.is_unknown() {
+            Self::UNKNOWN
+        } else {
+            Self::from(int.inner())
+        }
+    }
+}
+
+impl From<Float> for Accounting {
+    #[inline]
+    fn from(float: Float) -> Self {
+        let f = float.inner();
+        if float.is_unknown() || !f.is_finite() {
+            return Self::UNKNOWN;
+        }
+
+        // Keep the same amount of decimals the `Float` was created with.
+        let decimals = float.as_str().rsplit_once('.').map_or(0, |(_, d)| d.len());
+        let abs = Float::priv_precision(f.abs(), decimals);
+        if abs.is_unknown() {
+            return Self::UNKNOWN;
+        }
+
+        Self::from_priv(f, f < 0.0, abs.as_str())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int() {
+        assert_eq!(Accounting::from(0), "0");
+        assert_eq!(Accounting::from(-1), "(1)");
+        assert_eq!(Accounting::from(-1_000_000), "(1,000,000)");
+        assert_eq!(Accounting::from(i64::MAX), "9,223,372,036,854,775,807");
+        assert_eq!(Accounting::from(Int::from(-1_000)), "(1,000)");
+        assert_eq!(Accounting::from(Int::from(1_000)), "1,000");
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Accounting::from(-0.0), "0.000");
+        assert_eq!(Accounting::from(-0.5), "(0.500)");
+        assert_eq!(Accounting::from(-1_234.5_f32), "(1,234.500)");
+        assert_eq!(Accounting::from(Float::from_0(-1_234.5)), "(1,234)");
+        assert_eq!(Accounting::from(Float::from_4(-1.5)), "(1.5000)");
+        assert_eq!(Accounting::from(Float::from_2(1.5)), "1.50");
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(Accounting::from(f32::NAN).is_unknown());
+        assert!(Accounting::from(f64::INFINITY).is_unknown());
+        assert!(Accounting::from(Float::NAN).is_unknown());
+        assert!(Accounting::from(Float::INFINITY).is_unknown());
+        assert!(Accounting::from(-1e30).is_unknown());
+    }
+
+    #[test]
+    fn math() {
+        let a = Accounting::from(1.0) - 3.0;
+        assert_eq!(a, -2.0);
+        assert_eq!(a, "(2.000)");
+        assert!(a.is_negative());
+        assert_eq!(a + a, "(4.000)");
+        assert_eq!(a * a, "4.000");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: Accounting = Accounting::from(-1.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[-1.0,"(1.000)"]"#);
+
+        let this: Accounting = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, -1.0);
+        assert_eq!(this, "(1.000)");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<Accounting>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&Accounting::UNKNOWN).unwrap();
+        assert_eq!(json, r#"[0.0,"???"]"#);
+        assert!(serde_json::from_str::<Accounting>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this: Accounting = Accounting::from(-1.0);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: Accounting = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, -1.0);
+        assert_eq!(this, "(1.000)");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&Accounting::UNKNOWN).unwrap();
+        let this: Accounting = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
@@ -25,3 +25,170 @@ pub const NAN: &str = "NaN";
 
 /// The string returned when encountering an `INFINITY` variant of an `f32/f64`.
 pub const INFINITY: &str = "inf";
+
+//---------------------------------------------------------------------------------------------------- Integer MAX/MIN
+// Pre-formatted integer limits, see `IntegerBounds`.
+
+/// [`u8::MAX`] formatted as an [`Unsigned`](crate::num::Unsigned).
+pub const UNSIGNED_U8_MAX: &str = "255";
+
+/// [`u16::MAX`] formatted as an [`Unsigned`](crate::num::Unsigned).
+pub const UNSIGNED_U16_MAX: &str = "65,535";
+
+/// [`u32::MAX`] formatted as an [`Unsigned`](crate::num::Unsigned).
+pub const UNSIGNED_U32_MAX: &str = "4,294,967,295";
+
+/// [`u64::MAX`] formatted as an [`Unsigned`](crate::num::Unsigned).
+pub const UNSIGNED_U64_MAX: &str = "18,446,744,073,709,551,615";
+
+/// [`u128::MAX`] formatted as an [`Unsigned128`](crate::num::Unsigned128).
+pub const UNSIGNED_U128_MAX: &str = "340,282,366,920,938,463,463,374,607,431,768,211,455";
+
+/// [`usize::MAX`] formatted as an [`Unsigned`](crate::num::Unsigned).
+pub const UNSIGNED_USIZE_MAX: &str = match usize::BITS {
+    16 => UNSIGNED_U16_MAX,
+    32 => UNSIGNED_U32_MAX,
+    _ => UNSIGNED_U64_MAX,
+};
+
+/// [`i8::MAX`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I8_MAX: &str = "127";
+
+/// [`i8::MIN`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I8_MIN: &str = "-128";
+
+/// [`i16::MAX`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I16_MAX: &str = "32,767";
+
+/// [`i16::MIN`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I16_MIN: &str = "-32,768";
+
+/// [`i32::MAX`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I32_MAX: &str = "2,147,483,647";
+
+/// [`i32::MIN`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I32_MIN: &str = "-2,147,483,648";
+
+/// [`i64::MAX`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I64_MAX: &str = "9,223,372,036,854,775,807";
+
+/// [`i64::MIN`] formatted as an [`Int`](crate::num::Int).
+pub const INT_I64_MIN: &str = "-9,223,372,036,854,775,808";
+
+/// [`i128::MAX`] formatted as an [`Int128`](crate::num::Int128).
+pub const INT_I128_MAX: &str = "170,141,183,460,469,231,731,687,303,715,884,105,727";
+
+/// [`i128::MIN`] formatted as an [`Int128`](crate::num::Int128).
+pub const INT_I128_MIN: &str = "-170,141,183,460,469,231,731,687,303,715,884,105,728";
+
+/// [`isize::MAX`] formatted as an [`Int`](crate::num::Int).
+pub const INT_ISIZE_MAX: &str = match isize::BITS {
+    16 => INT_I16_MAX,
+    32 => INT_I32_MAX,
+    _ => INT_I64_MAX,
+};
+
+/// [`isize::MIN`] formatted as an [`Int`](crate::num::Int).
+pub const INT_ISIZE_MIN: &str = match isize::BITS {
+    16 => INT_I16_MIN,
+    32 => INT_I32_MIN,
+    _ => INT_I64_MIN,
+};
+
+//---------------------------------------------------------------------------------------------------- IntegerBounds
+/// Integer primitives with pre-formatted `MAX`/`MIN` strings
+///
+/// This is implemented for all integer primitives, and is used by [`max_str`] and [`min_str`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(<u16 as IntegerBounds>::MAX_STR, "65,535");
+/// assert_eq!(<i16 as IntegerBounds>::MIN_STR, "-32,768");
+/// ```
+pub trait IntegerBounds: private::Sealed {
+    /// The formatted `MAX`.
+    const MAX_STR: &'static str;
+    /// The formatted `MIN`.
+    const MIN_STR: &'static str;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+// Implements `IntegerBounds`.
+macro_rules! impl_bounds {
+	($($int:ty => $max:expr, $min:expr),* $(,)?) => {$(
+		impl private::Sealed for $int {}
+		impl IntegerBounds for $int {
+			const MAX_STR: &'static str = $max;
+			const MIN_STR: &'static str = $min;
+		}
+	)*};
+}
+impl_bounds! {
+    u8    => UNSIGNED_U8_MAX, "0",
+    u16   => UNSIGNED_U16_MAX, "0",
+    u32   => UNSIGNED_U32_MAX, "0",
+    u64   => UNSIGNED_U64_MAX, "0",
+    u128  => UNSIGNED_U128_MAX, "0",
+    usize => UNSIGNED_USIZE_MAX, "0",
+    i8    => INT_I8_MAX, INT_I8_MIN,
+    i16   => INT_I16_MAX, INT_I16_MIN,
+    i32   => INT_I32_MAX, INT_I32_MIN,
+    i64   => INT_I64_MAX, INT_I64_MIN,
+    i128  => INT_I128_MAX, INT_I128_MIN,
+    isize => INT_ISIZE_MAX, INT_ISIZE_MIN,
+}
+
+#[inline]
+#[must_use]
+/// Returns the formatted `MAX` of an integer type, without any runtime formatting
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(max_str::<u32>(), "4,294,967,295");
+/// assert_eq!(max_str::<i8>(),  "127");
+///
+/// // Usable in `const`.
+/// const HINT: &str = max_str::<u16>();
+/// assert_eq!(Unsigned::from(u16::MAX), HINT);
+/// ```
+pub const fn max_str<T: IntegerBounds>() -> &'static str {
+    T::MAX_STR
+}
+
+#[inline]
+#[must_use]
+/// Returns the formatted `MIN` of an integer type, without any runtime formatting
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(min_str::<u32>(), "0");
+/// assert_eq!(min_str::<i64>(), "-9,223,372,036,854,775,808");
+/// ```
+pub const fn min_str<T: IntegerBounds>() -> &'static str {
+    T::MIN_STR
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::{Int, Int128, Unsigned, Unsigned128};
+
+    #[test]
+    fn bounds() {
+        macro_rules! check {
+            ($($int:ty => $num:ty),*) => {$(
+                assert_eq!(<$num>::from(<$int>::MAX), max_str::<$int>(), stringify!($int));
+                assert_eq!(<$num>::from(<$int>::MIN), min_str::<$int>(), stringify!($int));
+            )*};
+        }
+        check! {
+            u8 => Unsigned, u16 => Unsigned, u32 => Unsigned, u64 => Unsigned, usize => Unsigned,
+            i8 => Int, i16 => Int, i32 => Int, i64 => Int, isize => Int,
+            u128 => Unsigned128, i128 => Int128
+        }
+    }
+}
@@ -1,13 +1,16 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::macros::{
-    impl_common, impl_const, impl_impl_math, impl_isize, impl_math, impl_traits, impl_usize,
-    return_bad_float, str_i64, str_u64,
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_isize, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float, str_i64,
+    str_u64,
 };
 use crate::num::constants::{INFINITY, NAN};
 #[allow(unused_imports)]
 use crate::num::{Int, Unsigned};
 use crate::str::Str;
+use crate::Rounding;
 use compact_str::{format_compact, CompactString}; // docs
+use std::cmp::Ordering;
 
 //---------------------------------------------------------------------------------------------------- Float
 /// Human readable float.
@@ -109,13 +112,16 @@ use compact_str::{format_compact, CompactString}; // docs
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Copy, Clone)]
 pub struct Float(f64, Str<{ Float::MAX_LEN }>);
 
 const LEN: usize = 22; // 14 decimal point accuracy + 8 extra chars
 
 impl_math!(Float, f64);
-impl_traits!(Float, f64);
+impl_from_option!(Float);
+impl_traits!(Float, f64, precision);
+impl_float_eq!(Float => f64);
+impl_std_from_str!(Float => from_str(DecimalMark::Point));
 
 //---------------------------------------------------------------------------------------------------- Float Constants
 impl Float {
@@ -183,6 +189,7 @@ macro_rules! impl_new {
 impl Float {
     impl_common!(f64);
     impl_const!();
+    impl_format_slice!(num, Float, [0.0_f64, -1.5, 1_234.567, 0.001]);
     impl_usize!();
     impl_isize!();
 
@@ -237,9 +244,262 @@ impl Float {
         }
     }
 
+    #[must_use]
+    /// Create a [`Float`] with `decimals` floating point, rounded with a specific [`Rounding`]
+    ///
+    /// `decimals` above `14` is treated as `14`.
+    ///
+    /// ```rust
+    /// # use readable::{num::*,Rounding};
+    /// assert_eq!(Float::with_rounding(2.675, 2, Rounding::Truncate), "2.67");
+    /// assert_eq!(Float::with_rounding(2.675, 2, Rounding::HalfUp),   "2.68");
+    /// assert_eq!(Float::with_rounding(2.665, 2, Rounding::HalfEven), "2.66");
+    ///
+    /// // Rounding can carry into the integer.
+    /// assert_eq!(Float::with_rounding(999.99, 1, Rounding::HalfUp), "1,000.0");
+    ///
+    /// // Negative numbers round by their absolute value.
+    /// assert_eq!(Float::with_rounding(-1.5, 0, Rounding::HalfUp),   "-2");
+    /// assert_eq!(Float::with_rounding(-1.5, 0, Rounding::Truncate), "-1");
+    /// assert_eq!(Float::with_rounding(-0.4, 0, Rounding::HalfUp),   "0");
+    ///
+    /// // The inner float is the input.
+    /// assert_eq!(Float::with_rounding(2.675, 2, Rounding::HalfUp), 2.675);
+    /// ```
+    ///
+    /// [`Float::UNKNOWN`] is returned if the string is longer than [`Float::MAX_LEN`].
+    ///
+    /// ```rust
+    /// # use readable::{num::*,Rounding};
+    /// assert!(Float::with_rounding(f64::MAX, 2, Rounding::HalfUp).is_unknown());
+    /// ```
+    pub fn with_rounding(f: f64, decimals: u8, rounding: Rounding) -> Self {
+        return_bad_float!(f, Self::NAN, Self::INFINITY);
+
+        let mut s = Str::new();
+        if push_rounded(&mut s, f, usize::from(decimals.min(14)), rounding, "") {
+            Self(f, s)
+        } else {
+            Self::UNKNOWN
+        }
+    }
+
     seq_macro::seq!(N in 1..=14 {
         impl_new!(N);
     });
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a formatted float string with an explicit [`DecimalMark`]
+    ///
+    /// Surrounding whitespace is trimmed, and `_` or ` ` are ignored before the decimal mark.
+    ///
+    /// The thousands separator is the opposite of the [`DecimalMark`],
+    /// and must be followed by exactly 3 digits, so ambiguous input
+    /// like `1,234` with [`DecimalMark::Comma`] is not silently mis-parsed.
+    ///
+    /// The amount of decimals in the input is kept, up to `14`.
+    ///
+    /// [`std::str::FromStr`] uses [`DecimalMark::Point`], e.g. `"1,234.5".parse::<Float>()`.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let us = Float::from_str("1,234.56", DecimalMark::Point).unwrap();
+    /// let eu = Float::from_str("1.234,56", DecimalMark::Comma).unwrap();
+    /// assert_eq!(us, 1234.56);
+    /// assert_eq!(eu, 1234.56);
+    /// assert_eq!(us, "1,234.56");
+    /// assert_eq!(eu, "1,234.56");
+    ///
+    /// assert_eq!(Float::from_str("1,2", DecimalMark::Comma).unwrap(), "1.2");
+    /// assert_eq!(Float::from_str("1 000", DecimalMark::Comma).unwrap(), "1,000");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not a number, or the separators do not match
+    /// the [`DecimalMark`], [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Float::from_str("1.234,56", DecimalMark::Point).is_err());
+    /// assert!(Float::from_str("1,234.56", DecimalMark::Comma).is_err());
+    /// assert!(Float::from_str("1,2", DecimalMark::Point).is_err());
+    /// assert!(Float::from_str("?.???", DecimalMark::Point).is_err());
+    /// ```
+    pub fn from_str(string: &str, mark: DecimalMark) -> Result<Self, Self> {
+        // Longer than any formatted number, anything longer is rejected.
+        const MAX: usize = 64;
+        let mut buf = [0_u8; MAX];
+        let mut len = 0;
+
+        let (decimal, group) = match mark {
+            DecimalMark::Point => (b'.', b','),
+            DecimalMark::Comma => (b',', b'.'),
+        };
+
+        // Digits after the decimal mark, if seen.
+        let mut decimals: Option<usize> = None;
+        // Digits after the last thousands separator, if seen.
+        let mut grouped: Option<usize> = None;
+
+        for b in string.trim().bytes() {
+            if decimals.is_none() {
+                if matches!(b, b'_' | b' ') {
+                    continue;
+                } else if b == group {
+                    if len == 0 || grouped.is_some_and(|n| n != 3) {
+                        return Err(Self::UNKNOWN);
+                    }
+                    grouped = Some(0);
+                    continue;
+                } else if b == decimal {
+                    if grouped.is_some_and(|n| n != 3) {
+                        return Err(Self::UNKNOWN);
+                    }
+                    grouped = None;
+                    decimals = Some(0);
+                }
+            } else if b == group || b == decimal {
+                return Err(Self::UNKNOWN);
+            }
+
+            if b.is_ascii_digit() {
+                if let Some(n) = decimals.as_mut().or(grouped.as_mut()) {
+                    *n += 1;
+                }
+            }
+
+            if len == MAX {
+                return Err(Self::UNKNOWN);
+            }
+            buf[len] = if b == decimal { b'.' } else { b };
+            len += 1;
+        }
+
+        if grouped.is_some_and(|n| n != 3) {
+            return Err(Self::UNKNOWN);
+        }
+
+        match std::str::from_utf8(&buf[..len]).map(str::parse::<f64>) {
+            Ok(Ok(f)) => Ok(Self::priv_precision(f, decimals.unwrap_or(0))),
+            _ => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Float::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str, mark: DecimalMark) -> Self {
+        match Self::from_str(string, mark) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- DecimalMark
+/// The decimal mark used when parsing with [`Float::from_str`]
+///
+/// The thousands separator is always the other one.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Float::from_str("1,000.5", DecimalMark::Point).unwrap(), 1000.5);
+/// assert_eq!(Float::from_str("1.000,5", DecimalMark::Comma).unwrap(), 1000.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DecimalMark {
+    /// `.` decimal mark, `,` thousands separator, e.g. `1,234.56`
+    Point,
+    /// `,` decimal mark, `.` thousands separator, e.g. `1.234,56`
+    Comma,
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Float {
+    /// Dispatch to `from_X` with `precision`, used by the `{:.X}` formatting flag.
+    ///
+    /// Anything over `14` is the same as `14`.
+    pub(super) fn priv_precision(f: f64, precision: usize) -> Self {
+        seq_macro::seq!(N in 1..=14 {
+            match precision {
+                0 => Self::from_0(f),
+                #( N => Self::from_~N(f), )*
+                _ => Self::from_14(f),
+            }
+        })
+    }
+}
+
+/// Push `f` rounded to `decimals` with commas and a `suffix`, e.g. `-1,234.57%`.
+///
+/// `false` is returned (and nothing is pushed) if it does not fit in `s`.
+pub(super) fn push_rounded<const N: usize>(
+    s: &mut Str<N>,
+    f: f64,
+    decimals: usize,
+    rounding: Rounding,
+    suffix: &str,
+) -> bool {
+    // The shortest representation, this never uses an exponent.
+    let string = format_compact!("{}", f.abs());
+    let (int, fract) = string.split_once('.').unwrap_or((&string, ""));
+    let fract = fract.as_bytes();
+
+    // The digits being kept, the last `decimals` are the fraction.
+    let mut digits: Vec<u8> = int
+        .bytes()
+        .chain((0..decimals).map(|i| fract.get(i).copied().unwrap_or(b'0')))
+        .collect();
+
+    // The cut off digits compared to one half.
+    let rest = match fract.get(decimals..).and_then(<[u8]>::split_first) {
+        None => Ordering::Less,
+        Some((b'5', tail)) if tail.iter().all(|b| *b == b'0') => Ordering::Equal,
+        Some((first, _)) => first.cmp(&b'5').then(Ordering::Greater),
+    };
+
+    let odd = digits.last().is_some_and(|d| d % 2 == 1);
+    if rounding.round_up(odd, rest) {
+        // Carry the `1`, e.g. `9.99` -> `10.00`.
+        if let Some(i) = digits.iter().rposition(|d| *d != b'9') {
+            digits[i] += 1;
+            digits[i + 1..].fill(b'0');
+        } else {
+            digits.fill(b'0');
+            digits.insert(0, b'1');
+        }
+    }
+
+    let (int, fract) = digits.split_at(digits.len() - decimals);
+    let negative = f.is_sign_negative() && digits.iter().any(|d| *d != b'0');
+
+    let len = usize::from(negative)
+        + int.len()
+        + (int.len() - 1) / 3
+        + if decimals == 0 { 0 } else { decimals + 1 }
+        + suffix.len();
+    if len > s.remaining() {
+        return false;
+    }
+
+    if negative {
+        s.push_char_panic('-');
+    }
+    crate::raw::push_grouped(s, int);
+    if decimals != 0 {
+        s.push_char_panic('.');
+        // SAFETY: these are ASCII digits.
+        s.push_str_panic(unsafe { std::str::from_utf8_unchecked(fract) });
+    }
+    s.push_str_panic(suffix);
+    true
 }
 
 //---------------------------------------------------------------------------------------------------- From `u*`
@@ -319,6 +579,111 @@ impl From<f64> for Float {
 mod tests {
     use super::*;
 
+    #[test]
+    fn with_rounding() {
+        let f = 1.999;
+        assert_eq!(Float::with_rounding(f, 2, Rounding::Truncate), "1.99");
+        assert_eq!(Float::with_rounding(f, 2, Rounding::HalfUp), "2.00");
+        assert_eq!(Float::with_rounding(f, 2, Rounding::HalfEven), "2.00");
+        assert_eq!(Float::with_rounding(f, 5, Rounding::HalfUp), "1.99900");
+
+        // Ties with a non-zero tail are not ties.
+        assert_eq!(Float::with_rounding(0.125, 2, Rounding::HalfEven), "0.12");
+        assert_eq!(Float::with_rounding(0.1251, 2, Rounding::HalfEven), "0.13");
+        assert_eq!(Float::with_rounding(0.1250, 2, Rounding::HalfUp), "0.13");
+
+        // Carrying through commas.
+        assert_eq!(
+            Float::with_rounding(999_999.5, 0, Rounding::HalfEven),
+            "1,000,000"
+        );
+        assert_eq!(
+            Float::with_rounding(-999.96, 1, Rounding::HalfUp),
+            "-1,000.0"
+        );
+
+        // Small numbers.
+        assert_eq!(
+            Float::with_rounding(1e-10, 14, Rounding::HalfUp),
+            "0.00000000010000"
+        );
+        assert_eq!(Float::with_rounding(-1e-10, 2, Rounding::HalfUp), "0.00");
+        assert_eq!(Float::with_rounding(-0.0, 1, Rounding::HalfUp), "0.0");
+    }
+
+    #[test]
+    fn eq_ord_hash() {
+        use std::collections::HashSet;
+
+        assert_eq!(Float::NAN, Float::NAN);
+        assert_eq!(Float::from(f64::NAN), Float::from(-f64::NAN));
+        assert_eq!(Float(-0.0, Float::ZERO.1), Float::ZERO);
+        assert!(Float::from(-1.0) < Float::ZERO);
+        assert!(Float::from(f64::MAX) < Float::INFINITY);
+        assert!(Float::INFINITY < Float::NAN);
+
+        // Same number, different formatting.
+        assert_ne!(Float::from_1(1.0), Float::from_2(1.0));
+
+        let set: HashSet<Float> = [
+            Float::NAN,
+            Float::from(f64::NAN),
+            Float::from_1(1.0),
+            Float::from_1(1.0),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Float::NAN));
+        assert!(set.contains(&Float::from_1(1.0)));
+
+        let set: HashSet<Float> = [Float(-0.0, Float::ZERO.1), Float::ZERO]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn from_str() {
+        use DecimalMark::{Comma, Point};
+
+        assert_eq!(Float::from_str("0", Point).unwrap(), "0");
+        assert!(Float::from_str("0.0", Comma).is_err());
+        assert_eq!(Float::from_str("0,0", Comma).unwrap(), "0.0");
+        assert_eq!(Float::from_str(" 1_234.5 ", Point).unwrap(), "1,234.5");
+        assert_eq!(
+            Float::from_str("1.234.567,125", Comma).unwrap(),
+            "1,234,567.125"
+        );
+        assert_eq!(
+            Float::from_str("1,234,567.125", Point).unwrap(),
+            "1,234,567.125"
+        );
+        assert_eq!(Float::from_str("1.", Point).unwrap(), "1");
+
+        // Round-trip.
+        for f in [0.5, 1.25, 1_000.125, 123_456.789] {
+            let this = Float::from(f);
+            assert_eq!(Float::from_str(&this, Point).unwrap(), this);
+        }
+
+        for s in [
+            "",
+            ",1",
+            "1,,000",
+            "1,00",
+            "1,0000",
+            "1,000.0,0",
+            "1.0.0",
+            "1,000_0.0",
+            "a",
+        ] {
+            assert!(Float::from_str(s, Point).is_err(), "{s}");
+        }
+        assert!(Float::from_str(&"0".repeat(100), Point).is_err());
+        assert!(Float::from_str_silent("1.0", Comma).is_unknown());
+    }
+
     #[test]
     fn special() {
         assert_eq!(Float::from(0.0), "0.000");
@@ -0,0 +1,450 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::return_bad_float;
+use crate::num::constants::{INFINITY, NAN};
+use crate::str::Str;
+use compact_str::format_compact;
+
+//---------------------------------------------------------------------------------------------------- FloatSig
+/// Human readable float with `N` significant figures
+///
+/// Unlike [`Float`](crate::num::Float) which always prints a fixed amount
+/// of decimals, this keeps the first `N` significant digits, so
+/// small and large readings have the same precision:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(FloatSig::<3>::from(0.0001234), "0.000123");
+/// assert_eq!(FloatSig::<3>::from(1.2345),    "1.23");
+/// assert_eq!(FloatSig::<3>::from(123.45),    "123");
+/// assert_eq!(FloatSig::<3>::from(12345.6),   "12,300");
+///
+/// assert_eq!(FloatSig::<1>::from(0.96),      "1");
+/// assert_eq!(FloatSig::<5>::from(-2.0),      "-2.0000");
+/// assert_eq!(FloatSig::<3>::from(0.0),       "0.00");
+/// ```
+///
+/// `N` must be in-between `1..=17` (the significant digits of an [`f64`]),
+/// other values fail to compile when a [`FloatSig`] is created.
+///
+/// ## Rounding
+/// The last digit is rounded to the nearest, ties to even,
+/// using the exact value of the inner [`f64`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(FloatSig::<2>::from(0.125), "0.12");
+/// assert_eq!(FloatSig::<2>::from(0.375), "0.38");
+/// assert_eq!(FloatSig::<2>::from(999.9), "1,000");
+/// ```
+///
+/// ## Errors
+/// Inputting [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`] or the [`f32`] variants returns errors.
+///
+/// Numbers that are too large or too small to fit in [`FloatSig::MAX_LEN`]
+/// without an exponent return [`FloatSig::UNKNOWN`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(FloatSig::<3>::from(f64::NAN),      "NaN");
+/// assert_eq!(FloatSig::<3>::from(f64::INFINITY), "inf");
+/// assert!(FloatSig::<3>::from(f64::MAX).is_unknown());
+/// assert!(FloatSig::<3>::from(1e-40).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<32>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<FloatSig<3>>(), 48);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct FloatSig<const N: usize>(f64, Str<MAX_LEN>);
+
+const MAX_LEN: usize = 32;
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl<const N: usize> FloatSig<N> {
+    /// Fails to compile if `N` is not `1..=17`.
+    const VALID_DIGITS: () = assert!(N >= 1 && N <= 17, "FloatSig<N>: N must be 1..=17");
+
+    /// The maximum string length of a [`FloatSig`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let f = FloatSig::<3>::from(-1.23e-27);
+    /// assert_eq!(f, "-0.00000000000000000000000000123");
+    /// assert_eq!(f.len(), FloatSig::<3>::MAX_LEN);
+    ///
+    /// // One more digit does not fit.
+    /// assert!(FloatSig::<4>::from(-1.23e-27).is_unknown());
+    /// ```
+    pub const MAX_LEN: usize = MAX_LEN;
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(FloatSig::<3>::NAN, "NaN");
+    /// assert!(FloatSig::<3>::NAN.is_nan());
+    /// ```
+    pub const NAN: Self = Self(f64::NAN, Str::from_static_str(NAN));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(FloatSig::<3>::INFINITY, "inf");
+    /// assert!(FloatSig::<3>::INFINITY.is_infinite());
+    /// ```
+    pub const INFINITY: Self = Self(f64::INFINITY, Str::from_static_str(INFINITY));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(FloatSig::<3>::UNKNOWN, 0.0);
+    /// assert_eq!(FloatSig::<3>::UNKNOWN, "?.???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.???"));
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl<const N: usize> FloatSig<N> {
+    #[inline]
+    #[must_use]
+    /// Returns the inner number.
+    pub const fn inner(&self) -> f64 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub const fn as_str(&self) -> &str {
+        self.1.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.1.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    /// The length of the inner [`String`]
+    pub const fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_nan`].
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_infinite`].
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(FloatSig::<3>::UNKNOWN.is_unknown());
+    /// assert!(!FloatSig::<3>::from(0.0).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.as_str().as_bytes(), b"?.???")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl<const N: usize> FloatSig<N> {
+    #[allow(clippy::let_unit_value)]
+    fn priv_from(f: f64) -> Self {
+        let () = Self::VALID_DIGITS;
+        return_bad_float!(f, Self::NAN, Self::INFINITY);
+
+        // `N` correctly rounded digits and the exponent, e.g. `1.23e-4`.
+        let string = format_compact!("{:.*e}", N - 1, f.abs());
+        let Some((mantissa, exp)) = string.split_once('e') else {
+            return Self::UNKNOWN;
+        };
+        let Ok(exp) = exp.parse::<i32>() else {
+            return Self::UNKNOWN;
+        };
+        let digits: Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+
+        // Split into the integer and fraction digits, padding with `0`s.
+        let (int, fract) = if exp < 0 {
+            let zeros = exp.unsigned_abs() as usize - 1;
+            let fract = std::iter::repeat(b'0')
+                .take(zeros)
+                .chain(digits)
+                .collect::<Vec<u8>>();
+            (vec![b'0'], fract)
+        } else {
+            let exp = exp as usize;
+            if exp + 1 >= digits.len() {
+                let mut int = digits;
+                int.resize(exp + 1, b'0');
+                (int, Vec::new())
+            } else {
+                let fract = digits[exp + 1..].to_vec();
+                let mut int = digits;
+                int.truncate(exp + 1);
+                (int, fract)
+            }
+        };
+
+        let negative = f.is_sign_negative() && f != 0.0;
+        let len = usize::from(negative)
+            + int.len()
+            + (int.len() - 1) / 3
+            + if fract.is_empty() { 0 } else { fract.len() + 1 };
+        if len > MAX_LEN {
+            return Self::UNKNOWN;
+        }
+
+        let mut s = Str::new();
+        if negative {
+            s.push_char_panic('-');
+        }
+        crate::raw::push_grouped(&mut s, &int);
+        if !fract.is_empty() {
+            s.push_char_panic('.');
+            // SAFETY: these are ASCII digits.
+            s.push_str_panic(unsafe { std::str::from_utf8_unchecked(&fract) });
+        }
+        Self(f, s)
+    }
+
+    /// `NaN`s are all the same, and `-0.0` is `0.0`.
+    fn canonical(&self) -> f64 {
+        if self.0.is_nan() {
+            f64::NAN
+        } else {
+            self.0 + 0.0
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl<const N: usize> From<f64> for FloatSig<N> {
+    #[inline]
+    fn from(f: f64) -> Self {
+        Self::priv_from(f)
+    }
+}
+
+impl<const N: usize> From<f32> for FloatSig<N> {
+    #[inline]
+    fn from(f: f32) -> Self {
+        Self::priv_from(f64::from(f))
+    }
+}
+
+// Implementation Macro.
+macro_rules! impl_from {
+	($($number:ty),*) => { $(
+		impl<const N: usize> From<$number> for FloatSig<N> {
+			#[inline]
+			fn from(number: $number) -> Self {
+				Self::priv_from(number as f64)
+			}
+		}
+	)*};
+}
+impl_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<const N: usize> std::ops::Deref for FloatSig<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FloatSig<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> std::fmt::Debug for FloatSig<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FloatSig")
+            .field("inner", &self.0)
+            .field("str", &self.1.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> std::fmt::Display for FloatSig<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const N: usize> Default for FloatSig<N> {
+    #[inline]
+    /// Returns `0.0`
+    fn default() -> Self {
+        Self::priv_from(0.0)
+    }
+}
+
+// Same as `impl_float_eq!()`, but that does not take `const N: usize` types.
+impl<const N: usize> PartialEq for FloatSig<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<const N: usize> Eq for FloatSig<N> {}
+
+impl<const N: usize> PartialOrd for FloatSig<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FloatSig<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.canonical()
+            .total_cmp(&other.canonical())
+            .then_with(|| self.1.cmp(&other.1))
+    }
+}
+
+impl<const N: usize> std::hash::Hash for FloatSig<N> {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.canonical().to_bits().hash(state);
+        self.1.hash(state);
+    }
+}
+
+impl<const N: usize> PartialEq<str> for FloatSig<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FloatSig<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<f64> for FloatSig<N> {
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", FloatSig::<3>::from(1234.5)),
+            r#"FloatSig { inner: 1234.5, str: "1,230" }"#
+        );
+    }
+
+    #[test]
+    fn powers() {
+        let expected = [
+            "0.0000000000000000123",
+            "0.000000000000000123",
+            "0.00000000000000123",
+            "0.0000000000000123",
+            "0.000000000000123",
+            "0.00000000000123",
+            "0.0000000000123",
+            "0.000000000123",
+            "0.00000000123",
+            "0.0000000123",
+            "0.000000123",
+            "0.00000123",
+            "0.0000123",
+            "0.000123",
+            "0.00123",
+            "0.0123",
+            "0.123",
+            "1.23",
+            "12.3",
+            "123",
+            "1,230",
+            "12,300",
+            "123,000",
+            "1,230,000",
+            "12,300,000",
+            "123,000,000",
+            "1,230,000,000",
+            "12,300,000,000",
+            "123,000,000,000",
+            "1,230,000,000,000",
+            "12,300,000,000,000",
+            "123,000,000,000,000",
+        ];
+
+        for (i, expected) in expected.into_iter().enumerate() {
+            let f = 1.2345 * 10_f64.powi(i as i32 - 17);
+            assert_eq!(FloatSig::<3>::from(f), expected, "{f}");
+            assert_eq!(FloatSig::<3>::from(-f), format!("-{expected}").as_str());
+        }
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(FloatSig::<1>::from(12345.6), "10,000");
+        assert_eq!(FloatSig::<5>::from(12345.6), "12,346");
+        assert_eq!(FloatSig::<6>::from(12345.6), "12,345.6");
+        assert_eq!(FloatSig::<8>::from(12345.6), "12,345.600");
+        assert_eq!(FloatSig::<17>::from(0.1), "0.10000000000000001");
+        assert_eq!(FloatSig::<3>::from(u64::MAX), "18,400,000,000,000,000,000");
+        assert_eq!(FloatSig::<3>::from(-7_i8), "-7.00");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(FloatSig::<1>::from(0.0), "0");
+        assert_eq!(FloatSig::<3>::from(-0.0), "0.00");
+        assert_eq!(FloatSig::<3>::from(-0.0), FloatSig::<3>::from(0.0));
+        assert_eq!(FloatSig::<3>::default(), "0.00");
+        assert_eq!(FloatSig::<3>::from(-0.000_4), "-0.000400");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = FloatSig::<3>::from(12345.6);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[12345.6,"12,300"]"#);
+
+        let this: FloatSig<3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 12345.6);
+        assert_eq!(this, "12,300");
+    }
+}
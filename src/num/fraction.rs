@@ -0,0 +1,570 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Fraction
+/// Human readable fraction
+///
+/// This takes a `(numerator, denominator)` and formats
+/// it as a fraction reduced to its lowest terms:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Fraction::new(3, 4),  "3/4");
+/// assert_eq!(Fraction::new(6, 8),  "3/4");
+/// assert_eq!(Fraction::new(-2, 4), "-1/2");
+/// assert_eq!(Fraction::new(4, 2),  "2");
+/// assert_eq!(Fraction::new(0, 5),  "0");
+/// ```
+///
+/// ## Mixed
+/// [`Fraction::new_mixed`] (or [`Fraction::to_mixed`]) formats
+/// improper fractions with the whole number split out:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Fraction::new_mixed(3, 2),    "1 1/2");
+/// assert_eq!(Fraction::new_mixed(-7, 4),   "-1 3/4");
+/// assert_eq!(Fraction::new_mixed(1, 2),    "1/2");
+/// assert_eq!(Fraction::new(3, 2).to_mixed(), "1 1/2");
+/// ```
+///
+/// ## Float
+/// [`Fraction::from_f64`] finds the closest fraction to a float using a
+/// [continued fraction](https://en.wikipedia.org/wiki/Continued_fraction#Best_rational_approximations)
+/// approximation, with a denominator no larger than `max_denominator`.
+///
+/// [`Fraction::from`] does the same, using [`Fraction::DEFAULT_MAX_DENOMINATOR`].
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Fraction::from(0.75),              "3/4");
+/// assert_eq!(Fraction::from(1.0 / 3.0),         "1/3");
+/// assert_eq!(Fraction::from(-0.125),            "-1/8");
+/// assert_eq!(Fraction::from_f64(0.333, 10),     "1/3");
+/// assert_eq!(Fraction::from_f64(0.333, 1_000),  "333/1000");
+/// assert_eq!(Fraction::from_f64(3.14159, 10),   "22/7");
+/// assert_eq!(Fraction::from(2.5).to_mixed(),    "2 1/2");
+/// ```
+///
+/// ## Errors
+/// [`Fraction::UNKNOWN`] is returned if:
+/// - The denominator (or `max_denominator`) is `0`
+/// - The float is [`f64::NAN`], [`f64::INFINITY`], or [`f64::NEG_INFINITY`]
+/// - The float's numerator does not fit in an [`i64`]
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Fraction::new(1, 0).is_unknown());
+/// assert!(Fraction::from_f64(0.5, 0).is_unknown());
+/// assert!(Fraction::from(f64::NAN).is_unknown());
+/// assert!(Fraction::from(1e30).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<42>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Fraction>(), 64);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i64,
+    denominator: u64,
+    string: Str<{ Fraction::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Fraction {
+    /// The maximum string length of a [`Fraction`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(i64::MIN, u64::MAX).len(), 41);
+    /// assert_eq!(Fraction::new_mixed(i64::MIN, 5_000_000_000_000_000_001).len(), Fraction::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 42;
+
+    /// The `max_denominator` used by [`Fraction::from`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::from(1.0 / 64.0),  "1/64");
+    /// assert_eq!(Fraction::from(1.0 / 256.0), "0");
+    /// ```
+    pub const DEFAULT_MAX_DENOMINATOR: u64 = 64;
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::ZERO, "0");
+    /// assert_eq!(Fraction::ZERO.numerator(), 0);
+    /// assert_eq!(Fraction::ZERO.denominator(), 1);
+    /// ```
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+        string: Str::from_static_str("0"),
+    };
+
+    /// Returned on error situations
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::UNKNOWN, "?/?");
+    /// assert_eq!(Fraction::UNKNOWN.numerator(), 0);
+    /// assert_eq!(Fraction::UNKNOWN.denominator(), 0);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        numerator: 0,
+        denominator: 0,
+        string: Str::from_static_str("?/?"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Fraction Impl
+impl Fraction {
+    #[must_use]
+    /// Create a new reduced [`Fraction`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let f = Fraction::new(10, 4);
+    /// assert_eq!(f, "5/2");
+    /// assert_eq!(f.numerator(), 5);
+    /// assert_eq!(f.denominator(), 2);
+    /// ```
+    pub fn new(numerator: i64, denominator: u64) -> Self {
+        match Self::reduce(numerator, denominator) {
+            Some((n, d)) => Self::priv_from(n, d, false),
+            None => Self::UNKNOWN,
+        }
+    }
+
+    #[must_use]
+    /// Same as [`Fraction::new`], but formatted as a mixed number
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new_mixed(10, 4), "2 1/2");
+    /// assert_eq!(Fraction::new_mixed(10, 5), "2");
+    /// ```
+    pub fn new_mixed(numerator: i64, denominator: u64) -> Self {
+        match Self::reduce(numerator, denominator) {
+            Some((n, d)) => Self::priv_from(n, d, true),
+            None => Self::UNKNOWN,
+        }
+    }
+
+    #[must_use]
+    /// Create the closest [`Fraction`] to `f` with a denominator no larger than `max_denominator`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::from_f64(0.1, 100),  "1/10");
+    /// assert_eq!(Fraction::from_f64(0.09, 5),   "0");
+    /// assert_eq!(Fraction::from_f64(0.19, 5),   "1/5");
+    /// assert_eq!(Fraction::from_f64(-1.6, 1),   "-2");
+    /// assert_eq!(Fraction::from_f64(1.4, 1),    "1");
+    /// ```
+    pub fn from_f64(f: f64, max_denominator: u64) -> Self {
+        match Self::approximate(f, max_denominator) {
+            Some((n, d)) => Self::priv_from(n, d, false),
+            None => Self::UNKNOWN,
+        }
+    }
+
+    #[must_use]
+    /// Re-format `self` as a mixed number
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(7, 2).to_mixed(), "3 1/2");
+    /// assert!(Fraction::UNKNOWN.to_mixed().is_unknown());
+    /// ```
+    pub fn to_mixed(&self) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::priv_from(self.numerator, self.denominator, true)
+    }
+
+    #[must_use]
+    /// Re-format `self` as an improper fraction
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new_mixed(7, 2).to_improper(), "7/2");
+    /// assert!(Fraction::UNKNOWN.to_improper().is_unknown());
+    /// ```
+    pub fn to_improper(&self) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::priv_from(self.numerator, self.denominator, false)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the reduced numerator
+    pub const fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the reduced denominator
+    ///
+    /// This is `0` for [`Fraction::UNKNOWN`].
+    pub const fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `numerator / denominator` as an [`f64`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(3, 4).to_f64(), 0.75);
+    /// assert!(Fraction::UNKNOWN.to_f64().is_nan());
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        if self.is_unknown() {
+            return f64::NAN;
+        }
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(3, 4).as_str(), "3/4");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(3, 4).as_bytes(), b"3/4");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Fraction::new(3, 4).len(), 3);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Fraction::UNKNOWN.is_unknown());
+    /// assert!(!Fraction::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.denominator == 0
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Fraction {
+    /// Euclidean greatest common divisor.
+    const fn gcd(mut a: u64, mut b: u64) -> u64 {
+        while b != 0 {
+            let t = b;
+            b = a % b;
+            a = t;
+        }
+        a
+    }
+
+    /// Reduce to lowest terms, `None` if `denominator == 0`.
+    fn reduce(numerator: i64, denominator: u64) -> Option<(i64, u64)> {
+        if denominator == 0 {
+            return None;
+        }
+        if numerator == 0 {
+            return Some((0, 1));
+        }
+
+        let g = Self::gcd(numerator.unsigned_abs(), denominator);
+        let n = i128::from(numerator) / i128::from(g);
+        Some((n as i64, denominator / g))
+    }
+
+    /// Best rational approximation of `f` with a denominator `<= max_denominator`.
+    ///
+    /// This walks the continued fraction convergents of `f` until the denominator
+    /// would exceed `max_denominator`, then picks between the last convergent
+    /// and the largest semiconvergent that still fits.
+    fn approximate(f: f64, max_denominator: u64) -> Option<(i64, u64)> {
+        if max_denominator == 0 || !f.is_finite() {
+            return None;
+        }
+
+        let negative = f < 0.0;
+        let x = f.abs();
+        if x >= i64::MAX as f64 {
+            return None;
+        }
+
+        // `h` are numerators, `k` are denominators.
+        // `0` is the convergent before the last, `1` is the last.
+        let (mut h0, mut h1) = (0_u64, 1_u64);
+        let (mut k0, mut k1) = (1_u64, 0_u64);
+        let mut r = x;
+
+        // A `u64` can hold at most ~92 terms.
+        for _ in 0..92 {
+            let a = r.floor();
+            let a_u64 = a as u64;
+
+            let next = a_u64
+                .checked_mul(h1)
+                .and_then(|n| n.checked_add(h0))
+                .zip(a_u64.checked_mul(k1).and_then(|n| n.checked_add(k0)));
+
+            match next {
+                Some((h2, k2)) if k2 <= max_denominator => {
+                    (h0, h1) = (h1, h2);
+                    (k0, k1) = (k1, k2);
+                }
+                _ => {
+                    // The largest semiconvergent that fits, `k1` is always
+                    // at least `1` here since the first term always fits.
+                    let t = (max_denominator - k0) / k1;
+                    let h = t.checked_mul(h1).and_then(|n| n.checked_add(h0));
+                    let k = t * k1 + k0;
+
+                    if let Some(h) = h {
+                        let semi = (h as f64 / k as f64 - x).abs();
+                        let last = (h1 as f64 / k1 as f64 - x).abs();
+                        if semi < last {
+                            (h1, k1) = (h, k);
+                        }
+                    }
+                    break;
+                }
+            }
+
+            let fract = r - a;
+            if fract == 0.0 {
+                break;
+            }
+            r = 1.0 / fract;
+        }
+
+        let n = i64::try_from(h1).ok()?;
+        Self::reduce(if negative { -n } else { n }, k1)
+    }
+
+    /// Format an already reduced fraction.
+    fn priv_from(numerator: i64, denominator: u64, mixed: bool) -> Self {
+        let mut string = Str::new();
+        let s = &mut string;
+
+        if numerator < 0 {
+            s.push_str_panic("-");
+        }
+        let abs = numerator.unsigned_abs();
+
+        if denominator == 1 {
+            s.push_str_panic(itoa!(abs));
+        } else if mixed && abs > denominator {
+            s.push_str_panic(itoa!(abs / denominator));
+            s.push_str_panic(" ");
+            s.push_str_panic(itoa!(abs % denominator));
+            s.push_str_panic("/");
+            s.push_str_panic(itoa!(denominator));
+        } else {
+            s.push_str_panic(itoa!(abs));
+            s.push_str_panic("/");
+            s.push_str_panic(itoa!(denominator));
+        }
+
+        Self {
+            numerator,
+            denominator,
+            string,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<f32> for Fraction {
+    #[inline]
+    fn from(f: f32) -> Self {
+        Self::from_f64(f64::from(f), Self::DEFAULT_MAX_DENOMINATOR)
+    }
+}
+
+impl From<f64> for Fraction {
+    #[inline]
+    fn from(f: f64) -> Self {
+        Self::from_f64(f, Self::DEFAULT_MAX_DENOMINATOR)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Fraction {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Fraction {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Fraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Fraction")
+            .field("inner", &(self.numerator, self.denominator))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Fraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Fraction {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Fraction {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Fraction> for str {
+    #[inline]
+    fn eq(&self, other: &Fraction) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Fraction> for &str {
+    #[inline]
+    fn eq(&self, other: &Fraction) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Fraction::new(3, 4)),
+            r#"Fraction { inner: (3, 4), str: "3/4" }"#
+        );
+    }
+
+    #[test]
+    fn reduce() {
+        assert_eq!(Fraction::new(i64::MIN, 2), "-4611686018427387904");
+        assert_eq!(Fraction::new(i64::MIN, 1 << 63), "-1");
+        assert_eq!(
+            Fraction::new(i64::MAX, u64::MAX),
+            "9223372036854775807/18446744073709551615"
+        );
+        assert_eq!(Fraction::new(-0, 7), Fraction::ZERO);
+        assert_eq!(Fraction::new_mixed(-0, 7), Fraction::ZERO);
+    }
+
+    #[test]
+    fn approximate() {
+        let pi = std::f64::consts::PI;
+        assert_eq!(Fraction::from_f64(pi, 1), "3");
+        assert_eq!(Fraction::from_f64(pi, 7), "22/7");
+        assert_eq!(Fraction::from_f64(pi, 100), "311/99");
+        assert_eq!(Fraction::from_f64(pi, 106), "333/106");
+        assert_eq!(Fraction::from_f64(pi, 113), "355/113");
+        assert!((Fraction::from_f64(pi, u64::MAX).to_f64() - pi).abs() < 1e-15);
+
+        for d in 1..=64_u64 {
+            for n in 0..=d {
+                let f = Fraction::from(n as f64 / d as f64);
+                assert_eq!(f, Fraction::new(n as i64, d));
+            }
+        }
+    }
+
+    #[test]
+    fn floats() {
+        assert!(Fraction::from(f32::NAN).is_unknown());
+        assert!(Fraction::from(f64::INFINITY).is_unknown());
+        assert!(Fraction::from(f64::NEG_INFINITY).is_unknown());
+        assert_eq!(Fraction::from(-0.0), Fraction::ZERO);
+        assert_eq!(Fraction::from(1e18), "1000000000000000000");
+        assert_eq!(Fraction::from(0.5_f32), "1/2");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: Fraction = Fraction::new(3, 4);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"{"numerator":3,"denominator":4,"string":"3/4"}"#);
+
+        let this: Fraction = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "3/4");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<Fraction>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&Fraction::UNKNOWN).unwrap();
+        assert!(serde_json::from_str::<Fraction>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this: Fraction = Fraction::new(3, 4);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: Fraction = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "3/4");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&Fraction::UNKNOWN).unwrap();
+        let this: Fraction = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
@@ -1,5 +1,8 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_isize, impl_math, impl_traits};
+use crate::macros::{
+    impl_common, impl_const, impl_format_slice, impl_from_option, impl_from_str, impl_impl_math,
+    impl_isize, impl_math, impl_std_from_str, impl_traits,
+};
 use crate::num::{constants::COMMA, Unsigned};
 use crate::str::Str;
 use std::num::{
@@ -24,6 +27,8 @@ use std::num::{
 /// - You need to use [`Int::try_from`] for anything above [`u32`]
 /// - [`Int::UNKNOWN`] will be returned on error
 ///
+/// For the full [`i128`] range, use [`Int128`](crate::num::Int128).
+///
 /// ## Size
 /// [`Str<26>`] is used internally to represent the string.
 ///
@@ -97,13 +102,15 @@ use std::num::{
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Int(i64, Str<LEN>);
 
 const LEN: usize = 26;
 
 impl_math!(Int, i64);
+impl_from_option!(Int);
 impl_traits!(Int, i64);
+impl_std_from_str!(Int => from_str);
 
 //---------------------------------------------------------------------------------------------------- Int Constants
 impl Int {
@@ -151,7 +158,9 @@ impl Int {
 impl Int {
     impl_common!(i64);
     impl_const!();
+    impl_format_slice!(num, Int, [0_i64, -1_000, 1_234_567, i64::MIN]);
     impl_isize!();
+    impl_from_str!(Int, i64, "-1,234,567", -1_234_567);
 
     #[inline]
     #[must_use]
@@ -730,6 +739,21 @@ impl_f!(f64);
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_str() {
+        for n in [i64::MIN, 0, 1, 999, 1_000, 65_536, 1_234_567, i64::MAX] {
+            let this = Int::from(n);
+            assert_eq!(Int::from_str(this.as_str()).unwrap(), this);
+        }
+        assert_eq!(Int::from_str("1 000_000").unwrap(), 1_000_000);
+        assert_eq!(Int::from_str("+1").unwrap(), 1);
+        assert_eq!(Int::from_str("-1_000").unwrap(), -1_000);
+        assert!(Int::from_str("9,223,372,036,854,775,808").is_err());
+        assert!(Int::from_str("1,000.0").is_err());
+        assert!(Int::from_str(&"0".repeat(100)).is_err());
+        assert!(Int::from_str_silent("a").is_unknown());
+    }
+
     #[test]
     fn unsigned() {
         assert_eq!(Int::from(1_000_i64), "1,000");
@@ -0,0 +1,348 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_from_option, impl_from_str, impl_impl_math, impl_math,
+    impl_std_from_str, impl_traits,
+};
+use crate::num::{Int, Unsigned, Unsigned128};
+use crate::raw::push_grouped;
+use crate::str::Str;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+//---------------------------------------------------------------------------------------------------- Int128
+/// Human readable [`i128`].
+///
+/// This is the same as [`Int`], but the full [`i128`] range is supported.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Int128::from(-1_000_i32), "-1,000");
+/// assert_eq!(Int128::from(i64::MIN as i128 - 1), "-9,223,372,036,854,775,809");
+/// assert_eq!(Int128::MIN, "-170,141,183,460,469,231,731,687,303,715,884,105,728");
+///
+/// // Unsigned integers over `i128::MAX` fail.
+/// assert_eq!(Int128::try_from(u128::MAX), Err(Int128::UNKNOWN));
+/// ```
+///
+/// ## Digits
+/// Integers that are already strings (e.g. from a database) can be formatted with [`Int128::from_digits`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// let digits = "-123456789012345678901234567890";
+/// assert_eq!(Int128::from_digits(digits).unwrap(), "-123,456,789,012,345,678,901,234,567,890");
+/// ```
+///
+/// ## Size
+/// [`Str<52>`] is used internally to represent the string.
+///
+/// The total size depends on the alignment of [`i128`], which is `8` or `16` depending on the Rust version.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(matches!(std::mem::size_of::<Int128>(), 72 | 80));
+/// ```
+///
+/// ## Math
+/// These operators are overloaded, the same as [`Int`]:
+/// - `Add +`
+/// - `Sub -`
+/// - `Div /`
+/// - `Mul *`
+/// - `Rem %`
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Int128::from(10) - 20 == Int128::from(-10));
+/// assert!(Int128::from(10) * -10 == Int128::from(-100));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Int128(i128, Str<{ Int128::MAX_LEN }>);
+
+impl_math!(Int128, i128);
+impl_from_option!(Int128);
+impl_traits!(Int128, i128);
+impl_std_from_str!(Int128 => from_str);
+
+//---------------------------------------------------------------------------------------------------- Int128 Constants
+impl Int128 {
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::ZERO, 0);
+    /// assert_eq!(Int128::ZERO, "0");
+    /// ```
+    pub const ZERO: Self = Self(0, Str::from_static_str("0"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::from(i128::MIN), Int128::MIN);
+    /// assert_eq!(Int128::MIN, "-170,141,183,460,469,231,731,687,303,715,884,105,728");
+    /// ```
+    pub const MIN: Self = Self(
+        i128::MIN,
+        Str::from_static_str("-170,141,183,460,469,231,731,687,303,715,884,105,728"),
+    );
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::from(i128::MAX), Int128::MAX);
+    /// assert_eq!(Int128::MAX, "170,141,183,460,469,231,731,687,303,715,884,105,727");
+    /// ```
+    pub const MAX: Self = Self(
+        i128::MAX,
+        Str::from_static_str("170,141,183,460,469,231,731,687,303,715,884,105,727"),
+    );
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::try_from(u128::MAX), Err(Int128::UNKNOWN));
+    /// assert_eq!(Int128::UNKNOWN, 0);
+    /// assert_eq!(Int128::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("???"));
+
+    /// The maximum string length of an [`Int128`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::MIN.len(), 52);
+    /// ```
+    pub const MAX_LEN: usize = 52;
+}
+
+//---------------------------------------------------------------------------------------------------- Int128 Impl
+impl Int128 {
+    impl_common!(i128);
+    impl_const!();
+    impl_from_str!(Int128, i128, "-1,234,567", -1_234_567);
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Int128::UNKNOWN.is_unknown());
+    /// assert!(!Int128::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+
+    /// Format a string of plain ASCII digits with an optional leading `-`, e.g. `"-1234567"`
+    ///
+    /// Unlike [`Int128::from_str`], this is strict: there is
+    /// no whitespace trimming and no separators are allowed.
+    ///
+    /// Leading zeros are ignored.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Int128::from_digits("-1234567").unwrap(), "-1,234,567");
+    /// assert_eq!(Int128::from_digits("000100").unwrap(), "100");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is empty, contains anything other than `0-9` after the optional `-`,
+    /// or is out of the [`i128`] range, [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Int128::from_digits("").is_err());
+    /// assert!(Int128::from_digits("-").is_err());
+    /// assert!(Int128::from_digits("+1").is_err());
+    /// assert!(Int128::from_digits("-1,000").is_err());
+    /// assert!(Int128::from_digits("170141183460469231731687303715884105728").is_err());
+    /// ```
+    pub fn from_digits(digits: &str) -> Result<Self, Self> {
+        let unsigned = digits.strip_prefix('-').unwrap_or(digits);
+        if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Self::UNKNOWN);
+        }
+
+        match digits.parse::<i128>() {
+            Ok(i) => Ok(Self::from(i)),
+            Err(_) => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::from_digits`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_digits_silent(digits: &str) -> Self {
+        match Self::from_digits(digits) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private functions.
+impl Int128 {
+    #[inline]
+    fn from_priv(i: i128) -> Self {
+        let mut s = Str::new();
+        if i.is_negative() {
+            s.push_char_panic('-');
+        }
+        push_grouped(
+            &mut s,
+            crate::toa::Itoa::new(i.unsigned_abs()).as_str().as_bytes(),
+        );
+        Self(i, s)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `i*`
+macro_rules! impl_i {
+	($( $from:ty ),* $(,)?) => {
+		$(
+			impl From<$from> for Int128 {
+				#[inline]
+				fn from(int: $from) -> Self {
+					Self::from_priv(int as i128)
+				}
+			}
+			impl From<&$from> for Int128 {
+				#[inline]
+				fn from(int: &$from) -> Self {
+					Self::from_priv(*int as i128)
+				}
+			}
+		)*
+	}
+}
+impl_i!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+//---------------------------------------------------------------------------------------------------- From `NonZero*`
+macro_rules! impl_noni {
+	($( $from:ty ),* $(,)?) => {
+		$(
+			impl From<$from> for Int128 {
+				#[inline]
+				fn from(int: $from) -> Self {
+					Self::from_priv(int.get() as i128)
+				}
+			}
+		)*
+	}
+}
+impl_noni! {
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    &NonZeroI8, &NonZeroI16, &NonZeroI32, &NonZeroI64, &NonZeroI128, &NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64,
+    &NonZeroU8, &NonZeroU16, &NonZeroU32, &NonZeroU64,
+}
+
+//---------------------------------------------------------------------------------------------------- From `u128`
+/// This will return [`Self::UNKNOWN`] wrapped
+/// in [`Result::Err`] if the input is over [`i128::MAX`].
+impl TryFrom<u128> for Int128 {
+    type Error = Self;
+    #[inline]
+    fn try_from(uint: u128) -> Result<Self, Self> {
+        match i128::try_from(uint) {
+            Ok(i) => Ok(Self::from_priv(i)),
+            Err(_) => Err(Self::UNKNOWN),
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `readable::num`
+impl From<Int> for Int128 {
+    #[inline]
+    fn from(int: Int) -> Self {
+        if int.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_priv(i128::from(int.inner()))
+    }
+}
+
+impl From<Unsigned> for Int128 {
+    #[inline]
+    fn from(unsigned: Unsigned) -> Self {
+        if unsigned.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_priv(i128::from(unsigned.inner()))
+    }
+}
+
+/// This will return [`Self::UNKNOWN`] wrapped
+/// in [`Result::Err`] if the input is over [`i128::MAX`] or unknown.
+impl TryFrom<Unsigned128> for Int128 {
+    type Error = Self;
+    #[inline]
+    fn try_from(unsigned: Unsigned128) -> Result<Self, Self> {
+        if unsigned.is_unknown() {
+            return Err(Self::UNKNOWN);
+        }
+        Self::try_from(unsigned.inner())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int() {
+        // Every length matches `Int` in the `i64` range.
+        let mut n = 1_i64;
+        loop {
+            for i in [n, n - 1, -n, -(n - 1)] {
+                assert_eq!(Int128::from(i).as_str(), Int::from(i).as_str());
+            }
+            match n.checked_mul(10) {
+                Some(next) => n = next,
+                None => break,
+            }
+        }
+        assert_eq!(Int128::from(i64::MIN).as_str(), Int::MIN.as_str());
+        assert_eq!(Int128::from(i64::MAX).as_str(), Int::MAX.as_str());
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(Int128::from(i128::MIN), Int128::MIN);
+        assert_eq!(Int128::from(i128::MAX), Int128::MAX);
+        assert_eq!(Int128::MIN.len(), Int128::MAX_LEN);
+    }
+
+    #[test]
+    fn digits() {
+        let min = i128::MIN.to_string();
+        assert_eq!(Int128::from_digits(&min).unwrap(), Int128::MIN);
+        assert_eq!(Int128::from_digits("-0").unwrap(), Int128::ZERO);
+        assert!(Int128::from_digits("--1").is_err());
+        assert!(Int128::from_digits("1-").is_err());
+        assert!(Int128::from_digits_silent(" 1").is_unknown());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Int128::from_str(Int128::MIN.as_str()).unwrap(), Int128::MIN);
+        assert!(Int128::from_str("1.5").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Int128::from(-1_000);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[-1000,"-1,000"]"#);
+
+        let this: Int128 = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, -1_000);
+    }
+}
@@ -0,0 +1,116 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::impl_json_value;
+use crate::num::{Float, Int, Percent, Unsigned};
+
+//---------------------------------------------------------------------------------------------------- serde_json
+/// This will return [`Self::UNKNOWN`] wrapped in [`Result::Err`]
+/// if the number is negative or too large for a [`u64`].
+impl TryFrom<&serde_json::Number> for Unsigned {
+    type Error = Self;
+    #[inline]
+    fn try_from(number: &serde_json::Number) -> Result<Self, Self> {
+        match (number.as_u64(), number.as_f64()) {
+            (Some(u), _) => Ok(Self::from(u)),
+            (None, Some(f)) => Self::try_from(f),
+            (None, None) => Err(Self::UNKNOWN),
+        }
+    }
+}
+
+/// This will return [`Self::UNKNOWN`] wrapped in [`Result::Err`]
+/// if the number is too large for an [`i64`].
+impl TryFrom<&serde_json::Number> for Int {
+    type Error = Self;
+    #[inline]
+    fn try_from(number: &serde_json::Number) -> Result<Self, Self> {
+        match (number.as_i64(), number.as_u64(), number.as_f64()) {
+            (Some(i), _, _) => Ok(Self::from(i)),
+            (None, Some(u), _) => Self::try_from(u),
+            (None, None, Some(f)) => Self::try_from(f),
+            (None, None, None) => Err(Self::UNKNOWN),
+        }
+    }
+}
+
+// Implements `TryFrom<&serde_json::Number>` for the `f64` based types.
+macro_rules! impl_f64 {
+	($($type:ty),*) => {
+		$(
+			/// This will return [`Self::UNKNOWN`] wrapped in [`Result::Err`]
+			/// if the number is too large to format.
+			impl TryFrom<&serde_json::Number> for $type {
+				type Error = Self;
+				#[inline]
+				fn try_from(number: &serde_json::Number) -> Result<Self, Self> {
+					let Some(f) = number.as_f64() else {
+						return Err(Self::UNKNOWN);
+					};
+					let this = Self::from(f);
+					if this.is_unknown() {
+						Err(Self::UNKNOWN)
+					} else {
+						Ok(this)
+					}
+				}
+			}
+		)*
+	};
+}
+impl_f64!(Float, Percent);
+
+impl_json_value!(Unsigned, Int, Float, Percent);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(Unsigned::try_from(&json!(1_000)).unwrap(), "1,000");
+        assert_eq!(Unsigned::try_from(&json!(u64::MAX)).unwrap(), u64::MAX);
+        assert_eq!(Unsigned::try_from(&json!(1_000.9)).unwrap(), "1,000");
+        assert_eq!(Unsigned::try_from(&json!(" 1000 ")).unwrap(), "1,000");
+        assert_eq!(Unsigned::try_from(&json!("1e3")).unwrap(), "1,000");
+        assert_eq!(Unsigned::try_from(&json!(-1)), Err(Unsigned::UNKNOWN));
+        assert_eq!(Unsigned::try_from(&json!(1e30)), Err(Unsigned::UNKNOWN));
+    }
+
+    #[test]
+    fn int() {
+        assert_eq!(Int::try_from(&json!(-1_000)).unwrap(), "-1,000");
+        assert_eq!(Int::try_from(&json!(i64::MIN)).unwrap(), i64::MIN);
+        assert_eq!(Int::try_from(&json!(-1_000.9)).unwrap(), "-1,000");
+        assert_eq!(Int::try_from(&json!("-1000")).unwrap(), "-1,000");
+        assert_eq!(Int::try_from(&json!(u64::MAX)), Err(Int::UNKNOWN));
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Float::try_from(&json!(1_000.123)).unwrap(), "1,000.123");
+        assert_eq!(Float::try_from(&json!(1_000)).unwrap(), "1,000.000");
+        assert_eq!(Float::try_from(&json!("1000.123")).unwrap(), "1,000.123");
+        assert_eq!(Float::try_from(&json!(1e30)), Err(Float::UNKNOWN));
+
+        assert_eq!(Percent::try_from(&json!(50.5)).unwrap(), "50.50%");
+        assert_eq!(Percent::try_from(&json!("50")).unwrap(), "50.00%");
+    }
+
+    #[test]
+    fn non_number() {
+        for value in [
+            json!(null),
+            json!(true),
+            json!("one"),
+            json!(""),
+            json!([1]),
+            json!({ "a": 1 }),
+        ] {
+            assert_eq!(Unsigned::try_from(&value), Err(Unsigned::UNKNOWN));
+            assert_eq!(Int::try_from(&value), Err(Int::UNKNOWN));
+            assert_eq!(Float::try_from(&value), Err(Float::UNKNOWN));
+            assert_eq!(Percent::try_from(&value), Err(Percent::UNKNOWN));
+        }
+    }
+}
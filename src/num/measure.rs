@@ -0,0 +1,335 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::num::float::push_rounded;
+use crate::num::FloatSig;
+use crate::str::Str;
+use crate::Rounding;
+use compact_str::format_compact;
+
+//---------------------------------------------------------------------------------------------------- Measure
+/// Human readable measurement with an uncertainty
+///
+/// This takes a `(value, uncertainty)` and formats it as `value ± uncertainty`.
+///
+/// The uncertainty is rounded to `1` significant figure
+/// (with [`FloatSig<1>`]), and the value is rounded to the same decimal place:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Measure::new(12.34, 0.43),     "12.3 ± 0.4");
+/// assert_eq!(Measure::new(9.80665, 0.0021), "9.807 ± 0.002");
+/// assert_eq!(Measure::new(299_792.458, 4.2), "299,792 ± 4");
+/// assert_eq!(Measure::new(1_234.5, 56.0),   "1,230 ± 60");
+/// assert_eq!(Measure::new(-0.5, 0.25),      "-0.5 ± 0.2");
+/// ```
+///
+/// The uncertainty's rounding can carry into the next decimal place:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Measure::new(12.34, 0.96), "12 ± 1");
+/// ```
+///
+/// A negative uncertainty is treated as positive, and a
+/// `0.0` uncertainty formats both numbers with `3` decimals.
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Measure::new(12.34, -0.43), "12.3 ± 0.4");
+/// assert_eq!(Measure::new(12.34, 0.0),   "12.340 ± 0.000");
+/// ```
+///
+/// ## Errors
+/// [`Measure::UNKNOWN`] is returned if:
+/// - Either number is [`f64::NAN`], [`f64::INFINITY`], or [`f64::NEG_INFINITY`]
+/// - The string is longer than [`Measure::MAX_LEN`]
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Measure::new(f64::NAN, 0.1).is_unknown());
+/// assert!(Measure::new(1.0, f64::INFINITY).is_unknown());
+/// assert!(Measure::new(1e30, 0.1).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<48>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Measure>(), 72);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct Measure {
+    value: f64,
+    uncertainty: f64,
+    string: Str<{ Measure::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Measure {
+    /// The maximum string length of a [`Measure`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let m = Measure::new(-1_234_567_890_123.4, 0.1);
+    /// assert_eq!(m, "-1,234,567,890,123.4 ± 0.1");
+    /// assert!(m.len() <= Measure::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 48;
+
+    /// Returned on error situations
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Measure::UNKNOWN, "? ± ?");
+    /// assert_eq!(Measure::UNKNOWN.value(), 0.0);
+    /// assert_eq!(Measure::UNKNOWN.uncertainty(), 0.0);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        value: 0.0,
+        uncertainty: 0.0,
+        string: Str::from_static_str("? ± ?"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Measure Impl
+impl Measure {
+    #[must_use]
+    /// Create a new [`Measure`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let m = Measure::new(12.34, 0.43);
+    /// assert_eq!(m, "12.3 ± 0.4");
+    /// assert_eq!(m.value(), 12.34);
+    /// assert_eq!(m.uncertainty(), 0.43);
+    /// ```
+    pub fn new(value: f64, uncertainty: f64) -> Self {
+        if !value.is_finite() || !uncertainty.is_finite() {
+            return Self::UNKNOWN;
+        }
+
+        let uncertainty = uncertainty.abs();
+        let mut string = Str::new();
+
+        if uncertainty == 0.0 {
+            if !push_rounded(&mut string, value, 3, Rounding::HalfEven, " ± ")
+                || !push_rounded(&mut string, 0.0, 3, Rounding::HalfEven, "")
+            {
+                return Self::UNKNOWN;
+            }
+        } else {
+            // The exponent of the uncertainty's significant figure, after rounding.
+            let exp = format_compact!("{uncertainty:.0e}")
+                .split_once('e')
+                .and_then(|(_, exp)| exp.parse::<i32>().ok())
+                .unwrap_or(0);
+
+            // Round the value to the same decimal place.
+            let fits = if exp < 0 {
+                let decimals = exp.unsigned_abs() as usize;
+                push_rounded(&mut string, value, decimals, Rounding::HalfEven, " ± ")
+            } else {
+                let scale = 10_f64.powi(exp);
+                let scaled = value / scale;
+                let trunc = scaled.trunc();
+                let rest = (scaled - trunc).abs().total_cmp(&0.5);
+                let value = if Rounding::HalfEven.round_up(trunc % 2.0 != 0.0, rest) {
+                    (trunc + scaled.signum()) * scale
+                } else {
+                    trunc * scale
+                };
+                push_rounded(&mut string, value, 0, Rounding::HalfEven, " ± ")
+            };
+
+            let sig = FloatSig::<1>::from(uncertainty);
+            if !fits || sig.is_unknown() || sig.len() > string.remaining() {
+                return Self::UNKNOWN;
+            }
+            string.push_str_panic(sig.as_str());
+        }
+
+        Self {
+            value,
+            uncertainty,
+            string,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the inner value
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the inner uncertainty, this is always positive
+    pub const fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    /// The length of the inner [`String`]
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Measure::UNKNOWN.is_unknown());
+    /// assert!(!Measure::new(1.0, 0.1).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.as_bytes(), b"? \xC2\xB1 ?")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<(f64, f64)> for Measure {
+    #[inline]
+    fn from((value, uncertainty): (f64, f64)) -> Self {
+        Self::new(value, uncertainty)
+    }
+}
+
+impl From<(f32, f32)> for Measure {
+    #[inline]
+    fn from((value, uncertainty): (f32, f32)) -> Self {
+        Self::new(f64::from(value), f64::from(uncertainty))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Measure {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Measure {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Measure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Measure")
+            .field("inner", &(self.value, self.uncertainty))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Measure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Measure {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Measure {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Measure::new(12.34, 0.43)),
+            r#"Measure { inner: (12.34, 0.43), str: "12.3 ± 0.4" }"#
+        );
+    }
+
+    #[test]
+    fn decimals() {
+        assert_eq!(Measure::new(1.234_567, 0.000_03), "1.23457 ± 0.00003");
+        assert_eq!(Measure::new(1.234_567, 0.003), "1.235 ± 0.003");
+        assert_eq!(Measure::new(1.234_567, 0.3), "1.2 ± 0.3");
+        assert_eq!(Measure::new(1.234_567, 3.0), "1 ± 3");
+        assert_eq!(Measure::new(1.234_567, 30.0), "0 ± 30");
+        assert_eq!(Measure::new(123_456.7, 3_000.0), "123,000 ± 3,000");
+        assert_eq!(Measure::new(0.0, 0.009_7), "0.00 ± 0.01");
+    }
+
+    #[test]
+    fn ties() {
+        // Both sides of the decimal point round half to even.
+        assert_eq!(Measure::new(0.25, 0.1), "0.2 ± 0.1");
+        assert_eq!(Measure::new(0.35, 0.1), "0.4 ± 0.1");
+        assert_eq!(Measure::new(25.0, 10.0), "20 ± 10");
+        assert_eq!(Measure::new(35.0, 10.0), "40 ± 10");
+        assert_eq!(Measure::new(-25.0, 10.0), "-20 ± 10");
+        assert_eq!(Measure::new(2.5, 1.0), "2 ± 1");
+        assert_eq!(Measure::new(250.0, 100.0), "200 ± 100");
+        assert_eq!(Measure::new(251.0, 100.0), "300 ± 100");
+    }
+
+    #[test]
+    fn tuple() {
+        assert_eq!(Measure::from((12.34, 0.43)), Measure::new(12.34, 0.43));
+        assert_eq!(Measure::from((12.5_f32, 0.5_f32)), "12.5 ± 0.5");
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(Measure::new(f64::NEG_INFINITY, 1.0).is_unknown());
+        assert!(Measure::new(1.0, f64::NAN).is_unknown());
+        assert!(Measure::new(1.0, 1e-40).is_unknown());
+        assert!(Measure::new(1e300, 1e299).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Measure::new(12.34, 0.43);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(
+            json,
+            r#"{"value":12.34,"uncertainty":0.43,"string":"12.3 ± 0.4"}"#
+        );
+
+        let this: Measure = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, Measure::new(12.34, 0.43));
+        assert_eq!(this, "12.3 ± 0.4");
+    }
+}
@@ -6,11 +6,50 @@ pub use int::*;
 mod float;
 pub use float::*;
 
+mod float_sig;
+pub use float_sig::*;
+
 mod percent;
 pub use percent::*;
 
+mod permille;
+pub use permille::*;
+
+mod ppm;
+pub use ppm::*;
+
+mod percent_bar;
+pub use percent_bar::*;
+
+mod sparkline;
+pub use sparkline::*;
+
+mod fraction;
+pub use fraction::*;
+
+mod accounting;
+pub use accounting::*;
+
+mod measure;
+pub use measure::*;
+
 mod unsigned;
 pub use unsigned::*;
 
+mod unsigned128;
+pub use unsigned128::*;
+
+mod int128;
+pub use int128::*;
+
+mod zero_pad;
+pub use zero_pad::*;
+
+mod separated;
+pub use separated::*;
+
 mod constants;
 pub use constants::*;
+
+#[cfg(feature = "serde_json")]
+mod interop;
@@ -1,10 +1,13 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::macros::{
-    impl_common, impl_const, impl_impl_math, impl_isize, impl_math, impl_traits, impl_usize,
-    return_bad_float, str_i64, str_u64,
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_isize, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float, str_i64,
+    str_u64,
 };
 use crate::num::constants::{INFINITY, NAN};
+use crate::num::float::push_rounded;
 use crate::str::Str;
+use crate::Rounding;
 use compact_str::format_compact;
 
 //---------------------------------------------------------------------------------------------------- Percent
@@ -126,13 +129,41 @@ use compact_str::format_compact;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+#[derive(Copy, Clone)]
 pub struct Percent(f64, Str<{ Percent::MAX_LEN }>);
 
 const LEN: usize = 22; // 14 decimal point accuracy + 8 extra chars
 
 impl_math!(Percent, f64);
-impl_traits!(Percent, f64);
+impl_from_option!(Percent);
+impl_traits!(@common Percent, f64);
+impl_float_eq!(Percent => f64);
+impl_std_from_str!(Percent => from_str);
+#[cfg(feature = "clap")]
+crate::macros::impl_clap!(Percent, "a percent, e.g. `50%` or `12.5`");
+
+// Same as `impl_traits!(Percent, f64, precision)`,
+// but the precision flag keeps the [`PercentFormat`] suffix.
+impl std::fmt::Display for Percent {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) if self.0.is_finite() && !self.is_unknown() => {
+                let percent = Self::priv_precision(self.0, p);
+                match percent.priv_with_suffix(self.priv_suffix()) {
+                    Some(s) => crate::str::pad(f, s.as_str()),
+                    None => crate::str::pad(f, percent.as_str()),
+                }
+            }
+            _ => crate::str::pad(f, self.1.as_str()),
+        }
+    }
+}
 
 //---------------------------------------------------------------------------------------------------- Percent Constants
 impl Percent {
@@ -201,6 +232,7 @@ macro_rules! impl_new {
 impl Percent {
     impl_common!(f64);
     impl_const!();
+    impl_format_slice!(num, Percent, [0.0_f64, 50.0, 99.999, 100.0]);
     impl_usize!();
     impl_isize!();
 
@@ -259,6 +291,246 @@ impl Percent {
     seq_macro::seq!(N in 3..=14 {
         impl_new!(N);
     });
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a percent, e.g. `50%`
+    ///
+    /// The `%` is optional and can have a space before it,
+    /// the number is parsed with [`Float::from_str`](crate::num::Float::from_str)
+    /// so `,` thousands separators are allowed.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Percent::from_str("50%").unwrap(),       "50.00%");
+    /// assert_eq!(Percent::from_str("50.5 %").unwrap(),    "50.50%");
+    /// assert_eq!(Percent::from_str("12.5").unwrap(),      "12.50%");
+    /// assert_eq!(Percent::from_str("1,000.00%").unwrap(), 1_000.0);
+    ///
+    /// // Formatted strings parse back.
+    /// let percent = Percent::from(12.34);
+    /// assert_eq!(Percent::from_str(&percent).unwrap(), percent);
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not a finite number,
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Percent::from_str("").is_err());
+    /// assert!(Percent::from_str("%").is_err());
+    /// assert!(Percent::from_str("50%%").is_err());
+    /// assert!(Percent::from_str("NaN").is_err());
+    /// assert!(Percent::from_str("?.??%").is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        let string = string.trim();
+        let number = string.strip_suffix('%').unwrap_or(string);
+
+        match crate::num::Float::from_str(number, crate::num::DecimalMark::Point) {
+            Ok(float) if float.inner().is_finite() => match Self::from(float.inner()) {
+                this if this.is_unknown() => Err(this),
+                this => Ok(this),
+            },
+            _ => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Percent::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[must_use]
+    /// Re-format the symbol and spacing with a [`PercentFormat`]
+    ///
+    /// The number itself (and its precision) stays the same.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let percent = Percent::from(50.0);
+    /// assert_eq!(percent.with_format(PercentFormat::SPACE), "50.00 %");
+    /// assert_eq!(percent.with_format(PercentFormat::BARE),  "50.00");
+    ///
+    /// // French uses a narrow no-break space.
+    /// let percent = Percent::new_0(50.0).with_format(PercentFormat::NARROW_NO_BREAK_SPACE);
+    /// assert_eq!(percent, "50\u{202f}%");
+    ///
+    /// // Back to the default.
+    /// assert_eq!(percent.with_format(PercentFormat::DEFAULT), "50%");
+    ///
+    /// // The `{:.X}` flag keeps the format.
+    /// assert_eq!(format!("{:.1}", percent), "50.0\u{202f}%");
+    /// ```
+    ///
+    /// [`Self::NAN`], [`Self::INFINITY`] and [`Self::UNKNOWN`] are returned as-is.
+    ///
+    /// If the new string is longer than [`Self::MAX_LEN`], [`Self::UNKNOWN`] is returned.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Percent::UNKNOWN.with_format(PercentFormat::SPACE).is_unknown());
+    /// assert!(Percent::new_14(10_000.0).with_format(PercentFormat::SPACE).is_unknown());
+    /// ```
+    pub fn with_format(self, format: PercentFormat) -> Self {
+        if !self.0.is_finite() || self.is_unknown() {
+            return self;
+        }
+
+        let mut suffix = Str::<8>::new();
+        if let Some(symbol) = format.symbol {
+            if let Some(space) = format.space {
+                suffix.push_char_panic(space);
+            }
+            suffix.push_char_panic(symbol);
+        }
+
+        self.priv_with_suffix(suffix.as_str())
+            .unwrap_or(Self::UNKNOWN)
+    }
+
+    #[must_use]
+    /// Create a [`Percent`] with `decimals` floating point, rounded with a specific [`Rounding`]
+    ///
+    /// `decimals` above `14` is treated as `14`.
+    ///
+    /// ```rust
+    /// # use readable::{num::*,Rounding};
+    /// assert_eq!(Percent::with_rounding(12.345, 2, Rounding::Truncate), "12.34%");
+    /// assert_eq!(Percent::with_rounding(12.345, 2, Rounding::HalfUp),   "12.35%");
+    /// assert_eq!(Percent::with_rounding(12.345, 2, Rounding::HalfEven), "12.34%");
+    /// assert_eq!(Percent::with_rounding(99.95,  1, Rounding::HalfUp),   "100.0%");
+    /// assert_eq!(Percent::with_rounding(1234.5, 0, Rounding::HalfEven), "1,234%");
+    /// ```
+    ///
+    /// [`Percent::UNKNOWN`] is returned if the string is longer than [`Percent::MAX_LEN`].
+    ///
+    /// ```rust
+    /// # use readable::{num::*,Rounding};
+    /// assert!(Percent::with_rounding(1e20, 2, Rounding::HalfUp).is_unknown());
+    /// ```
+    pub fn with_rounding(f: f64, decimals: u8, rounding: Rounding) -> Self {
+        return_bad_float!(f, Self::NAN, Self::INFINITY);
+
+        let mut s = Str::new();
+        if push_rounded(&mut s, f, usize::from(decimals.min(14)), rounding, "%") {
+            Self(f, s)
+        } else {
+            Self::UNKNOWN
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- PercentFormat
+/// The symbol and spacing used by [`Percent::with_format`]
+///
+/// ```rust
+/// # use readable::num::*;
+/// let format = PercentFormat {
+///     space: Some(' '),
+///     symbol: Some('٪'),
+/// };
+/// assert_eq!(Percent::from(50.0).with_format(format), "50.00 ٪");
+///
+/// // No symbol means no space either.
+/// let format = PercentFormat {
+///     space: Some(' '),
+///     symbol: None,
+/// };
+/// assert_eq!(Percent::from(50.0).with_format(format), "50.00");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+// `borsh` does not implement its traits for `char`.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PercentFormat {
+    /// Inserted in-between the number and the symbol, e.g. ` ` for `50 %`
+    pub space: Option<char>,
+    /// The symbol, e.g. `%`, or [`None`] for just the number
+    pub symbol: Option<char>,
+}
+
+impl PercentFormat {
+    /// No space, `%` symbol, e.g. `50%`
+    ///
+    /// This is what all [`Percent`] constructors use.
+    pub const DEFAULT: Self = Self {
+        space: None,
+        symbol: Some('%'),
+    };
+
+    /// Space before the `%` symbol, e.g. `50 %` (SI, German)
+    pub const SPACE: Self = Self {
+        space: Some(' '),
+        symbol: Some('%'),
+    };
+
+    /// No-break space (`U+00A0`) before the `%` symbol, e.g. `50\u{a0}%`
+    pub const NO_BREAK_SPACE: Self = Self {
+        space: Some('\u{a0}'),
+        symbol: Some('%'),
+    };
+
+    /// Narrow no-break space (`U+202F`) before the `%` symbol, e.g. `50\u{202f}%` (French)
+    pub const NARROW_NO_BREAK_SPACE: Self = Self {
+        space: Some('\u{202f}'),
+        symbol: Some('%'),
+    };
+
+    /// No symbol, just the number, e.g. `50`
+    pub const BARE: Self = Self {
+        space: None,
+        symbol: None,
+    };
+}
+
+impl Default for PercentFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Percent {
+    /// Everything after the number, e.g. `%` or ` %`.
+    fn priv_suffix(&self) -> &str {
+        let s = self.as_str();
+        let number = s.trim_end_matches(|c: char| !c.is_ascii_digit());
+        &s[number.len()..]
+    }
+
+    /// Swap the suffix for `suffix`, [`None`] if it doesn't fit.
+    fn priv_with_suffix(&self, suffix: &str) -> Option<Self> {
+        let s = self.as_str();
+        let number = &s[..s.len() - self.priv_suffix().len()];
+
+        let mut string = Str::new();
+        string.push_str(number).ok()?;
+        string.push_str(suffix).ok()?;
+        Some(Self(self.0, string))
+    }
+
+    /// Dispatch to `new_X` with `precision`, used by the `{:.X}` formatting flag.
+    ///
+    /// Anything over `14` is the same as `14`.
+    pub(super) fn priv_precision(f: f64, precision: usize) -> Self {
+        seq_macro::seq!(N in 3..=14 {
+            match precision {
+                0 => Self::new_0(f),
+                1 => Self::new_1(f),
+                2 => Self::from(f),
+                #( N => Self::new_~N(f), )*
+                _ => Self::new_14(f),
+            }
+        })
+    }
 }
 
 // Implementation Macro.
@@ -331,11 +603,69 @@ impl From<f64> for Percent {
     }
 }
 
+//---------------------------------------------------------------------------------------------------- SQL
+// Stored as a `DOUBLE PRECISION`.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+impl Percent {
+    #[inline]
+    fn priv_from_sql(f: f64) -> Self {
+        Self::from(f)
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_wraps)]
+    const fn priv_to_sql(&self) -> Option<&f64> {
+        Some(&self.0)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+crate::macros::impl_sqlx!(Percent => f64);
+#[cfg(feature = "diesel")]
+crate::macros::impl_diesel!(Percent => f64 as Double);
+
 //---------------------------------------------------------------------------------------------------- TESTS
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "clap")]
+    fn clap() {
+        let command = clap::Command::new("test").arg(
+            clap::Arg::new("arg")
+                .long("arg")
+                .value_parser(clap::value_parser!(Percent)),
+        );
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["test", "--arg", "12.5%"])
+            .unwrap();
+        assert_eq!(*matches.get_one::<Percent>("arg").unwrap(), 12.5);
+
+        let error = command
+            .try_get_matches_from(["test", "--arg", "12.5%%"])
+            .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(error.to_string().contains("expected a percent"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "sqlx", feature = "diesel"))]
+    fn sql() {
+        assert_eq!(Percent::priv_from_sql(50.5), "50.50%");
+        assert!(Percent::priv_from_sql(f64::NAN).is_nan());
+        assert_eq!(Percent::from(50.5).priv_to_sql(), Some(&50.5));
+    }
+
+    #[test]
+    fn from_option() {
+        assert_eq!(Percent::from(Some(50.0)), "50.00%");
+        assert_eq!(Percent::from(Some(5_u8)), "5.00%");
+        assert!(Percent::from(None::<f64>).is_unknown());
+    }
+
     #[test]
     fn special() {
         assert_eq!(Percent::ZERO, "0.00%");
@@ -393,6 +723,38 @@ mod tests {
         assert_eq!(Percent::from(-1_000_000_i32), "-1,000,000.00%");
     }
 
+    #[test]
+    fn with_format() {
+        let formats = [
+            PercentFormat::DEFAULT,
+            PercentFormat::SPACE,
+            PercentFormat::NO_BREAK_SPACE,
+            PercentFormat::NARROW_NO_BREAK_SPACE,
+            PercentFormat::BARE,
+        ];
+        for a in formats {
+            for b in formats {
+                // Formats can be swapped back and forth.
+                let percent = Percent::from(-12.5).with_format(a).with_format(b);
+                assert_eq!(percent, Percent::from(-12.5).with_format(b));
+                assert_eq!(percent, -12.5);
+            }
+        }
+
+        assert_eq!(Percent::NAN.with_format(PercentFormat::BARE), NAN);
+        assert_eq!(Percent::INFINITY.with_format(PercentFormat::BARE), INFINITY);
+    }
+
+    #[test]
+    fn with_format_precision() {
+        let percent = Percent::from(50.0).with_format(PercentFormat::SPACE);
+        assert_eq!(format!("{:.0}", percent), "50 %");
+        assert_eq!(format!("{:>8.1}", percent), "  50.0 %");
+
+        let percent = Percent::from(50.0).with_format(PercentFormat::BARE);
+        assert_eq!(format!("{:.3}", percent), "50.000");
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
@@ -0,0 +1,385 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::num::Percent;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- PercentBar
+/// Human-readable progress bar with a percent
+///
+/// This renders a progress bar of a configurable width with a [`Percent`] appended:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(PercentBar::from(50.0),            "[#####-----] 50%");
+/// assert_eq!(PercentBar::new(25.0, 20),         "[#####---------------] 25%");
+/// assert_eq!(PercentBar::new_unicode(55.0, 10), "[█████▌    ] 55%");
+/// ```
+///
+/// ## Clamping
+/// The input is clamped in-between `0.0..=100.0`.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(PercentBar::from(-5.0),  "[----------] 0%");
+/// assert_eq!(PercentBar::from(150.0), "[##########] 100%");
+/// ```
+///
+/// The width is clamped to [`PercentBar::MAX_WIDTH`].
+///
+/// The bar is only filled when a full segment is reached, and the [`Percent`] has no decimals,
+/// so the bar and number always agree, e.g. `99.9` is still `99%` and not a full bar.
+///
+/// ## Unicode
+/// [`PercentBar::new_unicode`] uses block characters, and will
+/// fill the bar in `1/8` segments of a single character.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(PercentBar::new_unicode(0.0, 4),   "[    ] 0%");
+/// assert_eq!(PercentBar::new_unicode(62.5, 4),  "[██▍ ] 62%");
+/// assert_eq!(PercentBar::new_unicode(100.0, 4), "[████] 100%");
+/// ```
+///
+/// ## Unknown
+/// [`PercentBar::UNKNOWN`] is returned if the input is [`f64::NAN`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(PercentBar::from(f64::NAN).is_unknown());
+/// assert!(PercentBar::from(Percent::UNKNOWN).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<157>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<PercentBar>(), 192);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct PercentBar {
+    percent: Percent,
+    width: u8,
+    string: Str<{ PercentBar::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl PercentBar {
+    /// The default bar width, used in [`PercentBar::from`]
+    pub const DEFAULT_WIDTH: u8 = 10;
+
+    /// The maximum bar width
+    pub const MAX_WIDTH: u8 = 50;
+
+    /// The maximum string length of a [`PercentBar`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let bar = PercentBar::new_unicode(100.0, PercentBar::MAX_WIDTH);
+    /// assert_eq!(bar.len(), PercentBar::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 157;
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::UNKNOWN, "[??????????] ?%");
+    /// assert_eq!(PercentBar::UNKNOWN.percent(), Percent::UNKNOWN);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        percent: Percent::UNKNOWN,
+        width: Self::DEFAULT_WIDTH,
+        string: Str::from_static_str("[??????????] ?%"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- PercentBar Impl
+impl PercentBar {
+    #[must_use]
+    /// Create an ASCII [`PercentBar`] with a `width`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new(66.7, 3), "[#--] 66%");
+    /// assert_eq!(PercentBar::new(100.0, 0), "[] 100%");
+    /// ```
+    pub fn new(percent: f64, width: u8) -> Self {
+        Self::priv_from(percent, width, false)
+    }
+
+    #[must_use]
+    /// Create a Unicode block [`PercentBar`] with a `width`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new_unicode(50.0, 3), "[█▌ ] 50%");
+    /// ```
+    pub fn new_unicode(percent: f64, width: u8) -> Self {
+        Self::priv_from(percent, width, true)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the [`Percent`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::from(150.0).percent(), 100.0);
+    /// ```
+    pub const fn percent(&self) -> Percent {
+        self.percent
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the bar width
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new(0.0, 255).width(), PercentBar::MAX_WIDTH);
+    /// ```
+    pub const fn width(&self) -> u8 {
+        self.width
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new(50.0, 2).as_str(), "[#-] 50%");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new(50.0, 2).as_bytes(), b"[#-] 50%");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(PercentBar::new(50.0, 2).len(), 8);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(PercentBar::UNKNOWN.is_unknown());
+    /// assert!(!PercentBar::from(0.0).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"[??????????] ?%")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl PercentBar {
+    /// Partially filled Unicode blocks, in `1/8` steps.
+    const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    fn priv_from(percent: f64, width: u8, unicode: bool) -> Self {
+        if percent.is_nan() {
+            return Self::UNKNOWN;
+        }
+
+        let percent = Percent::new_0(percent.clamp(0.0, 100.0));
+        let width = width.min(Self::MAX_WIDTH);
+
+        // Fill from the whole number so the bar matches the `Percent` string.
+        // This is always in-between `0..=100`.
+        let whole = u32::from(percent.inner() as u8);
+        let w = u32::from(width);
+
+        let mut string = Str::new();
+        string.push_char_panic('[');
+
+        if unicode {
+            let eighths = whole * w * 8 / 100;
+            let (full, partial) = (eighths / 8, eighths % 8);
+            for i in 0..w {
+                let c = match i.cmp(&full) {
+                    std::cmp::Ordering::Less => '█',
+                    std::cmp::Ordering::Equal => Self::EIGHTHS[partial as usize],
+                    std::cmp::Ordering::Greater => ' ',
+                };
+                string.push_char_panic(c);
+            }
+        } else {
+            let full = whole * w / 100;
+            for i in 0..w {
+                string.push_char_panic(if i < full { '#' } else { '-' });
+            }
+        }
+
+        string.push_str_panic("] ");
+        string.push_str_panic(percent);
+
+        Self {
+            percent,
+            width,
+            string,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<f32> for PercentBar {
+    #[inline]
+    fn from(percent: f32) -> Self {
+        Self::new(f64::from(percent), Self::DEFAULT_WIDTH)
+    }
+}
+
+impl From<f64> for PercentBar {
+    #[inline]
+    fn from(percent: f64) -> Self {
+        Self::new(percent, Self::DEFAULT_WIDTH)
+    }
+}
+
+impl From<Percent> for PercentBar {
+    #[inline]
+    fn from(percent: Percent) -> Self {
+        if percent.is_unknown() {
+            Self::UNKNOWN
+        } else {
+            Self::new(percent.inner(), Self::DEFAULT_WIDTH)
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for PercentBar {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PercentBar {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for PercentBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PercentBar")
+            .field("inner", &(self.percent.inner(), self.width))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for PercentBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for PercentBar {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PercentBar {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<PercentBar> for str {
+    #[inline]
+    fn eq(&self, other: &PercentBar) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<PercentBar> for &str {
+    #[inline]
+    fn eq(&self, other: &PercentBar) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", PercentBar::new(50.0, 4)),
+            r#"PercentBar { inner: (50.0, 4), str: "[##--] 50%" }"#
+        );
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(PercentBar::from(0.0), "[----------] 0%");
+        assert_eq!(PercentBar::from(9.9), "[----------] 9%");
+        assert_eq!(PercentBar::from(10.0), "[#---------] 10%");
+        assert_eq!(PercentBar::from(99.9), "[#########-] 99%");
+        assert_eq!(PercentBar::from(100.0), "[##########] 100%");
+        assert_eq!(PercentBar::from(f64::INFINITY), "[##########] 100%");
+        assert_eq!(PercentBar::from(f64::NEG_INFINITY), "[----------] 0%");
+        assert_eq!(PercentBar::from(Percent::from(50.0)), "[#####-----] 50%");
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(PercentBar::new_unicode(1.0, 1), "[ ] 1%");
+        assert_eq!(PercentBar::new_unicode(13.0, 1), "[▏] 13%");
+        assert_eq!(PercentBar::new_unicode(99.0, 1), "[▉] 99%");
+        assert_eq!(PercentBar::new_unicode(100.0, 1), "[█] 100%");
+        assert_eq!(PercentBar::new_unicode(100.0, 0), "[] 100%");
+    }
+
+    #[test]
+    fn width() {
+        for width in 0..=u8::MAX {
+            for percent in [0.0, 33.3, 50.0, 99.9, 100.0] {
+                let bar = PercentBar::new_unicode(percent, width);
+                let w = usize::from(width.min(PercentBar::MAX_WIDTH));
+                assert_eq!(bar.width(), w as u8);
+                let inner = bar.split_once(']').unwrap().0;
+                assert_eq!(inner.chars().count(), w + 1);
+
+                let bar = PercentBar::new(percent, width);
+                assert_eq!(bar.split_once(']').unwrap().0.len(), w + 1);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = PercentBar::from(50.0);
+        let json = serde_json::to_string(&this).unwrap();
+        let this: PercentBar = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "[#####-----] 50%");
+        assert_eq!(this.percent(), 50.0);
+    }
+}
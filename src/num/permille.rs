@@ -0,0 +1,285 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_from_option, impl_impl_math, impl_math,
+    impl_traits,
+};
+use crate::num::constants::{INFINITY, NAN};
+use crate::num::Percent;
+use crate::str::Str;
+
+/// The per-mille sign.
+const SIGN: &str = "‰";
+
+//---------------------------------------------------------------------------------------------------- Permille
+/// Human readable per-mille (parts per thousand).
+///
+/// This is [`Percent`] with the `‰` sign, for metrics
+/// that need more granularity than a percent, e.g. error rates, salinity.
+///
+/// [`Permille::from`] input can be:
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`usize`]
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`isize`]
+/// - [`f32`], [`f64`]
+/// - [`Percent`], scaled by `10`
+///
+/// The input is already in per-mille, use [`Permille::from_ratio`] for a `0.0..=1.0` ratio.
+///
+/// Like [`Percent`], the default is `2` decimal numbers, which can be changed
+/// with the `new_X` functions or the `{:.X}` formatting flag.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Permille::from(3.5),        "3.50‰");
+/// assert_eq!(Permille::new_0(3.5),       "3‰");
+/// assert_eq!(Permille::new_3(3.5),       "3.500‰");
+/// assert_eq!(Permille::from(1_000_u32),  "1,000.00‰");
+/// assert_eq!(Permille::from(-1_i32),     "-1.00‰");
+///
+/// // Scaling.
+/// assert_eq!(Permille::from_ratio(0.0035),        "3.50‰");
+/// assert_eq!(Permille::from(Percent::from(0.35)), "3.50‰");
+/// ```
+///
+/// ## Size
+/// [`Str<24>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Permille>(), 40);
+/// ```
+///
+/// ## Float Errors
+/// - Inputting [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`] or the [`f32`] variants returns errors
+///
+/// ## Math
+/// These operators are overloaded, the same as [`Percent`]:
+/// - `Add +`
+/// - `Sub -`
+/// - `Div /`
+/// - `Mul *`
+/// - `Rem %`
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Permille::from(10.0) + 10.0, Permille::from(20.0));
+/// assert_eq!(Permille::from(10.0) * 10.0, Permille::from(100.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Permille(f64, Str<{ Permille::MAX_LEN }>);
+
+impl_math!(Permille, f64);
+impl_from_option!(Permille);
+impl_traits!(Permille, f64, precision);
+impl_float_eq!(Permille => f64);
+
+//---------------------------------------------------------------------------------------------------- Permille Constants
+impl Permille {
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::ZERO, 0.0);
+    /// assert_eq!(Permille::ZERO, "0.00‰");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0.00‰"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::NAN, "NaN");
+    /// assert!(Permille::NAN.is_nan());
+    /// ```
+    pub const NAN: Self = Self(f64::NAN, Str::from_static_str(NAN));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::INFINITY, "inf");
+    /// assert!(Permille::INFINITY.is_infinite());
+    /// ```
+    pub const INFINITY: Self = Self(f64::INFINITY, Str::from_static_str(INFINITY));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::UNKNOWN, 0.0);
+    /// assert_eq!(Permille::UNKNOWN, "?.??‰");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.??‰"));
+
+    /// The maximum string length of a [`Permille`].
+    ///
+    /// This is [`Percent::MAX_LEN`] + `2`, as `‰` is `3` bytes.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::MAX_LEN, 24);
+    /// ```
+    pub const MAX_LEN: usize = Percent::MAX_LEN + SIGN.len() - 1;
+}
+
+//---------------------------------------------------------------------------------------------------- Macros
+// Implements `new_X` functions.
+macro_rules! impl_new {
+    ( $num:tt ) => {
+        paste::item! {
+            #[inline]
+            #[doc = "Same as [`Percent::new_" $num "`] but with `‰`."]
+            #[must_use]
+            pub fn [<new_ $num>](f: f64) -> Self {
+                Self::priv_from_percent(Percent::[<new_ $num>](f))
+            }
+        }
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Permille Impl
+impl Permille {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_nan`].
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_infinite`].
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Permille::UNKNOWN.is_unknown());
+    /// assert!(!Permille::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        // `?.??‰`
+        matches!(self.as_str().as_bytes(), b"?.??\xe2\x80\xb0")
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a [`Permille`] from a ratio, where `1.0` is `1,000‰`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Permille::from_ratio(0.0),   "0.00‰");
+    /// assert_eq!(Permille::from_ratio(0.012), "12.00‰");
+    /// assert_eq!(Permille::from_ratio(1.0),   "1,000.00‰");
+    /// ```
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self::from(ratio * 1_000.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Percent::new_0`] but with `‰`.
+    pub fn new_0(f: f64) -> Self {
+        Self::priv_from_percent(Percent::new_0(f))
+    }
+
+    impl_new!(1);
+    seq_macro::seq!(N in 3..=14 {
+        impl_new!(N);
+    });
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Permille {
+    /// Swap the `%` of a formatted [`Percent`] for `‰`.
+    fn priv_from_percent(percent: Percent) -> Self {
+        let mut s = Str::new();
+        match percent.as_str().strip_suffix('%') {
+            Some(number) => {
+                s.push_str_panic(number);
+                s.push_str_panic(SIGN);
+            }
+            // `NaN` and `inf`.
+            None => {
+                s.push_str_panic(percent.as_str());
+            }
+        }
+        Self(percent.inner(), s)
+    }
+
+    /// Same as [`Percent::priv_precision`], used by the `{:.X}` formatting flag.
+    fn priv_precision(f: f64, precision: usize) -> Self {
+        Self::priv_from_percent(Percent::priv_precision(f, precision))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+// Implementation Macro.
+macro_rules! impl_from {
+	($( $number:ty ),*) => {
+		$(
+			impl From<$number> for Permille {
+				#[inline]
+				fn from(number: $number) -> Self {
+					Self::priv_from_percent(Percent::from(number))
+				}
+			}
+		)*
+	}
+}
+impl_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl From<Percent> for Permille {
+    #[inline]
+    /// `1%` is `10‰`.
+    fn from(percent: Percent) -> Self {
+        if percent.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from(percent.inner() * 10.0)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special() {
+        assert_eq!(Permille::from(0.0), Permille::ZERO);
+        assert_eq!(Permille::from(f64::NAN), NAN);
+        assert_eq!(Permille::from(f64::INFINITY), INFINITY);
+        assert_eq!(Permille::from(f64::NEG_INFINITY), INFINITY);
+        assert!(Permille::from(Percent::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    fn max_len() {
+        let max = Permille::new_14(10_000.0);
+        assert_eq!(max.len(), Permille::MAX_LEN);
+        assert!(Permille::new_14(100_000.0).is_unknown());
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(format!("{:.0}", Permille::from(3.5)), "3‰");
+        assert_eq!(format!("{:.1}", Permille::from(3.5)), "3.5‰");
+        assert_eq!(format!("{:.3}", Permille::from(3.5)), "3.500‰");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Permille::from(1.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[1.0,"1.00‰"]"#);
+
+        let this: Permille = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 1.0);
+        assert_eq!(this, "1.00‰");
+    }
+}
@@ -0,0 +1,298 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_from_option, impl_impl_math, impl_math,
+    impl_traits,
+};
+use crate::num::constants::{INFINITY, NAN};
+use crate::num::{Percent, Permille};
+use crate::str::Str;
+
+/// The parts-per-million suffix.
+const SUFFIX: &str = " ppm";
+
+//---------------------------------------------------------------------------------------------------- Ppm
+/// Human readable parts per million.
+///
+/// This is [`Percent`] with a ` ppm` suffix, for metrics
+/// that need much more granularity than a percent, e.g. gas concentrations, clock drift.
+///
+/// [`Ppm::from`] input can be:
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`usize`]
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`isize`]
+/// - [`f32`], [`f64`]
+/// - [`Percent`], scaled by `10,000`
+/// - [`Permille`], scaled by `1,000`
+///
+/// The input is already in parts per million, use [`Ppm::from_ratio`] for a `0.0..=1.0` ratio.
+///
+/// Like [`Percent`], the default is `2` decimal numbers, which can be changed
+/// with the `new_X` functions or the `{:.X}` formatting flag.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Ppm::from(420.5),       "420.50 ppm");
+/// assert_eq!(Ppm::new_0(420.5),      "420 ppm");
+/// assert_eq!(Ppm::new_1(420.5),      "420.5 ppm");
+/// assert_eq!(Ppm::from(1_000_u32),   "1,000.00 ppm");
+/// assert_eq!(Ppm::from(-20_i32),     "-20.00 ppm");
+///
+/// // Scaling.
+/// assert_eq!(Ppm::from_ratio(0.000_42),        "420.00 ppm");
+/// assert_eq!(Ppm::from(Percent::from(0.042)),  "420.00 ppm");
+/// assert_eq!(Ppm::from(Permille::from(0.42)),  "420.00 ppm");
+/// ```
+///
+/// ## Size
+/// [`Str<25>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Ppm>(), 40);
+/// ```
+///
+/// ## Float Errors
+/// - Inputting [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`] or the [`f32`] variants returns errors
+///
+/// ## Math
+/// These operators are overloaded, the same as [`Percent`]:
+/// - `Add +`
+/// - `Sub -`
+/// - `Div /`
+/// - `Mul *`
+/// - `Rem %`
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Ppm::from(10.0) + 10.0, Ppm::from(20.0));
+/// assert_eq!(Ppm::from(10.0) * 10.0, Ppm::from(100.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Ppm(f64, Str<{ Ppm::MAX_LEN }>);
+
+impl_math!(Ppm, f64);
+impl_from_option!(Ppm);
+impl_traits!(Ppm, f64, precision);
+impl_float_eq!(Ppm => f64);
+
+//---------------------------------------------------------------------------------------------------- Ppm Constants
+impl Ppm {
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::ZERO, 0.0);
+    /// assert_eq!(Ppm::ZERO, "0.00 ppm");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0.00 ppm"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::NAN, "NaN");
+    /// assert!(Ppm::NAN.is_nan());
+    /// ```
+    pub const NAN: Self = Self(f64::NAN, Str::from_static_str(NAN));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::INFINITY, "inf");
+    /// assert!(Ppm::INFINITY.is_infinite());
+    /// ```
+    pub const INFINITY: Self = Self(f64::INFINITY, Str::from_static_str(INFINITY));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::UNKNOWN, 0.0);
+    /// assert_eq!(Ppm::UNKNOWN, "?.?? ppm");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.?? ppm"));
+
+    /// The maximum string length of a [`Ppm`].
+    ///
+    /// This is [`Percent::MAX_LEN`] + `3`, as ` ppm` is `4` bytes.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::MAX_LEN, 25);
+    /// ```
+    pub const MAX_LEN: usize = Percent::MAX_LEN + SUFFIX.len() - 1;
+}
+
+//---------------------------------------------------------------------------------------------------- Macros
+// Implements `new_X` functions.
+macro_rules! impl_new {
+    ( $num:tt ) => {
+        paste::item! {
+            #[inline]
+            #[doc = "Same as [`Percent::new_" $num "`] but with ` ppm`."]
+            #[must_use]
+            pub fn [<new_ $num>](f: f64) -> Self {
+                Self::priv_from_percent(Percent::[<new_ $num>](f))
+            }
+        }
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Ppm Impl
+impl Ppm {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_nan`].
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Calls [`f64::is_infinite`].
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Ppm::UNKNOWN.is_unknown());
+    /// assert!(!Ppm::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.as_str().as_bytes(), b"?.?? ppm")
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a [`Ppm`] from a ratio, where `1.0` is `1,000,000 ppm`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Ppm::from_ratio(0.0),      "0.00 ppm");
+    /// assert_eq!(Ppm::from_ratio(0.000_01), "10.00 ppm");
+    /// assert_eq!(Ppm::from_ratio(1.0),      "1,000,000.00 ppm");
+    /// ```
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self::from(ratio * 1_000_000.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Percent::new_0`] but with ` ppm`.
+    pub fn new_0(f: f64) -> Self {
+        Self::priv_from_percent(Percent::new_0(f))
+    }
+
+    impl_new!(1);
+    seq_macro::seq!(N in 3..=14 {
+        impl_new!(N);
+    });
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Ppm {
+    /// Swap the `%` of a formatted [`Percent`] for ` ppm`.
+    fn priv_from_percent(percent: Percent) -> Self {
+        let mut s = Str::new();
+        match percent.as_str().strip_suffix('%') {
+            Some(number) => {
+                s.push_str_panic(number);
+                s.push_str_panic(SUFFIX);
+            }
+            // `NaN` and `inf`.
+            None => {
+                s.push_str_panic(percent.as_str());
+            }
+        }
+        Self(percent.inner(), s)
+    }
+
+    /// Same as [`Percent::priv_precision`], used by the `{:.X}` formatting flag.
+    fn priv_precision(f: f64, precision: usize) -> Self {
+        Self::priv_from_percent(Percent::priv_precision(f, precision))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+// Implementation Macro.
+macro_rules! impl_from {
+	($( $number:ty ),*) => {
+		$(
+			impl From<$number> for Ppm {
+				#[inline]
+				fn from(number: $number) -> Self {
+					Self::priv_from_percent(Percent::from(number))
+				}
+			}
+		)*
+	}
+}
+impl_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl From<Percent> for Ppm {
+    #[inline]
+    /// `1%` is `10,000 ppm`.
+    fn from(percent: Percent) -> Self {
+        if percent.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from(percent.inner() * 10_000.0)
+    }
+}
+
+impl From<Permille> for Ppm {
+    #[inline]
+    /// `1‰` is `1,000 ppm`.
+    fn from(permille: Permille) -> Self {
+        if permille.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from(permille.inner() * 1_000.0)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special() {
+        assert_eq!(Ppm::from(0.0), Ppm::ZERO);
+        assert_eq!(Ppm::from(f64::NAN), NAN);
+        assert_eq!(Ppm::from(f64::INFINITY), INFINITY);
+        assert_eq!(Ppm::from(f64::NEG_INFINITY), INFINITY);
+        assert!(Ppm::from(Percent::UNKNOWN).is_unknown());
+        assert!(Ppm::from(Permille::UNKNOWN).is_unknown());
+    }
+
+    #[test]
+    fn max_len() {
+        let max = Ppm::new_14(10_000.0);
+        assert_eq!(max.len(), Ppm::MAX_LEN);
+        assert!(Ppm::new_14(100_000.0).is_unknown());
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(format!("{:.0}", Ppm::from(420.5)), "420 ppm");
+        assert_eq!(format!("{:.1}", Ppm::from(420.5)), "420.5 ppm");
+        assert_eq!(format!("{:>12.1}", Ppm::from(420.5)), "   420.5 ppm");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Ppm::from(1.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[1.0,"1.00 ppm"]"#);
+
+        let this: Ppm = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 1.0);
+        assert_eq!(this, "1.00 ppm");
+    }
+}
@@ -0,0 +1,284 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::impl_std_from_str;
+use crate::num::constants::COMMA;
+use crate::num::Int128;
+use crate::str::SmartStr;
+
+//---------------------------------------------------------------------------------------------------- Separated
+/// Comma separated string of digits, of any length
+///
+/// This inserts commas into a string that is already an integer
+/// (e.g. from a database or FFI) without parsing it, so there is no
+/// overflow, unlike [`Unsigned128::from_digits`](crate::num::Unsigned128::from_digits).
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Separated::from_digits("1234567").unwrap(),               "1,234,567");
+/// assert_eq!(Separated::from_digits("-1234567").unwrap(),              "-1,234,567");
+/// assert_eq!(Separated::from_digits("123456789012345678901").unwrap(), "123,456,789,012,345,678,901");
+/// ```
+///
+/// Leading zeros are ignored.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Separated::from_digits("000100").unwrap(), "100");
+/// assert_eq!(Separated::from_digits("-0").unwrap(),     "0");
+/// ```
+///
+/// ## Size
+/// [`SmartStr<52>`] is used internally to represent the string, so
+/// anything up to [`Int128::MAX_LEN`] is stored inline, longer strings are heap allocated.
+///
+/// ```rust
+/// # use readable::num::*;
+/// let inline = Separated::from_digits(&u128::MAX.to_string()).unwrap();
+/// assert!(inline.is_inline());
+///
+/// let heap = Separated::from_digits(&"9".repeat(100)).unwrap();
+/// assert!(!heap.is_inline());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Separated(SmartStr<{ Int128::MAX_LEN }>);
+
+impl_std_from_str!(Separated => from_digits);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Separated {
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Separated::ZERO, "0");
+    /// assert_eq!(Separated::ZERO, Separated::from_digits("0").unwrap());
+    /// ```
+    pub const ZERO: Self = Self(SmartStr::from_static_str("0"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Separated::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(SmartStr::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl Separated {
+    /// Format a string of plain ASCII digits, with an optional leading `-`
+    ///
+    /// Like [`Unsigned128::from_digits`](crate::num::Unsigned128::from_digits),
+    /// this is strict: there is no whitespace trimming and no separators are allowed.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Separated::from_digits("100").unwrap(),  "100");
+    /// assert_eq!(Separated::from_digits("1000").unwrap(), "1,000");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is empty or contains anything other than
+    /// `0-9` (after the `-`), [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Separated::from_digits("").is_err());
+    /// assert!(Separated::from_digits("-").is_err());
+    /// assert!(Separated::from_digits("+1").is_err());
+    /// assert!(Separated::from_digits(" 1").is_err());
+    /// assert!(Separated::from_digits("1,000").is_err());
+    /// assert!(Separated::from_digits("1.0").is_err());
+    /// ```
+    pub fn from_digits(digits: &str) -> Result<Self, Self> {
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, digits),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Self::UNKNOWN);
+        }
+
+        let digits = digits.trim_start_matches('0').as_bytes();
+        if digits.is_empty() {
+            return Ok(Self::ZERO);
+        }
+
+        let mut s = SmartStr::new();
+        if negative {
+            s.push_char('-');
+        }
+        for (i, d) in digits.iter().enumerate() {
+            if i != 0 && (digits.len() - i) % 3 == 0 {
+                s.push_char(char::from(COMMA));
+            }
+            s.push_char(char::from(*d));
+        }
+
+        Ok(Self(s))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::from_digits`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_digits_silent(digits: &str) -> Self {
+        match Self::from_digits(digits) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    /// The length of the inner [`String`]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// If the string is stored inline, i.e. it is not longer than [`Int128::MAX_LEN`]
+    pub const fn is_inline(&self) -> bool {
+        self.0.is_inline()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Separated::UNKNOWN.is_unknown());
+    /// assert!(!Separated::ZERO.is_unknown());
+    /// ```
+    pub fn is_unknown(&self) -> bool {
+        self.as_bytes() == b"???"
+    }
+
+    #[inline]
+    #[must_use]
+    /// Consume [`Self`] and return the inner [`String`]
+    pub fn into_string(self) -> String {
+        self.0.into_string()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Separated {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Separated {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for Separated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl Default for Separated {
+    #[inline]
+    /// Returns [`Self::ZERO`]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq<str> for Separated {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Separated {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::{Int128, Unsigned128};
+
+    #[test]
+    fn same_as_128() {
+        for digits in [
+            u128::MAX.to_string(),
+            i128::MIN.to_string(),
+            i128::MAX.to_string(),
+            "1".into(),
+            "12".into(),
+            "123".into(),
+            "1234".into(),
+            "-1000".into(),
+        ] {
+            let separated = Separated::from_digits(&digits).unwrap();
+            match digits.parse::<u128>() {
+                Ok(u) => assert_eq!(separated, Unsigned128::from(u).as_str()),
+                Err(_) => assert_eq!(
+                    separated,
+                    Int128::from(digits.parse::<i128>().unwrap()).as_str()
+                ),
+            }
+            assert!(separated.is_inline());
+        }
+    }
+
+    #[test]
+    fn long() {
+        let digits = "1".repeat(1000);
+        let separated = Separated::from_digits(&digits).unwrap();
+        assert_eq!(separated.len(), 1000 + 333);
+        assert!(separated.starts_with("1,111,"));
+        assert!(separated.split(',').skip(1).all(|group| group == "111"));
+    }
+
+    #[test]
+    fn zeros() {
+        assert_eq!(Separated::from_digits("0000").unwrap(), Separated::ZERO);
+        assert_eq!(Separated::from_digits("-0000").unwrap(), Separated::ZERO);
+        assert_eq!(Separated::from_digits("-0001000").unwrap(), "-1,000");
+        assert!(Separated::from_digits("--1").is_err());
+        assert!(Separated::from_digits_silent("1-").is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Separated::from_digits("123456789012345678901").unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#""123,456,789,012,345,678,901""#);
+
+        let this: Separated = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "123,456,789,012,345,678,901");
+    }
+}
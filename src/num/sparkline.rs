@@ -0,0 +1,383 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Sparkline
+/// Human-readable spark-line of a small numeric series
+///
+/// This takes a slice of numbers and renders one block character per number:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Sparkline::new(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]), "▁▂▃▄▅▆▇█");
+/// assert_eq!(Sparkline::new(&[0.0, 50.0, 100.0, 50.0, 0.0]),           "▁▅█▅▁");
+/// ```
+///
+/// ## Normalization
+/// [`Sparkline::new`] scales the blocks between the lowest and highest number in the slice.
+///
+/// [`Sparkline::new_range`] scales them between a fixed `min` and `max` instead,
+/// which is useful for things like CPU usage that are always `0..=100`.
+///
+/// Numbers outside of that range are clamped.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Sparkline::new(&[10.0, 20.0]),                    "▁█");
+/// assert_eq!(Sparkline::new_range(&[10.0, 20.0], 0.0, 100.0),  "▂▂");
+/// assert_eq!(Sparkline::new_range(&[-1.0, 200.0], 0.0, 100.0), "▁█");
+/// ```
+///
+/// A flat series is rendered with the lowest block.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Sparkline::new(&[5.0, 5.0, 5.0]), "▁▁▁");
+/// ```
+///
+/// ## Gaps
+/// [`f64::NAN`], [`f64::INFINITY`] and [`f64::NEG_INFINITY`] are rendered as a space.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Sparkline::new(&[1.0, f64::NAN, 2.0]), "▁ █");
+/// ```
+///
+/// ## Length
+/// Only the _last_ [`Sparkline::MAX_POINTS`] numbers are rendered, older numbers are ignored.
+///
+/// ```rust
+/// # use readable::num::*;
+/// let series: Vec<f64> = (0..100).map(f64::from).collect();
+/// let spark = Sparkline::new(&series);
+/// assert_eq!(spark.points(), Sparkline::MAX_POINTS);
+/// ```
+///
+/// ## Errors
+/// [`Sparkline::UNKNOWN`] is returned if [`Sparkline::new_range`]'s `min` or `max`
+/// are not finite, or if `min` is larger than `max`.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Sparkline::new_range(&[1.0], 1.0, 0.0).is_unknown());
+/// assert!(Sparkline::new_range(&[1.0], 0.0, f64::NAN).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<96>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<Sparkline>(), 98);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Sparkline(u8, Str<{ Sparkline::MAX_LEN }>);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Sparkline {
+    /// The maximum amount of numbers rendered in a [`Sparkline`]
+    pub const MAX_POINTS: usize = 32;
+
+    /// The maximum string length of a [`Sparkline`]
+    ///
+    /// Each block is `3` bytes.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// let spark = Sparkline::new(&[1.0; Sparkline::MAX_POINTS]);
+    /// assert_eq!(spark.len(), Sparkline::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = Self::MAX_POINTS * 3;
+
+    /// The blocks, from lowest to highest.
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::UNKNOWN, "?");
+    /// assert_eq!(Sparkline::UNKNOWN.points(), 0);
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("?"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::EMPTY, "");
+    /// assert_eq!(Sparkline::EMPTY, Sparkline::new(&[]));
+    /// ```
+    pub const EMPTY: Self = Self(0, Str::new());
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl Sparkline {
+    #[must_use]
+    /// Create a [`Sparkline`] scaled between the lowest and highest number in `series`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new(&[3.0, 1.0, 2.0]), "█▁▅");
+    /// ```
+    pub fn new(series: &[f64]) -> Self {
+        let series = Self::tail(series);
+
+        let (min, max) = series
+            .iter()
+            .filter(|f| f.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), f| {
+                (min.min(*f), max.max(*f))
+            });
+
+        Self::priv_from(series, min, max)
+    }
+
+    #[must_use]
+    /// Create a [`Sparkline`] scaled between a fixed `min` and `max`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new_range(&[0.0, 25.0, 50.0, 75.0, 100.0], 0.0, 100.0), "▁▃▅▆█");
+    /// ```
+    pub fn new_range(series: &[f64], min: f64, max: f64) -> Self {
+        if !min.is_finite() || !max.is_finite() || min > max {
+            return Self::UNKNOWN;
+        }
+
+        Self::priv_from(Self::tail(series), min, max)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the amount of numbers rendered
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new(&[1.0, 2.0, 3.0]).points(), 3);
+    /// ```
+    pub const fn points(&self) -> usize {
+        self.0 as usize
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new(&[1.0, 2.0]).as_str(), "▁█");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.1.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new(&[1.0, 2.0]).as_bytes(), "▁█".as_bytes());
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.1.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the byte length of the string
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Sparkline::new(&[1.0, 2.0]).len(), 6);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Sparkline::EMPTY.is_empty());
+    /// assert!(!Sparkline::new(&[1.0]).is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.1.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Sparkline::UNKNOWN.is_unknown());
+    /// assert!(!Sparkline::EMPTY.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Sparkline {
+    /// Returns the last `MAX_POINTS` of `series`.
+    fn tail(series: &[f64]) -> &[f64] {
+        &series[series.len().saturating_sub(Self::MAX_POINTS)..]
+    }
+
+    fn priv_from(series: &[f64], min: f64, max: f64) -> Self {
+        let range = max - min;
+        let top = (Self::BLOCKS.len() - 1) as f64;
+
+        let mut string = Str::new();
+        for f in series {
+            let c = if !f.is_finite() {
+                ' '
+            } else if range > 0.0 {
+                // This is always in-between `0.0..=7.0`.
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let i = ((f.clamp(min, max) - min) / range * top).round() as usize;
+                Self::BLOCKS[i]
+            } else {
+                Self::BLOCKS[0]
+            };
+            string.push_char_panic(c);
+        }
+
+        // `series` is at most `MAX_POINTS`.
+        #[allow(clippy::cast_possible_truncation)]
+        Self(series.len() as u8, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<&[f64]> for Sparkline {
+    #[inline]
+    fn from(series: &[f64]) -> Self {
+        Self::new(series)
+    }
+}
+
+impl<const N: usize> From<&[f64; N]> for Sparkline {
+    #[inline]
+    fn from(series: &[f64; N]) -> Self {
+        Self::new(series)
+    }
+}
+
+impl From<&Vec<f64>> for Sparkline {
+    #[inline]
+    fn from(series: &Vec<f64>) -> Self {
+        Self::new(series)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Sparkline {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Sparkline {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Sparkline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sparkline")
+            .field("inner", &self.0)
+            .field("str", &self.1.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Sparkline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Sparkline {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Sparkline {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Sparkline> for str {
+    #[inline]
+    fn eq(&self, other: &Sparkline) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Sparkline> for &str {
+    #[inline]
+    fn eq(&self, other: &Sparkline) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Sparkline::new(&[1.0, 2.0])),
+            r#"Sparkline { inner: 2, str: "▁█" }"#
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Sparkline::new(&[]), "");
+        assert_eq!(Sparkline::new(&[1.0]), "▁");
+        assert_eq!(Sparkline::new(&[f64::NAN]), " ");
+        assert_eq!(Sparkline::new(&[-1.0, 0.0, 1.0]), "▁▅█");
+        assert_eq!(
+            Sparkline::new(&[f64::NEG_INFINITY, 0.0, f64::INFINITY, 1.0]),
+            " ▁ █"
+        );
+    }
+
+    #[test]
+    fn new_range() {
+        assert_eq!(Sparkline::new_range(&[0.0, 1.0], 0.0, 0.0), "▁▁");
+        assert_eq!(Sparkline::new_range(&[0.0, 1.0], -1.0, 1.0), "▅█");
+        assert!(Sparkline::new_range(&[], f64::NEG_INFINITY, 0.0).is_unknown());
+    }
+
+    #[test]
+    fn tail() {
+        let series: Vec<f64> = (0..=Sparkline::MAX_POINTS).map(|i| i as f64).collect();
+        let spark = Sparkline::from(&series);
+        assert_eq!(spark.points(), Sparkline::MAX_POINTS);
+        assert_eq!(spark.len(), Sparkline::MAX_LEN);
+        assert!(spark.starts_with('▁'));
+        assert!(spark.ends_with('█'));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Sparkline::new(&[1.0, 2.0]);
+        let json = serde_json::to_string(&this).unwrap();
+        let this: Sparkline = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "▁█");
+        assert_eq!(this.points(), 2);
+    }
+}
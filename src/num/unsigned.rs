@@ -1,5 +1,8 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize};
+use crate::macros::{
+    impl_common, impl_const, impl_format_slice, impl_from_option, impl_from_str, impl_impl_math,
+    impl_math, impl_std_from_str, impl_traits, impl_usize,
+};
 use crate::num::{constants::COMMA, Int};
 use crate::str::Str;
 use std::num::{
@@ -22,6 +25,8 @@ use std::num::{
 ///
 /// will all lead to `Err(Unsigned::unknown)` being returned.
 ///
+/// [`u128`] inputs larger than [`u64::MAX`] also fail, use [`Unsigned128`](crate::num::Unsigned128) for the full range.
+///
 /// ```rust
 /// # use readable::num::*;
 /// // Signed floats will fail.
@@ -121,13 +126,20 @@ use std::num::{
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Unsigned(u64, Str<{ Unsigned::MAX_LEN }>);
 
 const LEN: usize = 26;
 
 impl_math!(Unsigned, u64);
+impl_from_option!(Unsigned);
 impl_traits!(Unsigned, u64);
+impl_std_from_str!(Unsigned => from_str);
 
 //---------------------------------------------------------------------------------------------------- Unsigned Constants
 impl Unsigned {
@@ -169,7 +181,9 @@ impl Unsigned {
     // Impl Macros.
     impl_common!(u64);
     impl_const!();
+    impl_format_slice!(num, Unsigned, [0_u64, 1_000, 1_234_567, u64::MAX]);
     impl_usize!();
+    impl_from_str!(Unsigned, u64, "1,234,567", 1_234_567);
 
     #[inline]
     #[must_use]
@@ -711,11 +725,96 @@ macro_rules! impl_f {
 impl_f!(f32);
 impl_f!(f64);
 
+//---------------------------------------------------------------------------------------------------- SQL
+// Stored as a `BIGINT`, so anything over `i64::MAX` can't be stored.
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+impl Unsigned {
+    #[inline]
+    // Negative numbers are `Self::UNKNOWN`.
+    fn priv_from_sql(i: i64) -> Self {
+        Self::try_from(i).unwrap_or_else(|e| e)
+    }
+
+    #[inline]
+    const fn priv_to_sql(&self) -> Option<&i64> {
+        crate::macros::u64_as_i64(&self.0)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+crate::macros::impl_sqlx!(Unsigned => i64);
+#[cfg(feature = "diesel")]
+crate::macros::impl_diesel!(Unsigned => i64 as BigInt);
+
 //---------------------------------------------------------------------------------------------------- TESTS
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(any(feature = "sqlx", feature = "diesel"))]
+    fn sql() {
+        assert_eq!(Unsigned::priv_from_sql(1_000), "1,000");
+        assert!(Unsigned::priv_from_sql(-1).is_unknown());
+
+        assert_eq!(Unsigned::from(1_000_u64).priv_to_sql(), Some(&1_000));
+        assert_eq!(
+            Unsigned::from(i64::MAX as u64).priv_to_sql(),
+            Some(&i64::MAX)
+        );
+        assert_eq!(Unsigned::from(i64::MAX as u64 + 1).priv_to_sql(), None);
+        assert_eq!(Unsigned::MAX.priv_to_sql(), None);
+    }
+
+    #[test]
+    fn from_option() {
+        assert_eq!(Unsigned::from(Some(1_000_u32)), "1,000");
+        assert_eq!(Unsigned::from(Some(&1_000_u64)), "1,000");
+        assert!(Unsigned::from(None::<u64>).is_unknown());
+        // `None` is unknown even if `Some` would not be.
+        assert!(Unsigned::from(None::<u8>).is_unknown());
+    }
+
+    #[test]
+    fn sum() {
+        let v = [
+            Unsigned::from(1_u64),
+            Unsigned::from(2_u64),
+            Unsigned::from(3_u64),
+        ];
+        assert_eq!(v.iter().sum::<Unsigned>(), "6");
+        assert_eq!(v.into_iter().sum::<Unsigned>(), "6");
+        assert_eq!(
+            std::iter::empty::<Unsigned>().sum::<Unsigned>(),
+            Unsigned::ZERO
+        );
+
+        // Saturates instead of panicking.
+        let v = [Unsigned::MAX, Unsigned::from(1_u64)];
+        assert_eq!(v.iter().sum::<Unsigned>(), Unsigned::MAX);
+    }
+
+    #[test]
+    fn from_str() {
+        for n in [0, 0, 1, 999, 1_000, 65_536, 1_234_567, u64::MAX] {
+            let this = Unsigned::from(n);
+            assert_eq!(Unsigned::from_str(this.as_str()).unwrap(), this);
+        }
+        assert_eq!(Unsigned::from_str("1 000_000").unwrap(), 1_000_000);
+        assert_eq!(Unsigned::from_str("+1").unwrap(), 1);
+        assert!(Unsigned::from_str("-1").is_err());
+        assert!(Unsigned::from_str("18,446,744,073,709,551,616").is_err());
+        assert!(Unsigned::from_str("1,000.0").is_err());
+        assert!(Unsigned::from_str(&"0".repeat(100)).is_err());
+        assert!(Unsigned::from_str_silent("a").is_unknown());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("1,234".parse::<Unsigned>().unwrap(), 1_234);
+        assert_eq!("-1".parse::<Unsigned>(), Err(crate::Error::Parse));
+    }
+
     #[test]
     fn unsigned() {
         assert_eq!(Unsigned::from(1_000_u64), "1,000");
@@ -837,6 +936,24 @@ mod tests {
             .is_unknown());
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn postcard() {
+        // `postcard` does not allocate.
+        let mut buf = [0_u8; 64];
+        let bytes = postcard::to_slice(&Unsigned::from(1000_u64), &mut buf).unwrap();
+
+        let this: Unsigned = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, 1000);
+        assert_eq!(this, "1,000");
+
+        // Unknown.
+        let bytes = postcard::to_slice(&Unsigned::UNKNOWN, &mut buf).unwrap();
+        assert!(postcard::from_bytes::<Unsigned>(bytes)
+            .unwrap()
+            .is_unknown());
+    }
+
     #[test]
     #[cfg(feature = "bincode")]
     fn bincode() {
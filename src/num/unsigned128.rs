@@ -0,0 +1,346 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_from_option, impl_from_str, impl_impl_math, impl_math,
+    impl_std_from_str, impl_traits,
+};
+use crate::num::{Int, Int128, Unsigned};
+use crate::str::Str;
+use std::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+//---------------------------------------------------------------------------------------------------- Unsigned128
+/// Human readable [`u128`].
+///
+/// This is the same as [`Unsigned`], but the full [`u128`] range is supported,
+/// e.g. for token amounts, hashes as integers, or `NUMERIC` database columns.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(Unsigned128::from(1_000_u32), "1,000");
+/// assert_eq!(Unsigned128::from(u64::MAX as u128 + 1), "18,446,744,073,709,551,616");
+/// assert_eq!(Unsigned128::MAX, "340,282,366,920,938,463,463,374,607,431,768,211,455");
+///
+/// // Negative signed integers fail.
+/// assert_eq!(Unsigned128::try_from(-1_i128), Err(Unsigned128::UNKNOWN));
+/// ```
+///
+/// ## Digits
+/// Integers that are already strings (e.g. from a database) can be formatted with [`Unsigned128::from_digits`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// let digits = "123456789012345678901234567890";
+/// assert_eq!(Unsigned128::from_digits(digits).unwrap(), "123,456,789,012,345,678,901,234,567,890");
+/// ```
+///
+/// ## Size
+/// [`Str<51>`] is used internally to represent the string.
+///
+/// The total size depends on the alignment of [`u128`], which is `8` or `16` depending on the Rust version.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(matches!(std::mem::size_of::<Unsigned128>(), 72 | 80));
+/// ```
+///
+/// ## Math
+/// These operators are overloaded, the same as [`Unsigned`]:
+/// - `Add +`
+/// - `Sub -`
+/// - `Div /`
+/// - `Mul *`
+/// - `Rem %`
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert!(Unsigned128::from(10_u64) + 10 == Unsigned128::from(20_u64));
+/// assert!(Unsigned128::from(10_u64) * 10 == Unsigned128::from(100_u64));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Unsigned128(u128, Str<{ Unsigned128::MAX_LEN }>);
+
+impl_math!(Unsigned128, u128);
+impl_from_option!(Unsigned128);
+impl_traits!(Unsigned128, u128);
+impl_std_from_str!(Unsigned128 => from_str);
+
+//---------------------------------------------------------------------------------------------------- Unsigned128 Constants
+impl Unsigned128 {
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Unsigned128::ZERO, 0);
+    /// assert_eq!(Unsigned128::ZERO, "0");
+    /// ```
+    pub const ZERO: Self = Self(0, Str::from_static_str("0"));
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Unsigned128::MAX, u128::MAX);
+    /// assert_eq!(Unsigned128::MAX, "340,282,366,920,938,463,463,374,607,431,768,211,455");
+    /// ```
+    pub const MAX: Self = Self(
+        u128::MAX,
+        Str::from_static_str("340,282,366,920,938,463,463,374,607,431,768,211,455"),
+    );
+
+    /// Returned when using [`Unsigned128::UNKNOWN`] and error situations.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Unsigned128::try_from(-1), Err(Unsigned128::UNKNOWN));
+    /// assert_eq!(Unsigned128::UNKNOWN, 0);
+    /// assert_eq!(Unsigned128::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("???"));
+
+    /// The maximum string length of an [`Unsigned128`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Unsigned128::MAX.len(), 51);
+    /// ```
+    pub const MAX_LEN: usize = 51;
+}
+
+//---------------------------------------------------------------------------------------------------- Unsigned128 Impl
+impl Unsigned128 {
+    // Impl Macros.
+    impl_common!(u128);
+    impl_const!();
+    impl_from_str!(Unsigned128, u128, "1,234,567", 1_234_567);
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Unsigned128::UNKNOWN.is_unknown());
+    /// assert!(!Unsigned128::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+
+    /// Format a string of plain ASCII digits, e.g. `"1234567"`
+    ///
+    /// Unlike [`Unsigned128::from_str`], this is strict: there is
+    /// no whitespace trimming and no separators are allowed.
+    ///
+    /// Leading zeros are ignored.
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(Unsigned128::from_digits("1234567").unwrap(), "1,234,567");
+    /// assert_eq!(Unsigned128::from_digits("000100").unwrap(), "100");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is empty, contains anything other than `0-9`,
+    /// or is larger than [`u128::MAX`], [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert!(Unsigned128::from_digits("").is_err());
+    /// assert!(Unsigned128::from_digits("+1").is_err());
+    /// assert!(Unsigned128::from_digits("1,000").is_err());
+    /// assert!(Unsigned128::from_digits("340282366920938463463374607431768211456").is_err());
+    /// ```
+    pub fn from_digits(digits: &str) -> Result<Self, Self> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Self::UNKNOWN);
+        }
+
+        match digits.parse::<u128>() {
+            Ok(u) => Ok(Self::from(u)),
+            Err(_) => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Self::from_digits`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_digits_silent(digits: &str) -> Self {
+        match Self::from_digits(digits) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private functions.
+impl Unsigned128 {
+    #[inline]
+    fn from_priv(u: u128) -> Self {
+        let mut s = Str::new();
+        crate::raw::push_grouped(&mut s, crate::toa::Itoa::new(u).as_str().as_bytes());
+        Self(u, s)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `u*`
+macro_rules! impl_u {
+	($( $from:ty ),* $(,)?) => {
+		$(
+			impl From<$from> for Unsigned128 {
+				#[inline]
+				fn from(uint: $from) -> Self {
+					Self::from_priv(uint as u128)
+				}
+			}
+			impl From<&$from> for Unsigned128 {
+				#[inline]
+				fn from(uint: &$from) -> Self {
+					Self::from_priv(*uint as u128)
+				}
+			}
+		)*
+	}
+}
+impl_u!(u8, u16, u32, u64, u128, usize);
+
+//---------------------------------------------------------------------------------------------------- From `NonZeroU*`
+macro_rules! impl_nonu {
+	($( $from:ty ),* $(,)?) => {
+		$(
+			impl From<$from> for Unsigned128 {
+				#[inline]
+				fn from(uint: $from) -> Self {
+					Self::from_priv(uint.get() as u128)
+				}
+			}
+		)*
+	}
+}
+impl_nonu! {
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    &NonZeroU8, &NonZeroU16, &NonZeroU32, &NonZeroU64, &NonZeroU128, &NonZeroUsize,
+}
+
+//---------------------------------------------------------------------------------------------------- From `i*`
+macro_rules! impl_i {
+	($( $from:ty ),* $(,)?) => {
+		$(
+			/// This will return [`Self::UNKNOWN`] wrapped
+			/// in [`Result::Err`] if the input is negative.
+			impl TryFrom<$from> for Unsigned128 {
+				type Error = Self;
+				#[inline]
+				fn try_from(int: $from) -> Result<Self, Self> {
+					match u128::try_from(int) {
+						Ok(u) => Ok(Self::from_priv(u)),
+						Err(_) => Err(Self::UNKNOWN),
+					}
+				}
+			}
+		)*
+	}
+}
+impl_i!(i8, i16, i32, i64, i128, isize);
+
+//---------------------------------------------------------------------------------------------------- From `readable::num`
+impl From<Unsigned> for Unsigned128 {
+    #[inline]
+    fn from(unsigned: Unsigned) -> Self {
+        if unsigned.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_priv(u128::from(unsigned.inner()))
+    }
+}
+
+/// This will return [`Self::UNKNOWN`] wrapped
+/// in [`Result::Err`] if the input is negative or unknown.
+impl TryFrom<Int> for Unsigned128 {
+    type Error = Self;
+    #[inline]
+    fn try_from(int: Int) -> Result<Self, Self> {
+        if int.is_unknown() {
+            return Err(Self::UNKNOWN);
+        }
+        Self::try_from(int.inner())
+    }
+}
+
+/// This will return [`Self::UNKNOWN`] wrapped
+/// in [`Result::Err`] if the input is negative or unknown.
+impl TryFrom<Int128> for Unsigned128 {
+    type Error = Self;
+    #[inline]
+    fn try_from(int: Int128) -> Result<Self, Self> {
+        if int.is_unknown() {
+            return Err(Self::UNKNOWN);
+        }
+        Self::try_from(int.inner())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned() {
+        // Every length matches `Unsigned` up until `u64::MAX`.
+        let mut n = 1_u64;
+        loop {
+            assert_eq!(Unsigned128::from(n).as_str(), Unsigned::from(n).as_str());
+            assert_eq!(
+                Unsigned128::from(n - 1).as_str(),
+                Unsigned::from(n - 1).as_str()
+            );
+            match n.checked_mul(10) {
+                Some(next) => n = next,
+                None => break,
+            }
+        }
+        assert_eq!(Unsigned128::from(u64::MAX).as_str(), Unsigned::MAX.as_str());
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Unsigned128::from(u128::MAX), Unsigned128::MAX);
+        assert_eq!(Unsigned128::MAX.len(), Unsigned128::MAX_LEN);
+        assert_eq!(
+            Unsigned128::from(u128::MAX / 10),
+            "34,028,236,692,093,846,346,337,460,743,176,821,145"
+        );
+    }
+
+    #[test]
+    fn digits() {
+        let max = u128::MAX.to_string();
+        assert_eq!(Unsigned128::from_digits(&max).unwrap(), Unsigned128::MAX);
+        assert_eq!(Unsigned128::from_digits("0").unwrap(), Unsigned128::ZERO);
+        assert!(Unsigned128::from_digits(" 1").is_err());
+        assert!(Unsigned128::from_digits("1.0").is_err());
+        assert!(Unsigned128::from_digits_silent("-1").is_unknown());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Unsigned128::from_str(Unsigned128::MAX.as_str()).unwrap(),
+            Unsigned128::MAX
+        );
+        assert!(Unsigned128::from_str("-1").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Unsigned128::from(u128::MAX);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(
+            json,
+            r#"[340282366920938463463374607431768211455,"340,282,366,920,938,463,463,374,607,431,768,211,455"]"#
+        );
+
+        let this: Unsigned128 = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, u128::MAX);
+    }
+}
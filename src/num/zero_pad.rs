@@ -0,0 +1,346 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- ZeroPad
+/// Fixed-width, zero-padded integer
+///
+/// This formats an integer with leading zeros to exactly `W` digits,
+/// for fixed-width counters like track numbers or frame counters:
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(ZeroPad::<3>::from(7_u8),      "007");
+/// assert_eq!(ZeroPad::<3>::from(42_u32),    "042");
+/// assert_eq!(ZeroPad::<3>::from(999_u64),   "999");
+/// assert_eq!(ZeroPad::<6>::from(1234_i32),  "001234");
+/// assert_eq!(ZeroPad::<1>::from(0_usize),   "0");
+/// ```
+///
+/// `W` must be in-between `1..=20` (the digits in [`u64::MAX`]),
+/// other widths fail to compile when a [`ZeroPad`] is created.
+///
+/// ## Saturation
+/// The [`From`] implementations saturate, numbers
+/// that do not fit in `W` digits are [`ZeroPad::MAX`],
+/// and negative numbers are [`ZeroPad::ZERO`].
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(ZeroPad::<3>::from(1000_u32), "999");
+/// assert_eq!(ZeroPad::<3>::from(-1_i32),   "000");
+/// ```
+///
+/// ## Compile-time panic
+/// [`ZeroPad::new`] panics instead, which is a compile-time error in `const` contexts.
+///
+/// ```rust
+/// # use readable::num::*;
+/// const TRACK: ZeroPad<2> = ZeroPad::new(7);
+/// assert_eq!(TRACK, "07");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use readable::num::*;
+/// // This doesn't fit, will panic at compile time.
+/// const TRACK: ZeroPad<2> = ZeroPad::new(100);
+/// ```
+///
+/// ## Size
+/// [`Str<W>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::num::*;
+/// assert_eq!(std::mem::size_of::<ZeroPad<3>>(), 16);
+/// assert_eq!(std::mem::size_of::<ZeroPad<20>>(), 32);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ZeroPad<const W: usize>(u64, Str<W>);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl<const W: usize> ZeroPad<W> {
+    /// Fails to compile if `W` is not `1..=20`.
+    const VALID_WIDTH: () = assert!(W >= 1 && W <= 20, "ZeroPad<W>: W must be 1..=20");
+
+    /// The largest number that fits in `W` digits
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<1>::MAX_NUM, 9);
+    /// assert_eq!(ZeroPad::<3>::MAX_NUM, 999);
+    /// assert_eq!(ZeroPad::<20>::MAX_NUM, u64::MAX);
+    /// ```
+    pub const MAX_NUM: u64 = {
+        let mut max: u64 = 0;
+        let mut i = 0;
+        while i < W {
+            max = max.saturating_mul(10).saturating_add(9);
+            i += 1;
+        }
+        max
+    };
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<3>::ZERO, 0);
+    /// assert_eq!(ZeroPad::<3>::ZERO, "000");
+    /// ```
+    pub const ZERO: Self = Self::priv_from(0);
+
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<3>::MAX, 999);
+    /// assert_eq!(ZeroPad::<3>::MAX, "999");
+    /// assert_eq!(ZeroPad::<20>::MAX, "18446744073709551615");
+    /// ```
+    pub const MAX: Self = Self::priv_from(Self::MAX_NUM);
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl<const W: usize> ZeroPad<W> {
+    #[inline]
+    #[must_use]
+    /// Create a [`ZeroPad`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<4>::new(12), "0012");
+    /// ```
+    ///
+    /// ## Panics
+    /// This panics if `n` is larger than [`ZeroPad::MAX_NUM`],
+    /// see [`ZeroPad::saturating`] for a non-panicking version.
+    ///
+    /// ```rust,should_panic
+    /// # use readable::num::*;
+    /// let n = 100;
+    /// let pad = ZeroPad::<2>::new(n);
+    /// ```
+    pub const fn new(n: u64) -> Self {
+        assert!(
+            n <= Self::MAX_NUM,
+            "ZeroPad::new(): the number does not fit in W digits"
+        );
+        Self::priv_from(n)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a [`ZeroPad`], saturating at [`ZeroPad::MAX`]
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<2>::saturating(12),  "12");
+    /// assert_eq!(ZeroPad::<2>::saturating(123), "99");
+    /// ```
+    pub const fn saturating(n: u64) -> Self {
+        if n > Self::MAX_NUM {
+            Self::MAX
+        } else {
+            Self::priv_from(n)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the inner number.
+    pub const fn inner(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub const fn as_str(&self) -> &str {
+        self.1.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.1.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    /// The length of the inner [`String`], this is always `W`
+    ///
+    /// ```rust
+    /// # use readable::num::*;
+    /// assert_eq!(ZeroPad::<5>::from(1_u8).len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.1.len()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl<const W: usize> ZeroPad<W> {
+    #[allow(clippy::let_unit_value)]
+    const fn priv_from(n: u64) -> Self {
+        let () = Self::VALID_WIDTH;
+
+        let mut buf = [b'0'; W];
+        let mut i = W;
+        let mut n2 = n;
+        while n2 != 0 && i > 0 {
+            i -= 1;
+            buf[i] = b'0' + (n2 % 10) as u8;
+            n2 /= 10;
+        }
+
+        // SAFETY: the buffer is all ASCII digits.
+        Self(n, unsafe { Str::from_raw(buf, W as u8) })
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+// Implementation Macro.
+macro_rules! impl_from {
+	(unsigned $($u:ty),*) => { $(
+		impl<const W: usize> From<$u> for ZeroPad<W> {
+			#[inline]
+			fn from(u: $u) -> Self {
+				Self::saturating(u64::try_from(u).unwrap_or(u64::MAX))
+			}
+		}
+	)*};
+	(signed $($i:ty),*) => { $(
+		impl<const W: usize> From<$i> for ZeroPad<W> {
+			#[inline]
+			fn from(i: $i) -> Self {
+				if i.is_negative() {
+					return Self::ZERO;
+				}
+				Self::saturating(u64::try_from(i).unwrap_or(u64::MAX))
+			}
+		}
+	)*};
+}
+impl_from!(unsigned u8, u16, u32, u64, u128, usize);
+impl_from!(signed i8, i16, i32, i64, i128, isize);
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<const W: usize> std::ops::Deref for ZeroPad<W> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const W: usize> AsRef<str> for ZeroPad<W> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const W: usize> std::fmt::Debug for ZeroPad<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZeroPad")
+            .field("inner", &self.0)
+            .field("str", &self.1.as_str())
+            .finish()
+    }
+}
+
+impl<const W: usize> std::fmt::Display for ZeroPad<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const W: usize> Default for ZeroPad<W> {
+    #[inline]
+    /// Returns [`Self::ZERO`]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const W: usize> PartialEq<str> for ZeroPad<W> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const W: usize> PartialEq<&str> for ZeroPad<W> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const W: usize> PartialEq<u64> for ZeroPad<W> {
+    #[inline]
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", ZeroPad::<4>::new(7)),
+            r#"ZeroPad { inner: 7, str: "0007" }"#
+        );
+    }
+
+    #[test]
+    fn digits() {
+        let mut n = 1_u64;
+        let mut digits = 1;
+        while let Some(next) = n.checked_mul(10) {
+            assert_eq!(
+                ZeroPad::<20>::from(n).as_str().trim_start_matches('0'),
+                n.to_string()
+            );
+            assert_eq!(
+                ZeroPad::<20>::from(n - 1),
+                format!("{:020}", n - 1).as_str()
+            );
+            assert_eq!(
+                ZeroPad::<19>::saturating(n),
+                format!("{:019}", n.min(ZeroPad::<19>::MAX_NUM)).as_str()
+            );
+            n = next;
+            digits += 1;
+        }
+        assert_eq!(digits, 20);
+    }
+
+    #[test]
+    fn saturate() {
+        assert_eq!(ZeroPad::<2>::from(u128::MAX), "99");
+        assert_eq!(ZeroPad::<2>::from(i128::MIN), "00");
+        assert_eq!(ZeroPad::<20>::from(u128::MAX), ZeroPad::<20>::MAX);
+        assert_eq!(ZeroPad::<20>::from(u64::MAX), "18446744073709551615");
+        assert_eq!(ZeroPad::<3>::saturating(1000).inner(), 999);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = ZeroPad::<3>::from(7_u8);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[7,"007"]"#);
+
+        let this: ZeroPad<3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 7);
+        assert_eq!(this, "007");
+    }
+}
@@ -0,0 +1,57 @@
+//! Common traits and types
+//!
+//! This re-exports the traits that must be in scope to be used,
+//! and the most common types of each enabled module.
+//!
+//! ```rust
+//! use readable::prelude::*;
+//!
+//! assert_eq!(Unsigned::from(1000_u64), "1,000");
+//! assert_eq!(Byte::from(1024_u64), "1.024 KB");
+//! assert_eq!(Runtime::from(61), "1:01");
+//!
+//! // No extra trait imports.
+//! let uptime = Uptime::sys_uptime();
+//! let time = Time::sys_time();
+//! let date = Date::sysdate();
+//! assert_eq!("hello world".head_dot(5), "hello...");
+//! ```
+
+//---------------------------------------------------------------------------------------------------- Traits
+pub use crate::str::{FullWidth, HeadTail, Numerals};
+
+#[cfg(feature = "date")]
+pub use crate::date::SysDate;
+
+#[cfg(feature = "time")]
+pub use crate::time::SysTime;
+
+#[cfg(feature = "up")]
+pub use crate::up::{SysIdle, SysUptime};
+
+//---------------------------------------------------------------------------------------------------- Types
+pub use crate::str::Str;
+pub use crate::Error;
+pub use crate::ReadableOr;
+pub use crate::Rounding;
+
+#[cfg(feature = "byte")]
+pub use crate::byte::Byte;
+
+#[cfg(feature = "date")]
+pub use crate::date::Date;
+
+#[cfg(feature = "num")]
+pub use crate::num::{Float, Int, Percent, Unsigned};
+
+#[cfg(feature = "run")]
+pub use crate::run::{Runtime, RuntimeMilli, RuntimePad};
+
+#[cfg(feature = "time")]
+pub use crate::time::{Military, Time};
+
+#[cfg(feature = "up")]
+pub use crate::up::{Htop, Idle, Uptime, UptimeCompact, UptimeFull};
+
+//---------------------------------------------------------------------------------------------------- Macros
+pub use crate::readable_fmt;
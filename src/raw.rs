@@ -0,0 +1,249 @@
+//! Low-level formatting functions
+//!
+//! These are exposed for composing custom formats, and write the same
+//! output as the types, e.g. [`write_ymd`] is the same as `Date`.
+//!
+//! Only [`push_grouped`] is shared with the number types, the date
+//! and runtime types keep their own specialized formatting.
+//!
+//! The `write_*` functions write ASCII into the front of a `&mut [u8]`
+//! and return how many bytes were written, so the output can be
+//! sliced with `&buf[..len]` or appended after with `&mut buf[len..]`.
+//!
+//! ```rust
+//! # use readable::raw::*;
+//! let mut buf = [0; 32];
+//! let mut len = write_ymd(&mut buf, 2024, 1, 2);
+//! buf[len] = b' ';
+//! len += 1;
+//! len += write_hms(&mut buf[len..], 3, 4, 5);
+//! assert_eq!(&buf[..len], b"2024-01-02 3:04:05");
+//! ```
+//!
+//! ## Panics
+//! All functions panic if the output does not fit in the buffer.
+//!
+//! ```rust,should_panic
+//! # use readable::raw::*;
+//! let mut buf = [0; 9];
+//! // `YYYY-MM-DD` is 10 bytes.
+//! write_ymd(&mut buf, 2024, 1, 2);
+//! ```
+//!
+//! No validation is done on the input numbers, e.g. `write_hms(buf, 0, 75, 0)` writes `0:75:00`.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+use crate::toa::Itoa;
+
+//---------------------------------------------------------------------------------------------------- Padding
+#[inline]
+/// Write `n` with leading zeros so that it is at least `width` digits
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 8];
+///
+/// let len = write_zero_pad(&mut buf, 7, 3);
+/// assert_eq!(&buf[..len], b"007");
+///
+/// // Numbers longer than `width` are not cut off.
+/// let len = write_zero_pad(&mut buf, 12345, 3);
+/// assert_eq!(&buf[..len], b"12345");
+/// ```
+pub fn write_zero_pad(buf: &mut [u8], n: u64, width: usize) -> usize {
+    let itoa = Itoa::new(n);
+    let digits = itoa.as_str().as_bytes();
+    let pad = width.saturating_sub(digits.len());
+    let len = pad + digits.len();
+
+    buf[..pad].fill(b'0');
+    buf[pad..len].copy_from_slice(digits);
+    len
+}
+
+#[inline]
+/// Write `n` padded to 2 digits, e.g. a month, day, minute or second
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 2];
+///
+/// let len = write_2_digits(&mut buf, 1);
+/// assert_eq!(&buf[..len], b"01");
+///
+/// let len = write_2_digits(&mut buf, 12);
+/// assert_eq!(&buf[..len], b"12");
+/// ```
+pub fn write_2_digits(buf: &mut [u8], n: u8) -> usize {
+    write_zero_pad(buf, u64::from(n), 2)
+}
+
+//---------------------------------------------------------------------------------------------------- Date
+#[inline]
+/// Write a year padded to 4 digits, i.e. `YYYY`
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 5];
+///
+/// let len = write_year(&mut buf, 2024);
+/// assert_eq!(&buf[..len], b"2024");
+///
+/// let len = write_year(&mut buf, 999);
+/// assert_eq!(&buf[..len], b"0999");
+/// ```
+pub fn write_year(buf: &mut [u8], year: u16) -> usize {
+    write_zero_pad(buf, u64::from(year), 4)
+}
+
+#[inline]
+/// Write a date in `YYYY-MM-DD` form
+///
+/// This is the same format as [`Date`](crate::date::Date).
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 10];
+///
+/// let len = write_ymd(&mut buf, 2024, 1, 2);
+/// assert_eq!(&buf[..len], b"2024-01-02");
+/// ```
+pub fn write_ymd(buf: &mut [u8], year: u16, month: u8, day: u8) -> usize {
+    let mut len = write_year(buf, year);
+    buf[len] = b'-';
+    len += 1;
+    len += write_2_digits(&mut buf[len..], month);
+    buf[len] = b'-';
+    len += 1;
+    len + write_2_digits(&mut buf[len..], day)
+}
+
+//---------------------------------------------------------------------------------------------------- Time
+#[inline]
+/// Write a clock time in `h:mm:ss` form
+///
+/// The hours are not padded, the minutes and seconds are padded to 2 digits.
+///
+/// This is the same format as [`Runtime`](crate::run::Runtime) when hours are present.
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 16];
+///
+/// let len = write_hms(&mut buf, 1, 2, 3);
+/// assert_eq!(&buf[..len], b"1:02:03");
+///
+/// let len = write_hms(&mut buf, 100, 59, 59);
+/// assert_eq!(&buf[..len], b"100:59:59");
+/// ```
+pub fn write_hms(buf: &mut [u8], hour: u64, minute: u8, second: u8) -> usize {
+    let mut len = write_zero_pad(buf, hour, 1);
+    buf[len] = b':';
+    len += 1;
+    len += write_2_digits(&mut buf[len..], minute);
+    buf[len] = b':';
+    len += 1;
+    len + write_2_digits(&mut buf[len..], second)
+}
+
+//---------------------------------------------------------------------------------------------------- Comma
+#[inline]
+/// Write ASCII `digits` with a comma every 3 digits, e.g. `1,234,567`
+///
+/// The digits are copied as-is, there is no validation.
+///
+/// ```rust
+/// # use readable::raw::*;
+/// let mut buf = [0; 16];
+///
+/// let len = write_grouped(&mut buf, b"1234567");
+/// assert_eq!(&buf[..len], b"1,234,567");
+///
+/// let len = write_grouped(&mut buf, b"100");
+/// assert_eq!(&buf[..len], b"100");
+/// ```
+pub fn write_grouped(buf: &mut [u8], digits: &[u8]) -> usize {
+    let mut len = 0;
+    for (i, d) in digits.iter().enumerate() {
+        if i != 0 && (digits.len() - i) % 3 == 0 {
+            buf[len] = b',';
+            len += 1;
+        }
+        buf[len] = *d;
+        len += 1;
+    }
+    len
+}
+
+#[inline]
+/// Push ASCII `digits` into `s`, with a comma every 3 digits, e.g. `1,234,567`
+///
+/// This is [`write_grouped`] for [`Str`].
+///
+/// ```rust
+/// # use readable::{raw::*,str::*};
+/// let mut s = Str::<16>::new();
+/// s.push_str_panic("$");
+/// push_grouped(&mut s, b"1234567");
+/// assert_eq!(s, "$1,234,567");
+/// ```
+///
+/// ## Panics
+/// This panics if `s` does not have enough capacity left.
+pub fn push_grouped<const N: usize>(s: &mut Str<N>, digits: &[u8]) {
+    for (i, d) in digits.iter().enumerate() {
+        if i != 0 && (digits.len() - i) % 3 == 0 {
+            s.push_char_panic(',');
+        }
+        s.push_char_panic(char::from(*d));
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(b: &[u8], l: usize) -> &str {
+        std::str::from_utf8(&b[..l]).unwrap()
+    }
+
+    #[test]
+    fn zero_pad() {
+        let buf = &mut [0; 32];
+        for n in [0, 1, 9, 10, 99, 100, 12345, u64::MAX] {
+            for width in 0..=24 {
+                let len = write_zero_pad(buf, n, width);
+                assert_eq!(s(buf, len), format!("{n:0width$}"));
+            }
+        }
+    }
+
+    #[test]
+    fn hms() {
+        let buf = &mut [0; 32];
+        let len = write_hms(buf, 0, 0, 0);
+        assert_eq!(s(buf, len), "0:00:00");
+        let len = write_hms(buf, 10, 10, 10);
+        assert_eq!(s(buf, len), "10:10:10");
+        let len = write_hms(buf, u64::MAX, 1, 1);
+        assert_eq!(s(buf, len), format!("{}:01:01", u64::MAX));
+    }
+
+    #[test]
+    fn grouped() {
+        let buf = &mut [0; 64];
+        for n in [0, 1, 12, 123, 1234, 12345, 123_456, 1_234_567, u128::MAX] {
+            let digits = n.to_string();
+            let len = write_grouped(buf, digits.as_bytes());
+
+            let mut string = Str::<64>::new();
+            push_grouped(&mut string, digits.as_bytes());
+            assert_eq!(s(buf, len), string.as_str());
+
+            #[cfg(feature = "num")]
+            assert_eq!(string, crate::num::Unsigned128::from(n).as_str());
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//---------------------------------------------------------------------------------------------------- readable_fmt
+#[macro_export]
+/// Format a number with the right `readable` type
+///
+/// This is a single entry point for all the `From` constructors,
+/// where the type is picked with a keyword after `=>`:
+///
+/// | Keyword         | Type | Feature |
+/// |-----------------|------|---------|
+/// | `unsigned`      | [`Unsigned`](crate::num::Unsigned) | `num`
+/// | `int`           | [`Int`](crate::num::Int) | `num`
+/// | `float`         | [`Float`](crate::num::Float) | `num`
+/// | `percent`       | [`Percent`](crate::num::Percent) | `num`
+/// | `permille`      | [`Permille`](crate::num::Permille) | `num`
+/// | `ppm`           | [`Ppm`](crate::num::Ppm) | `num`
+/// | `accounting`    | [`Accounting`](crate::num::Accounting) | `num`
+/// | `bytes`         | [`Byte`](crate::byte::Byte) | `byte`
+/// | `runtime`       | [`Runtime`](crate::run::Runtime) | `run`
+/// | `runtime_pad`   | [`RuntimePad`](crate::run::RuntimePad) | `run`
+/// | `runtime_milli` | [`RuntimeMilli`](crate::run::RuntimeMilli) | `run`
+/// | `runtime_hours` | [`RuntimeHours`](crate::run::RuntimeHours) | `run`
+/// | `runtime_units` | [`RuntimeUnits`](crate::run::RuntimeUnits) | `run`
+/// | `uptime`        | [`Uptime`](crate::up::Uptime) | `up`
+/// | `uptime_full`   | [`UptimeFull`](crate::up::UptimeFull) | `up`
+/// | `htop`          | [`Htop`](crate::up::Htop) | `up`
+/// | `uptime_compact` | [`UptimeCompact`](crate::up::UptimeCompact) | `up`
+/// | `time`          | [`Time`](crate::time::Time) | `time`
+/// | `military`      | [`Military`](crate::time::Military) | `time`
+/// | `ip`            | [`Ip`](crate::net::Ip) | `net`
+///
+/// The input can be anything that type implements [`From`] for.
+///
+/// ```rust
+/// use readable::readable_fmt;
+///
+/// assert_eq!(readable_fmt!(1024_u64 => bytes),  "1.024 KB");
+/// assert_eq!(readable_fmt!(86461_u32 => uptime), "1d, 1m, 1s");
+/// assert_eq!(readable_fmt!(61 => runtime),       "1:01");
+/// assert_eq!(readable_fmt!(1000_u32 => unsigned), "1,000");
+/// assert_eq!(readable_fmt!(-1000 => int),        "-1,000");
+/// assert_eq!(readable_fmt!(50.0 => percent),     "50.00%");
+///
+/// // The output is the actual type.
+/// let uptime: readable::up::Uptime = readable_fmt!(60_u32 => uptime);
+/// assert_eq!(uptime.inner(), 60);
+/// ```
+///
+/// An unknown keyword is a compile error:
+/// ```rust,compile_fail
+/// readable::readable_fmt!(1 => seconds);
+/// ```
+macro_rules! readable_fmt {
+    ($e:expr => unsigned) => {
+        <$crate::num::Unsigned>::from($e)
+    };
+    ($e:expr => int) => {
+        <$crate::num::Int>::from($e)
+    };
+    ($e:expr => float) => {
+        <$crate::num::Float>::from($e)
+    };
+    ($e:expr => percent) => {
+        <$crate::num::Percent>::from($e)
+    };
+    ($e:expr => permille) => {
+        <$crate::num::Permille>::from($e)
+    };
+    ($e:expr => ppm) => {
+        <$crate::num::Ppm>::from($e)
+    };
+    ($e:expr => accounting) => {
+        <$crate::num::Accounting>::from($e)
+    };
+    ($e:expr => bytes) => {
+        <$crate::byte::Byte>::from($e)
+    };
+    ($e:expr => runtime) => {
+        <$crate::run::Runtime>::from($e)
+    };
+    ($e:expr => runtime_pad) => {
+        <$crate::run::RuntimePad>::from($e)
+    };
+    ($e:expr => runtime_milli) => {
+        <$crate::run::RuntimeMilli>::from($e)
+    };
+    ($e:expr => runtime_hours) => {
+        <$crate::run::RuntimeHours>::from($e)
+    };
+    ($e:expr => runtime_units) => {
+        <$crate::run::RuntimeUnits>::from($e)
+    };
+    ($e:expr => uptime) => {
+        <$crate::up::Uptime>::from($e)
+    };
+    ($e:expr => uptime_full) => {
+        <$crate::up::UptimeFull>::from($e)
+    };
+    ($e:expr => htop) => {
+        <$crate::up::Htop>::from($e)
+    };
+    ($e:expr => uptime_compact) => {
+        <$crate::up::UptimeCompact>::from($e)
+    };
+    ($e:expr => time) => {
+        <$crate::time::Time>::from($e)
+    };
+    ($e:expr => military) => {
+        <$crate::time::Military>::from($e)
+    };
+    ($e:expr => ip) => {
+        <$crate::net::Ip>::from($e)
+    };
+}
@@ -0,0 +1,200 @@
+//! Fallback display
+
+//---------------------------------------------------------------------------------------------------- ReadableOr
+/// A formatted value, or a fallback string
+///
+/// This is for optional data that should still display something, e.g.
+/// a song with an unknown length showing `N/A` instead of a [`Runtime`](crate::run::Runtime).
+///
+/// Both variants display as their string.
+///
+/// ```rust
+/// # use readable::*;
+/// # use readable::run::*;
+/// let known: ReadableOr<Runtime> = Some(Runtime::from(225)).into();
+/// let unknown: ReadableOr<Runtime> = None.into();
+///
+/// assert_eq!(known, "3:45");
+/// assert_eq!(unknown, "N/A");
+///
+/// // Both can be used like a `&str`.
+/// assert_eq!(format!("[{known:>5}] [{unknown:>5}]"), "[ 3:45] [  N/A]");
+/// ```
+///
+/// The fallback can be any other type that is a `str`, e.g. another `readable` type:
+///
+/// ```rust
+/// # use readable::*;
+/// # use readable::num::*;
+/// let or = ReadableOr::from_option(None, Unsigned::from(0_u64));
+/// let value: ReadableOr<Percent, Unsigned> = or;
+/// assert_eq!(value, "0");
+///
+/// let value = ReadableOr::from_option(Some(Percent::from(50.0)), "-");
+/// assert_eq!(value, "50.00%");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReadableOr<A, B = &'static str> {
+    /// The formatted value
+    Readable(A),
+    /// The fallback
+    Or(B),
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl<A> ReadableOr<A> {
+    /// The fallback used by [`From<Option<A>>`](#impl-From%3COption%3CA%3E%3E-for-ReadableOr%3CA%3E)
+    pub const NOT_AVAILABLE: &'static str = "N/A";
+}
+
+impl<A, B> ReadableOr<A, B> {
+    #[inline]
+    /// [`ReadableOr::Readable`] if `option` is [`Some`], else [`ReadableOr::Or`] with `or`
+    ///
+    /// ```rust
+    /// # use readable::*;
+    /// # use readable::byte::*;
+    /// let size = ReadableOr::from_option(None::<Byte>, "unknown size");
+    /// assert_eq!(size, "unknown size");
+    /// ```
+    pub fn from_option(option: Option<A>, or: B) -> Self {
+        match option {
+            Some(a) => Self::Readable(a),
+            None => Self::Or(or),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this is [`ReadableOr::Readable`]
+    pub const fn is_readable(&self) -> bool {
+        matches!(self, Self::Readable(_))
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this is [`ReadableOr::Or`]
+    pub const fn is_or(&self) -> bool {
+        matches!(self, Self::Or(_))
+    }
+
+    #[inline]
+    /// Returns the formatted value, if there is one
+    ///
+    /// ```rust
+    /// # use readable::*;
+    /// # use readable::num::*;
+    /// let value = ReadableOr::<Unsigned>::from(Some(Unsigned::from(1_u64)));
+    /// assert_eq!(value.readable(), Some(&Unsigned::from(1_u64)));
+    ///
+    /// let value = ReadableOr::<Unsigned>::from(None);
+    /// assert_eq!(value.readable(), None);
+    /// ```
+    pub const fn readable(&self) -> Option<&A> {
+        match self {
+            Self::Readable(a) => Some(a),
+            Self::Or(_) => None,
+        }
+    }
+
+    #[inline]
+    /// Returns the formatted value, if there is one
+    pub fn into_readable(self) -> Option<A> {
+        match self {
+            Self::Readable(a) => Some(a),
+            Self::Or(_) => None,
+        }
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> ReadableOr<A, B> {
+    #[inline]
+    #[must_use]
+    /// The string of whichever variant this is
+    ///
+    /// ```rust
+    /// # use readable::*;
+    /// # use readable::up::*;
+    /// let uptime = ReadableOr::<Uptime>::from(Some(Uptime::from(61_u32)));
+    /// assert_eq!(uptime.as_str(), "1m, 1s");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Readable(a) => a.as_ref(),
+            Self::Or(b) => b.as_ref(),
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<A> From<Option<A>> for ReadableOr<A> {
+    #[inline]
+    /// [`None`] is [`ReadableOr::NOT_AVAILABLE`], i.e. `N/A`
+    fn from(option: Option<A>) -> Self {
+        Self::from_option(option, Self::NOT_AVAILABLE)
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> std::ops::Deref for ReadableOr<A, B> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> AsRef<str> for ReadableOr<A, B> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> std::fmt::Display for ReadableOr<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> PartialEq<str> for ReadableOr<A, B> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<A: AsRef<str>, B: AsRef<str>> PartialEq<&str> for ReadableOr<A, B> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        let readable = ReadableOr::<&str, String>::Readable("a");
+        let or = ReadableOr::<&str, String>::Or("b".into());
+
+        assert!(readable.is_readable());
+        assert!(!readable.is_or());
+        assert!(or.is_or());
+        assert_eq!(readable.as_str(), "a");
+        assert_eq!(or.as_str(), "b");
+        assert_eq!(or.to_string(), "b");
+        assert_eq!(readable.into_readable(), Some("a"));
+        assert_eq!(or.into_readable(), None);
+
+        assert_eq!(ReadableOr::from(Some("c")), "c");
+        assert_eq!(
+            ReadableOr::<&str>::from(None),
+            ReadableOr::<&str>::NOT_AVAILABLE
+        );
+    }
+}
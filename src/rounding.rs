@@ -0,0 +1,77 @@
+//! Rounding
+
+//---------------------------------------------------------------------------------------------------- Use
+use std::cmp::Ordering;
+
+//---------------------------------------------------------------------------------------------------- Rounding
+/// How to round the last shown decimal
+///
+/// This is taken by the `with_rounding()` constructors,
+/// e.g. `Float::with_rounding()`, `Percent::with_rounding()` and `Byte::with_rounding()`.
+///
+/// Floats are rounded by their shortest decimal representation
+/// (the one [`Display`](std::fmt::Display) prints), so `2.675` is a
+/// tie even though the closest [`f64`] is slightly below it.
+///
+/// Negative numbers are rounded by their absolute value, e.g. [`Rounding::HalfUp`] rounds `-0.5` to `-1`.
+///
+/// | Input   | [`Rounding::Truncate`] | [`Rounding::HalfUp`] | [`Rounding::HalfEven`] |
+/// |---------|------------------------|----------------------|------------------------|
+/// | `1.234` | `1.23`                 | `1.23`               | `1.23`
+/// | `1.235` | `1.23`                 | `1.24`               | `1.24`
+/// | `1.245` | `1.24`                 | `1.25`               | `1.24`
+/// | `1.246` | `1.24`                 | `1.25`               | `1.25`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rounding {
+    /// Cut off the extra decimals, i.e. round towards zero
+    Truncate,
+    /// Round to the nearest, ties away from zero
+    HalfUp,
+    /// Round to the nearest, ties to the even digit, i.e. "banker's rounding"
+    HalfEven,
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Rounding {
+    #[inline]
+    /// If the last kept digit should be incremented.
+    ///
+    /// `odd` is if the last kept digit is odd,
+    /// `rest` is the cut off part compared to one half of the last digit.
+    pub(crate) const fn round_up(self, odd: bool, rest: Ordering) -> bool {
+        match (self, rest) {
+            (Self::Truncate, _) | (_, Ordering::Less) => false,
+            (_, Ordering::Greater) | (Self::HalfUp, Ordering::Equal) => true,
+            (Self::HalfEven, Ordering::Equal) => odd,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_up() {
+        for odd in [true, false] {
+            for rest in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+                assert!(!Rounding::Truncate.round_up(odd, rest));
+            }
+            assert!(!Rounding::HalfUp.round_up(odd, Ordering::Less));
+            assert!(!Rounding::HalfEven.round_up(odd, Ordering::Less));
+            assert!(Rounding::HalfUp.round_up(odd, Ordering::Greater));
+            assert!(Rounding::HalfEven.round_up(odd, Ordering::Greater));
+            assert!(Rounding::HalfUp.round_up(odd, Ordering::Equal));
+        }
+        assert!(Rounding::HalfEven.round_up(true, Ordering::Equal));
+        assert!(!Rounding::HalfEven.round_up(false, Ordering::Equal));
+    }
+}
@@ -0,0 +1,93 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::run::{Runtime, RuntimeHours, RuntimeMilli, RuntimePad, RuntimeUnion, RuntimeUnits};
+
+//---------------------------------------------------------------------------------------------------- chrono
+// Implements `From<chrono::Duration>`.
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono {
+	($($run:ty),*) => {
+		$(
+			impl From<chrono::Duration> for $run {
+				#[inline]
+				/// Returns [`Self::UNKNOWN`] if the [`chrono::Duration`] is negative or greater than [`Self::MAX`].
+				fn from(duration: chrono::Duration) -> Self {
+					let secs = duration.num_milliseconds() as f64 / 1_000.0;
+					Self::try_from_secs(secs).unwrap_or(Self::UNKNOWN)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "chrono")]
+impl_chrono!(
+    Runtime,
+    RuntimePad,
+    RuntimeHours,
+    RuntimeUnits,
+    RuntimeMilli,
+    RuntimeUnion
+);
+
+//---------------------------------------------------------------------------------------------------- time
+// Implements `From<time::Duration>`.
+#[cfg(feature = "time-crate")]
+macro_rules! impl_time {
+	($($run:ty),*) => {
+		$(
+			impl From<::time::Duration> for $run {
+				#[inline]
+				/// Returns [`Self::UNKNOWN`] if the [`time::Duration`](::time::Duration) is negative or greater than [`Self::MAX`].
+				fn from(duration: ::time::Duration) -> Self {
+					Self::try_from_secs(duration.as_seconds_f64()).unwrap_or(Self::UNKNOWN)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "time-crate")]
+impl_time!(
+    Runtime,
+    RuntimePad,
+    RuntimeHours,
+    RuntimeUnits,
+    RuntimeMilli,
+    RuntimeUnion
+);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        let duration = chrono::Duration::seconds(3_661) + chrono::Duration::milliseconds(555);
+        assert_eq!(Runtime::from(duration), "1:01:01");
+        assert_eq!(RuntimePad::from(duration), "01:01:01");
+        assert_eq!(RuntimeHours::from(duration), "1:01:01");
+        assert_eq!(RuntimeUnits::from(duration), "1h1m1s");
+        assert_eq!(RuntimeMilli::from(duration), "01:01:01.555");
+        assert_eq!(RuntimeUnion::from(duration).as_str_milli(), "01:01:01.555");
+
+        assert_eq!(Runtime::from(chrono::Duration::zero()), Runtime::ZERO);
+        assert!(Runtime::from(chrono::Duration::milliseconds(-1)).is_unknown());
+        assert!(RuntimeMilli::from(chrono::Duration::seconds(360_000)).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "time-crate")]
+    fn time() {
+        let duration = ::time::Duration::seconds(3_661) + ::time::Duration::milliseconds(555);
+        assert_eq!(Runtime::from(duration), "1:01:01");
+        assert_eq!(RuntimePad::from(duration), "01:01:01");
+        assert_eq!(RuntimeHours::from(duration), "1:01:01");
+        assert_eq!(RuntimeUnits::from(duration), "1h1m1s");
+        assert_eq!(RuntimeMilli::from(duration), "01:01:01.555");
+        assert_eq!(RuntimeUnion::from(duration).as_str_milli(), "01:01:01.555");
+
+        assert_eq!(Runtime::from(::time::Duration::ZERO), Runtime::ZERO);
+        assert!(Runtime::from(::time::Duration::milliseconds(-1)).is_unknown());
+        assert!(RuntimeMilli::from(::time::Duration::seconds(360_000)).is_unknown());
+    }
+}
@@ -57,9 +57,54 @@
 //! assert_eq!(milli2.inner(), 1.555);
 //! ```
 //!
-//! This is because the inner [`f32`] stored is simply copied,
+//! This is because the inner float stored is simply copied,
 //! only the formatted string is different.
 //!
+//! [`RuntimeMilli`] stores an [`f64`] while the other types store an [`f32`],
+//! when converting to [`RuntimeMilli`] the [`f32`] is rounded to the nearest millisecond.
+//!
+//! ## ISO 8601
+//! [`Runtime`], [`RuntimePad`], [`RuntimeHours`], [`RuntimeUnits`] and [`RuntimeMilli`] can be formatted as
+//! (and parsed from) ISO 8601 durations with `as_iso8601()` and `from_iso8601()`, e.g:
+//!
+//! ```rust
+//! # use readable::run::*;
+//! assert_eq!(Runtime::from(5_411).as_iso8601(), "PT1H30M11S");
+//! assert_eq!(Runtime::from_iso8601("PT90M").unwrap(), "1:30:00");
+//! assert_eq!(RuntimeMilli::from(1.5).as_iso8601(), "PT1.5S");
+//! ```
+//!
+//! Hours are the largest unit used when formatting,
+//! and only [`RuntimeMilli`] keeps the milliseconds.
+//!
+//! [`Runtime`]'s [`std::str::FromStr`] is [`Runtime::from_str`], which also accepts ISO 8601,
+//! the other types use `from_iso8601()`, so `str::parse()` works too:
+//!
+//! ```rust
+//! # use readable::run::*;
+//! let runtime: Runtime = "PT1M1S".parse().unwrap();
+//! assert_eq!(runtime, "1:01");
+//! assert_eq!("1:01".parse::<Runtime>().unwrap(), runtime);
+//! assert_eq!("1:01".parse::<RuntimePad>(), Err(readable::Error::Parse));
+//! ```
+//!
+//! ## `chrono` & `time`
+//! With the `chrono` and `time-crate` features, all [`Runtime`] types implement
+//! [`From`] the signed `chrono::Duration` and `time::Duration`.
+//!
+//! Negative durations return an `UNKNOWN`, the same as negative integers.
+//!
+//! ```rust
+//! # use readable::run::*;
+//! # #[cfg(feature = "chrono")] {
+//! assert_eq!(RuntimeMilli::from(chrono::Duration::milliseconds(61_500)), "00:01:01.500");
+//! assert!(Runtime::from(chrono::Duration::seconds(-1)).is_unknown());
+//! # }
+//! # #[cfg(feature = "time-crate")] {
+//! assert_eq!(Runtime::from(time::Duration::seconds(61)), "1:01");
+//! # }
+//! ```
+//!
 //! ## Errors
 //! The max input is `359999` seconds, or: anything over `99:59:59`.
 //!
@@ -92,16 +137,13 @@
 //! assert_eq!(pad, "00:00:03");
 //! assert_eq!(pad, 3.0);
 //!
-//! // Floating point error!
+//! // `RuntimeMilli` formats whole milliseconds,
+//! // so floating point error does not reach the string.
 //! let milli = RuntimeMilli::from(2.0) + 1.555;
-//! assert_eq!(milli.inner(), 3.5549998);
-//!
-//! // Use 1 more decimal to make sure
-//! // weird rounding doesn't happen.
-//! let milli = RuntimeMilli::from(2.0) + 1.5551;
-//! assert_eq!(milli, RuntimeMilli::from(3.5551));
 //! assert_eq!(milli, "00:00:03.555");
-//! assert_eq!(milli, 3.5551);
+//!
+//! // Although the inner `f64` itself may still be slightly off.
+//! assert_eq!(milli.inner(), 3.5549999999999997);
 //! ```
 //!
 //! ## Copy
@@ -129,8 +171,20 @@ pub use runtime::*;
 mod runtime_pad;
 pub use runtime_pad::*;
 
+mod runtime_hours;
+pub use runtime_hours::*;
+
 mod runtime_milli;
 pub use runtime_milli::*;
 
+mod runtime_units;
+pub use runtime_units::*;
+
 mod runtime_union;
 pub use runtime_union::*;
+
+mod timecode;
+pub use timecode::*;
+
+#[cfg(any(feature = "chrono", feature = "time-crate"))]
+mod interop;
@@ -1,6 +1,9 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize};
-use crate::run::{RuntimeMilli, RuntimePad, RuntimeUnion};
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize,
+};
+use crate::run::{RuntimeHours, RuntimeMilli, RuntimePad, RuntimeUnion, RuntimeUnits};
 use crate::str::Str;
 
 //---------------------------------------------------------------------------------------------------- Runtime
@@ -19,6 +22,21 @@ use crate::str::Str;
 /// assert_eq!(std::mem::size_of::<Runtime>(), 16);
 /// ```
 ///
+/// ## Inlining
+/// The `inline_runtime_*` features make [`Runtime::from`] copy
+/// pre-formatted strings instead of formatting, in exchange for binary size:
+///
+/// | Feature               | Inlined range          | Binary size |
+/// |-----------------------|------------------------|-------------|
+/// | `inline_runtime_1h`   | `0:00` ..= `59:59`     | ~70 KB      |
+/// | `inline_runtime_24h`  | `0:00` ..= `23:59:59`  | ~2 MB       |
+/// | `inline_runtime_full` | `0:00` ..= `99:59:59`  | ~8 MB       |
+///
+/// Runtimes past the inlined range are formatted as usual, so the output is always the same.
+///
+/// The range can also be set exactly with the `READABLE_INLINED_RUNTIME`
+/// environment variable at build time, e.g. `READABLE_INLINED_RUNTIME=600` for `0:00` ..= `9:59`.
+///
 /// ## Examples
 /// ```rust
 /// # use readable::run::*;
@@ -46,19 +64,32 @@ use crate::str::Str;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone)]
 pub struct Runtime(pub(super) f32, pub(super) Str<{ Runtime::MAX_LEN }>);
 
 impl_runtime! { // This macro is defined below.
     self  = Runtime,
+    float = f32,
     len   = Runtime::MAX_LEN,
     union = as_str,
 
     other = RuntimePad,
     other = RuntimeMilli,
+    other = RuntimeHours,
+    other = RuntimeUnits,
+}
+impl_components! {
+    self  = Runtime,
+    str   = as_str,
+    milli = 0,
 }
 impl_math!(Runtime, f32);
 impl_traits!(Runtime, f32);
+impl_from_option!(Runtime);
+impl_float_eq!(Runtime => f32);
+impl_std_from_str!(Runtime => from_str);
+#[cfg(feature = "clap")]
+crate::macros::impl_clap!(Runtime, "a runtime, e.g. `1h30m`, `90s` or `1:30:00`");
 
 //---------------------------------------------------------------------------------------------------- Runtime Constants
 impl Runtime {
@@ -137,6 +168,16 @@ impl Runtime {
 impl Runtime {
     impl_common!(f32);
     impl_const!();
+    impl_format_slice!(run, Runtime, [0_u32, 65, 3599, 86_399]);
+    impl_iso8601!(
+        Runtime,
+        run,
+        run,
+        "5_411",
+        "PT1H30M11S",
+        "PT1H30M",
+        "5400.0"
+    );
     impl_usize!();
 
     #[inline]
@@ -149,10 +190,139 @@ impl Runtime {
     pub const fn is_unknown(&self) -> bool {
         matches!(self.1.as_bytes(), b"?:??")
     }
+
+    #[must_use]
+    /// Create a [`Runtime`] from an audio sample position
+    ///
+    /// This divides `samples` by `sample_rate` using integers, so
+    /// the string is exact and does not go through lossy [`f32`] seconds.
+    ///
+    /// [`Self::UNKNOWN`] is returned if `sample_rate` is `0`
+    /// or if the runtime is greater than [`Self::MAX_F32`].
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Runtime::from_samples(44_100, 44_100),          "0:01");
+    /// assert_eq!(Runtime::from_samples(158_760_000, 44_100),     "1:00:00");
+    /// assert_eq!(Runtime::from_samples(15_875_955_900, 44_100),  "99:59:59");
+    /// assert_eq!(Runtime::from_samples(15_875_955_901, 44_100),  Runtime::UNKNOWN);
+    /// assert_eq!(Runtime::from_samples(1, 0),                    Runtime::UNKNOWN);
+    /// ```
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let Some((inner, h, m, s, _)) = Self::priv_from_samples(samples, sample_rate) else {
+            return Self::UNKNOWN;
+        };
+
+        let mut buf = [0; Self::MAX_LEN];
+        let len = if h > 0 {
+            Self::format_hms(&mut buf, h, m, s)
+        } else {
+            Self::format_ms(&mut buf, m, s)
+        };
+
+        // SAFETY: we know the str len
+        Self(inner as f32, unsafe { Str::from_raw(buf, len as u8) })
+    }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a runtime, e.g. `1h30m`
+    ///
+    /// This accepts:
+    /// - [`Runtime`]'s own format, `H:MM:SS` or `M:SS`
+    /// - Units, `h`, `m` and `s`, in that order, each at most once, e.g. `1h30m`, `1h 30m 5s` or `1.5h`
+    /// - Seconds, e.g. `90`
+    /// - ISO 8601 durations, e.g. `PT1H30M`, the same as [`Runtime::from_iso8601`]
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Runtime::from_str("1:30:00").unwrap(),   "1:30:00");
+    /// assert_eq!(Runtime::from_str("5:00").unwrap(),      "5:00");
+    /// assert_eq!(Runtime::from_str("1h30m").unwrap(),     "1:30:00");
+    /// assert_eq!(Runtime::from_str("1h 30m 5s").unwrap(), "1:30:05");
+    /// assert_eq!(Runtime::from_str("1.5h").unwrap(),      "1:30:00");
+    /// assert_eq!(Runtime::from_str("90").unwrap(),        "1:30");
+    /// assert_eq!(Runtime::from_str("PT1H30M").unwrap(),   "1:30:00");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string can't be parsed, or is over [`Runtime::MAX`],
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert!(Runtime::from_str("").is_err());
+    /// assert!(Runtime::from_str("30m1h").is_err());
+    /// assert!(Runtime::from_str("1h1h").is_err());
+    /// assert!(Runtime::from_str("1:60").is_err());
+    /// assert!(Runtime::from_str("100h").is_err());
+    /// assert!(Runtime::from_str("-1s").is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        let string = string.trim();
+        if string.starts_with('P') {
+            return Self::from_iso8601(string);
+        }
+
+        match Self::priv_parse_secs(string).map(Self::try_from_secs) {
+            Some(Ok(this)) => Ok(this),
+            _ => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Runtime::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
 impl Runtime {
+    // Parse `H:MM:SS`, `M:SS`, `1h30m5s` or `90` into seconds.
+    fn priv_parse_secs(string: &str) -> Option<f64> {
+        if string.is_empty() {
+            return None;
+        }
+
+        if string.contains(':') {
+            let mut parts = string.split(':');
+            let mut secs = f64::from(parts.next()?.parse::<u32>().ok()?);
+            let mut count = 1;
+            for part in parts {
+                if part.len() != 2 {
+                    return None;
+                }
+                let n = part.parse::<u8>().ok().filter(|n| *n < 60)?;
+                secs = secs.mul_add(60.0, f64::from(n));
+                count += 1;
+            }
+            return (count <= 3).then_some(secs);
+        }
+
+        if let Some(secs) = crate::iso8601::number(string) {
+            return Some(secs);
+        }
+
+        let mut units = [('h', 3_600.0), ('m', 60.0), ('s', 1.0)].into_iter();
+        let mut total = 0.0;
+        let mut rest = string;
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')?;
+            let (number, tail) = rest.split_at(end);
+            let mut tail = tail.chars();
+            let unit = tail.next()?;
+            let (_, secs) = units.find(|(u, _)| *u == unit)?;
+            total += crate::iso8601::number(number)? * secs;
+            rest = tail.as_str().trim_start();
+        }
+
+        Some(total)
+    }
+
     #[allow(unreachable_code)]
     #[inline]
     // Private function used in float `From`.
@@ -169,6 +339,12 @@ impl Runtime {
             return Self::ZERO;
         }
 
+        // Past the inlined range, this falls back to formatting.
+        #[cfg(feature = "inline_runtime_1h")]
+        if let Some(string) = readable_inlined_runtime::inlined(runtime as u32) {
+            return Self(runtime, Str::from_static_str(string));
+        }
+
         let (hours, minutes, seconds) = (h as u8, m as u8, s as u8);
         let mut buf = [0; Self::MAX_LEN];
 
@@ -183,6 +359,36 @@ impl Runtime {
         Self(runtime, unsafe { Str::from_raw(buf, len as u8) })
     }
 
+    #[inline]
+    // Exact `(inner, hours, minutes, seconds, milliseconds)` from an audio sample position.
+    //
+    // `None` is returned if `sample_rate` is `0` or the runtime is over `MAX_F32`.
+    pub(super) fn priv_from_samples(
+        samples: u64,
+        sample_rate: u32,
+    ) -> Option<(f64, u8, u8, u8, u16)> {
+        if sample_rate == 0 {
+            return None;
+        }
+
+        let rate = u64::from(sample_rate);
+        let secs = samples / rate;
+        let milli = (samples % rate) * 1000 / rate;
+
+        // Same limit as the float constructors.
+        let max = Self::MAX_F32 as u64;
+        if secs > max || (secs == max && samples % rate != 0) {
+            return None;
+        }
+
+        let inner = samples as f64 / rate as f64;
+        let hours = (secs / 3600) as u8;
+        let minutes = (secs % 3600 / 60) as u8;
+        let seconds = (secs % 60) as u8;
+
+        Some((inner, hours, minutes, seconds, milli as u16))
+    }
+
     #[inline]
     pub(super) fn priv_from_inner(runtime: f32) -> Option<(f32, f32, f32)> {
         // Zero length.
@@ -216,12 +422,12 @@ impl Runtime {
     #[inline]
     // 0 Padding for `hh:mm:ss` according to `Runtime` rules.
     //
-    // INVARIANT: Assumes `hour` is 1 or greater.
-    fn format_hms(buf: &mut [u8; Self::MAX_LEN], hour: u8, min: u8, sec: u8) -> usize {
+    // `Runtime` only calls this if `hour` is 1 or greater,
+    // `RuntimeHours` also calls this with `0`, e.g. `0:01:00`.
+    pub(super) fn format_hms(buf: &mut [u8; Self::MAX_LEN], hour: u8, min: u8, sec: u8) -> usize {
         const Z: u8 = b'0';
         const C: u8 = b':';
 
-        debug_assert!(hour >= 1);
         debug_assert!(hour < 100);
         debug_assert!(min < 60);
         debug_assert!(sec < 60);
@@ -388,11 +594,53 @@ impl Runtime {
     }
 }
 
+//---------------------------------------------------------------------------------------------------- CastFloat
+/// Conversion of the inner float between `Runtime` types.
+///
+/// [`RuntimeMilli`] uses an [`f64`] while the others use [`f32`],
+/// going from [`f32`] -> [`f64`] rounds to the nearest millisecond,
+/// which is the highest precision any `Runtime` type displays.
+///
+/// This makes `RuntimeMilli` -> `Runtime` -> `RuntimeMilli` lossless.
+pub(super) trait CastFloat<T> {
+    /// Cast `self` into `T`.
+    fn cast(self) -> T;
+}
+
+impl CastFloat<Self> for f32 {
+    #[inline]
+    fn cast(self) -> Self {
+        self
+    }
+}
+
+impl CastFloat<Self> for f64 {
+    #[inline]
+    fn cast(self) -> Self {
+        self
+    }
+}
+
+impl CastFloat<f32> for f64 {
+    #[inline]
+    fn cast(self) -> f32 {
+        self as f32
+    }
+}
+
+impl CastFloat<f64> for f32 {
+    #[inline]
+    fn cast(self) -> f64 {
+        (f64::from(self) * 1000.0).round() / 1000.0
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- Runtime* Impl Macro
 // This is a macro for implementing across all `Runtime`-like types.
 macro_rules! impl_runtime {
 	(
 		self  = $self:ty,
+		float = $float:ty,
 		$(
 			len   = $max_len:expr,
 			union = $str_function:ident,
@@ -401,11 +649,42 @@ macro_rules! impl_runtime {
 			other = $other:ty
 		),* $(,)?
 	) => {
+		//---------------------------------------------------------------------------------------------------- Checked
+		impl $self {
+			#[inline]
+			/// Same as the float [`From`] implementations but returns the reason for failure
+			///
+			/// ```rust
+			/// # use readable::run::*;
+			/// # use readable::Error;
+			#[doc = concat!("assert_eq!(", stringify!($self), "::try_from_secs(61.0).unwrap(), ", stringify!($self), "::from(61.0));")]
+			#[doc = concat!("assert_eq!(", stringify!($self), "::try_from_secs(-1.0),          Err(Error::Negative));")]
+			#[doc = concat!("assert_eq!(", stringify!($self), "::try_from_secs(f64::NAN),      Err(Error::NonFinite));")]
+			#[doc = concat!("assert_eq!(", stringify!($self), "::try_from_secs(360_000.0),     Err(Error::TooLarge));")]
+			/// ```
+			///
+			/// ## Errors
+			/// - [`crate::Error::NonFinite`] if `secs` is [`f64::NAN`], [`f64::INFINITY`] or [`f64::NEG_INFINITY`]
+			/// - [`crate::Error::Negative`] if `secs` is negative
+			#[doc = concat!("- [`crate::Error::TooLarge`] if `secs` is larger than [`", stringify!($self), "::MAX`]")]
+			pub fn try_from_secs(secs: f64) -> Result<Self, $crate::Error> {
+				if !secs.is_finite() {
+					Err($crate::Error::NonFinite)
+				} else if secs < 0.0 {
+					Err($crate::Error::Negative)
+				} else if secs > f64::from($crate::run::Runtime::MAX_F32) {
+					Err($crate::Error::TooLarge)
+				} else {
+					Ok(Self::priv_from(secs as $float))
+				}
+			}
+		}
+
 		//---------------------------------------------------------------------------------------------------- Duration
 		impl From<std::time::Duration> for $self {
 			#[inline]
 			fn from(runtime: std::time::Duration) -> Self {
-				let f = runtime.as_secs_f32();
+				let f = runtime.as_secs_f64() as $float;
 				Self::priv_from(f)
 			}
 		}
@@ -413,7 +692,7 @@ macro_rules! impl_runtime {
 		impl From<&std::time::Duration> for $self {
 			#[inline]
 			fn from(runtime: &std::time::Duration) -> Self {
-				let f = runtime.as_secs_f32();
+				let f = runtime.as_secs_f64() as $float;
 				Self::priv_from(f)
 			}
 		}
@@ -423,7 +702,7 @@ macro_rules! impl_runtime {
 			/// # Panics
 			/// This constructor will panic if `runtime` is negative or not finite.
 			fn from(runtime: $self) -> Self {
-				std::time::Duration::from_secs_f32(runtime.inner())
+				std::time::Duration::from_secs_f64(f64::from(runtime.inner()))
 			}
 		}
 
@@ -432,7 +711,7 @@ macro_rules! impl_runtime {
 			/// # Panics
 			/// This constructor will panic if `runtime` is negative or not finite.
 			fn from(runtime: &$self) -> Self {
-				std::time::Duration::from_secs_f32(runtime.inner())
+				std::time::Duration::from_secs_f64(f64::from(runtime.inner()))
 			}
 		}
 
@@ -440,7 +719,7 @@ macro_rules! impl_runtime {
 		impl From<std::time::Instant> for $self {
 			#[inline]
 			fn from(runtime: std::time::Instant) -> Self {
-				let f = runtime.elapsed().as_secs_f32();
+				let f = runtime.elapsed().as_secs_f64() as $float;
 				Self::priv_from(f)
 			}
 		}
@@ -448,7 +727,7 @@ macro_rules! impl_runtime {
 		impl From<&std::time::Instant> for $self {
 			#[inline]
 			fn from(runtime: &std::time::Instant) -> Self {
-				let f = runtime.elapsed().as_secs_f32();
+				let f = runtime.elapsed().as_secs_f64() as $float;
 				Self::priv_from(f)
 			}
 		}
@@ -458,13 +737,13 @@ macro_rules! impl_runtime {
 			impl From<$other> for $self {
 				#[inline]
 				fn from(runtime: $other) -> Self {
-					Self::priv_from(runtime.inner())
+					Self::priv_from($crate::run::runtime::CastFloat::<$float>::cast(runtime.inner()))
 				}
 			}
 			impl From<&$other> for $self {
 				#[inline]
 				fn from(runtime: &$other) -> Self {
-					Self::priv_from(runtime.inner())
+					Self::priv_from($crate::run::runtime::CastFloat::<$float>::cast(runtime.inner()))
 				}
 			}
 		)*
@@ -477,7 +756,7 @@ macro_rules! impl_runtime {
 					fn from(f: $from) -> Self {
 						$crate::macros::return_bad_float!(f, Self::UNKNOWN, Self::UNKNOWN);
 
-						Self::priv_from(f as f32)
+						Self::priv_from(f as $float)
 					}
 				}
 				impl From<&$from> for $self {
@@ -485,7 +764,7 @@ macro_rules! impl_runtime {
 					fn from(f: &$from) -> Self {
 						$crate::macros::return_bad_float!(f, Self::UNKNOWN, Self::UNKNOWN);
 
-						Self::priv_from(*f as f32)
+						Self::priv_from(*f as $float)
 					}
 				}
 			}
@@ -499,13 +778,13 @@ macro_rules! impl_runtime {
 				impl From<$from> for $self {
 					#[inline]
 					fn from(runtime: $from) -> Self {
-						Self::priv_from(runtime as f32)
+						Self::priv_from(runtime as $float)
 					}
 				}
 				impl From<&$from> for $self {
 					#[inline]
 					fn from(runtime: &$from) -> Self {
-						Self::priv_from(*runtime as f32)
+						Self::priv_from(*runtime as $float)
 					}
 				}
 			}
@@ -526,7 +805,7 @@ macro_rules! impl_runtime {
 						if runtime.is_negative() {
 							return Self::UNKNOWN;
 						}
-						Self::priv_from(runtime as f32)
+						Self::priv_from(runtime as $float)
 					}
 				}
 				impl From<&$from> for $self {
@@ -535,7 +814,7 @@ macro_rules! impl_runtime {
 						if runtime.is_negative() {
 							return Self::UNKNOWN;
 						}
-						Self::priv_from(*runtime as f32)
+						Self::priv_from(*runtime as $float)
 					}
 				}
 			}
@@ -552,13 +831,15 @@ macro_rules! impl_runtime {
 			impl PartialEq<$other> for $self {
 				#[inline]
 				fn eq(&self, other: &$other) -> bool {
-					self.inner() == other.inner()
+					$crate::run::runtime::CastFloat::<f32>::cast(self.inner())
+						== $crate::run::runtime::CastFloat::<f32>::cast(other.inner())
 				}
 			}
 			impl PartialEq<&$other> for $self {
 				#[inline]
 				fn eq(&self, other: &&$other) -> bool {
-					self.inner() == other.inner()
+					$crate::run::runtime::CastFloat::<f32>::cast(self.inner())
+						== $crate::run::runtime::CastFloat::<f32>::cast(other.inner())
 				}
 			}
 		)*
@@ -569,7 +850,7 @@ macro_rules! impl_runtime {
 				#[inline]
 				fn from(runtime: RuntimeUnion) -> Self {
 					Self(
-						runtime.inner(),
+						$crate::run::runtime::CastFloat::<$float>::cast(runtime.inner()),
 						// SAFETY: Input string must be the same length.
 						// We know `as_str_full()` always returns the correct str.
 						Str::from_str_exact(runtime.$str_function()),
@@ -580,7 +861,7 @@ macro_rules! impl_runtime {
 				#[inline]
 				fn from(runtime: &RuntimeUnion) -> Self {
 					Self(
-						runtime.inner(),
+						$crate::run::runtime::CastFloat::<$float>::cast(runtime.inner()),
 						// SAFETY: Input string must be the same length.
 						// We know `as_str_full()` always returns the correct str.
 						Str::from_str_exact(runtime.$str_function()),
@@ -592,11 +873,337 @@ macro_rules! impl_runtime {
 }
 pub(super) use impl_runtime;
 
+//---------------------------------------------------------------------------------------------------- Components
+/// Implement `hours()`, `minutes()`, `seconds()` and `milliseconds()`.
+///
+/// These are read back out of the formatted string (`$str_function`),
+/// so they are always the exact components being displayed.
+macro_rules! impl_components {
+	(
+		self  = $self:ty,
+		str   = $str_function:ident,
+		milli = $milli:literal $(,)?
+	) => {
+		impl $self {
+			#[inline]
+			#[must_use]
+			/// The displayed hours
+			///
+			/// This is `0` when the hours are not displayed, or if [`Self::UNKNOWN`].
+			///
+			/// ```rust
+			/// # use readable::run::*;
+			#[doc = concat!("assert_eq!(", stringify!($self), "::from(3723.5).hours(), 1);")]
+			#[doc = concat!("assert_eq!(", stringify!($self), "::from(59.0).hours(),   0);")]
+			/// ```
+			pub const fn hours(&self) -> u8 {
+				$crate::run::runtime::priv_components(self.$str_function().as_bytes()).0
+			}
+
+			#[inline]
+			#[must_use]
+			/// The displayed minutes
+			///
+			/// This is `0` if [`Self::UNKNOWN`].
+			///
+			/// ```rust
+			/// # use readable::run::*;
+			#[doc = concat!("assert_eq!(", stringify!($self), "::from(3723.5).minutes(), 2);")]
+			/// ```
+			pub const fn minutes(&self) -> u8 {
+				$crate::run::runtime::priv_components(self.$str_function().as_bytes()).1
+			}
+
+			#[inline]
+			#[must_use]
+			/// The displayed seconds
+			///
+			/// This is `0` if [`Self::UNKNOWN`].
+			///
+			/// ```rust
+			/// # use readable::run::*;
+			#[doc = concat!("assert_eq!(", stringify!($self), "::from(3723.5).seconds(), 3);")]
+			/// ```
+			pub const fn seconds(&self) -> u8 {
+				$crate::run::runtime::priv_components(self.$str_function().as_bytes()).2
+			}
+
+			#[inline]
+			#[must_use]
+			/// The displayed milliseconds
+			///
+			/// This is `0` for types that do not display milliseconds, or if [`Self::UNKNOWN`].
+			///
+			/// ```rust
+			/// # use readable::run::*;
+			#[doc = concat!("assert_eq!(", stringify!($self), "::from(3723.5).milliseconds(), ", $milli, ");")]
+			/// ```
+			pub const fn milliseconds(&self) -> u16 {
+				$crate::run::runtime::priv_components(self.$str_function().as_bytes()).3
+			}
+		}
+	};
+}
+pub(super) use impl_components;
+
+#[inline]
+/// Parse `(hours, minutes, seconds, milliseconds)` out of a formatted runtime string.
+///
+/// This handles both `H:MM:SS` and `1h2m3s` (`RuntimeUnits`) strings.
+///
+/// Missing fields and `?` are `0`.
+pub(super) const fn priv_components(bytes: &[u8]) -> (u8, u8, u8, u16) {
+    let (mut h, mut m, mut s) = (0, 0, 0);
+    let mut current: u16 = 0;
+    let mut milli = false;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b @ b'0'..=b'9' => current = current * 10 + (b - b'0') as u16,
+            b':' => {
+                h = m;
+                m = current;
+                current = 0;
+            }
+            b'h' => {
+                h = current;
+                current = 0;
+            }
+            b'm' => {
+                m = current;
+                current = 0;
+            }
+            b's' => {
+                s = current;
+                current = 0;
+            }
+            b'.' => {
+                s = current;
+                current = 0;
+                milli = true;
+            }
+            _ => (),
+        }
+        i += 1;
+    }
+
+    if milli {
+        (h as u8, m as u8, s as u8, current)
+    } else {
+        // Only one of these is non-zero, `s` for `1h2m3s`, `current` for `H:MM:SS`.
+        (h as u8, m as u8, (s + current) as u8, 0)
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- TESTS
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "clap")]
+    fn clap() {
+        let command = clap::Command::new("test").arg(
+            clap::Arg::new("arg")
+                .long("arg")
+                .value_parser(clap::value_parser!(Runtime)),
+        );
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["test", "--arg", "1h30m"])
+            .unwrap();
+        assert_eq!(*matches.get_one::<Runtime>("arg").unwrap(), 5_400.0);
+
+        let error = command
+            .try_get_matches_from(["test", "--arg", "1h30x"])
+            .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(error.to_string().contains("expected a runtime"));
+    }
+
+    #[test]
+    fn from_str() {
+        for (string, secs) in [
+            ("0", 0.0),
+            ("0:00", 0.0),
+            ("1:01", 61.0),
+            ("10:00:00", 36_000.0),
+            ("99:59:59", 359_999.0),
+            (" 1h ", 3_600.0),
+            ("1h1s", 3_601.0),
+            ("1m 1s", 61.0),
+            ("0,5m", 30.0),
+            ("90.5", 90.5),
+            ("PT1M", 60.0),
+        ] {
+            assert_eq!(Runtime::from_str(string).unwrap(), secs, "{string}");
+        }
+
+        for string in [
+            "",
+            " ",
+            ":",
+            "1:",
+            ":01",
+            "1:1",
+            "1:001",
+            "1:01:01:01",
+            "100:00:00",
+            "1h1",
+            "1x",
+            "1s1m",
+            "1H",
+            "1 h",
+            "h",
+            "1hh",
+            "-1",
+            "1.2.3s",
+            "pt1m",
+        ] {
+            assert!(Runtime::from_str(string).is_err(), "{string}");
+        }
+
+        assert_eq!("1h30m".parse::<Runtime>().unwrap(), "1:30:00");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Runtime::from(61.0)),
+            r#"Runtime { inner: 61.0, str: "1:01" }"#
+        );
+        assert_eq!(
+            format!("{:?}", Runtime::UNKNOWN),
+            r#"Runtime { inner: 0.0, str: "?:??" }"#
+        );
+    }
+
+    #[test]
+    fn from_option() {
+        assert_eq!(Runtime::from(Some(225_u32)), "3:45");
+        assert_eq!(
+            Runtime::from(Some(std::time::Duration::from_secs(60))),
+            "1:00"
+        );
+        assert!(Runtime::from(None::<u32>).is_unknown());
+    }
+
+    #[test]
+    fn sum() {
+        let playlist = [
+            Runtime::from(61.5),
+            Runtime::from(58.5),
+            Runtime::from(3600),
+        ];
+        assert_eq!(playlist.iter().sum::<Runtime>(), "1:02:00");
+        assert_eq!(
+            std::iter::empty::<Runtime>().sum::<Runtime>(),
+            Runtime::ZERO
+        );
+
+        // Past `99:59:59`, same as `+`.
+        let playlist = [Runtime::MAX, Runtime::from(1)];
+        assert!(playlist.into_iter().sum::<Runtime>().is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "inline_runtime_1h")]
+    fn inlined() {
+        for secs in 0..=Runtime::MAX_F32 as u32 {
+            let Some(inlined) = readable_inlined_runtime::inlined(secs) else {
+                break;
+            };
+
+            let (h, m, s) = (
+                (secs / 3600) as u8,
+                (secs % 3600 / 60) as u8,
+                (secs % 60) as u8,
+            );
+            let mut buf = [0; Runtime::MAX_LEN];
+            let len = if h > 0 {
+                Runtime::format_hms(&mut buf, h, m, s)
+            } else {
+                Runtime::format_ms(&mut buf, m, s)
+            };
+            assert_eq!(inlined.as_bytes(), &buf[..len], "{secs}");
+            assert_eq!(Runtime::from(secs), inlined);
+        }
+
+        // Past the inlined range, formatting is the fallback.
+        assert_eq!(Runtime::from(86_399.5), "23:59:59");
+        assert_eq!(Runtime::from(359_999), "99:59:59");
+        assert!(Runtime::from(360_000).is_unknown());
+    }
+
+    #[test]
+    fn eq_ord_hash() {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(Runtime::from(61), "b");
+        map.insert(Runtime::UNKNOWN, "?");
+        map.insert(Runtime::from(1), "a");
+        assert_eq!(map.into_values().collect::<Vec<_>>(), ["?", "a", "b"]);
+
+        let set: std::collections::HashSet<Runtime> = [Runtime::from(1), Runtime::from(1.0_f64)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn components() {
+        for secs in (0..=359_999_u32).step_by(7) {
+            let expected = (
+                (secs / 3600) as u8,
+                (secs / 60 % 60) as u8,
+                (secs % 60) as u8,
+            );
+
+            let r = Runtime::from(secs);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 0)
+            );
+            let r = RuntimePad::from(secs);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 0)
+            );
+            let r = RuntimeHours::from(secs);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 0)
+            );
+            let r = RuntimeUnits::from(secs);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 0)
+            );
+            let r = RuntimeMilli::from(f64::from(secs) + 0.25);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 250)
+            );
+            let r = RuntimeUnion::from(f64::from(secs) + 0.25);
+            assert_eq!(
+                (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+                (expected.0, expected.1, expected.2, 250)
+            );
+        }
+
+        let r = Runtime::UNKNOWN;
+        assert_eq!(
+            (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+            (0, 0, 0, 0)
+        );
+        let r = RuntimeMilli::UNKNOWN;
+        assert_eq!(
+            (r.hours(), r.minutes(), r.seconds(), r.milliseconds()),
+            (0, 0, 0, 0)
+        );
+    }
+
     #[test]
     fn _format_hms() {
         fn s(b: &[u8], l: usize) -> &str {
@@ -674,6 +1281,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_samples() {
+        for rate in [8_000, 44_100, 48_000, 96_000] {
+            for secs in (0..Runtime::MAX_F32 as u64).step_by(997) {
+                let samples = secs * u64::from(rate);
+                assert_eq!(Runtime::from_samples(samples, rate), Runtime::from(secs));
+                assert_eq!(
+                    RuntimePad::from_samples(samples, rate),
+                    RuntimePad::from(secs)
+                );
+                assert_eq!(
+                    RuntimeMilli::from_samples(samples, rate),
+                    RuntimeMilli::from(secs)
+                );
+            }
+        }
+    }
+
     #[test]
     fn all_floats() {
         let mut f = 1.0;
@@ -727,6 +1352,22 @@ mod tests {
         assert!(serde_json::from_str::<Runtime>(&json).unwrap().is_unknown());
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn postcard() {
+        // `postcard` does not allocate.
+        let mut buf = [0_u8; 64];
+        let bytes = postcard::to_slice(&Runtime::from(111.999), &mut buf).unwrap();
+
+        let this: Runtime = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, 111.999);
+        assert_eq!(this, "1:51");
+
+        // Unknown.
+        let bytes = postcard::to_slice(&Runtime::UNKNOWN, &mut buf).unwrap();
+        assert!(postcard::from_bytes::<Runtime>(bytes).unwrap().is_unknown());
+    }
+
     #[test]
     #[cfg(feature = "bincode")]
     fn bincode() {
@@ -0,0 +1,307 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize,
+};
+use crate::run::{Runtime, RuntimeMilli, RuntimePad, RuntimeUnion, RuntimeUnits};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- RuntimeHours
+/// [`Runtime`] but always includes the hours
+///
+/// This is the exact same type as [`Runtime`], except, the
+/// hour field is _always_ shown, without being padded like [`RuntimePad`].
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(Runtime::MINUTE,      "1:00");     // hour left out
+/// assert_eq!(RuntimeHours::MINUTE, "0:01:00");  // hour shown
+/// assert_eq!(RuntimePad::MINUTE,   "00:01:00"); // hour shown and padded
+///
+/// assert_eq!(Runtime::HOUR,      "1:00:00");
+/// assert_eq!(RuntimeHours::HOUR, "1:00:00");
+/// assert_eq!(RuntimePad::HOUR,   "01:00:00");
+/// ```
+///
+/// ## Size
+/// [`Str<8>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(std::mem::size_of::<RuntimeHours>(), 16);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::run::*;
+/// // Always round down.
+/// assert_eq!(RuntimeHours::from(11.1111), "0:00:11");
+/// assert_eq!(RuntimeHours::from(11.9999), "0:00:11");
+///
+/// assert_eq!(RuntimeHours::from(111.111), "0:01:51");
+/// assert_eq!(RuntimeHours::from(111.999), "0:01:51");
+///
+/// assert_eq!(RuntimeHours::from(11111.1), "3:05:11");
+/// assert_eq!(RuntimeHours::from(11111.9), "3:05:11");
+///
+/// assert_eq!(RuntimeHours::from(0.0), "0:00:00");
+/// assert_eq!(RuntimeHours::from(1.0), "0:00:01");
+/// assert_eq!(RuntimeHours::from(1.9), "0:00:01");
+/// assert_eq!(RuntimeHours::from(2.0), "0:00:02");
+///
+/// assert_eq!(RuntimeHours::from(f32::NAN),      "?:??:??");
+/// assert_eq!(RuntimeHours::from(f64::INFINITY), "?:??:??");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct RuntimeHours(pub(super) f32, pub(super) Str<{ RuntimeHours::MAX_LEN }>);
+
+crate::run::runtime::impl_runtime! {
+    self  = RuntimeHours,
+    float = f32,
+
+    other = Runtime,
+    other = RuntimePad,
+    other = RuntimeMilli,
+    other = RuntimeUnits,
+}
+crate::run::runtime::impl_components! {
+    self  = RuntimeHours,
+    str   = as_str,
+    milli = 0,
+}
+impl_math!(RuntimeHours, f32);
+impl_traits!(RuntimeHours, f32);
+impl_from_option!(RuntimeHours);
+impl_float_eq!(RuntimeHours => f32);
+impl_std_from_str!(RuntimeHours => from_iso8601);
+
+//---------------------------------------------------------------------------------------------------- RuntimeHours Constants
+impl RuntimeHours {
+    /// The max length of [`RuntimeHours`]'s string.
+    pub const MAX_LEN: usize = 8;
+
+    /// [`f32`] inside of [`RuntimeHours::ZERO`]
+    pub const ZERO_F32: f32 = 0.0;
+
+    /// [`f32`] inside of [`RuntimeHours::SECOND`]
+    pub const SECOND_F32: f32 = 1.0;
+
+    /// [`f32`] inside of [`RuntimeHours::MINUTE`]
+    pub const MINUTE_F32: f32 = 60.0;
+
+    /// [`f32`] inside of [`RuntimeHours::HOUR`]
+    pub const HOUR_F32: f32 = 3600.0;
+
+    /// [`f32`] inside of [`RuntimeHours::DAY`]
+    pub const DAY_F32: f32 = 86400.0;
+
+    /// Input greater to [`RuntimeHours`] will make it return [`Self::MAX`]
+    pub const MAX_F32: f32 = 359999.0;
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::UNKNOWN, 0.0);
+    /// assert_eq!(RuntimeHours::UNKNOWN, "?:??:??");
+    /// ```
+    pub const UNKNOWN: Self = Self(Self::ZERO_F32, Str::from_static_str("?:??:??"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::ZERO, 0.0);
+    /// assert_eq!(RuntimeHours::ZERO, "0:00:00");
+    /// ```
+    pub const ZERO: Self = Self(Self::ZERO_F32, Str::from_static_str("0:00:00"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::SECOND, 1.0);
+    /// assert_eq!(RuntimeHours::SECOND, "0:00:01");
+    /// ```
+    pub const SECOND: Self = Self(Self::SECOND_F32, Str::from_static_str("0:00:01"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::MINUTE, 60.0);
+    /// assert_eq!(RuntimeHours::MINUTE, "0:01:00");
+    /// ```
+    pub const MINUTE: Self = Self(Self::MINUTE_F32, Str::from_static_str("0:01:00"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::HOUR, 3600.0);
+    /// assert_eq!(RuntimeHours::HOUR, "1:00:00");
+    /// ```
+    pub const HOUR: Self = Self(Self::HOUR_F32, Str::from_static_str("1:00:00"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::DAY, 86400.0);
+    /// assert_eq!(RuntimeHours::DAY, "24:00:00");
+    /// ```
+    pub const DAY: Self = Self(Self::DAY_F32, Str::from_static_str("24:00:00"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::MAX, 359999.0);
+    /// assert_eq!(RuntimeHours::MAX, "99:59:59");
+    /// ```
+    pub const MAX: Self = Self(Self::MAX_F32, Str::from_static_str("99:59:59"));
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl RuntimeHours {
+    impl_common!(f32);
+    impl_const!();
+    impl_format_slice!(run, RuntimeHours, [0_u32, 65, 3599, 86_399]);
+    impl_iso8601!(
+        RuntimeHours,
+        run,
+        run,
+        "5_411",
+        "PT1H30M11S",
+        "PT1H30M",
+        "5400.0"
+    );
+    impl_usize!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert!(RuntimeHours::UNKNOWN.is_unknown());
+    /// assert!(!RuntimeHours::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?:??:??")
+    }
+
+    #[must_use]
+    /// Create a [`RuntimeHours`] from an audio sample position
+    ///
+    /// See [`Runtime::from_samples`] for more details.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeHours::from_samples(44_100, 44_100),         "0:00:01");
+    /// assert_eq!(RuntimeHours::from_samples(158_760_000, 44_100),    "1:00:00");
+    /// assert_eq!(RuntimeHours::from_samples(15_875_955_901, 44_100), RuntimeHours::UNKNOWN);
+    /// assert_eq!(RuntimeHours::from_samples(1, 0),                   RuntimeHours::UNKNOWN);
+    /// ```
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let Some((inner, h, m, s, _)) = Runtime::priv_from_samples(samples, sample_rate) else {
+            return Self::UNKNOWN;
+        };
+
+        let mut buf = [0; Self::MAX_LEN];
+        let len = Runtime::format_hms(&mut buf, h, m, s);
+
+        // SAFETY: we know the str len
+        Self(inner as f32, unsafe { Str::from_raw(buf, len as u8) })
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+impl RuntimeHours {
+    #[allow(unreachable_code)]
+    // Private function used in float `From`.
+    //
+    // INVARIANT:
+    // `handle_float!()` should be
+    // called before this function.
+    pub(super) fn priv_from(runtime: f32) -> Self {
+        let Some((h, m, s)) = Runtime::priv_from_inner(runtime) else {
+            return Self::UNKNOWN;
+        };
+
+        if (h, m, s) == (0.0, 0.0, 0.0) {
+            return Self::ZERO;
+        }
+
+        let mut buf = [0; Self::MAX_LEN];
+        let len = Runtime::format_hms(&mut buf, h as u8, m as u8, s as u8);
+
+        // SAFETY: we know the str len
+        Self(runtime, unsafe { Str::from_raw(buf, len as u8) })
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `RuntimeUnion`
+impl From<RuntimeUnion> for RuntimeHours {
+    #[inline]
+    fn from(runtime: RuntimeUnion) -> Self {
+        Self::priv_from(runtime.inner())
+    }
+}
+
+impl From<&RuntimeUnion> for RuntimeHours {
+    #[inline]
+    fn from(runtime: &RuntimeUnion) -> Self {
+        Self::priv_from(runtime.inner())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_uint() {
+        for i in 0..RuntimeHours::MAX_F32 as u32 {
+            let rt = RuntimeHours::from(i);
+            assert_eq!(rt.inner() as u32, i);
+            let pad = RuntimePad::from(i);
+            assert_eq!(rt.as_str(), pad.strip_prefix('0').unwrap_or(&pad));
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            RuntimeHours::from(RuntimeHours::MAX_F32 + 1.0),
+            RuntimeHours::UNKNOWN
+        );
+        assert_eq!(RuntimeHours::from(-1), RuntimeHours::UNKNOWN);
+    }
+
+    #[test]
+    fn special() {
+        assert_eq!(RuntimeHours::from(f32::NAN), RuntimeHours::UNKNOWN);
+        assert_eq!(RuntimeHours::from(f32::INFINITY), RuntimeHours::UNKNOWN);
+        assert_eq!(RuntimeHours::from(f64::NEG_INFINITY), RuntimeHours::UNKNOWN);
+    }
+
+    #[test]
+    fn from_runtime() {
+        let runtime = RuntimeMilli::from(61.5);
+        let hours = RuntimeHours::from(runtime);
+        assert_eq!(hours, "0:01:01");
+        assert_eq!(RuntimeMilli::from(hours), "00:01:01.500");
+        assert_eq!(RuntimeHours::from(RuntimeUnion::from(61.5)), hours);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: RuntimeHours = RuntimeHours::from(111.999);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[111.999,"0:01:51"]"#);
+
+        let this: RuntimeHours = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 111.999);
+        assert_eq!(this, "0:01:51");
+
+        // Unknown.
+        let json = serde_json::to_string(&RuntimeHours::UNKNOWN).unwrap();
+        assert_eq!(json, r#"[0.0,"?:??:??"]"#);
+        assert!(serde_json::from_str::<RuntimeHours>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+}
@@ -1,13 +1,16 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_math, impl_traits};
-use crate::run::{Runtime, RuntimePad, RuntimeUnion};
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits,
+};
+use crate::run::{Runtime, RuntimeHours, RuntimePad, RuntimeUnion, RuntimeUnits};
 use crate::str::Str;
 
 //---------------------------------------------------------------------------------------------------- RuntimeMilli
 /// [`RuntimePad`] but with milliseconds
 ///
 /// This is the exact same type as [`RuntimePad`], except, the
-/// milliseconds are included, which makes this type `4` bytes bigger.
+/// milliseconds are included, which makes this type `8` bytes bigger (it also stores an [`f64`] instead of an [`f32`]).
 ///
 /// ```rust
 /// # use readable::run::*;
@@ -23,7 +26,7 @@ use crate::str::Str;
 ///
 /// ```rust
 /// # use readable::run::*;
-/// assert_eq!(std::mem::size_of::<RuntimeMilli>(), 20);
+/// assert_eq!(std::mem::size_of::<RuntimeMilli>(), 24);
 /// ```
 ///
 /// ## Examples
@@ -53,91 +56,102 @@ use crate::str::Str;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
-pub struct RuntimeMilli(pub(super) f32, pub(super) Str<{ RuntimeMilli::MAX_LEN }>);
+#[derive(Copy, Clone)]
+pub struct RuntimeMilli(pub(super) f64, pub(super) Str<{ RuntimeMilli::MAX_LEN }>);
 
 crate::run::runtime::impl_runtime! {
     self  = RuntimeMilli,
+    float = f64,
     len   = RuntimeMilli::MAX_LEN,
     union = as_str_milli,
 
     other = Runtime,
     other = RuntimePad,
+    other = RuntimeHours,
+    other = RuntimeUnits,
 }
-impl_math!(RuntimeMilli, f32);
-impl_traits!(RuntimeMilli, f32);
+crate::run::runtime::impl_components! {
+    self  = RuntimeMilli,
+    str   = as_str,
+    milli = 500,
+}
+impl_math!(RuntimeMilli, f64);
+impl_traits!(RuntimeMilli, f64);
+impl_from_option!(RuntimeMilli);
+impl_float_eq!(RuntimeMilli => f64);
+impl_std_from_str!(RuntimeMilli => from_iso8601);
 
 //---------------------------------------------------------------------------------------------------- RuntimeMilli Constants
 impl RuntimeMilli {
     /// The max length of [`RuntimeMilli`]'s string.
     pub const MAX_LEN: usize = 12;
 
-    /// [`f32`] inside of [`RuntimeMilli::ZERO`]
-    pub const ZERO_F32: f32 = 0.0;
+    /// [`f64`] inside of [`RuntimeMilli::ZERO`]
+    pub const ZERO_F64: f64 = 0.0;
 
-    /// [`f32`] inside of [`RuntimeMilli::SECOND`]
-    pub const SECOND_F32: f32 = 1.0;
+    /// [`f64`] inside of [`RuntimeMilli::SECOND`]
+    pub const SECOND_F64: f64 = 1.0;
 
-    /// [`f32`] inside of [`RuntimeMilli::MINUTE`]
-    pub const MINUTE_F32: f32 = 60.0;
+    /// [`f64`] inside of [`RuntimeMilli::MINUTE`]
+    pub const MINUTE_F64: f64 = 60.0;
 
-    /// [`f32`] inside of [`RuntimeMilli::HOUR`]
-    pub const HOUR_F32: f32 = 3600.0;
+    /// [`f64`] inside of [`RuntimeMilli::HOUR`]
+    pub const HOUR_F64: f64 = 3600.0;
 
-    /// [`f32`] inside of [`RuntimeMilli::DAY`]
-    pub const DAY_F32: f32 = 86400.0;
+    /// [`f64`] inside of [`RuntimeMilli::DAY`]
+    pub const DAY_F64: f64 = 86400.0;
 
     /// Input greater to [`RuntimeMilli`] will make it return [`Self::MAX`]
-    pub const MAX_F32: f32 = 359999.0;
+    pub const MAX_F64: f64 = 359999.0;
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::UNKNOWN, 0.0);
     /// assert_eq!(RuntimeMilli::UNKNOWN, "??:??:??.???");
     /// ```
-    pub const UNKNOWN: Self = Self(Self::ZERO_F32, Str::from_static_str("??:??:??.???"));
+    pub const UNKNOWN: Self = Self(Self::ZERO_F64, Str::from_static_str("??:??:??.???"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::ZERO, 0.0);
     /// assert_eq!(RuntimeMilli::ZERO, "00:00:00.000");
     /// ```
-    pub const ZERO: Self = Self(Self::ZERO_F32, Str::from_static_str("00:00:00.000"));
+    pub const ZERO: Self = Self(Self::ZERO_F64, Str::from_static_str("00:00:00.000"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::SECOND, 1.0);
     /// assert_eq!(RuntimeMilli::SECOND, "00:00:01.000");
     /// ```
-    pub const SECOND: Self = Self(Self::SECOND_F32, Str::from_static_str("00:00:01.000"));
+    pub const SECOND: Self = Self(Self::SECOND_F64, Str::from_static_str("00:00:01.000"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::MINUTE, 60.0);
     /// assert_eq!(RuntimeMilli::MINUTE, "00:01:00.000");
     /// ```
-    pub const MINUTE: Self = Self(Self::MINUTE_F32, Str::from_static_str("00:01:00.000"));
+    pub const MINUTE: Self = Self(Self::MINUTE_F64, Str::from_static_str("00:01:00.000"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::HOUR, 3600.0);
     /// assert_eq!(RuntimeMilli::HOUR, "01:00:00.000");
     /// ```
-    pub const HOUR: Self = Self(Self::HOUR_F32, Str::from_static_str("01:00:00.000"));
+    pub const HOUR: Self = Self(Self::HOUR_F64, Str::from_static_str("01:00:00.000"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::DAY, 86400.0);
     /// assert_eq!(RuntimeMilli::DAY, "24:00:00.000");
     /// ```
-    pub const DAY: Self = Self(Self::DAY_F32, Str::from_static_str("24:00:00.000"));
+    pub const DAY: Self = Self(Self::DAY_F64, Str::from_static_str("24:00:00.000"));
 
     /// ```rust
     /// # use readable::run::*;
     /// assert_eq!(RuntimeMilli::MAX, 359999.0);
     /// assert_eq!(RuntimeMilli::MAX, "99:59:59.000");
     /// ```
-    pub const MAX: Self = Self(Self::MAX_F32, Str::from_static_str("99:59:59.000"));
+    pub const MAX: Self = Self(Self::MAX_F64, Str::from_static_str("99:59:59.000"));
 }
 
 //---------------------------------------------------------------------------------------------------- Impl
@@ -175,8 +189,18 @@ pub(super) use impl_as_str_runtime_inner;
 
 //---------------------------------------------------------------------------------------------------- Impl
 impl RuntimeMilli {
-    impl_common!(f32);
+    impl_common!(f64);
     impl_const!();
+    impl_format_slice!(run, RuntimeMilli, [0_u32, 65, 3599, 86_399]);
+    impl_iso8601!(
+        RuntimeMilli,
+        milli,
+        run,
+        "5_411.5",
+        "PT1H30M11.5S",
+        "PT1H30M0.25S",
+        "5400.25"
+    );
 
     #[inline]
     #[must_use]
@@ -240,6 +264,34 @@ impl RuntimeMilli {
     pub const fn is_unknown(&self) -> bool {
         matches!(self.1.as_bytes(), b"??:??:??.???")
     }
+
+    #[must_use]
+    /// Create a [`RuntimeMilli`] from an audio sample position
+    ///
+    /// See [`Runtime::from_samples`] for more details.
+    ///
+    /// The milliseconds are always rounded down.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeMilli::from_samples(44_100, 44_100),         "00:00:01.000");
+    /// assert_eq!(RuntimeMilli::from_samples(66_150, 44_100),         "00:00:01.500");
+    /// assert_eq!(RuntimeMilli::from_samples(44_099, 44_100),         "00:00:00.999");
+    /// assert_eq!(RuntimeMilli::from_samples(15_875_955_899, 44_100), "99:59:58.999");
+    /// assert_eq!(RuntimeMilli::from_samples(15_875_955_901, 44_100), RuntimeMilli::UNKNOWN);
+    /// assert_eq!(RuntimeMilli::from_samples(1, 0),                   RuntimeMilli::UNKNOWN);
+    /// ```
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let Some((inner, h, m, s, milli)) = Runtime::priv_from_samples(samples, sample_rate) else {
+            return Self::UNKNOWN;
+        };
+
+        let mut buf = [0; Self::MAX_LEN];
+        Self::format(&mut buf, h, m, s, milli);
+
+        // SAFETY: we know the str len
+        Self(inner, unsafe { Str::from_raw(buf, Self::MAX_LEN as u8) })
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
@@ -251,25 +303,33 @@ impl RuntimeMilli {
     // INVARIANT:
     // `handle_float!()` should be
     // called before this function.
-    pub(super) fn priv_from(runtime: f32) -> Self {
-        let Some((h, m, s)) = Runtime::priv_from_inner(runtime) else {
-            return Self::UNKNOWN;
-        };
-
-        if (h, m, s) == (0.0, 0.0, 0.0) {
+    pub(super) fn priv_from(runtime: f64) -> Self {
+        // Zero length.
+        if runtime <= 0.0 {
             return Self::ZERO;
         }
 
-        // println!("h: {}, m: {}, s: {}, mm: {}", h as u8, m as u8, s as u8, (1_000.0 * s.fract()).round() as u16);
+        // Return unknown if over max.
+        if runtime > Self::MAX_F64 {
+            return Self::UNKNOWN;
+        }
+
+        // Work in whole milliseconds so that
+        // the float error never reaches the string.
+        let total = (runtime * 1000.0).round() as u32;
+        let hours = total / 3_600_000;
+        let minutes = total / 60_000 % 60;
+        let seconds = total / 1000 % 60;
+        let milli = total % 1000;
 
         // Format.
         let mut buf = [0; Self::MAX_LEN];
         Self::format(
             &mut buf,
-            h as u8,
-            m as u8,
-            s as u8,
-            (1000.0 * s.fract()).round() as u16,
+            hours as u8,
+            minutes as u8,
+            seconds as u8,
+            milli as u16,
         );
 
         // SAFETY: we know the str len
@@ -408,7 +468,7 @@ mod tests {
 
     #[test]
     fn all_uint() {
-        for i in 0..RuntimeMilli::MAX_F32 as u32 {
+        for i in 0..RuntimeMilli::MAX_F64 as u32 {
             let rt = RuntimeMilli::from(i);
             println!("rt: {rt} - i: {i}");
             assert_eq!(rt.inner() as u32, i);
@@ -420,7 +480,7 @@ mod tests {
     #[test]
     fn all_floats() {
         let mut f = 1.0;
-        while f < RuntimeMilli::MAX_F32 {
+        while f < RuntimeMilli::MAX_F64 {
             let rt = RuntimeMilli::from(f);
             println!("rt: {rt} - f: {f}");
             assert_eq!(rt, f);
@@ -428,20 +488,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn precision() {
+        assert_eq!(RuntimeMilli::from(2.0) + 1.555, "00:00:03.555");
+        assert_eq!(RuntimeMilli::from(11.9996), "00:00:12.000");
+        assert_eq!(RuntimeMilli::from(359_998.999), "99:59:58.999");
+
+        // Lossless round-trip through the `f32` types.
+        for f in [0.001, 1.555, 3599.999, 86399.5] {
+            let milli = RuntimeMilli::from(f);
+            assert_eq!(RuntimeMilli::from(Runtime::from(milli)).inner(), f);
+            assert_eq!(RuntimeMilli::from(RuntimePad::from(milli)).inner(), f);
+            assert_eq!(RuntimeMilli::from(RuntimeUnion::from(milli)).inner(), f);
+        }
+    }
+
     #[test]
     fn overflow_float() {
-        assert_eq!(RuntimeMilli::from(RuntimeMilli::MAX_F32 + 1.0), 0.0);
+        assert_eq!(RuntimeMilli::from(RuntimeMilli::MAX_F64 + 1.0), 0.0);
         assert_eq!(
-            RuntimeMilli::from(RuntimeMilli::MAX_F32 + 1.0),
+            RuntimeMilli::from(RuntimeMilli::MAX_F64 + 1.0),
             RuntimeMilli::UNKNOWN
         );
     }
 
     #[test]
     fn overflow_uint() {
-        assert_eq!(RuntimeMilli::from(RuntimeMilli::MAX_F32 + 1.0), 0.0);
+        assert_eq!(RuntimeMilli::from(RuntimeMilli::MAX_F64 + 1.0), 0.0);
         assert_eq!(
-            RuntimeMilli::from(RuntimeMilli::MAX_F32 + 1.0),
+            RuntimeMilli::from(RuntimeMilli::MAX_F64 + 1.0),
             RuntimeMilli::UNKNOWN
         );
     }
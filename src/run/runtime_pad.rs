@@ -1,6 +1,9 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize};
-use crate::run::{Runtime, RuntimeMilli, RuntimeUnion};
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize,
+};
+use crate::run::{Runtime, RuntimeHours, RuntimeMilli, RuntimeUnion, RuntimeUnits};
 use crate::str::Str;
 
 //---------------------------------------------------------------------------------------------------- RuntimePad
@@ -59,19 +62,30 @@ use crate::str::Str;
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone)]
 pub struct RuntimePad(pub(super) f32, pub(super) Str<{ RuntimePad::MAX_LEN }>);
 
 crate::run::runtime::impl_runtime! {
     self  = RuntimePad,
+    float = f32,
     len   = RuntimeMilli::MAX_LEN,
     union = as_str_pad,
 
     other = Runtime,
     other = RuntimeMilli,
+    other = RuntimeHours,
+    other = RuntimeUnits,
+}
+crate::run::runtime::impl_components! {
+    self  = RuntimePad,
+    str   = as_str,
+    milli = 0,
 }
 impl_math!(RuntimePad, f32);
 impl_traits!(RuntimePad, f32);
+impl_from_option!(RuntimePad);
+impl_float_eq!(RuntimePad => f32);
+impl_std_from_str!(RuntimePad => from_iso8601);
 
 //---------------------------------------------------------------------------------------------------- RuntimePad Constants
 impl RuntimePad {
@@ -150,6 +164,16 @@ impl RuntimePad {
 impl RuntimePad {
     impl_common!(f32);
     impl_const!();
+    impl_format_slice!(run, RuntimePad, [0_u32, 65, 3599, 86_399]);
+    impl_iso8601!(
+        RuntimePad,
+        run,
+        run,
+        "5_411",
+        "PT1H30M11S",
+        "PT1H30M",
+        "5400.0"
+    );
     impl_usize!();
 
     #[inline]
@@ -184,6 +208,32 @@ impl RuntimePad {
     pub const fn is_unknown(&self) -> bool {
         matches!(self.1.as_bytes(), b"??:??:??")
     }
+
+    #[must_use]
+    /// Create a [`RuntimePad`] from an audio sample position
+    ///
+    /// See [`Runtime::from_samples`] for more details.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimePad::from_samples(44_100, 44_100),         "00:00:01");
+    /// assert_eq!(RuntimePad::from_samples(158_760_000, 44_100),    "01:00:00");
+    /// assert_eq!(RuntimePad::from_samples(15_875_955_901, 44_100), RuntimePad::UNKNOWN);
+    /// assert_eq!(RuntimePad::from_samples(1, 0),                   RuntimePad::UNKNOWN);
+    /// ```
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let Some((inner, h, m, s, _)) = Runtime::priv_from_samples(samples, sample_rate) else {
+            return Self::UNKNOWN;
+        };
+
+        let mut buf = [0; Self::MAX_LEN];
+        Self::format(&mut buf, h.into(), m.into(), s.into());
+
+        // SAFETY: we know the str len
+        Self(inner as f32, unsafe {
+            Str::from_raw(buf, Self::MAX_LEN as u8)
+        })
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
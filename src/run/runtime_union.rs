@@ -1,6 +1,7 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_impl_math, impl_math};
-use crate::run::{Runtime, RuntimeMilli, RuntimePad};
+use crate::macros::{impl_from_option, impl_impl_math, impl_math};
+use crate::run::runtime::CastFloat;
+use crate::run::{Runtime, RuntimeHours, RuntimeMilli, RuntimePad, RuntimeUnits};
 use crate::str::Str;
 
 //---------------------------------------------------------------------------------------------------- RuntimeUnion
@@ -77,12 +78,21 @@ pub struct RuntimeUnion {
 
 crate::run::runtime::impl_runtime! {
     self  = RuntimeUnion,
+    float = f64,
     other = Runtime,
     other = RuntimePad,
     other = RuntimeMilli,
+    other = RuntimeHours,
+    other = RuntimeUnits,
 }
 
+crate::run::runtime::impl_components! {
+    self  = RuntimeUnion,
+    str   = as_str_milli,
+    milli = 500,
+}
 impl_math!(RuntimeUnion, f32);
+impl_from_option!(RuntimeUnion);
 
 //---------------------------------------------------------------------------------------------------- RuntimeUnion Constants
 impl RuntimeUnion {
@@ -280,8 +290,8 @@ impl RuntimeUnion {
     /// # use readable::run::*;
     /// assert_eq!(RuntimeUnion::from(65.555).to_milli(), RuntimeMilli::from(65.555));
     /// ```
-    pub const fn to_milli(&self) -> RuntimeMilli {
-        RuntimeMilli(self.inner, self.milli)
+    pub fn to_milli(&self) -> RuntimeMilli {
+        RuntimeMilli(CastFloat::<f64>::cast(self.inner), self.milli)
     }
 
     #[inline]
@@ -296,11 +306,11 @@ impl RuntimeUnion {
     /// assert_eq!(p, RuntimePad::from(65.555));
     /// assert_eq!(m, RuntimeMilli::from(65.555));
     /// ```
-    pub const fn into_inner(self) -> (Runtime, RuntimePad, RuntimeMilli) {
+    pub fn into_inner(self) -> (Runtime, RuntimePad, RuntimeMilli) {
         (
             Runtime(self.inner, self.runtime),
             RuntimePad(self.inner, self.pad),
-            RuntimeMilli(self.inner, self.milli),
+            RuntimeMilli(CastFloat::<f64>::cast(self.inner), self.milli),
         )
     }
 
@@ -326,7 +336,12 @@ impl RuntimeUnion {
     #[allow(unreachable_code)]
     #[inline]
     // Private function used in inner `From`.
-    fn priv_from(inner: f32) -> Self {
+    //
+    // This takes an `f64` so that the `RuntimeMilli`
+    // string is the same as `RuntimeMilli::from(f64)`.
+    fn priv_from(float: f64) -> Self {
+        let inner = float as f32;
+
         let runtime = Runtime::priv_from(inner);
         if runtime == Runtime::UNKNOWN {
             return Self::UNKNOWN;
@@ -335,7 +350,7 @@ impl RuntimeUnion {
         // The above UNKNOWN check should preclude
         // the need for checking the below.
         let pad = RuntimePad::priv_from(inner).1;
-        let milli = RuntimeMilli::priv_from(inner).1;
+        let milli = RuntimeMilli::priv_from(float).1;
 
         Self {
             inner,
@@ -380,6 +395,24 @@ impl PartialEq<&RuntimeUnion> for f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn same_as_runtime_types() {
+        for f in [
+            0.0,
+            1.0005,
+            1.555,
+            65.555,
+            3599.9995,
+            86399.123_456,
+            359_998.999,
+        ] {
+            let union = RuntimeUnion::from(f);
+            assert_eq!(union.as_str(), Runtime::from(f).as_str());
+            assert_eq!(union.as_str_pad(), RuntimePad::from(f).as_str());
+            assert_eq!(union.as_str_milli(), RuntimeMilli::from(f).as_str());
+        }
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
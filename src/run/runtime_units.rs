@@ -0,0 +1,395 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::macros::{
+    impl_common, impl_const, impl_float_eq, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize,
+};
+use crate::run::{Runtime, RuntimeHours, RuntimeMilli, RuntimePad, RuntimeUnion};
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- RuntimeUnits
+/// [`Runtime`] but with units instead of colons, e.g. `1h23m45s`
+///
+/// This is the form YouTube's `t=` URL parameter uses.
+///
+/// Zero units are left out, except for zero itself, which is `0s`.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(Runtime::from(5025),      "1:23:45");
+/// assert_eq!(RuntimeUnits::from(5025), "1h23m45s");
+///
+/// assert_eq!(Runtime::HOUR,      "1:00:00");
+/// assert_eq!(RuntimeUnits::HOUR, "1h");
+/// ```
+///
+/// The string can be parsed back with [`RuntimeUnits::from_str`].
+///
+/// ## Size
+/// [`Str<9>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(std::mem::size_of::<RuntimeUnits>(), 16);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::run::*;
+/// // Always round down.
+/// assert_eq!(RuntimeUnits::from(11.1111), "11s");
+/// assert_eq!(RuntimeUnits::from(11.9999), "11s");
+///
+/// assert_eq!(RuntimeUnits::from(111.111), "1m51s");
+/// assert_eq!(RuntimeUnits::from(111.999), "1m51s");
+///
+/// assert_eq!(RuntimeUnits::from(11111.1), "3h5m11s");
+/// assert_eq!(RuntimeUnits::from(11111.9), "3h5m11s");
+///
+/// assert_eq!(RuntimeUnits::from(0.0),    "0s");
+/// assert_eq!(RuntimeUnits::from(0.5),    "0s");
+/// assert_eq!(RuntimeUnits::from(1.0),    "1s");
+/// assert_eq!(RuntimeUnits::from(3601.0), "1h1s");
+///
+/// assert_eq!(RuntimeUnits::from(f32::NAN),      "?h??m??s");
+/// assert_eq!(RuntimeUnits::from(f64::INFINITY), "?h??m??s");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct RuntimeUnits(pub(super) f32, pub(super) Str<{ RuntimeUnits::MAX_LEN }>);
+
+crate::run::runtime::impl_runtime! {
+    self  = RuntimeUnits,
+    float = f32,
+
+    other = Runtime,
+    other = RuntimePad,
+    other = RuntimeMilli,
+    other = RuntimeHours,
+}
+crate::run::runtime::impl_components! {
+    self  = RuntimeUnits,
+    str   = as_str,
+    milli = 0,
+}
+impl_math!(RuntimeUnits, f32);
+impl_traits!(RuntimeUnits, f32);
+impl_from_option!(RuntimeUnits);
+impl_float_eq!(RuntimeUnits => f32);
+impl_std_from_str!(RuntimeUnits => from_str);
+
+//---------------------------------------------------------------------------------------------------- RuntimeUnits Constants
+impl RuntimeUnits {
+    /// The max length of [`RuntimeUnits`]'s string.
+    pub const MAX_LEN: usize = 9;
+
+    /// [`f32`] inside of [`RuntimeUnits::ZERO`]
+    pub const ZERO_F32: f32 = 0.0;
+
+    /// [`f32`] inside of [`RuntimeUnits::SECOND`]
+    pub const SECOND_F32: f32 = 1.0;
+
+    /// [`f32`] inside of [`RuntimeUnits::MINUTE`]
+    pub const MINUTE_F32: f32 = 60.0;
+
+    /// [`f32`] inside of [`RuntimeUnits::HOUR`]
+    pub const HOUR_F32: f32 = 3600.0;
+
+    /// [`f32`] inside of [`RuntimeUnits::DAY`]
+    pub const DAY_F32: f32 = 86400.0;
+
+    /// Input greater to [`RuntimeUnits`] will make it return [`Self::MAX`]
+    pub const MAX_F32: f32 = 359999.0;
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::UNKNOWN, 0.0);
+    /// assert_eq!(RuntimeUnits::UNKNOWN, "?h??m??s");
+    /// ```
+    pub const UNKNOWN: Self = Self(Self::ZERO_F32, Str::from_static_str("?h??m??s"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::ZERO, 0.0);
+    /// assert_eq!(RuntimeUnits::ZERO, "0s");
+    /// ```
+    pub const ZERO: Self = Self(Self::ZERO_F32, Str::from_static_str("0s"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::SECOND, 1.0);
+    /// assert_eq!(RuntimeUnits::SECOND, "1s");
+    /// ```
+    pub const SECOND: Self = Self(Self::SECOND_F32, Str::from_static_str("1s"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::MINUTE, 60.0);
+    /// assert_eq!(RuntimeUnits::MINUTE, "1m");
+    /// ```
+    pub const MINUTE: Self = Self(Self::MINUTE_F32, Str::from_static_str("1m"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::HOUR, 3600.0);
+    /// assert_eq!(RuntimeUnits::HOUR, "1h");
+    /// ```
+    pub const HOUR: Self = Self(Self::HOUR_F32, Str::from_static_str("1h"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::DAY, 86400.0);
+    /// assert_eq!(RuntimeUnits::DAY, "24h");
+    /// ```
+    pub const DAY: Self = Self(Self::DAY_F32, Str::from_static_str("24h"));
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::MAX, 359999.0);
+    /// assert_eq!(RuntimeUnits::MAX, "99h59m59s");
+    /// ```
+    pub const MAX: Self = Self(Self::MAX_F32, Str::from_static_str("99h59m59s"));
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl RuntimeUnits {
+    impl_common!(f32);
+    impl_const!();
+    impl_format_slice!(run, RuntimeUnits, [0_u32, 65, 3599, 86_399]);
+    impl_iso8601!(
+        RuntimeUnits,
+        run,
+        run,
+        "5_411",
+        "PT1H30M11S",
+        "PT1H30M",
+        "5400.0"
+    );
+    impl_usize!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert!(RuntimeUnits::UNKNOWN.is_unknown());
+    /// assert!(!RuntimeUnits::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?h??m??s")
+    }
+
+    #[must_use]
+    /// Create a [`RuntimeUnits`] from an audio sample position
+    ///
+    /// See [`Runtime::from_samples`] for more details.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::from_samples(44_100, 44_100),         "1s");
+    /// assert_eq!(RuntimeUnits::from_samples(158_760_000, 44_100),    "1h");
+    /// assert_eq!(RuntimeUnits::from_samples(15_875_955_901, 44_100), RuntimeUnits::UNKNOWN);
+    /// assert_eq!(RuntimeUnits::from_samples(1, 0),                   RuntimeUnits::UNKNOWN);
+    /// ```
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let Some((inner, h, m, s, _)) = Runtime::priv_from_samples(samples, sample_rate) else {
+            return Self::UNKNOWN;
+        };
+
+        Self::format(inner as f32, h, m, s)
+    }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a runtime, e.g. `1h23m45s`
+    ///
+    /// This accepts the same input as [`Runtime::from_str`],
+    /// so [`RuntimeUnits`]'s own format, `H:MM:SS`, seconds and ISO 8601 all work.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::from_str("1h23m45s").unwrap(), 5025.0);
+    /// assert_eq!(RuntimeUnits::from_str("1:23:45").unwrap(),  "1h23m45s");
+    /// assert_eq!(RuntimeUnits::from_str("90").unwrap(),       "1m30s");
+    /// assert_eq!(RuntimeUnits::from_str("PT1H30M").unwrap(),  "1h30m");
+    ///
+    /// // Round-trip.
+    /// let units = RuntimeUnits::from(5025);
+    /// assert_eq!(RuntimeUnits::from_str(&units).unwrap(), units);
+    ///
+    /// // `FromStr` is implemented with this function.
+    /// let units: RuntimeUnits = "1h2m".parse().unwrap();
+    /// assert_eq!(units, 3720.0);
+    /// ```
+    ///
+    /// # Errors
+    /// If the string can't be parsed, or is over [`RuntimeUnits::MAX`],
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert!(RuntimeUnits::from_str("").is_err());
+    /// assert!(RuntimeUnits::from_str("23m1h").is_err());
+    /// assert!(RuntimeUnits::from_str("100h").is_err());
+    /// assert!(RuntimeUnits::from_str(&RuntimeUnits::UNKNOWN).is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        match Runtime::from_str(string) {
+            Ok(runtime) => Ok(Self::from(runtime)),
+            Err(_) => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`RuntimeUnits::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    #[must_use]
+    /// Format as an `ffmpeg` duration argument, e.g. `-ss 01:23:45`
+    ///
+    /// `ffmpeg` does not accept `h` or `m` units, only
+    /// `[HH:]MM:SS` or plain seconds, so this returns the
+    /// [`RuntimePad`] string, which every `ffmpeg` version accepts.
+    ///
+    /// [`Self::UNKNOWN`] returns [`RuntimePad::UNKNOWN`]'s string.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(RuntimeUnits::from(5025).to_ffmpeg_arg(), "01:23:45");
+    /// assert_eq!(RuntimeUnits::from(62).to_ffmpeg_arg(),   "00:01:02");
+    /// assert_eq!(RuntimeUnits::ZERO.to_ffmpeg_arg(),       "00:00:00");
+    ///
+    /// let args = ["-ss", &RuntimeUnits::from(62).to_ffmpeg_arg()].join(" ");
+    /// assert_eq!(args, "-ss 00:01:02");
+    /// ```
+    pub fn to_ffmpeg_arg(&self) -> Str<{ RuntimePad::MAX_LEN }> {
+        if self.is_unknown() {
+            RuntimePad::UNKNOWN.1
+        } else {
+            RuntimePad::priv_from(self.0).1
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+impl RuntimeUnits {
+    // Private function used in float `From`.
+    //
+    // INVARIANT:
+    // `handle_float!()` should be
+    // called before this function.
+    pub(super) fn priv_from(runtime: f32) -> Self {
+        let Some((h, m, s)) = Runtime::priv_from_inner(runtime) else {
+            return Self::UNKNOWN;
+        };
+
+        Self::format(runtime, h as u8, m as u8, s as u8)
+    }
+
+    #[inline]
+    // Format `1h23m45s`, leaving out zero units.
+    fn format(inner: f32, hour: u8, min: u8, sec: u8) -> Self {
+        debug_assert!(hour < 100);
+        debug_assert!(min < 60);
+        debug_assert!(sec < 60);
+
+        if (hour, min, sec) == (0, 0, 0) {
+            return Self(inner, Self::ZERO.1);
+        }
+
+        let mut string = Str::new();
+        for (unit, suffix) in [(hour, "h"), (min, "m"), (sec, "s")] {
+            if unit > 0 {
+                string.push_str_panic(itoa!(unit));
+                string.push_str_panic(suffix);
+            }
+        }
+
+        Self(inner, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From `RuntimeUnion`
+impl From<RuntimeUnion> for RuntimeUnits {
+    #[inline]
+    fn from(runtime: RuntimeUnion) -> Self {
+        Self::priv_from(runtime.inner())
+    }
+}
+
+impl From<&RuntimeUnion> for RuntimeUnits {
+    #[inline]
+    fn from(runtime: &RuntimeUnion) -> Self {
+        Self::priv_from(runtime.inner())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_uint() {
+        for i in 0..RuntimeUnits::MAX_F32 as u32 {
+            let rt = RuntimeUnits::from(i);
+            assert_eq!(rt.inner() as u32, i);
+            assert_eq!(RuntimeUnits::from_str(&rt).unwrap(), rt);
+            assert_eq!(rt.to_ffmpeg_arg().as_str(), RuntimePad::from(i).as_str());
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            RuntimeUnits::from(RuntimeUnits::MAX_F32 + 1.0),
+            RuntimeUnits::UNKNOWN
+        );
+        assert_eq!(RuntimeUnits::from(-1), RuntimeUnits::UNKNOWN);
+    }
+
+    #[test]
+    fn special() {
+        assert_eq!(RuntimeUnits::from(f32::NAN), RuntimeUnits::UNKNOWN);
+        assert_eq!(RuntimeUnits::from(f32::INFINITY), RuntimeUnits::UNKNOWN);
+        assert_eq!(RuntimeUnits::from(f64::NEG_INFINITY), RuntimeUnits::UNKNOWN);
+        assert_eq!(RuntimeUnits::UNKNOWN.to_ffmpeg_arg(), "??:??:??");
+    }
+
+    #[test]
+    fn from_runtime() {
+        let runtime = RuntimeMilli::from(61.5);
+        let units = RuntimeUnits::from(runtime);
+        assert_eq!(units, "1m1s");
+        assert_eq!(RuntimeMilli::from(units), "00:01:01.500");
+        assert_eq!(RuntimeUnits::from(RuntimeUnion::from(61.5)), units);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: RuntimeUnits = RuntimeUnits::from(111.999);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[111.999,"1m51s"]"#);
+
+        let this: RuntimeUnits = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 111.999);
+        assert_eq!(this, "1m51s");
+
+        // Unknown.
+        let json = serde_json::to_string(&RuntimeUnits::UNKNOWN).unwrap();
+        assert_eq!(json, r#"[0.0,"?h??m??s"]"#);
+        assert!(serde_json::from_str::<RuntimeUnits>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+}
@@ -0,0 +1,557 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::run::RuntimeMilli;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- Framerate
+/// Video framerates used by [`Timecode`]
+///
+/// The `NTSC` framerates (`23.976`, `29.97`, `59.94`) are the exact
+/// fractional rates, e.g. [`Framerate::Fps29_97`] is `30000/1001`.
+///
+/// The `Df` variants use drop-frame timecode labels.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(Framerate::Fps24.nominal(), 24);
+/// assert_eq!(Framerate::Fps24.fps(),     24.0);
+///
+/// assert_eq!(Framerate::Fps29_97Df.nominal(), 30);
+/// assert_eq!(Framerate::Fps29_97Df.fps(),     30000.0 / 1001.0);
+/// assert!(Framerate::Fps29_97Df.is_drop_frame());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Framerate {
+    /// `24000/1001` frames per second
+    Fps23_976,
+    /// `24` frames per second
+    Fps24,
+    /// `25` frames per second
+    Fps25,
+    /// `30000/1001` frames per second, non-drop-frame labels
+    Fps29_97,
+    /// `30000/1001` frames per second, drop-frame labels
+    Fps29_97Df,
+    /// `30` frames per second
+    Fps30,
+    /// `50` frames per second
+    Fps50,
+    /// `60000/1001` frames per second, non-drop-frame labels
+    Fps59_94,
+    /// `60000/1001` frames per second, drop-frame labels
+    Fps59_94Df,
+    /// `60` frames per second
+    Fps60,
+}
+
+impl Framerate {
+    #[inline]
+    #[must_use]
+    /// The nominal (rounded) framerate used for the `FF` field
+    pub const fn nominal(self) -> u8 {
+        match self {
+            Self::Fps23_976 | Self::Fps24 => 24,
+            Self::Fps25 => 25,
+            Self::Fps29_97 | Self::Fps29_97Df | Self::Fps30 => 30,
+            Self::Fps50 => 50,
+            Self::Fps59_94 | Self::Fps59_94Df | Self::Fps60 => 60,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The exact amount of frames per second
+    pub fn fps(self) -> f64 {
+        let nominal = f64::from(self.nominal());
+        if self.is_ntsc() {
+            nominal * 1000.0 / 1001.0
+        } else {
+            nominal
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this is a fractional `NTSC` framerate
+    pub const fn is_ntsc(self) -> bool {
+        matches!(
+            self,
+            Self::Fps23_976 | Self::Fps29_97 | Self::Fps29_97Df | Self::Fps59_94 | Self::Fps59_94Df
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this framerate uses drop-frame labels
+    pub const fn is_drop_frame(self) -> bool {
+        matches!(self, Self::Fps29_97Df | Self::Fps59_94Df)
+    }
+
+    #[inline]
+    /// How many frame labels are dropped every minute (except every 10th minute)
+    const fn drop_count(self) -> u32 {
+        match self {
+            Self::Fps29_97Df => 2,
+            Self::Fps59_94Df => 4,
+            _ => 0,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Timecode
+/// SMPTE timecode in `HH:MM:SS:FF` format
+///
+/// This takes seconds (or a frame count) and a [`Framerate`] and
+/// formats the frame's timecode label.
+///
+/// Drop-frame framerates use a `;` before the frame field, i.e. `HH:MM:SS;FF`.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(Timecode::from_secs(1.5,  Framerate::Fps24), "00:00:01:12");
+/// assert_eq!(Timecode::from_secs(61.0, Framerate::Fps25), "00:01:01:00");
+///
+/// // Drop-frame skips the labels `00` and `01` at the start
+/// // of every minute, except every 10th minute.
+/// assert_eq!(Timecode::from_frames(1799, Framerate::Fps29_97Df), "00:00:59;29");
+/// assert_eq!(Timecode::from_frames(1800, Framerate::Fps29_97Df), "00:01:00;02");
+/// assert_eq!(Timecode::from_frames(17982, Framerate::Fps29_97Df), "00:10:00;00");
+///
+/// // Which keeps the timecode in sync with wall-clock time.
+/// assert_eq!(Timecode::from_secs(3600.0, Framerate::Fps29_97Df), "01:00:00;00");
+/// assert_eq!(Timecode::from_secs(3600.0, Framerate::Fps29_97),   "00:59:56:12");
+/// ```
+///
+/// ## Frames
+/// The input is always rounded down to the nearest whole frame.
+///
+/// ## Errors
+/// [`Timecode::UNKNOWN`] is returned if the input is
+/// negative, `NaN`, infinite, or past `23:59:59:FF`.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert!(Timecode::from_secs(-1.0, Framerate::Fps24).is_unknown());
+/// assert!(Timecode::from_secs(f64::NAN, Framerate::Fps24).is_unknown());
+/// assert!(Timecode::from_secs(86400.0, Framerate::Fps24).is_unknown());
+/// assert!(!Timecode::from_secs(86399.0, Framerate::Fps24).is_unknown());
+/// ```
+///
+/// ## [`RuntimeMilli`]
+/// [`Timecode`] can be converted from/to [`RuntimeMilli`].
+///
+/// ```rust
+/// # use readable::run::*;
+/// let milli = RuntimeMilli::from(90.5);
+///
+/// let timecode = Timecode::from_milli(milli, Framerate::Fps30);
+/// assert_eq!(timecode, "00:01:30:15");
+///
+/// assert_eq!(timecode.to_milli(), milli);
+/// assert_eq!(RuntimeMilli::from(timecode), "00:01:30.500");
+/// ```
+///
+/// ## Size
+/// [`Str<11>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::run::*;
+/// assert_eq!(std::mem::size_of::<Timecode>(), 20);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timecode {
+    frames: u32,
+    framerate: Framerate,
+    string: Str<{ Timecode::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Timecode Constants
+impl Timecode {
+    /// The max length of [`Timecode`]'s string.
+    pub const MAX_LEN: usize = 11;
+
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::UNKNOWN, "??:??:??:??");
+    /// assert_eq!(Timecode::UNKNOWN.frames(), 0);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        frames: 0,
+        framerate: Framerate::Fps30,
+        string: Str::from_static_str("??:??:??:??"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Timecode Impl
+impl Timecode {
+    #[must_use]
+    /// Create a [`Timecode`] from seconds
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::from_secs(0.0,    Framerate::Fps60),     "00:00:00:00");
+    /// assert_eq!(Timecode::from_secs(0.99,   Framerate::Fps60),     "00:00:00:59");
+    /// assert_eq!(Timecode::from_secs(3599.0, Framerate::Fps23_976), "00:59:55:09");
+    /// ```
+    pub fn from_secs(secs: f64, framerate: Framerate) -> Self {
+        if !secs.is_finite() || secs < 0.0 {
+            return Self::UNKNOWN;
+        }
+
+        let frames = (secs * framerate.fps()).floor();
+        if frames > f64::from(u32::MAX) {
+            return Self::UNKNOWN;
+        }
+
+        Self::from_frames(frames as u32, framerate)
+    }
+
+    #[must_use]
+    /// Create a [`Timecode`] from a frame count
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::from_frames(0,    Framerate::Fps24),      "00:00:00:00");
+    /// assert_eq!(Timecode::from_frames(24,   Framerate::Fps24),      "00:00:01:00");
+    /// assert_eq!(Timecode::from_frames(3600, Framerate::Fps59_94Df), "00:01:00;04");
+    /// ```
+    pub fn from_frames(frames: u32, framerate: Framerate) -> Self {
+        let nominal = u32::from(framerate.nominal());
+        let drop = framerate.drop_count();
+
+        // Add the dropped labels back in, then
+        // format as a regular non-drop-frame label.
+        let label = if drop == 0 {
+            u64::from(frames)
+        } else {
+            let per_10_min = nominal * 600 - drop * 9;
+            let per_min = nominal * 60 - drop;
+            let tens = frames / per_10_min;
+            let rem = frames % per_10_min;
+            let mut label = u64::from(frames) + u64::from(drop * 9 * tens);
+            if rem > drop {
+                label += u64::from(drop * ((rem - drop) / per_min));
+            }
+            label
+        };
+
+        let nominal = u64::from(nominal);
+        let hour = label / (nominal * 3600);
+        if hour >= 24 {
+            return Self::UNKNOWN;
+        }
+        let min = label / (nominal * 60) % 60;
+        let sec = label / nominal % 60;
+        let frame = label % nominal;
+
+        let sep = if framerate.is_drop_frame() {
+            b';'
+        } else {
+            b':'
+        };
+        let mut buf = [b':'; Self::MAX_LEN];
+        Self::format_2(&mut buf, 0, hour);
+        Self::format_2(&mut buf, 3, min);
+        Self::format_2(&mut buf, 6, sec);
+        buf[8] = sep;
+        Self::format_2(&mut buf, 9, frame);
+
+        Self {
+            frames,
+            framerate,
+            // SAFETY: we know the str len
+            string: unsafe { Str::from_raw(buf, Self::MAX_LEN as u8) },
+        }
+    }
+
+    #[must_use]
+    /// Create a [`Timecode`] from a [`RuntimeMilli`]
+    ///
+    /// If the [`RuntimeMilli`] is unknown, [`Timecode::UNKNOWN`] is returned.
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// let milli = RuntimeMilli::from(1.5);
+    /// assert_eq!(Timecode::from_milli(milli, Framerate::Fps24), "00:00:01:12");
+    ///
+    /// assert!(Timecode::from_milli(RuntimeMilli::UNKNOWN, Framerate::Fps24).is_unknown());
+    /// ```
+    pub fn from_milli(milli: RuntimeMilli, framerate: Framerate) -> Self {
+        if milli.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_secs(milli.inner(), framerate)
+    }
+
+    #[must_use]
+    /// Creates a [`RuntimeMilli`] from the time of this [`Timecode`]'s frame
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// let timecode = Timecode::from_frames(36, Framerate::Fps24);
+    /// assert_eq!(timecode.to_milli(), "00:00:01.500");
+    ///
+    /// assert!(Timecode::UNKNOWN.to_milli().is_unknown());
+    /// ```
+    pub fn to_milli(&self) -> RuntimeMilli {
+        if self.is_unknown() {
+            return RuntimeMilli::UNKNOWN;
+        }
+        RuntimeMilli::from(self.secs())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the total frame count
+    pub const fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the [`Framerate`]
+    pub const fn framerate(&self) -> Framerate {
+        self.framerate
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the time in seconds of the frame
+    ///
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::from_frames(36, Framerate::Fps24).secs(), 1.5);
+    /// ```
+    pub fn secs(&self) -> f64 {
+        f64::from(self.frames) / self.framerate.fps()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::from_frames(36, Framerate::Fps24).as_str(), "00:00:01:12");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert_eq!(Timecode::from_frames(36, Framerate::Fps24).as_bytes(), b"00:00:01:12");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::run::*;
+    /// assert!(Timecode::UNKNOWN.is_unknown());
+    /// assert!(!Timecode::from_frames(0, Framerate::Fps24).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"??:??:??:??")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+impl Timecode {
+    #[inline]
+    // Format a 2 digit `00` number into `buf[idx..idx + 2]`.
+    fn format_2(buf: &mut [u8; Self::MAX_LEN], idx: usize, n: u64) {
+        debug_assert!(n < 100);
+        buf[idx] = b'0' + (n / 10) as u8;
+        buf[idx + 1] = b'0' + (n % 10) as u8;
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Trait impl
+impl From<Timecode> for RuntimeMilli {
+    #[inline]
+    fn from(timecode: Timecode) -> Self {
+        timecode.to_milli()
+    }
+}
+
+impl From<&Timecode> for RuntimeMilli {
+    #[inline]
+    fn from(timecode: &Timecode) -> Self {
+        timecode.to_milli()
+    }
+}
+
+impl std::ops::Deref for Timecode {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Timecode {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Timecode")
+            .field("inner", &(self.frames, self.framerate))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Timecode {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Timecode {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Timecode> for str {
+    #[inline]
+    fn eq(&self, other: &Timecode) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Timecode> for &str {
+    #[inline]
+    fn eq(&self, other: &Timecode) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Timecode::from_frames(25, Framerate::Fps25)),
+            r#"Timecode { inner: (25, Fps25), str: "00:00:01:00" }"#
+        );
+    }
+
+    #[test]
+    fn drop_frame() {
+        let f = Framerate::Fps29_97Df;
+        assert_eq!(Timecode::from_frames(1800 * 2 - 2, f), "00:02:00;02");
+        assert_eq!(Timecode::from_frames(17981, f), "00:09:59;29");
+        assert_eq!(Timecode::from_frames(17982, f), "00:10:00;00");
+        assert_eq!(Timecode::from_frames(17982 + 1800, f), "00:11:00;02");
+        assert_eq!(Timecode::from_frames(17982 * 144 - 1, f), "23:59:59;29");
+        assert!(Timecode::from_frames(17982 * 144, f).is_unknown());
+
+        let f = Framerate::Fps59_94Df;
+        assert_eq!(Timecode::from_frames(3599, f), "00:00:59;59");
+        assert_eq!(Timecode::from_frames(35964, f), "00:10:00;00");
+    }
+
+    #[test]
+    fn non_drop_frame() {
+        for f in [
+            Framerate::Fps23_976,
+            Framerate::Fps24,
+            Framerate::Fps25,
+            Framerate::Fps29_97,
+            Framerate::Fps30,
+            Framerate::Fps50,
+            Framerate::Fps59_94,
+            Framerate::Fps60,
+        ] {
+            let n = u32::from(f.nominal());
+            assert_eq!(
+                Timecode::from_frames(n - 1, f).as_bytes()[9..],
+                [b'0' + (n - 1) as u8 / 10, b'0' + (n - 1) as u8 % 10]
+            );
+            assert_eq!(
+                Timecode::from_frames(n * 86400 - 1, f).as_bytes()[..8],
+                *b"23:59:59"
+            );
+            assert!(Timecode::from_frames(n * 86400, f).is_unknown());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for frames in [0, 1, 29, 30, 1799, 1800, 17982, 100_000] {
+            let f = Framerate::Fps29_97Df;
+            let t = Timecode::from_frames(frames, f);
+            assert_eq!(Timecode::from_secs(t.secs() + 0.000_001, f), t);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Timecode::from_frames(1800, Framerate::Fps29_97Df);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(
+            json,
+            r#"{"frames":1800,"framerate":"Fps29_97Df","string":"00:01:00;02"}"#
+        );
+
+        let this: Timecode = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "00:01:00;02");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<Timecode>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&Timecode::UNKNOWN).unwrap();
+        assert!(serde_json::from_str::<Timecode>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this = Timecode::from_frames(1800, Framerate::Fps29_97Df);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: Timecode = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "00:01:00;02");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&Timecode::UNKNOWN).unwrap();
+        let this: Timecode = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
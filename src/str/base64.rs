@@ -0,0 +1,300 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+use crate::Error;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// The standard `base64` alphabet, `RFC 4648`.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Compile-time check that `M` bytes encode into `N` bytes.
+struct Fits<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> Fits<N, M> {
+    const OK: () = assert!(
+        Base64Str::<N>::encoded_len(M) <= N,
+        "base64 encoded length is longer than N"
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- Base64Str
+/// Stack-allocated `base64` encoding of bytes
+///
+/// This encodes small byte slices (keys, tokens, etc) into a [`Str<N>`]
+/// with the standard padded alphabet (`RFC 4648`).
+///
+/// `N` is the maximum string length, the same as [`Str<N>`],
+/// so at most `189` bytes can be encoded.
+///
+/// ```rust
+/// # use readable::str::*;
+/// let b64 = Base64Str::<8>::new(b"hello").unwrap();
+/// assert_eq!(b64, "aGVsbG8=");
+///
+/// // 32 byte key.
+/// let key = [0xff; 32];
+/// let b64 = Base64Str::<44>::from_array(&key);
+/// assert_eq!(b64, "//////////////////////////////////////////8=");
+/// ```
+///
+/// ## Errors
+/// If the encoded string does not fit in `N` bytes, [`Base64Str::new`] returns [`Error::TooLarge`].
+///
+/// ```rust
+/// # use readable::{Error, str::*};
+/// assert_eq!(Base64Str::<7>::new(b"hello"), Err(Error::TooLarge));
+/// ```
+///
+/// ## Compile-time check
+/// [`Base64Str::from_array`] checks the capacity at compile time instead.
+///
+/// ```rust,compile_fail
+/// # use readable::str::*;
+/// // 5 bytes need 8 characters, this won't compile.
+/// let b64 = Base64Str::<7>::from_array(b"hello");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Base64Str<const N: usize>(Str<N>);
+
+//---------------------------------------------------------------------------------------------------- Base64Str Impl
+impl<const N: usize> Base64Str<N> {
+    #[inline]
+    #[must_use]
+    /// The `base64` encoded length of `bytes` amount of bytes
+    ///
+    /// A [`Base64Str<N>`] needs an `N` at least this large.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(Base64Str::<0>::encoded_len(0), 0);
+    /// assert_eq!(Base64Str::<0>::encoded_len(1), 4);
+    /// assert_eq!(Base64Str::<0>::encoded_len(3), 4);
+    /// assert_eq!(Base64Str::<0>::encoded_len(32), 44);
+    /// ```
+    pub const fn encoded_len(bytes: usize) -> usize {
+        (bytes + 2) / 3 * 4
+    }
+
+    /// Encode `bytes`
+    ///
+    /// ## Errors
+    /// If the encoded string is longer than `N`
+    /// (see [`Base64Str::encoded_len`]), [`Error::TooLarge`] is returned.
+    pub const fn new(bytes: &[u8]) -> Result<Self, Error> {
+        if Self::encoded_len(bytes.len()) > N {
+            return Err(Error::TooLarge);
+        }
+        Ok(Self::encode(bytes))
+    }
+
+    #[must_use]
+    /// Encode a byte array, checking that it fits in `N` at compile time
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// const B64: Base64Str<4> = Base64Str::from_array(b"hi");
+    /// assert_eq!(B64, "aGk=");
+    /// ```
+    pub const fn from_array<const M: usize>(bytes: &[u8; M]) -> Self {
+        // Will cause panics at compile time.
+        #[allow(clippy::let_unit_value)]
+        let () = Fits::<N, M>::OK;
+        Self::encode(bytes)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// The length of the inner [`String`]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the inner [`String`] is empty, i.e. no bytes were encoded
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner [`Str<N>`]
+    pub const fn into_str(self) -> Str<N> {
+        self.0
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Base64Str impl (private)
+impl<const N: usize> Base64Str<N> {
+    // INVARIANT: the encoded length must fit in `N`.
+    const fn encode(bytes: &[u8]) -> Self {
+        // Will cause panics at compile time if `N > 255`.
+        let _: u8 = Str::<N>::CAPACITY;
+
+        let mut buf = [0; N];
+        let mut len = 0;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let b0 = bytes[i];
+            let b1 = if i + 1 < bytes.len() { bytes[i + 1] } else { 0 };
+            let b2 = if i + 2 < bytes.len() { bytes[i + 2] } else { 0 };
+
+            buf[len] = ALPHABET[(b0 >> 2) as usize];
+            buf[len + 1] = ALPHABET[(((b0 & 0b11) << 4) | (b1 >> 4)) as usize];
+            buf[len + 2] = if i + 1 < bytes.len() {
+                ALPHABET[(((b1 & 0b1111) << 2) | (b2 >> 6)) as usize]
+            } else {
+                b'='
+            };
+            buf[len + 3] = if i + 2 < bytes.len() {
+                ALPHABET[(b2 & 0b11_1111) as usize]
+            } else {
+                b'='
+            };
+
+            i += 3;
+            len += 4;
+        }
+
+        // SAFETY: the alphabet is ASCII, and the invariant covers the length.
+        Self(unsafe { Str::from_raw(buf, len as u8) })
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<const N: usize> TryFrom<&[u8]> for Base64Str<N> {
+    type Error = Error;
+
+    #[inline]
+    /// Same as [`Base64Str::new`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize> From<Base64Str<N>> for Str<N> {
+    #[inline]
+    fn from(b64: Base64Str<N>) -> Self {
+        b64.0
+    }
+}
+
+impl<const N: usize> PartialEq<str> for Base64Str<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Base64Str<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> std::fmt::Display for Base64Str<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const N: usize> std::ops::Deref for Base64Str<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for Base64Str<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Base64Str<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> std::borrow::Borrow<str> for Base64Str<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Default for Base64Str<N> {
+    #[inline]
+    /// Returns an empty [`Base64Str`]
+    fn default() -> Self {
+        Self(Str::new())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_4648() {
+        // https://datatracker.ietf.org/doc/html/rfc4648#section-10
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(Base64Str::<8>::new(input.as_bytes()).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn max() {
+        let bytes = [0; 189];
+        let b64 = Base64Str::<255>::new(&bytes).unwrap();
+        assert_eq!(b64.len(), 252);
+        assert_eq!(Base64Str::<255>::new(&[0; 190]), Err(Error::TooLarge));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Base64Str::<8>::new(b"hello").unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#""aGVsbG8=""#);
+
+        let this: Base64Str<8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "aGVsbG8=");
+    }
+}
@@ -0,0 +1,55 @@
+//---------------------------------------------------------------------------------------------------- Use
+#[allow(unused_imports)]
+use crate::str::Numerals;
+use crate::str::{Numeral, NumeralStr}; // docs
+
+//---------------------------------------------------------------------------------------------------- FullWidth
+impl<T: AsRef<str>> FullWidth for T {}
+
+/// Full-width digits (`１２３`) for CJK UIs
+///
+/// ASCII digits take half the width of CJK characters in
+/// a terminal, so columns of numbers next to CJK text don't line up.
+///
+/// This trait renders any string with its digits as full-width characters,
+/// everything else (`,`, `.`, `:`, units, etc) is left as-is.
+///
+/// This is the same as [`Numerals::numerals`] with [`Numeral::FullWidth`].
+///
+/// Anything that implements [`AsRef<str>`] can use this trait,
+/// so it works on any instance of the `num`, `run` and `time` types
+/// (and every other type), without changing the type itself.
+///
+/// ```rust
+/// use readable::str::FullWidth;
+/// # use readable::num::*;
+/// # use readable::run::*;
+/// # use readable::time::*;
+///
+/// assert_eq!(Unsigned::from(1234_u64).full_width(), "１,２３４");
+/// assert_eq!(Float::from(1234.5678).full_width(),   "１,２３４.５６８");
+/// assert_eq!(Percent::from(50.0).full_width(),      "５０.００%");
+/// assert_eq!(Runtime::from(61).full_width(),        "１:０１");
+/// assert_eq!(Time::from(86399).full_width(),        "１１:５９:５９ PM");
+///
+/// // It is `Display`, `to_string()` allocates the actual string.
+/// let runtime = Runtime::from(61).full_width().to_string();
+/// assert_eq!(runtime, "１:０１");
+/// assert_eq!(runtime.len(), 10);
+/// ```
+pub trait FullWidth: AsRef<str> {
+    #[inline]
+    /// Render the digits of this string as full-width characters
+    ///
+    /// This does not allocate, see [`NumeralStr`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!("1,000 KB".full_width(), "１,０００ KB");
+    /// assert_eq!("no digits".full_width(), "no digits");
+    /// assert_eq!("1,000 KB".full_width(), "1,000 KB".numerals(Numeral::FullWidth));
+    /// ```
+    fn full_width(&self) -> NumeralStr<'_> {
+        NumeralStr::new(self.as_ref(), Numeral::FullWidth)
+    }
+}
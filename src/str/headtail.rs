@@ -436,7 +436,7 @@ macro_rules! impl_string {
 			}
 			impl fmt::Display for $name<'_> {
 				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-					write!(f, "{}", self.string)
+					crate::str::pad(f, self.string)
 				}
 			}
 		)*
@@ -486,7 +486,7 @@ macro_rules! impl_cow {
 			}
 			impl fmt::Display for $name<'_> {
 				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-					write!(f, "{}", self.cow)
+					crate::str::pad(f, &self.cow)
 				}
 			}
 			impl AsRef<str> for $name<'_> {
@@ -585,8 +585,8 @@ pub struct HeadTailStr<'a> {
 impl fmt::Display for HeadTailStr<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.tail {
-            Some(t) => write!(f, "{}{t}", self.head),
-            None => write!(f, "{}", self.head),
+            Some(t) => crate::str::pad_parts(f, &[self.head, t]),
+            None => crate::str::pad(f, self.head),
         }
     }
 }
@@ -650,8 +650,8 @@ pub struct HeadTailDot<'a> {
 impl fmt::Display for HeadTailDot<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.tail {
-            Some(t) => write!(f, "{}{DOT}{t}", self.head),
-            None => write!(f, "{}", self.head),
+            Some(t) => crate::str::pad_parts(f, &[self.head, DOT, t]),
+            None => crate::str::pad(f, self.head),
         }
     }
 }
@@ -0,0 +1,368 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+use crate::Error;
+
+//---------------------------------------------------------------------------------------------------- HexFormat
+/// How [`HexStr`] formats bytes
+///
+/// ```rust
+/// # use readable::str::*;
+/// let bytes = [0xde, 0xad, 0xbe, 0xef];
+///
+/// let format = HexFormat {
+///     prefix: true,
+///     uppercase: true,
+///     separator: None,
+/// };
+/// assert_eq!(HexStr::<10>::with_format(&bytes, format).unwrap(), "0xDEADBEEF");
+///
+/// // Only change some fields.
+/// let format = HexFormat {
+///     separator: Some(' '),
+///     ..HexFormat::DEFAULT
+/// };
+/// assert_eq!(HexStr::<11>::with_format(&bytes, format).unwrap(), "de ad be ef");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+// `borsh` does not implement its traits for `char`.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct HexFormat {
+    /// Start with `0x`
+    pub prefix: bool,
+    /// Use `A-F` instead of `a-f`
+    pub uppercase: bool,
+    /// Inserted in-between each byte, e.g. `:` for `de:ad:be:ef`
+    pub separator: Option<char>,
+}
+
+impl HexFormat {
+    /// Lowercase, no prefix, no separator, e.g. `deadbeef`
+    ///
+    /// This is used by [`HexStr::new`].
+    pub const DEFAULT: Self = Self {
+        prefix: false,
+        uppercase: false,
+        separator: None,
+    };
+
+    /// Lowercase, `:` separated, e.g. `de:ad:be:ef:00:01`
+    pub const MAC: Self = Self {
+        prefix: false,
+        uppercase: false,
+        separator: Some(':'),
+    };
+
+    #[inline]
+    #[must_use]
+    /// The string length `bytes` amount of bytes will be formatted into
+    ///
+    /// A [`HexStr<N>`] needs an `N` at least this large.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(HexFormat::DEFAULT.len(4), 8);
+    /// assert_eq!(HexFormat::MAC.len(6), 17);
+    /// assert_eq!(HexFormat::MAC.len(0), 0);
+    /// ```
+    pub const fn len(self, bytes: usize) -> usize {
+        let prefix = if self.prefix { 2 } else { 0 };
+        let separator = match self.separator {
+            Some(c) => c.len_utf8() * bytes.saturating_sub(1),
+            None => 0,
+        };
+
+        prefix + (bytes * 2) + separator
+    }
+}
+
+impl Default for HexFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- HexStr
+/// Stack-allocated hex encoding of bytes
+///
+/// This formats bytes into a [`Str<N>`] as hex,
+/// with an optional `0x` prefix, uppercase, and separator, see [`HexFormat`].
+///
+/// `N` is the maximum string length, the same as [`Str<N>`].
+///
+/// ```rust
+/// # use readable::str::*;
+/// // Digests.
+/// let hex = HexStr::<8>::new(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+/// assert_eq!(hex, "deadbeef");
+///
+/// // MAC addresses.
+/// let mac = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+/// let hex = HexStr::<17>::with_format(&mac, HexFormat::MAC).unwrap();
+/// assert_eq!(hex, "00:1a:2b:3c:4d:5e");
+/// ```
+///
+/// ## Errors
+/// If the formatted string does not fit in `N` bytes, [`Error::TooLarge`] is returned.
+///
+/// ```rust
+/// # use readable::{Error, str::*};
+/// assert_eq!(HexStr::<7>::new(&[0xde, 0xad, 0xbe, 0xef]), Err(Error::TooLarge));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct HexStr<const N: usize>(Str<N>);
+
+//---------------------------------------------------------------------------------------------------- HexStr Impl
+impl<const N: usize> HexStr<N> {
+    #[inline]
+    /// Same as [`HexStr::with_format`] with [`HexFormat::DEFAULT`]
+    ///
+    /// ## Errors
+    /// Same as [`HexStr::with_format`].
+    pub fn new(bytes: &[u8]) -> Result<Self, Error> {
+        Self::with_format(bytes, HexFormat::DEFAULT)
+    }
+
+    /// Hex-encode `bytes` with `format`
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let format = HexFormat {
+    ///     prefix: true,
+    ///     uppercase: true,
+    ///     separator: Some('_'),
+    /// };
+    /// let hex = HexStr::<13>::with_format(&[0xde, 0xad, 0xbe, 0xef], format).unwrap();
+    /// assert_eq!(hex, "0xDE_AD_BE_EF");
+    ///
+    /// // Multi-byte separators work too.
+    /// let format = HexFormat {
+    ///     separator: Some('·'),
+    ///     ..HexFormat::DEFAULT
+    /// };
+    /// let hex = HexStr::<10>::with_format(&[0xde, 0xad, 0xbe], format).unwrap();
+    /// assert_eq!(hex, "de·ad·be");
+    /// ```
+    ///
+    /// ## Errors
+    /// If the formatted string is longer than `N`
+    /// (see [`HexFormat::len`]), [`Error::TooLarge`] is returned.
+    pub fn with_format(bytes: &[u8], format: HexFormat) -> Result<Self, Error> {
+        if format.len(bytes.len()) > N {
+            return Err(Error::TooLarge);
+        }
+
+        let digits = if format.uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+
+        let mut string = Str::new();
+        if format.prefix {
+            string.push_str_panic("0x");
+        }
+        for (i, b) in bytes.iter().enumerate() {
+            if let (Some(c), true) = (format.separator, i != 0) {
+                string.push_char_panic(c);
+            }
+            string.push_char_panic(char::from(digits[usize::from(b >> 4)]));
+            string.push_char_panic(char::from(digits[usize::from(b & 0xF)]));
+        }
+
+        Ok(Self(string))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return a borrowed [`str`] without consuming [`Self`].
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ byte slice of the inner [`String`]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// The length of the inner [`String`]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the inner [`String`] is empty, i.e. no bytes (and no prefix) were formatted
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner [`Str<N>`]
+    pub const fn into_str(self) -> Str<N> {
+        self.0
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<const N: usize> TryFrom<&[u8]> for HexStr<N> {
+    type Error = Error;
+
+    #[inline]
+    /// Same as [`HexStr::new`].
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize, const ARRAY: usize> TryFrom<&[u8; ARRAY]> for HexStr<N> {
+    type Error = Error;
+
+    #[inline]
+    /// Same as [`HexStr::new`].
+    fn try_from(bytes: &[u8; ARRAY]) -> Result<Self, Error> {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize> From<HexStr<N>> for Str<N> {
+    #[inline]
+    fn from(hex: HexStr<N>) -> Self {
+        hex.0
+    }
+}
+
+impl<const N: usize> PartialEq<str> for HexStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for HexStr<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> std::fmt::Display for HexStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const N: usize> std::ops::Deref for HexStr<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for HexStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for HexStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> std::borrow::Borrow<str> for HexStr<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Default for HexStr<N> {
+    #[inline]
+    /// Returns an empty [`HexStr`]
+    fn default() -> Self {
+        Self(Str::new())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_bytes() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        for b in &bytes {
+            let lower = HexStr::<2>::new(&[*b]).unwrap();
+            assert_eq!(lower.as_str(), format!("{b:02x}"));
+
+            let format = HexFormat {
+                prefix: true,
+                uppercase: true,
+                separator: None,
+            };
+            let upper = HexStr::<4>::with_format(&[*b], format).unwrap();
+            assert_eq!(upper.as_str(), format!("{b:#04X}").replace("0X", "0x"));
+        }
+    }
+
+    #[test]
+    fn len() {
+        let bytes = [0xAB; 20];
+        for prefix in [false, true] {
+            for separator in [None, Some(':'), Some('·'), Some('🦀')] {
+                let format = HexFormat {
+                    prefix,
+                    uppercase: false,
+                    separator,
+                };
+                for n in 0..=bytes.len() {
+                    let hex = HexStr::<255>::with_format(&bytes[..n], format).unwrap();
+                    assert_eq!(hex.len(), format.len(n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(HexStr::<0>::new(&[]).unwrap().is_empty());
+        assert_eq!(HexStr::<0>::default(), "");
+        let format = HexFormat {
+            prefix: true,
+            ..HexFormat::DEFAULT
+        };
+        assert_eq!(HexStr::<2>::with_format(&[], format).unwrap(), "0x");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = HexStr::<8>::new(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#""deadbeef""#);
+
+        let this: HexStr<8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "deadbeef");
+    }
+}
@@ -3,10 +3,41 @@
 //! This module contain [`Str`], the stack-based that
 //! backs almost all string types in `readable`.
 //!
+//! [`SmartStr`] is a [`Str`] that spills onto the heap
+//! when the string no longer fits in its stack buffer.
+//!
 //! It also contains some general string utilities.
 
 mod str;
 pub use self::str::Str;
 
+mod smart;
+pub use smart::SmartStr;
+
+mod pad;
+pub(crate) use pad::{pad, pad_iter, pad_parts};
+
 mod headtail;
 pub use headtail::{Head, HeadDot, HeadTail, HeadTailDot, HeadTailStr, Tail, TailDot, DOT};
+
+mod numeral;
+pub use numeral::{Numeral, NumeralStr, Numerals};
+
+mod full_width;
+pub use full_width::FullWidth;
+
+#[deprecated(note = "renamed to `NumeralStr`, returned by `FullWidth::full_width()`")]
+/// Struct returned from [`FullWidth::full_width()`], now [`NumeralStr`]
+pub type FullWidthStr<'a> = NumeralStr<'a>;
+
+mod hex;
+pub use hex::{HexFormat, HexStr};
+
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "base64")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base64")))]
+pub use base64::Base64Str;
+
+mod shorthash;
+pub use shorthash::ShortHash;
@@ -0,0 +1,381 @@
+//---------------------------------------------------------------------------------------------------- Use
+use std::fmt;
+
+//---------------------------------------------------------------------------------------------------- Numeral
+/// A numeral system, the digits used when rendering with [`Numerals`]
+///
+/// All types format with ASCII digits, [`Numerals`] swaps
+/// them for one of these when rendering, per instance.
+///
+/// Only the digits are swapped, separators (`,`, `.`, `:`) and units are left as-is,
+/// e.g. [`Numeral::ArabicIndic`] keeps the ASCII `.` and `,` instead of
+/// the Arabic decimal (`٫`) and thousands (`٬`) separators.
+///
+/// | Numeral                  | Digits       | Bytes per digit |
+/// |--------------------------|--------------|-----------------|
+/// | [`Numeral::Ascii`]       | `0123456789` | `1`
+/// | [`Numeral::FullWidth`]   | `０１２３４５６７８９` | `3`
+/// | [`Numeral::ArabicIndic`] | `٠١٢٣٤٥٦٧٨٩` | `2`
+/// | [`Numeral::Devanagari`]  | `०१२३४५६७८९` | `3`
+///
+/// ```rust
+/// # use readable::str::*;
+/// assert_eq!(Numeral::ArabicIndic.digits()[1], "١");
+/// assert_eq!(Numeral::Devanagari.digits()[1], "१");
+/// assert_eq!(Numeral::default(), Numeral::Ascii);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Numeral {
+    #[default]
+    /// ASCII digits, `0123456789`, the same as the original string
+    Ascii,
+    /// Full-width digits, `０１２３４５６７８９`, for alignment in CJK UIs
+    FullWidth,
+    /// Arabic-Indic digits, `٠١٢٣٤٥٦٧٨٩`
+    ///
+    /// The separators stay ASCII, `1,234.5` is `١,٢٣٤.٥` not `١٬٢٣٤٫٥`.
+    ArabicIndic,
+    /// Devanagari digits, `०१२३४५६७८९`
+    Devanagari,
+}
+
+impl Numeral {
+    /// All the numeral systems
+    pub const ALL: [Self; 4] = [
+        Self::Ascii,
+        Self::FullWidth,
+        Self::ArabicIndic,
+        Self::Devanagari,
+    ];
+
+    #[inline]
+    #[must_use]
+    /// The digits `0` to `9` in this numeral system
+    pub const fn digits(self) -> &'static [&'static str; 10] {
+        match self {
+            Self::Ascii => &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+            Self::FullWidth => &["０", "１", "２", "３", "４", "５", "６", "７", "８", "９"],
+            Self::ArabicIndic => &["٠", "١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩"],
+            Self::Devanagari => &["०", "१", "२", "३", "४", "५", "६", "७", "८", "९"],
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The digit `d`, or [`None`] if `d > 9`
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(Numeral::FullWidth.digit(1), Some('１'));
+    /// assert_eq!(Numeral::FullWidth.digit(10), None);
+    /// ```
+    pub const fn digit(self, d: u8) -> Option<char> {
+        if d > 9 {
+            return None;
+        }
+        let zero = match self {
+            Self::Ascii => '0',
+            Self::FullWidth => '０',
+            Self::ArabicIndic => '٠',
+            Self::Devanagari => '०',
+        };
+        // All the digits are contiguous after `zero`.
+        char::from_u32(zero as u32 + d as u32)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Numerals
+impl<T: AsRef<str>> Numerals for T {}
+
+/// Render digits in another [`Numeral`] system
+///
+/// This trait renders any string with its ASCII digits swapped
+/// for another numeral system, e.g. full-width digits (`１２３`) so columns
+/// of numbers line up next to CJK text, or Arabic-Indic (`١٢٣`) and Devanagari (`१२३`).
+///
+/// Everything else (`,`, `.`, `:`, units, etc) is left as-is.
+///
+/// Anything that implements [`AsRef<str>`] can use this trait,
+/// so it works on any instance of the `num`, `run` and `time` types
+/// (and every other type), without changing the type itself.
+///
+/// ```rust
+/// use readable::str::{FullWidth, Numeral, Numerals};
+/// # use readable::num::*;
+/// # use readable::run::*;
+/// # use readable::time::*;
+///
+/// assert_eq!(Unsigned::from(1234_u64).full_width(), "１,２３４");
+/// assert_eq!(Float::from(1234.5678).full_width(),   "１,２３４.５６８");
+/// assert_eq!(Runtime::from(61).full_width(),        "１:０１");
+///
+/// assert_eq!(Unsigned::from(1234_u64).numerals(Numeral::ArabicIndic), "١,٢٣٤");
+/// assert_eq!(Float::from_1(1234.5).numerals(Numeral::ArabicIndic),    "١,٢٣٤.٥");
+/// assert_eq!(Percent::from(50.0).numerals(Numeral::ArabicIndic),      "٥٠.٠٠%");
+/// assert_eq!(Time::from(86399).numerals(Numeral::Devanagari),         "११:५९:५९ PM");
+///
+/// // It is `Display`, `to_string()` allocates the actual string.
+/// let runtime = Runtime::from(61).full_width().to_string();
+/// assert_eq!(runtime, "１:０１");
+/// assert_eq!(runtime.len(), 10);
+/// ```
+pub trait Numerals: AsRef<str> {
+    #[inline]
+    /// Render the digits of this string in the [`Numeral`] system
+    ///
+    /// This does not allocate, see [`NumeralStr`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!("1,000 KB".numerals(Numeral::Devanagari), "१,००० KB");
+    /// assert_eq!("1,000 KB".numerals(Numeral::Ascii), "1,000 KB");
+    /// assert_eq!("no digits".numerals(Numeral::ArabicIndic), "no digits");
+    /// ```
+    fn numerals(&self, numeral: Numeral) -> NumeralStr<'_> {
+        NumeralStr {
+            string: self.as_ref(),
+            numeral,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- NumeralStr
+/// Struct returned from [`Numerals::numerals()`] and [`FullWidth::full_width()`](crate::str::FullWidth::full_width)
+///
+/// This struct:
+/// - Implements no-allocation [`PartialEq`] with [`str`]
+/// - Implements [`Display`](fmt::Display) and thus `.to_string()`
+/// - Respects width, fill and alignment, counted in `char`'s like [`str`]
+///
+/// ```rust
+/// # use readable::str::*;
+/// # use readable::run::*;
+/// let runtime = Runtime::from(61);
+/// assert_eq!(format!("[{:>6}]", runtime.full_width()), "[  １:０１]");
+/// assert_eq!(format!("[{:<6}]", runtime.full_width()), "[１:０１  ]");
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct NumeralStr<'a> {
+    string: &'a str,
+    numeral: Numeral,
+}
+
+impl<'a> NumeralStr<'a> {
+    #[inline]
+    /// Render `string` in the `numeral` system.
+    pub(super) const fn new(string: &'a str, numeral: Numeral) -> Self {
+        Self { string, numeral }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the original string, with ASCII digits
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!("1:01".full_width().as_original(), "1:01");
+    /// ```
+    pub const fn as_original(&self) -> &'a str {
+        self.string
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the [`Numeral`] system used
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!("1:01".full_width().numeral(), Numeral::FullWidth);
+    /// ```
+    pub const fn numeral(&self) -> Numeral {
+        self.numeral
+    }
+
+    #[must_use]
+    /// The length in bytes of the rendered string
+    ///
+    /// Digits are `1` to `3` bytes in UTF-8 depending on the [`Numeral`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!("1:01".numerals(Numeral::Ascii).len(), 4);
+    /// assert_eq!("1:01".numerals(Numeral::ArabicIndic).len(), 7);
+    /// assert_eq!("1:01".full_width().len(), 10);
+    /// assert_eq!("".full_width().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        let digits = self.string.bytes().filter(u8::is_ascii_digit).count();
+        self.string.len() + digits * (self.numeral.digits()[0].len() - 1)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the string is empty
+    pub const fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    #[inline]
+    /// The `char`'s of the rendered string
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert!("1:01".full_width().chars().eq("１:０１".chars()));
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        let numeral = self.numeral;
+        self.string.chars().map(move |c| {
+            if c.is_ascii_digit() {
+                numeral.digit(c as u8 - b'0').unwrap_or(c)
+            } else {
+                c
+            }
+        })
+    }
+
+    #[inline]
+    /// The string in parts, ASCII digits are swapped with the numeral's.
+    const fn parts(&self) -> Parts<'a> {
+        Parts {
+            string: self.string,
+            digits: self.numeral.digits(),
+        }
+    }
+}
+
+impl fmt::Display for NumeralStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::str::pad_iter(f, self.parts())
+    }
+}
+
+impl fmt::Debug for NumeralStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumeralStr")
+            .field("numeral", &self.numeral)
+            .field("str", &self.to_string())
+            .finish()
+    }
+}
+
+impl PartialEq<str> for NumeralStr<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for NumeralStr<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.chars().eq(other.chars())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Parts
+/// Iterator over a string's parts, each ASCII digit is its own part from `digits`.
+#[derive(Clone)]
+struct Parts<'a> {
+    string: &'a str,
+    digits: &'static [&'static str; 10],
+}
+
+impl<'a> Iterator for Parts<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.string.as_bytes().first()?;
+
+        if first.is_ascii_digit() {
+            self.string = self.string.get(1..)?;
+            return Some(self.digits[usize::from(first - b'0')]);
+        }
+
+        let end = self
+            .string
+            .bytes()
+            .position(|b| b.is_ascii_digit())
+            .unwrap_or(self.string.len());
+        let (part, rest) = self.string.split_at(end);
+        self.string = rest;
+        Some(part)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::str::FullWidth;
+
+    #[test]
+    fn digits() {
+        for numeral in Numeral::ALL {
+            for (i, digit) in numeral.digits().iter().enumerate() {
+                let d = u8::try_from(i).unwrap();
+                let ascii = i.to_string();
+                let rendered = ascii.numerals(numeral);
+                assert_eq!(rendered, *digit);
+                assert_eq!(rendered.to_string(), *digit);
+                assert_eq!(rendered.len(), digit.len());
+                assert_eq!(numeral.digit(d).unwrap().to_string(), *digit);
+            }
+        }
+
+        assert_eq!("0123456789".full_width(), "０１２３４５６７８９");
+        assert_eq!("0123456789".numerals(Numeral::ArabicIndic), "٠١٢٣٤٥٦٧٨٩");
+        assert_eq!("0123456789".numerals(Numeral::Devanagari), "०१२३४५६७८९");
+    }
+
+    #[test]
+    fn non_ascii() {
+        // Already converted digits and other non-ASCII `char`'s are left as-is.
+        let s = "２0🦀1年٣";
+        for (numeral, expected) in [
+            (Numeral::Ascii, "２0🦀1年٣"),
+            (Numeral::FullWidth, "２０🦀１年٣"),
+            (Numeral::ArabicIndic, "２٠🦀١年٣"),
+            (Numeral::Devanagari, "２०🦀१年٣"),
+        ] {
+            let rendered = s.numerals(numeral);
+            assert_eq!(rendered, expected);
+            assert_eq!(rendered.to_string(), expected);
+            assert_eq!(rendered.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn not_eq() {
+        assert_ne!("1".full_width(), "1");
+        assert_ne!("1".full_width(), "１ ");
+        assert_ne!("12".full_width(), "１");
+        assert_ne!("1".numerals(Numeral::ArabicIndic), "१");
+    }
+
+    #[test]
+    fn pad() {
+        let s = "1,000".full_width();
+        assert_eq!(format!("{s:>7}"), "  １,０００");
+        assert_eq!(format!("{s:^7}"), " １,０００ ");
+        assert_eq!(format!("{s:-<7}"), "１,０００--");
+        assert_eq!(format!("{s:3}"), "１,０００");
+        assert_eq!(format!("{s:.1}"), "１,０００");
+
+        let s = "1,000".numerals(Numeral::Devanagari);
+        assert_eq!(format!("{s:>7}"), "  १,०००");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", "1:01".full_width()),
+            r#"NumeralStr { numeral: FullWidth, str: "１:０１" }"#
+        );
+    }
+}
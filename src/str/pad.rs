@@ -0,0 +1,108 @@
+//---------------------------------------------------------------------------------------------------- Use
+use std::fmt::{self, Write};
+
+//---------------------------------------------------------------------------------------------------- Pad
+/// Write `s` into `f`, respecting the width, fill, and alignment flags.
+///
+/// This is [`fmt::Formatter::pad`] except the precision flag is ignored
+/// instead of truncating the string, e.g. `{:.1}` on a `Byte` should
+/// not cut `1.000 KB` into `1`.
+///
+/// Like [`str`], the default alignment is left.
+#[inline]
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    if f.precision().is_none() {
+        return f.pad(s);
+    }
+    pad_parts(f, &[s])
+}
+
+/// Same as [`pad()`] but with multiple string parts written back-to-back.
+#[inline]
+pub(crate) fn pad_parts(f: &mut fmt::Formatter<'_>, parts: &[&str]) -> fmt::Result {
+    pad_iter(f, parts.iter().copied())
+}
+
+/// Same as [`pad_parts()`] but the parts come from an iterator.
+///
+/// The iterator is walked twice if there is a width, once to count `char`s.
+pub(crate) fn pad_iter<'a, I>(f: &mut fmt::Formatter<'_>, mut parts: I) -> fmt::Result
+where
+    I: Iterator<Item = &'a str> + Clone,
+{
+    // No width, no need to count `char`s.
+    let Some(width) = f.width() else {
+        return parts.try_for_each(|s| f.write_str(s));
+    };
+
+    // ASCII is checked a word at a time, counting `char`s is not.
+    let chars: usize = parts
+        .clone()
+        .map(|s| {
+            if s.is_ascii() {
+                s.len()
+            } else {
+                s.chars().count()
+            }
+        })
+        .sum();
+    let padding = width.saturating_sub(chars);
+
+    let (pre, post) = match f.align() {
+        None | Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+    };
+
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    for s in parts {
+        f.write_str(s)?;
+    }
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use crate::str::{HeadTail, Str};
+
+    #[test]
+    fn pad() {
+        let s = Str::<8>::from_static_str("abc");
+        assert_eq!(format!("{s}"), "abc");
+        assert_eq!(format!("{s:5}"), "abc  ");
+        assert_eq!(format!("{s:<5}"), "abc  ");
+        assert_eq!(format!("{s:>5}"), "  abc");
+        assert_eq!(format!("{s:^6}"), " abc  ");
+        assert_eq!(format!("{s:*^7}"), "**abc**");
+        assert_eq!(format!("{s:2}"), "abc");
+
+        // Non-ASCII pads by `char`s, not bytes.
+        let s = Str::<8>::from_static_str("です");
+        assert_eq!(format!("{s:>4}"), "  です");
+        assert_eq!(format!("{s:.1}"), "です");
+    }
+
+    #[test]
+    fn precision_is_ignored() {
+        let s = Str::<8>::from_static_str("abc");
+        assert_eq!(format!("{s:.1}"), "abc");
+        assert_eq!(format!("{s:>5.1}"), "  abc");
+        assert_eq!(format!("{s:^6.0}"), " abc  ");
+    }
+
+    #[test]
+    fn pad_parts() {
+        let s = "hello world".head_tail_dot(2, 2);
+        assert_eq!(format!("{s}"), "he...ld");
+        assert_eq!(format!("{s:>9}"), "  he...ld");
+        assert_eq!(format!("{s:.1}"), "he...ld");
+    }
+}
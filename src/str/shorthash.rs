@@ -0,0 +1,306 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_traits};
+use crate::str::{HeadTail, Str};
+
+/// The separator inserted between the head and tail of a [`ShortHash`].
+const ELLIPSIS: &str = "…";
+
+/// Lowercase hex digits.
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+//---------------------------------------------------------------------------------------------------- ShortHash
+/// Shortened hash/ID display, e.g. `a1b2c3d…9f8e`
+///
+/// This takes a hex hash (or UUID) string and keeps the first `head`
+/// and last `tail` characters, separated with `…`, using [`HeadTail::head_tail`].
+///
+/// The inner "integer" is the `(head, tail)` lengths used.
+///
+/// ```rust
+/// # use readable::str::*;
+/// let hash = "a1b2c3d4e5f60718293a4b5c6d7e8f9f8e";
+///
+/// // The default is 7 head, 4 tail characters, like `git`.
+/// let short = ShortHash::new(hash).unwrap();
+/// assert_eq!(short, "a1b2c3d…9f8e");
+/// assert_eq!(short, (7, 4));
+/// assert!(short.cut());
+///
+/// // Configurable lengths.
+/// let short = ShortHash::with_len(hash, 4, 2).unwrap();
+/// assert_eq!(short, "a1b2…8e");
+///
+/// // UUIDs work too.
+/// let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+/// assert_eq!(ShortHash::new(uuid).unwrap(), "67e5504…e0c8");
+///
+/// // Short enough, nothing is cut.
+/// let short = ShortHash::with_len("abcdef", 3, 3).unwrap();
+/// assert_eq!(short, "abcdef");
+/// assert!(!short.cut());
+/// ```
+///
+/// ## Bytes
+/// Raw hash bytes are hex-encoded first.
+///
+/// Only the head and tail bytes are encoded, so this never allocates.
+///
+/// ```rust
+/// # use readable::str::*;
+/// let bytes = [0xa1, 0xb2, 0xc3, 0xd4, 0x00, 0x00, 0x9f, 0x8e];
+/// assert_eq!(ShortHash::from(&bytes), "a1b2c3d…9f8e");
+/// assert_eq!(ShortHash::from_bytes(&bytes, 2, 2), "a1…8e");
+///
+/// // SHA-256.
+/// let sha256 = [0xff; 32];
+/// assert_eq!(ShortHash::from(&sha256), "fffffff…ffff");
+/// ```
+///
+/// ## Size
+/// [`Str<67>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::str::*;
+/// assert_eq!(std::mem::size_of::<ShortHash>(), 70);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct ShortHash((u8, u8), Str<{ ShortHash::MAX_LEN }>);
+
+impl_traits!(ShortHash, (u8, u8));
+
+//---------------------------------------------------------------------------------------------------- ShortHash Constants
+impl ShortHash {
+    /// The default head length, `7`
+    pub const HEAD: u8 = 7;
+
+    /// The default tail length, `4`
+    pub const TAIL: u8 = 4;
+
+    /// The maximum head or tail length, `32`
+    ///
+    /// Larger lengths are capped to this, and `0` is raised to `1`.
+    pub const MAX_HEAD_TAIL: u8 = 32;
+
+    /// The maximum string length of a [`ShortHash`]
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let max = ShortHash::with_len(&"f".repeat(100), 32, 32).unwrap();
+    /// assert_eq!(max.len(), ShortHash::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 67;
+
+    /// This is the exact same as [`Self::UNKNOWN`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(ShortHash::ZERO, ShortHash::UNKNOWN);
+    /// ```
+    pub const ZERO: Self = Self::UNKNOWN;
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(ShortHash::UNKNOWN, (0, 0));
+    /// assert_eq!(ShortHash::UNKNOWN, "???");
+    /// ```
+    pub const UNKNOWN: Self = Self((0, 0), Str::from_static_str("???"));
+}
+
+//---------------------------------------------------------------------------------------------------- ShortHash Impl
+impl ShortHash {
+    impl_common!((u8, u8));
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert!(ShortHash::UNKNOWN.is_unknown());
+    /// assert!(!ShortHash::new("abc").unwrap().is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"???")
+    }
+
+    #[must_use]
+    /// Returns `true` if the input was cut, i.e. it contains `…`
+    pub fn cut(&self) -> bool {
+        self.as_str().contains(ELLIPSIS)
+    }
+
+    #[inline]
+    /// Same as [`ShortHash::with_len`] with [`ShortHash::HEAD`] and [`ShortHash::TAIL`]
+    ///
+    /// ## Errors
+    /// Same as [`ShortHash::with_len`].
+    pub fn new(hash: &str) -> Result<Self, Self> {
+        Self::with_len(hash, Self::HEAD, Self::TAIL)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`ShortHash::new`] but silently returns an [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn new_silent(hash: &str) -> Self {
+        match Self::new(hash) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    /// Keep the first `head` and last `tail` characters of `hash`
+    ///
+    /// `head` and `tail` are kept within `1..=`[`ShortHash::MAX_HEAD_TAIL`].
+    ///
+    /// ## Errors
+    /// If `hash` is empty, or contains anything other than
+    /// ASCII hex digits and `-`, [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(ShortHash::with_len("", 7, 4), Err(ShortHash::UNKNOWN));
+    /// assert_eq!(ShortHash::with_len("xyz", 7, 4), Err(ShortHash::UNKNOWN));
+    /// assert_eq!(ShortHash::with_len("ABC-def", 7, 4).unwrap(), "ABC-def");
+    /// ```
+    pub fn with_len(hash: &str, head: u8, tail: u8) -> Result<Self, Self> {
+        if hash.is_empty() || !hash.bytes().all(|b| b.is_ascii_hexdigit() || b == b'-') {
+            return Err(Self::UNKNOWN);
+        }
+
+        let head = head.clamp(1, Self::MAX_HEAD_TAIL);
+        let tail = tail.clamp(1, Self::MAX_HEAD_TAIL);
+
+        let mut string = Str::new();
+        match hash
+            .head_tail(usize::from(head), usize::from(tail))
+            .into_parts()
+        {
+            (h, Some(t)) => {
+                string.push_str_panic(h);
+                string.push_str_panic(ELLIPSIS);
+                string.push_str_panic(t);
+            }
+            // Not cut, `head + tail` is at most 64.
+            (h, None) => {
+                string.push_str_panic(h);
+            }
+        }
+
+        Ok(Self((head, tail), string))
+    }
+
+    #[must_use]
+    /// Hex-encode `bytes`, then keep the first `head` and last `tail` characters
+    ///
+    /// `head` and `tail` are kept within `1..=`[`ShortHash::MAX_HEAD_TAIL`].
+    ///
+    /// [`Self::UNKNOWN`] is returned if `bytes` is empty.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(ShortHash::from_bytes(&[0xde, 0xad, 0xbe, 0xef], 3, 3), "dea…eef");
+    /// assert_eq!(ShortHash::from_bytes(&[0xde, 0xad, 0xbe, 0xef], 4, 4), "deadbeef");
+    /// assert!(ShortHash::from_bytes(&[], 7, 4).is_unknown());
+    /// ```
+    pub fn from_bytes(bytes: &[u8], head: u8, tail: u8) -> Self {
+        const HALF: usize = ShortHash::MAX_HEAD_TAIL as usize / 2;
+
+        // Small enough to encode all of it.
+        if bytes.len() <= HALF * 2 {
+            let mut hex = Str::<{ HALF * 4 }>::new();
+            push_hex(&mut hex, bytes);
+            return match Self::with_len(hex.as_str(), head, tail) {
+                Ok(s) | Err(s) => s,
+            };
+        }
+
+        // Else, only the ends are needed, and it is always cut.
+        let head = head.clamp(1, Self::MAX_HEAD_TAIL);
+        let tail = tail.clamp(1, Self::MAX_HEAD_TAIL);
+
+        let mut head_hex = Str::<{ HALF * 2 }>::new();
+        let mut tail_hex = Str::<{ HALF * 2 }>::new();
+        push_hex(&mut head_hex, &bytes[..HALF]);
+        push_hex(&mut tail_hex, &bytes[bytes.len() - HALF..]);
+
+        let mut string = Str::new();
+        string.push_str_panic(head_hex.head(usize::from(head)));
+        string.push_str_panic(ELLIPSIS);
+        string.push_str_panic(tail_hex.tail(usize::from(tail)));
+
+        Self((head, tail), string)
+    }
+}
+
+/// Push `bytes` as lowercase hex.
+fn push_hex<const N: usize>(string: &mut Str<N>, bytes: &[u8]) {
+    for b in bytes {
+        string.push_char_panic(char::from(HEX[usize::from(b >> 4)]));
+        string.push_char_panic(char::from(HEX[usize::from(b & 0xF)]));
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl From<&[u8]> for ShortHash {
+    #[inline]
+    /// Same as [`ShortHash::from_bytes`] with [`ShortHash::HEAD`] and [`ShortHash::TAIL`].
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes, Self::HEAD, Self::TAIL)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for ShortHash {
+    #[inline]
+    /// Same as [`ShortHash::from_bytes`] with [`ShortHash::HEAD`] and [`ShortHash::TAIL`].
+    fn from(bytes: &[u8; N]) -> Self {
+        Self::from_bytes(bytes, Self::HEAD, Self::TAIL)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_match_str() {
+        // Both paths of `from_bytes()` must match hex-encoding everything.
+        for len in 1..=64_u8 {
+            let bytes: Vec<u8> = (0..len).map(|i| i.wrapping_mul(37)).collect();
+            let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+            for (head, tail) in [(0, 0), (1, 1), (7, 4), (32, 32), (255, 255)] {
+                assert_eq!(
+                    ShortHash::from_bytes(&bytes, head, tail),
+                    ShortHash::with_len(&hex, head, tail).unwrap(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cap() {
+        let hash = "f".repeat(100);
+        let short = ShortHash::with_len(&hash, u8::MAX, u8::MAX).unwrap();
+        assert_eq!(short, (32, 32));
+        assert_eq!(short.len(), ShortHash::MAX_LEN);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = ShortHash::new("a1b2c3d4e5f69f8e").unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[7,4],"a1b2c3d…9f8e"]"#);
+
+        let this: ShortHash = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (7, 4));
+        assert_eq!(this, "a1b2c3d…9f8e");
+    }
+}
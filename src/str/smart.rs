@@ -0,0 +1,860 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- SmartStr
+/// A [`Str`] that spills onto the heap
+///
+/// [`SmartStr`] stores strings up to `N` bytes inline in a [`Str<N>`],
+/// anything longer than that is moved into a heap allocated [`String`].
+///
+/// This means [`SmartStr`] never fails to push, unlike [`Str`],
+/// while still keeping the stack fast path for short strings.
+///
+/// The same rules for `N` apply, `N` must not be greater than `255`.
+///
+/// ## Usage
+/// ```rust
+/// # use readable::str::*;
+/// let mut s = SmartStr::<5>::new();
+/// assert!(s.is_inline());
+///
+/// // Fits inline.
+/// s.push_str("hello");
+/// assert_eq!(s, "hello");
+/// assert!(s.is_inline());
+///
+/// // Does not fit, spills onto the heap.
+/// s.push_str(" world");
+/// assert_eq!(s, "hello world");
+/// assert!(s.is_heap());
+///
+/// // Move back inline once it fits again.
+/// s.truncate(5);
+/// assert!(s.shrink_to_inline());
+/// assert_eq!(s, "hello");
+/// assert!(s.is_inline());
+/// ```
+///
+/// ## Equality
+/// [`SmartStr`]'s are compared by their strings, not where they are stored.
+/// ```rust
+/// # use readable::str::*;
+/// let inline = SmartStr::<8>::from("hello");
+/// let heap   = SmartStr::<8>::from(String::from("hello"));
+/// assert!(inline.is_inline());
+/// assert!(heap.is_heap());
+/// assert_eq!(inline, heap);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SmartStr<const N: usize>(Repr<N>);
+
+/// Where the string is stored.
+#[derive(Clone, Debug)]
+enum Repr<const N: usize> {
+    Inline(Str<N>),
+    Heap(String),
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl<const N: usize> SmartStr<N> {
+    #[inline]
+    #[must_use]
+    /// Returns an empty, inline [`SmartStr`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<4>::new();
+    /// assert!(s.is_empty());
+    /// assert!(s.is_inline());
+    /// ```
+    pub const fn new() -> Self {
+        Self(Repr::Inline(Str::new()))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create an inline [`SmartStr`] from a static [`str`]
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// const S: SmartStr<5> = SmartStr::from_static_str("hello");
+    /// assert_eq!(S, "hello");
+    /// ```
+    ///
+    /// ## Panics
+    /// This panics (at compile time if used in a `const`) if `s.len() > N`.
+    ///
+    /// Use [`SmartStr::from`] for strings that may need to be on the heap.
+    pub const fn from_static_str(s: &'static str) -> Self {
+        Self(Repr::Inline(Str::from_static_str(s)))
+    }
+
+    #[inline]
+    #[must_use]
+    /// If the string is stored inline in a [`Str<N>`]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline(_))
+    }
+
+    #[inline]
+    #[must_use]
+    /// If the string has spilled onto the heap
+    pub const fn is_heap(&self) -> bool {
+        matches!(self.0, Repr::Heap(_))
+    }
+
+    #[inline]
+    #[must_use]
+    /// This [`SmartStr`], as a [`str`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<2>::from("hello");
+    /// assert_eq!(s.as_str(), "hello");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Inline(s) => s.as_str(),
+            Repr::Heap(s) => s.as_str(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the _valid_ bytes of this [`SmartStr`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<2>::from("hello");
+    /// assert_eq!(s.as_bytes(), b"hello");
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the length of the string
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(SmartStr::<2>::from("hi").len(), 2);
+    /// assert_eq!(SmartStr::<2>::from("hello").len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Inline(s) => s.len(),
+            Repr::Heap(s) => s.len(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// If this [`SmartStr`] is empty.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert!(SmartStr::<2>::new().is_empty());
+    /// assert!(!SmartStr::<2>::from("hello").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// How many bytes can be held before (re)allocating
+    ///
+    /// This is `N` for inline strings.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert_eq!(SmartStr::<8>::new().capacity(), 8);
+    /// assert!(SmartStr::<2>::from("hello").capacity() >= 5);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        match &self.0 {
+            Repr::Inline(_) => N,
+            Repr::Heap(s) => s.capacity(),
+        }
+    }
+
+    #[inline]
+    /// Appends `self` with the [`str`] `s`.
+    ///
+    /// If `s` does not fit inline, `self` is moved onto the heap.
+    ///
+    /// This returns the new length of the string.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<3>::new();
+    /// assert_eq!(s.push_str("ab"), 2);
+    /// assert!(s.is_inline());
+    ///
+    /// assert_eq!(s.push_str("cd"), 4);
+    /// assert!(s.is_heap());
+    /// assert_eq!(s, "abcd");
+    /// ```
+    pub fn push_str<S: AsRef<str>>(&mut self, s: S) -> usize {
+        let s = s.as_ref();
+
+        match &mut self.0 {
+            Repr::Inline(inline) => {
+                if inline.push_str(s).is_err() {
+                    let mut heap = String::with_capacity(inline.len() + s.len());
+                    heap.push_str(inline.as_str());
+                    heap.push_str(s);
+                    self.0 = Repr::Heap(heap);
+                }
+            }
+            Repr::Heap(heap) => heap.push_str(s),
+        }
+
+        self.len()
+    }
+
+    #[inline]
+    /// [`SmartStr::push_str`], but with a `char`
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<3>::new();
+    /// assert_eq!(s.push_char('で'), 3);
+    /// assert!(s.is_inline());
+    ///
+    /// assert_eq!(s.push_char('す'), 6);
+    /// assert!(s.is_heap());
+    /// assert_eq!(s, "です");
+    /// ```
+    pub fn push_char(&mut self, c: char) -> usize {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    #[inline]
+    /// Clears the string.
+    ///
+    /// If the string is on the heap, the allocation is kept,
+    /// use [`SmartStr::shrink_to_inline`] to move it back inline.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<2>::from("hello");
+    /// s.clear();
+    /// assert!(s.is_empty());
+    /// assert!(s.is_heap());
+    /// ```
+    pub fn clear(&mut self) {
+        match &mut self.0 {
+            Repr::Inline(s) => s.clear(),
+            Repr::Heap(s) => s.clear(),
+        }
+    }
+
+    /// Move the string back inline if it fits in `N` bytes
+    ///
+    /// This returns `true` if the string is inline after this call.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<2>::from("hello");
+    /// assert!(!s.shrink_to_inline());
+    ///
+    /// s.truncate(2);
+    /// assert!(s.shrink_to_inline());
+    /// assert_eq!(s, "he");
+    /// ```
+    pub fn shrink_to_inline(&mut self) -> bool {
+        match &self.0 {
+            Repr::Inline(_) => true,
+            Repr::Heap(heap) => match Str::try_from(heap.as_str()) {
+                Ok(inline) => {
+                    self.0 = Repr::Inline(inline);
+                    true
+                }
+                Err(_) => false,
+            },
+        }
+    }
+
+    #[inline]
+    /// Shortens this [`SmartStr`] to the specified length.
+    ///
+    /// If `new_len` is greater than the string’s current length, this has no effect.
+    ///
+    /// This does not move the string back inline.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<2>::from("hello");
+    /// s.truncate(1);
+    /// assert_eq!(s, "h");
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `new_len` does not lie on a [`char`] boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.0 {
+            Repr::Inline(s) => s.truncate(new_len),
+            Repr::Heap(s) => s.truncate(new_len),
+        }
+    }
+
+    #[inline]
+    /// Removes the last character from the [`SmartStr`] and returns it.
+    ///
+    /// Returns `None` if this [`SmartStr`] is empty.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<1>::from("ab");
+    /// assert_eq!(s.pop(), Some('b'));
+    /// assert_eq!(s.pop(), Some('a'));
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<char> {
+        match &mut self.0 {
+            Repr::Inline(s) => s.pop(),
+            Repr::Heap(s) => s.pop(),
+        }
+    }
+
+    #[inline]
+    /// Removes a [`char`] from this [`SmartStr`] at a byte position and returns it.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<1>::from("foo");
+    /// assert_eq!(s.remove(0), 'f');
+    /// assert_eq!(s, "oo");
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `idx` is larger than or equal to the [`SmartStr`]’s length,
+    /// or if it does not lie on a [`char`] boundary.
+    pub fn remove(&mut self, idx: usize) -> char {
+        match &mut self.0 {
+            Repr::Inline(s) => s.remove(idx),
+            Repr::Heap(s) => s.remove(idx),
+        }
+    }
+
+    #[inline]
+    /// Calls [`str::make_ascii_uppercase`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<2>::from("hello");
+    /// s.make_ascii_uppercase();
+    /// assert_eq!(s, "HELLO");
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        match &mut self.0 {
+            Repr::Inline(s) => s.make_ascii_uppercase(),
+            Repr::Heap(s) => s.make_ascii_uppercase(),
+        }
+    }
+
+    #[inline]
+    /// Calls [`str::make_ascii_lowercase`].
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<2>::from("HELLO");
+    /// s.make_ascii_lowercase();
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        match &mut self.0 {
+            Repr::Inline(s) => s.make_ascii_lowercase(),
+            Repr::Heap(s) => s.make_ascii_lowercase(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Consumes `self` into a [`String`]
+    ///
+    /// If the string is already on the heap, this does not allocate.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s: String = SmartStr::<2>::from("hello").into_string();
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn into_string(self) -> String {
+        match self.0 {
+            Repr::Inline(s) => s.into_string(),
+            Repr::Heap(s) => s,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Consumes `self` into the valid bytes
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let v: Vec<u8> = SmartStr::<2>::from("hello").into_vec();
+    /// assert_eq!(v, b"hello");
+    /// ```
+    pub fn into_vec(self) -> Vec<u8> {
+        self.into_string().into_bytes()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+impl<const N: usize> From<Str<N>> for SmartStr<N> {
+    #[inline]
+    fn from(s: Str<N>) -> Self {
+        Self(Repr::Inline(s))
+    }
+}
+
+impl<const N: usize> From<&str> for SmartStr<N> {
+    #[inline]
+    /// Stores `s` inline if it fits, else on the heap.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert!(SmartStr::<5>::from("hello").is_inline());
+    /// assert!(SmartStr::<4>::from("hello").is_heap());
+    /// ```
+    fn from(s: &str) -> Self {
+        match Str::try_from(s) {
+            Ok(s) => Self(Repr::Inline(s)),
+            Err(_) => Self(Repr::Heap(s.to_owned())),
+        }
+    }
+}
+
+impl<const N: usize> From<String> for SmartStr<N> {
+    #[inline]
+    /// This re-uses the [`String`] allocation, it is not moved inline.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// assert!(SmartStr::<5>::from(String::from("hello")).is_heap());
+    /// ```
+    fn from(s: String) -> Self {
+        Self(Repr::Heap(s))
+    }
+}
+
+impl<const N: usize> From<SmartStr<N>> for String {
+    #[inline]
+    fn from(s: SmartStr<N>) -> Self {
+        s.into_string()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl<const N: usize> PartialEq for SmartStr<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for SmartStr<N> {}
+
+impl<const N: usize> PartialOrd for SmartStr<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for SmartStr<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> std::hash::Hash for SmartStr<N> {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> PartialEq<str> for SmartStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for SmartStr<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<Str<N>> for SmartStr<N> {
+    #[inline]
+    fn eq(&self, other: &Str<N>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> std::fmt::Display for SmartStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const N: usize> std::ops::Deref for SmartStr<N> {
+    type Target = str;
+
+    #[inline]
+    /// Equivalent to [`SmartStr::as_str()`].
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for SmartStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for SmartStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> std::borrow::Borrow<str> for SmartStr<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> std::default::Default for SmartStr<N> {
+    #[inline]
+    /// Calls [`Self::new`]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: AsRef<str>> std::ops::Add<T> for SmartStr<N> {
+    type Output = Self;
+
+    #[inline]
+    /// Implements the `+` operator.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<3>::from("foo");
+    /// assert_eq!(s + "bar", "foobar");
+    /// ```
+    fn add(mut self, s: T) -> Self::Output {
+        self.push_str(s);
+        self
+    }
+}
+
+impl<const N: usize, T: AsRef<str>> std::ops::AddAssign<T> for SmartStr<N> {
+    #[inline]
+    /// Implements the `+=` operator.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<3>::from("foo");
+    /// s += "bar";
+    /// assert_eq!(s, "foobar");
+    /// ```
+    fn add_assign(&mut self, s: T) {
+        self.push_str(s);
+    }
+}
+
+impl<const N: usize> Extend<char> for SmartStr<N> {
+    #[inline]
+    /// Calls [`SmartStr::push_char`] for each `char`.
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|c| {
+            self.push_char(c);
+        });
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a str> for SmartStr<N> {
+    #[inline]
+    /// Calls [`SmartStr::push_str`] for each `str`.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let mut s = SmartStr::<3>::new();
+    /// s.extend(["hello", " ", "world", "!"]);
+    /// assert_eq!(s, "hello world!");
+    /// ```
+    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|s| {
+            self.push_str(s);
+        });
+    }
+}
+
+impl<const N: usize> std::fmt::Write for SmartStr<N> {
+    #[inline]
+    /// Calls [`SmartStr::push_str()`], this never fails.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// use std::fmt::Write;
+    /// let mut s = SmartStr::<3>::new();
+    /// write!(s, "{} {}", "hello", 123).unwrap();
+    /// assert_eq!(s, "hello 123");
+    /// ```
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Serde
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for SmartStr<N> {
+    #[inline]
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<2>::from("hello");
+    /// let json = serde_json::to_string(&s).unwrap();
+    /// assert_eq!(json, "\"hello\"");
+    /// ```
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for SmartStr<N> {
+    #[inline]
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s: SmartStr<8> = serde_json::from_str("\"hello\"").unwrap();
+    /// assert_eq!(s, "hello");
+    /// assert!(s.is_inline());
+    ///
+    /// let s: SmartStr<2> = serde_json::from_str("\"hello\"").unwrap();
+    /// assert_eq!(s, "hello");
+    /// assert!(s.is_heap());
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{self, Visitor};
+        use std::marker::PhantomData;
+
+        struct SmartStrVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+        impl<const N: usize> Visitor<'_> for SmartStrVisitor<N> {
+            type Value = SmartStr<N>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(SmartStr::from(v))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                // Keep the allocation only if it doesn't fit inline.
+                match Str::try_from(v.as_str()) {
+                    Ok(s) => Ok(SmartStr::from(s)),
+                    Err(_) => Ok(SmartStr::from(v)),
+                }
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match std::str::from_utf8(v) {
+                    Ok(s) => Ok(SmartStr::from(s)),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(SmartStrVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const N: usize> bincode::Encode for SmartStr<N> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        bincode::Encode::encode(self.as_str(), encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const N: usize> bincode::Decode for SmartStr<N> {
+    #[inline]
+    fn decode<D: bincode::de::Decoder>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        use bincode::de::read::Reader;
+
+        let len: u64 = bincode::Decode::decode(decoder)?;
+        let len = usize::try_from(len)
+            .map_err(|_| bincode::error::DecodeError::OutsideUsizeRange(len))?;
+
+        if len <= N {
+            return Str::priv_bincode_decode(decoder, len).map(Self::from);
+        }
+
+        decoder.claim_container_read::<u8>(len)?;
+        let mut vec = vec![0; len];
+        decoder.reader().read(&mut vec)?;
+
+        match String::from_utf8(vec) {
+            Ok(s) => Ok(Self::from(s)),
+            Err(e) => Err(bincode::error::DecodeError::Utf8 {
+                inner: e.utf8_error(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, const N: usize> bincode::BorrowDecode<'de> for SmartStr<N> {
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        bincode::Decode::decode(decoder)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize> borsh::BorshSerialize for SmartStr<N> {
+    #[inline]
+    /// ```rust
+    /// # use readable::str::*;
+    /// let s = SmartStr::<2>::from("hello");
+    /// let bytes = borsh::to_vec(&s).unwrap();
+    /// assert_eq!(bytes, borsh::to_vec(&"hello").unwrap());
+    /// ```
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.as_str(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize> borsh::BorshDeserialize for SmartStr<N> {
+    #[inline]
+    /// ```rust
+    /// # use readable::str::*;
+    /// let bytes = borsh::to_vec(&"hello").unwrap();
+    /// let s: SmartStr<2> = borsh::from_slice(&bytes).unwrap();
+    /// assert_eq!(s, "hello");
+    /// ```
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        use std::io::Read;
+
+        let len: u32 = borsh::BorshDeserialize::deserialize_reader(reader)?;
+        let len = len as usize;
+
+        if len <= N {
+            return Str::priv_borsh_deserialize(reader, len).map(Self::from);
+        }
+
+        // Grow with the input instead of trusting `len` for the allocation.
+        let mut vec = Vec::new();
+        reader.take(len as u64).read_to_end(&mut vec)?;
+        if vec.len() != len {
+            return Err(borsh::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        match String::from_utf8(vec) {
+            Ok(s) => Ok(Self::from(s)),
+            Err(e) => Err(borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, e)),
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+#[cfg(any(feature = "serde", feature = "bincode", feature = "borsh"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn postcard() {
+        let mut buf = [0_u8; 16];
+        let bytes = postcard::to_slice(&SmartStr::<8>::from("hello"), &mut buf).unwrap();
+
+        let this: SmartStr<8> = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, "hello");
+        assert!(this.is_inline());
+
+        let this: SmartStr<2> = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, "hello");
+        assert!(this.is_heap());
+
+        // Owned strings that fit are moved inline.
+        let this: SmartStr<8> = serde_json::from_reader(&b"\"hello\""[..]).unwrap();
+        assert!(this.is_inline());
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec("hello", config).unwrap();
+
+        let this: SmartStr<8> = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, "hello");
+        assert!(this.is_inline());
+
+        let this: SmartStr<2> = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, "hello");
+        assert!(this.is_heap());
+
+        assert!(bincode::decode_from_slice::<SmartStr<2>, _>(&bytes[..5], config).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let bytes = borsh::to_vec("hello").unwrap();
+
+        let this: SmartStr<8> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "hello");
+        assert!(this.is_inline());
+
+        let this: SmartStr<2> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "hello");
+        assert!(this.is_heap());
+
+        // Not enough bytes, the length is not trusted.
+        assert!(borsh::from_slice::<SmartStr<2>>(b"\xff\xff\xff\xffhello").is_err());
+        assert!(borsh::from_slice::<SmartStr<2>>(b"\x06\x00\x00\x00\xff\xff").is_err());
+    }
+}
@@ -88,7 +88,7 @@ use std::sync::Arc;
 /// assert_eq!(string, "hello-------------------");
 /// assert_eq!(string.len(), 24);
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[repr(C)]
 pub struct Str<const N: usize> {
     buf: [u8; N],
@@ -675,6 +675,139 @@ impl<const N: usize> Str<N> {
         unsafe { std::str::from_utf8_unchecked_mut(self.as_bytes_mut()) }
     }
 
+    #[inline]
+    #[must_use]
+    /// Returns the index of the first `byte` in this [`Str`]
+    ///
+    /// This, and the other `_byte(s)` search functions are `const` versions
+    /// of the [`str`] functions, which are still available through [`Deref`](std::ops::Deref),
+    /// e.g. `Str::contains()`, `Str::find()` and `Str::char_indices()`.
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<5> = Str::from_static_str("hello");
+    /// const L: Option<usize> = S.find_byte(b'l');
+    /// assert_eq!(L, Some(2));
+    /// assert_eq!(S.find_byte(b'a'), None);
+    ///
+    /// // `str` functions work too.
+    /// assert_eq!(S.find('l'), Some(2));
+    /// assert_eq!(S.char_indices().nth(1), Some((1, 'e')));
+    /// ```
+    pub const fn find_byte(&self, byte: u8) -> Option<usize> {
+        let bytes = self.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == byte {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if `byte` is in this [`Str`]
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<5> = Str::from_static_str("hello");
+    /// assert!(S.contains_byte(b'h'));
+    /// assert!(!S.contains_byte(b'a'));
+    /// ```
+    pub const fn contains_byte(&self, byte: u8) -> bool {
+        self.find_byte(byte).is_some()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the index of the first match of `bytes` in this [`Str`]
+    ///
+    /// Empty `bytes` always match at `0`.
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<11> = Str::from_static_str("hello world");
+    /// assert_eq!(S.find_bytes(b"world"), Some(6));
+    /// assert_eq!(S.find_bytes(b"o"), Some(4));
+    /// assert_eq!(S.find_bytes(b""), Some(0));
+    /// assert_eq!(S.find_bytes(b"worlds"), None);
+    /// ```
+    pub const fn find_bytes(&self, bytes: &[u8]) -> Option<usize> {
+        let haystack = self.as_bytes();
+        if bytes.len() > haystack.len() {
+            return None;
+        }
+
+        let mut i = 0;
+        while i <= haystack.len() - bytes.len() {
+            if Self::eq_at(haystack, i, bytes) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if `bytes` is in this [`Str`]
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<11> = Str::from_static_str("hello world");
+    /// assert!(S.contains_bytes(b"lo w"));
+    /// assert!(!S.contains_bytes(b"hello!"));
+    /// ```
+    pub const fn contains_bytes(&self, bytes: &[u8]) -> bool {
+        self.find_bytes(bytes).is_some()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if this [`Str`] starts with `bytes`
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<11> = Str::from_static_str("hello world");
+    /// assert!(S.starts_with_bytes(b"hello"));
+    /// assert!(!S.starts_with_bytes(b"world"));
+    /// ```
+    pub const fn starts_with_bytes(&self, bytes: &[u8]) -> bool {
+        let haystack = self.as_bytes();
+        bytes.len() <= haystack.len() && Self::eq_at(haystack, 0, bytes)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if this [`Str`] ends with `bytes`
+    ///
+    /// ``` rust
+    /// # use readable::str::*;
+    /// const S: Str<11> = Str::from_static_str("hello world");
+    /// assert!(S.ends_with_bytes(b"world"));
+    /// assert!(!S.ends_with_bytes(b"hello"));
+    /// ```
+    pub const fn ends_with_bytes(&self, bytes: &[u8]) -> bool {
+        let haystack = self.as_bytes();
+        bytes.len() <= haystack.len() && Self::eq_at(haystack, haystack.len() - bytes.len(), bytes)
+    }
+
+    /// If `haystack[start..]` starts with `bytes`.
+    ///
+    /// INVARIANT: `start + bytes.len()` must be `<= haystack.len()`.
+    const fn eq_at(haystack: &[u8], start: usize, bytes: &[u8]) -> bool {
+        let mut i = 0;
+        while i < bytes.len() {
+            if haystack[start + i] != bytes[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
     #[inline]
     #[must_use]
     /// Consumes `self` into a [`String`]
@@ -972,6 +1105,11 @@ impl<const N: usize> Str<N> {
     /// assert_eq!(s, "wor");
     /// assert_eq!(0, s.push_str_saturating("🦀"));
     /// assert_eq!(s, "wor");
+    ///
+    /// // Only whole `char`s are pushed, no matter how long the input is.
+    /// let mut s = Str::<10>::new();
+    /// assert_eq!(9, s.push_str_saturating("で".repeat(10_000)));
+    /// assert_eq!(s, "ででで");
     /// ```
     pub fn push_str_saturating<S: AsRef<str>>(&mut self, s: S) -> usize {
         let s = s.as_ref();
@@ -986,29 +1124,19 @@ impl<const N: usize> Str<N> {
         }
 
         // Figure out what `char` index we can stop at.
-        let index = if s.is_ascii() {
-            remaining
-        } else {
-            // Handle UTF-8 correctly.
-            // We use `.rev()` because we assume the string
-            // is only slightly longer, so starting linear
-            // search from the end is faster.
-            let mut index = 0;
-            for (i, _) in s.char_indices().rev() {
-                index = i;
-
-                if i <= remaining {
-                    break;
-                }
-            }
-
-            // We didn't find a good index, push nothing.
-            if index == 0 {
-                return 0;
-            }
+        //
+        // A `char` is at most 4 bytes, so this
+        // walks back at most 3 bytes, no matter
+        // how long (or non-ASCII) `s` is.
+        let mut index = remaining;
+        while !s.is_char_boundary(index) {
+            index -= 1;
+        }
 
-            index
-        };
+        // We didn't find a good index, push nothing.
+        if index == 0 {
+            return 0;
+        }
 
         #[allow(clippy::string_slice)]
         self.push_str_panic(&s[..index]);
@@ -1142,6 +1270,145 @@ impl<const N: usize> Str<N> {
         }
     }
 
+    #[must_use]
+    /// Append a static [`str`] to `self`, in a `const` context
+    ///
+    /// This is [`Str::push_str_panic`], but it takes and returns `self`
+    /// so that it can be used to build constants at compile time.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// const VERSION: &str = "1.2.3";
+    /// const STR: Str<16> = Str::from_static_str("v").append_static(VERSION);
+    ///
+    /// assert_eq!(STR, "v1.2.3");
+    ///
+    /// const CHAIN: Str<16> = Str::new()
+    ///     .append_static("a")
+    ///     .append_static("bc")
+    ///     .append_static("です");
+    /// assert_eq!(CHAIN, "abcです");
+    /// ```
+    ///
+    /// ## Compile-time panic
+    /// This function will panic at compile time
+    /// if the [`str`] does not fit in the remaining capacity.
+    ///
+    /// ```rust,ignore
+    /// # use readable::str::*;
+    /// // This doesn't fit, will panic at compile time.
+    /// const STR: Str<3> = Str::from_static_str("ab").append_static("cd");
+    /// ```
+    pub const fn append_static(self, s: &'static str) -> Self {
+        let bytes = s.as_bytes();
+        let len = self.len as usize;
+        let new_len = len + bytes.len();
+
+        assert!(new_len <= N, "str length is longer than remaining capacity");
+
+        let mut buf = self.buf;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            buf[len + i] = bytes[i];
+            i += 1;
+        }
+
+        Self {
+            buf,
+            len: new_len as u8,
+        }
+    }
+
+    #[must_use]
+    /// Append a [`char`] to `self`, in a `const` context
+    ///
+    /// This is [`Str::push_char_panic`], but it takes and returns `self`
+    /// so that it can be used to build constants at compile time.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// const STR: Str<8> = Str::from_static_str("v1")
+    ///     .append_char('.')
+    ///     .append_char('🦀');
+    ///
+    /// assert_eq!(STR, "v1.🦀");
+    /// ```
+    ///
+    /// ## Compile-time panic
+    /// This function will panic at compile time
+    /// if the [`char`] does not fit in the remaining capacity.
+    ///
+    /// ```rust,ignore
+    /// # use readable::str::*;
+    /// // This doesn't fit, will panic at compile time.
+    /// const STR: Str<3> = Str::from_static_str("ab").append_char('で');
+    /// ```
+    pub const fn append_char(self, c: char) -> Self {
+        let ch = c as u32;
+        let char_len = c.len_utf8();
+        let len = self.len as usize;
+
+        assert!(
+            len + char_len <= N,
+            "char length is longer than remaining capacity"
+        );
+
+        let mut buf = self.buf;
+
+        // Same as `char::encode_utf8()`, which isn't `const`.
+        match char_len {
+            1 => buf[len] = ch as u8,
+            2 => {
+                buf[len] = (ch >> 6 & 0x1F) as u8 | 0b1100_0000;
+                buf[len + 1] = (ch & 0x3F) as u8 | 0b1000_0000;
+            }
+            3 => {
+                buf[len] = (ch >> 12 & 0x0F) as u8 | 0b1110_0000;
+                buf[len + 1] = (ch >> 6 & 0x3F) as u8 | 0b1000_0000;
+                buf[len + 2] = (ch & 0x3F) as u8 | 0b1000_0000;
+            }
+            _ => {
+                buf[len] = (ch >> 18 & 0x07) as u8 | 0b1111_0000;
+                buf[len + 1] = (ch >> 12 & 0x3F) as u8 | 0b1000_0000;
+                buf[len + 2] = (ch >> 6 & 0x3F) as u8 | 0b1000_0000;
+                buf[len + 3] = (ch & 0x3F) as u8 | 0b1000_0000;
+            }
+        }
+
+        Self {
+            buf,
+            len: (len + char_len) as u8,
+        }
+    }
+
+    #[must_use]
+    /// Shorten `self` to `new_len` bytes, in a `const` context
+    ///
+    /// This is [`Str::truncate`], but it takes and returns `self`.
+    ///
+    /// If `new_len` is greater than the current length, this has no effect.
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// const STR: Str<8> = Str::from_static_str("hello").truncated(4);
+    /// assert_eq!(STR, "hell");
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `new_len` does not lie on a [`char`] boundary.
+    pub const fn truncated(mut self, new_len: usize) -> Self {
+        if new_len <= self.len as usize {
+            // UTF-8 continuation bytes are `0b10xx_xxxx`.
+            assert!(
+                new_len == self.len as usize || (self.buf[new_len] as i8) >= -0x40,
+                "new_len does not lie on a char boundary"
+            );
+            self.len = new_len as u8;
+        }
+        self
+    }
+
     #[inline]
     #[must_use]
     /// Decomposes a [`Str`] into its raw components
@@ -1517,7 +1784,21 @@ impl<const N: usize> PartialEq<&str> for Str<N> {
 impl<const N: usize> std::fmt::Display for Str<N> {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl<const N: usize> std::fmt::Debug for Str<N> {
+    #[inline]
+    /// Same as the [`Debug`](std::fmt::Debug) of the [`str`], i.e. quoted and escaped
+    ///
+    /// ```rust
+    /// # use readable::str::*;
+    /// let string = Str::<8>::from_static_str("hello");
+    /// assert_eq!(format!("{string:?}"), "\"hello\"");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
     }
 }
 
@@ -1856,9 +2137,34 @@ impl<const N: usize> bincode::Decode for Str<N> {
     fn decode<D: bincode::de::Decoder>(
         decoder: &mut D,
     ) -> Result<Self, bincode::error::DecodeError> {
-        let s: String = bincode::Decode::decode(decoder)?;
-        #[allow(clippy::map_err_ignore)]
-        Self::try_from(s).map_err(|_| bincode::error::DecodeError::Other("Str::invalid() failed"))
+        let len: u64 = bincode::Decode::decode(decoder)?;
+        match usize::try_from(len) {
+            Ok(len) if len <= N => Self::priv_bincode_decode(decoder, len),
+            _ => Err(bincode::error::DecodeError::Other("Str is too long")),
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<const N: usize> Str<N> {
+    // Read the `len` (`<= N`) bytes of a `str` that bincode
+    // encoded straight into the buffer, without a `String` in-between.
+    pub(super) fn priv_bincode_decode<D: bincode::de::Decoder>(
+        decoder: &mut D,
+        len: usize,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        use bincode::de::read::Reader;
+
+        decoder.claim_bytes_read(len)?;
+        let mut this = Self::new();
+        decoder.reader().read(&mut this.buf[..len])?;
+
+        if let Err(inner) = std::str::from_utf8(&this.buf[..len]) {
+            return Err(bincode::error::DecodeError::Utf8 { inner });
+        }
+
+        this.len = len as u8;
+        Ok(this)
     }
 }
 #[cfg(feature = "bincode")]
@@ -1901,18 +2207,96 @@ impl<const N: usize> borsh::BorshDeserialize for Str<N> {
     /// assert!(borsh::from_slice::<Str<4>>(&bytes).is_err());
     /// ```
     fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
-        let s: String = borsh::BorshDeserialize::deserialize_reader(reader)?;
-        #[allow(clippy::map_err_ignore)]
-        Self::try_from(s).map_err(|_| {
-            borsh::io::Error::new(borsh::io::ErrorKind::Other, "Str::try_from() failed")
-        })
+        let len: u32 = borsh::BorshDeserialize::deserialize_reader(reader)?;
+        match usize::try_from(len) {
+            Ok(len) if len <= N => Self::priv_borsh_deserialize(reader, len),
+            _ => Err(borsh::io::Error::new(
+                borsh::io::ErrorKind::Other,
+                "Str::try_from() failed",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize> Str<N> {
+    // Read the `len` (`<= N`) bytes of a `str` that borsh
+    // serialized straight into the buffer, without a `String` in-between.
+    pub(super) fn priv_borsh_deserialize<R: std::io::Read>(
+        reader: &mut R,
+        len: usize,
+    ) -> borsh::io::Result<Self> {
+        let mut this = Self::new();
+        reader.read_exact(&mut this.buf[..len])?;
+
+        if let Err(e) = std::str::from_utf8(&this.buf[..len]) {
+            return Err(borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, e));
+        }
+
+        this.len = len as u8;
+        Ok(this)
     }
 }
 
 //---------------------------------------------------------------------------------------------------- TESTS
-//#[cfg(test)]
-//mod tests {
-//	#[test]
-//		fn __TEST__() {
-//	}
-//}
+#[cfg(test)]
+#[cfg(any(feature = "serde", feature = "bincode", feature = "borsh"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn postcard() {
+        // No allocation on either side.
+        let mut buf = [0_u8; 16];
+        let this = Str::<5>::from_static_str("hello");
+        let bytes = postcard::to_slice(&this, &mut buf).unwrap();
+        assert_eq!(bytes, b"\x05hello");
+
+        let this: Str<5> = postcard::from_bytes(bytes).unwrap();
+        assert_eq!(this, "hello");
+
+        // Too long.
+        assert!(postcard::from_bytes::<Str<4>>(b"\x05hello").is_err());
+        // Not UTF-8.
+        assert!(postcard::from_bytes::<Str<4>>(b"\x02\xff\xff").is_err());
+        // Not enough bytes.
+        assert!(postcard::from_bytes::<Str<5>>(b"\x05hell").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec("hello", config).unwrap();
+
+        let (this, read): (Str<5>, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(this, "hello");
+        assert_eq!(read, bytes.len());
+        assert_eq!(this, Str::<5>::from_static_str("hello"));
+
+        // Too long.
+        assert!(bincode::decode_from_slice::<Str<4>, _>(&bytes, config).is_err());
+        // Not UTF-8.
+        assert!(bincode::decode_from_slice::<Str<4>, _>(b"\x02\xff\xff", config).is_err());
+        // Not enough bytes.
+        assert!(bincode::decode_from_slice::<Str<5>, _>(&bytes[..5], config).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let bytes = borsh::to_vec("hello").unwrap();
+
+        let this: Str<5> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, "hello");
+        assert_eq!(this, Str::<5>::from_static_str("hello"));
+
+        // Too long.
+        assert!(borsh::from_slice::<Str<4>>(&bytes).is_err());
+        // Not UTF-8.
+        assert!(borsh::from_slice::<Str<4>>(b"\x02\x00\x00\x00\xff\xff").is_err());
+        // Not enough bytes.
+        assert!(borsh::from_slice::<Str<5>>(&bytes[..8]).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//---------------------------------------------------------------------------------------------------- CPU
+#[inline]
+#[must_use]
+/// Get the amount of CPUs available to this process
+///
+/// This uses [`std::thread::available_parallelism`],
+/// which respects things like cgroup quotas and CPU affinity.
+///
+/// This will return `0` if the amount could not be determined.
+pub fn cpu_count() -> usize {
+    std::thread::available_parallelism().map_or(0, std::num::NonZeroUsize::get)
+}
+
+//---------------------------------------------------------------------------------------------------- Load Average
+#[inline]
+#[must_use]
+/// Get the system load average over the last `1`, `5`, and `15` minutes
+///
+/// This can be used on:
+/// - macOS
+/// - BSDs
+/// - Linux
+///
+/// This will return [`None`] if the underlying system call fails, or on Windows.
+pub fn load_avg() -> Option<[f64; 3]> {
+    #[cfg(unix)]
+    {
+        use target_os_lib as libc;
+
+        let mut avg = [0.0; 3];
+        // SAFETY: calling C, `avg` has room for the `3` samples requested.
+        let samples = unsafe { libc::getloadavg(avg.as_mut_ptr(), 3) };
+        if samples == 3 {
+            return Some(avg);
+        }
+    }
+
+    None
+}
+
+//---------------------------------------------------------------------------------------------------- Memory
+#[inline]
+#[must_use]
+/// Get the `(used, total)` system memory in bytes
+///
+/// `used` is the memory that is not available for new allocations,
+/// i.e. reclaimable caches and buffers are not counted as used.
+///
+/// This can be used on:
+/// - Windows
+/// - Linux
+///
+/// This will return [`None`] if the underlying system call fails, or on other platforms.
+pub fn mem() -> Option<(u64, u64)> {
+    #[cfg(target_os = "windows")]
+    {
+        use target_os_lib as windows;
+        use windows::Win32::System::SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+        let mut status = MEMORYSTATUSEX {
+            dwLength: std::mem::size_of::<MEMORYSTATUSEX>() as u32,
+            ..Default::default()
+        };
+
+        // SAFETY: calling C
+        if unsafe { GlobalMemoryStatusEx(&mut status) }.is_ok() {
+            let used = status.ullTotalPhys.saturating_sub(status.ullAvailPhys);
+            return Some((used, status.ullTotalPhys));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        return parse_meminfo(&meminfo);
+    }
+
+    #[allow(unreachable_code)]
+    None
+}
+
+/// Parse the `(used, total)` bytes out of `/proc/meminfo`.
+#[cfg(any(target_os = "linux", test))]
+fn parse_meminfo(meminfo: &str) -> Option<(u64, u64)> {
+    let mut total = None;
+    let mut available = None;
+
+    for line in meminfo.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        // The values are in `kB`.
+        let value = || {
+            value
+                .trim()
+                .trim_end_matches("kB")
+                .trim_end()
+                .parse::<u64>()
+                .ok()
+                .map(|kb| kb.saturating_mul(1024))
+        };
+
+        match key {
+            "MemTotal" => total = value(),
+            "MemAvailable" => available = value(),
+            _ => continue,
+        }
+
+        if let (Some(total), Some(available)) = (total, available) {
+            return Some((total.saturating_sub(available), total));
+        }
+    }
+
+    None
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meminfo() {
+        let meminfo = "MemTotal:       16000000 kB\nMemFree:         1000000 kB\nMemAvailable:   12000000 kB\n";
+        assert_eq!(
+            parse_meminfo(meminfo),
+            Some((4_000_000 * 1024, 16_000_000 * 1024))
+        );
+
+        assert_eq!(parse_meminfo("MemTotal:       16000000 kB\n"), None);
+        assert_eq!(parse_meminfo(""), None);
+        assert_eq!(parse_meminfo("MemTotal: ??? kB\nMemAvailable: 1 kB"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn linux() {
+        assert!(cpu_count() >= 1);
+
+        let [one, five, fifteen] = load_avg().unwrap();
+        assert!(one >= 0.0 && five >= 0.0 && fifteen >= 0.0);
+
+        let (used, total) = mem().unwrap();
+        assert!(used <= total);
+        assert!(total > 0);
+    }
+}
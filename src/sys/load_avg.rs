@@ -0,0 +1,292 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::num::Float;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- SysLoadAvg
+/// Human-readable system load average
+///
+/// This takes the `1`, `5`, and `15` minute load averages
+/// and formats them with `2` decimals, like `uptime` and `htop`:
+/// ```rust
+/// # use readable::sys::*;
+/// assert_eq!(SysLoadAvg::new(0.52, 0.48, 0.4),  "0.52, 0.48, 0.40");
+/// assert_eq!(SysLoadAvg::new(12.0, 8.5, 1.125), "12.00, 8.50, 1.13");
+/// ```
+///
+/// ## Errors
+/// [`SysLoadAvg::UNKNOWN`] is returned if any of the inputs are:
+/// - Negative
+/// - Larger than [`SysLoadAvg::MAX`] (`99,999.99`)
+/// - [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`]
+///
+/// ```rust
+/// # use readable::sys::*;
+/// assert!(SysLoadAvg::new(-1.0, 0.0, 0.0).is_unknown());
+/// assert!(SysLoadAvg::new(0.0, f64::NAN, 0.0).is_unknown());
+/// assert!(SysLoadAvg::new(0.0, 0.0, 100_000.0).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<31>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::sys::*;
+/// assert_eq!(std::mem::size_of::<SysLoadAvg>(), 56);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct SysLoadAvg([f64; 3], Str<{ SysLoadAvg::MAX_LEN }>);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl SysLoadAvg {
+    /// The maximum string length of a [`SysLoadAvg`]
+    ///
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::MAX.len(), SysLoadAvg::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 31;
+
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::UNKNOWN, "?.??, ?.??, ?.??");
+    /// assert_eq!(SysLoadAvg::UNKNOWN.inner(), [0.0; 3]);
+    /// ```
+    pub const UNKNOWN: Self = Self([0.0; 3], Str::from_static_str("?.??, ?.??, ?.??"));
+
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::ZERO, "0.00, 0.00, 0.00");
+    /// assert_eq!(SysLoadAvg::ZERO, SysLoadAvg::new(0.0, 0.0, 0.0));
+    /// ```
+    pub const ZERO: Self = Self([0.0; 3], Str::from_static_str("0.00, 0.00, 0.00"));
+
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::MAX, "99,999.99, 99,999.99, 99,999.99");
+    /// assert_eq!(SysLoadAvg::MAX, SysLoadAvg::new(99_999.99, 99_999.99, 99_999.99));
+    /// ```
+    pub const MAX: Self = Self(
+        [99_999.99; 3],
+        Str::from_static_str("99,999.99, 99,999.99, 99,999.99"),
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl SysLoadAvg {
+    #[must_use]
+    /// Create a [`SysLoadAvg`] from the `1`, `5`, and `15` minute load averages
+    ///
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::new(1.0, 2.0, 3.0), "1.00, 2.00, 3.00");
+    /// ```
+    pub fn new(one: f64, five: f64, fifteen: f64) -> Self {
+        let avg = [one, five, fifteen];
+
+        let mut string = Str::new();
+        for (i, f) in avg.into_iter().enumerate() {
+            if !(0.0..=Self::MAX.0[0]).contains(&f) {
+                return Self::UNKNOWN;
+            }
+            if i != 0 {
+                string.push_str_panic(", ");
+            }
+            // Round first so `Float` carries e.g. `0.999` up to `1.00`.
+            string.push_str_panic(Float::from_2((f * 100.0).round() / 100.0));
+        }
+
+        Self(avg, string)
+    }
+
+    #[must_use]
+    /// Get the _current_ system load average
+    ///
+    /// See [`load_avg`](crate::sys::load_avg) for the supported platforms.
+    ///
+    /// [`SysLoadAvg::UNKNOWN`] is returned if the underlying system call fails.
+    pub fn sys_load_avg() -> Self {
+        match crate::sys::load_avg() {
+            Some([one, five, fifteen]) => Self::new(one, five, fifteen),
+            None => Self::UNKNOWN,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the inner `[1, 5, 15]` minute load averages
+    pub const fn inner(&self) -> [f64; 3] {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `1` minute load average
+    pub const fn one(&self) -> f64 {
+        self.0[0]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `5` minute load average
+    pub const fn five(&self) -> f64 {
+        self.0[1]
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `15` minute load average
+    pub const fn fifteen(&self) -> f64 {
+        self.0[2]
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::new(1.0, 2.0, 3.0).as_str(), "1.00, 2.00, 3.00");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.1.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::new(1.0, 2.0, 3.0).as_bytes(), b"1.00, 2.00, 3.00");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.1.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysLoadAvg::new(1.0, 2.0, 3.0).len(), 16);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert!(SysLoadAvg::UNKNOWN.is_unknown());
+    /// assert!(!SysLoadAvg::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?.??, ?.??, ?.??")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for SysLoadAvg {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SysLoadAvg {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for SysLoadAvg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SysLoadAvg")
+            .field("inner", &self.0)
+            .field("str", &self.1.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for SysLoadAvg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for SysLoadAvg {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SysLoadAvg {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<SysLoadAvg> for str {
+    #[inline]
+    fn eq(&self, other: &SysLoadAvg) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<SysLoadAvg> for &str {
+    #[inline]
+    fn eq(&self, other: &SysLoadAvg) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", SysLoadAvg::new(1.0, 2.0, 3.0)),
+            r#"SysLoadAvg { inner: [1.0, 2.0, 3.0], str: "1.00, 2.00, 3.00" }"#
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(SysLoadAvg::new(0.005, 0.0, 0.0), "0.01, 0.00, 0.00");
+        assert_eq!(SysLoadAvg::new(0.999, 1.995, 9.999), "1.00, 2.00, 10.00");
+        assert_eq!(SysLoadAvg::new(1_000.0, 10.0, 0.1), "1,000.00, 10.00, 0.10");
+        assert_eq!(SysLoadAvg::new(1.0, 2.0, 3.0).inner(), [1.0, 2.0, 3.0]);
+        assert!(SysLoadAvg::new(f64::INFINITY, 0.0, 0.0).is_unknown());
+        assert!(SysLoadAvg::new(0.0, 0.0, -0.1).is_unknown());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn sys_load_avg() {
+        assert!(!SysLoadAvg::sys_load_avg().is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = SysLoadAvg::new(1.0, 2.0, 3.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[1.0,2.0,3.0],"1.00, 2.00, 3.00"]"#);
+
+        let this: SysLoadAvg = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "1.00, 2.00, 3.00");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<SysLoadAvg>(&"---").is_err());
+    }
+}
@@ -0,0 +1,266 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::byte::Byte;
+use crate::str::Str;
+
+//---------------------------------------------------------------------------------------------------- SysMem
+/// Human-readable `used / total` system memory
+///
+/// This takes `(used, total)` bytes as input and stores the combined [`Byte`]'s:
+/// ```rust
+/// # use readable::sys::*;
+/// assert_eq!(SysMem::new(3_100_000_000, 16_000_000_000), "3.100 GB / 16.000 GB");
+/// assert_eq!(SysMem::new(512, 1_000),                   "512 B / 1.000 KB");
+/// ```
+///
+/// ## Errors
+/// [`SysMem::UNKNOWN`] is returned if `used` is larger than `total`.
+///
+/// ```rust
+/// # use readable::sys::*;
+/// assert!(SysMem::new(2, 1).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<23>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::sys::*;
+/// assert_eq!(std::mem::size_of::<SysMem>(), 72);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SysMem {
+    used: Byte,
+    total: Byte,
+    string: Str<{ SysMem::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl SysMem {
+    /// The maximum string length of a [`SysMem`]
+    ///
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!("xxx.xxx KB / xxx.xxx KB".len(), SysMem::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 23;
+
+    /// ```rust
+    /// # use readable::sys::*;
+    /// # use readable::byte::Byte;
+    /// assert_eq!(SysMem::UNKNOWN, "???.??? B / ???.??? B");
+    /// assert_eq!(SysMem::UNKNOWN.used(),  Byte::UNKNOWN);
+    /// assert_eq!(SysMem::UNKNOWN.total(), Byte::UNKNOWN);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        used: Byte::UNKNOWN,
+        total: Byte::UNKNOWN,
+        string: Str::from_static_str("???.??? B / ???.??? B"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl SysMem {
+    #[must_use]
+    /// Create a [`SysMem`] from `used` and `total` bytes
+    ///
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysMem::new(1, 2), "1 B / 2 B");
+    /// ```
+    pub fn new(used: u64, total: u64) -> Self {
+        if used > total {
+            return Self::UNKNOWN;
+        }
+
+        let used = Byte::from(used);
+        let total = Byte::from(total);
+
+        let mut string = Str::new();
+        string.push_str_panic(used);
+        string.push_str_panic(" / ");
+        string.push_str_panic(total);
+
+        Self {
+            used,
+            total,
+            string,
+        }
+    }
+
+    #[must_use]
+    /// Get the _current_ system memory usage
+    ///
+    /// See [`mem`](crate::sys::mem) for the supported platforms.
+    ///
+    /// [`SysMem::UNKNOWN`] is returned if the underlying system call fails.
+    pub fn sys_mem() -> Self {
+        match crate::sys::mem() {
+            Some((used, total)) => Self::new(used, total),
+            None => Self::UNKNOWN,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `used` [`Byte`]
+    pub const fn used(&self) -> Byte {
+        self.used
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the `total` [`Byte`]
+    pub const fn total(&self) -> Byte {
+        self.total
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysMem::new(1, 2).as_str(), "1 B / 2 B");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysMem::new(1, 2).as_bytes(), b"1 B / 2 B");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert_eq!(SysMem::new(1, 2).len(), 9);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::sys::*;
+    /// assert!(SysMem::UNKNOWN.is_unknown());
+    /// assert!(!SysMem::new(0, 0).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"???.??? B / ???.??? B")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for SysMem {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SysMem {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for SysMem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SysMem")
+            .field("inner", &(self.used.inner(), self.total.inner()))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for SysMem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for SysMem {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SysMem {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<SysMem> for str {
+    #[inline]
+    fn eq(&self, other: &SysMem) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<SysMem> for &str {
+    #[inline]
+    fn eq(&self, other: &SysMem) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", SysMem::new(512, 1024)),
+            r#"SysMem { inner: (512, 1024), str: "512 B / 1.024 KB" }"#
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(SysMem::new(0, 0), "0 B / 0 B");
+        assert_eq!(SysMem::new(u64::MAX, u64::MAX), "18.446 EB / 18.446 EB");
+        assert_eq!(SysMem::new(999_999, 999_999_999), "999.999 KB / 999.999 MB");
+        assert!(SysMem::new(1, 0).is_unknown());
+
+        let mem = SysMem::new(1_000, 2_000);
+        assert_eq!(mem.used(), 1_000);
+        assert_eq!(mem.total(), 2_000);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn sys_mem() {
+        assert!(!SysMem::sys_mem().is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = SysMem::new(1, 2);
+        let json = serde_json::to_string(&this).unwrap();
+        let this: SysMem = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "1 B / 2 B");
+        assert_eq!(this.used(), 1);
+        assert_eq!(this.total(), 2);
+    }
+}
@@ -0,0 +1,45 @@
+//! System information formatting
+//!
+//! Human-readable snapshots of the live system state, e.g:
+//! ```rust
+//! # use readable::sys::*;
+//! assert_eq!(SysLoadAvg::new(0.52, 0.48, 0.4), "0.52, 0.48, 0.40");
+//! assert_eq!(SysMem::new(3_100_000_000, 16_000_000_000), "3.100 GB / 16.000 GB");
+//! ```
+//!
+//! ## Live system state
+//! The `sys_*()` functions read the _current_ system state:
+//!
+//! ```rust
+//! # use readable::sys::*;
+//! let load_avg = SysLoadAvg::sys_load_avg();
+//! let mem = SysMem::sys_mem();
+//! let cpus = cpu_count();
+//! # // Get around CI.
+//! # let cpus = 1;
+//! assert!(cpus >= 1);
+//! ```
+//!
+//! If reading the system state fails, or the platform does not support it, an `UNKNOWN` is returned.
+//!
+//! | Platform | [`SysLoadAvg`] | [`SysMem`] | [`cpu_count`] |
+//! |----------|----------------|------------|---------------|
+//! | Linux    | ✅             | ✅         | ✅
+//! | macOS    | ✅             | ❌         | ✅
+//! | BSDs     | ✅             | ❌         | ✅
+//! | Windows  | ❌             | ✅         | ✅
+//!
+//! ## Copy
+//! [`Copy`] is available.
+//!
+//! The actual strings used internally are not [`String`](https://doc.rust-lang.org/std/string/struct.String.html)'s,
+//! but byte array buffer(s). See the specific type for more details.
+
+mod free;
+pub use free::*;
+
+mod load_avg;
+pub use load_avg::*;
+
+mod mem;
+pub use mem::*;
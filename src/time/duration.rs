@@ -0,0 +1,123 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::time::{Military, Time, TimeUnit};
+use std::ops::{Add, Sub};
+use std::time::Duration;
+
+//---------------------------------------------------------------------------------------------------- Clock
+// The whole seconds in a `Duration`, without the full days.
+const fn secs_of_day(duration: Duration) -> u64 {
+    duration.as_secs() % 86400
+}
+
+// Wrap `secs` (at most 2 days) around the day.
+const fn wrap(secs: u64) -> u32 {
+    (secs % 86400) as u32
+}
+
+// Implements `Add<Duration>` and `Sub<Duration>` for the clock types.
+//
+// These wrap around the day (like the clock itself does),
+// sub-second precision in the `Duration` is ignored.
+macro_rules! impl_clock {
+	($($s:ty),* $(,)?) => { $(
+		impl Add<Duration> for $s {
+			type Output = Self;
+			#[inline]
+			/// Move the clock forwards, wrapping around the day.
+			///
+			/// [`Self::UNKNOWN`] is returned as-is.
+			fn add(self, duration: Duration) -> Self {
+				if self.is_unknown() {
+					return Self::UNKNOWN;
+				}
+				Self::new(wrap(u64::from(self.inner()) + secs_of_day(duration)))
+			}
+		}
+
+		impl Sub<Duration> for $s {
+			type Output = Self;
+			#[inline]
+			/// Move the clock backwards, wrapping around the day.
+			///
+			/// [`Self::UNKNOWN`] is returned as-is.
+			fn sub(self, duration: Duration) -> Self {
+				if self.is_unknown() {
+					return Self::UNKNOWN;
+				}
+				Self::new(wrap(u64::from(self.inner()) + 86400 - secs_of_day(duration)))
+			}
+		}
+	)*}
+}
+impl_clock!(Time, Military);
+
+//---------------------------------------------------------------------------------------------------- TimeUnit
+impl Add<Duration> for TimeUnit {
+    type Output = Self;
+    #[inline]
+    /// Add the whole seconds of a [`Duration`].
+    ///
+    /// [`Self::UNKNOWN`] is returned if the input is unknown, or the result is over [`u32::MAX`].
+    fn add(self, duration: Duration) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from(Duration::from(self).saturating_add(duration))
+    }
+}
+
+impl Sub<Duration> for TimeUnit {
+    type Output = Self;
+    #[inline]
+    /// Subtract the whole seconds of a [`Duration`], saturating at `0`.
+    ///
+    /// [`Self::UNKNOWN`] is returned if the input is unknown.
+    fn sub(self, duration: Duration) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from(Duration::from(self).saturating_sub(duration))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock() {
+        let secs = [0, 1, 59, 3600, 43_199, 86_399, 86_400, 1_000_000, u64::MAX];
+        for start in [0, 1, 43_200, 86_399] {
+            for s in secs {
+                let d = Duration::from_secs(s);
+                let expected = ((u64::from(start) + s % 86400) % 86400) as u32;
+                assert_eq!(Military::new(start) + d, Military::new(expected));
+                assert_eq!(Time::new(start) + d, Time::new(expected));
+
+                // Going back undoes going forwards.
+                assert_eq!(Military::new(start) + d - d, Military::new(start));
+                assert_eq!(Time::new(start) - d + d, Time::new(start));
+            }
+        }
+
+        assert_eq!(Military::new(30) - Duration::from_secs(60), "23:59:30");
+        assert_eq!(Military::new(30) + Duration::from_millis(999), "00:00:30");
+        assert!((Time::UNKNOWN + Duration::from_secs(1)).is_unknown());
+        assert!((Military::UNKNOWN - Duration::from_secs(1)).is_unknown());
+    }
+
+    #[test]
+    fn time_unit() {
+        let unit = TimeUnit::new(60);
+        assert_eq!(unit + Duration::from_secs(30), TimeUnit::new(90));
+        assert_eq!(unit - Duration::from_secs(30), TimeUnit::new(30));
+        assert_eq!(unit - Duration::from_secs(90), TimeUnit::new(0));
+        assert_eq!(
+            TimeUnit::new(u32::MAX - 1) + Duration::from_secs(1),
+            TimeUnit::new(u32::MAX)
+        );
+        assert!((TimeUnit::new(u32::MAX) + Duration::from_secs(1)).is_unknown());
+        assert!((TimeUnit::UNKNOWN + Duration::from_secs(1)).is_unknown());
+    }
+}
@@ -0,0 +1,175 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::time::{secs_to_clock, Military, Time};
+
+//---------------------------------------------------------------------------------------------------- chrono
+// Implements `From<chrono::NaiveTime>` and the reverse `TryFrom`.
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono {
+	($($time:ty),*) => {
+		$(
+			impl From<chrono::NaiveTime> for $time {
+				#[inline]
+				/// Leap seconds and fractional seconds are ignored.
+				fn from(time: chrono::NaiveTime) -> Self {
+					use chrono::Timelike;
+					Self::new(time.num_seconds_from_midnight())
+				}
+			}
+
+			impl TryFrom<$time> for chrono::NaiveTime {
+				type Error = $time;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is unknown.
+				fn try_from(time: $time) -> Result<Self, Self::Error> {
+					if time.is_unknown() {
+						return Err(time);
+					}
+					Self::from_num_seconds_from_midnight_opt(time.inner(), 0).ok_or(time)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "chrono")]
+impl_chrono!(Time, Military);
+
+//---------------------------------------------------------------------------------------------------- time
+// Implements `From<time::Time>` and the reverse `TryFrom`.
+#[cfg(feature = "time-crate")]
+macro_rules! impl_time {
+	($($time:ty),*) => {
+		$(
+			impl From<::time::Time> for $time {
+				#[inline]
+				/// Fractional seconds are ignored.
+				fn from(time: ::time::Time) -> Self {
+					Self::new_specified(time.hour(), time.minute(), time.second())
+				}
+			}
+
+			impl TryFrom<$time> for ::time::Time {
+				type Error = $time;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is unknown.
+				fn try_from(time: $time) -> Result<Self, Self::Error> {
+					if time.is_unknown() {
+						return Err(time);
+					}
+					let (h, m, s) = secs_to_clock(time.inner());
+					#[allow(clippy::map_err_ignore)]
+					Self::from_hms(h, m, s).map_err(|_| time)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "time-crate")]
+impl_time!(Time, Military);
+
+//---------------------------------------------------------------------------------------------------- jiff
+// Implements `From<jiff::civil::{Time, DateTime}>` and the reverse `TryFrom`.
+#[cfg(feature = "jiff")]
+macro_rules! impl_jiff {
+	($($time:ty),*) => {
+		$(
+			impl From<jiff::civil::Time> for $time {
+				#[inline]
+				/// Fractional seconds are ignored.
+				fn from(time: jiff::civil::Time) -> Self {
+					// `jiff` clock values are always positive.
+					Self::new_specified(time.hour() as u8, time.minute() as u8, time.second() as u8)
+				}
+			}
+
+			impl From<jiff::civil::DateTime> for $time {
+				#[inline]
+				/// The date is ignored.
+				fn from(datetime: jiff::civil::DateTime) -> Self {
+					Self::from(datetime.time())
+				}
+			}
+
+			impl TryFrom<$time> for jiff::civil::Time {
+				type Error = $time;
+				#[inline]
+				/// Returns the input as the [`Err`] if it is unknown.
+				fn try_from(time: $time) -> Result<Self, Self::Error> {
+					if time.is_unknown() {
+						return Err(time);
+					}
+					let (h, m, s) = secs_to_clock(time.inner());
+					let (Ok(h), Ok(m), Ok(s)) = (i8::try_from(h), i8::try_from(m), i8::try_from(s)) else {
+						return Err(time);
+					};
+					#[allow(clippy::map_err_ignore)]
+					Self::new(h, m, s, 0).map_err(|_| time)
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "jiff")]
+impl_jiff!(Time, Military);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        let naive = chrono::NaiveTime::from_hms_opt(13, 21, 55).unwrap();
+        assert_eq!(Time::from(naive), "1:21:55 PM");
+        assert_eq!(Military::from(naive), "13:21:55");
+
+        assert_eq!(chrono::NaiveTime::try_from(Time::from(naive)), Ok(naive));
+        assert_eq!(
+            chrono::NaiveTime::try_from(Military::from(naive)),
+            Ok(naive)
+        );
+
+        // Leap second.
+        let naive = chrono::NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert_eq!(Military::from(naive), "23:59:59");
+
+        assert!(chrono::NaiveTime::try_from(Time::UNKNOWN).is_err());
+        assert!(chrono::NaiveTime::try_from(Military::UNKNOWN).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "time-crate")]
+    fn time() {
+        let time = ::time::Time::from_hms(13, 21, 55).unwrap();
+        assert_eq!(Time::from(time), "1:21:55 PM");
+        assert_eq!(Military::from(time), "13:21:55");
+
+        assert_eq!(::time::Time::try_from(Time::from(time)), Ok(time));
+        assert_eq!(::time::Time::try_from(Military::from(time)), Ok(time));
+
+        assert!(::time::Time::try_from(Time::UNKNOWN).is_err());
+        assert!(::time::Time::try_from(Military::UNKNOWN).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "jiff")]
+    fn jiff() {
+        let time = jiff::civil::time(13, 21, 55, 0);
+        assert_eq!(Time::from(time), "1:21:55 PM");
+        assert_eq!(Military::from(time), "13:21:55");
+        assert_eq!(
+            Military::from(jiff::civil::date(2020, 1, 1).to_datetime(time)),
+            "13:21:55"
+        );
+
+        assert_eq!(jiff::civil::Time::try_from(Time::from(time)), Ok(time));
+        assert_eq!(jiff::civil::Time::try_from(Military::from(time)), Ok(time));
+
+        // Fractional seconds.
+        let time = jiff::civil::time(23, 59, 59, 999_999_999);
+        assert_eq!(Military::from(time), "23:59:59");
+
+        assert!(jiff::civil::Time::try_from(Time::UNKNOWN).is_err());
+        assert!(jiff::civil::Time::try_from(Military::UNKNOWN).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::time::{Military, Time};
+use std::time::Instant;
+
+//---------------------------------------------------------------------------------------------------- Free
+/// Read the system clock, returning the seconds passed in the
+/// current day and the [`Instant`] the current second started at.
+fn now() -> (Instant, u32) {
+    use chrono::Timelike;
+    let now = chrono::offset::Local::now().time();
+    let instant = Instant::now();
+    // Count from the start of the second so ticks line up with the system clock.
+    // `chrono` represents leap seconds as `>= 1_000_000_000` nanoseconds.
+    let nanos = std::time::Duration::from_nanos(u64::from(now.nanosecond() % 1_000_000_000));
+    (
+        instant.checked_sub(nanos).unwrap_or(instant),
+        now.num_seconds_from_midnight(),
+    )
+}
+
+//---------------------------------------------------------------------------------------------------- Live
+// Implements a live, cached clock for a `time` type.
+macro_rules! impl_live {
+	($(
+		$live:ident => $time:ident, $getter:ident, $example:literal
+	),* $(,)?) => {
+		$(
+			#[doc = concat!("Live, cached [`", stringify!($time), "`] of the current system clock time")]
+			///
+			/// This reads the system clock once on creation and then keeps
+			/// counting with a monotonic [`Instant`], so
+			#[doc = concat!("[`", stringify!($live), "::refresh`] is cheap enough to call every frame.")]
+			///
+			/// The string is only re-formatted when the second changes.
+			///
+			/// ```rust
+			/// # use readable::time::*;
+			#[doc = concat!("let mut clock = ", stringify!($live), "::new();")]
+			///
+			/// // In a render loop...
+			/// if clock.refresh() {
+			///     println!("{clock}");
+			/// }
+			///
+			#[doc = concat!("assert_eq!(clock.", stringify!($getter), "(), ", stringify!($time), "::new(clock.inner()));")]
+			/// ```
+			///
+			/// ## Syncing
+			/// The system clock is re-read at most once a minute (on the first
+			#[doc = concat!("[`", stringify!($live), "::refresh`] after a minute has passed), or when calling [`", stringify!($live), "::sync`].")]
+			///
+			/// This picks up changes to the system clock or timezone, e.g. daylight saving time.
+			///
+			/// ## Formatting
+			#[doc = concat!("The formatting is the same as [`", stringify!($time), "`], e.g. `", $example, "`.")]
+			#[derive(Copy, Clone, Debug)]
+			pub struct $live {
+				/// When the system clock was last read.
+				synced_at: Instant,
+				/// The system clock seconds at `synced_at`.
+				synced: u32,
+				$getter: $time,
+			}
+
+			impl $live {
+				#[inline]
+				#[must_use]
+				/// Read the current system clock time
+				pub fn new() -> Self {
+					let (synced_at, synced) = now();
+					Self {
+						synced_at,
+						synced,
+						$getter: $time::priv_from(synced),
+					}
+				}
+
+				/// Bring the clock up to date
+				///
+				/// This returns `true` if the string changed.
+				///
+				/// If still within the same second, this does nothing.
+				pub fn refresh(&mut self) -> bool {
+					let elapsed = self.synced_at.elapsed().as_secs();
+					if elapsed >= 60 {
+						return self.sync();
+					}
+
+					// `elapsed` is less than `60`.
+					#[allow(clippy::cast_possible_truncation)]
+					self.update((self.synced + elapsed as u32) % 86400)
+				}
+
+				/// Re-read the system clock
+				///
+				/// This returns `true` if the string changed.
+				pub fn sync(&mut self) -> bool {
+					(self.synced_at, self.synced) = now();
+					self.update(self.synced)
+				}
+
+				#[inline]
+				#[must_use]
+				/// Return the inner seconds as of the last refresh
+				pub const fn inner(&self) -> u32 {
+					self.$getter.inner()
+				}
+
+				#[inline]
+				#[must_use]
+				#[doc = concat!("Return the [`", stringify!($time), "`] as of the last refresh")]
+				pub const fn $getter(&self) -> $time {
+					self.$getter
+				}
+
+				#[inline]
+				#[must_use]
+				/// ```rust
+				/// # use readable::time::*;
+				#[doc = concat!("let clock = ", stringify!($live), "::new();")]
+				#[doc = concat!("assert_eq!(clock.as_str(), ", stringify!($time), "::new(clock.inner()).as_str());")]
+				/// ```
+				pub const fn as_str(&self) -> &str {
+					self.$getter.as_str()
+				}
+
+				/// Set the clock to `secs`, re-formatting only if it changed.
+				fn update(&mut self, secs: u32) -> bool {
+					if secs == self.$getter.inner() {
+						false
+					} else {
+						self.$getter = $time::priv_from(secs);
+						true
+					}
+				}
+			}
+
+			impl From<$live> for $time {
+				#[inline]
+				fn from(live: $live) -> Self {
+					live.$getter
+				}
+			}
+
+			impl From<&$live> for $time {
+				#[inline]
+				fn from(live: &$live) -> Self {
+					live.$getter
+				}
+			}
+
+			impl std::ops::Deref for $live {
+				type Target = str;
+
+				#[inline]
+				fn deref(&self) -> &Self::Target {
+					self.as_str()
+				}
+			}
+
+			impl AsRef<str> for $live {
+				#[inline]
+				fn as_ref(&self) -> &str {
+					self.as_str()
+				}
+			}
+
+			impl std::fmt::Display for $live {
+				fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+					crate::str::pad(f, self.as_str())
+				}
+			}
+
+			impl PartialEq<str> for $live {
+				#[inline]
+				fn eq(&self, other: &str) -> bool {
+					self.as_str() == other
+				}
+			}
+
+			impl PartialEq<&str> for $live {
+				#[inline]
+				fn eq(&self, other: &&str) -> bool {
+					self.as_str() == *other
+				}
+			}
+
+			impl PartialEq<$live> for str {
+				#[inline]
+				fn eq(&self, other: &$live) -> bool {
+					self == other.as_str()
+				}
+			}
+
+			impl PartialEq<$live> for &str {
+				#[inline]
+				fn eq(&self, other: &$live) -> bool {
+					*self == other.as_str()
+				}
+			}
+		)*
+	};
+}
+impl_live! {
+    ClockLive => Time, time, "11:59:59 PM",
+    MilitaryLive => Military, military, "23:59:59",
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update() {
+        let mut clock = ClockLive::new();
+        let mut military = MilitaryLive::new();
+
+        for secs in 0..86400 {
+            clock.update(secs);
+            military.update(secs);
+            assert_eq!(clock.time(), Time::new(secs));
+            assert_eq!(military.military(), Military::new(secs));
+        }
+
+        assert!(!clock.update(86399));
+        assert!(clock.update(0));
+        assert_eq!(clock, "12:00:00 AM");
+        assert!(!military.update(86399));
+        assert!(military.update(0));
+        assert_eq!(military, "00:00:00");
+    }
+
+    #[test]
+    fn refresh() {
+        let mut clock = MilitaryLive::new();
+        let before = clock.inner();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        assert!(clock.refresh());
+        assert_ne!(clock.inner(), before);
+        assert_eq!(clock, Military::new(clock.inner()).as_str());
+    }
+}
@@ -0,0 +1,156 @@
+//---------------------------------------------------------------------------------------------------- Meridiem
+/// `AM` or `PM`
+///
+/// This is the meridiem shown by [`Time`](crate::time::Time), returned
+/// by `Time::meridiem()` and `Military::meridiem()` alongside their
+/// `hour12()`, so custom 12-hour layouts can be built from the parts:
+///
+/// ```rust
+/// # use readable::time::*;
+/// let time = Time::from_hms(21, 5, 0).unwrap();
+/// assert_eq!(time, "9:05:00 PM");
+///
+/// assert_eq!(time.hour12(), 9);
+/// assert_eq!(time.meridiem(), Meridiem::Pm);
+///
+/// // e.g. a big hour with a small `pm`.
+/// let lock_screen = format!("{}:{:02} {}", time.hour12(), time.minute(), time.meridiem().as_str_lower());
+/// assert_eq!(lock_screen, "9:05 pm");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Meridiem {
+    /// Ante meridiem, hours `0..=11`
+    Am,
+    /// Post meridiem, hours `12..=23`
+    Pm,
+}
+
+impl Meridiem {
+    #[inline]
+    #[must_use]
+    /// The meridiem of an hour of the day (`0..=23`)
+    ///
+    /// Hours over `23` wrap around the day.
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Meridiem::from_hour(0),  Meridiem::Am);
+    /// assert_eq!(Meridiem::from_hour(11), Meridiem::Am);
+    /// assert_eq!(Meridiem::from_hour(12), Meridiem::Pm);
+    /// assert_eq!(Meridiem::from_hour(23), Meridiem::Pm);
+    /// assert_eq!(Meridiem::from_hour(24), Meridiem::Am);
+    /// ```
+    pub const fn from_hour(hour: u8) -> Self {
+        if hour % 24 > 11 {
+            Self::Pm
+        } else {
+            Self::Am
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// `AM` or `PM`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Am => "AM",
+            Self::Pm => "PM",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// `am` or `pm`
+    pub const fn as_str_lower(self) -> &'static str {
+        match self {
+            Self::Am => "am",
+            Self::Pm => "pm",
+        }
+    }
+}
+
+impl std::fmt::Display for Meridiem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Hour12
+// Implements `hour12()` and `meridiem()` for the clock types.
+macro_rules! impl_hour12 {
+	($($s:ident => $example:literal),* $(,)?) => { $(
+		impl crate::time::$s {
+			#[inline]
+			#[must_use]
+			/// The hour on a 12-hour clock, `1..=12`
+			///
+			/// This is `12` for midnight and noon, use with [`Self::meridiem`].
+			///
+			/// ```rust
+			/// # use readable::time::*;
+			#[doc = concat!("let time = ", stringify!($s), "::from_hms(0, 30, 0).unwrap();")]
+			/// assert_eq!(time.hour12(), 12);
+			///
+			#[doc = concat!("let time = ", stringify!($s), "::from_hms(21, 5, 0).unwrap();")]
+			#[doc = concat!("assert_eq!(time, \"", $example, "\");")]
+			/// assert_eq!(time.hour12(), 9);
+			/// ```
+			pub const fn hour12(&self) -> u8 {
+				match self.hour() % 12 {
+					0 => 12,
+					hour => hour,
+				}
+			}
+
+			#[inline]
+			#[must_use]
+			/// Whether this is before ([`Meridiem::Am`]) or after ([`Meridiem::Pm`]) noon
+			///
+			/// ```rust
+			/// # use readable::time::*;
+			#[doc = concat!("assert_eq!(", stringify!($s), "::from_hms(11, 59, 59).unwrap().meridiem(), Meridiem::Am);")]
+			#[doc = concat!("assert_eq!(", stringify!($s), "::from_hms(12, 0, 0).unwrap().meridiem(),   Meridiem::Pm);")]
+			/// ```
+			pub const fn meridiem(&self) -> Meridiem {
+				Meridiem::from_hour(self.hour())
+			}
+		}
+	)*};
+}
+impl_hour12! {
+    Time => "9:05:00 PM",
+    Military => "21:05:00",
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use crate::time::{Military, Time};
+
+    #[test]
+    fn all_secs() {
+        for secs in 0..86400 {
+            let time = Time::from(secs);
+            let military = Military::from(secs);
+            assert_eq!(time.hour12(), military.hour12());
+            assert_eq!(time.meridiem(), military.meridiem());
+
+            // `Time`'s string is built from the same parts.
+            let expected = format!(
+                "{}:{:02}:{:02} {}",
+                time.hour12(),
+                time.minute(),
+                time.second(),
+                time.meridiem()
+            );
+            assert_eq!(time, expected.as_str());
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::macros::{
-    handle_over_u32, impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize,
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_math, impl_traits, impl_usize,
 };
 #[cfg(feature = "num")]
 use crate::num::Unsigned;
@@ -25,6 +26,16 @@ use crate::time::{Time, TimeUnit};
 /// assert_eq!(Military::from(86401), "00:00:01");
 /// ```
 ///
+/// Adding or subtracting a [`std::time::Duration`] also wraps around the day:
+/// ```rust
+/// # use readable::time::*;
+/// # use std::time::Duration;
+/// let time = Military::from(30);
+/// assert_eq!(time, "00:00:30");
+/// assert_eq!(time - Duration::from_secs(60), "23:59:30");
+/// assert_eq!(time + Duration::from_secs(86400), time);
+/// ```
+///
 /// ## Size
 /// [`Str<7>`] is used internally to represent the string.
 ///
@@ -76,11 +87,12 @@ use crate::time::{Time, TimeUnit};
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct Military(pub(super) u32, pub(super) Str<{ Military::MAX_LEN }>);
 
 impl_traits!(Military, u32);
 impl_math!(Military, u32);
+impl_from_option!(Military);
 
 //---------------------------------------------------------------------------------------------------- Military Constants
 impl Military {
@@ -117,6 +129,7 @@ impl Military {
 impl Military {
     impl_common!(u32);
     impl_const!();
+    impl_format_slice!(time, Military, [0_u32, 65, 43_200, 86_399]);
     impl_usize!();
 
     #[inline]
@@ -169,6 +182,98 @@ impl Military {
         Self::priv_from((seconds as u32) + (minutes as u32 * 60) + (hours as u32 * 3600))
     }
 
+    #[inline]
+    /// Create a [`Self`] from an `hour`, `minute`, and `second` of the day
+    ///
+    /// Unlike [`Military::new_specified`], this does not wrap.
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(23, 59, 59).unwrap(), "23:59:59");
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap(),  "13:30:05");
+    /// ```
+    ///
+    /// ## Errors
+    /// [`crate::Error::TooLarge`] is returned if `hour` is over `23`,
+    /// or `minute`/`second` is over `59`.
+    ///
+    /// ```rust
+    /// # use readable::{time::*,Error};
+    /// assert_eq!(Military::from_hms(24, 0, 0),  Err(Error::TooLarge));
+    /// assert_eq!(Military::from_hms(0, 60, 0),  Err(Error::TooLarge));
+    /// assert_eq!(Military::from_hms(0, 0, 60),  Err(Error::TooLarge));
+    /// ```
+    pub const fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self, crate::Error> {
+        if hour > 23 || minute > 59 || second > 59 {
+            Err(crate::Error::TooLarge)
+        } else {
+            Ok(Self::new_specified(hour, minute, second))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The hour of the day, `0..=23`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap().hour(), 13);
+    /// ```
+    pub const fn hour(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).0
+    }
+
+    #[inline]
+    #[must_use]
+    /// The minute of the hour, `0..=59`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap().minute(), 30);
+    /// ```
+    pub const fn minute(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).1
+    }
+
+    #[inline]
+    #[must_use]
+    /// The second of the minute, `0..=59`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap().second(), 5);
+    /// ```
+    pub const fn second(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).2
+    }
+
+    #[inline]
+    #[must_use]
+    /// The seconds past midnight, `0..=86399`
+    ///
+    /// This is the same as [`Military::inner`].
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap().seconds_past_midnight(), 48605);
+    /// assert_eq!(Military::from(86400 + 1).seconds_past_midnight(), 1);
+    /// ```
+    pub const fn seconds_past_midnight(&self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// The minutes past midnight, `0..=1439`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Military::from_hms(13, 30, 5).unwrap().minutes_past_midnight(), 810);
+    /// ```
+    pub const fn minutes_past_midnight(&self) -> u16 {
+        (self.0 / 60) as u16
+    }
+
     #[inline]
     #[must_use]
     /// ```rust
@@ -385,6 +490,18 @@ impl From<&Military> for std::time::Duration {
 mod tests {
     use super::*;
 
+    #[test]
+    fn hms() {
+        for secs in 0..86400 {
+            let (h, m, s) = crate::time::secs_to_clock(secs);
+            let this = Military::from_hms(h, m, s).unwrap();
+            assert_eq!(this, Military::from(secs));
+            assert_eq!((this.hour(), this.minute(), this.second()), (h, m, s));
+            assert_eq!(this.seconds_past_midnight(), secs);
+            assert_eq!(u32::from(this.minutes_past_midnight()), secs / 60);
+        }
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
@@ -40,6 +40,40 @@
 //!
 //! Only the types within `readable::time` implement this trait.
 //!
+//! [`Time::now`] and [`Military::now`] do the same without the trait import.
+//!
+//! For render loops, [`ClockLive`] and [`MilitaryLive`] cache the formatted
+//! string and only re-format it when the second changes, e.g:
+//!
+//! ```rust
+//! # use readable::time::*;
+//! let mut clock = MilitaryLive::new();
+//!
+//! // Every frame...
+//! if clock.refresh() {
+//!     println!("{clock}");
+//! }
+//! ```
+//!
+//! ## 12-hour parts
+//! `hour12()` and [`Meridiem`] split [`Time`] (and [`Military`]) into the parts of a 12-hour clock:
+//!
+//! ```rust
+//! # use readable::time::*;
+//! let time = Time::from(86399);
+//! assert_eq!((time.hour12(), time.meridiem()), (11, Meridiem::Pm));
+//! ```
+//!
+//! ## Spans
+//! [`TimeSpan`] is the span between two clock times, wrapping across midnight if needed:
+//!
+//! ```rust
+//! # use readable::time::*;
+//! let span = TimeSpan::between(Military::from(82800), Military::from(3600));
+//! assert_eq!(span.inner(), 7200);
+//! assert!(span.wraps());
+//! ```
+//!
 //! ## From other [`Time`] types
 //! All types in this module support lossless conversion with each other using [`From`].
 //!
@@ -59,6 +93,38 @@
 //! assert_eq!(Military::from(Time::UNKNOWN), Military::UNKNOWN);
 //! ```
 //!
+//! ## `chrono` and `time`
+//! With the `chrono` and `time-crate` features, [`Time`] and [`Military`] implement:
+//! - [`From`] [`chrono::NaiveTime`](https://docs.rs/chrono/latest/chrono/struct.NaiveTime.html) and [`time::Time`](https://docs.rs/time/latest/time/struct.Time.html)
+//! - [`TryFrom`] for the reverse, returning the input as the [`Err`] if it is unknown
+//!
+//! Fractional and leap seconds are ignored.
+//!
+//! ```rust
+//! # use readable::time::*;
+//! # #[cfg(feature = "chrono")] {
+//! let naive = chrono::NaiveTime::from_hms_opt(13, 21, 55).unwrap();
+//! let time  = Time::from(naive);
+//! assert_eq!(time, "1:21:55 PM");
+//! assert_eq!(chrono::NaiveTime::try_from(time), Ok(naive));
+//! # }
+//! ```
+//!
+//! ## `jiff`
+//! With the `jiff` feature, [`Time`] and [`Military`] implement:
+//! - [`From`] [`jiff::civil::Time`](https://docs.rs/jiff/latest/jiff/civil/struct.Time.html) and [`jiff::civil::DateTime`](https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html) (the date is ignored)
+//! - [`TryFrom`] for the reverse into [`jiff::civil::Time`](https://docs.rs/jiff/latest/jiff/civil/struct.Time.html), returning the input as the [`Err`] if it is unknown
+//!
+//! ```rust
+//! # use readable::time::*;
+//! # #[cfg(feature = "jiff")] {
+//! let civil = jiff::civil::time(13, 21, 55, 0);
+//! let time  = Military::from(civil);
+//! assert_eq!(time, "13:21:55");
+//! assert_eq!(jiff::civil::Time::try_from(time), Ok(civil));
+//! # }
+//! ```
+//!
 //! ## Copy
 //! [`Copy`] is available.
 //!
@@ -111,5 +177,19 @@ pub use time::*;
 mod military;
 pub use military::*;
 
+mod meridiem;
+pub use meridiem::*;
+
 mod systime;
 pub use systime::*;
+
+mod live;
+pub use live::*;
+
+mod span;
+pub use span::*;
+
+mod duration;
+
+#[cfg(any(feature = "chrono", feature = "time-crate", feature = "jiff"))]
+mod interop;
@@ -0,0 +1,294 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::time::Military;
+
+#[cfg(feature = "run")]
+use crate::run::Runtime;
+#[cfg(feature = "up")]
+use crate::up::Uptime;
+
+/// Seconds in a day.
+const DAY: u32 = 86400;
+
+//---------------------------------------------------------------------------------------------------- TimeSpan
+/// The span between two clock times
+///
+/// Like [`TimeUnit`](crate::time::TimeUnit), this is not a string;
+/// it is a utility type that takes a `start` and `end` clock time
+/// and calculates the seconds in-between them.
+///
+/// Clock times have no date, so an `end` before the `start` is
+/// assumed to be on the next day, i.e. the span crosses midnight.
+///
+/// ```rust
+/// # use readable::time::*;
+/// // 09:00:00 -> 17:30:00
+/// let span = TimeSpan::between(Military::new_specified(9, 0, 0), Military::new_specified(17, 30, 0));
+/// assert_eq!(span.inner(), 30600);
+/// assert!(!span.wraps());
+///
+/// // 22:00:00 -> 06:15:00, crossing midnight.
+/// let span = TimeSpan::between(Military::new_specified(22, 0, 0), Military::new_specified(6, 15, 0));
+/// assert_eq!(span.inner(), 29700);
+/// assert!(span.wraps());
+///
+/// // `Time` works too.
+/// let span = TimeSpan::between(Time::from(82800), Time::from(3600));
+/// assert_eq!(span.start(), "23:00:00");
+/// assert_eq!(span.end(),   "01:00:00");
+/// assert_eq!(span.inner(), 7200);
+/// ```
+///
+/// Equal times are a span of `0`, not a full day.
+///
+/// ```rust
+/// # use readable::time::*;
+/// let span = TimeSpan::between(Military::from(3600), Military::from(3600));
+/// assert_eq!(span.inner(), 0);
+/// ```
+///
+/// ## Formatting
+/// The span can be formatted with [`TimeSpan::runtime`] (`run` feature)
+/// or [`TimeSpan::uptime`] (`up` feature).
+///
+/// ```rust
+/// # use readable::time::*;
+/// let span = TimeSpan::between(Military::new_specified(22, 0, 0), Military::new_specified(6, 15, 30));
+/// # #[cfg(feature = "run")]
+/// assert_eq!(span.runtime(), "8:15:30");
+/// # #[cfg(feature = "up")]
+/// assert_eq!(span.uptime(), "8h, 15m, 30s");
+/// ```
+///
+/// ## Unknown
+/// If either input is unknown, [`TimeSpan::UNKNOWN`] is returned,
+/// which formats into [`Runtime::UNKNOWN`](crate::run::Runtime::UNKNOWN)
+/// and [`Uptime::UNKNOWN`](crate::up::Uptime::UNKNOWN).
+///
+/// ```rust
+/// # use readable::time::*;
+/// let span = TimeSpan::between(Time::UNKNOWN, Military::from(3600));
+/// assert!(span.is_unknown());
+/// # #[cfg(feature = "run")]
+/// assert!(span.runtime().is_unknown());
+/// ```
+///
+/// ## Size
+/// ```rust
+/// # use readable::time::*;
+/// assert_eq!(std::mem::size_of::<TimeSpan>(), 12);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TimeSpan {
+    unknown: bool,
+    start: u32,
+    end: u32,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl TimeSpan {
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(TimeSpan::UNKNOWN.inner(), 0);
+    /// assert!(TimeSpan::UNKNOWN.start().is_unknown());
+    /// assert!(TimeSpan::UNKNOWN.is_unknown());
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        unknown: true,
+        start: 0,
+        end: 0,
+    };
+
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(TimeSpan::ZERO.inner(), 0);
+    /// assert_eq!(TimeSpan::ZERO, TimeSpan::new(0, 0));
+    /// assert!(!TimeSpan::ZERO.is_unknown());
+    /// ```
+    pub const ZERO: Self = Self {
+        unknown: false,
+        start: 0,
+        end: 0,
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Impl
+impl TimeSpan {
+    #[inline]
+    #[must_use]
+    /// Create a [`TimeSpan`] from the seconds passed in a day for `start` and `end`
+    ///
+    /// Inputs wrap around like [`Military::new`], so `86400` is `00:00:00`.
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(TimeSpan::new(3600, 7200).inner(), 3600);
+    /// assert_eq!(TimeSpan::new(7200, 3600).inner(), 82800);
+    /// assert_eq!(TimeSpan::new(86400, 3600).inner(), 3600);
+    /// ```
+    pub const fn new(start: u32, end: u32) -> Self {
+        Self {
+            unknown: false,
+            start: start % DAY,
+            end: end % DAY,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a [`TimeSpan`] between two clock times
+    ///
+    /// Anything that converts into a [`Military`] can be used, e.g. [`Time`](crate::time::Time).
+    ///
+    /// If either input is unknown, [`TimeSpan::UNKNOWN`] is returned.
+    pub fn between(start: impl Into<Military>, end: impl Into<Military>) -> Self {
+        let (start, end) = (start.into(), end.into());
+        if start.is_unknown() || end.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::new(start.inner(), end.inner())
+    }
+
+    #[inline]
+    #[must_use]
+    /// The total seconds in-between the start and end
+    ///
+    /// This is always less than `86400`.
+    pub const fn inner(&self) -> u32 {
+        if self.end >= self.start {
+            self.end - self.start
+        } else {
+            DAY - self.start + self.end
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The start clock time
+    pub const fn start(&self) -> Military {
+        if self.unknown {
+            return Military::UNKNOWN;
+        }
+        Military::new(self.start)
+    }
+
+    #[inline]
+    #[must_use]
+    /// The end clock time
+    pub const fn end(&self) -> Military {
+        if self.unknown {
+            return Military::UNKNOWN;
+        }
+        Military::new(self.end)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the span crosses midnight, i.e. the end is before the start
+    pub const fn wraps(&self) -> bool {
+        self.end < self.start
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert!(TimeSpan::UNKNOWN.is_unknown());
+    /// assert!(!TimeSpan::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.unknown
+    }
+
+    #[cfg(feature = "run")]
+    #[inline]
+    #[must_use]
+    /// Format the span as a [`Runtime`], e.g. `8:15:30`
+    pub fn runtime(&self) -> Runtime {
+        if self.unknown {
+            return Runtime::UNKNOWN;
+        }
+        Runtime::from(self.inner())
+    }
+
+    #[cfg(feature = "up")]
+    #[inline]
+    #[must_use]
+    /// Format the span as an [`Uptime`], e.g. `8h, 15m, 30s`
+    pub fn uptime(&self) -> Uptime {
+        if self.unknown {
+            return Uptime::UNKNOWN;
+        }
+        Uptime::from(self.inner())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+// There is no string, so `str` is the start and end, e.g. `("01:00:00", "02:00:00")`.
+impl std::fmt::Debug for TimeSpan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimeSpan")
+            .field("inner", &self.inner())
+            .field("str", &(self.start().as_str(), self.end().as_str()))
+            .finish()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::Time;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", TimeSpan::new(3600, 7200)),
+            r#"TimeSpan { inner: 3600, str: ("01:00:00", "02:00:00") }"#
+        );
+    }
+
+    #[test]
+    fn wrap() {
+        for (start, end, expected, wraps) in [
+            (0, 0, 0, false),
+            (0, 86399, 86399, false),
+            (86399, 0, 1, true),
+            (86399, 86399, 0, false),
+            (3600, 3599, 86399, true),
+            (79200, 22500, 29700, true),
+        ] {
+            let span = TimeSpan::new(start, end);
+            assert_eq!(span.inner(), expected, "{start} -> {end}");
+            assert_eq!(span.wraps(), wraps, "{start} -> {end}");
+            assert_eq!(span.start(), Military::new(start));
+            assert_eq!(span.end(), Military::new(end));
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(TimeSpan::between(Military::UNKNOWN, Military::ZERO).is_unknown());
+        assert!(TimeSpan::between(Military::ZERO, Time::UNKNOWN).is_unknown());
+        assert!(TimeSpan::UNKNOWN.start().is_unknown());
+        assert!(TimeSpan::UNKNOWN.end().is_unknown());
+        #[cfg(feature = "up")]
+        assert!(TimeSpan::UNKNOWN.uptime().is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = TimeSpan::new(79200, 22500);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"{"unknown":false,"start":79200,"end":22500}"#);
+
+        let this: TimeSpan = serde_json::from_str(&json).unwrap();
+        assert_eq!(this.inner(), 29700);
+    }
+}
@@ -23,6 +23,32 @@ pub trait SysTime {
     fn sys_time() -> Self;
 }
 
+//---------------------------------------------------------------------------------------------------- Inherent Impl
+// Implements `now()`, the same as `SysTime::sys_time()` without the trait import.
+macro_rules! impl_now {
+	($($n:ident),* $(,)?) => {
+		$(
+			impl crate::time::$n {
+				#[inline]
+				#[must_use]
+				/// Create a `Self` from the current system clock time
+				///
+				/// This is the same as [`SysTime::sys_time`], without needing the trait in scope.
+				///
+				/// ```rust
+				/// # use readable::time::*;
+				#[doc = concat!("let now = ", stringify!($n), "::now();")]
+				/// assert!(!now.is_unknown());
+				/// ```
+				pub fn now() -> Self {
+					<Self as SysTime>::sys_time()
+				}
+			}
+		)*
+	};
+}
+impl_now!(Time, Military);
+
 //---------------------------------------------------------------------------------------------------- Uptime Function
 mod private {
     use crate::time::{Military, Time, TimeUnit};
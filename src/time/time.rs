@@ -1,11 +1,12 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::macros::{
-    handle_over_u32, impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize,
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_math, impl_traits, impl_usize,
 };
 #[cfg(feature = "num")]
 use crate::num::Unsigned;
 use crate::str::Str;
-use crate::time::{Military, TimeUnit};
+use crate::time::{Meridiem, Military, TimeUnit};
 
 //---------------------------------------------------------------------------------------------------- Time
 /// Clock time - `11:59:59 PM`
@@ -25,6 +26,16 @@ use crate::time::{Military, TimeUnit};
 /// assert_eq!(Time::from(86401), "12:00:01 AM");
 /// ```
 ///
+/// Adding or subtracting a [`std::time::Duration`] also wraps around the day:
+/// ```rust
+/// # use readable::time::*;
+/// # use std::time::Duration;
+/// let time = Time::from(30);
+/// assert_eq!(time, "12:00:30 AM");
+/// assert_eq!(time - Duration::from_secs(60), "11:59:30 PM");
+/// assert_eq!(time + Duration::from_secs(86400), time);
+/// ```
+///
 /// ## Size
 /// [`Str<11>`] is used internally to represent the string.
 ///
@@ -76,11 +87,12 @@ use crate::time::{Military, TimeUnit};
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct Time(pub(super) u32, pub(super) Str<{ Time::MAX_LEN }>);
 
 impl_traits!(Time, u32);
 impl_math!(Time, u32);
+impl_from_option!(Time);
 
 //---------------------------------------------------------------------------------------------------- Time Constants
 impl Time {
@@ -117,6 +129,7 @@ impl Time {
 impl Time {
     impl_common!(u32);
     impl_const!();
+    impl_format_slice!(time, Time, [0_u32, 65, 43_200, 86_399]);
     impl_usize!();
 
     #[inline]
@@ -169,6 +182,150 @@ impl Time {
         Self::priv_from((seconds as u32) + (minutes as u32 * 60) + (hours as u32 * 3600))
     }
 
+    #[inline]
+    /// Create a [`Self`] from an `hour`, `minute`, and `second` of the day
+    ///
+    /// Unlike [`Time::new_specified`], this does not wrap.
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(23, 59, 59).unwrap(), "11:59:59 PM");
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap(),  "1:30:05 PM");
+    /// ```
+    ///
+    /// ## Errors
+    /// [`crate::Error::TooLarge`] is returned if `hour` is over `23`,
+    /// or `minute`/`second` is over `59`.
+    ///
+    /// ```rust
+    /// # use readable::{time::*,Error};
+    /// assert_eq!(Time::from_hms(24, 0, 0),  Err(Error::TooLarge));
+    /// assert_eq!(Time::from_hms(0, 60, 0),  Err(Error::TooLarge));
+    /// assert_eq!(Time::from_hms(0, 0, 60),  Err(Error::TooLarge));
+    /// ```
+    pub const fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Self, crate::Error> {
+        if hour > 23 || minute > 59 || second > 59 {
+            Err(crate::Error::TooLarge)
+        } else {
+            Ok(Self::new_specified(hour, minute, second))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// The hour of the day, `0..=23`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap().hour(), 13);
+    /// ```
+    pub const fn hour(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).0
+    }
+
+    #[inline]
+    #[must_use]
+    /// The minute of the hour, `0..=59`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap().minute(), 30);
+    /// ```
+    pub const fn minute(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).1
+    }
+
+    #[inline]
+    #[must_use]
+    /// The second of the minute, `0..=59`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap().second(), 5);
+    /// ```
+    pub const fn second(&self) -> u8 {
+        crate::time::secs_to_clock(self.0).2
+    }
+
+    #[inline]
+    #[must_use]
+    /// The seconds past midnight, `0..=86399`
+    ///
+    /// This is the same as [`Time::inner`].
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap().seconds_past_midnight(), 48605);
+    /// assert_eq!(Time::from(86400 + 1).seconds_past_midnight(), 1);
+    /// ```
+    pub const fn seconds_past_midnight(&self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// The minutes past midnight, `0..=1439`
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// assert_eq!(Time::from_hms(13, 30, 5).unwrap().minutes_past_midnight(), 810);
+    /// ```
+    pub const fn minutes_past_midnight(&self) -> u16 {
+        (self.0 / 60) as u16
+    }
+
+    #[must_use]
+    /// Create a [`Self`] from seconds, formatted with `format`
+    ///
+    /// This wraps the same way [`Time::new`] does.
+    ///
+    /// ```rust
+    /// # use readable::time::*;
+    /// let secs = 21 * 3600 + 5 * 60;
+    /// assert_eq!(Time::new(secs), "9:05:00 PM");
+    ///
+    /// assert_eq!(Time::with_format(secs, TimeFormat::NO_SECONDS), "9:05 PM");
+    ///
+    /// let format = TimeFormat {
+    ///     zero_pad: true,
+    ///     seconds: true,
+    /// };
+    /// assert_eq!(Time::with_format(secs, format), "09:05:00 PM");
+    ///
+    /// let format = TimeFormat {
+    ///     zero_pad: true,
+    ///     seconds: false,
+    /// };
+    /// assert_eq!(Time::with_format(secs, format), "09:05 PM");
+    /// ```
+    pub fn with_format(total_seconds: u32, format: TimeFormat) -> Self {
+        let total_seconds = total_seconds % 86400;
+        let (hours, minutes, seconds) = crate::time::secs_to_clock(total_seconds);
+
+        let mut string = Str::new();
+        let mut push = |bytes: &[u8]| {
+            for b in bytes {
+                string.push_char_panic(char::from(*b));
+            }
+        };
+
+        let h = Self::str_0_23(hours);
+        if format.zero_pad && h.len() == 1 {
+            push(b"0");
+        }
+        push(h);
+        push(b":");
+        push(Self::str_0_59(minutes));
+        if format.seconds {
+            push(b":");
+            push(Self::str_0_59(seconds));
+        }
+        push(b" ");
+        push(Meridiem::from_hour(hours).as_str().as_bytes());
+
+        Self(total_seconds, string)
+    }
+
     #[inline]
     #[must_use]
     /// ```rust
@@ -181,6 +338,61 @@ impl Time {
     }
 }
 
+//---------------------------------------------------------------------------------------------------- TimeFormat
+/// The hour padding and seconds used by [`Time::with_format`]
+///
+/// ```rust
+/// # use readable::time::*;
+/// let format = TimeFormat {
+///     zero_pad: false,
+///     seconds: false,
+/// };
+/// assert_eq!(Time::with_format(3600, format), "1:00 AM");
+///
+/// let format = TimeFormat {
+///     zero_pad: true,
+///     seconds: true,
+/// };
+/// assert_eq!(Time::with_format(3600, format), "01:00:00 AM");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeFormat {
+    /// Pad single digit hours with a `0`, e.g. `09:05:00 PM`
+    pub zero_pad: bool,
+    /// Show the seconds, e.g. `9:05:00 PM` instead of `9:05 PM`
+    pub seconds: bool,
+}
+
+impl TimeFormat {
+    /// No padding, with seconds, e.g. `9:05:00 PM`
+    ///
+    /// This is what all other [`Time`] constructors use.
+    pub const DEFAULT: Self = Self {
+        zero_pad: false,
+        seconds: true,
+    };
+
+    /// No padding, without seconds, e.g. `9:05 PM`
+    pub const NO_SECONDS: Self = Self {
+        zero_pad: false,
+        seconds: false,
+    };
+}
+
+impl Default for TimeFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- Private impl
 impl Time {
     pub(super) const fn priv_from(total_seconds: u32) -> Self {
@@ -452,6 +664,52 @@ impl From<&Time> for std::time::Duration {
 mod tests {
     use super::*;
 
+    #[test]
+    fn hms() {
+        for secs in 0..86400 {
+            let (h, m, s) = crate::time::secs_to_clock(secs);
+            let this = Time::from_hms(h, m, s).unwrap();
+            assert_eq!(this, Time::from(secs));
+            assert_eq!((this.hour(), this.minute(), this.second()), (h, m, s));
+            assert_eq!(this.seconds_past_midnight(), secs);
+            assert_eq!(u32::from(this.minutes_past_midnight()), secs / 60);
+        }
+    }
+
+    #[test]
+    fn with_format() {
+        for secs in 0..86400 {
+            let time = Time::new(secs);
+            assert_eq!(Time::with_format(secs, TimeFormat::DEFAULT), time);
+
+            let short = Time::with_format(secs, TimeFormat::NO_SECONDS);
+            let mut expected = time.as_str().to_owned();
+            expected.replace_range(expected.len() - 6..expected.len() - 3, "");
+            assert_eq!(short, expected.as_str());
+            assert_eq!(short.inner(), secs);
+
+            for format in [
+                TimeFormat {
+                    zero_pad: true,
+                    seconds: true,
+                },
+                TimeFormat {
+                    zero_pad: true,
+                    seconds: false,
+                },
+            ] {
+                let padded = Time::with_format(secs, format);
+                assert_eq!(padded.as_bytes()[2], b':');
+                assert!(padded.len() <= Time::MAX_LEN);
+            }
+        }
+
+        assert_eq!(
+            Time::with_format(86400 + 1, TimeFormat::NO_SECONDS),
+            "12:00 AM"
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
@@ -1,10 +1,10 @@
 //---------------------------------------------------------------------------------------------------- Use
-use crate::macros::{impl_impl_math, impl_math, return_bad_float};
+use crate::macros::{impl_from_option, impl_impl_math, impl_math, return_bad_float};
 #[cfg(feature = "num")]
 use crate::num::Unsigned;
 use crate::time::{Military, Time};
 #[cfg(feature = "up")]
-use crate::up::{Htop, Uptime, UptimeFull};
+use crate::up::{Htop, Uptime, UptimeCompact, UptimeFull};
 
 //---------------------------------------------------------------------------------------------------- Use
 /// Unit of time
@@ -113,6 +113,7 @@ pub struct TimeUnit {
 }
 
 impl_math!(TimeUnit, u32);
+impl_from_option!(TimeUnit);
 
 //---------------------------------------------------------------------------------------------------- Constants
 impl TimeUnit {
@@ -909,7 +910,7 @@ macro_rules! impl_from_time {
 }
 impl_from_time!(TimeUnit => Time, Military);
 #[cfg(feature = "up")]
-impl_from_time!(TimeUnit => UptimeFull, Htop, Uptime);
+impl_from_time!(TimeUnit => UptimeFull, Htop, Uptime, UptimeCompact);
 #[cfg(feature = "num")]
 impl_from_time!(TimeUnit => Unsigned);
 
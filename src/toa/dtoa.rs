@@ -20,7 +20,7 @@
     clippy::unseparated_literal_suffix
 )]
 
-use core::mem::{self, MaybeUninit};
+use core::mem::MaybeUninit;
 use core::slice;
 use core::str;
 
@@ -53,51 +53,9 @@ use core::str;
 // License for the specific language governing permissions and limitations under
 // the License.
 
+#[cfg(any(not(feature = "ryu"), feature = "half"))]
 use core::ptr;
 
-/*
-inline unsigned CountDecimalDigit32(uint32_t n) {
-    // Simple pure C++ implementation was faster than __builtin_clz version in this situation.
-    if (n < 10) return 1;
-    if (n < 100) return 2;
-    if (n < 1000) return 3;
-    if (n < 10000) return 4;
-    if (n < 100000) return 5;
-    if (n < 1000000) return 6;
-    if (n < 10000000) return 7;
-    if (n < 100000000) return 8;
-    // Will not reach 10 digits in DigitGen()
-    //if (n < 1000000000) return 9;
-    //return 10;
-    return 9;
-}
-*/
-
-#[inline]
-const fn count_decimal_digit32(n: u32) -> usize {
-    if n < 10 {
-        1
-    } else if n < 100 {
-        2
-    } else if n < 1000 {
-        3
-    } else if n < 10000 {
-        4
-    } else if n < 100000 {
-        5
-    } else if n < 1000000 {
-        6
-    } else if n < 10000000 {
-        7
-    } else if n < 100000000 {
-        8
-    }
-    // Will not reach 10 digits in digit_gen()
-    else {
-        9
-    }
-}
-
 /*
 inline char* WriteExponent(int K, char* buffer) {
     if (K < 0) {
@@ -125,6 +83,7 @@ inline char* WriteExponent(int K, char* buffer) {
 */
 
 #[inline]
+#[cfg(any(not(feature = "ryu"), feature = "half"))]
 unsafe fn write_exponent(mut k: isize, mut buffer: *mut u8) -> *mut u8 {
     if k < 0 {
         *buffer = b'-';
@@ -154,7 +113,8 @@ inline char* Prettify(char* buffer, int length, int k, int maxDecimalPlaces) {
 */
 
 #[inline]
-unsafe fn prettify(buffer: *mut u8, length: isize, k: isize) -> *mut u8 {
+#[cfg(any(not(feature = "ryu"), feature = "half"))]
+pub(super) unsafe fn prettify(buffer: *mut u8, length: isize, k: isize) -> *mut u8 {
     let kk = length + k; // 10^(kk-1) <= v < 10^kk
 
     /*
@@ -302,245 +262,6 @@ unsafe fn prettify(buffer: *mut u8, length: isize, k: isize) -> *mut u8 {
     }
 }
 
-macro_rules! dtoa_inner {
-    (
-        floating_type: $fty:ty,
-        significand_type: $sigty:ty,
-        exponent_type: $expty:ty,
-        $($diyfp_param:ident: $diyfp_value:tt,)*
-    ) => {
-        diyfp! {
-            floating_type: $fty,
-            significand_type: $sigty,
-            exponent_type: $expty,
-            $($diyfp_param: $diyfp_value,)*
-        };
-
-        /*
-        inline void GrisuRound(char* buffer, int len, uint64_t delta, uint64_t rest, uint64_t ten_kappa, uint64_t wp_w) {
-            while (rest < wp_w && delta - rest >= ten_kappa &&
-                (rest + ten_kappa < wp_w ||  /// closer
-                    wp_w - rest > rest + ten_kappa - wp_w)) {
-                buffer[len - 1]--;
-                rest += ten_kappa;
-            }
-        }
-        */
-
-        #[inline]
-        #[allow(clippy::missing_const_for_fn)] // can't be const due to *mut
-        unsafe fn grisu_round(buffer: *mut u8, len: isize, delta: $sigty, mut rest: $sigty, ten_kappa: $sigty, wp_w: $sigty) {
-            while rest < wp_w && delta - rest >= ten_kappa &&
-                (rest + ten_kappa < wp_w || // closer
-                    wp_w - rest > rest + ten_kappa - wp_w) {
-                *buffer.offset(len - 1) -= 1;
-                rest += ten_kappa;
-            }
-        }
-
-        /*
-        inline void DigitGen(const DiyFp& W, const DiyFp& Mp, uint64_t delta, char* buffer, int* len, int* K) {
-            static const uint32_t kPow10[] = { 1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000 };
-            const DiyFp one(uint64_t(1) << -Mp.e, Mp.e);
-            const DiyFp wp_w = Mp - W;
-            uint32_t p1 = static_cast<uint32_t>(Mp.f >> -one.e);
-            uint64_t p2 = Mp.f & (one.f - 1);
-            unsigned kappa = CountDecimalDigit32(p1); // kappa in [0, 9]
-            *len = 0;
-        */
-
-        // Returns length and k.
-        #[inline]
-        unsafe fn digit_gen(w: DiyFp, mp: DiyFp, mut delta: $sigty, buffer: *mut u8, mut k: isize) -> (isize, isize) {
-            static POW10: [$sigty; 10] = [ 1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000 ];
-            let one = DiyFp::new(1 << -mp.e, mp.e);
-            let wp_w = mp - w;
-            let mut p1 = (mp.f >> -one.e) as u32;
-            let mut p2 = mp.f & (one.f - 1);
-            let mut kappa = count_decimal_digit32(p1); // kappa in [0, 9]
-            let mut len = 0;
-
-            /*
-            while (kappa > 0) {
-                uint32_t d = 0;
-                switch (kappa) {
-                    case  9: d = p1 /  100000000; p1 %=  100000000; break;
-                    case  8: d = p1 /   10000000; p1 %=   10000000; break;
-                    case  7: d = p1 /    1000000; p1 %=    1000000; break;
-                    case  6: d = p1 /     100000; p1 %=     100000; break;
-                    case  5: d = p1 /      10000; p1 %=      10000; break;
-                    case  4: d = p1 /       1000; p1 %=       1000; break;
-                    case  3: d = p1 /        100; p1 %=        100; break;
-                    case  2: d = p1 /         10; p1 %=         10; break;
-                    case  1: d = p1;              p1 =           0; break;
-                    default:;
-                }
-                if (d || *len)
-                    buffer[(*len)++] = static_cast<char>('0' + static_cast<char>(d));
-                kappa--;
-                uint64_t tmp = (static_cast<uint64_t>(p1) << -one.e) + p2;
-                if (tmp <= delta) {
-                    *K += kappa;
-                    GrisuRound(buffer, *len, delta, tmp, static_cast<uint64_t>(kPow10[kappa]) << -one.e, wp_w.f);
-                    return;
-                }
-            }
-            */
-            while kappa > 0 {
-                let mut d = 0u32;
-                match kappa {
-                    9 => { d = p1 /  100000000; p1 %=  100000000; }
-                    8 => { d = p1 /   10000000; p1 %=   10000000; }
-                    7 => { d = p1 /    1000000; p1 %=    1000000; }
-                    6 => { d = p1 /     100000; p1 %=     100000; }
-                    5 => { d = p1 /      10000; p1 %=      10000; }
-                    4 => { d = p1 /       1000; p1 %=       1000; }
-                    3 => { d = p1 /        100; p1 %=        100; }
-                    2 => { d = p1 /         10; p1 %=         10; }
-                    1 => { d = p1;              p1 =           0; }
-                    _ => {}
-                }
-                if d != 0 || len != 0 {
-                    *buffer.offset(len) = b'0' + d as u8;
-                    len += 1;
-                }
-                kappa -= 1;
-                let tmp = ((p1 as $sigty) << -one.e) + p2;
-                if tmp <= delta {
-                    k += kappa as isize;
-                    grisu_round(buffer, len, delta, tmp, *POW10.get_unchecked(kappa) << -one.e, wp_w.f);
-                    return (len, k);
-                }
-            }
-
-            // kappa = 0
-            /*
-            for (;;) {
-                p2 *= 10;
-                delta *= 10;
-                char d = static_cast<char>(p2 >> -one.e);
-                if (d || *len)
-                    buffer[(*len)++] = static_cast<char>('0' + d);
-                p2 &= one.f - 1;
-                kappa--;
-                if (p2 < delta) {
-                    *K += kappa;
-                    int index = -static_cast<int>(kappa);
-                    GrisuRound(buffer, *len, delta, p2, one.f, wp_w.f * (index < 9 ? kPow10[-static_cast<int>(kappa)] : 0));
-                    return;
-                }
-            }
-            */
-            loop {
-                p2 *= 10;
-                delta *= 10;
-                let d = (p2 >> -one.e) as u8;
-                if d != 0 || len != 0 {
-                    *buffer.offset(len) = b'0' + d;
-                    len += 1;
-                }
-                p2 &= one.f - 1;
-                kappa = kappa.wrapping_sub(1);
-                if p2 < delta {
-                    k += kappa as isize;
-                    let index = -(kappa as isize);
-                    grisu_round(
-                        buffer,
-                        len,
-                        delta,
-                        p2,
-                        one.f,
-                        wp_w.f * if index < 9 {
-                            *POW10.get_unchecked(-(kappa as isize) as usize)
-                        } else {
-                            0
-                        },
-                    );
-                    return (len, k);
-                }
-            }
-        }
-
-        /*
-        inline void Grisu2(double value, char* buffer, int* length, int* K) {
-            const DiyFp v(value);
-            DiyFp w_m, w_p;
-            v.NormalizedBoundaries(&w_m, &w_p);
-
-            const DiyFp c_mk = GetCachedPower(w_p.e, K);
-            const DiyFp W = v.Normalize() * c_mk;
-            DiyFp Wp = w_p * c_mk;
-            DiyFp Wm = w_m * c_mk;
-            Wm.f++;
-            Wp.f--;
-            DigitGen(W, Wp, Wp.f - Wm.f, buffer, length, K);
-        }
-        */
-
-        // Returns length and k.
-        #[inline]
-        unsafe fn grisu2(value: $fty, buffer: *mut u8) -> (isize, isize) {
-            let v = DiyFp::from(value);
-            let (w_m, w_p) = v.normalized_boundaries();
-
-            let (c_mk, k) = get_cached_power(w_p.e);
-            let w = v.normalize() * c_mk;
-            let mut wp = w_p * c_mk;
-            let mut wm = w_m * c_mk;
-            wm.f += 1;
-            wp.f -= 1;
-            digit_gen(w, wp, wp.f - wm.f, buffer, k)
-        }
-
-        /*
-        inline char* dtoa(double value, char* buffer, int maxDecimalPlaces = 324) {
-            RAPIDJSON_ASSERT(maxDecimalPlaces >= 1);
-            Double d(value);
-            if (d.IsZero()) {
-                if (d.Sign())
-                    *buffer++ = '-';     // -0.0, Issue #289
-                buffer[0] = '0';
-                buffer[1] = '.';
-                buffer[2] = '0';
-                return &buffer[3];
-            }
-            else {
-                if (value < 0) {
-                    *buffer++ = '-';
-                    value = -value;
-                }
-                int length, K;
-                Grisu2(value, buffer, &length, &K);
-                return Prettify(buffer, length, K, maxDecimalPlaces);
-            }
-        }
-        */
-
-        #[inline]
-        unsafe fn dtoa(buf: &mut [MaybeUninit<u8>; 25], mut value: $fty) -> &str {
-            if value == 0.0 {
-                if value.is_sign_negative() {
-                    "-0.0"
-                } else {
-                    "0.0"
-                }
-            } else {
-                let start = buf.as_mut_ptr().cast::<u8>();
-                let mut buf_ptr = start;
-                if value < 0.0 {
-                    *buf_ptr = b'-';
-                    buf_ptr = buf_ptr.offset(1);
-                    value = -value;
-                }
-                let (length, k) = grisu2(value, buf_ptr);
-                let end = prettify(buf_ptr, length, k);
-                let len = end as usize - start as usize;
-                str::from_utf8_unchecked(slice::from_raw_parts(start, len))
-            }
-        }
-    };
-}
-
 //---------------------------------------------------------------------------------------------------- Const
 const NAN: &str = "NaN";
 const INFINITY: &str = "inf";
@@ -666,6 +387,46 @@ impl Dtoa {
         }
     }
 
+    /// Create a new [`Dtoa`] with exactly `decimals` decimal places.
+    ///
+    /// This rounds the shortest representation (the one [`Dtoa::new()`] outputs),
+    /// with ties rounded away from zero, so `2.675` is `2.68` even though
+    /// `format!("{:.2}", 2.675)` is `2.67`.
+    ///
+    /// The output never uses an exponent.
+    ///
+    /// ```rust
+    /// # use readable::toa::Dtoa;
+    /// assert_eq!(Dtoa::fixed(1.0, 3),      "1.000");
+    /// assert_eq!(Dtoa::fixed(2.675, 2),    "2.68");
+    /// assert_eq!(Dtoa::fixed(-9.96, 1),    "-10.0");
+    /// assert_eq!(Dtoa::fixed(123.456, 0),  "123");
+    /// assert_eq!(Dtoa::fixed(1e-7, 8),     "0.00000010");
+    /// assert_eq!(Dtoa::fixed(1e21, 1),     "1000000000000000000000.0");
+    /// assert_eq!(Dtoa::fixed(0.1_f32, 10), "0.1000000000");
+    ///
+    /// assert_eq!(Dtoa::fixed(f64::NAN, 2),      "NaN");
+    /// assert_eq!(Dtoa::fixed(f64::INFINITY, 2), "inf");
+    /// ```
+    ///
+    /// If the output is longer than the `25` byte buffer,
+    /// the shortest representation is returned instead.
+    ///
+    /// ```rust
+    /// # use readable::toa::Dtoa;
+    /// assert_eq!(Dtoa::fixed(1.5, 30),     "1.5");
+    /// assert_eq!(Dtoa::fixed(f64::MAX, 0), "1.7976931348623157e308");
+    /// ```
+    pub fn fixed<N: IntoDtoa>(num: N, decimals: u8) -> Self {
+        let shortest = Self::new(num);
+        if num.is_nonfinite() {
+            return shortest;
+        }
+        shortest
+            .priv_fixed(usize::from(decimals))
+            .unwrap_or(shortest)
+    }
+
     #[inline]
     /// Turns [`Dtoa`] into a `&str`.
     ///
@@ -684,6 +445,100 @@ impl Dtoa {
     }
 }
 
+impl Dtoa {
+    /// Round the (finite) shortest representation to `decimals`.
+    ///
+    /// [`None`] is returned if the output does not fit.
+    fn priv_fixed(&self, decimals: usize) -> Option<Self> {
+        const LEN: usize = 25;
+
+        let string = self.as_str().as_bytes();
+        let (negative, string) = match string.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, string),
+        };
+
+        // `1.5e-7` -> `1.5`, `-7`.
+        let (mantissa, exp) = match string.iter().position(|b| *b == b'e') {
+            Some(i) => {
+                let exp = str::from_utf8(&string[i + 1..]).ok()?;
+                (&string[..i], exp.parse::<isize>().ok()?)
+            }
+            None => (string, 0),
+        };
+        let (int, fract) = match mantissa.iter().position(|b| *b == b'.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, &[][..]),
+        };
+
+        // The digits of `int` and `fract` are indexed from `0`,
+        // anything outside of them is a `0`. The `.` is before index `point`.
+        let digit = |i: isize| -> u8 {
+            usize::try_from(i)
+                .ok()
+                .and_then(|i| int.get(i).or_else(|| fract.get(i.checked_sub(int.len())?)))
+                .copied()
+                .unwrap_or(b'0')
+        };
+        let point = isize::try_from(int.len()).ok()? + exp;
+
+        // There is always at least 1 integer digit, e.g. `0.001`.
+        let start = point.min(1) - 1;
+        let end = point.checked_add(isize::try_from(decimals).ok()?)?;
+        let len = (end - start) as usize;
+        if len > LEN {
+            return None;
+        }
+
+        // The kept digits, with the first byte reserved for a carry.
+        let mut digits = [b'0'; LEN + 1];
+        for (d, i) in digits[1..=len].iter_mut().zip(start..end) {
+            *d = digit(i);
+        }
+
+        let mut first = 1;
+        if digit(end) >= b'5' {
+            // Carry the `1`, e.g. `9.96` -> `10.0`.
+            let kept = &mut digits[1..=len];
+            if let Some(i) = kept.iter().rposition(|d| *d != b'9') {
+                kept[i] += 1;
+                kept[i + 1..].fill(b'0');
+            } else {
+                kept.fill(b'0');
+                digits[0] = b'1';
+                first = 0;
+            }
+        }
+
+        let int_len = (point - start) as usize + 1 - first;
+        let total = usize::from(negative) + int_len + if decimals == 0 { 0 } else { decimals + 1 };
+        if total > LEN {
+            return None;
+        }
+
+        let mut bytes = [MaybeUninit::<u8>::uninit(); LEN];
+        let mut i = 0;
+        let mut push = |byte: u8| {
+            bytes[i].write(byte);
+            i += 1;
+        };
+
+        if negative {
+            push(b'-');
+        }
+        digits[first..first + int_len].iter().for_each(|d| push(*d));
+        if decimals != 0 {
+            push(b'.');
+            digits[first + int_len..=len].iter().for_each(|d| push(*d));
+        }
+
+        Some(Self {
+            len: total as u8,
+            bytes,
+        })
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- DtoaTmp
 /// A short-lived version of [`Dtoa`]
 ///
@@ -713,21 +568,57 @@ impl Dtoa {
 /// assert_eq!(dtoa.format(3.0), "3.0");
 /// ```
 ///
+/// ## Reuse
+/// There is no per-format cost other than the formatting itself,
+/// so a single [`DtoaTmp`] can be used in a loop:
+/// ```rust
+/// # use readable::toa::*;
+/// let mut dtoa = DtoaTmp::new();
+/// let mut total = 0;
+///
+/// for i in 0..100_u8 {
+///     total += dtoa.format(f32::from(i) / 4.0).len();
+/// }
+/// assert_eq!(total, 410);
+///
+/// // The last formatted string is kept until the next format.
+/// assert_eq!(dtoa.as_str(), "24.75");
+/// dtoa.clear();
+/// assert!(dtoa.is_empty());
+/// ```
+///
 /// ## Size
 /// ```rust
 /// # use readable::toa::*;
-/// assert_eq!(std::mem::size_of::<DtoaTmp>(), 25);
+/// assert_eq!(std::mem::size_of::<DtoaTmp>(), 26);
 /// ```
 #[derive(Copy, Clone, Debug)]
 pub struct DtoaTmp {
+    len: u8,
     bytes: [MaybeUninit<u8>; 25],
 }
 
+impl Default for DtoaTmp {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DtoaTmp {
-    /// Create a new [`DtoaTmp`].
+    /// The byte capacity of the inner buffer, enough for any [`IntoDtoa`]
+    ///
+    /// ```rust
+    /// # use readable::toa::*;
+    /// assert_eq!(DtoaTmp::CAPACITY, 25);
+    /// ```
+    pub const CAPACITY: usize = 25;
+
+    /// Create a new, empty [`DtoaTmp`].
     #[inline]
     pub const fn new() -> Self {
         Self {
+            len: 0,
             bytes: [MaybeUninit::<u8>::uninit(); 25],
         }
     }
@@ -754,9 +645,14 @@ impl DtoaTmp {
     /// ```
     pub fn format<N: IntoDtoa>(&mut self, num: N) -> &str {
         if num.is_nonfinite() {
-            num.format_nonfinite()
+            let string = num.format_nonfinite();
+            for (b, byte) in self.bytes.iter_mut().zip(string.as_bytes()) {
+                b.write(*byte);
+            }
+            self.len = string.len() as u8;
+            self.as_str()
         } else {
-            num.write(&mut self.bytes)
+            self.format_finite(num)
         }
     }
 
@@ -782,7 +678,84 @@ impl DtoaTmp {
     /// assert_eq!(dtoa.format_finite(f64::NEG_INFINITY), "-1.797693134862316e308");
     /// ```
     pub fn format_finite<N: IntoDtoa>(&mut self, num: N) -> &str {
-        num.write(&mut self.bytes)
+        self.len = num.write(&mut self.bytes).len() as u8;
+        self.as_str()
+    }
+
+    #[inline]
+    /// Format an [`IntoDtoa`] and write it into `w`
+    ///
+    /// The returned [`&str`] is the same one written, and is kept in `self`.
+    ///
+    /// ```rust
+    /// # use readable::toa::DtoaTmp;
+    /// let mut dtoa = DtoaTmp::new();
+    /// let mut string = String::new();
+    ///
+    /// for f in [0.5, 2.5, f64::NAN] {
+    ///     let s = dtoa.format_into(f, &mut string).unwrap();
+    ///     assert_eq!(s.len(), 3);
+    /// }
+    /// assert_eq!(string, "0.52.5NaN");
+    /// ```
+    ///
+    /// ## Errors
+    /// This errors if `w` errors.
+    pub fn format_into<N: IntoDtoa, W: std::fmt::Write>(
+        &mut self,
+        num: N,
+        w: &mut W,
+    ) -> Result<&str, std::fmt::Error> {
+        let s = self.format(num);
+        w.write_str(s)?;
+        Ok(s)
+    }
+
+    #[inline]
+    /// The last formatted [`&str`]
+    ///
+    /// This is empty after [`DtoaTmp::new`] and [`DtoaTmp::clear`].
+    ///
+    /// ```rust
+    /// # use readable::toa::DtoaTmp;
+    /// let mut dtoa = DtoaTmp::new();
+    /// assert_eq!(dtoa.as_str(), "");
+    ///
+    /// dtoa.format(-1.5);
+    /// assert_eq!(dtoa.as_str(), "-1.5");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        // Safety: `len` is always set to an initialized range.
+        unsafe {
+            let slice = slice::from_raw_parts(self.bytes.as_ptr().cast::<u8>(), self.len as usize);
+            std::str::from_utf8_unchecked(slice)
+        }
+    }
+
+    #[inline]
+    /// Returns the `str` byte length of the last formatted string
+    pub const fn len(&self) -> u8 {
+        self.len
+    }
+
+    #[inline]
+    /// If there is no formatted string
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    /// Forget the last formatted string
+    ///
+    /// This is cheap, the buffer is not zeroed.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    #[inline]
+    /// Returns [`DtoaTmp::CAPACITY`]
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
     }
 }
 
@@ -884,7 +857,7 @@ impl<T: IntoDtoa> std::convert::From<T> for Dtoa {
 
 impl std::fmt::Display for Dtoa {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        crate::str::pad(f, self.as_str())
     }
 }
 
@@ -936,6 +909,27 @@ impl std::fmt::Display for Dtoa {
 /// let dtoa = Dtoa::new(u64::MAX as f64);
 /// assert_ne!(dtoa, format!("{}.0", u64::MAX)) // not equal
 /// ```
+///
+/// ## `half`
+/// With the `half` feature, [`half::f16`] and [`half::bf16`] are implemented as well.
+///
+/// These output the shortest string that round-trips
+/// back into the _half_ float, not the `f32`/`f64` it widens to.
+///
+/// ```rust
+/// # #[cfg(feature = "half")] {
+/// # use readable::toa::*;
+/// use half::{f16, bf16};
+///
+/// assert_eq!(Dtoa::new(f16::from_f32(0.1)),  "0.1");
+/// assert_eq!(Dtoa::new(f16::MAX),            "65500.0"); // 65504
+/// assert_eq!(Dtoa::new(f16::NEG_INFINITY),   "-inf");
+/// assert_eq!(Dtoa::new(bf16::from_f32(0.1)), "0.1");
+///
+/// // Not the `f64` digits.
+/// assert_eq!(Dtoa::new(f16::from_f32(0.1).to_f64()), "0.0999755859375");
+/// # }
+/// ```
 pub trait IntoDtoa: private::Sealed {}
 
 use std::num::{NonZeroI16, NonZeroI32, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU8};
@@ -956,6 +950,11 @@ impl_float! {
     NonZeroU16,
     NonZeroU32,
 }
+#[cfg(feature = "half")]
+impl_float! {
+    half::f16,
+    half::bf16,
+}
 
 //---------------------------------------------------------------------------------------------------- Impl
 // Seal to prevent downstream implementations of IntoDtoa trait.
@@ -1049,25 +1048,19 @@ impl private::Sealed for f32 {
     }
 
     #[inline]
+    #[cfg(feature = "ryu")]
     fn write(self, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
-        dtoa_inner! {
-            floating_type: f32,
-            significand_type: u32,
-            exponent_type: i32,
-
-            diy_significand_size: 32,
-            significand_size: 23,
-            exponent_bias: 0x7F,
-            mask_type: u32,
-            exponent_mask: 0x7F800000,
-            significand_mask: 0x007FFFFF,
-            hidden_bit: 0x00800000,
-            cached_powers_f: CACHED_POWERS_F_32,
-            cached_powers_e: CACHED_POWERS_E_32,
-            min_power: (-36),
-        };
-        // SAFETY: dtolnay
-        unsafe { dtoa(buf, self) }
+        // SAFETY: `ryu` writes at most 24 ASCII bytes.
+        unsafe {
+            let len = ryu::raw::format32(self, buf.as_mut_ptr().cast::<u8>());
+            str::from_utf8_unchecked(slice::from_raw_parts(buf.as_ptr().cast::<u8>(), len))
+        }
+    }
+
+    #[inline]
+    #[cfg(not(feature = "ryu"))]
+    fn write(self, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
+        super::grisu::write_f32(self, buf)
     }
 }
 
@@ -1094,32 +1087,143 @@ impl private::Sealed for f64 {
     }
 
     #[inline]
+    #[cfg(feature = "ryu")]
     fn write(self, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
-        dtoa_inner! {
-            floating_type: f64,
-            significand_type: u64,
-            exponent_type: isize,
-
-            diy_significand_size: 64,
-            significand_size: 52,
-            exponent_bias: 0x3FF,
-            mask_type: u64,
-            exponent_mask: 0x7FF0000000000000,
-            significand_mask: 0x000FFFFFFFFFFFFF,
-            hidden_bit: 0x0010000000000000,
-            cached_powers_f: CACHED_POWERS_F_64,
-            cached_powers_e: CACHED_POWERS_E_64,
-            min_power: (-348),
-        };
-        // SAFETY: dtolnay
-        unsafe { dtoa(buf, self) }
+        // SAFETY: `ryu` writes at most 24 ASCII bytes.
+        unsafe {
+            let len = ryu::raw::format64(self, buf.as_mut_ptr().cast::<u8>());
+            str::from_utf8_unchecked(slice::from_raw_parts(buf.as_ptr().cast::<u8>(), len))
+        }
+    }
+
+    #[inline]
+    #[cfg(not(feature = "ryu"))]
+    fn write(self, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
+        super::grisu::write_f64(self, buf)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Half
+// `grisu2` finds the shortest digits for `f32`/`f64`, which for a widened
+// half float are far too many, e.g. `0.1_f16` is `0.0999755859375_f64`.
+//
+// Half floats have at most `5` significant digits though, so
+// this just tries `1..=5` digits until one round-trips.
+#[cfg(feature = "half")]
+macro_rules! impl_half {
+	($( $half:ty ),* $(,)?) => {$(
+		impl private::Sealed for $half {
+			#[inline]
+			fn is_nonfinite(self) -> bool {
+				!self.is_finite()
+			}
+
+			#[cold]
+			fn format_nonfinite(self) -> &'static str {
+				if self.is_nan() {
+					NAN
+				} else if self.is_sign_negative() {
+					NEG_INFINITY
+				} else {
+					INFINITY
+				}
+			}
+
+			#[inline]
+			fn write(self, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
+				let value = self.to_f64();
+				if value == 0.0 {
+					return if value.is_sign_negative() { "-0.0" } else { "0.0" };
+				}
+
+				let abs = value.abs();
+				let mut sci = HalfBuf::new();
+				let mut precision = 0;
+				loop {
+					sci.clear();
+					// `HalfBuf` fits any half float with `5` digits.
+					std::fmt::Write::write_fmt(&mut sci, format_args!("{abs:.precision$e}")).unwrap();
+					let parsed: f64 = sci.as_str().parse().unwrap();
+					if <$half>::from_f64(parsed).to_bits() == (self.to_bits() & 0x7FFF) || precision == 4 {
+						break;
+					}
+					precision += 1;
+				}
+
+				// `d.ddddeX` -> digits and exponent.
+				let (mantissa, exponent) = sci.as_str().split_once('e').unwrap();
+				let exponent: isize = exponent.parse().unwrap();
+
+				// SAFETY: at most `1` sign + `5` digits + `prettify()`'s
+				// padding is written, which fits in `25` bytes.
+				unsafe {
+					let start = buf.as_mut_ptr().cast::<u8>();
+					let mut buf_ptr = start;
+					if value < 0.0 {
+						*buf_ptr = b'-';
+						buf_ptr = buf_ptr.offset(1);
+					}
+					let mut length = 0;
+					for digit in mantissa.bytes().filter(u8::is_ascii_digit) {
+						*buf_ptr.offset(length) = digit;
+						length += 1;
+					}
+					let end = prettify(buf_ptr, length, exponent - (length - 1));
+					let len = end as usize - start as usize;
+					str::from_utf8_unchecked(slice::from_raw_parts(start, len))
+				}
+			}
+		}
+	)*};
+}
+#[cfg(feature = "half")]
+impl_half!(half::f16, half::bf16);
+
+/// Scratch space for `impl_half!()`'s `{:e}` formatting, e.g. `5.9605e-8`.
+#[cfg(feature = "half")]
+struct HalfBuf {
+    bytes: [u8; 16],
+    len: usize,
+}
+
+#[cfg(feature = "half")]
+impl HalfBuf {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; 16],
+            len: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: only `&str`'s are written.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+#[cfg(feature = "half")]
+impl std::fmt::Write for HalfBuf {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 
+#[cfg(any(not(feature = "ryu"), feature = "half"))]
 const MAX_DECIMAL_PLACES: isize = 324;
 
+#[cfg(any(not(feature = "ryu"), feature = "half"))]
 static DEC_DIGITS_LUT: [u8; 200] = *b"\
     0001020304050607080910111213141516171819\
     2021222324252627282930313233343536373839\
@@ -1127,77 +1231,70 @@ static DEC_DIGITS_LUT: [u8; 200] = *b"\
     6061626364656667686970717273747576777879\
     8081828384858687888990919293949596979899";
 
-// 10^-36, 10^-28, ..., 10^52
-#[rustfmt::skip]
-static CACHED_POWERS_F_32: [u32; 12] = [
-    0xaa242499, 0xfd87b5f3, 0xbce50865, 0x8cbccc09,
-    0xd1b71759, 0x9c400000, 0xe8d4a510, 0xad78ebc6,
-    0x813f3979, 0xc097ce7c, 0x8f7e32ce, 0xd5d238a5,
-];
-
-#[rustfmt::skip]
-static CACHED_POWERS_E_32: [i16; 12] = [
-    -151, -125, -98, -71, -45, -18, 8, 35, 62, 88, 115, 141,
-];
-
-// 10^-348, 10^-340, ..., 10^340
-#[rustfmt::skip]
-static CACHED_POWERS_F_64: [u64; 87] = [
-    0xfa8fd5a0081c0288, 0xbaaee17fa23ebf76,
-    0x8b16fb203055ac76, 0xcf42894a5dce35ea,
-    0x9a6bb0aa55653b2d, 0xe61acf033d1a45df,
-    0xab70fe17c79ac6ca, 0xff77b1fcbebcdc4f,
-    0xbe5691ef416bd60c, 0x8dd01fad907ffc3c,
-    0xd3515c2831559a83, 0x9d71ac8fada6c9b5,
-    0xea9c227723ee8bcb, 0xaecc49914078536d,
-    0x823c12795db6ce57, 0xc21094364dfb5637,
-    0x9096ea6f3848984f, 0xd77485cb25823ac7,
-    0xa086cfcd97bf97f4, 0xef340a98172aace5,
-    0xb23867fb2a35b28e, 0x84c8d4dfd2c63f3b,
-    0xc5dd44271ad3cdba, 0x936b9fcebb25c996,
-    0xdbac6c247d62a584, 0xa3ab66580d5fdaf6,
-    0xf3e2f893dec3f126, 0xb5b5ada8aaff80b8,
-    0x87625f056c7c4a8b, 0xc9bcff6034c13053,
-    0x964e858c91ba2655, 0xdff9772470297ebd,
-    0xa6dfbd9fb8e5b88f, 0xf8a95fcf88747d94,
-    0xb94470938fa89bcf, 0x8a08f0f8bf0f156b,
-    0xcdb02555653131b6, 0x993fe2c6d07b7fac,
-    0xe45c10c42a2b3b06, 0xaa242499697392d3,
-    0xfd87b5f28300ca0e, 0xbce5086492111aeb,
-    0x8cbccc096f5088cc, 0xd1b71758e219652c,
-    0x9c40000000000000, 0xe8d4a51000000000,
-    0xad78ebc5ac620000, 0x813f3978f8940984,
-    0xc097ce7bc90715b3, 0x8f7e32ce7bea5c70,
-    0xd5d238a4abe98068, 0x9f4f2726179a2245,
-    0xed63a231d4c4fb27, 0xb0de65388cc8ada8,
-    0x83c7088e1aab65db, 0xc45d1df942711d9a,
-    0x924d692ca61be758, 0xda01ee641a708dea,
-    0xa26da3999aef774a, 0xf209787bb47d6b85,
-    0xb454e4a179dd1877, 0x865b86925b9bc5c2,
-    0xc83553c5c8965d3d, 0x952ab45cfa97a0b3,
-    0xde469fbd99a05fe3, 0xa59bc234db398c25,
-    0xf6c69a72a3989f5c, 0xb7dcbf5354e9bece,
-    0x88fcf317f22241e2, 0xcc20ce9bd35c78a5,
-    0x98165af37b2153df, 0xe2a0b5dc971f303a,
-    0xa8d9d1535ce3b396, 0xfb9b7cd9a4a7443c,
-    0xbb764c4ca7a44410, 0x8bab8eefb6409c1a,
-    0xd01fef10a657842c, 0x9b10a4e5e9913129,
-    0xe7109bfba19c0c9d, 0xac2820d9623bf429,
-    0x80444b5e7aa7cf85, 0xbf21e44003acdd2d,
-    0x8e679c2f5e44ff8f, 0xd433179d9c8cb841,
-    0x9e19db92b4e31ba9, 0xeb96bf6ebadf77d9,
-    0xaf87023b9bf0ee6b,
-];
-
-#[rustfmt::skip]
-static CACHED_POWERS_E_64: [i16; 87] = [
-    -1220, -1193, -1166, -1140, -1113, -1087, -1060, -1034, -1007,  -980,
-    -954,   -927,  -901,  -874,  -847,  -821,  -794,  -768,  -741,  -715,
-    -688,   -661,  -635,  -608,  -582,  -555,  -529,  -502,  -475,  -449,
-    -422,   -396,  -369,  -343,  -316,  -289,  -263,  -236,  -210,  -183,
-    -157,   -130,  -103,   -77,   -50,   -24,     3,    30,    56,    83,
-     109,    136,   162,   189,   216,   242,   269,   295,   322,   348,
-     375,    402,   428,   455,   481,   508,   534,   561,   588,   614,
-     641,    667,   694,   720,   747,   774,   800,   827,   853,   880,
-     907,    933,   960,   986,  1013,  1039,  1066,
-];
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed() {
+        for i in 0..100_000 {
+            let f = f64::from(i).mul_add(0.013_7, -500.0);
+            for decimals in 0..=6 {
+                let dtoa = Dtoa::fixed(f, decimals);
+                assert!(!dtoa.contains('e'));
+
+                // Ties in the shortest representation round away from
+                // zero, everything else matches the exact rounding.
+                let shortest = Dtoa::new(f);
+                let fract = shortest.split_once('.').unwrap().1;
+                if fract.len() == usize::from(decimals) + 1 && fract.ends_with('5') {
+                    continue;
+                }
+                assert_eq!(dtoa, format!("{f:.0$}", usize::from(decimals)));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half() {
+        use half::{bf16, f16};
+
+        for (f, expected) in [
+            (f16::from_f32(0.1), "0.1"),
+            (f16::from_f32(-2.5), "-2.5"),
+            (f16::ONE, "1.0"),
+            (f16::MAX, "65500.0"),
+            (f16::MIN, "-65500.0"),
+            (f16::NEG_ZERO, "-0.0"),
+            (f16::NAN, "NaN"),
+            (f16::INFINITY, "inf"),
+        ] {
+            assert_eq!(Dtoa::new(f), expected);
+        }
+
+        assert_eq!(Dtoa::new(bf16::from_f32(0.1)), "0.1");
+        assert_eq!(Dtoa::new(bf16::ONE), "1.0");
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half_round_trip() {
+        use half::{bf16, f16};
+
+        for bits in 0..=u16::MAX {
+            let f = f16::from_bits(bits);
+            if f.is_finite() {
+                let parsed: f64 = Dtoa::new(f).as_str().parse().unwrap();
+                assert_eq!(f16::from_f64(parsed).to_bits(), bits, "{f}");
+            }
+
+            let f = bf16::from_bits(bits);
+            if f.is_finite() {
+                let parsed: f64 = Dtoa::new(f).as_str().parse().unwrap();
+                assert_eq!(bf16::from_f64(parsed).to_bits(), bits, "{f}");
+            }
+        }
+    }
+}
@@ -0,0 +1,463 @@
+// The Grisu2 `dtoa` backend, taken from `https://github.com/dtolnay/dtoa`.
+//
+// This is unused with the `ryu` feature.
+
+//---------------------------------------------------------------------------------------------------- Use
+#![allow(
+    clippy::cast_lossless,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::doc_markdown,
+    clippy::expl_impl_clone_on_copy,
+    clippy::if_not_else,
+    clippy::missing_errors_doc,
+    clippy::must_use_candidate,
+    clippy::needless_doctest_main,
+    clippy::range_plus_one,
+    clippy::semicolon_if_nothing_returned, // https://github.com/rust-lang/rust-clippy/issues/7768
+    clippy::shadow_unrelated,
+    clippy::suspicious_else_formatting,
+    clippy::transmute_float_to_int,
+    clippy::unreadable_literal,
+    clippy::unseparated_literal_suffix
+)]
+
+use super::dtoa::prettify;
+use core::mem::{self, MaybeUninit};
+use core::slice;
+use core::str;
+
+//----------------------------------------------------------------------------------------------------
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// ---
+//
+// The C++ implementation preserved here in comments is licensed as follows:
+//
+// Tencent is pleased to support the open source community by making RapidJSON
+// available.
+//
+// Copyright (C) 2015 THL A29 Limited, a Tencent company, and Milo Yip. All
+// rights reserved.
+//
+// Licensed under the MIT License (the "License"); you may not use this file
+// except in compliance with the License. You may obtain a copy of the License
+// at
+//
+// http://opensource.org/licenses/MIT
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations under
+// the License.
+
+/*
+inline unsigned CountDecimalDigit32(uint32_t n) {
+    // Simple pure C++ implementation was faster than __builtin_clz version in this situation.
+    if (n < 10) return 1;
+    if (n < 100) return 2;
+    if (n < 1000) return 3;
+    if (n < 10000) return 4;
+    if (n < 100000) return 5;
+    if (n < 1000000) return 6;
+    if (n < 10000000) return 7;
+    if (n < 100000000) return 8;
+    // Will not reach 10 digits in DigitGen()
+    //if (n < 1000000000) return 9;
+    //return 10;
+    return 9;
+}
+*/
+
+#[inline]
+const fn count_decimal_digit32(n: u32) -> usize {
+    if n < 10 {
+        1
+    } else if n < 100 {
+        2
+    } else if n < 1000 {
+        3
+    } else if n < 10000 {
+        4
+    } else if n < 100000 {
+        5
+    } else if n < 1000000 {
+        6
+    } else if n < 10000000 {
+        7
+    } else if n < 100000000 {
+        8
+    }
+    // Will not reach 10 digits in digit_gen()
+    else {
+        9
+    }
+}
+
+macro_rules! dtoa_inner {
+    (
+        floating_type: $fty:ty,
+        significand_type: $sigty:ty,
+        exponent_type: $expty:ty,
+        $($diyfp_param:ident: $diyfp_value:tt,)*
+    ) => {
+        diyfp! {
+            floating_type: $fty,
+            significand_type: $sigty,
+            exponent_type: $expty,
+            $($diyfp_param: $diyfp_value,)*
+        };
+
+        /*
+        inline void GrisuRound(char* buffer, int len, uint64_t delta, uint64_t rest, uint64_t ten_kappa, uint64_t wp_w) {
+            while (rest < wp_w && delta - rest >= ten_kappa &&
+                (rest + ten_kappa < wp_w ||  /// closer
+                    wp_w - rest > rest + ten_kappa - wp_w)) {
+                buffer[len - 1]--;
+                rest += ten_kappa;
+            }
+        }
+        */
+
+        #[inline]
+        #[allow(clippy::missing_const_for_fn)] // can't be const due to *mut
+        unsafe fn grisu_round(buffer: *mut u8, len: isize, delta: $sigty, mut rest: $sigty, ten_kappa: $sigty, wp_w: $sigty) {
+            while rest < wp_w && delta - rest >= ten_kappa &&
+                (rest + ten_kappa < wp_w || // closer
+                    wp_w - rest > rest + ten_kappa - wp_w) {
+                *buffer.offset(len - 1) -= 1;
+                rest += ten_kappa;
+            }
+        }
+
+        /*
+        inline void DigitGen(const DiyFp& W, const DiyFp& Mp, uint64_t delta, char* buffer, int* len, int* K) {
+            static const uint32_t kPow10[] = { 1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000 };
+            const DiyFp one(uint64_t(1) << -Mp.e, Mp.e);
+            const DiyFp wp_w = Mp - W;
+            uint32_t p1 = static_cast<uint32_t>(Mp.f >> -one.e);
+            uint64_t p2 = Mp.f & (one.f - 1);
+            unsigned kappa = CountDecimalDigit32(p1); // kappa in [0, 9]
+            *len = 0;
+        */
+
+        // Returns length and k.
+        #[inline]
+        unsafe fn digit_gen(w: DiyFp, mp: DiyFp, mut delta: $sigty, buffer: *mut u8, mut k: isize) -> (isize, isize) {
+            static POW10: [$sigty; 10] = [ 1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000 ];
+            let one = DiyFp::new(1 << -mp.e, mp.e);
+            let wp_w = mp - w;
+            let mut p1 = (mp.f >> -one.e) as u32;
+            let mut p2 = mp.f & (one.f - 1);
+            let mut kappa = count_decimal_digit32(p1); // kappa in [0, 9]
+            let mut len = 0;
+
+            /*
+            while (kappa > 0) {
+                uint32_t d = 0;
+                switch (kappa) {
+                    case  9: d = p1 /  100000000; p1 %=  100000000; break;
+                    case  8: d = p1 /   10000000; p1 %=   10000000; break;
+                    case  7: d = p1 /    1000000; p1 %=    1000000; break;
+                    case  6: d = p1 /     100000; p1 %=     100000; break;
+                    case  5: d = p1 /      10000; p1 %=      10000; break;
+                    case  4: d = p1 /       1000; p1 %=       1000; break;
+                    case  3: d = p1 /        100; p1 %=        100; break;
+                    case  2: d = p1 /         10; p1 %=         10; break;
+                    case  1: d = p1;              p1 =           0; break;
+                    default:;
+                }
+                if (d || *len)
+                    buffer[(*len)++] = static_cast<char>('0' + static_cast<char>(d));
+                kappa--;
+                uint64_t tmp = (static_cast<uint64_t>(p1) << -one.e) + p2;
+                if (tmp <= delta) {
+                    *K += kappa;
+                    GrisuRound(buffer, *len, delta, tmp, static_cast<uint64_t>(kPow10[kappa]) << -one.e, wp_w.f);
+                    return;
+                }
+            }
+            */
+            while kappa > 0 {
+                let mut d = 0u32;
+                match kappa {
+                    9 => { d = p1 /  100000000; p1 %=  100000000; }
+                    8 => { d = p1 /   10000000; p1 %=   10000000; }
+                    7 => { d = p1 /    1000000; p1 %=    1000000; }
+                    6 => { d = p1 /     100000; p1 %=     100000; }
+                    5 => { d = p1 /      10000; p1 %=      10000; }
+                    4 => { d = p1 /       1000; p1 %=       1000; }
+                    3 => { d = p1 /        100; p1 %=        100; }
+                    2 => { d = p1 /         10; p1 %=         10; }
+                    1 => { d = p1;              p1 =           0; }
+                    _ => {}
+                }
+                if d != 0 || len != 0 {
+                    *buffer.offset(len) = b'0' + d as u8;
+                    len += 1;
+                }
+                kappa -= 1;
+                let tmp = ((p1 as $sigty) << -one.e) + p2;
+                if tmp <= delta {
+                    k += kappa as isize;
+                    grisu_round(buffer, len, delta, tmp, *POW10.get_unchecked(kappa) << -one.e, wp_w.f);
+                    return (len, k);
+                }
+            }
+
+            // kappa = 0
+            /*
+            for (;;) {
+                p2 *= 10;
+                delta *= 10;
+                char d = static_cast<char>(p2 >> -one.e);
+                if (d || *len)
+                    buffer[(*len)++] = static_cast<char>('0' + d);
+                p2 &= one.f - 1;
+                kappa--;
+                if (p2 < delta) {
+                    *K += kappa;
+                    int index = -static_cast<int>(kappa);
+                    GrisuRound(buffer, *len, delta, p2, one.f, wp_w.f * (index < 9 ? kPow10[-static_cast<int>(kappa)] : 0));
+                    return;
+                }
+            }
+            */
+            loop {
+                p2 *= 10;
+                delta *= 10;
+                let d = (p2 >> -one.e) as u8;
+                if d != 0 || len != 0 {
+                    *buffer.offset(len) = b'0' + d;
+                    len += 1;
+                }
+                p2 &= one.f - 1;
+                kappa = kappa.wrapping_sub(1);
+                if p2 < delta {
+                    k += kappa as isize;
+                    let index = -(kappa as isize);
+                    grisu_round(
+                        buffer,
+                        len,
+                        delta,
+                        p2,
+                        one.f,
+                        wp_w.f * if index < 9 {
+                            *POW10.get_unchecked(-(kappa as isize) as usize)
+                        } else {
+                            0
+                        },
+                    );
+                    return (len, k);
+                }
+            }
+        }
+
+        /*
+        inline void Grisu2(double value, char* buffer, int* length, int* K) {
+            const DiyFp v(value);
+            DiyFp w_m, w_p;
+            v.NormalizedBoundaries(&w_m, &w_p);
+
+            const DiyFp c_mk = GetCachedPower(w_p.e, K);
+            const DiyFp W = v.Normalize() * c_mk;
+            DiyFp Wp = w_p * c_mk;
+            DiyFp Wm = w_m * c_mk;
+            Wm.f++;
+            Wp.f--;
+            DigitGen(W, Wp, Wp.f - Wm.f, buffer, length, K);
+        }
+        */
+
+        // Returns length and k.
+        #[inline]
+        unsafe fn grisu2(value: $fty, buffer: *mut u8) -> (isize, isize) {
+            let v = DiyFp::from(value);
+            let (w_m, w_p) = v.normalized_boundaries();
+
+            let (c_mk, k) = get_cached_power(w_p.e);
+            let w = v.normalize() * c_mk;
+            let mut wp = w_p * c_mk;
+            let mut wm = w_m * c_mk;
+            wm.f += 1;
+            wp.f -= 1;
+            digit_gen(w, wp, wp.f - wm.f, buffer, k)
+        }
+
+        /*
+        inline char* dtoa(double value, char* buffer, int maxDecimalPlaces = 324) {
+            RAPIDJSON_ASSERT(maxDecimalPlaces >= 1);
+            Double d(value);
+            if (d.IsZero()) {
+                if (d.Sign())
+                    *buffer++ = '-';     // -0.0, Issue #289
+                buffer[0] = '0';
+                buffer[1] = '.';
+                buffer[2] = '0';
+                return &buffer[3];
+            }
+            else {
+                if (value < 0) {
+                    *buffer++ = '-';
+                    value = -value;
+                }
+                int length, K;
+                Grisu2(value, buffer, &length, &K);
+                return Prettify(buffer, length, K, maxDecimalPlaces);
+            }
+        }
+        */
+
+        #[inline]
+        unsafe fn dtoa(buf: &mut [MaybeUninit<u8>; 25], mut value: $fty) -> &str {
+            if value == 0.0 {
+                if value.is_sign_negative() {
+                    "-0.0"
+                } else {
+                    "0.0"
+                }
+            } else {
+                let start = buf.as_mut_ptr().cast::<u8>();
+                let mut buf_ptr = start;
+                if value < 0.0 {
+                    *buf_ptr = b'-';
+                    buf_ptr = buf_ptr.offset(1);
+                    value = -value;
+                }
+                let (length, k) = grisu2(value, buf_ptr);
+                let end = prettify(buf_ptr, length, k);
+                let len = end as usize - start as usize;
+                str::from_utf8_unchecked(slice::from_raw_parts(start, len))
+            }
+        }
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Write
+#[inline]
+/// Write the shortest representation of an `f32` into `buf`.
+pub(super) fn write_f32(value: f32, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
+    dtoa_inner! {
+        floating_type: f32,
+        significand_type: u32,
+        exponent_type: i32,
+
+        diy_significand_size: 32,
+        significand_size: 23,
+        exponent_bias: 0x7F,
+        mask_type: u32,
+        exponent_mask: 0x7F800000,
+        significand_mask: 0x007FFFFF,
+        hidden_bit: 0x00800000,
+        cached_powers_f: CACHED_POWERS_F_32,
+        cached_powers_e: CACHED_POWERS_E_32,
+        min_power: (-36),
+    };
+    // SAFETY: dtolnay
+    unsafe { dtoa(buf, value) }
+}
+
+#[inline]
+/// Write the shortest representation of an `f64` into `buf`.
+pub(super) fn write_f64(value: f64, buf: &mut [MaybeUninit<u8>; 25]) -> &str {
+    dtoa_inner! {
+        floating_type: f64,
+        significand_type: u64,
+        exponent_type: isize,
+
+        diy_significand_size: 64,
+        significand_size: 52,
+        exponent_bias: 0x3FF,
+        mask_type: u64,
+        exponent_mask: 0x7FF0000000000000,
+        significand_mask: 0x000FFFFFFFFFFFFF,
+        hidden_bit: 0x0010000000000000,
+        cached_powers_f: CACHED_POWERS_F_64,
+        cached_powers_e: CACHED_POWERS_E_64,
+        min_power: (-348),
+    };
+    // SAFETY: dtolnay
+    unsafe { dtoa(buf, value) }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// 10^-36, 10^-28, ..., 10^52
+#[rustfmt::skip]
+static CACHED_POWERS_F_32: [u32; 12] = [
+    0xaa242499, 0xfd87b5f3, 0xbce50865, 0x8cbccc09,
+    0xd1b71759, 0x9c400000, 0xe8d4a510, 0xad78ebc6,
+    0x813f3979, 0xc097ce7c, 0x8f7e32ce, 0xd5d238a5,
+];
+
+#[rustfmt::skip]
+static CACHED_POWERS_E_32: [i16; 12] = [
+    -151, -125, -98, -71, -45, -18, 8, 35, 62, 88, 115, 141,
+];
+
+// 10^-348, 10^-340, ..., 10^340
+#[rustfmt::skip]
+static CACHED_POWERS_F_64: [u64; 87] = [
+    0xfa8fd5a0081c0288, 0xbaaee17fa23ebf76,
+    0x8b16fb203055ac76, 0xcf42894a5dce35ea,
+    0x9a6bb0aa55653b2d, 0xe61acf033d1a45df,
+    0xab70fe17c79ac6ca, 0xff77b1fcbebcdc4f,
+    0xbe5691ef416bd60c, 0x8dd01fad907ffc3c,
+    0xd3515c2831559a83, 0x9d71ac8fada6c9b5,
+    0xea9c227723ee8bcb, 0xaecc49914078536d,
+    0x823c12795db6ce57, 0xc21094364dfb5637,
+    0x9096ea6f3848984f, 0xd77485cb25823ac7,
+    0xa086cfcd97bf97f4, 0xef340a98172aace5,
+    0xb23867fb2a35b28e, 0x84c8d4dfd2c63f3b,
+    0xc5dd44271ad3cdba, 0x936b9fcebb25c996,
+    0xdbac6c247d62a584, 0xa3ab66580d5fdaf6,
+    0xf3e2f893dec3f126, 0xb5b5ada8aaff80b8,
+    0x87625f056c7c4a8b, 0xc9bcff6034c13053,
+    0x964e858c91ba2655, 0xdff9772470297ebd,
+    0xa6dfbd9fb8e5b88f, 0xf8a95fcf88747d94,
+    0xb94470938fa89bcf, 0x8a08f0f8bf0f156b,
+    0xcdb02555653131b6, 0x993fe2c6d07b7fac,
+    0xe45c10c42a2b3b06, 0xaa242499697392d3,
+    0xfd87b5f28300ca0e, 0xbce5086492111aeb,
+    0x8cbccc096f5088cc, 0xd1b71758e219652c,
+    0x9c40000000000000, 0xe8d4a51000000000,
+    0xad78ebc5ac620000, 0x813f3978f8940984,
+    0xc097ce7bc90715b3, 0x8f7e32ce7bea5c70,
+    0xd5d238a4abe98068, 0x9f4f2726179a2245,
+    0xed63a231d4c4fb27, 0xb0de65388cc8ada8,
+    0x83c7088e1aab65db, 0xc45d1df942711d9a,
+    0x924d692ca61be758, 0xda01ee641a708dea,
+    0xa26da3999aef774a, 0xf209787bb47d6b85,
+    0xb454e4a179dd1877, 0x865b86925b9bc5c2,
+    0xc83553c5c8965d3d, 0x952ab45cfa97a0b3,
+    0xde469fbd99a05fe3, 0xa59bc234db398c25,
+    0xf6c69a72a3989f5c, 0xb7dcbf5354e9bece,
+    0x88fcf317f22241e2, 0xcc20ce9bd35c78a5,
+    0x98165af37b2153df, 0xe2a0b5dc971f303a,
+    0xa8d9d1535ce3b396, 0xfb9b7cd9a4a7443c,
+    0xbb764c4ca7a44410, 0x8bab8eefb6409c1a,
+    0xd01fef10a657842c, 0x9b10a4e5e9913129,
+    0xe7109bfba19c0c9d, 0xac2820d9623bf429,
+    0x80444b5e7aa7cf85, 0xbf21e44003acdd2d,
+    0x8e679c2f5e44ff8f, 0xd433179d9c8cb841,
+    0x9e19db92b4e31ba9, 0xeb96bf6ebadf77d9,
+    0xaf87023b9bf0ee6b,
+];
+
+#[rustfmt::skip]
+static CACHED_POWERS_E_64: [i16; 87] = [
+    -1220, -1193, -1166, -1140, -1113, -1087, -1060, -1034, -1007,  -980,
+    -954,   -927,  -901,  -874,  -847,  -821,  -794,  -768,  -741,  -715,
+    -688,   -661,  -635,  -608,  -582,  -555,  -529,  -502,  -475,  -449,
+    -422,   -396,  -369,  -343,  -316,  -289,  -263,  -236,  -210,  -183,
+    -157,   -130,  -103,   -77,   -50,   -24,     3,    30,    56,    83,
+     109,    136,   162,   189,   216,   242,   269,   295,   322,   348,
+     375,    402,   428,   455,   481,   508,   534,   561,   588,   614,
+     641,    667,   694,   720,   747,   774,   800,   827,   853,   880,
+     907,    933,   960,   986,  1013,  1039,  1066,
+];
@@ -150,21 +150,60 @@ impl Itoa {
 /// assert_eq!(itoa.format(30), "30");
 /// ```
 ///
+/// ## Reuse
+/// There is no per-format cost other than the formatting itself,
+/// so a single [`ItoaTmp`] can be used in a loop:
+/// ```rust
+/// # use readable::toa::*;
+/// let mut itoa = ItoaTmp::new();
+/// let mut total = 0;
+///
+/// for i in 0..1000_u32 {
+///     total += itoa.format(i).len();
+/// }
+/// assert_eq!(total, 2890);
+///
+/// // The last formatted string is kept until the next format.
+/// assert_eq!(itoa.as_str(), "999");
+/// itoa.clear();
+/// assert!(itoa.is_empty());
+/// ```
+///
 /// ## Size
 /// ```rust
 /// # use readable::toa::*;
-/// assert_eq!(std::mem::size_of::<ItoaTmp>(), 40);
+/// assert_eq!(std::mem::size_of::<ItoaTmp>(), 42);
 /// ```
 #[derive(Copy, Clone, Debug)]
 pub struct ItoaTmp {
+    len: u8,
+    offset: u8,
     bytes: [MaybeUninit<u8>; I128_MAX_LEN],
 }
 
+impl Default for ItoaTmp {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ItoaTmp {
+    /// The byte capacity of the inner buffer, enough for any [`Integer`]
+    ///
+    /// ```rust
+    /// # use readable::toa::*;
+    /// assert_eq!(ItoaTmp::CAPACITY, 40);
+    /// assert_eq!(ItoaTmp::CAPACITY, i128::MIN.to_string().len());
+    /// ```
+    pub const CAPACITY: usize = I128_MAX_LEN;
+
     #[inline]
-    /// Create a new [`ItoaTmp`].
+    /// Create a new, empty [`ItoaTmp`].
     pub const fn new() -> Self {
         Self {
+            len: 0,
+            offset: 0,
             bytes: [MaybeUninit::<u8>::uninit(); I128_MAX_LEN],
         }
     }
@@ -185,15 +224,97 @@ impl ItoaTmp {
     /// ```
     pub fn format<I: Integer>(&mut self, integer: I) -> &str {
         // SAFETY: dtolnay
+        let (len, offset) = integer.write(unsafe {
+            &mut *(&mut self.bytes as *mut [MaybeUninit<u8>; I128_MAX_LEN]
+                as *mut <I as private::Sealed>::Itoa)
+        });
+        self.len = len as u8;
+        self.offset = offset as u8;
+        self.as_str()
+    }
+
+    #[inline]
+    /// Format an [`Integer`] and write it into `w`
+    ///
+    /// The returned [`&str`] is the same one written, and is kept in `self`.
+    ///
+    /// ```rust
+    /// # use readable::toa::ItoaTmp;
+    /// let mut itoa = ItoaTmp::new();
+    /// let mut string = String::new();
+    ///
+    /// for i in [1, 22, 333] {
+    ///     let s = itoa.format_into(i, &mut string).unwrap();
+    ///     assert_eq!(s, i.to_string());
+    /// }
+    /// assert_eq!(string, "122333");
+    /// ```
+    ///
+    /// ## Errors
+    /// This errors if `w` errors.
+    pub fn format_into<I: Integer, W: std::fmt::Write>(
+        &mut self,
+        integer: I,
+        w: &mut W,
+    ) -> Result<&str, std::fmt::Error> {
+        let s = self.format(integer);
+        w.write_str(s)?;
+        Ok(s)
+    }
+
+    #[inline]
+    /// The last formatted [`&str`]
+    ///
+    /// This is empty after [`ItoaTmp::new`] and [`ItoaTmp::clear`].
+    ///
+    /// ```rust
+    /// # use readable::toa::ItoaTmp;
+    /// let mut itoa = ItoaTmp::new();
+    /// assert_eq!(itoa.as_str(), "");
+    ///
+    /// itoa.format(-123);
+    /// assert_eq!(itoa.as_str(), "-123");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        // Safety: `len` and `offset` are always set to an initialized range.
         unsafe {
-            let (len, offset) = integer.write(
-                &mut *(&mut self.bytes as *mut [MaybeUninit<u8>; I128_MAX_LEN]
-                    as *mut <I as private::Sealed>::Itoa),
+            let slice = slice::from_raw_parts(
+                self.bytes
+                    .as_ptr()
+                    .offset(self.offset as isize)
+                    .cast::<u8>(),
+                self.len as usize,
             );
-            let slice = slice::from_raw_parts(self.bytes.as_ptr().offset(offset) as *const u8, len);
             std::str::from_utf8_unchecked(slice)
         }
     }
+
+    #[inline]
+    /// Returns the `str` byte length of the last formatted string
+    pub const fn len(&self) -> u8 {
+        self.len
+    }
+
+    #[inline]
+    /// If there is no formatted string
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    /// Forget the last formatted string
+    ///
+    /// This is cheap, the buffer is not zeroed.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.offset = 0;
+    }
+
+    #[inline]
+    /// Returns [`ItoaTmp::CAPACITY`]
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private Itoa
@@ -337,7 +458,7 @@ impl<T: Integer> std::convert::From<T> for Itoa {
 
 impl std::fmt::Display for Itoa {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        crate::str::pad(f, self.as_str())
     }
 }
 
@@ -589,6 +710,22 @@ impl_non_zero!(I128_MAX_LEN => NonZeroI128 => i128, U128_MAX_LEN => NonZeroU128
 mod tests {
     use super::*;
 
+    #[test]
+    fn tmp_reuse() {
+        let mut itoa = ItoaTmp::new();
+        for i in [i128::MIN, -1, 0, 1, i128::MAX] {
+            let len = itoa.format(i).len();
+            assert_eq!(itoa.as_str(), i.to_string());
+            assert_eq!(usize::from(itoa.len()), len);
+            assert!(len <= itoa.capacity());
+        }
+        for i in [u128::MAX, 0] {
+            assert_eq!(itoa.format(i), i.to_string());
+        }
+        itoa.clear();
+        assert_eq!(itoa.as_str(), "");
+    }
+
     #[test]
     fn all_u128_lengths() {
         for i in [
@@ -7,6 +7,14 @@
 //!
 //! The strings are stack allocated.
 //!
+//! [`Itoa`] covers every integer up to [`u128`]/[`i128`], and
+//! [`Dtoa`] covers [`f32`]/[`f64`], and with the `half` feature, `half::f16`/`half::bf16`.
+//!
+//! With the `ryu` feature, [`Dtoa`] uses [`ryu`](https://docs.rs/ryu) instead of the
+//! built-in Grisu2 implementation. The API is the same and both output the shortest
+//! round-trip representation, although `ryu` switches to an exponent sooner,
+//! e.g. `1e16` instead of `10000000000000000.0`.
+//!
 //! ```rust
 //! use readable::{
 //!     toa::{Itoa, Dtoa},
@@ -28,8 +36,11 @@
 //! ```
 
 //---------------------------------------------------------------------------------------------------- Dtoa
+#[cfg(not(feature = "ryu"))]
 #[macro_use]
 mod diyfp;
+#[cfg(not(feature = "ryu"))]
+mod grisu;
 #[macro_use]
 mod dtoa;
 pub use dtoa::{Dtoa, DtoaTmp, IntoDtoa};
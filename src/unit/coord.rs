@@ -0,0 +1,466 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_std_from_str, impl_traits};
+use crate::str::Str;
+use crate::toa::ItoaTmp;
+
+//---------------------------------------------------------------------------------------------------- Coord
+/// Human-readable latitude/longitude coordinate
+///
+/// The inner "integer" is a tuple of `(latitude, longitude)` in decimal degrees.
+///
+/// - The latitude must be `-90.0..=90.0`
+/// - The longitude must be `-180.0..=180.0`
+///
+/// There are 2 formats:
+/// - [`Coord::dms`], degrees/minutes/seconds, e.g. `40°26′46″N 79°58′56″W`
+/// - [`Coord::decimal`], signed decimal degrees, e.g. `40.4461, -79.9822`
+///
+/// ```rust
+/// # use readable::unit::*;
+/// let coord = Coord::new(40.4461, -79.9822).unwrap();
+/// assert_eq!(coord, "40°26′46″N 79°58′56″W");
+/// assert_eq!(coord, (40.4461, -79.9822));
+///
+/// let coord = Coord::dms(40.4461, -79.9822, 1).unwrap();
+/// assert_eq!(coord, "40°26′46.0″N 79°58′55.9″W");
+///
+/// let coord = Coord::decimal(40.4461, -79.9822, 2).unwrap();
+/// assert_eq!(coord, "40.45, -79.98");
+/// ```
+///
+/// Unlike the other `unit` types, the numbers are rounded, not truncated.
+///
+/// ## Parsing
+/// [`Coord::from_str`] parses both formats back,
+/// keeping the format and the precision of the input.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// let coord = Coord::from_str("40°26′46″N 79°58′56″W").unwrap();
+/// assert_eq!(coord, "40°26′46″N 79°58′56″W");
+///
+/// let coord = Coord::from_str("40.4461, -79.9822").unwrap();
+/// assert_eq!(coord, "40.4461, -79.9822");
+/// ```
+///
+/// ## Size
+/// [`Str<40>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(std::mem::size_of::<Coord>(), 64);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct Coord((f64, f64), Str<{ Coord::MAX_LEN }>);
+
+impl_traits!(Coord, (f64, f64));
+impl_std_from_str!(Coord => from_str);
+
+//---------------------------------------------------------------------------------------------------- Coord Constants
+impl Coord {
+    /// The maximum string length of a [`Coord`]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// let max = Coord::dms(-90.0, -180.0, Coord::MAX_DMS_PRECISION).unwrap();
+    /// assert_eq!(max, "90°00′00.000″S 180°00′00.000″W");
+    /// assert_eq!(max.len(), Coord::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 40;
+
+    /// The maximum decimals of the seconds in [`Coord::dms`]
+    pub const MAX_DMS_PRECISION: u8 = 3;
+
+    /// The maximum decimals in [`Coord::decimal`]
+    pub const MAX_DECIMAL_PRECISION: u8 = 8;
+
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Coord::ZERO, (0.0, 0.0));
+    /// assert_eq!(Coord::ZERO, "0°00′00″N 0°00′00″E");
+    /// ```
+    pub const ZERO: Self = Self((0.0, 0.0), Str::from_static_str("0°00′00″N 0°00′00″E"));
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Coord::UNKNOWN, (0.0, 0.0));
+    /// assert_eq!(Coord::UNKNOWN, "?°??′??″? ?°??′??″?");
+    /// ```
+    pub const UNKNOWN: Self = Self((0.0, 0.0), Str::from_static_str("?°??′??″? ?°??′??″?"));
+}
+
+//---------------------------------------------------------------------------------------------------- Coord Impl
+impl Coord {
+    impl_common!((f64, f64));
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert!(Coord::UNKNOWN.is_unknown());
+    /// assert!(!Coord::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(
+            self.1.as_bytes(),
+            b"?\xC2\xB0??\xE2\x80\xB2??\xE2\x80\xB3? ?\xC2\xB0??\xE2\x80\xB2??\xE2\x80\xB3?"
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner latitude
+    pub const fn lat(&self) -> f64 {
+        self.0 .0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner longitude
+    pub const fn lon(&self) -> f64 {
+        self.0 .1
+    }
+
+    #[inline]
+    /// Same as [`Coord::dms`] with no decimals, e.g. `40°26′46″N 79°58′56″W`
+    ///
+    /// ## Errors
+    /// If the latitude or longitude is out of range (or not finite),
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Coord::new(91.0, 0.0), Err(Coord::UNKNOWN));
+    /// assert_eq!(Coord::new(0.0, -180.1), Err(Coord::UNKNOWN));
+    /// assert_eq!(Coord::new(f64::NAN, 0.0), Err(Coord::UNKNOWN));
+    /// ```
+    pub fn new(lat: f64, lon: f64) -> Result<Self, Self> {
+        Self::dms(lat, lon, 0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Coord::new`] but silently returns an [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn new_silent(lat: f64, lon: f64) -> Self {
+        match Self::new(lat, lon) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+
+    /// Format as degrees/minutes/seconds, with `precision` decimals on the seconds
+    ///
+    /// `precision` is capped at [`Coord::MAX_DMS_PRECISION`].
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Coord::dms(51.5007, -0.1246, 0).unwrap(), "51°30′03″N 0°07′29″W");
+    /// assert_eq!(Coord::dms(51.5007, -0.1246, 2).unwrap(), "51°30′02.52″N 0°07′28.56″W");
+    /// assert_eq!(Coord::dms(-33.8568, 151.2153, 9).unwrap(), "33°51′24.480″S 151°12′55.080″E");
+    /// ```
+    ///
+    /// ## Errors
+    /// Same as [`Coord::new`].
+    pub fn dms(lat: f64, lon: f64, precision: u8) -> Result<Self, Self> {
+        if !Self::ok(lat, lon) {
+            return Err(Self::UNKNOWN);
+        }
+
+        let precision = precision.min(Self::MAX_DMS_PRECISION);
+        let mut s = Str::new();
+        Self::push_dms(&mut s, lat, precision, if lat < 0.0 { 'S' } else { 'N' });
+        s.push_char_panic(' ');
+        Self::push_dms(&mut s, lon, precision, if lon < 0.0 { 'W' } else { 'E' });
+
+        Ok(Self((lat, lon), s))
+    }
+
+    /// Format as signed decimal degrees, with `precision` decimals
+    ///
+    /// `precision` is capped at [`Coord::MAX_DECIMAL_PRECISION`].
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Coord::decimal(51.5007, -0.1246, 0).unwrap(), "52, 0");
+    /// assert_eq!(Coord::decimal(51.5007, -0.1246, 4).unwrap(), "51.5007, -0.1246");
+    /// assert_eq!(Coord::decimal(-33.8568, 151.2153, 6).unwrap(), "-33.856800, 151.215300");
+    /// ```
+    ///
+    /// ## Errors
+    /// Same as [`Coord::new`].
+    pub fn decimal(lat: f64, lon: f64, precision: u8) -> Result<Self, Self> {
+        if !Self::ok(lat, lon) {
+            return Err(Self::UNKNOWN);
+        }
+
+        let precision = precision.min(Self::MAX_DECIMAL_PRECISION);
+        let mut s = Str::new();
+        Self::push_decimal(&mut s, lat, precision);
+        s.push_str_panic(", ");
+        Self::push_decimal(&mut s, lon, precision);
+
+        Ok(Self((lat, lon), s))
+    }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a [`Coord`] string in either format
+    ///
+    /// The output keeps the format and the precision of the input.
+    ///
+    /// The degrees/minutes/seconds format also accepts:
+    /// - ASCII `d`, `'` and `"` instead of `°`, `′` and `″`
+    /// - Missing minutes and seconds, e.g. `40.4461°N`
+    /// - Spaces or a comma between the parts
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// let coord = Coord::from_str("40°26′46.3″N 79°58′55.9″W").unwrap();
+    /// assert_eq!(coord, "40°26′46.3″N 79°58′55.9″W");
+    ///
+    /// let coord = Coord::from_str(r#"40d 26' 46" N, 79d 58' 56" W"#).unwrap();
+    /// assert_eq!(coord, "40°26′46″N 79°58′56″W");
+    ///
+    /// let coord = Coord::from_str("40.5°N 79.5°W").unwrap();
+    /// assert_eq!(coord, "40°30′00″N 79°30′00″W");
+    ///
+    /// let coord = Coord::from_str("  -33.86 151.21 ").unwrap();
+    /// assert_eq!(coord, "-33.86, 151.21");
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not a coordinate, or is out of range,
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert!(Coord::from_str("").is_err());
+    /// assert!(Coord::from_str("40.0").is_err());
+    /// assert!(Coord::from_str("91.0, 0.0").is_err());
+    /// assert!(Coord::from_str("40°N 79°N").is_err());
+    /// assert!(Coord::from_str(Coord::UNKNOWN.as_str()).is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        let dms = string.contains(['N', 'S', 'E', 'W']);
+
+        // Split hemispheres off the numbers, e.g. `46″N` -> `46 N`.
+        let mut tokens = string
+            .split(|c: char| {
+                c.is_whitespace() || matches!(c, ',' | '°' | '′' | '″' | 'd' | '\'' | '"')
+            })
+            .flat_map(|t| match t.find(['N', 'S', 'E', 'W']) {
+                Some(i) if i != 0 => [&t[..i], &t[i..]],
+                _ => [t, ""],
+            })
+            .filter(|t| !t.is_empty());
+
+        let parsed = if dms {
+            Self::parse_dms(&mut tokens, ['N', 'S']).and_then(|(lat, p1)| {
+                Self::parse_dms(&mut tokens, ['E', 'W']).map(|(lon, p2)| (lat, lon, p1.max(p2)))
+            })
+        } else {
+            Self::parse_decimal(tokens.next()).and_then(|(lat, p1)| {
+                Self::parse_decimal(tokens.next()).map(|(lon, p2)| (lat, lon, p1.max(p2)))
+            })
+        };
+
+        match parsed {
+            Some((lat, lon, precision)) if tokens.next().is_none() => {
+                if dms {
+                    Self::dms(lat, lon, precision)
+                } else {
+                    Self::decimal(lat, lon, precision)
+                }
+            }
+            _ => Err(Self::UNKNOWN),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Coord::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Coord impl (private)
+impl Coord {
+    #[inline]
+    fn ok(lat: f64, lon: f64) -> bool {
+        (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)
+    }
+
+    /// Push a `10^precision` scaled number with `precision` zero-padded decimals.
+    fn push_fraction<const N: usize>(s: &mut Str<N>, scaled: u64, precision: u8) {
+        let pow = 10_u64.pow(u32::from(precision));
+        let mut itoa = ItoaTmp::new();
+        s.push_str_panic(itoa.format(scaled / pow));
+
+        if precision != 0 {
+            s.push_char_panic('.');
+            let frac = itoa.format(scaled % pow);
+            for _ in frac.len()..usize::from(precision) {
+                s.push_char_panic('0');
+            }
+            s.push_str_panic(frac);
+        }
+    }
+
+    fn push_dms<const N: usize>(s: &mut Str<N>, degrees: f64, precision: u8, hemisphere: char) {
+        let pow = 10_u64.pow(u32::from(precision));
+        // Round once on the total, so `59.9″` carries into the minutes.
+        let total = (degrees.abs() * 3600.0 * pow as f64).round() as u64;
+        let (d, rem) = (total / (3600 * pow), total % (3600 * pow));
+        let (m, sec) = (rem / (60 * pow), rem % (60 * pow));
+
+        let mut itoa = ItoaTmp::new();
+        s.push_str_panic(itoa.format(d));
+        s.push_char_panic('°');
+        if m < 10 {
+            s.push_char_panic('0');
+        }
+        s.push_str_panic(itoa.format(m));
+        s.push_char_panic('′');
+        if sec < 10 * pow {
+            s.push_char_panic('0');
+        }
+        Self::push_fraction(s, sec, precision);
+        s.push_char_panic('″');
+        s.push_char_panic(hemisphere);
+    }
+
+    fn push_decimal<const N: usize>(s: &mut Str<N>, degrees: f64, precision: u8) {
+        let scaled = (degrees.abs() * 10_u64.pow(u32::from(precision)) as f64).round() as u64;
+        // No `-0`.
+        if degrees < 0.0 && scaled != 0 {
+            s.push_char_panic('-');
+        }
+        Self::push_fraction(s, scaled, precision);
+    }
+
+    /// Parse a number, and how many decimals it has.
+    fn parse_decimal(token: Option<&str>) -> Option<(f64, u8)> {
+        let token = token?;
+        let n = token.parse::<f64>().ok()?;
+        let decimals = token.find('.').map_or(0, |i| token.len() - i - 1);
+        Some((n, u8::try_from(decimals).unwrap_or(u8::MAX)))
+    }
+
+    /// Parse up to 3 numbers (degrees, minutes, seconds) then 1 of the `hemispheres`.
+    fn parse_dms(
+        tokens: &mut dyn Iterator<Item = &str>,
+        hemispheres: [char; 2],
+    ) -> Option<(f64, u8)> {
+        let mut degrees = 0.0;
+        let mut precision = 0;
+
+        for (i, scale) in [1.0, 60.0, 3600.0, 0.0].into_iter().enumerate() {
+            let token = tokens.next()?;
+
+            if i != 0 {
+                if token == hemispheres[0].encode_utf8(&mut [0; 4]) {
+                    return Some((degrees, precision));
+                }
+                if token == hemispheres[1].encode_utf8(&mut [0; 4]) {
+                    return Some((-degrees, precision));
+                }
+            }
+
+            if scale == 0.0 {
+                return None;
+            }
+            let (n, p) = Self::parse_decimal(Some(token))?;
+            // Minutes and seconds must be `0..60`.
+            if !n.is_sign_positive() || (i != 0 && n >= 60.0) {
+                return None;
+            }
+            degrees += n / scale;
+            // Only the seconds' decimals are kept.
+            if i == 2 {
+                precision = p;
+            }
+        }
+
+        None
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dms_round_trip() {
+        for lat in (-90..=90).step_by(7) {
+            for lon in (-180..=180).step_by(11) {
+                let (lat, lon) = (f64::from(lat) + 0.123_456, f64::from(lon) - 0.654_321);
+                let Ok(coord) = Coord::dms(lat, lon, 3) else {
+                    continue;
+                };
+                let parsed = Coord::from_str(&coord).unwrap();
+                assert_eq!(parsed.as_str(), coord.as_str());
+                assert!((parsed.lat() - lat).abs() < 0.000_001);
+                assert!((parsed.lon() - lon).abs() < 0.000_001);
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        for precision in 0..=Coord::MAX_DECIMAL_PRECISION {
+            let coord = Coord::decimal(-12.345_678_9, 98.765_432_1, precision).unwrap();
+            let parsed = Coord::from_str(&coord).unwrap();
+            assert_eq!(parsed.as_str(), coord.as_str());
+        }
+    }
+
+    #[test]
+    fn carry() {
+        // `59.9999″` rounds up into the minutes and degrees.
+        let coord = Coord::new(10.0 - 0.000_001, 0.0).unwrap();
+        assert_eq!(coord, "10°00′00″N 0°00′00″E");
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(Coord::new(90.0, 180.0).is_ok());
+        assert!(Coord::new(-90.0, -180.0).is_ok());
+        assert!(Coord::new(90.000_001, 0.0).is_err());
+        assert!(Coord::new(0.0, f64::INFINITY).is_err());
+        assert!(Coord::from_str("90°00′01″N 0°E").is_err());
+        assert!(Coord::from_str("0°N 0°E 0°E").is_err());
+        assert!(Coord::from_str("0°60′N 0°E").is_err());
+        assert!(Coord::from_str("0°5′N 0°5′5″E").is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Coord::decimal(40.5, -79.5, 1).unwrap();
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[[40.5,-79.5],"40.5, -79.5"]"#);
+
+        let this: Coord = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, (40.5, -79.5));
+        assert_eq!(this, "40.5, -79.5");
+    }
+}
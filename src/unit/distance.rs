@@ -0,0 +1,268 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_float_eq, impl_traits};
+use crate::str::Str;
+use crate::unit::System;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// 1 `kilometer` in `meters`
+const KILOMETER: f64 = 1_000.0;
+/// 1 `foot` in `meters`
+const FOOT: f64 = 0.3048;
+/// 1 `mile` in `meters`
+const MILE: f64 = 1_609.344;
+
+//---------------------------------------------------------------------------------------------------- Distance
+/// Human-readable distance formatting
+///
+/// This takes meters as input and will store a formatted
+/// string with the proper unit for the [`System`].
+///
+/// The unit will increase as the inner number increases, for example:
+/// ```rust
+/// # use readable::unit::*;
+/// // Metric.
+/// assert_eq!(Distance::metric(0.5),           "0 m");
+/// assert_eq!(Distance::metric(450.0),         "450 m");
+/// assert_eq!(Distance::metric(999.9),         "999 m");
+/// assert_eq!(Distance::metric(1_000.0),       "1.0 km");
+/// assert_eq!(Distance::metric(1_250.0),       "1.2 km");
+/// assert_eq!(Distance::metric(42_195.0),      "42.1 km");
+/// assert_eq!(Distance::metric(12_345_678.0),  "12,345.6 km");
+///
+/// // Imperial.
+/// assert_eq!(Distance::imperial(0.3048),      "1 ft");
+/// assert_eq!(Distance::imperial(1_000.0),     "3,280 ft");
+/// assert_eq!(Distance::imperial(1_609.0),     "5,278 ft");
+/// assert_eq!(Distance::imperial(1_609.344),   "1.0 mi");
+/// assert_eq!(Distance::imperial(42_195.0),    "26.2 mi");
+/// ```
+///
+/// Whole units (`m`, `ft`) have no decimals, larger units (`km`, `mi`) have 1 decimal.
+///
+/// Like [`Byte`](crate::byte::Byte), the numbers are truncated, not rounded.
+///
+/// The maximum input is [`Distance::MAX`], `1,000,000,000,000.0 km`.
+///
+/// ## Input
+/// The inner [`f64`] is the input in meters,
+/// the [`System`] only changes the output string.
+///
+/// [`From`] input can be:
+/// - Any unsigned integer [`u8`], [`usize`], etc
+/// - Any signed integer [`i8`], [`isize`], etc
+/// - [`f32`] or [`f64`]
+///
+/// [`From`] always uses [`System::Metric`].
+///
+/// ```rust
+/// # use readable::unit::*;
+/// let distance = Distance::imperial(1_000.0);
+/// assert_eq!(distance, 1_000.0);
+/// assert_eq!(distance, "3,280 ft");
+/// assert_eq!(Distance::from(1_000), "1.0 km");
+/// ```
+///
+/// ## Errors
+/// A [`Distance::UNKNOWN`] will be returned if the input is:
+/// - Negative
+/// - Larger than [`Distance::MAX`]
+/// - [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`]
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(Distance::from(-1.0), Distance::unknown());
+/// assert_eq!(Distance::from(f64::NAN), Distance::unknown());
+/// assert_eq!(Distance::from(1e16), Distance::unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<22>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(std::mem::size_of::<Distance>(), 32);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Distance(f64, Str<{ Distance::MAX_LEN }>);
+
+impl_traits!(Distance, f64);
+impl_float_eq!(Distance => f64);
+
+//---------------------------------------------------------------------------------------------------- Distance Constants
+impl Distance {
+    /// The maximum string length of a [`Distance`]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Distance::MAX.len(), Distance::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 22;
+
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Distance::ZERO, 0.0);
+    /// assert_eq!(Distance::ZERO, "0 m");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0 m"));
+
+    /// The maximum input, `1,000,000,000,000` kilometers
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Distance::MAX, 1e15);
+    /// assert_eq!(Distance::MAX, "1,000,000,000,000.0 km");
+    /// assert_eq!(Distance::MAX, Distance::from(1e15));
+    /// ```
+    pub const MAX: Self = Self(1e15, Str::from_static_str("1,000,000,000,000.0 km"));
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Distance::UNKNOWN, 0.0);
+    /// assert_eq!(Distance::UNKNOWN, "?.? m");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.? m"));
+}
+
+//---------------------------------------------------------------------------------------------------- Distance Impl
+impl Distance {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert!(Distance::UNKNOWN.is_unknown());
+    /// assert!(!Distance::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?.? m")
+    }
+
+    #[must_use]
+    /// Create a [`Distance`] from `meters`, formatted with the units of `system`
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Distance::new(5_000.0, System::Metric), "5.0 km");
+    /// assert_eq!(Distance::new(5_000.0, System::Imperial), "3.1 mi");
+    /// ```
+    pub fn new(meters: f64, system: System) -> Self {
+        if !(0.0..=Self::MAX.0).contains(&meters) {
+            return Self::UNKNOWN;
+        }
+
+        let string = match system {
+            System::Metric if meters < KILOMETER => super::whole(meters, "m"),
+            System::Metric => super::tenths(meters / KILOMETER, "km"),
+            System::Imperial if meters < MILE => super::whole(meters / FOOT, "ft"),
+            System::Imperial => super::tenths(meters / MILE, "mi"),
+        };
+
+        Self(meters, string)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Distance::new`] with [`System::Metric`], e.g. `1.2 km`
+    pub fn metric(meters: f64) -> Self {
+        Self::new(meters, System::Metric)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Distance::new`] with [`System::Imperial`], e.g. `1.2 mi`
+    pub fn imperial(meters: f64) -> Self {
+        Self::new(meters, System::Imperial)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Distance {
+			#[inline]
+			/// Input is in meters, formatted with [`System::Metric`].
+			fn from(meters: $from) -> Self {
+				Self::metric(meters as f64)
+			}
+		}
+		impl From<&$from> for Distance {
+			#[inline]
+			/// Input is in meters, formatted with [`System::Metric`].
+			fn from(meters: &$from) -> Self {
+				Self::metric(*meters as f64)
+			}
+		}
+	)*}
+}
+impl_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metric() {
+        for m in 0..1_000_u32 {
+            assert_eq!(Distance::from(m).as_str(), format!("{m} m"));
+        }
+        for m in (1_000..100_000_u32).step_by(100) {
+            let km = m / 1_000;
+            let tenth = m / 100 % 10;
+            assert_eq!(Distance::from(m).as_str(), format!("{km}.{tenth} km"));
+        }
+    }
+
+    #[test]
+    fn imperial() {
+        for ft in 0..5_280_u32 {
+            let meters = f64::from(ft) * FOOT;
+            assert_eq!(
+                Distance::imperial(meters).as_str(),
+                format!("{} ft", crate::num::Unsigned::from(ft))
+            );
+        }
+        for tenths in 10..1_000_u32 {
+            let meters = f64::from(tenths) * MILE / 10.0;
+            let expected = format!("{}.{} mi", tenths / 10, tenths % 10);
+            assert_eq!(Distance::imperial(meters).as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Distance::metric(1e15), Distance::MAX);
+        assert_eq!(Distance::imperial(1e15), "621,371,192,237.3 mi");
+        assert!(Distance::imperial(1e15 + 1.0).is_unknown());
+        assert!(Distance::from(f64::INFINITY).is_unknown());
+        assert!(Distance::from(i64::MIN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Distance::metric(1_200.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[1200.0,"1.2 km"]"#);
+
+        let this: Distance = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 1_200.0);
+        assert_eq!(this, "1.2 km");
+    }
+}
@@ -0,0 +1,265 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_float_eq, impl_traits};
+use crate::str::Str;
+use crate::unit::System;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// 1 `kilogram` in `grams`
+const KILOGRAM: f64 = 1_000.0;
+/// 1 `ounce` in `grams`
+const OUNCE: f64 = 28.349_523_125;
+/// 1 `pound` in `grams`
+const POUND: f64 = 453.592_37;
+
+//---------------------------------------------------------------------------------------------------- Mass
+/// Human-readable mass formatting
+///
+/// This takes grams as input and will store a formatted
+/// string with the proper unit for the [`System`].
+///
+/// The unit will increase as the inner number increases, for example:
+/// ```rust
+/// # use readable::unit::*;
+/// // Metric.
+/// assert_eq!(Mass::metric(0.5),        "0 g");
+/// assert_eq!(Mass::metric(450.0),      "450 g");
+/// assert_eq!(Mass::metric(999.9),      "999 g");
+/// assert_eq!(Mass::metric(1_000.0),    "1.0 kg");
+/// assert_eq!(Mass::metric(1_250.0),    "1.2 kg");
+/// assert_eq!(Mass::metric(75_000.0),   "75.0 kg");
+///
+/// // Imperial.
+/// assert_eq!(Mass::imperial(28.35),    "1 oz");
+/// assert_eq!(Mass::imperial(100.0),    "3 oz");
+/// assert_eq!(Mass::imperial(453.0),    "15 oz");
+/// assert_eq!(Mass::imperial(453.6),    "1.0 lb");
+/// assert_eq!(Mass::imperial(5_000.0),  "11.0 lb");
+/// ```
+///
+/// Whole units (`g`, `oz`) have no decimals, larger units (`kg`, `lb`) have 1 decimal.
+///
+/// Like [`Distance`](crate::unit::Distance), the numbers are truncated, not rounded.
+///
+/// The maximum input is [`Mass::MAX`], `1,000,000,000,000.0 kg`.
+///
+/// ## Input
+/// The inner [`f64`] is the input in grams,
+/// the [`System`] only changes the output string.
+///
+/// [`From`] input can be:
+/// - Any unsigned integer [`u8`], [`usize`], etc
+/// - Any signed integer [`i8`], [`isize`], etc
+/// - [`f32`] or [`f64`]
+///
+/// [`From`] always uses [`System::Metric`].
+///
+/// ```rust
+/// # use readable::unit::*;
+/// let mass = Mass::imperial(1_000.0);
+/// assert_eq!(mass, 1_000.0);
+/// assert_eq!(mass, "2.2 lb");
+/// assert_eq!(Mass::from(1_000), "1.0 kg");
+/// ```
+///
+/// ## Errors
+/// A [`Mass::UNKNOWN`] will be returned if the input is:
+/// - Negative
+/// - Larger than [`Mass::MAX`]
+/// - [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`]
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(Mass::from(-1.0), Mass::unknown());
+/// assert_eq!(Mass::from(f64::NAN), Mass::unknown());
+/// assert_eq!(Mass::from(1e16), Mass::unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<22>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(std::mem::size_of::<Mass>(), 32);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Mass(f64, Str<{ Mass::MAX_LEN }>);
+
+impl_traits!(Mass, f64);
+impl_float_eq!(Mass => f64);
+
+//---------------------------------------------------------------------------------------------------- Mass Constants
+impl Mass {
+    /// The maximum string length of a [`Mass`]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Mass::MAX.len(), Mass::MAX_LEN);
+    /// assert_eq!(Mass::imperial(1e15).len(), Mass::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 22;
+
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Mass::ZERO, 0.0);
+    /// assert_eq!(Mass::ZERO, "0 g");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0 g"));
+
+    /// The maximum input, `1,000,000,000,000` kilograms
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Mass::MAX, 1e15);
+    /// assert_eq!(Mass::MAX, "1,000,000,000,000.0 kg");
+    /// assert_eq!(Mass::MAX, Mass::from(1e15));
+    /// ```
+    pub const MAX: Self = Self(1e15, Str::from_static_str("1,000,000,000,000.0 kg"));
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Mass::UNKNOWN, 0.0);
+    /// assert_eq!(Mass::UNKNOWN, "?.? g");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.? g"));
+}
+
+//---------------------------------------------------------------------------------------------------- Mass Impl
+impl Mass {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert!(Mass::UNKNOWN.is_unknown());
+    /// assert!(!Mass::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?.? g")
+    }
+
+    #[must_use]
+    /// Create a [`Mass`] from `grams`, formatted with the units of `system`
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Mass::new(5_000.0, System::Metric), "5.0 kg");
+    /// assert_eq!(Mass::new(5_000.0, System::Imperial), "11.0 lb");
+    /// ```
+    pub fn new(grams: f64, system: System) -> Self {
+        if !(0.0..=Self::MAX.0).contains(&grams) {
+            return Self::UNKNOWN;
+        }
+
+        let string = match system {
+            System::Metric if grams < KILOGRAM => super::whole(grams, "g"),
+            System::Metric => super::tenths(grams / KILOGRAM, "kg"),
+            System::Imperial if grams < POUND => super::whole(grams / OUNCE, "oz"),
+            System::Imperial => super::tenths(grams / POUND, "lb"),
+        };
+
+        Self(grams, string)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Mass::new`] with [`System::Metric`], e.g. `1.2 kg`
+    pub fn metric(grams: f64) -> Self {
+        Self::new(grams, System::Metric)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Mass::new`] with [`System::Imperial`], e.g. `1.2 lb`
+    pub fn imperial(grams: f64) -> Self {
+        Self::new(grams, System::Imperial)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Mass {
+			#[inline]
+			/// Input is in grams, formatted with [`System::Metric`].
+			fn from(grams: $from) -> Self {
+				Self::metric(grams as f64)
+			}
+		}
+		impl From<&$from> for Mass {
+			#[inline]
+			/// Input is in grams, formatted with [`System::Metric`].
+			fn from(grams: &$from) -> Self {
+				Self::metric(*grams as f64)
+			}
+		}
+	)*}
+}
+impl_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metric() {
+        for g in 0..1_000_u32 {
+            assert_eq!(Mass::from(g).as_str(), format!("{g} g"));
+        }
+        for g in (1_000..100_000_u32).step_by(100) {
+            let kg = g / 1_000;
+            let tenth = g / 100 % 10;
+            assert_eq!(Mass::from(g).as_str(), format!("{kg}.{tenth} kg"));
+        }
+    }
+
+    #[test]
+    fn imperial() {
+        for oz in 0..16_u32 {
+            let grams = f64::from(oz) * OUNCE;
+            assert_eq!(Mass::imperial(grams).as_str(), format!("{oz} oz"));
+        }
+        for tenths in 10..1_000_u32 {
+            let grams = f64::from(tenths) * POUND / 10.0;
+            let expected = format!("{}.{} lb", tenths / 10, tenths % 10);
+            assert_eq!(Mass::imperial(grams).as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Mass::metric(1e15), Mass::MAX);
+        assert_eq!(Mass::imperial(1e15), "2,204,622,621,848.7 lb");
+        assert!(Mass::imperial(1e15 + 1.0).is_unknown());
+        assert!(Mass::from(f64::NEG_INFINITY).is_unknown());
+        assert!(Mass::from(i64::MIN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Mass::metric(1_200.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[1200.0,"1.2 kg"]"#);
+
+        let this: Mass = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 1_200.0);
+        assert_eq!(this, "1.2 kg");
+    }
+}
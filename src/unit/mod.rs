@@ -0,0 +1,89 @@
+//! Measurement unit formatting
+//!
+//! Human-readable physical measurements that scale their unit automatically, e.g:
+//! ```rust
+//! # use readable::unit::*;
+//! assert_eq!(Distance::from(450.0), "450 m");
+//! assert_eq!(Distance::from(1_200.0), "1.2 km");
+//! assert_eq!(Distance::imperial(1_931.3), "1.2 mi");
+//! assert_eq!(Mass::from(75_000.0), "75.0 kg");
+//! assert_eq!(Volume::imperial(355.0), "12 fl oz");
+//! ```
+//!
+//! ## System
+//! The inputs are always in the smallest metric unit of the type, the [`System`]
+//! only decides which units the _output_ string uses.
+//!
+//! | Type         | Input       | [`System::Metric`] | [`System::Imperial`] |
+//! |--------------|-------------|--------------------|----------------------|
+//! | [`Distance`] | meters      | `m`, `km`          | `ft`, `mi`
+//! | [`Mass`]     | grams       | `g`, `kg`          | `oz`, `lb`
+//! | [`Volume`]   | milliliters | `mL`, `L`          | `fl oz`, `gal` (US)
+//!
+//! [`From`] always uses [`System::Metric`].
+//!
+//! ```rust
+//! # use readable::unit::*;
+//! assert_eq!(Distance::new(100.0, System::Metric), "100 m");
+//! assert_eq!(Distance::new(100.0, System::Imperial), "328 ft");
+//! ```
+//!
+//! ## Rounding
+//! The smaller unit is a whole number, the larger unit has 1 decimal.
+//!
+//! Like [`Byte`](crate::byte::Byte), numbers are always truncated, never rounded up,
+//! so `999.9 m` is `999 m`, not `1.0 km`.
+//!
+//! Negative, `NaN`, infinite and too large inputs return `UNKNOWN`, also available with `unknown()`.
+//!
+//! ## Coordinates
+//! [`Coord`] formats a latitude/longitude in degrees/minutes/seconds or decimal degrees,
+//! and parses both back.
+//!
+//! ```rust
+//! # use readable::unit::*;
+//! assert_eq!(Coord::new(40.4461, -79.9822).unwrap(), "40°26′46″N 79°58′56″W");
+//! assert_eq!(Coord::decimal(40.4461, -79.9822, 2).unwrap(), "40.45, -79.98");
+//! ```
+
+mod system;
+pub use system::*;
+
+mod coord;
+pub use coord::*;
+
+mod distance;
+pub use distance::*;
+
+mod mass;
+pub use mass::*;
+
+mod volume;
+pub use volume::*;
+
+//---------------------------------------------------------------------------------------------------- Private
+use crate::str::Str;
+
+// Float noise, e.g. `0.9144 / 0.3048` is `2.9999999999999996`.
+const EPSILON: f64 = 1e-6;
+
+/// Format `value` as a whole number, e.g. `1,234 ft`.
+fn whole<const N: usize>(value: f64, suffix: &str) -> Str<N> {
+    let mut s = Str::new();
+    s.push_str_panic(crate::num::Unsigned::from((value + EPSILON) as u64));
+    s.push_char_panic(' ');
+    s.push_str_panic(suffix);
+    s
+}
+
+/// Format `value` with 1 (truncated) decimal, e.g. `1,234.5 km`.
+fn tenths<const N: usize>(value: f64, suffix: &str) -> Str<N> {
+    let tenths = (value * 10.0 + EPSILON) as u64;
+    let mut s = Str::new();
+    s.push_str_panic(crate::num::Unsigned::from(tenths / 10));
+    s.push_char_panic('.');
+    s.push_char_panic(char::from(b'0' + (tenths % 10) as u8));
+    s.push_char_panic(' ');
+    s.push_str_panic(suffix);
+    s
+}
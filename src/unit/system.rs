@@ -0,0 +1,44 @@
+//---------------------------------------------------------------------------------------------------- System
+/// A system of measurement
+///
+/// This decides which units the [`unit`](crate::unit) types output.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(System::default(), System::Metric);
+/// assert_eq!(System::Metric.as_str(), "metric");
+/// assert_eq!(System::Imperial.as_str(), "imperial");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum System {
+    #[default]
+    /// Meters, kilometers, etc
+    Metric,
+    /// Feet, miles, etc
+    Imperial,
+}
+
+impl System {
+    #[inline]
+    #[must_use]
+    /// The lowercase name of the system
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Metric => "metric",
+            Self::Imperial => "imperial",
+        }
+    }
+}
+
+impl std::fmt::Display for System {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
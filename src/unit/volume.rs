@@ -0,0 +1,269 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::macros::{impl_common, impl_const, impl_float_eq, impl_traits};
+use crate::str::Str;
+use crate::unit::System;
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// 1 `liter` in `milliliters`
+const LITER: f64 = 1_000.0;
+/// 1 US `fluid ounce` in `milliliters`
+const FLUID_OUNCE: f64 = 29.573_529_562_5;
+/// 1 US `gallon` in `milliliters`
+const GALLON: f64 = 3_785.411_784;
+
+//---------------------------------------------------------------------------------------------------- Volume
+/// Human-readable volume formatting
+///
+/// This takes milliliters as input and will store a formatted
+/// string with the proper unit for the [`System`].
+///
+/// [`System::Imperial`] uses the US customary units, `fl oz` and `gal`.
+///
+/// The unit will increase as the inner number increases, for example:
+/// ```rust
+/// # use readable::unit::*;
+/// // Metric.
+/// assert_eq!(Volume::metric(0.5),          "0 mL");
+/// assert_eq!(Volume::metric(250.0),        "250 mL");
+/// assert_eq!(Volume::metric(999.9),        "999 mL");
+/// assert_eq!(Volume::metric(1_000.0),      "1.0 L");
+/// assert_eq!(Volume::metric(1_500.0),      "1.5 L");
+/// assert_eq!(Volume::metric(20_000.0),     "20.0 L");
+///
+/// // Imperial.
+/// assert_eq!(Volume::imperial(29.6),       "1 fl oz");
+/// assert_eq!(Volume::imperial(355.0),      "12 fl oz");
+/// assert_eq!(Volume::imperial(1_000.0),    "33 fl oz");
+/// assert_eq!(Volume::imperial(3_785.5),    "1.0 gal");
+/// assert_eq!(Volume::imperial(20_000.0),   "5.2 gal");
+/// ```
+///
+/// Whole units (`mL`, `fl oz`) have no decimals, larger units (`L`, `gal`) have 1 decimal.
+///
+/// Like [`Distance`](crate::unit::Distance), the numbers are truncated, not rounded.
+///
+/// The maximum input is [`Volume::MAX`], `1,000,000,000,000.0 L`.
+///
+/// ## Input
+/// The inner [`f64`] is the input in milliliters,
+/// the [`System`] only changes the output string.
+///
+/// [`From`] input can be:
+/// - Any unsigned integer [`u8`], [`usize`], etc
+/// - Any signed integer [`i8`], [`isize`], etc
+/// - [`f32`] or [`f64`]
+///
+/// [`From`] always uses [`System::Metric`].
+///
+/// ```rust
+/// # use readable::unit::*;
+/// let volume = Volume::imperial(1_000.0);
+/// assert_eq!(volume, 1_000.0);
+/// assert_eq!(volume, "33 fl oz");
+/// assert_eq!(Volume::from(1_000), "1.0 L");
+/// ```
+///
+/// ## Errors
+/// A [`Volume::UNKNOWN`] will be returned if the input is:
+/// - Negative
+/// - Larger than [`Volume::MAX`]
+/// - [`f64::NAN`], [`f64::INFINITY`], [`f64::NEG_INFINITY`]
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(Volume::from(-1.0), Volume::unknown());
+/// assert_eq!(Volume::from(f64::NAN), Volume::unknown());
+/// assert_eq!(Volume::from(1e16), Volume::unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<21>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::unit::*;
+/// assert_eq!(std::mem::size_of::<Volume>(), 32);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone)]
+pub struct Volume(f64, Str<{ Volume::MAX_LEN }>);
+
+impl_traits!(Volume, f64);
+impl_float_eq!(Volume => f64);
+
+//---------------------------------------------------------------------------------------------------- Volume Constants
+impl Volume {
+    /// The maximum string length of a [`Volume`]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Volume::MAX.len(), Volume::MAX_LEN);
+    /// assert_eq!(Volume::imperial(1e15).len(), Volume::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 21;
+
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Volume::ZERO, 0.0);
+    /// assert_eq!(Volume::ZERO, "0 mL");
+    /// ```
+    pub const ZERO: Self = Self(0.0, Str::from_static_str("0 mL"));
+
+    /// The maximum input, `1,000,000,000,000` liters
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Volume::MAX, 1e15);
+    /// assert_eq!(Volume::MAX, "1,000,000,000,000.0 L");
+    /// assert_eq!(Volume::MAX, Volume::from(1e15));
+    /// ```
+    pub const MAX: Self = Self(1e15, Str::from_static_str("1,000,000,000,000.0 L"));
+
+    /// Returned on error situations.
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Volume::UNKNOWN, 0.0);
+    /// assert_eq!(Volume::UNKNOWN, "?.? mL");
+    /// ```
+    pub const UNKNOWN: Self = Self(0.0, Str::from_static_str("?.? mL"));
+}
+
+//---------------------------------------------------------------------------------------------------- Volume Impl
+impl Volume {
+    impl_common!(f64);
+    impl_const!();
+
+    #[inline]
+    #[must_use]
+    /// Returns [`Self::UNKNOWN`]
+    pub const fn unknown() -> Self {
+        Self::UNKNOWN
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert!(Volume::UNKNOWN.is_unknown());
+    /// assert!(!Volume::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"?.? mL")
+    }
+
+    #[must_use]
+    /// Create a [`Volume`] from `milliliters`, formatted with the units of `system`
+    ///
+    /// ```rust
+    /// # use readable::unit::*;
+    /// assert_eq!(Volume::new(5_000.0, System::Metric), "5.0 L");
+    /// assert_eq!(Volume::new(5_000.0, System::Imperial), "1.3 gal");
+    /// ```
+    pub fn new(milliliters: f64, system: System) -> Self {
+        if !(0.0..=Self::MAX.0).contains(&milliliters) {
+            return Self::UNKNOWN;
+        }
+
+        let string = match system {
+            System::Metric if milliliters < LITER => super::whole(milliliters, "mL"),
+            System::Metric => super::tenths(milliliters / LITER, "L"),
+            System::Imperial if milliliters < GALLON => {
+                super::whole(milliliters / FLUID_OUNCE, "fl oz")
+            }
+            System::Imperial => super::tenths(milliliters / GALLON, "gal"),
+        };
+
+        Self(milliliters, string)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Volume::new`] with [`System::Metric`], e.g. `1.2 L`
+    pub fn metric(milliliters: f64) -> Self {
+        Self::new(milliliters, System::Metric)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Volume::new`] with [`System::Imperial`], e.g. `1.2 gal`
+    pub fn imperial(milliliters: f64) -> Self {
+        Self::new(milliliters, System::Imperial)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- From
+macro_rules! impl_from {
+	($($from:ty),* $(,)?) => { $(
+		impl From<$from> for Volume {
+			#[inline]
+			/// Input is in milliliters, formatted with [`System::Metric`].
+			fn from(milliliters: $from) -> Self {
+				Self::metric(milliliters as f64)
+			}
+		}
+		impl From<&$from> for Volume {
+			#[inline]
+			/// Input is in milliliters, formatted with [`System::Metric`].
+			fn from(milliliters: &$from) -> Self {
+				Self::metric(*milliliters as f64)
+			}
+		}
+	)*}
+}
+impl_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metric() {
+        for ml in 0..1_000_u32 {
+            assert_eq!(Volume::from(ml).as_str(), format!("{ml} mL"));
+        }
+        for ml in (1_000..100_000_u32).step_by(100) {
+            let l = ml / 1_000;
+            let tenth = ml / 100 % 10;
+            assert_eq!(Volume::from(ml).as_str(), format!("{l}.{tenth} L"));
+        }
+    }
+
+    #[test]
+    fn imperial() {
+        for oz in 0..128_u32 {
+            let ml = f64::from(oz) * FLUID_OUNCE;
+            assert_eq!(Volume::imperial(ml).as_str(), format!("{oz} fl oz"));
+        }
+        for tenths in 10..1_000_u32 {
+            let ml = f64::from(tenths) * GALLON / 10.0;
+            let expected = format!("{}.{} gal", tenths / 10, tenths % 10);
+            assert_eq!(Volume::imperial(ml).as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Volume::metric(1e15), Volume::MAX);
+        assert_eq!(Volume::imperial(1e15), "264,172,052,358.1 gal");
+        assert!(Volume::imperial(1e15 + 1.0).is_unknown());
+        assert!(Volume::from(f64::NAN).is_unknown());
+        assert!(Volume::from(i64::MIN).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Volume::metric(1_200.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[1200.0,"1.2 L"]"#);
+
+        let this: Volume = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 1_200.0);
+        assert_eq!(this, "1.2 L");
+    }
+}
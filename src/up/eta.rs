@@ -0,0 +1,340 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::macros::{impl_common, impl_const, impl_from_option, impl_traits, impl_usize};
+use crate::str::Str;
+use crate::up::Uptime;
+use std::time::Duration;
+
+//---------------------------------------------------------------------------------------------------- Eta
+/// Human-readable estimated time remaining
+///
+/// This takes the remaining units of work and a rate (units per second)
+/// and formats the estimated time left, using the same units as [`Uptime`].
+///
+/// The amount of seconds is always rounded up.
+///
+/// ```rust
+/// # use readable::up::*;
+/// // 1000 units left, 5 units a second.
+/// assert_eq!(Eta::new(1_000, 5.0), "~3m 20s left");
+/// assert_eq!(Eta::new(1_000, 5.0), 200_u32);
+///
+/// // 1 unit left, 1000 units a second.
+/// assert_eq!(Eta::new(1, 1_000.0), "~1s left");
+///
+/// // Nothing left.
+/// assert_eq!(Eta::new(0, 1.0), "~0s left");
+/// ```
+///
+/// ## Samples
+/// [`Eta::from_samples`] can be used to calculate the rate
+/// from a window of `(elapsed, done)` progress samples.
+///
+/// ```rust
+/// # use readable::up::*;
+/// # use std::time::Duration;
+/// let samples = [
+///     (Duration::from_secs(0), 0),
+///     (Duration::from_secs(1), 10),
+///     (Duration::from_secs(2), 20),
+/// ];
+/// assert_eq!(Eta::from_samples(600, &samples), "~1m left");
+/// ```
+///
+/// ## Errors
+/// [`Eta::UNKNOWN`] is returned if:
+/// - The rate is zero, negative, `NaN`, or infinite
+/// - The estimated time is greater than [`u32::MAX`] seconds
+/// - Less than 2 samples are given to [`Eta::from_samples`]
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert!(Eta::new(1, 0.0).is_unknown());
+/// assert!(Eta::new(1, -1.0).is_unknown());
+/// assert!(Eta::new(1, f64::NAN).is_unknown());
+/// assert!(Eta::new(u64::MAX, 0.001).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<30>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(std::mem::size_of::<Eta>(), 36);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eta(u32, Str<{ Eta::MAX_LEN }>);
+
+impl_traits!(Eta, u32);
+impl_from_option!(Eta);
+
+//---------------------------------------------------------------------------------------------------- Eta Constants
+impl Eta {
+    /// The maximum string length of an [`Eta`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!("~136y 11m 30d 23h 59m 59s left".len(), Eta::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 30;
+
+    /// Returned when the rate is invalid or the estimate overflows
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Eta::UNKNOWN, 0);
+    /// assert_eq!(Eta::UNKNOWN, "(unknown)");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("(unknown)"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Eta::ZERO, 0);
+    /// assert_eq!(Eta::ZERO, "~0s left");
+    /// assert_eq!(Eta::ZERO, Eta::new(0, 1.0));
+    /// ```
+    pub const ZERO: Self = Self(0, Str::from_static_str("~0s left"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Eta::MAX, u32::MAX);
+    /// assert_eq!(Eta::MAX, "~136y 2m 8d 6h 28m 15s left");
+    /// assert_eq!(Eta::MAX, Eta::new(u64::from(u32::MAX), 1.0));
+    /// ```
+    pub const MAX: Self = Self(
+        u32::MAX,
+        Str::from_static_str("~136y 2m 8d 6h 28m 15s left"),
+    );
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl Eta {
+    impl_common!(u32);
+    impl_const!();
+    impl_usize!();
+
+    #[must_use]
+    /// Create an [`Eta`] from the `remaining` units of work and a `rate` in units per second
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Eta::new(3_600, 1.0),  "~1h left");
+    /// assert_eq!(Eta::new(3_601, 1.0),  "~1h 1s left");
+    /// assert_eq!(Eta::new(10, 3.0),     "~4s left");
+    /// assert_eq!(Eta::new(86_400, 0.5), "~2d left");
+    /// ```
+    pub fn new(remaining: u64, rate: f64) -> Self {
+        if !rate.is_finite() || rate <= 0.0 {
+            return Self::UNKNOWN;
+        }
+
+        let secs = (remaining as f64 / rate).ceil();
+        if secs > f64::from(u32::MAX) {
+            return Self::UNKNOWN;
+        }
+
+        Self::from_priv(secs as u32)
+    }
+
+    #[must_use]
+    /// Create an [`Eta`] from the `remaining` units of work and a window of progress samples
+    ///
+    /// Each sample is `(elapsed, done)`, where `elapsed` is the time since
+    /// some fixed starting point and `done` is the total units done at that point.
+    ///
+    /// The rate is calculated using the first and last sample.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// # use std::time::Duration;
+    /// let samples = [
+    ///     (Duration::from_secs(10), 100),
+    ///     (Duration::from_secs(15), 150),
+    ///     (Duration::from_secs(20), 200),
+    /// ];
+    /// assert_eq!(Eta::from_samples(800, &samples), "~1m 20s left");
+    ///
+    /// // Not enough samples.
+    /// assert!(Eta::from_samples(800, &samples[..1]).is_unknown());
+    ///
+    /// // No progress was made.
+    /// let samples = [
+    ///     (Duration::from_secs(10), 100),
+    ///     (Duration::from_secs(20), 100),
+    /// ];
+    /// assert!(Eta::from_samples(800, &samples).is_unknown());
+    /// ```
+    pub fn from_samples(remaining: u64, samples: &[(Duration, u64)]) -> Self {
+        let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+            return Self::UNKNOWN;
+        };
+
+        let Some(elapsed) = last.0.checked_sub(first.0) else {
+            return Self::UNKNOWN;
+        };
+        let Some(done) = last.1.checked_sub(first.1) else {
+            return Self::UNKNOWN;
+        };
+
+        Self::new(remaining, done as f64 / elapsed.as_secs_f64())
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(Eta::UNKNOWN.is_unknown());
+    /// assert!(!Eta::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+impl Eta {
+    #[inline]
+    fn plural(s: &mut Str<{ Self::MAX_LEN }>, name: &'static str, value: u32, started: &mut bool) {
+        if value > 0 {
+            if *started {
+                s.push_str_panic(" ");
+            }
+            s.push_str_panic(itoa!(value));
+            s.push_str_panic(name);
+            *started = true;
+        }
+    }
+
+    fn from_priv(secs: u32) -> Self {
+        if secs == 0 {
+            return Self::ZERO;
+        }
+
+        // Same units as `Uptime`.
+        let years = secs / 31_536_000; // 365 days
+        let ydays = secs % 31_536_000;
+        let months = ydays / 2_678_400; // 31 days
+        let mdays = ydays % 2_678_400;
+        let days = mdays / 86400;
+        let day_secs = mdays % 86400;
+        let hours = day_secs / 3600;
+        let minutes = day_secs % 3600 / 60;
+        let seconds = day_secs % 60;
+
+        let started = &mut false;
+        let mut string = Str::new();
+        let s = &mut string;
+        s.push_str_panic("~");
+        Self::plural(s, "y", years, started);
+        Self::plural(s, "m", months, started);
+        Self::plural(s, "d", days, started);
+        Self::plural(s, "h", hours, started);
+        Self::plural(s, "m", minutes, started);
+        Self::plural(s, "s", seconds, started);
+        s.push_str_panic(" left");
+
+        Self(secs, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Trait Impl
+impl From<Eta> for Uptime {
+    #[inline]
+    fn from(value: Eta) -> Self {
+        if value.is_unknown() {
+            Self::UNKNOWN
+        } else {
+            Self::from(value.inner())
+        }
+    }
+}
+
+impl From<Eta> for Duration {
+    #[inline]
+    fn from(value: Eta) -> Self {
+        Self::from_secs(value.inner().into())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_ints() {
+        let mut f = 1_u64;
+        while f < u64::from(u32::MAX) {
+            let t = Eta::new(f, 1.0);
+            assert_eq!(t, f as u32);
+            assert_eq!(Uptime::from(t), Uptime::from(f));
+            f *= 10;
+        }
+    }
+
+    #[test]
+    fn rate() {
+        assert_eq!(Eta::new(1, f64::INFINITY), Eta::UNKNOWN);
+        assert_eq!(Eta::new(1, f64::NEG_INFINITY), Eta::UNKNOWN);
+        assert_eq!(Eta::new(1, -0.0), Eta::UNKNOWN);
+        assert_eq!(Eta::new(u64::from(u32::MAX) + 1, 1.0), Eta::UNKNOWN);
+        assert_eq!(Eta::new(u64::MAX, f64::MAX), "~1s left");
+    }
+
+    #[test]
+    fn samples() {
+        assert!(Eta::from_samples(1, &[]).is_unknown());
+
+        // Backwards samples.
+        let samples = [(Duration::from_secs(2), 0), (Duration::from_secs(1), 10)];
+        assert!(Eta::from_samples(1, &samples).is_unknown());
+        let samples = [(Duration::from_secs(1), 10), (Duration::from_secs(2), 0)];
+        assert!(Eta::from_samples(1, &samples).is_unknown());
+
+        // Same instant.
+        let samples = [(Duration::from_secs(1), 0), (Duration::from_secs(1), 10)];
+        assert!(Eta::from_samples(1, &samples).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: Eta = Eta::new(200, 1.0);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[200,"~3m 20s left"]"#);
+
+        let this: Eta = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 200_u32);
+        assert_eq!(this, "~3m 20s left");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<Eta>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&Eta::UNKNOWN).unwrap();
+        assert_eq!(json, r#"[0,"(unknown)"]"#);
+        assert!(serde_json::from_str::<Eta>(&json).unwrap().is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this: Eta = Eta::new(200, 1.0);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: Eta = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, 200_u32);
+        assert_eq!(this, "~3m 20s left");
+
+        // Unknown.
+        let bytes = borsh::to_vec(&Eta::UNKNOWN).unwrap();
+        let this: Eta = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
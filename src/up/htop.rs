@@ -1,14 +1,14 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::itoa;
 use crate::macros::{
-    handle_over_u32, impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize,
-    return_bad_float,
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float,
 };
 use crate::run::RuntimePad;
 use crate::str::Str;
 #[cfg(feature = "time")]
 use crate::time::TimeUnit;
-use crate::up::{Uptime, UptimeFull}; // needed in `from_priv`
+use crate::up::{Uptime, UptimeCompact, UptimeFull}; // needed in `from_priv`
 
 //---------------------------------------------------------------------------------------------------- Htop
 /// [`htop`](https://github.com/htop-dev/htop)-style uptime formatting
@@ -73,11 +73,13 @@ use crate::up::{Uptime, UptimeFull}; // needed in `from_priv`
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Htop(pub(super) u32, pub(super) Str<{ Htop::MAX_LEN }>);
 
 impl_math!(Htop, u32);
+impl_from_option!(Htop);
 impl_traits!(Htop, u32);
+impl_std_from_str!(Htop => from_str);
 
 //---------------------------------------------------------------------------------------------------- Constants
 impl Htop {
@@ -164,6 +166,8 @@ impl Htop {
 impl Htop {
     impl_common!(u32);
     impl_const!();
+    impl_format_slice!(up, Htop, [0_u32, 65, 86_400, u32::MAX]);
+    impl_iso8601!(Htop, up, up, "93_784_u32", "P1DT2H3M4S", "PT90M", "5400");
     impl_usize!();
 
     #[inline]
@@ -176,6 +180,62 @@ impl Htop {
     pub const fn is_unknown(&self) -> bool {
         matches!(*self, Self::UNKNOWN)
     }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse an `htop`-style string back into an [`Htop`]
+    ///
+    /// This is the inverse of the formatting, i.e. an optional
+    /// `X day, `, `X days, ` or `X days(!), ` prefix followed by `HH:MM:SS`.
+    ///
+    /// Surrounding whitespace is ignored, and ISO 8601
+    /// durations (see [`Htop::from_iso8601`]) are accepted as well.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Htop::from_str("00:45:25").unwrap(),              2725);
+    /// assert_eq!(Htop::from_str("1 day, 19:54:39").unwrap(),       158079);
+    /// assert_eq!(Htop::from_str("2 days, 00:45:25").unwrap(),      175525);
+    /// assert_eq!(Htop::from_str("101 days(!), 00:00:00").unwrap(), 8726400);
+    /// assert_eq!(Htop::from_str("PT1H").unwrap(),                  3600);
+    ///
+    /// // Round-trip.
+    /// let htop = Htop::from(158079_u32);
+    /// assert_eq!(Htop::from_str(&htop).unwrap(), htop);
+    ///
+    /// // `FromStr` is implemented with this function.
+    /// let htop: Htop = "1 day, 19:54:39".parse().unwrap();
+    /// assert_eq!(htop, 158079);
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not `htop`-style, or is over [`u32::MAX`] seconds,
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(Htop::from_str("").is_err());
+    /// assert!(Htop::from_str("45:25").is_err());
+    /// assert!(Htop::from_str("00:60:00").is_err());
+    /// assert!(Htop::from_str("1 day 00:00:00").is_err());
+    /// assert!(Htop::from_str("49711 days(!), 00:00:00").is_err());
+    /// assert!(Htop::from_str(&Htop::UNKNOWN).is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        match Self::priv_from_str(string.trim()) {
+            Some(secs) => Ok(Self::from_priv(secs)),
+            None => Self::from_iso8601(string),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`Htop::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
@@ -208,6 +268,44 @@ impl Htop {
 
         Self(secs, string)
     }
+
+    /// Parse `[X day(s)[(!)], ]HH:MM:SS` into seconds.
+    fn priv_from_str(string: &str) -> Option<u32> {
+        let (days, clock) = match string.split_once(", ") {
+            Some((days, clock)) => {
+                let days = days
+                    .strip_suffix(" days(!)")
+                    .or_else(|| days.strip_suffix(" days"))
+                    .or_else(|| days.strip_suffix(" day"))?;
+                (Self::priv_digits(days, usize::MAX)?, clock)
+            }
+            None => (0, string),
+        };
+
+        let mut clock = clock.split(':');
+        let hour = Self::priv_digits(clock.next()?, 2)?;
+        let minute = Self::priv_digits(clock.next()?, 2)?;
+        let second = Self::priv_digits(clock.next()?, 2)?;
+        if clock.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        let secs = days
+            .checked_mul(86400)?
+            .checked_add(hour * 3600 + minute * 60 + second)?;
+        u32::try_from(secs).ok()
+    }
+
+    /// Parse a non-empty ASCII number that is at most `max_len` digits.
+    fn priv_digits(string: &str, max_len: usize) -> Option<u64> {
+        if string.is_empty()
+            || string.len() > max_len
+            || !string.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        string.parse().ok()
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Other Uptime Impl.
@@ -235,7 +333,7 @@ macro_rules! impl_from_time {
 		}
 	)*}
 }
-impl_from_time!(Htop => Uptime, UptimeFull);
+impl_from_time!(Htop => Uptime, UptimeFull, UptimeCompact);
 #[cfg(feature = "time")]
 impl_from_time!(Htop => TimeUnit);
 
@@ -455,6 +553,41 @@ mod tests {
         assert_eq!(Htop::from(f64::NEG_INFINITY), Htop::UNKNOWN);
     }
 
+    #[test]
+    fn from_str() {
+        let mut f = 1_u64;
+        while f < u64::from(u32::MAX) {
+            let t = Htop::from(f);
+            assert_eq!(Htop::from_str(&t).unwrap(), t);
+            f = f * 7 + 3;
+        }
+        assert_eq!(Htop::from_str(&Htop::MAX).unwrap(), Htop::MAX);
+        assert_eq!(Htop::from_str(&Htop::ZERO).unwrap(), Htop::ZERO);
+        assert_eq!(Htop::from_str("  1 day, 19:54:39\n").unwrap(), 158_079);
+
+        for s in [
+            "",
+            ":",
+            "00:00",
+            "00:00:00:00",
+            "0:00:00:",
+            "24:00:00",
+            "00:00:60",
+            "+1:00:00",
+            "1 days 00:00:00",
+            "day, 00:00:00",
+            " day, 00:00:00",
+            "-1 day, 00:00:00",
+            "1 day, ",
+            "1 week, 00:00:00",
+            "49710 days(!), 06:28:16",
+            "99999999999999999999 days, 00:00:00",
+            "(unknown)",
+        ] {
+            assert!(Htop::from_str(s).is_err(), "{s}");
+        }
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde() {
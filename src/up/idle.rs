@@ -0,0 +1,353 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::str::Str;
+use crate::up::Uptime;
+
+//---------------------------------------------------------------------------------------------------- Idle
+/// Human-readable system uptime and idle percent
+///
+/// This takes `(uptime, idle)` seconds as input and
+/// stores the [`Uptime`] and the percent of it spent idle:
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(Idle::new(273_600, 164_160), "up 3d, 4h (idle 60%)");
+/// assert_eq!(Idle::new(61, 0),            "up 1m, 1s (idle 0%)");
+/// assert_eq!(Idle::new(0, 0),             "up 0s (idle 0%)");
+/// ```
+///
+/// The percent is rounded to the nearest whole number.
+///
+/// ```rust
+/// # use readable::up::*;
+/// let idle = Idle::new(3, 2);
+/// assert_eq!(idle, "up 3s (idle 67%)");
+/// assert_eq!(idle.uptime(), Uptime::from(3_u32));
+/// assert_eq!(idle.idle_percent(), 67);
+/// ```
+///
+/// ## Errors
+/// [`Idle::UNKNOWN`] is returned if `idle` is larger than `uptime`.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert!(Idle::new(1, 2).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<44>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(std::mem::size_of::<Idle>(), 84);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Idle {
+    uptime: Uptime,
+    idle_percent: u8,
+    string: Str<{ Idle::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl Idle {
+    /// The maximum string length of an [`Idle`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let idle = "up ---y, --m, --d, --h, --m, --s (idle 100%)";
+    /// assert_eq!(idle.len(), Idle::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = "up  (idle 100%)".len() + Uptime::MAX_LEN;
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::UNKNOWN, "(unknown)");
+    /// assert_eq!(Idle::UNKNOWN.uptime(), Uptime::UNKNOWN);
+    /// assert_eq!(Idle::UNKNOWN.idle_percent(), 0);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        uptime: Uptime::UNKNOWN,
+        idle_percent: 0,
+        string: Str::from_static_str("(unknown)"),
+    };
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::ZERO, "up 0s (idle 0%)");
+    /// assert_eq!(Idle::ZERO, Idle::new(0, 0));
+    /// ```
+    pub const ZERO: Self = Self {
+        uptime: Uptime::ZERO,
+        idle_percent: 0,
+        string: Str::from_static_str("up 0s (idle 0%)"),
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl Idle {
+    #[must_use]
+    /// Create an [`Idle`] from `uptime` and `idle` seconds
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::new(2, 1), "up 2s (idle 50%)");
+    /// ```
+    pub fn new(uptime: u32, idle: u32) -> Self {
+        if idle > uptime {
+            return Self::UNKNOWN;
+        }
+
+        let idle_percent = if uptime == 0 {
+            0
+        } else {
+            // Rounded `idle * 100 / uptime`, this is `0..=100`.
+            ((u64::from(idle) * 200 + u64::from(uptime)) / (u64::from(uptime) * 2)) as u8
+        };
+
+        Self::priv_from(Uptime::from(uptime), idle_percent)
+    }
+
+    #[must_use]
+    /// Get the _current_ system uptime and idle percent
+    ///
+    /// See [`uptime_idle`](crate::up::uptime_idle) for the supported platforms.
+    ///
+    /// [`Idle::UNKNOWN`] is returned if the system idle time could not be read.
+    ///
+    /// This is the same as [`SysIdle::sys_idle`], without needing the trait in scope.
+    pub fn now() -> Self {
+        <Self as SysIdle>::sys_idle()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the [`Uptime`]
+    pub const fn uptime(&self) -> Uptime {
+        self.uptime
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the percent (`0..=100`) of the uptime spent idle
+    pub const fn idle_percent(&self) -> u8 {
+        self.idle_percent
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::new(2, 1).as_str(), "up 2s (idle 50%)");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::new(2, 1).as_bytes(), b"up 2s (idle 50%)");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(Idle::new(2, 1).len(), 16);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(Idle::UNKNOWN.is_unknown());
+    /// assert!(!Idle::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"(unknown)")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl Idle {
+    fn priv_from(uptime: Uptime, idle_percent: u8) -> Self {
+        let mut string = Str::new();
+        string.push_str_panic("up ");
+        string.push_str_panic(uptime);
+        string.push_str_panic(" (idle ");
+        string.push_str_panic(itoa!(idle_percent));
+        string.push_str_panic("%)");
+
+        Self {
+            uptime,
+            idle_percent,
+            string,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- SysIdle Trait
+/// System uptime and idle time
+///
+/// This is the same as [`SysUptime`](crate::up::SysUptime),
+/// for types that also hold the system idle time, i.e. [`Idle`].
+///
+/// This trait is sealed and can only be implemented internally on `readable` types.
+pub trait SysIdle: private::Sealed {
+    /// This function creates a `Self` from the live system uptime and idle time
+    ///
+    /// See [`uptime_idle`](crate::up::uptime_idle) for the supported platforms.
+    ///
+    /// If the system idle time could not be read, an `UNKNOWN` is returned, e.g. [`Idle::UNKNOWN`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use readable::up::*;
+    /// // Introduce trait into scope.
+    /// use readable::up::SysIdle;
+    ///
+    /// let idle = Idle::sys_idle();
+    /// println!("{idle}");
+    /// # #[cfg(target_os = "linux")]
+    /// assert!(!idle.is_unknown());
+    /// ```
+    fn sys_idle() -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Idle {}
+}
+
+impl SysIdle for Idle {
+    fn sys_idle() -> Self {
+        match crate::up::uptime_idle() {
+            Some((uptime, idle)) => {
+                let idle_percent = if uptime > 0.0 {
+                    (idle / uptime * 100.0).round().min(100.0) as u8
+                } else {
+                    0
+                };
+                Self::priv_from(Uptime::from(uptime), idle_percent)
+            }
+            None => Self::UNKNOWN,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for Idle {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Idle {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Idle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Idle")
+            .field("inner", &(self.uptime.inner(), self.idle_percent))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Idle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for Idle {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Idle {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Idle> for str {
+    #[inline]
+    fn eq(&self, other: &Idle) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Idle> for &str {
+    #[inline]
+    fn eq(&self, other: &Idle) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", Idle::new(3600, 1800)),
+            r#"Idle { inner: (3600, 50), str: "up 1h (idle 50%)" }"#
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Idle::new(100, 0).idle_percent(), 0);
+        assert_eq!(Idle::new(100, 100).idle_percent(), 100);
+        assert_eq!(Idle::new(200, 1).idle_percent(), 1);
+        assert_eq!(Idle::new(201, 1).idle_percent(), 0);
+        assert_eq!(Idle::new(u32::MAX, u32::MAX).idle_percent(), 100);
+        assert_eq!(Idle::new(u32::MAX, u32::MAX / 2).idle_percent(), 50);
+        assert!(Idle::new(0, 1).is_unknown());
+        assert!(Idle::new(u32::MAX - 1, u32::MAX).is_unknown());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn sys_idle() {
+        let idle = Idle::sys_idle();
+        assert!(!idle.is_unknown());
+        assert!(idle.idle_percent() <= 100);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = Idle::new(273_600, 164_160);
+        let json = serde_json::to_string(&this).unwrap();
+        let this: Idle = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "up 3d, 4h (idle 60%)");
+        assert_eq!(this.uptime(), 273_600_u32);
+        assert_eq!(this.idle_percent(), 60);
+    }
+}
@@ -0,0 +1,170 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::up::{Htop, Uptime, UptimeCompact, UptimeFull};
+
+//---------------------------------------------------------------------------------------------------- jiff
+// Implements `From<jiff::Span>` and the reverse `TryFrom`.
+#[cfg(feature = "jiff")]
+macro_rules! impl_jiff {
+	($($up:ty),*) => {
+		$(
+			impl From<jiff::Span> for $up {
+				#[inline]
+				/// Calendar units use the same naive lengths as this module (`31` day months, `365` day years).
+				///
+				/// Fractional seconds are ignored.
+				///
+				/// Returns [`Self::UNKNOWN`] if the [`jiff::Span`] is negative or greater than [`u32::MAX`] seconds.
+				fn from(span: jiff::Span) -> Self {
+					let secs = i128::from(span.get_years()) * 31_536_000
+						+ i128::from(span.get_months()) * 2_678_400
+						+ i128::from(span.get_weeks()) * 604_800
+						+ i128::from(span.get_days()) * 86_400
+						+ i128::from(span.get_hours()) * 3_600
+						+ i128::from(span.get_minutes()) * 60
+						+ i128::from(span.get_seconds())
+						+ i128::from(span.get_milliseconds()) / 1_000
+						+ i128::from(span.get_microseconds()) / 1_000_000
+						+ i128::from(span.get_nanoseconds()) / 1_000_000_000;
+
+					match u32::try_from(secs) {
+						Ok(u) => Self::from(u),
+						Err(_) => Self::UNKNOWN,
+					}
+				}
+			}
+
+			impl TryFrom<$up> for jiff::Span {
+				type Error = $up;
+				#[inline]
+				/// The [`jiff::Span`] only contains seconds, use [`jiff::Span::round`] to balance it.
+				///
+				/// Returns the input as the [`Err`] if it is unknown.
+				fn try_from(up: $up) -> Result<Self, Self::Error> {
+					if up.is_unknown() {
+						return Err(up);
+					}
+					Ok(Self::new().seconds(i64::from(up.inner())))
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "jiff")]
+impl_jiff!(Uptime, UptimeFull, Htop, UptimeCompact);
+
+//---------------------------------------------------------------------------------------------------- chrono
+// Implements `From<chrono::Duration>`.
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono {
+	($($up:ty),*) => {
+		$(
+			impl From<chrono::Duration> for $up {
+				#[inline]
+				/// Fractional seconds are ignored.
+				///
+				/// Returns [`Self::UNKNOWN`] if the [`chrono::Duration`] is negative or greater than [`u32::MAX`] seconds.
+				fn from(duration: chrono::Duration) -> Self {
+					if duration < chrono::Duration::zero() {
+						return Self::UNKNOWN;
+					}
+					match u32::try_from(duration.num_seconds()) {
+						Ok(u) => Self::from(u),
+						Err(_) => Self::UNKNOWN,
+					}
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "chrono")]
+impl_chrono!(Uptime, UptimeFull, Htop, UptimeCompact);
+
+//---------------------------------------------------------------------------------------------------- time
+// Implements `From<time::Duration>`.
+#[cfg(feature = "time-crate")]
+macro_rules! impl_time {
+	($($up:ty),*) => {
+		$(
+			impl From<::time::Duration> for $up {
+				#[inline]
+				/// Fractional seconds are ignored.
+				///
+				/// Returns [`Self::UNKNOWN`] if the [`time::Duration`](::time::Duration) is negative or greater than [`u32::MAX`] seconds.
+				fn from(duration: ::time::Duration) -> Self {
+					if duration.is_negative() {
+						return Self::UNKNOWN;
+					}
+					match u32::try_from(duration.whole_seconds()) {
+						Ok(u) => Self::from(u),
+						Err(_) => Self::UNKNOWN,
+					}
+				}
+			}
+		)*
+	};
+}
+#[cfg(feature = "time-crate")]
+impl_time!(Uptime, UptimeFull, Htop, UptimeCompact);
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "jiff")]
+    fn jiff() {
+        let span = jiff::Span::new().days(1).minutes(1).seconds(1);
+        assert_eq!(Uptime::from(span), "1d, 1m, 1s");
+        assert_eq!(UptimeFull::from(span), "1 day, 1 minute, 1 second");
+        assert_eq!(Htop::from(span), "1 day, 00:01:01");
+        assert_eq!(UptimeCompact::from(span), "1d1m1s");
+
+        // Naive calendar units.
+        let span = jiff::Span::new().years(1).months(1).weeks(1);
+        assert_eq!(Uptime::from(span), "1y, 1m, 7d");
+
+        // Fractional seconds.
+        let span = jiff::Span::new().seconds(1).milliseconds(1_999);
+        assert_eq!(Uptime::from(span), "2s");
+
+        // Negative and overflowing spans.
+        assert!(Uptime::from(jiff::Span::new().seconds(-1)).is_unknown());
+        assert!(Htop::from(jiff::Span::new().years(200)).is_unknown());
+
+        let span = jiff::Span::try_from(Uptime::from(86_461_u32)).unwrap();
+        assert_eq!(span.get_seconds(), 86_461);
+        let span = jiff::Span::try_from(Htop::from(u32::MAX)).unwrap();
+        assert_eq!(span.get_seconds(), i64::from(u32::MAX));
+
+        assert!(jiff::Span::try_from(Uptime::UNKNOWN).is_err());
+        assert!(jiff::Span::try_from(UptimeFull::UNKNOWN).is_err());
+        assert!(jiff::Span::try_from(Htop::UNKNOWN).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        let duration = chrono::Duration::days(1) + chrono::Duration::milliseconds(61_999);
+        assert_eq!(Uptime::from(duration), "1d, 1m, 1s");
+        assert_eq!(UptimeFull::from(duration), "1 day, 1 minute, 1 second");
+        assert_eq!(Htop::from(duration), "1 day, 00:01:01");
+        assert_eq!(UptimeCompact::from(duration), "1d1m1s");
+
+        assert!(Uptime::from(chrono::Duration::milliseconds(-1)).is_unknown());
+        assert!(Htop::from(chrono::Duration::seconds(i64::from(u32::MAX) + 1)).is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "time-crate")]
+    fn time() {
+        let duration = ::time::Duration::days(1) + ::time::Duration::milliseconds(61_999);
+        assert_eq!(Uptime::from(duration), "1d, 1m, 1s");
+        assert_eq!(UptimeFull::from(duration), "1 day, 1 minute, 1 second");
+        assert_eq!(Htop::from(duration), "1 day, 00:01:01");
+        assert_eq!(UptimeCompact::from(duration), "1d1m1s");
+
+        assert!(Uptime::from(::time::Duration::milliseconds(-1)).is_unknown());
+        assert!(Htop::from(::time::Duration::seconds(i64::from(u32::MAX) + 1)).is_unknown());
+    }
+}
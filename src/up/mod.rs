@@ -8,6 +8,7 @@
 //! assert_eq!(Uptime::from(SECONDS),     "1d, 19h, 54m, 39s");
 //! assert_eq!(UptimeFull::from(SECONDS), "1 day, 19 hours, 54 minutes, 39 seconds");
 //! assert_eq!(Htop::from(SECONDS),       "1 day, 19:54:39");
+//! assert_eq!(UptimeCompact::from(SECONDS), "1d19h54m39s");
 //! ```
 //!
 //! ## Input
@@ -49,6 +50,35 @@
 //!
 //! Only the types within `readable::up` implement this trait.
 //!
+//! [`Uptime::now`], [`UptimeFull::now`], [`Htop::now`] and [`UptimeCompact::now`] do the same without the trait import.
+//!
+//! [`Idle`] (with the [`SysIdle`] trait) holds the system uptime and the percent of it spent idle,
+//! i.e. the second field of `/proc/uptime` on Linux:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! assert_eq!(Idle::new(273_600, 164_160), "up 3d, 4h (idle 60%)");
+//! ```
+//!
+//! [`UptimeLive`] can be used to keep an [`Uptime`] up to date in place, e.g:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! let mut live = UptimeLive::sys_uptime();
+//!
+//! // In a loop, once a second...
+//! if live.refresh() {
+//!     println!("{live}");
+//! }
+//! ```
+//!
+//! [`UptimeSince`] (with the `date` feature) holds both the boot date and the uptime:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! assert_eq!(UptimeSince::new(1_714_046_400, 273_600), "up since Mon 2024-04-22, 3d, 4h");
+//! ```
+//!
 //! ## From other [`Uptime`] types
 //! All types in this module support lossless conversion with each other using [`From`].
 //!
@@ -78,6 +108,38 @@
 //! assert_eq!(Htop::from(unknown), Htop::UNKNOWN);
 //! ```
 //!
+//! ## ISO 8601
+//! [`Uptime`], [`UptimeFull`], [`Htop`] and [`UptimeCompact`] can be formatted as (and parsed from)
+//! ISO 8601 durations with `as_iso8601()` and `from_iso8601()`, e.g:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! assert_eq!(Uptime::from(93_784).as_iso8601(), "P1DT2H3M4S");
+//! assert_eq!(Uptime::from_iso8601("PT90M").unwrap(), "1h, 30m");
+//! ```
+//!
+//! Days are the largest unit used when formatting.
+//!
+//! [`std::str::FromStr`] is implemented with `from_iso8601()`, so `str::parse()` works too:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! let uptime: Uptime = "P1DT1M".parse().unwrap();
+//! assert_eq!(uptime, "1d, 1m");
+//! assert_eq!("1d, 1m".parse::<Uptime>(), Err(readable::Error::Parse));
+//! ```
+//!
+//! [`Htop`] can also parse its own format back with [`Htop::from_str`], e.g. `1 day, 19:54:39`,
+//! and [`UptimeCompact::from_str`] parses both its own compact form and ISO 8601:
+//!
+//! ```rust
+//! # use readable::up::*;
+//! let compact = UptimeCompact::from_str("P3DT4H5M").unwrap();
+//! assert_eq!(compact, "3d4h5m");
+//! assert_eq!(compact.as_iso8601(), "P3DT4H5M");
+//! assert_eq!(UptimeCompact::from_str("3d4h5m").unwrap(), compact);
+//! ```
+//!
 //! ## Naive Uptime
 //! These types naively assume that:
 //! 1. Each day is `86400` seconds
@@ -86,13 +148,53 @@
 //!
 //! This is incorrect as not all months are 31 days long and leap years exist.
 //!
+//! `breakdown()` returns these naive units as an [`UptimeParts`].
+//!
+//! [`UptimeCalendar`] can change the month and year lengths,
+//! e.g. [`UptimeCalendar::AVERAGE`] uses the average Gregorian month and year.
+//!
+//! ## `jiff`
+//! With the `jiff` feature, [`Uptime`], [`UptimeFull`], [`Htop`] and [`UptimeCompact`] implement:
+//! - [`From`] [`jiff::Span`](https://docs.rs/jiff/latest/jiff/struct.Span.html), using the naive units above
+//! - [`TryFrom`] for the reverse, returning the input as the [`Err`] if it is unknown
+//!
+//! Negative spans, or spans larger than [`u32::MAX`] seconds will return an `UNKNOWN`.
+//!
+//! ## `chrono` & `time`
+//! With the `chrono` and `time-crate` features, [`Uptime`], [`UptimeFull`], [`Htop`] and [`UptimeCompact`] implement
+//! [`From`] the signed `chrono::Duration` and `time::Duration`, ignoring fractional seconds.
+//!
+//! Like [`jiff::Span`](https://docs.rs/jiff/latest/jiff/struct.Span.html), negative durations
+//! or durations larger than [`u32::MAX`] seconds will return an `UNKNOWN`.
+//!
+//! ```rust
+//! # use readable::up::*;
+//! # #[cfg(feature = "chrono")] {
+//! assert_eq!(Uptime::from(chrono::Duration::seconds(86461)), "1d, 1m, 1s");
+//! assert!(Uptime::from(chrono::Duration::seconds(-1)).is_unknown());
+//! # }
+//! # #[cfg(feature = "time-crate")] {
+//! assert_eq!(Uptime::from(time::Duration::seconds(86461)), "1d, 1m, 1s");
+//! # }
+//! ```
+//!
+//! ```rust
+//! # use readable::up::*;
+//! # #[cfg(feature = "jiff")] {
+//! let span = jiff::Span::new().days(1).minutes(1).seconds(1);
+//! let uptime = Uptime::from(span);
+//! assert_eq!(uptime, "1d, 1m, 1s");
+//! assert_eq!(jiff::Span::try_from(uptime).unwrap().get_seconds(), 86461);
+//! # }
+//! ```
+//!
 //! ## Formatting
 //! The formatting for [`Uptime`] & [`UptimeFull`] is:
 //! - The lowest unit is `second`
 //! - The highest is `year`
 //! - `week` is skipped in favor of `7 days`
 //!
-//! See [`Htop`] for its formatting rules.
+//! See [`Htop`] and [`UptimeCompact`] for their formatting rules.
 //!
 //! ## Copy
 //! [`Copy`] is available.
@@ -141,8 +243,37 @@ pub use uptime::*;
 mod uptime_full;
 pub use uptime_full::*;
 
+mod uptime_live;
+pub use uptime_live::*;
+
+mod uptime_approx;
+pub use uptime_approx::*;
+
+mod uptime_parts;
+pub use uptime_parts::*;
+
+mod uptime_calendar;
+pub use uptime_calendar::*;
+
+mod eta;
+pub use eta::*;
+
 mod sys_uptime;
 pub use sys_uptime::*;
 
+mod idle;
+pub use idle::*;
+
+#[cfg(feature = "date")]
+mod uptime_since;
+#[cfg(feature = "date")]
+pub use uptime_since::*;
+
 mod htop;
 pub use htop::*;
+
+mod uptime_compact;
+pub use uptime_compact::*;
+
+#[cfg(any(feature = "chrono", feature = "time-crate", feature = "jiff"))]
+mod interop;
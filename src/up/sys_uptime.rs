@@ -1,7 +1,7 @@
 //---------------------------------------------------------------------------------------------------- Use
 #[cfg(feature = "time")]
 use crate::time::TimeUnit;
-use crate::up::{Htop, Uptime, UptimeFull};
+use crate::up::{Htop, Uptime, UptimeCompact, UptimeFull, UptimeLive};
 
 //---------------------------------------------------------------------------------------------------- SysUptime Trait
 /// System uptime
@@ -20,6 +20,8 @@ pub trait SysUptime: private::Sealed {
     /// - BSDs
     /// - Linux
     ///
+    /// If the system uptime could not be read, an `UNKNOWN` is returned, e.g. [`Uptime::UNKNOWN`].
+    ///
     /// ## Example
     /// ```rust
     /// # use readable::up::*;
@@ -57,19 +59,26 @@ pub fn uptime() -> u32 {
 
         // SAFETY: calling C
         let milliseconds = unsafe { windows::Win32::System::SystemInformation::GetTickCount64() };
-        return (milliseconds as f64 / 1000.0) as u32;
+        return u32::try_from(milliseconds / 1000).unwrap_or(u32::MAX);
     }
 
-    #[cfg(all(target_os = "unix", not(target_os = "linux")))]
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd",
+    ))]
     {
-        use std::time::{Duration, SystemTime};
+        use std::time::SystemTime;
         use target_os_lib as libc;
 
         let mut request = [libc::CTL_KERN, libc::KERN_BOOTTIME];
 
         let mut timeval = libc::timeval {
             tv_sec: 0,
-            tv_nsec: 0,
+            tv_usec: 0,
         };
 
         let mut size: libc::size_t = std::mem::size_of_val(&timeval);
@@ -77,18 +86,20 @@ pub fn uptime() -> u32 {
         // SAFETY: calling C
         let err = unsafe {
             libc::sysctl(
-                &mut request[0],
+                request.as_mut_ptr(),
                 2,
-                &mut timeval as _,
+                std::ptr::addr_of_mut!(timeval).cast(),
                 &mut size,
                 std::ptr::null_mut(),
                 0,
             )
         };
 
-        if err == 0 {
-            if let Ok(mut sys) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-                return sys - Duration::from_secs(timeval.tv_sec as u64);
+        // `kern.boottime` is the wall clock time the system booted at.
+        if err == 0 && timeval.tv_sec > 0 {
+            if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+                let uptime = now.as_secs().saturating_sub(timeval.tv_sec as u64);
+                return u32::try_from(uptime).unwrap_or(u32::MAX);
             }
         }
     }
@@ -104,19 +115,66 @@ pub fn uptime() -> u32 {
         let ptr = std::ptr::addr_of_mut!(timespec);
 
         // SAFETY: calling C
-        // Get time, ignore return error.
-        unsafe {
-            libc::clock_gettime(libc::CLOCK_MONOTONIC, ptr);
-        }
+        let err = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, ptr) };
 
         // Uptime is set if no error, else
         // our default `0` is returned.
-        return timespec.tv_sec as u32;
+        if err == 0 {
+            return timespec.tv_sec as u32;
+        }
     }
 
+    #[allow(unreachable_code)]
     0
 }
 
+//---------------------------------------------------------------------------------------------------- Idle Function
+#[inline]
+#[must_use]
+/// Get the current system `(uptime, idle)` time in seconds
+///
+/// `idle` is the wall-clock time the system has been idle,
+/// i.e. the idle time of all CPUs (the second field of `/proc/uptime`)
+/// divided by the amount of online CPUs, so it is never larger than `uptime`.
+///
+/// This function can only be used on Linux.
+///
+/// This will return [`None`] if `/proc/uptime` could not be read, or on other platforms.
+pub fn uptime_idle() -> Option<(f64, f64)> {
+    #[cfg(target_os = "linux")]
+    {
+        use target_os_lib as libc;
+
+        // SAFETY: calling C
+        let cpus = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+        if cpus < 1 {
+            return None;
+        }
+
+        let proc_uptime = std::fs::read_to_string("/proc/uptime").ok()?;
+        let (uptime, idle) = parse_proc_uptime(&proc_uptime)?;
+        return Some((uptime, (idle / cpus as f64).min(uptime)));
+    }
+
+    #[allow(unreachable_code)]
+    None
+}
+
+/// Parse the `(uptime, idle)` seconds out of `/proc/uptime`.
+#[cfg(any(target_os = "linux", test))]
+fn parse_proc_uptime(proc_uptime: &str) -> Option<(f64, f64)> {
+    let mut fields = proc_uptime.split_ascii_whitespace().map(str::parse::<f64>);
+
+    match (fields.next(), fields.next()) {
+        (Some(Ok(uptime)), Some(Ok(idle)))
+            if uptime.is_finite() && idle.is_finite() && uptime >= 0.0 && idle >= 0.0 =>
+        {
+            Some((uptime, idle))
+        }
+        _ => None,
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- SysUptime Impl
 mod private {
     use super::*;
@@ -125,6 +183,10 @@ mod private {
     impl Sealed for Uptime {}
     impl Sealed for UptimeFull {}
     impl Sealed for Htop {}
+    impl Sealed for UptimeCompact {}
+    impl Sealed for UptimeLive {}
+    #[cfg(feature = "date")]
+    impl Sealed for crate::up::UptimeSince {}
     #[cfg(feature = "time")]
     impl Sealed for TimeUnit {}
 }
@@ -135,12 +197,111 @@ macro_rules! impl_uptime {
 			impl SysUptime for $time {
 				#[inline]
 				fn sys_uptime() -> Self {
-					Self::from(uptime())
+					match uptime() {
+						// The system call failed, or the platform is not supported.
+						0 => Self::UNKNOWN,
+						u => Self::from(u),
+					}
 				}
 			}
 		)*
 	};
 }
-impl_uptime!(Uptime, UptimeFull, Htop);
+impl_uptime!(Uptime, UptimeFull, Htop, UptimeCompact);
+
+// Implements `now()`, the same as `SysUptime::sys_uptime()` without the trait import.
+macro_rules! impl_now {
+	($($time:ident),*) => {
+		$(
+			impl $time {
+				#[inline]
+				#[must_use]
+				/// Create a `Self` from the current system uptime
+				///
+				/// This is the same as [`SysUptime::sys_uptime`], without needing the trait in scope.
+				///
+				/// ```rust
+				/// # use readable::up::*;
+				#[doc = concat!("let uptime = ", stringify!($time), "::now();")]
+				/// # // Get around CI.
+				/// # let uptime = 1;
+				/// assert!(uptime >= 1);
+				/// ```
+				pub fn now() -> Self {
+					<Self as SysUptime>::sys_uptime()
+				}
+			}
+		)*
+	};
+}
+impl_now!(Uptime, UptimeFull, Htop, UptimeCompact);
+
+impl SysUptime for UptimeLive {
+    #[inline]
+    fn sys_uptime() -> Self {
+        match uptime() {
+            // The system call failed, or the platform is not supported.
+            0 => Self::unknown(),
+            u => Self::from_secs(u),
+        }
+    }
+}
 #[cfg(feature = "time")]
 impl_uptime!(TimeUnit);
+
+#[cfg(feature = "date")]
+impl SysUptime for crate::up::UptimeSince {
+    #[inline]
+    fn sys_uptime() -> Self {
+        match uptime() {
+            // The system call failed, or the platform is not supported.
+            0 => Self::UNKNOWN,
+            u => {
+                use std::time::{SystemTime, UNIX_EPOCH};
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs());
+                Self::new(now, u)
+            }
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+    fn sys_uptime() {
+        assert!(uptime() > 0);
+        assert!(!Uptime::sys_uptime().is_unknown());
+        assert!(!Htop::sys_uptime().is_unknown());
+        assert!(!UptimeCompact::sys_uptime().is_unknown());
+        assert!(!UptimeLive::sys_uptime().is_unknown());
+    }
+
+    #[test]
+    fn proc_uptime() {
+        assert_eq!(
+            parse_proc_uptime("350735.47 234388.90\n"),
+            Some((350_735.47, 234_388.90))
+        );
+        assert_eq!(parse_proc_uptime("0.00 0.00"), Some((0.0, 0.0)));
+
+        assert_eq!(parse_proc_uptime(""), None);
+        assert_eq!(parse_proc_uptime("350735.47"), None);
+        assert_eq!(parse_proc_uptime("350735.47 ???"), None);
+        assert_eq!(parse_proc_uptime("-1.0 0.0"), None);
+        assert_eq!(parse_proc_uptime("inf 0.0"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn linux() {
+        let (uptime, idle) = uptime_idle().unwrap();
+        assert!(uptime > 0.0);
+        assert!(idle <= uptime);
+    }
+}
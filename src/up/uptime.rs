@@ -1,13 +1,13 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::itoa;
 use crate::macros::{
-    handle_over_u32, impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize,
-    return_bad_float,
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float,
 };
 use crate::str::Str;
 #[cfg(feature = "time")]
 use crate::time::TimeUnit;
-use crate::up::{Htop, UptimeFull};
+use crate::up::{Htop, UptimeCalendar, UptimeCompact, UptimeFull};
 
 //---------------------------------------------------------------------------------------------------- Uptime
 /// Human-readable uptime
@@ -88,11 +88,13 @@ use crate::up::{Htop, UptimeFull};
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Uptime(pub(super) u32, pub(super) Str<{ Uptime::MAX_LEN }>);
 
 impl_math!(Uptime, u32);
+impl_from_option!(Uptime);
 impl_traits!(Uptime, u32);
+impl_std_from_str!(Uptime => from_iso8601);
 
 //---------------------------------------------------------------------------------------------------- Constants
 impl Uptime {
@@ -171,6 +173,8 @@ impl Uptime {
 impl Uptime {
     impl_common!(u32);
     impl_const!();
+    impl_format_slice!(up, Uptime, [0_u32, 65, 86_400, u32::MAX]);
+    impl_iso8601!(Uptime, up, up, "93_784_u32", "P1DT2H3M4S", "PT90M", "5400");
     impl_usize!();
 
     #[inline]
@@ -183,12 +187,104 @@ impl Uptime {
     pub const fn is_unknown(&self) -> bool {
         matches!(*self, Self::UNKNOWN)
     }
+
+    #[must_use]
+    /// Only show the `n` most significant units
+    ///
+    /// The smaller units are cut off, the inner number stays the same.
+    ///
+    /// `0` is treated the same as `1`.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = Uptime::from(93_784_u32);
+    /// assert_eq!(uptime, "1d, 2h, 3m, 4s");
+    /// assert_eq!(uptime.max_units(2), "1d, 2h");
+    /// assert_eq!(uptime.max_units(1), "1d");
+    /// assert_eq!(uptime.max_units(2).inner(), 93_784);
+    ///
+    /// // Only shown units count.
+    /// assert_eq!(Uptime::from(86_405_u32).max_units(2), "1d, 5s");
+    /// ```
+    pub fn max_units(&self, n: u8) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_units(self.0, Self::units_max(self.0, n, false))
+    }
+
+    #[must_use]
+    /// Same as [`Self::max_units`], but rounds the last unit to the nearest whole
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = Uptime::from(95_400_u32);
+    /// assert_eq!(uptime, "1d, 2h, 30m");
+    /// assert_eq!(uptime.max_units(2), "1d, 2h");
+    /// assert_eq!(uptime.max_units_round(2), "1d, 3h");
+    ///
+    /// // Rounding can carry into the larger units.
+    /// assert_eq!(Uptime::from(86_399_u32).max_units_round(2), "1d");
+    /// ```
+    pub fn max_units_round(&self, n: u8) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_units(self.0, Self::units_max(self.0, n, true))
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
 impl Uptime {
+    /// The seconds in each unit, from years to seconds.
+    pub(super) const UNIT_SECS: [u32; 6] = [31_536_000, 2_678_400, 86400, 3600, 60, 1];
+
+    #[inline]
+    /// `secs` as `[years, months, days, hours, minutes, seconds]`.
+    pub(super) const fn units(secs: u32) -> [u32; 6] {
+        UptimeCalendar::NAIVE.units(secs)
+    }
+
+    /// `units(secs)` but only keeping the `n` most significant non-zero units.
+    pub(super) fn units_max(secs: u32, n: u8, round: bool) -> [u32; 6] {
+        let mut units = Self::units(secs);
+
+        // Index of the last unit kept, if there are more than `n`.
+        let Some(last) = units
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| **u != 0)
+            .nth(usize::from(n.max(1)) - 1)
+            .map(|(i, _)| i)
+        else {
+            return units;
+        };
+
+        let rest: u32 = (last + 1..units.len())
+            .map(|i| units[i] * Self::UNIT_SECS[i])
+            .sum();
+
+        let unit = Self::UNIT_SECS[last];
+        if round && rest * 2 >= unit {
+            // Round up and cut again, this may carry into larger units.
+            if let Some(secs) = (secs - rest).checked_add(unit) {
+                return Self::units_max(secs, n, false);
+            }
+        }
+
+        for u in &mut units[last + 1..] {
+            *u = 0;
+        }
+        units
+    }
+
     #[inline]
-    fn plural(s: &mut Str<{ Self::MAX_LEN }>, name: &'static str, value: u32, started: &mut bool) {
+    pub(super) fn plural(
+        s: &mut Str<{ Self::MAX_LEN }>,
+        name: &'static str,
+        value: u32,
+        started: &mut bool,
+    ) {
         if value > 0 {
             if *started {
                 s.push_str_panic(", ");
@@ -207,19 +303,16 @@ impl Uptime {
         // 	return Self(secs, CompactString::new_inline(readable_inlined_time::inlined(secs as u16)))
         // }
 
+        Self::from_units(secs, Self::units(secs))
+    }
+
+    /// Format `[years, months, days, hours, minutes, seconds]`, keeping `secs` as the inner number.
+    pub(super) fn from_units(secs: u32, units: [u32; 6]) -> Self {
         if secs == 0 {
             return Self::ZERO;
         }
 
-        let years = secs / 31_536_000; // 365 days
-        let ydays = secs % 31_536_000;
-        let months = ydays / 2_678_400; // 31 days
-        let mdays = ydays % 2_678_400;
-        let days = mdays / 86400;
-        let day_secs = mdays % 86400;
-        let hours = day_secs / 3600;
-        let minutes = day_secs % 3600 / 60;
-        let seconds = day_secs % 60;
+        let [years, months, days, hours, minutes, seconds] = units;
 
         let started = &mut false;
         let mut string = Str::new();
@@ -390,7 +483,7 @@ macro_rules! impl_from_time {
 		}
 	)*}
 }
-impl_from_time!(Uptime => UptimeFull, Htop);
+impl_from_time!(Uptime => UptimeFull, Htop, UptimeCompact);
 #[cfg(feature = "time")]
 impl_from_time!(Uptime => TimeUnit);
 
@@ -450,6 +543,52 @@ impl From<&Uptime> for std::time::Duration {
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_option() {
+        assert_eq!(Uptime::from(Some(61_u32)), "1m, 1s");
+        assert!(Uptime::from(None::<u64>).is_unknown());
+
+        let column: Option<i64> = None;
+        assert_eq!(Uptime::from(column), Uptime::UNKNOWN);
+    }
+
+    #[test]
+    fn sum() {
+        let v = [Uptime::from(60_u32), Uptime::from(1_u32)];
+        assert_eq!(v.iter().sum::<Uptime>(), "1m, 1s");
+
+        // Saturates instead of panicking.
+        let v = [Uptime::from(u32::MAX), Uptime::from(1_u32)];
+        assert_eq!(v.into_iter().sum::<Uptime>(), Uptime::from(u32::MAX));
+    }
+
+    #[test]
+    fn max_units() {
+        for secs in (0..u32::MAX).step_by(7_919) {
+            let uptime = Uptime::from(secs);
+            for n in 1..=6 {
+                let max = uptime.max_units(n);
+                assert_eq!(max.inner(), secs);
+                assert!(uptime.starts_with(max.as_str()));
+                assert!(max.matches(", ").count() < usize::from(n));
+
+                let round = uptime.max_units_round(n);
+                assert_eq!(round.inner(), secs);
+                assert!(round.matches(", ").count() < usize::from(n));
+            }
+            assert_eq!(uptime.max_units(6), uptime);
+            assert_eq!(uptime.max_units(0), uptime.max_units(1));
+        }
+
+        assert_eq!(Uptime::from(89_u32).max_units_round(1), "1m");
+        assert_eq!(Uptime::from(90_u32).max_units_round(1), "2m");
+        assert_eq!(Uptime::from(3_599_u32).max_units_round(1), "1h");
+        assert_eq!(Uptime::MAX.max_units(1), "136y");
+        assert_eq!(Uptime::MAX.max_units_round(3), "136y, 2m, 8d");
+        assert_eq!(Uptime::ZERO.max_units_round(1), Uptime::ZERO);
+        assert!(Uptime::UNKNOWN.max_units(1).is_unknown());
+    }
+
     #[test]
     fn all_ints() {
         let mut f = 1_u64;
@@ -0,0 +1,372 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::macros::{
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_traits,
+    impl_usize,
+};
+use crate::str::Str;
+use crate::up::{Htop, Uptime, UptimeCompact, UptimeFull};
+
+//---------------------------------------------------------------------------------------------------- ApproxThresholds
+/// The thresholds used by [`UptimeApprox`]
+///
+/// ```rust
+/// # use readable::up::*;
+/// let thresholds = ApproxThresholds {
+///     just_now: 60,
+///     almost: 50,
+/// };
+///
+/// assert_eq!(UptimeApprox::new(59, thresholds),   "just now");
+/// assert_eq!(UptimeApprox::new(5400, thresholds), "almost 2 hours");
+///
+/// assert_eq!(UptimeApprox::from(59_u32),   "59 seconds");
+/// assert_eq!(UptimeApprox::from(5400_u32), "about 1 hour");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ApproxThresholds {
+    /// Seconds below this are formatted as `just now`.
+    pub just_now: u32,
+    /// The percent (`0..=100`) of the way to the next whole
+    /// unit at which `almost` is used instead of `about`.
+    ///
+    /// Values over `100` are treated as `100`, i.e. `almost` is never used.
+    pub almost: u8,
+}
+
+impl ApproxThresholds {
+    /// The thresholds used by the `From` implementations of [`UptimeApprox`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(ApproxThresholds::DEFAULT.just_now, 10);
+    /// assert_eq!(ApproxThresholds::DEFAULT.almost, 75);
+    /// ```
+    pub const DEFAULT: Self = Self {
+        just_now: 10,
+        almost: 75,
+    };
+}
+
+impl Default for ApproxThresholds {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- UptimeApprox
+/// Approximate, human-readable uptime
+///
+/// This rounds an uptime to the single largest unit (the same units as [`Uptime`])
+/// and phrases it naturally, e.g. `about 2 hours` or `almost 3 days`.
+///
+/// - Seconds below [`ApproxThresholds::just_now`] are `just now`
+/// - An exact amount of a unit has no prefix, e.g. `2 hours`
+/// - Otherwise, the amount is rounded down and prefixed with `about`...
+/// - ...unless it is [`ApproxThresholds::almost`] percent of the way to the next whole unit, then it is rounded up and prefixed with `almost`
+///
+/// Rounding up into the next unit uses that unit instead, e.g. `almost 1 hour` instead of `almost 60 minutes`.
+///
+/// The `From` implementations use [`ApproxThresholds::DEFAULT`], [`UptimeApprox::new`] takes custom ones.
+///
+/// ## Size
+/// [`Str<17>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(std::mem::size_of::<UptimeApprox>(), 24);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(UptimeApprox::from(0_u32),        "just now");
+/// assert_eq!(UptimeApprox::from(9_u32),        "just now");
+/// assert_eq!(UptimeApprox::from(10_u32),       "10 seconds");
+/// assert_eq!(UptimeApprox::from(59_u32),       "59 seconds");
+/// assert_eq!(UptimeApprox::from(60_u32),       "1 minute");
+/// assert_eq!(UptimeApprox::from(61_u32),       "about 1 minute");
+/// assert_eq!(UptimeApprox::from(105_u32),      "almost 2 minutes");
+/// assert_eq!(UptimeApprox::from(3599_u32),     "almost 1 hour");
+/// assert_eq!(UptimeApprox::from(7200_u32),     "2 hours");
+/// assert_eq!(UptimeApprox::from(9000_u32),     "about 2 hours");
+/// assert_eq!(UptimeApprox::from(237600_u32),   "almost 3 days");
+/// assert_eq!(UptimeApprox::from(2678400_u32),  "1 month");
+/// assert_eq!(UptimeApprox::from(31535999_u32), "almost 1 year");
+/// assert_eq!(UptimeApprox::from(u32::MAX),     "about 136 years");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UptimeApprox(u32, Str<{ UptimeApprox::MAX_LEN }>);
+
+impl_traits!(UptimeApprox, u32);
+impl_from_option!(UptimeApprox);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl UptimeApprox {
+    /// The max length of [`UptimeApprox`]'s string.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!("almost 59 minutes".len(), UptimeApprox::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 17;
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeApprox::UNKNOWN, 0);
+    /// assert_eq!(UptimeApprox::UNKNOWN, "(unknown)");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("(unknown)"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeApprox::ZERO, 0);
+    /// assert_eq!(UptimeApprox::ZERO, "just now");
+    /// ```
+    pub const ZERO: Self = Self(0, Str::from_static_str("just now"));
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl UptimeApprox {
+    impl_common!(u32);
+    impl_const!();
+    impl_format_slice!(up, UptimeApprox, [0_u32, 65, 86_400, u32::MAX]);
+    impl_usize!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(UptimeApprox::UNKNOWN.is_unknown());
+    /// assert!(!UptimeApprox::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.1.as_bytes(), b"(unknown)")
+    }
+
+    #[must_use]
+    /// Create an [`UptimeApprox`] with custom [`ApproxThresholds`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let never_almost = ApproxThresholds {
+    ///     just_now: 0,
+    ///     almost: 100,
+    /// };
+    /// assert_eq!(UptimeApprox::new(0, never_almost),    "0 seconds");
+    /// assert_eq!(UptimeApprox::new(1, never_almost),    "1 second");
+    /// assert_eq!(UptimeApprox::new(3599, never_almost), "about 59 minutes");
+    /// ```
+    pub fn new(secs: u32, thresholds: ApproxThresholds) -> Self {
+        if secs < thresholds.just_now {
+            return Self(secs, Self::ZERO.1);
+        }
+
+        // The largest non-zero unit, or seconds.
+        let i = Uptime::UNIT_SECS
+            .iter()
+            .position(|unit| secs >= *unit)
+            .unwrap_or(Uptime::UNIT_SECS.len() - 1);
+
+        let unit = Uptime::UNIT_SECS[i];
+        let mut amount = secs / unit;
+        let rest = secs % unit;
+
+        let mut name = i;
+
+        let prefix = if rest == 0 {
+            ""
+        } else if u64::from(rest) * 100 >= u64::from(unit) * u64::from(thresholds.almost.min(100)) {
+            amount += 1;
+            "almost "
+        } else {
+            "about "
+        };
+
+        // Rounding up reached the next unit, use it instead.
+        if i > 0 && u64::from(amount) * u64::from(unit) >= u64::from(Uptime::UNIT_SECS[i - 1]) {
+            amount = 1;
+            name = i - 1;
+        }
+
+        let mut string = Str::new();
+        string.push_str_panic(prefix);
+        string.push_str_panic(itoa!(amount));
+        string.push_str_panic(" ");
+        string.push_str_panic(UNIT_NAMES[name]);
+        if amount != 1 {
+            string.push_str_panic("s");
+        }
+
+        Self(secs, string)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+/// Singular unit names, in the same order as `Uptime::UNIT_SECS`.
+const UNIT_NAMES: [&str; 6] = ["year", "month", "day", "hour", "minute", "second"];
+
+//---------------------------------------------------------------------------------------------------- "u*" impl
+// Implementation Macro.
+macro_rules! impl_u {
+	($($u:ty),* $(,)?) => { $(
+		impl From<$u> for UptimeApprox {
+			#[inline]
+			fn from(u: $u) -> Self {
+				Self::new(u as u32, ApproxThresholds::DEFAULT)
+			}
+		}
+		impl From<&$u> for UptimeApprox {
+			#[inline]
+			fn from(u: &$u) -> Self {
+				Self::new(*u as u32, ApproxThresholds::DEFAULT)
+			}
+		}
+	)*}
+}
+impl_u!(u8, u16, u32);
+#[cfg(not(target_pointer_width = "64"))]
+impl_u!(usize);
+
+macro_rules! impl_u_over {
+	($($u:ty),* $(,)?) => { $(
+		impl From<$u> for UptimeApprox {
+			#[inline]
+			fn from(u: $u) -> Self {
+				handle_over_u32!(u, $u);
+				Self::new(u as u32, ApproxThresholds::DEFAULT)
+			}
+		}
+		impl From<&$u> for UptimeApprox {
+			#[inline]
+			fn from(u: &$u) -> Self {
+				handle_over_u32!(*u, $u);
+				Self::new(*u as u32, ApproxThresholds::DEFAULT)
+			}
+		}
+	)*}
+}
+impl_u_over!(u64, u128);
+#[cfg(target_pointer_width = "64")]
+impl_u_over!(usize);
+
+//---------------------------------------------------------------------------------------------------- Other Uptime Impl.
+macro_rules! impl_from_time {
+	($this:ty => $($other:ty),* $(,)?) => { $(
+		impl From<$other> for $this {
+			#[inline]
+			fn from(from: $other) -> Self {
+				if from.is_unknown() {
+					Self::UNKNOWN
+				} else {
+					Self::new(from.inner(), ApproxThresholds::DEFAULT)
+				}
+			}
+		}
+		impl From<&$other> for $this {
+			#[inline]
+			fn from(from: &$other) -> Self {
+				if from.is_unknown() {
+					Self::UNKNOWN
+				} else {
+					Self::new(from.inner(), ApproxThresholds::DEFAULT)
+				}
+			}
+		}
+	)*}
+}
+impl_from_time!(UptimeApprox => Uptime, UptimeFull, Htop, UptimeCompact);
+
+//---------------------------------------------------------------------------------------------------- Trait Impl
+impl From<std::time::Duration> for UptimeApprox {
+    #[inline]
+    fn from(duration: std::time::Duration) -> Self {
+        let u = duration.as_secs();
+        handle_over_u32!(u, u64);
+        Self::new(u as u32, ApproxThresholds::DEFAULT)
+    }
+}
+
+impl From<&std::time::Duration> for UptimeApprox {
+    #[inline]
+    fn from(duration: &std::time::Duration) -> Self {
+        let u = duration.as_secs();
+        handle_over_u32!(u, u64);
+        Self::new(u as u32, ApproxThresholds::DEFAULT)
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_len() {
+        let thresholds = [
+            ApproxThresholds::DEFAULT,
+            ApproxThresholds {
+                just_now: 0,
+                almost: 0,
+            },
+            ApproxThresholds {
+                just_now: 0,
+                almost: 100,
+            },
+        ];
+
+        for t in thresholds {
+            for i in (0..u32::MAX).step_by(997).chain([u32::MAX]) {
+                let approx = UptimeApprox::new(i, t);
+                assert_eq!(approx.inner(), i);
+                assert!(approx.len() <= UptimeApprox::MAX_LEN);
+            }
+        }
+    }
+
+    #[test]
+    fn carry() {
+        assert_eq!(UptimeApprox::from(59_u32 * 60 + 50), "almost 1 hour");
+        assert_eq!(UptimeApprox::from(86399_u32), "almost 1 day");
+        // 12 months are longer than a year.
+        assert_eq!(UptimeApprox::from(31_500_000_u32), "almost 1 year");
+        assert_eq!(UptimeApprox::from(Uptime::MONTH * 11), "11 months");
+    }
+
+    #[test]
+    fn from_other() {
+        assert_eq!(UptimeApprox::from(Uptime::UNKNOWN), UptimeApprox::UNKNOWN);
+        assert_eq!(UptimeApprox::from(Uptime::DAY), "1 day");
+        assert_eq!(UptimeApprox::from(u64::MAX), UptimeApprox::UNKNOWN);
+        assert_eq!(
+            UptimeApprox::from(std::time::Duration::from_secs(7260)),
+            "about 2 hours"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: UptimeApprox = UptimeApprox::from(9000_u32);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[9000,"about 2 hours"]"#);
+
+        let this: UptimeApprox = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 9000);
+        assert_eq!(this, "about 2 hours");
+    }
+}
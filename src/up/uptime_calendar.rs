@@ -0,0 +1,245 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::up::{Uptime, UptimeFull, UptimeParts};
+
+//---------------------------------------------------------------------------------------------------- UptimeCalendar
+/// The month and year lengths used to break down an uptime
+///
+/// The `From` implementations of the `up` types use [`UptimeCalendar::NAIVE`],
+/// i.e. `31` day months and `365` day years.
+///
+/// [`Uptime::with_calendar`], [`UptimeFull::with_calendar`] and
+/// [`UptimeParts::with_calendar`] take a different one:
+///
+/// ```rust
+/// # use readable::up::*;
+/// // 1 year of 365.2425 days.
+/// let secs = 31_556_952;
+/// assert_eq!(Uptime::from(secs),                                   "1y, 5h, 49m, 12s");
+/// assert_eq!(Uptime::with_calendar(secs, UptimeCalendar::AVERAGE), "1y");
+///
+/// // 30 day months, 365.25 day years.
+/// let calendar = UptimeCalendar::new(30 * 86400, 31_557_600).unwrap();
+/// assert_eq!(Uptime::with_calendar(31_557_600, calendar), "1y");
+/// assert_eq!(Uptime::with_calendar(30 * 86400, calendar), "1m");
+/// assert_eq!(Uptime::from(30 * 86400_u32),                "30d");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UptimeCalendar {
+    month_secs: u32,
+    year_secs: u32,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl UptimeCalendar {
+    /// The shortest allowed month, `28` days
+    pub const MIN_MONTH_SECS: u32 = 28 * 86400;
+    /// The longest allowed month, `31` days
+    pub const MAX_MONTH_SECS: u32 = 31 * 86400;
+    /// The shortest allowed year, `365` days
+    pub const MIN_YEAR_SECS: u32 = 365 * 86400;
+    /// The longest allowed year, `366` days
+    pub const MAX_YEAR_SECS: u32 = 366 * 86400;
+
+    /// `31` day months and `365` day years
+    ///
+    /// This is the calendar used by default.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCalendar::NAIVE.month_secs(), 2_678_400);
+    /// assert_eq!(UptimeCalendar::NAIVE.year_secs(),  31_536_000);
+    /// assert_eq!(UptimeCalendar::NAIVE, UptimeCalendar::default());
+    /// ```
+    pub const NAIVE: Self = Self {
+        month_secs: 31 * 86400,
+        year_secs: 365 * 86400,
+    };
+
+    /// The average Gregorian month and year, `30.436875` and `365.2425` days
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCalendar::AVERAGE.month_secs(), 2_629_746);
+    /// assert_eq!(UptimeCalendar::AVERAGE.year_secs(),  31_556_952);
+    /// ```
+    pub const AVERAGE: Self = Self {
+        month_secs: 2_629_746,
+        year_secs: 31_556_952,
+    };
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl UptimeCalendar {
+    #[inline]
+    #[must_use]
+    /// Create a calendar from the seconds in a month and year
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// // 30 day months, 365.25 day years.
+    /// let calendar = UptimeCalendar::new(2_592_000, 31_557_600).unwrap();
+    /// assert_eq!(calendar.month_secs(), 2_592_000);
+    /// assert_eq!(calendar.year_secs(),  31_557_600);
+    /// ```
+    ///
+    /// ## Errors
+    /// [`None`] is returned if the month is not in-between
+    /// [`Self::MIN_MONTH_SECS`] and [`Self::MAX_MONTH_SECS`],
+    /// or the year is not in-between [`Self::MIN_YEAR_SECS`] and [`Self::MAX_YEAR_SECS`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(UptimeCalendar::new(0, 31_536_000).is_none());
+    /// assert!(UptimeCalendar::new(32 * 86400, 31_536_000).is_none());
+    /// assert!(UptimeCalendar::new(2_678_400, 300 * 86400).is_none());
+    /// ```
+    pub const fn new(month_secs: u32, year_secs: u32) -> Option<Self> {
+        if month_secs < Self::MIN_MONTH_SECS
+            || month_secs > Self::MAX_MONTH_SECS
+            || year_secs < Self::MIN_YEAR_SECS
+            || year_secs > Self::MAX_YEAR_SECS
+        {
+            return None;
+        }
+
+        Some(Self {
+            month_secs,
+            year_secs,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// The seconds in a month
+    pub const fn month_secs(&self) -> u32 {
+        self.month_secs
+    }
+
+    #[inline]
+    #[must_use]
+    /// The seconds in a year
+    pub const fn year_secs(&self) -> u32 {
+        self.year_secs
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl UptimeCalendar {
+    #[inline]
+    /// `secs` as `[years, months, days, hours, minutes, seconds]`.
+    pub(super) const fn units(self, secs: u32) -> [u32; 6] {
+        let years = secs / self.year_secs;
+        let ydays = secs % self.year_secs;
+        let months = ydays / self.month_secs;
+        let mdays = ydays % self.month_secs;
+        let days = mdays / 86400;
+        let day_secs = mdays % 86400;
+        let hours = day_secs / 3600;
+        let minutes = day_secs % 3600 / 60;
+        let seconds = day_secs % 60;
+        [years, months, days, hours, minutes, seconds]
+    }
+}
+
+impl Default for UptimeCalendar {
+    #[inline]
+    /// Returns [`Self::NAIVE`]
+    fn default() -> Self {
+        Self::NAIVE
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- With Calendar
+impl Uptime {
+    #[must_use]
+    /// Create an [`Uptime`] that breaks down `secs` with a custom [`UptimeCalendar`]
+    ///
+    /// The calendar is not stored, e.g. [`Uptime::max_units`] and
+    /// math operators go back to [`UptimeCalendar::NAIVE`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = Uptime::with_calendar(u32::MAX, UptimeCalendar::AVERAGE);
+    /// assert_eq!(uptime, "136y, 1m, 6d, 20h, 27m, 57s");
+    /// assert_eq!(uptime.inner(), u32::MAX);
+    /// ```
+    pub fn with_calendar(secs: u32, calendar: UptimeCalendar) -> Self {
+        Self::from_units(secs, calendar.units(secs))
+    }
+}
+
+impl UptimeFull {
+    #[must_use]
+    /// Create an [`UptimeFull`] that breaks down `secs` with a custom [`UptimeCalendar`]
+    ///
+    /// See [`Uptime::with_calendar`] for more details.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = UptimeFull::with_calendar(31_556_952, UptimeCalendar::AVERAGE);
+    /// assert_eq!(uptime, "1 year");
+    /// ```
+    pub fn with_calendar(secs: u32, calendar: UptimeCalendar) -> Self {
+        Self::from_units(secs, calendar.units(secs))
+    }
+}
+
+impl UptimeParts {
+    #[inline]
+    #[must_use]
+    /// Break down `secs` with a custom [`UptimeCalendar`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let parts = UptimeParts::with_calendar(31_556_952, UptimeCalendar::AVERAGE);
+    /// assert_eq!(parts, UptimeParts { years: 1, ..Default::default() });
+    ///
+    /// // `to_secs()` always uses `UptimeCalendar::NAIVE`.
+    /// assert_eq!(parts.to_secs(), 31_536_000);
+    /// ```
+    pub const fn with_calendar(secs: u32, calendar: UptimeCalendar) -> Self {
+        Self::from_units(calendar.units(secs))
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive() {
+        for secs in (0..=u32::MAX).step_by(999_983).chain([u32::MAX]) {
+            assert_eq!(UptimeCalendar::NAIVE.units(secs), Uptime::units(secs));
+            assert_eq!(
+                Uptime::with_calendar(secs, UptimeCalendar::NAIVE),
+                Uptime::from(secs)
+            );
+            assert_eq!(
+                UptimeFull::with_calendar(secs, UptimeCalendar::NAIVE),
+                UptimeFull::from(secs)
+            );
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        // The shortest months and longest years have the most units,
+        // they must still fit in the strings without panicking.
+        let calendar = UptimeCalendar::new(
+            UptimeCalendar::MIN_MONTH_SECS,
+            UptimeCalendar::MAX_YEAR_SECS,
+        )
+        .unwrap();
+
+        let secs = UptimeCalendar::MAX_YEAR_SECS - 1;
+        assert_eq!(calendar.units(secs)[1], 13);
+        assert!(Uptime::with_calendar(secs, calendar).starts_with("13m, "));
+        assert!(UptimeFull::with_calendar(secs, calendar).starts_with("13 months, "));
+
+        for secs in (0..=u32::MAX).step_by(999_983).chain([u32::MAX]) {
+            assert!(!Uptime::with_calendar(secs, calendar).is_unknown());
+            assert!(!UptimeFull::with_calendar(secs, calendar).is_unknown());
+        }
+    }
+}
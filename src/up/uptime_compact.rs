@@ -0,0 +1,636 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::itoa;
+use crate::macros::{
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float,
+};
+use crate::str::Str;
+#[cfg(feature = "time")]
+use crate::time::TimeUnit;
+use crate::up::{Htop, Uptime, UptimeFull};
+
+//---------------------------------------------------------------------------------------------------- UptimeCompact
+/// Compact uptime, e.g. `3d4h5m6s`
+///
+/// This is the same as [`Uptime`] without spaces or commas,
+/// meant for narrow columns, e.g. in a TUI.
+///
+/// Days are the largest unit, so there is no ambiguity
+/// between `m` as in months and `m` as in minutes, and
+/// the string can be parsed back with [`UptimeCompact::from_str`].
+///
+/// ## Size
+/// [`Str<15>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(std::mem::size_of::<UptimeCompact>(), 20);
+/// ```
+///
+/// ## Examples
+/// ```rust
+/// # use readable::up::UptimeCompact;
+/// assert_eq!(UptimeCompact::from(0_u32),       "0s");
+/// assert_eq!(UptimeCompact::from(1_u32),       "1s");
+/// assert_eq!(UptimeCompact::from(59_u32),      "59s");
+/// assert_eq!(UptimeCompact::from(60_u32),      "1m");
+/// assert_eq!(UptimeCompact::from(61_u32),      "1m1s");
+/// assert_eq!(UptimeCompact::from(3599_u32),    "59m59s");
+/// assert_eq!(UptimeCompact::from(3600_u32),    "1h");
+/// assert_eq!(UptimeCompact::from(86399_u32),   "23h59m59s");
+/// assert_eq!(UptimeCompact::from(86400_u32),   "1d");
+/// assert_eq!(UptimeCompact::from(86401_u32),   "1d1s");
+/// assert_eq!(UptimeCompact::from(273_906_u32), "3d4h5m6s");
+/// assert_eq!(UptimeCompact::from(2678400_u32), "31d");
+/// assert_eq!(UptimeCompact::from(u32::MAX),    "49710d6h28m15s");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UptimeCompact(pub(super) u32, pub(super) Str<{ UptimeCompact::MAX_LEN }>);
+
+impl_math!(UptimeCompact, u32);
+impl_from_option!(UptimeCompact);
+impl_traits!(UptimeCompact, u32);
+impl_std_from_str!(UptimeCompact => from_str);
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl UptimeCompact {
+    /// ```rust
+    /// # use readable::up::*;
+    /// let time = "49709d23h59m59s";
+    /// assert_eq!(time.len(), UptimeCompact::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = 15;
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::UNKNOWN, 0);
+    /// assert_eq!(UptimeCompact::UNKNOWN, "(unknown)");
+    /// ```
+    pub const UNKNOWN: Self = Self(0, Str::from_static_str("(unknown)"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::ZERO, 0);
+    /// assert_eq!(UptimeCompact::ZERO, "0s");
+    /// assert_eq!(UptimeCompact::ZERO, UptimeCompact::from(0));
+    /// ```
+    pub const ZERO: Self = Self(0, Str::from_static_str("0s"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::SECOND, 1);
+    /// assert_eq!(UptimeCompact::SECOND, "1s");
+    /// assert_eq!(UptimeCompact::SECOND, UptimeCompact::from(1));
+    /// ```
+    pub const SECOND: Self = Self(1, Str::from_static_str("1s"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::MINUTE, 60);
+    /// assert_eq!(UptimeCompact::MINUTE, "1m");
+    /// assert_eq!(UptimeCompact::MINUTE, UptimeCompact::from(60));
+    /// ```
+    pub const MINUTE: Self = Self(60, Str::from_static_str("1m"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::HOUR, 3600);
+    /// assert_eq!(UptimeCompact::HOUR, "1h");
+    /// assert_eq!(UptimeCompact::HOUR, UptimeCompact::from(3600));
+    /// ```
+    pub const HOUR: Self = Self(3600, Str::from_static_str("1h"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::DAY, 86400);
+    /// assert_eq!(UptimeCompact::DAY, "1d");
+    /// assert_eq!(UptimeCompact::DAY, UptimeCompact::from(86400));
+    /// ```
+    pub const DAY: Self = Self(86400, Str::from_static_str("1d"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::MONTH, 2678400);
+    /// assert_eq!(UptimeCompact::MONTH, "31d");
+    /// assert_eq!(UptimeCompact::MONTH, UptimeCompact::from(2678400));
+    /// ```
+    pub const MONTH: Self = Self(2678400, Str::from_static_str("31d"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::YEAR, 31536000);
+    /// assert_eq!(UptimeCompact::YEAR, "365d");
+    /// assert_eq!(UptimeCompact::YEAR, UptimeCompact::from(31536000));
+    /// ```
+    pub const YEAR: Self = Self(31536000, Str::from_static_str("365d"));
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::MAX, u32::MAX);
+    /// assert_eq!(UptimeCompact::MAX, "49710d6h28m15s");
+    /// assert_eq!(UptimeCompact::MAX, UptimeCompact::from(u32::MAX));
+    /// ```
+    pub const MAX: Self = Self(u32::MAX, Str::from_static_str("49710d6h28m15s"));
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl UptimeCompact {
+    impl_common!(u32);
+    impl_const!();
+    impl_format_slice!(up, UptimeCompact, [0_u32, 65, 86_400, u32::MAX]);
+    impl_iso8601!(
+        UptimeCompact,
+        up,
+        up,
+        "93_784_u32",
+        "P1DT2H3M4S",
+        "PT90M",
+        "5400"
+    );
+    impl_usize!();
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(UptimeCompact::UNKNOWN.is_unknown());
+    /// assert!(!UptimeCompact::ZERO.is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(*self, Self::UNKNOWN)
+    }
+
+    #[allow(clippy::should_implement_trait)] // i don't want to `use std::str::FromStr` everytime.
+    /// Parse a compact string back into an [`UptimeCompact`]
+    ///
+    /// This is the inverse of the formatting, i.e. integers suffixed with
+    /// `d`, `h`, `m` or `s`, in that order, each unit at most once.
+    ///
+    /// Surrounding whitespace (and whitespace in-between units) is ignored,
+    /// and ISO 8601 durations (see [`UptimeCompact::from_iso8601`]) are accepted as well.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeCompact::from_str("3d4h5m6s").unwrap(), 273_906);
+    /// assert_eq!(UptimeCompact::from_str("3d 4h").unwrap(),    273_600);
+    /// assert_eq!(UptimeCompact::from_str("90m").unwrap(),      5400);
+    /// assert_eq!(UptimeCompact::from_str("0s").unwrap(),       0);
+    /// assert_eq!(UptimeCompact::from_str("P3DT4H5M").unwrap(), "3d4h5m");
+    ///
+    /// // Round-trip.
+    /// let compact = UptimeCompact::from(158079_u32);
+    /// assert_eq!(UptimeCompact::from_str(&compact).unwrap(), compact);
+    /// assert_eq!(UptimeCompact::from_str(&compact.as_iso8601()).unwrap(), compact);
+    ///
+    /// // `FromStr` is implemented with this function.
+    /// let compact: UptimeCompact = "1d19h54m39s".parse().unwrap();
+    /// assert_eq!(compact, 158079);
+    /// ```
+    ///
+    /// # Errors
+    /// If the string is not in the compact form, or is over [`u32::MAX`] seconds,
+    /// [`Self::UNKNOWN`] is returned wrapped in [`Result::Err`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(UptimeCompact::from_str("").is_err());
+    /// assert!(UptimeCompact::from_str("4h3d").is_err());
+    /// assert!(UptimeCompact::from_str("1h1h").is_err());
+    /// assert!(UptimeCompact::from_str("1.5h").is_err());
+    /// assert!(UptimeCompact::from_str("1w").is_err());
+    /// assert!(UptimeCompact::from_str("49711d").is_err());
+    /// assert!(UptimeCompact::from_str(&UptimeCompact::UNKNOWN).is_err());
+    /// ```
+    pub fn from_str(string: &str) -> Result<Self, Self> {
+        match Self::priv_from_str(string.trim()) {
+            Some(secs) => Ok(Self::from_priv(secs)),
+            None => Self::from_iso8601(string),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Same as [`UptimeCompact::from_str`] but silently returns a [`Self::UNKNOWN`]
+    /// on error that isn't wrapped in a [`Result::Err`].
+    pub fn from_str_silent(string: &str) -> Self {
+        match Self::from_str(string) {
+            Ok(s) | Err(s) => s,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private impl
+impl UptimeCompact {
+    #[inline]
+    #[must_use]
+    fn from_priv(secs: u32) -> Self {
+        if secs == 0 {
+            return Self::ZERO;
+        }
+
+        let units = [
+            (secs / 86400, "d"),
+            (secs % 86400 / 3600, "h"),
+            (secs % 3600 / 60, "m"),
+            (secs % 60, "s"),
+        ];
+
+        let mut string = Str::new();
+        for (unit, suffix) in units {
+            if unit > 0 {
+                string.push_str_panic(itoa!(unit));
+                string.push_str_panic(suffix);
+            }
+        }
+
+        Self(secs, string)
+    }
+
+    /// Parse `[Xd][Xh][Xm][Xs]` into seconds.
+    fn priv_from_str(string: &str) -> Option<u32> {
+        if string.is_empty() {
+            return None;
+        }
+
+        let mut units = [('d', 86400), ('h', 3600), ('m', 60), ('s', 1)].into_iter();
+        let mut total: u64 = 0;
+        let mut rest = string;
+        while !rest.is_empty() {
+            let end = rest.find(|c: char| !c.is_ascii_digit())?;
+            let (number, tail) = rest.split_at(end);
+            let mut tail = tail.chars();
+            let unit = tail.next()?;
+            let (_, secs) = units.find(|(u, _)| *u == unit)?;
+            let number: u64 = number.parse::<u32>().ok()?.into();
+            total += number * secs;
+            rest = tail.as_str().trim_start();
+        }
+
+        u32::try_from(total).ok()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Other Uptime Impl.
+macro_rules! impl_from_time {
+	($this:ty => $($other:ty),* $(,)?) => { $(
+		impl From<$other> for $this {
+			#[inline]
+			fn from(from: $other) -> Self {
+				if from.is_unknown() {
+					Self::UNKNOWN
+				} else {
+					Self::from_priv(from.inner())
+				}
+			}
+		}
+		impl From<&$other> for $this {
+			#[inline]
+			fn from(from: &$other) -> Self {
+				if from.is_unknown() {
+					Self::UNKNOWN
+				} else {
+					Self::from_priv(from.inner())
+				}
+			}
+		}
+	)*}
+}
+impl_from_time!(UptimeCompact => Uptime, UptimeFull, Htop);
+#[cfg(feature = "time")]
+impl_from_time!(UptimeCompact => TimeUnit);
+
+//---------------------------------------------------------------------------------------------------- "u*" impl
+// Implementation Macro.
+macro_rules! impl_u {
+	($($u:ty),* $(,)?) => { $(
+		impl From<$u> for UptimeCompact {
+			#[inline]
+			fn from(u: $u) -> Self {
+				Self::from_priv(u as u32)
+			}
+		}
+		impl From<&$u> for UptimeCompact {
+			#[inline]
+			fn from(u: &$u) -> Self {
+				Self::from_priv(*u as u32)
+			}
+		}
+	)*}
+}
+impl_u!(u8, u16, u32);
+#[cfg(not(target_pointer_width = "64"))]
+impl_u!(usize);
+
+macro_rules! impl_u_over {
+	($($u:ty),* $(,)?) => { $(
+		impl From<$u> for UptimeCompact {
+			#[inline]
+			fn from(u: $u) -> Self {
+				handle_over_u32!(u, $u);
+				Self::from_priv(u as u32)
+			}
+		}
+		impl From<&$u> for UptimeCompact {
+			#[inline]
+			fn from(u: &$u) -> Self {
+				handle_over_u32!(*u, $u);
+				Self::from_priv(*u as u32)
+			}
+		}
+	)*}
+}
+
+impl_u_over!(u64, u128);
+#[cfg(target_pointer_width = "64")]
+impl_u_over!(usize);
+
+//---------------------------------------------------------------------------------------------------- i* impl
+macro_rules! impl_int {
+	($($int:ty),* $(,)?) => { $(
+		impl From<$int> for UptimeCompact {
+			#[inline]
+			fn from(int: $int) -> Self {
+				if int.is_negative() {
+					return Self::UNKNOWN;
+				}
+				Self::from_priv(int as u32)
+			}
+		}
+		impl From<&$int> for UptimeCompact {
+			#[inline]
+			fn from(int: &$int) -> Self {
+				if int.is_negative() {
+					return Self::UNKNOWN;
+				}
+				Self::from_priv(*int as u32)
+			}
+		}
+	)*}
+}
+impl_int!(i8, i16, i32);
+#[cfg(not(target_pointer_width = "64"))]
+impl_u!(isize);
+
+macro_rules! impl_int_over {
+	($($int:ty),* $(,)?) => { $(
+		impl From<$int> for UptimeCompact {
+			#[inline]
+			fn from(int: $int) -> Self {
+				if int.is_negative() {
+					return Self::UNKNOWN;
+				}
+				handle_over_u32!(int, $int);
+				Self::from_priv(int as u32)
+			}
+		}
+		impl From<&$int> for UptimeCompact {
+			#[inline]
+			fn from(int: &$int) -> Self {
+				if int.is_negative() {
+					return Self::UNKNOWN;
+				}
+				handle_over_u32!(*int, $int);
+				Self::from_priv(*int as u32)
+			}
+		}
+	)*}
+}
+impl_int_over!(i64, i128);
+#[cfg(target_pointer_width = "64")]
+impl_u_over!(isize);
+
+//---------------------------------------------------------------------------------------------------- "f" impl
+macro_rules! impl_f {
+    ($float:ty) => {
+        impl From<$float> for UptimeCompact {
+            #[inline]
+            fn from(float: $float) -> Self {
+                return_bad_float!(float, Self::UNKNOWN, Self::UNKNOWN);
+                if float.is_sign_negative() {
+                    return Self::UNKNOWN;
+                }
+                handle_over_u32!(float, $float);
+                Self::from_priv(float as u32)
+            }
+        }
+        impl From<&$float> for UptimeCompact {
+            #[inline]
+            fn from(float: &$float) -> Self {
+                return_bad_float!(float, Self::UNKNOWN, Self::UNKNOWN);
+                if float.is_sign_negative() {
+                    return Self::UNKNOWN;
+                }
+                handle_over_u32!(*float, $float);
+                Self::from_priv(*float as u32)
+            }
+        }
+    };
+}
+impl_f!(f32);
+impl_f!(f64);
+
+//---------------------------------------------------------------------------------------------------- Trait Impl
+impl From<std::time::Duration> for UptimeCompact {
+    #[inline]
+    fn from(duration: std::time::Duration) -> Self {
+        let u = duration.as_secs();
+        handle_over_u32!(u, u64);
+        Self::from_priv(u as u32)
+    }
+}
+
+impl From<&std::time::Duration> for UptimeCompact {
+    #[inline]
+    fn from(duration: &std::time::Duration) -> Self {
+        let u = duration.as_secs();
+        handle_over_u32!(u, u64);
+        Self::from_priv(u as u32)
+    }
+}
+
+impl From<std::time::Instant> for UptimeCompact {
+    #[inline]
+    fn from(instant: std::time::Instant) -> Self {
+        let u = instant.elapsed().as_secs();
+        handle_over_u32!(u, u64);
+        Self::from_priv(u as u32)
+    }
+}
+
+impl From<&std::time::Instant> for UptimeCompact {
+    #[inline]
+    fn from(instant: &std::time::Instant) -> Self {
+        let u = instant.elapsed().as_secs();
+        handle_over_u32!(u, u64);
+        Self::from_priv(u as u32)
+    }
+}
+
+impl From<UptimeCompact> for std::time::Duration {
+    #[inline]
+    fn from(value: UptimeCompact) -> Self {
+        Self::from_secs(value.inner().into())
+    }
+}
+
+impl From<&UptimeCompact> for std::time::Duration {
+    #[inline]
+    fn from(value: &UptimeCompact) -> Self {
+        Self::from_secs(value.inner().into())
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_ints() {
+        let mut f = 1_u64;
+        while f < (u64::from(UptimeCompact::MAX.0)) {
+            let t = UptimeCompact::from(f);
+            println!("t: {t}, f: {f}");
+            assert_eq!(t, f as u32);
+            f *= 10;
+        }
+    }
+
+    #[test]
+    fn over() {
+        assert_ne!(UptimeCompact::from(u32::MAX), UptimeCompact::UNKNOWN);
+        assert_eq!(
+            UptimeCompact::from(u64::from(u32::MAX) + 1),
+            UptimeCompact::UNKNOWN
+        );
+        assert_eq!(UptimeCompact::from(u64::MAX), UptimeCompact::UNKNOWN);
+        assert_eq!(UptimeCompact::from(f64::MAX), UptimeCompact::UNKNOWN);
+        assert_eq!(UptimeCompact::from(f32::MAX), UptimeCompact::UNKNOWN);
+    }
+
+    #[test]
+    fn special() {
+        assert_eq!(UptimeCompact::from(f32::NAN), UptimeCompact::UNKNOWN);
+        assert_eq!(UptimeCompact::from(f32::INFINITY), UptimeCompact::UNKNOWN);
+        assert_eq!(
+            UptimeCompact::from(f32::NEG_INFINITY),
+            UptimeCompact::UNKNOWN
+        );
+        assert_eq!(UptimeCompact::from(f64::NAN), UptimeCompact::UNKNOWN);
+        assert_eq!(UptimeCompact::from(f64::INFINITY), UptimeCompact::UNKNOWN);
+        assert_eq!(
+            UptimeCompact::from(f64::NEG_INFINITY),
+            UptimeCompact::UNKNOWN
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let mut f = 1_u64;
+        while f < u64::from(u32::MAX) {
+            let t = UptimeCompact::from(f);
+            assert_eq!(UptimeCompact::from_str(&t).unwrap(), t);
+            assert_eq!(UptimeCompact::from_str(&t.as_iso8601()).unwrap(), t);
+            assert_eq!(Uptime::from(t), f as u32);
+            f = f * 7 + 3;
+        }
+        assert_eq!(
+            UptimeCompact::from_str(&UptimeCompact::MAX).unwrap(),
+            UptimeCompact::MAX
+        );
+        assert_eq!(
+            UptimeCompact::from_str(&UptimeCompact::ZERO).unwrap(),
+            UptimeCompact::ZERO
+        );
+        assert_eq!(
+            UptimeCompact::from_str("  1d 19h 54m 39s\n").unwrap(),
+            158_079
+        );
+        assert_eq!(UptimeCompact::from_str("100000s").unwrap(), 100_000);
+
+        for s in [
+            "",
+            "1",
+            "d",
+            "1d1",
+            "1s1d",
+            "1m1m",
+            "1 d",
+            "-1s",
+            "+1s",
+            "1.5h",
+            "1,5h",
+            "1y",
+            "1D",
+            "49710d6h28m16s",
+            "99999999999999999999s",
+            "(unknown)",
+        ] {
+            assert!(UptimeCompact::from_str(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this: UptimeCompact = UptimeCompact::from(273_906_u32);
+        let json = serde_json::to_string(&this).unwrap();
+        assert_eq!(json, r#"[273906,"3d4h5m6s"]"#);
+
+        let this: UptimeCompact = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, 273_906_u32);
+        assert_eq!(this, "3d4h5m6s");
+
+        // Bad bytes.
+        assert!(serde_json::from_str::<UptimeCompact>(&"---").is_err());
+
+        // Unknown.
+        let json = serde_json::to_string(&UptimeCompact::UNKNOWN).unwrap();
+        assert_eq!(json, r#"[0,"(unknown)"]"#);
+        assert!(serde_json::from_str::<UptimeCompact>(&json)
+            .unwrap()
+            .is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn bincode() {
+        let this: UptimeCompact = UptimeCompact::from(273_906_u32);
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(&this, config).unwrap();
+
+        let this: UptimeCompact = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert_eq!(this, 273_906_u32);
+        assert_eq!(this, "3d4h5m6s");
+
+        // Unknown.
+        let bytes = bincode::encode_to_vec(&UptimeCompact::UNKNOWN, config).unwrap();
+        let this: UptimeCompact = bincode::decode_from_slice(&bytes, config).unwrap().0;
+        assert!(this.is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let this: UptimeCompact = UptimeCompact::from(273_906_u32);
+        let bytes = borsh::to_vec(&this).unwrap();
+
+        let this: UptimeCompact = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(this, 273_906_u32);
+        assert_eq!(this, "3d4h5m6s");
+
+        // Bad bytes.
+        assert!(borsh::from_slice::<UptimeCompact>(b"bad .-;[]124/ bytes").is_err());
+
+        // Unknown.
+        let bytes = borsh::to_vec(&UptimeCompact::UNKNOWN).unwrap();
+        let this: UptimeCompact = borsh::from_slice(&bytes).unwrap();
+        assert!(this.is_unknown());
+    }
+}
@@ -1,13 +1,13 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::itoa;
 use crate::macros::{
-    handle_over_u32, impl_common, impl_const, impl_impl_math, impl_math, impl_traits, impl_usize,
-    return_bad_float,
+    handle_over_u32, impl_common, impl_const, impl_format_slice, impl_from_option, impl_impl_math,
+    impl_iso8601, impl_math, impl_std_from_str, impl_traits, impl_usize, return_bad_float,
 };
 use crate::str::Str;
 #[cfg(feature = "time")]
 use crate::time::TimeUnit;
-use crate::up::{Htop, Uptime};
+use crate::up::{Htop, Uptime, UptimeCompact};
 
 //---------------------------------------------------------------------------------------------------- UptimeFull
 /// [`Uptime`] but with full specified words
@@ -76,11 +76,13 @@ use crate::up::{Htop, Uptime};
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct UptimeFull(pub(super) u32, pub(super) Str<{ UptimeFull::MAX_LEN }>);
 
 impl_math!(UptimeFull, u32);
+impl_from_option!(UptimeFull);
 impl_traits!(UptimeFull, u32);
+impl_std_from_str!(UptimeFull => from_iso8601);
 
 //---------------------------------------------------------------------------------------------------- Constants
 impl UptimeFull {
@@ -162,6 +164,16 @@ impl UptimeFull {
 impl UptimeFull {
     impl_common!(u32);
     impl_const!();
+    impl_format_slice!(up, UptimeFull, [0_u32, 65, 86_400, u32::MAX]);
+    impl_iso8601!(
+        UptimeFull,
+        up,
+        up,
+        "93_784_u32",
+        "P1DT2H3M4S",
+        "PT90M",
+        "5400"
+    );
     impl_usize!();
 
     #[inline]
@@ -174,6 +186,41 @@ impl UptimeFull {
     pub const fn is_unknown(&self) -> bool {
         matches!(*self, Self::UNKNOWN)
     }
+
+    #[must_use]
+    /// Only show the `n` most significant units
+    ///
+    /// See [`Uptime::max_units`] for more details.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = UptimeFull::from(93_784_u32);
+    /// assert_eq!(uptime, "1 day, 2 hours, 3 minutes, 4 seconds");
+    /// assert_eq!(uptime.max_units(2), "1 day, 2 hours");
+    /// assert_eq!(uptime.max_units(1), "1 day");
+    /// ```
+    pub fn max_units(&self, n: u8) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_units(self.0, Uptime::units_max(self.0, n, false))
+    }
+
+    #[must_use]
+    /// Same as [`Self::max_units`], but rounds the last unit to the nearest whole
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let uptime = UptimeFull::from(95_400_u32);
+    /// assert_eq!(uptime.max_units(2), "1 day, 2 hours");
+    /// assert_eq!(uptime.max_units_round(2), "1 day, 3 hours");
+    /// ```
+    pub fn max_units_round(&self, n: u8) -> Self {
+        if self.is_unknown() {
+            return Self::UNKNOWN;
+        }
+        Self::from_units(self.0, Uptime::units_max(self.0, n, true))
+    }
 }
 
 //---------------------------------------------------------------------------------------------------- Private impl
@@ -202,19 +249,16 @@ impl UptimeFull {
         // 	return Self(secs, CompactString::new_inline(readable_inlined_time::inlined(secs as u16)))
         // }
 
+        Self::from_units(secs, Uptime::units(secs))
+    }
+
+    /// Format `[years, months, days, hours, minutes, seconds]`, keeping `secs` as the inner number.
+    pub(super) fn from_units(secs: u32, units: [u32; 6]) -> Self {
         if secs == 0 {
             return Self::ZERO;
         }
 
-        let years = secs / 31_536_000; // 365 days
-        let ydays = secs % 31_536_000;
-        let months = ydays / 2_678_400; // 31 days
-        let mdays = ydays % 2_678_400;
-        let days = mdays / 86400;
-        let day_secs = mdays % 86400;
-        let hours = day_secs / 3600;
-        let minutes = day_secs % 3600 / 60;
-        let seconds = day_secs % 60;
+        let [years, months, days, hours, minutes, seconds] = units;
 
         let started = &mut false;
         let mut string = Str::new();
@@ -385,7 +429,7 @@ macro_rules! impl_from_time {
 		}
 	)*}
 }
-impl_from_time!(UptimeFull => Uptime, Htop);
+impl_from_time!(UptimeFull => Uptime, Htop, UptimeCompact);
 #[cfg(feature = "time")]
 impl_from_time!(UptimeFull => TimeUnit);
 
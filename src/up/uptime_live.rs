@@ -0,0 +1,410 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::str::Str;
+use crate::up::Uptime;
+use std::time::Instant;
+
+//---------------------------------------------------------------------------------------------------- UptimeLive
+/// Live, in-place updating [`Uptime`]
+///
+/// This holds a start time and an [`Uptime`] that
+/// can be brought up to date with [`UptimeLive::refresh`].
+///
+/// This is meant for things like status bars that display
+/// the uptime every second, instead of re-creating an [`Uptime`].
+///
+/// ```rust
+/// # use readable::up::*;
+/// let mut live = UptimeLive::new();
+/// assert_eq!(live, "0s");
+///
+/// // Some time later...
+/// live.refresh();
+/// assert!(live.inner() < 5);
+/// ```
+///
+/// ## Refreshing
+/// [`UptimeLive::refresh`] only re-formats the whole string when the
+/// hour changes, otherwise only the minutes & seconds at the end of
+/// the buffer are rewritten.
+///
+/// [`UptimeLive::as_str`] and the other getters do not touch the clock, they are cheap reads.
+///
+/// The formatting is the same as [`Uptime`].
+///
+/// ```rust
+/// # use readable::up::*;
+/// let live = UptimeLive::from_secs(3661);
+/// assert_eq!(live, "1h, 1m, 1s");
+/// assert_eq!(live.uptime(), Uptime::from(3661_u32));
+/// ```
+///
+/// ## Unknown
+/// If the uptime becomes larger than [`Uptime::MAX`], the inner [`Uptime`] will be [`Uptime::UNKNOWN`].
+///
+/// [`UptimeLive::unknown`] is always unknown, even after refreshing.
+#[derive(Copy, Clone)]
+pub struct UptimeLive {
+    start: Instant,
+    /// Seconds already elapsed at `start`.
+    offset: u32,
+    uptime: Uptime,
+    /// Length of the `years` to `hours` part of the string.
+    prefix: u8,
+    /// Created with `unknown()`, `refresh()` does nothing.
+    unknown: bool,
+}
+
+//---------------------------------------------------------------------------------------------------- UptimeLive Impl
+impl UptimeLive {
+    #[inline]
+    #[must_use]
+    /// Create a new [`UptimeLive`] starting from now
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeLive::new(), "0s");
+    /// ```
+    pub fn new() -> Self {
+        Self::from_instant(Instant::now())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a new [`UptimeLive`] starting from an [`Instant`]
+    ///
+    /// The uptime is refreshed before returning.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let start = std::time::Instant::now();
+    /// assert_eq!(UptimeLive::from_instant(start), "0s");
+    /// ```
+    pub fn from_instant(start: Instant) -> Self {
+        Self::from_priv(start, 0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a new [`UptimeLive`] starting from now, with `secs` already elapsed
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeLive::from_secs(60), "1m");
+    /// assert_eq!(UptimeLive::from_secs(86461), "1d, 1m, 1s");
+    /// ```
+    pub fn from_secs(secs: u32) -> Self {
+        Self::from_priv(Instant::now(), secs)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create an unknown [`UptimeLive`]
+    ///
+    /// This is returned by [`SysUptime::sys_uptime`](crate::up::SysUptime::sys_uptime)
+    /// if the system uptime could not be retrieved, and it stays unknown after refreshing.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let mut live = UptimeLive::unknown();
+    /// assert!(live.is_unknown());
+    /// assert_eq!(live, Uptime::UNKNOWN.as_str());
+    ///
+    /// assert!(!live.refresh());
+    /// assert!(live.is_unknown());
+    /// ```
+    pub fn unknown() -> Self {
+        Self {
+            start: Instant::now(),
+            offset: 0,
+            uptime: Uptime::UNKNOWN,
+            prefix: 0,
+            unknown: true,
+        }
+    }
+
+    /// Bring the uptime up to date
+    ///
+    /// This returns `true` if the string changed.
+    ///
+    /// If less than a second has passed since the last refresh, this does nothing.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let mut live = UptimeLive::new();
+    ///
+    /// // Nothing changed.
+    /// assert!(!live.refresh());
+    /// assert_eq!(live, "0s");
+    /// ```
+    pub fn refresh(&mut self) -> bool {
+        if self.unknown {
+            return false;
+        }
+
+        let secs = self.start.elapsed().as_secs() + u64::from(self.offset);
+        match u32::try_from(secs) {
+            Ok(secs) => self.update(secs),
+            Err(_) => {
+                let changed = !self.uptime.is_unknown();
+                self.uptime = Uptime::UNKNOWN;
+                self.prefix = 0;
+                changed
+            }
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the inner seconds as of the last refresh
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeLive::from_secs(60).inner(), 60);
+    /// ```
+    pub const fn inner(&self) -> u32 {
+        self.uptime.inner()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the [`Uptime`] as of the last refresh
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeLive::new().uptime(), Uptime::ZERO);
+    /// ```
+    pub const fn uptime(&self) -> Uptime {
+        self.uptime
+    }
+
+    #[inline]
+    #[must_use]
+    /// Return the [`Instant`] this [`UptimeLive`] is counting from
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let start = std::time::Instant::now();
+    /// assert_eq!(UptimeLive::from_instant(start).start(), start);
+    /// ```
+    pub const fn start(&self) -> Instant {
+        self.start
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeLive::new().as_str(), "0s");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.uptime.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(!UptimeLive::new().is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        self.uptime.is_unknown()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl UptimeLive {
+    fn from_priv(start: Instant, offset: u32) -> Self {
+        let mut this = Self {
+            start,
+            offset,
+            uptime: Uptime::ZERO,
+            prefix: 0,
+            unknown: false,
+        };
+        this.refresh();
+        this
+    }
+
+    /// Set the uptime to `secs`, only rewriting the tail if the hour is the same.
+    fn update(&mut self, secs: u32) -> bool {
+        let old = self.uptime.inner();
+        if self.uptime.is_unknown() || old / 3600 != secs / 3600 {
+            self.uptime = Uptime::from(secs);
+            self.prefix = if secs < 3600 {
+                0
+            } else {
+                Uptime::from(secs - secs % 3600).len_u8()
+            };
+            return true;
+        }
+
+        if old == secs {
+            return false;
+        }
+
+        // Keep the `years` to `hours` part as is, and only re-format
+        // the minutes & seconds. A fresh buffer is used so that no
+        // stale bytes are left behind the new length.
+        let mut s = Str::new();
+        s.push_str_panic(&self.uptime.as_str()[..usize::from(self.prefix)]);
+        let started = &mut (self.prefix != 0);
+        Uptime::plural(&mut s, "m", secs % 3600 / 60, started);
+        Uptime::plural(&mut s, "s", secs % 60, started);
+
+        if s.is_empty() {
+            self.uptime = Uptime::ZERO;
+        } else {
+            self.uptime = Uptime(secs, s);
+        }
+
+        true
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl From<UptimeLive> for Uptime {
+    #[inline]
+    fn from(live: UptimeLive) -> Self {
+        live.uptime
+    }
+}
+
+impl From<&UptimeLive> for Uptime {
+    #[inline]
+    fn from(live: &UptimeLive) -> Self {
+        live.uptime
+    }
+}
+
+impl std::ops::Deref for UptimeLive {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for UptimeLive {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for UptimeLive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The clock state is not part of the value.
+        let Self { uptime, .. } = self;
+        f.debug_struct("UptimeLive")
+            .field("inner", &uptime.inner())
+            .field("str", &self.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for UptimeLive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for UptimeLive {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for UptimeLive {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<UptimeLive> for str {
+    #[inline]
+    fn eq(&self, other: &UptimeLive) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<UptimeLive> for &str {
+    #[inline]
+    fn eq(&self, other: &UptimeLive) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", UptimeLive::from_secs(61)),
+            r#"UptimeLive { inner: 61, str: "1m, 1s" }"#
+        );
+    }
+
+    #[test]
+    fn update() {
+        let mut live = UptimeLive::new();
+
+        // Every second for the first few hours, and around a day.
+        for secs in (0..4 * 3600).chain(86_000..87_000) {
+            live.update(secs);
+            assert_eq!(live.uptime(), Uptime::from(secs), "{secs}");
+        }
+
+        // Skipping around.
+        for secs in [
+            59,
+            3599,
+            3600,
+            3660,
+            3601,
+            0,
+            31_536_000,
+            31_539_599,
+            61,
+            u32::MAX,
+        ] {
+            live.update(secs);
+            assert_eq!(live.uptime(), Uptime::from(secs), "{secs}");
+        }
+    }
+
+    #[test]
+    fn changed() {
+        let mut live = UptimeLive::new();
+        assert!(!live.update(0));
+        assert!(live.update(1));
+        assert!(!live.update(1));
+        assert!(live.update(3600));
+        assert!(!live.update(3600));
+    }
+
+    #[test]
+    fn unknown() {
+        let mut live = UptimeLive::from_secs(u32::MAX);
+        assert_eq!(live, "136y, 2m, 8d, 6h, 28m, 15s");
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        assert!(live.refresh());
+        assert!(live.is_unknown());
+        assert!(!live.refresh());
+
+        assert!(live.update(1));
+        assert_eq!(live, "1s");
+
+        // Stays unknown.
+        let mut live = UptimeLive::unknown();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        assert!(!live.refresh());
+        assert!(live.is_unknown());
+        assert_eq!(Uptime::from(live), Uptime::UNKNOWN);
+    }
+}
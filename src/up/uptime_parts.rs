@@ -0,0 +1,238 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::up::{Eta, Htop, Uptime, UptimeApprox, UptimeCompact, UptimeFull, UptimeLive};
+
+//---------------------------------------------------------------------------------------------------- UptimeParts
+/// The naive calendar units of an uptime
+///
+/// This is the `[years, months, days, hours, minutes, seconds]` breakdown
+/// that [`Uptime`] and [`UptimeFull`] display, returned by `breakdown()`
+/// on all the `up` types:
+///
+/// ```rust
+/// # use readable::up::*;
+/// let parts = Uptime::from(93_784_u32).breakdown();
+/// assert_eq!(parts, UptimeParts {
+///     years:   0,
+///     months:  0,
+///     days:    1,
+///     hours:   2,
+///     minutes: 3,
+///     seconds: 4,
+/// });
+/// assert_eq!(parts.to_secs(), 93_784);
+///
+/// // The breakdown does not depend on how the type displays.
+/// assert_eq!(Htop::from(93_784_u32), "1 day, 02:03:04");
+/// assert_eq!(Htop::from(93_784_u32).breakdown(), parts);
+/// ```
+///
+/// `UNKNOWN` variants return [`UptimeParts::ZERO`].
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(Uptime::UNKNOWN.breakdown(), UptimeParts::ZERO);
+/// ```
+///
+/// ## Naive time
+/// Like the other `readable::up` types, [`UptimeParts`] naively assumes that:
+/// 1. Each day is `86400` seconds
+/// 2. Each month is `31` days
+/// 3. Each year is `365` days
+///
+/// This is incorrect as not all months are 31 days long and leap years exist.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UptimeParts {
+    /// Years, `0..=136`
+    pub years: u8,
+    /// Months, `0..=11`
+    pub months: u8,
+    /// Days, `0..=30`
+    pub days: u8,
+    /// Hours, `0..=23`
+    pub hours: u8,
+    /// Minutes, `0..=59`
+    pub minutes: u8,
+    /// Seconds, `0..=59`
+    pub seconds: u8,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl UptimeParts {
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeParts::ZERO, UptimeParts::from_secs(0));
+    /// assert_eq!(UptimeParts::ZERO, UptimeParts::default());
+    /// ```
+    pub const ZERO: Self = Self {
+        years: 0,
+        months: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+    };
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeParts::MAX, UptimeParts::from_secs(u32::MAX));
+    /// assert_eq!(UptimeParts::MAX, Uptime::MAX.breakdown());
+    /// ```
+    pub const MAX: Self = Self::from_secs(u32::MAX);
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl UptimeParts {
+    #[inline]
+    #[must_use]
+    /// Break down `secs` into naive calendar units
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let parts = UptimeParts::from_secs(31_536_000 + 2_678_400 + 61);
+    /// assert_eq!(parts.years,   1);
+    /// assert_eq!(parts.months,  1);
+    /// assert_eq!(parts.days,    0);
+    /// assert_eq!(parts.hours,   0);
+    /// assert_eq!(parts.minutes, 1);
+    /// assert_eq!(parts.seconds, 1);
+    /// ```
+    pub const fn from_secs(secs: u32) -> Self {
+        Self::from_units(Uptime::units(secs))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Add the units back into seconds
+    ///
+    /// This saturates at [`u32::MAX`].
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let parts = UptimeParts { hours: 1, seconds: 1, ..Default::default() };
+    /// assert_eq!(parts.to_secs(), 3601);
+    ///
+    /// let parts = UptimeParts { years: u8::MAX, ..Default::default() };
+    /// assert_eq!(parts.to_secs(), u32::MAX);
+    /// ```
+    pub const fn to_secs(&self) -> u32 {
+        let units = [
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ];
+
+        let mut secs: u32 = 0;
+        let mut i = 0;
+        while i < units.len() {
+            secs = secs.saturating_add((units[i] as u32).saturating_mul(Uptime::UNIT_SECS[i]));
+            i += 1;
+        }
+        secs
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl UptimeParts {
+    #[inline]
+    /// From `[years, months, days, hours, minutes, seconds]`.
+    pub(super) const fn from_units(units: [u32; 6]) -> Self {
+        let [years, months, days, hours, minutes, seconds] = units;
+        Self {
+            years: years as u8,
+            months: months as u8,
+            days: days as u8,
+            hours: hours as u8,
+            minutes: minutes as u8,
+            seconds: seconds as u8,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Breakdown
+// Implementation Macro.
+macro_rules! impl_breakdown {
+	($($t:ty),*) => { $(
+		impl $t {
+			#[inline]
+			#[must_use]
+			/// Break down the inner seconds into naive calendar units, see [`UptimeParts`]
+			///
+			/// ```rust
+			/// # use readable::up::*;
+			#[doc = concat!("let parts = ", stringify!($t), "::from(93_784_u32).breakdown();")]
+			/// assert_eq!((parts.days, parts.hours, parts.minutes, parts.seconds), (1, 2, 3, 4));
+			///
+			#[doc = concat!("assert_eq!(", stringify!($t), "::UNKNOWN.breakdown(), UptimeParts::ZERO);")]
+			/// ```
+			pub const fn breakdown(&self) -> UptimeParts {
+				if self.is_unknown() {
+					UptimeParts::ZERO
+				} else {
+					UptimeParts::from_secs(self.inner())
+				}
+			}
+		}
+	)*};
+}
+impl_breakdown!(Uptime, UptimeFull, UptimeApprox, Htop, UptimeCompact);
+
+impl UptimeLive {
+    #[inline]
+    #[must_use]
+    /// Break down the seconds as of the last refresh into naive calendar units, see [`UptimeParts`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let parts = UptimeLive::from_secs(93_784).breakdown();
+    /// assert_eq!((parts.days, parts.hours, parts.minutes, parts.seconds), (1, 2, 3, 4));
+    /// ```
+    pub const fn breakdown(&self) -> UptimeParts {
+        self.uptime().breakdown()
+    }
+}
+
+impl Eta {
+    #[inline]
+    #[must_use]
+    /// Break down the remaining seconds into naive calendar units, see [`UptimeParts`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let parts = Eta::new(93_784, 1.0).breakdown();
+    /// assert_eq!((parts.days, parts.hours, parts.minutes, parts.seconds), (1, 2, 3, 4));
+    ///
+    /// assert_eq!(Eta::UNKNOWN.breakdown(), UptimeParts::ZERO);
+    /// ```
+    pub const fn breakdown(&self) -> UptimeParts {
+        if self.is_unknown() {
+            UptimeParts::ZERO
+        } else {
+            UptimeParts::from_secs(self.inner())
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for secs in (0..=u32::MAX).step_by(9_999_991).chain([u32::MAX]) {
+            let parts = UptimeParts::from_secs(secs);
+            assert_eq!(parts.to_secs(), secs);
+            assert_eq!(parts, Uptime::from(secs).breakdown());
+            assert_eq!(parts, UptimeFull::from(secs).breakdown());
+        }
+    }
+}
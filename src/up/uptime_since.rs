@@ -0,0 +1,377 @@
+//---------------------------------------------------------------------------------------------------- Use
+use crate::date::{Date, Weekday};
+use crate::str::Str;
+use crate::up::Uptime;
+
+//---------------------------------------------------------------------------------------------------- UptimeSince
+/// Human-readable boot date and system uptime
+///
+/// This takes `(now, uptime)` as input, where `now` is a UNIX timestamp
+/// and `uptime` is the seconds since boot, and stores the boot [`Date`] and the [`Uptime`]:
+/// ```rust
+/// # use readable::up::*;
+/// // 2024-04-25 12:00:00 UTC.
+/// let now = 1_714_046_400;
+///
+/// let since = UptimeSince::new(now, 273_600);
+/// assert_eq!(since, "up since Mon 2024-04-22, 3d, 4h");
+///
+/// let since = UptimeSince::new(now, 61);
+/// assert_eq!(since, "up since Thu 2024-04-25, 1m, 1s");
+/// ```
+///
+/// The boot date is in UTC.
+///
+/// ## Refreshing
+/// The boot date does not change while the system is up, so
+/// [`UptimeSince::refresh`] only re-formats the uptime at the end of the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// let mut since = UptimeSince::new(1_714_046_400, 273_600);
+///
+/// since.set_uptime(273_660);
+/// assert_eq!(since, "up since Mon 2024-04-22, 3d, 4h, 1m");
+/// assert_eq!(since.uptime(), Uptime::from(273_660_u32));
+/// ```
+///
+/// ## Errors
+/// [`UptimeSince::UNKNOWN`] is returned if the boot year is not in-between `1000-9999`.
+///
+/// ```rust
+/// # use readable::up::*;
+/// // The year `12732`.
+/// assert!(UptimeSince::new(339_618_217_000, 0).is_unknown());
+/// ```
+///
+/// ## Size
+/// [`Str<54>`] is used internally to represent the string.
+///
+/// ```rust
+/// # use readable::up::*;
+/// assert_eq!(std::mem::size_of::<UptimeSince>(), 108);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "date")))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UptimeSince {
+    boot: Date,
+    uptime: Uptime,
+    string: Str<{ UptimeSince::MAX_LEN }>,
+}
+
+//---------------------------------------------------------------------------------------------------- Constants
+impl UptimeSince {
+    /// The maximum string length of an [`UptimeSince`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let since = "up since Wed 2024-04-22, ---y, --m, --d, --h, --m, --s";
+    /// assert_eq!(since.len(), UptimeSince::MAX_LEN);
+    /// ```
+    pub const MAX_LEN: usize = Self::PREFIX_LEN + Uptime::MAX_LEN;
+
+    /// ```rust
+    /// # use readable::up::*;
+    /// # use readable::date::*;
+    /// assert_eq!(UptimeSince::UNKNOWN, "(unknown)");
+    /// assert_eq!(UptimeSince::UNKNOWN.boot_date(), Date::UNKNOWN);
+    /// assert_eq!(UptimeSince::UNKNOWN.uptime(), Uptime::UNKNOWN);
+    /// ```
+    pub const UNKNOWN: Self = Self {
+        boot: Date::UNKNOWN,
+        uptime: Uptime::UNKNOWN,
+        string: Str::from_static_str("(unknown)"),
+    };
+
+    /// The length of `up since Mon 2024-04-22, `.
+    const PREFIX_LEN: usize = "up since Mon , ".len() + Date::MAX_LEN;
+}
+
+//---------------------------------------------------------------------------------------------------- Pub Impl
+impl UptimeSince {
+    #[must_use]
+    /// Create an [`UptimeSince`] from the current UNIX timestamp and the `uptime` seconds
+    ///
+    /// If `uptime` is larger than `now`, the boot date is `1970-01-01`.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeSince::new(86_400, 86_400), "up since Thu 1970-01-01, 1d");
+    /// assert_eq!(UptimeSince::new(0, 60),          "up since Thu 1970-01-01, 1m");
+    /// ```
+    pub fn new(now: u64, uptime: u32) -> Self {
+        match Date::from_unix(now.saturating_sub(u64::from(uptime))) {
+            Ok(boot) => Self::priv_from(boot, Uptime::from(uptime)),
+            Err(_) => Self::UNKNOWN,
+        }
+    }
+
+    #[must_use]
+    /// Get the _current_ boot date and system uptime
+    ///
+    /// See [`uptime`](crate::up::uptime) for the supported platforms.
+    ///
+    /// [`UptimeSince::UNKNOWN`] is returned if the system uptime could not be read.
+    ///
+    /// This is the same as [`SysUptime::sys_uptime`](crate::up::SysUptime::sys_uptime), without needing the trait in scope.
+    pub fn now() -> Self {
+        <Self as crate::up::SysUptime>::sys_uptime()
+    }
+
+    /// Re-read the system uptime, keeping the boot date
+    ///
+    /// This returns `true` if the string changed.
+    ///
+    /// [`UptimeSince::UNKNOWN`] is not changed.
+    pub fn refresh(&mut self) -> bool {
+        match crate::up::uptime() {
+            0 => false,
+            uptime => self.set_uptime(uptime),
+        }
+    }
+
+    /// Set the uptime to `uptime` seconds, keeping the boot date
+    ///
+    /// This returns `true` if the string changed.
+    ///
+    /// [`UptimeSince::UNKNOWN`] is not changed.
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let mut since = UptimeSince::new(86_400, 60);
+    /// assert_eq!(since, "up since Thu 1970-01-01, 1m");
+    ///
+    /// assert!(since.set_uptime(120));
+    /// assert_eq!(since, "up since Thu 1970-01-01, 2m");
+    /// assert!(!since.set_uptime(120));
+    ///
+    /// let mut unknown = UptimeSince::UNKNOWN;
+    /// assert!(!unknown.set_uptime(120));
+    /// assert!(unknown.is_unknown());
+    /// ```
+    pub fn set_uptime(&mut self, uptime: u32) -> bool {
+        if self.is_unknown() || self.uptime.inner() == uptime {
+            return false;
+        }
+        // The `up since Mon 2024-04-22, ` prefix is always the same length.
+        let uptime = Uptime::from(uptime);
+        let mut string = Str::new();
+        string.push_str_panic(&self.string.as_str()[..Self::PREFIX_LEN]);
+        string.push_str_panic(uptime);
+
+        self.uptime = uptime;
+        self.string = string;
+        true
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the boot [`Date`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let since = UptimeSince::new(1_714_046_400, 273_600);
+    /// assert_eq!(since.boot_date(), "2024-04-22");
+    /// ```
+    pub const fn boot_date(&self) -> Date {
+        self.boot
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the [`Uptime`]
+    ///
+    /// ```rust
+    /// # use readable::up::*;
+    /// let since = UptimeSince::new(1_714_046_400, 273_600);
+    /// assert_eq!(since.uptime(), "3d, 4h");
+    /// ```
+    pub const fn uptime(&self) -> Uptime {
+        self.uptime
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeSince::new(60, 60).as_str(), "up since Thu 1970-01-01, 1m");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeSince::new(60, 60).as_bytes(), b"up since Thu 1970-01-01, 1m");
+    /// ```
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.string.as_bytes()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert_eq!(UptimeSince::new(60, 60).len(), 27);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.string.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// ```rust
+    /// # use readable::up::*;
+    /// assert!(UptimeSince::UNKNOWN.is_unknown());
+    /// assert!(!UptimeSince::new(0, 0).is_unknown());
+    /// ```
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self.string.as_bytes(), b"(unknown)")
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Private Impl
+impl UptimeSince {
+    fn priv_from(boot: Date, uptime: Uptime) -> Self {
+        let mut string = Str::new();
+        string.push_str_panic("up since ");
+        string.push_str_panic(Weekday::from(boot).as_str_short());
+        string.push_char_panic(' ');
+        string.push_str_panic(boot);
+        string.push_str_panic(", ");
+        string.push_str_panic(uptime);
+
+        Self {
+            boot,
+            uptime,
+            string,
+        }
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- Traits
+impl std::ops::Deref for UptimeSince {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for UptimeSince {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for UptimeSince {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UptimeSince")
+            .field("inner", &(self.boot.inner(), self.uptime.inner()))
+            .field("str", &self.string.as_str())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for UptimeSince {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::str::pad(f, self.as_str())
+    }
+}
+
+impl PartialEq<str> for UptimeSince {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for UptimeSince {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<UptimeSince> for str {
+    #[inline]
+    fn eq(&self, other: &UptimeSince) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<UptimeSince> for &str {
+    #[inline]
+    fn eq(&self, other: &UptimeSince) -> bool {
+        *self == other.as_str()
+    }
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", UptimeSince::new(86_400, 86_400)),
+            r#"UptimeSince { inner: ((1970, 1, 1), 86400), str: "up since Thu 1970-01-01, 1d" }"#
+        );
+    }
+
+    #[test]
+    fn new() {
+        // 9999-12-31 23:59:59 UTC.
+        let max = 253_402_300_799;
+        let since = UptimeSince::new(max, u32::MAX);
+        assert_eq!(since.boot_date(), "9863-11-24");
+        assert_eq!(since.uptime(), Uptime::MAX);
+        assert!(since.len() <= UptimeSince::MAX_LEN);
+
+        for uptime in (0..=u32::MAX).step_by(999_983) {
+            let since = UptimeSince::new(max, uptime);
+            assert!(since.as_str().starts_with("up since "));
+            assert!(since.as_str().ends_with(Uptime::from(uptime).as_str()));
+            assert_eq!(since.len(), UptimeSince::PREFIX_LEN + since.uptime().len());
+
+            let mut set = UptimeSince::new(max, 0);
+            set.set_uptime(uptime);
+            assert_eq!(set.as_str()[UptimeSince::PREFIX_LEN..], *since.uptime());
+        }
+
+        // Year `10000`.
+        assert!(UptimeSince::new(max + 1, 0).is_unknown());
+        assert!(!UptimeSince::new(max + 1, 1).is_unknown());
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+    fn now() {
+        let mut since = UptimeSince::now();
+        assert!(!since.is_unknown());
+        assert_eq!(since.boot_date(), UptimeSince::now().boot_date());
+        since.refresh();
+        assert!(!since.is_unknown());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let this = UptimeSince::new(1_714_046_400, 273_600);
+        let json = serde_json::to_string(&this).unwrap();
+        let this: UptimeSince = serde_json::from_str(&json).unwrap();
+        assert_eq!(this, "up since Mon 2024-04-22, 3d, 4h");
+        assert_eq!(this.uptime(), 273_600_u32);
+    }
+}